@@ -0,0 +1,102 @@
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use iced::Pixels;
+use iced::advanced::renderer::Headless;
+use iced::futures::FutureExt;
+
+use super::APP_FONT;
+use super::state::App;
+use super::view::export_image;
+use crate::graph::gif::{self, GifFrame};
+
+/// How long each step of the animation is shown, in hundredths of a second.
+const STEP_DELAY: u16 = 80;
+
+/// How long the last step is held before the animation starts over.
+const FINAL_DELAY: u16 = 240;
+
+impl App {
+    /// Replays the simulation trace on the simulated automaton and saves it as
+    /// an animated GIF, for embedding in slides.
+    ///
+    /// Every step is drawn offscreen through the canvas draw pipeline with the
+    /// image export settings. The cursor, path replay and transition preview
+    /// are restored afterwards.
+    ///
+    /// # Returns
+    /// - `Result<PathBuf, String>` - Where the animation was saved, or why it
+    ///   could not be.
+    pub fn save_simulation_animation(&mut self) -> Result<PathBuf, String> {
+        let steps = self
+            .simulation
+            .step_count()
+            .filter(|&steps| steps > 0)
+            .ok_or("there is no simulation to record")?;
+        // The tiny-skia renderer is created synchronously, so its future is already ready.
+        let mut renderer =
+            <iced::Renderer as Headless>::new(APP_FONT, Pixels(16.0), Some("tiny-skia"))
+                .now_or_never()
+                .flatten()
+                .ok_or("no offscreen renderer is available")?;
+
+        let cursor = self.simulation.cursor;
+        let replay = self.simulation.replay.take();
+        let preview = self.simulation.preview.take();
+        let mode = self.simulation.target.view();
+        let mut images = Vec::with_capacity(steps);
+        for step in 0..steps {
+            self.simulation.cursor = step;
+            images.extend(export_image(self, mode, &mut renderer));
+        }
+        self.simulation.cursor = cursor;
+        self.simulation.replay = replay;
+        self.simulation.preview = preview;
+
+        let first = images
+            .first()
+            .ok_or("the simulated automaton was not built")?;
+        let (width, height) = (first.width, first.height);
+        if images
+            .iter()
+            .any(|image| image.width != width || image.height != height)
+        {
+            return Err("the steps were drawn at different sizes".into());
+        }
+        let (Ok(gif_width), Ok(gif_height)) = (u16::try_from(width), u16::try_from(height)) else {
+            return Err("the animation is too large; lower the pixel density".into());
+        };
+
+        let last = images.len() - 1;
+        let frames: Vec<GifFrame> = images
+            .into_iter()
+            .enumerate()
+            .map(|(index, image)| GifFrame {
+                pixels: image.pixels,
+                delay: if index == last {
+                    FINAL_DELAY
+                } else {
+                    STEP_DELAY
+                },
+            })
+            .collect();
+
+        let path = animation_path();
+        std::fs::write(&path, gif::encode(gif_width, gif_height, &frames))
+            .map_err(|error| error.to_string())?;
+        Ok(path)
+    }
+}
+
+/// Returns a new file name for an animation in the user's home directory, or
+/// in the working directory when there is none.
+fn animation_path() -> PathBuf {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let dir = std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+        .unwrap_or_default();
+    dir.join(format!("regviz-simulation-{seconds}.gif"))
+}
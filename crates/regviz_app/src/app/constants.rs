@@ -1,3 +1,7 @@
+use std::time::Duration;
+
+use iced::Size;
+
 /// Minimum zoom level (25% of original size).
 pub const MIN_ZOOM_FACTOR: f32 = 0.25;
 
@@ -7,5 +11,35 @@ pub const MAX_ZOOM_FACTOR: f32 = 4.0;
 /// Default zoom level (100%, fit to screen).
 pub const DEFAULT_ZOOM_FACTOR: f32 = 1.0;
 
-/// Zoom step per scroll tick (10%).
+/// Default zoom step per scroll tick (10%).
 pub const ZOOM_STEP: f32 = 0.1;
+
+/// Smallest zoom step per scroll tick offered in the settings (1%).
+pub const MIN_ZOOM_STEP: f32 = 0.01;
+
+/// Largest zoom step per scroll tick offered in the settings (50%).
+pub const MAX_ZOOM_STEP: f32 = 0.5;
+
+/// Viewport width below which the panes stack and the controls move into a drawer.
+pub const NARROW_LAYOUT_WIDTH: f32 = 720.0;
+
+/// Initial size of a window showing a detached view.
+pub const DETACHED_WINDOW_SIZE: Size = Size::new(900.0, 700.0);
+
+/// Maximum number of accepting NFA paths offered for replay.
+pub const MAX_ACCEPTING_PATHS: usize = 5;
+
+/// Longest name a state can be given from the inspector.
+pub const MAX_STATE_NAME_LEN: usize = 12;
+
+/// Widget id of the regular expression input, for selecting text in it.
+pub const REGEX_INPUT_ID: &str = "regex-input";
+
+/// Widget id of the inspector's state name input, for focusing it.
+pub const RENAME_INPUT_ID: &str = "rename-input";
+
+/// Delay between steps while the simulation is playing.
+pub const PLAYBACK_INTERVAL: Duration = Duration::from_millis(600);
+
+/// How long the DFA states and transitions changed by an edit are flashed.
+pub const EDIT_FLASH_DURATION: Duration = Duration::from_millis(1500);
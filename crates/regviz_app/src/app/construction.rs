@@ -0,0 +1,164 @@
+use std::collections::{HashMap, HashSet};
+
+use regviz_core::core::nfa::ConstructionStep;
+use regviz_core::core::parser::{Ast, ParseEvent};
+
+use crate::graph::{AstGraph, EdgeHighlight, Graph, Highlights, Reveal, StateHighlight};
+
+/// Step-by-step replay of how a graph was built, one recorded step at a time.
+///
+/// Replays Thompson's construction of the NFA as [`ConstructionStep`]s and the
+/// parse of the pattern as [`ParseEvent`]s.
+#[derive(Debug, Clone)]
+pub struct Replay<T> {
+    /// Steps in the order they were recorded.
+    pub steps: Vec<T>,
+    /// Index of the step shown.
+    pub cursor: usize,
+    /// Whether steps are taken automatically.
+    pub playing: bool,
+}
+
+impl<T> Replay<T> {
+    /// Starts a replay at its first step, or returns `None` when nothing was recorded.
+    #[must_use]
+    pub fn new(steps: Vec<T>) -> Option<Self> {
+        (!steps.is_empty()).then_some(Self {
+            steps,
+            cursor: 0,
+            playing: false,
+        })
+    }
+
+    /// Returns the step shown.
+    #[must_use]
+    pub fn current(&self) -> &T {
+        &self.steps[self.cursor]
+    }
+
+    /// Returns the steps up to and including the one shown.
+    #[must_use]
+    pub fn taken(&self) -> &[T] {
+        &self.steps[..=self.cursor]
+    }
+
+    /// Returns whether there is a step left to take.
+    #[must_use]
+    pub fn can_step_forward(&self) -> bool {
+        self.cursor + 1 < self.steps.len()
+    }
+
+    /// Returns whether there is a step to take back.
+    #[must_use]
+    pub fn can_step_backward(&self) -> bool {
+        self.cursor > 0
+    }
+
+    /// Takes the next step, if any.
+    pub fn step_forward(&mut self) {
+        if self.can_step_forward() {
+            self.cursor += 1;
+        }
+    }
+
+    /// Takes back the step shown, unless it is the first.
+    pub fn step_backward(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    /// Starts or pauses playback, starting over once every step is taken.
+    pub fn toggle_play(&mut self) {
+        if self.playing {
+            self.playing = false;
+            return;
+        }
+        if !self.can_step_forward() {
+            self.cursor = 0;
+        }
+        self.playing = self.can_step_forward();
+    }
+
+    /// Advances playback by one step, pausing after the last one.
+    pub fn play_tick(&mut self) {
+        if !self.playing {
+            return;
+        }
+        self.step_forward();
+        self.playing = self.can_step_forward();
+    }
+}
+
+impl Replay<ConstructionStep> {
+    /// Returns the states, transitions and boxes built so far.
+    #[must_use]
+    pub fn reveal(&self) -> Reveal {
+        let mut reveal = Reveal::default();
+        for step in self.taken() {
+            reveal.states.extend(&step.states);
+            reveal
+                .edges
+                .extend(step.edges.iter().map(|edge| (edge.from, edge.to)));
+            reveal.boxes.insert(step.box_id);
+        }
+        reveal
+    }
+
+    /// Highlights what the most recently built fragment added.
+    #[must_use]
+    pub fn highlights(&self) -> Highlights {
+        let step = self.current();
+        let states: HashMap<_, _> = step
+            .states
+            .iter()
+            .map(|&state| (state, StateHighlight::Active))
+            .collect();
+        let edges: HashSet<_> = step
+            .edges
+            .iter()
+            .map(|edge| EdgeHighlight::new(edge.from, edge.to, edge.label))
+            .collect();
+        Highlights::new(states, edges)
+    }
+}
+
+impl Replay<ParseEvent> {
+    /// Returns the most recently built node and its number in the tree, which
+    /// is the left operand of any binding decision shown.
+    #[must_use]
+    pub fn last_built(&self) -> Option<(usize, &Ast)> {
+        self.taken().iter().rev().find_map(|event| match event {
+            ParseEvent::Build { id, node } => Some((*id, node)),
+            _ => None,
+        })
+    }
+
+    /// Returns the nodes of `ast` built so far and the links between them.
+    #[must_use]
+    pub fn reveal(&self, ast: &Ast) -> Reveal {
+        let mut reveal = Reveal::default();
+        for event in self.taken() {
+            if let ParseEvent::Build { id, .. } = event {
+                reveal.states.insert(*id as u32);
+            }
+        }
+        let pinned = HashMap::new();
+        reveal.edges = AstGraph::new(ast, &pinned)
+            .edges()
+            .into_iter()
+            .map(|edge| (edge.from, edge.to))
+            .filter(|(from, to)| reveal.states.contains(from) && reveal.states.contains(to))
+            .collect();
+        reveal
+    }
+
+    /// Highlights the most recently built node.
+    #[must_use]
+    pub fn highlights(&self) -> Highlights {
+        let states = self
+            .last_built()
+            .map(|(id, _)| (id as u32, StateHighlight::Active))
+            .into_iter()
+            .collect();
+        Highlights::new(states, HashSet::new())
+    }
+}
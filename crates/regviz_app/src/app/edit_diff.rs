@@ -0,0 +1,87 @@
+use iced::time::Instant;
+use regviz_core::core::dfa::Dfa;
+use regviz_core::core::diff::{self, DfaDiff};
+
+use super::constants::EDIT_FLASH_DURATION;
+
+/// How the last edit of the regex changed its DFAs, flashed on the canvas
+/// for a moment after the edit.
+#[derive(Debug, Default)]
+pub struct EditDiff {
+    /// Determinized and minimized DFAs of the last regex that built, to
+    /// compare the next ones with.
+    before: (Option<Dfa>, Option<Dfa>),
+    /// Changes from the previous determinized DFA, if it differed.
+    dfa: Option<DfaDiff>,
+    /// Changes from the previous minimized DFA, if it differed.
+    min_dfa: Option<DfaDiff>,
+    /// Seconds the changes have been flashing for.
+    pub elapsed: f32,
+    /// Frame the flash started on.
+    start: Option<Instant>,
+}
+
+impl EditDiff {
+    /// Keeps the DFAs built from the regex about to be replaced, to compare
+    /// the next ones with.
+    pub fn remember(&mut self, dfa: Option<Dfa>, min_dfa: Option<Dfa>) {
+        self.before = (dfa, min_dfa);
+    }
+
+    /// Compares the DFAs built from the edited regex with the remembered
+    /// ones and starts flashing the changes, if any.
+    ///
+    /// # Arguments
+    /// - `dfa` (`Option<&Dfa>`) - The new determinized DFA, if it was built.
+    /// - `min_dfa` (`Option<&Dfa>`) - The new minimized DFA, if it was built.
+    pub fn compare(&mut self, dfa: Option<&Dfa>, min_dfa: Option<&Dfa>) {
+        let (before_dfa, before_min) = std::mem::take(&mut self.before);
+        let changes = |before: Option<Dfa>, after: Option<&Dfa>| {
+            let changes = diff::diff(&before?, after?);
+            (!changes.is_identical()).then_some(changes)
+        };
+        self.dfa = changes(before_dfa, dfa);
+        self.min_dfa = changes(before_min, min_dfa);
+        self.elapsed = 0.0;
+        self.start = None;
+    }
+
+    /// Returns whether changes from the last edit are still flashing.
+    #[must_use]
+    pub fn is_flashing(&self) -> bool {
+        self.dfa.is_some() || self.min_dfa.is_some()
+    }
+
+    /// Fades the flash to where it is on the frame drawn at `now`, clearing
+    /// it once it has faded out.
+    pub fn advance(&mut self, now: Instant) {
+        let start = *self.start.get_or_insert(now);
+        let elapsed = now.duration_since(start);
+        if elapsed >= EDIT_FLASH_DURATION {
+            self.dfa = None;
+            self.min_dfa = None;
+            self.start = None;
+            self.elapsed = 0.0;
+        } else {
+            self.elapsed = elapsed.as_secs_f32();
+        }
+    }
+
+    /// Returns the flashing changes to the minimized DFA if `minimized`,
+    /// otherwise to the determinized one.
+    #[must_use]
+    pub fn changes(&self, minimized: bool) -> Option<&DfaDiff> {
+        if minimized {
+            self.min_dfa.as_ref()
+        } else {
+            self.dfa.as_ref()
+        }
+    }
+
+    /// Returns how strongly the changes are flashed, fading from 1 right
+    /// after the edit to 0 when the flash ends.
+    #[must_use]
+    pub fn intensity(&self) -> f32 {
+        (1.0 - self.elapsed / EDIT_FLASH_DURATION.as_secs_f32()).clamp(0.0, 1.0)
+    }
+}
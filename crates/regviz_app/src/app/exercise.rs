@@ -0,0 +1,173 @@
+use regviz_core::core::dfa::{self, Dfa};
+use regviz_core::core::diff::{self, DfaDiff};
+use regviz_core::core::equiv::{self, Equivalence};
+use regviz_core::core::min;
+use regviz_core::core::nfa::Nfa;
+use regviz_core::core::parser::Ast;
+use regviz_core::core::sim;
+use regviz_core::errors::BuildError;
+
+use super::i18n::Key;
+
+/// A target language the user has to describe with a regular expression.
+#[derive(Debug, Clone, Copy)]
+pub struct Exercise {
+    /// Plain-language description of the target language.
+    pub description: Key,
+    /// A regular expression for the target language, hidden until revealed.
+    pub solution: &'static str,
+}
+
+/// Preset exercises, roughly from easiest to hardest. All use Σ = {a, b}.
+pub const EXERCISES: &[Exercise] = &[
+    Exercise {
+        description: Key::ExerciseEndsInAb,
+        solution: "(a+b)*ab",
+    },
+    Exercise {
+        description: Key::ExerciseContainsAba,
+        solution: "(a+b)*aba(a+b)*",
+    },
+    Exercise {
+        description: Key::ExerciseAFollowedByB,
+        solution: "(b+ab)*",
+    },
+    Exercise {
+        description: Key::ExerciseLengthMultipleOfThree,
+        solution: "((a+b)(a+b)(a+b))*",
+    },
+    Exercise {
+        description: Key::ExerciseEvenAs,
+        solution: "b*(ab*ab*)*",
+    },
+    Exercise {
+        description: Key::ExerciseNoDoubleA,
+        solution: "(b+ab)*(a+\\e)",
+    },
+    Exercise {
+        description: Key::ExerciseSameEnds,
+        solution: "a+b+a(a+b)*a+b(a+b)*b",
+    },
+];
+
+/// Where the user's current answer stands against the target language.
+#[derive(Debug, Clone)]
+pub enum ExerciseStatus {
+    /// Nothing has been typed yet.
+    Empty,
+    /// The answer does not parse.
+    Invalid(BuildError),
+    /// The answer describes a different language.
+    Wrong {
+        /// A string on which the answer and the target disagree.
+        counterexample: String,
+        /// Whether the answer accepts the counterexample (and the target rejects it).
+        accepted_by_answer: bool,
+    },
+    /// The answer is equivalent to the target.
+    Solved,
+}
+
+/// State of exercise mode: which exercise is shown and how the answer fares.
+#[derive(Debug)]
+pub struct ExerciseState {
+    /// Index into [`EXERCISES`] of the current exercise.
+    pub index: usize,
+    /// The user's regular expression.
+    pub answer: String,
+    /// Result of checking `answer` against the target.
+    pub status: ExerciseStatus,
+    /// Minimized DFA for the answer, shown on the canvas.
+    pub answer_dfa: Option<Dfa>,
+    /// Structural differences from the target's minimized DFA to `answer_dfa`,
+    /// set while the answer is wrong.
+    pub answer_diff: Option<DfaDiff>,
+    /// Whether the solution is shown.
+    pub revealed: bool,
+    /// Exercises solved so far, by index.
+    pub solved: Vec<bool>,
+    /// DFA for the current exercise's target language.
+    target: Dfa,
+}
+
+impl Default for ExerciseState {
+    fn default() -> Self {
+        Self {
+            index: 0,
+            answer: String::new(),
+            status: ExerciseStatus::Empty,
+            answer_dfa: None,
+            answer_diff: None,
+            revealed: false,
+            solved: vec![false; EXERCISES.len()],
+            target: target_dfa(&EXERCISES[0]),
+        }
+    }
+}
+
+impl ExerciseState {
+    /// Returns the exercise being attempted.
+    #[must_use]
+    pub fn current(&self) -> &'static Exercise {
+        &EXERCISES[self.index]
+    }
+
+    /// Replaces the answer and checks it against the target.
+    pub fn set_answer(&mut self, answer: String) {
+        self.answer = answer;
+        self.check();
+    }
+
+    /// Moves to another exercise (wrapping around), clearing the answer.
+    pub fn go_to(&mut self, index: usize) {
+        self.index = index % EXERCISES.len();
+        self.target = target_dfa(self.current());
+        self.revealed = false;
+        self.set_answer(String::new());
+    }
+
+    /// Shows the hidden solution.
+    pub fn reveal(&mut self) {
+        self.revealed = true;
+    }
+
+    /// Re-checks the answer, recording the exercise as solved on success.
+    fn check(&mut self) {
+        let pattern = self.answer.trim();
+        self.answer_dfa = None;
+        self.answer_diff = None;
+        if pattern.is_empty() {
+            self.status = ExerciseStatus::Empty;
+            return;
+        }
+
+        let answer = match Ast::build(pattern) {
+            Ok(ast) => dfa::determinize(&Nfa::build(&ast)),
+            Err(err) => {
+                self.status = ExerciseStatus::Invalid(err);
+                return;
+            }
+        };
+        self.status = match equiv::hopcroft_karp(&answer, &self.target) {
+            Equivalence::Equivalent => {
+                self.solved[self.index] = true;
+                ExerciseStatus::Solved
+            }
+            Equivalence::Distinguished(counterexample) => ExerciseStatus::Wrong {
+                accepted_by_answer: sim::simulate_dfa(&answer, &counterexample),
+                counterexample,
+            },
+        };
+        let answer = min::minimize(&answer);
+        if matches!(self.status, ExerciseStatus::Wrong { .. }) {
+            self.answer_diff = Some(diff::diff(&min::minimize(&self.target), &answer));
+        }
+        self.answer_dfa = Some(answer);
+    }
+}
+
+/// Builds the DFA of an exercise's solution.
+fn target_dfa(exercise: &Exercise) -> Dfa {
+    let ast = Ast::build(exercise.solution).expect("preset solutions are valid patterns");
+    dfa::determinize(&Nfa::build(&ast))
+}
@@ -0,0 +1,418 @@
+use super::Key;
+
+/// English texts.
+pub(super) fn text(key: Key) -> &'static str {
+    match key {
+        Key::AppTitle => "Regular Expression Visualizer",
+        Key::AppSubtitle => "Build and visualize finite automata from regular expressions.",
+        Key::TutorialButton => "Tutorial",
+        Key::Language => "Language",
+        Key::ShowControls => "☰ Controls",
+        Key::HideControls => "✕ Close controls",
+
+        Key::RegexHeading => "Regular Expression",
+        Key::RegexPlaceholder => "e.g., (a+b)c",
+        Key::ParsedSummary => "Parsed successfully | {0} states | Alphabet: {1}",
+        Key::EnterRegexHint => "Enter a regular expression to visualize",
+        Key::ErrorMessage => "Error: {0}",
+        Key::HintDanglingEscape => "Expected a character to escape after '\\' here.",
+        Key::HintInvalidCharacter => {
+            "'{0}' cannot be used here; escape it as a literal or remove it."
+        }
+        Key::HintUnexpectedEof => "Expected an expression here.",
+        Key::HintMissingOperand => "Expected an expression before '{0}' here.",
+        Key::HintExpectedRightParen => "Expected ')' here.",
+        Key::HintUnmatchedRightParen => "This ')' has no matching '('.",
+        Key::HintEmptyGroup => "Expected an expression inside the parentheses here.",
+        Key::DidYouMean => "Did you mean {0}?",
+        Key::ApplySuggestion => "Apply",
+        Key::FixUseAlternation => "Alternation is written '+'.",
+        Key::FixEscapeCharacter => "Escape '{0}' to match it literally.",
+        Key::FixRemoveCharacter => "Remove '{0}'.",
+        Key::FixRemoveEscape => "Remove the trailing '\\'.",
+        Key::FixRemoveOperator => "Remove the extra '{0}'.",
+        Key::FixCloseGroup => "Close the group with ')'.",
+        Key::FixRemoveLeftParen => "Remove the unclosed '('.",
+        Key::FixRemoveRightParen => "Remove the unmatched ')'.",
+        Key::FixOpenGroup => "Open a group with '(' at the start.",
+        Key::FixEmptyGroupAsEpsilon => "Write the empty group as ε.",
+        Key::WarningRedundantRepetition => {
+            "Warning: {0} repeats an expression that already matches the empty string."
+        }
+        Key::WarningUnreachableAlternative => {
+            "Warning: the alternative {0} only matches strings {1} already matches."
+        }
+        Key::WarningEpsilonLoop => "Warning: {0} loops without reading any symbol.",
+        Key::WarningLargeDfa => "Warning: the DFA has {0} states; the graph may be hard to read.",
+        Key::AlphabetHeading => "Alphabet",
+        Key::AlphabetPlaceholder => "Inferred from the regex, e.g. abc",
+        Key::AlphabetRejected => "Error: {0}. Using the inferred alphabet instead.",
+        Key::AlphabetHint => "Declare extra symbols so the DFA has complete rows for them.",
+        Key::AlphabetUnused => "{0} ({1} not used by the regex)",
+        Key::TokensShown => "▾ Tokens ({0})",
+        Key::TokensHidden => "▸ Tokens ({0})",
+        Key::TokenColumn => "col. {0}",
+        Key::TokenColumns => "cols. {0}–{1}",
+        Key::TokenLiteral => "literal",
+        Key::TokenEpsilon => "empty string",
+        Key::TokenOperator => "operator",
+        Key::TokenGroup => "group",
+        Key::TokenEnd => "end of input",
+
+        Key::NewTab => "+ New tab",
+        Key::UntitledTab => "Untitled",
+        Key::PaneLayout => "Layout",
+        Key::LayoutControlsLeft => "Controls left",
+        Key::LayoutControlsRight => "Controls right",
+        Key::LayoutControlsBottom => "Controls below",
+        Key::LayoutVisualizationOnly => "Maximized",
+        Key::BoundingBoxes => "Bounding Boxes",
+        Key::BoxLiteral => "Literal",
+        Key::BoxConcat => "Concat",
+        Key::BoxAlternation => "Alt",
+        Key::BoxStar => "Star",
+        Key::BoxPlus => "Plus",
+        Key::BoxOptional => "Optional",
+        Key::DimNestedBoxes => "Dim nested boxes",
+        Key::NestingLevels => "Levels: {0} of {1}",
+        Key::ExpandedLevels => "Expanded: {0} of {1}",
+        Key::BoxStyle => "Box Style",
+        Key::GraphSearchPlaceholder => "Find a state or symbol",
+        Key::OpenInWindow => "Open in new window",
+        Key::BoxStyleLabel => "Label",
+        Key::BoxStyleDashed => "Dashed",
+        Key::BoxStyleFill => "Fill",
+        Key::BoxStyleCorners => "Corner radius: {0}px",
+        Key::LayoutTuningShown => "▾ Layout tuning",
+        Key::LayoutTuningHidden => "▸ Layout tuning",
+        Key::NodeSpacing => "Node spacing: {0}",
+        Key::LevelSpacing => "Level spacing: {0}",
+        Key::BranchGap => "Branch gap: {0}",
+        Key::BoxPadding => "Box padding: {0}",
+        Key::ResetLayoutTuning => "Reset spacing",
+        Key::NfaOrientation => "NFA direction",
+        Key::OrientationLeftToRight => "Left to right",
+        Key::OrientationRightToLeft => "Right to left",
+        Key::OrientationTopToBottom => "Top to bottom",
+        Key::Overlays => "Overlays",
+        Key::SccColors => "SCC Colors",
+        Key::ShowInfiniteWitness => "Show why this language is infinite",
+        Key::SccCaption => "States sharing a color lie on a common cycle.",
+        Key::Heatmap => "Heatmap",
+        Key::HeatmapCaption => {
+            "Redder states have more transitions; transitions into busy states are amber or red."
+        }
+        Key::SymbolCounts => "Transitions per symbol: {0}",
+        Key::EmptyString => "Empty string",
+        Key::StateNumbers => "DFA state numbers",
+        Key::NumberingOriginal => "Original",
+        Key::NumberingBfs => "BFS",
+        Key::NumberingNerodeClass => "Nerode class",
+        Key::TransitionGroupingLabel => "DFA transitions",
+        Key::GroupingMerged => "Merged",
+        Key::GroupingPartitioned => "Σ partition",
+        Key::GroupingSeparate => "Separate",
+        Key::ImageExport => "Image Export",
+        Key::ExportNodeRadius => "State radius: {0}px",
+        Key::ExportStrokeWidth => "Line width: {0}",
+        Key::ExportTextSize => "Label size: {0}",
+        Key::ExportSupersampling => "Pixel density: {0}x",
+        Key::CopySvg => "Copy view as SVG",
+        Key::SvgCopied => "View copied to the clipboard as SVG.",
+        Key::DeltaTable => "Table",
+        Key::DeltaListing => "Function listing",
+        Key::TransitionFunction => "Transition function δ",
+        Key::CopyLatex => "Copy as LaTeX",
+        Key::LatexCopied => "Definition copied to the clipboard as LaTeX.",
+        Key::DfaUnavailable => "DFA is not available",
+        Key::LanguageFinite => "The language is finite: no cycle can reach an accepting state.",
+        Key::LassoCaption => {
+            "Every u·vⁿ·w is accepted, with u = \"{0}\", v = \"{1}\", w = \"{2}\" (e.g. \"{3}\")."
+        }
+        Key::Zoom => "Zoom: {0}",
+        Key::ResetView => "Reset View",
+
+        Key::OperationsHeading => "Operations",
+        Key::OpComplement => "Complement",
+        Key::OpReverse => "Reverse",
+        Key::OpStar => "Star",
+        Key::OpTrim => "Trim",
+        Key::OperationsHint => {
+            "Applies to the DFA on screen (or the determinized DFA), then to each result."
+        }
+        Key::Undo => "Undo",
+        Key::Clear => "Clear",
+
+        Key::InspectorHeading => "Inspector",
+        Key::Accepting => "accepting",
+        Key::NotAccepting => "not accepting",
+        Key::StateSummary => "State {0} • {1}",
+        Key::ShowReachable => "Show reachable set",
+        Key::ShowAcceptingPaths => "Show states that can reach accept through here",
+        Key::UnreachableState => "This state is unreachable from the start state.",
+        Key::Name => "Name",
+        Key::RenamePlaceholder => "Rename this state",
+        Key::ResidualLanguage => "Language from this state: u⁻¹L with u = \"{0}\"",
+        Key::EmptyResidual => "∅ (no string is accepted from here)",
+        Key::LeftLanguage => "Strings reaching this state: {0}",
+        Key::RightLanguage => "Strings accepted from this state: {0}",
+
+        Key::PinState => "Pin here",
+        Key::UnpinState => "Unpin",
+        Key::RenameState => "Rename…",
+        Key::SetBreakpoint => "Set breakpoint",
+        Key::RemoveBreakpoint => "Remove breakpoint",
+        Key::CopyRightLanguage => "Copy residual regex",
+        Key::SelectTransition => "Select transition",
+        Key::CollapseBox => "Collapse box",
+        Key::ExpandBox => "Expand box",
+
+        Key::CursorPosition => "x {0}, y {1}",
+        Key::StateCount => "{0} states, {1} edges",
+        Key::TreeNodeCount => "{0} nodes, {1} edges",
+        Key::SelectedElement => "Selected: {0}",
+        Key::NothingSelected => "Nothing selected",
+        Key::EditSummary => "Last edit: {0} added, {1} removed, {2} changed",
+
+        Key::TitleAst => "Parse Tree Visualization",
+        Key::TitleNfa => "NFA Simulation",
+        Key::TitleDfa => "DFA Simulation",
+        Key::TitleMinDfa => "Minimized DFA Simulation",
+        Key::TitleAnalysis => "Language Analysis",
+        Key::TitleOperations => "Operation Result",
+        Key::TitleWorkbench => "Boolean Workbench",
+        Key::TitleExercises => "Exercises",
+        Key::TitleDefinition => "Formal Definition",
+        Key::NoParseTree => {
+            "This automaton was not built from a regular expression, so it has no parse tree"
+        }
+        Key::TabNfa => "NFA",
+        Key::TabDfa => "DFA",
+        Key::TabMinDfa => "Min DFA",
+        Key::TabAst => "AST",
+        Key::TabAnalysis => "Analysis",
+        Key::TabOperations => "Operations",
+        Key::TabWorkbench => "Workbench",
+        Key::TabExercises => "Exercises",
+        Key::TabDefinition => "Definition",
+        Key::DeterminizedUnavailable => "Determinized DFA is not available",
+        Key::MinimizedUnavailable => "Minimized DFA is not available",
+        Key::OperationsEmpty => {
+            "Apply an operation from the Operations panel to see its result here"
+        }
+        Key::Statistics => "Automaton sizes",
+        Key::StatStates => "States",
+        Key::StatTransitions => "Transitions",
+        Key::StatEpsilonTransitions => "{0} transitions",
+        Key::StatDeadStates => "Dead states",
+        Key::StatUnreachableStates => "Unreachable states",
+        Key::MinimizationReduction => "Minimization removed {0} of {1} DFA states ({2}).",
+        Key::Complexity => "Complexity",
+        Key::StarHeight => "Star height of the expression",
+        Key::MinimalDfaStates => "States of the minimal DFA",
+        Key::FoolingSetCaption => {
+            "No two of these {0} words can share a state, since some suffix is \
+             accepted after one but not the other, so every DFA for this language \
+             needs at least {0} states:"
+        }
+        Key::TransitionMonoid => "Transition monoid",
+        Key::MonoidSize => "{0} elements, {1} of them idempotent.",
+        Key::MonoidSizeAtLeast => "At least {0} elements; only the first are enumerated.",
+        Key::MonoidCaption => {
+            "Each row is the function a word induces on the states: the column of a \
+             state shows where the word leads from it. Idempotents satisfy f∘f = f. \
+             For the minimal DFA this is the syntactic monoid of the language."
+        }
+        Key::MonoidWord => "Word",
+        Key::MonoidIdempotent => "Idempotent",
+        Key::MonoidMoreElements => "… and {0} more elements",
+        Key::WordsPerLength => "Accepted words per length",
+        Key::WordsPerLengthCaption => {
+            "Bars stop growing for finite languages, grow steadily for polynomial \
+             ones and keep multiplying for exponential ones."
+        }
+
+        Key::SimulationHeading => "Simulation",
+        Key::WhatIf => "What if, after \"{0}\", the input continued with:",
+        Key::RemainingInput => "Remaining input",
+        Key::NothingMore => "nothing more",
+        Key::FoundSymbol => "found '{0}'",
+        Key::InputEnded => "the input ended",
+        Key::LongestPrefix => "Longest matched prefix: {0}",
+        Key::ExpectedBut => "Expected {0}, but {1}.",
+        Key::PathChoice => "Path {0} ({1} edges)",
+        Key::AcceptingPaths => "Accepting paths",
+        Key::EdgePosition => "Edge {0} / {1} • {2} —{3}→ {4}",
+        Key::EdgeAtStart => "Edge 0 / {0} • at start state",
+        Key::Back => "Back",
+        Key::TakeEdge => "Take edge",
+        Key::CopyTraceJson => "Copy trace as JSON",
+        Key::CopyTraceCsv => "Copy trace as CSV",
+        Key::TraceCopied => "Trace copied to the clipboard as {0}.",
+        Key::SaveAnimation => "Save animation (GIF)",
+        Key::AnimationSaved => "Animation saved to {0}.",
+        Key::AnimationFailed => "Could not save the animation: {0}.",
+        Key::HideComparison => "Hide engine comparison",
+        Key::CompareEngines => "Compare engines",
+        Key::EnginesDisagree => {
+            "The engines disagree from step {0}. They recognize the same language, so this is a bug in RegViz."
+        }
+        Key::EnginesAgree => "NFA, DFA and minimized DFA agree on every prefix.",
+        Key::StepColumn => "Step",
+        Key::HideSuffixes => "Hide suffix matches",
+        Key::ShowSuffixes => "Show suffix matches",
+        Key::SuffixCaption => "Reading the input backward through the reversed automaton:",
+        Key::SuffixMatches => "matches",
+        Key::SuffixNoMatch => "no match",
+        Key::RegexRequired => "Regex required",
+        Key::InputError => "Input error",
+        Key::Ready => "Ready",
+        Key::TestStringHeading => "Test String",
+        Key::TestStringPlaceholder => "Enter a string to validate",
+        Key::TestStringDisabled => "Provide a regex to enable simulation",
+        Key::SimulationLocked => "Build a valid regular expression to unlock the simulation.",
+        Key::SimulationHelp => {
+            "Simulate against the currently selected automaton. Right-click a state or transition to set a breakpoint."
+        }
+        Key::Previous => "Previous",
+        Key::Reset => "Reset",
+        Key::Next => "Next",
+        Key::Pause => "Pause",
+        Key::Play => "Play",
+        Key::Consumed => "Consumed: '{0}'",
+        Key::ConsumedNothing => "Consumed: –",
+        Key::AcceptingYes => "Accepting: Yes",
+        Key::AcceptingNo => "Accepting: No",
+        Key::StepSummary => "Step {0} / {1} • {2} • {3}",
+        Key::ActiveStates => "Active {0} states: {1}",
+        Key::PausedAtBreakpoint => "Paused at breakpoint: {0}",
+        Key::Breakpoints => "Breakpoints: {0} (right-click a state or transition to toggle)",
+        Key::InputRejected => "Input string is not accepted.",
+        Key::InputAccepted => "Input string is accepted.",
+        Key::SimulationUnknownSymbol => {
+            "Cannot simulate: symbol '{0}' at position {1} is not in the alphabet"
+        }
+        Key::SimulationEmptyAutomaton => "Cannot simulate: the automaton has no states",
+        Key::ReplayConstruction => "Replay construction",
+        Key::ShowFullNfa => "Show full NFA",
+        Key::ConstructionStep => "Fragment {0} / {1} • {2} • from {3}",
+        Key::ReplayParse => "Replay parse",
+        Key::ShowFullTree => "Show full tree",
+        Key::ParseTraceStep => "Step {0} / {1} • {2}",
+        Key::ParseConsumed => "Read {0} at column {1}",
+        Key::ParseBound => "{0} binds with power {1} ≥ {2}, taking {3} as its left operand",
+        Key::ParseYielded => "{0} binds with power {1} < {2}, so {3} is complete at this level",
+        Key::ParseBuilt => "Built {0}",
+        Key::ImplicitConcat => "Implicit concatenation",
+
+        Key::LeftPattern => "Left pattern",
+        Key::RightPattern => "Right pattern",
+        Key::PatternPlaceholder => "e.g., (a+b)*c",
+        Key::OpUnion => "Union",
+        Key::OpIntersection => "Intersection",
+        Key::OpDifference => "Difference",
+        Key::OpSymmetricDifference => "Symmetric difference",
+        Key::CombinationEmpty => "L(left) {0} L(right) is empty.",
+        Key::CombinationNonEmpty => "L(left) {0} L(right) is non-empty, e.g. \"{1}\".",
+        Key::EnterBothPatterns => "Enter both patterns to combine their languages",
+        Key::PatternsEquivalent => "L(left) = L(right): the patterns are equivalent",
+        Key::PatternsDistinguished => "L(left) ≠ L(right): only one pattern matches \"{0}\"",
+        Key::LeftInRight => "L(left) ⊆ L(right)",
+        Key::RightInLeft => "L(right) ⊆ L(left)",
+        Key::ContainmentHolds => "{0}: holds",
+        Key::ContainmentFails => "{0}: fails, e.g. \"{1}\"",
+        Key::VerdictBothEmpty => "Both languages are empty.",
+        Key::VerdictSomeNonEmpty => "At least one language is non-empty.",
+        Key::VerdictDisjoint => "The languages are disjoint.",
+        Key::VerdictOverlap => "The languages overlap.",
+        Key::VerdictContained => "L(left) ⊆ L(right).",
+        Key::VerdictNotContained => "L(left) ⊄ L(right).",
+        Key::VerdictEquivalent => "The patterns are equivalent.",
+        Key::VerdictNotEquivalent => "The patterns are not equivalent.",
+
+        Key::ExerciseProgress => "Exercise {0} of {1} · Σ = {a, b}",
+        Key::ExercisesSolved => "{0} solved",
+        Key::AnswerPlaceholder => "Your regular expression",
+        Key::RevealSolution => "Reveal solution",
+        Key::Solution => "Solution: {0}",
+        Key::AnswerDfaHint => "Write a pattern to see its minimized DFA",
+        Key::AnswerEmpty => "Describe the language above with a regular expression",
+        Key::AnswerOverAccepts => {
+            "Not yet: your pattern matches \"{0}\" but the target language does not"
+        }
+        Key::AnswerUnderAccepts => {
+            "Not yet: the target language contains \"{0}\" but your pattern rejects it"
+        }
+        Key::AnswerCorrect => "Correct: your pattern describes exactly the target language",
+        Key::AnswerDiffSummary => {
+            "Against the target's minimal DFA: {0} extra states, {1} missing states, {2} differing transitions"
+        }
+        Key::ExerciseEndsInAb => "Strings that end in ab.",
+        Key::ExerciseContainsAba => "Strings that contain aba as a substring.",
+        Key::ExerciseAFollowedByB => "Strings in which every a is immediately followed by a b.",
+        Key::ExerciseLengthMultipleOfThree => "Strings whose length is a multiple of 3.",
+        Key::ExerciseEvenAs => "Strings with an even number of a's.",
+        Key::ExerciseNoDoubleA => "Strings with no two consecutive a's.",
+        Key::ExerciseSameEnds => "Non-empty strings that start and end with the same symbol.",
+
+        Key::TutorialProgress => "Step {0} of {1} · {2}",
+        Key::Finish => "Finish",
+        Key::SkipTutorial => "Skip tutorial",
+        Key::TutorialTypeRegexTitle => "Type a regular expression",
+        Key::TutorialTypeRegexCaption => {
+            "Start by typing a pattern such as (a+b)*c. The parse tree and automata are rebuilt as you type."
+        }
+        Key::TutorialStepSimulationTitle => "Step through a simulation",
+        Key::TutorialStepSimulationCaption => {
+            "Enter a test string, then press Next to follow the automaton one symbol at a time."
+        }
+        Key::TutorialToggleBoxesTitle => "Toggle bounding boxes",
+        Key::TutorialToggleBoxesCaption => {
+            "In the NFA view, bounding boxes show which part of the pattern built each group of states. Toggle one on or off."
+        }
+        Key::TutorialSwitchToDfaTitle => "Switch to the DFA",
+        Key::TutorialSwitchToDfaCaption => {
+            "Finally, pick DFA below the canvas to see the deterministic automaton built by subset construction."
+        }
+
+        Key::SyntaxReferenceShown => "▾ Syntax reference",
+        Key::SyntaxReferenceHidden => "▸ Syntax reference",
+        Key::SyntaxSearchPlaceholder => "Search syntax, e.g. repeat",
+        Key::SyntaxNoMatch => "Nothing matches \"{0}\"",
+        Key::SyntaxPrecedenceNote => {
+            "Precedence 1 binds tightest. Binary operators group left to right."
+        }
+        Key::SyntaxPrecedenceLevel => "{0} (precedence {1})",
+        Key::SyntaxLiterals => "Literals",
+        Key::SyntaxEpsilon => "Epsilon",
+        Key::SyntaxEscapes => "Escapes",
+        Key::SyntaxGrouping => "Grouping",
+        Key::SyntaxOperators => "Operators",
+        Key::SyntaxLiteralMeaning => "Matches that character; whitespace is ignored",
+        Key::SyntaxEpsilonMeaning => "Matches the empty string",
+        Key::SyntaxEscapeMeaning => "Matches the character c literally",
+        Key::SyntaxGroupingMeaning => "Groups a sub-expression",
+        Key::SyntaxStarMeaning => "Zero or more repetitions of the operand",
+        Key::SyntaxOptMeaning => "Zero or one occurrence of the operand",
+        Key::SyntaxConcatMeaning => "Concatenation; juxtaposition (ab) means the same",
+        Key::SyntaxAltMeaning => "Alternation: either operand",
+
+        Key::Theme => "Theme",
+        Key::ThemeSystem => "System",
+        Key::ThemeLight => "Light",
+        Key::ThemeDark => "Dark",
+        Key::HighContrast => "High contrast",
+        Key::ZoomStep => "Zoom step: {0}%",
+        Key::ResetSettings => "Reset settings",
+        Key::TextView => "Text view",
+        Key::TextViewHeading => "States and transitions",
+        Key::TextViewHint => {
+            "↑/↓ move between states, Home jumps to the start, typing a symbol follows its transition and Esc clears the selection."
+        }
+        Key::StateEntry => "State {0}",
+        Key::StateStart => "start",
+        Key::StateAccepting => "accepting",
+        Key::StateNoTransitions => "no transitions",
+        Key::StateSelected => "Selected: {0}",
+    }
+}
@@ -0,0 +1,435 @@
+use super::Key;
+
+/// Spanish texts.
+pub(super) fn text(key: Key) -> &'static str {
+    match key {
+        Key::AppTitle => "Visualizador de expresiones regulares",
+        Key::AppSubtitle => {
+            "Construye y visualiza autómatas finitos a partir de expresiones regulares."
+        }
+        Key::TutorialButton => "Tutorial",
+        Key::Language => "Idioma",
+        Key::ShowControls => "☰ Controles",
+        Key::HideControls => "✕ Cerrar controles",
+
+        Key::RegexHeading => "Expresión regular",
+        Key::RegexPlaceholder => "p. ej., (a+b)c",
+        Key::ParsedSummary => "Analizada correctamente | {0} estados | Alfabeto: {1}",
+        Key::EnterRegexHint => "Escribe una expresión regular para visualizarla",
+        Key::ErrorMessage => "Error: {0}",
+        Key::HintDanglingEscape => "Falta el carácter a escapar después de '\\' aquí.",
+        Key::HintInvalidCharacter => {
+            "'{0}' no se puede usar aquí; escápalo como literal o quítalo."
+        }
+        Key::HintUnexpectedEof => "Se esperaba una expresión aquí.",
+        Key::HintMissingOperand => "Se esperaba una expresión antes de '{0}' aquí.",
+        Key::HintExpectedRightParen => "Se esperaba ')' aquí.",
+        Key::HintUnmatchedRightParen => "Este ')' no tiene un '(' correspondiente.",
+        Key::HintEmptyGroup => "Se esperaba una expresión dentro de los paréntesis aquí.",
+        Key::DidYouMean => "¿Quisiste decir {0}?",
+        Key::ApplySuggestion => "Aplicar",
+        Key::FixUseAlternation => "La alternancia se escribe '+'.",
+        Key::FixEscapeCharacter => "Escapa '{0}' para reconocerlo como literal.",
+        Key::FixRemoveCharacter => "Quita '{0}'.",
+        Key::FixRemoveEscape => "Quita la '\\' final.",
+        Key::FixRemoveOperator => "Quita el '{0}' sobrante.",
+        Key::FixCloseGroup => "Cierra el grupo con ')'.",
+        Key::FixRemoveLeftParen => "Quita el '(' sin cerrar.",
+        Key::FixRemoveRightParen => "Quita el ')' sin pareja.",
+        Key::FixOpenGroup => "Abre un grupo con '(' al principio.",
+        Key::FixEmptyGroupAsEpsilon => "Escribe el grupo vacío como ε.",
+        Key::WarningRedundantRepetition => {
+            "Aviso: {0} repite una expresión que ya reconoce la cadena vacía."
+        }
+        Key::WarningUnreachableAlternative => {
+            "Aviso: la alternativa {0} solo reconoce cadenas que {1} ya reconoce."
+        }
+        Key::WarningEpsilonLoop => "Aviso: {0} es un ciclo que no lee ningún símbolo.",
+        Key::WarningLargeDfa => {
+            "Aviso: el AFD tiene {0} estados; el grafo puede ser difícil de leer."
+        }
+        Key::AlphabetHeading => "Alfabeto",
+        Key::AlphabetPlaceholder => "Se deduce de la expresión, p. ej. abc",
+        Key::AlphabetRejected => "Error: {0}. Se usa el alfabeto deducido.",
+        Key::AlphabetHint => {
+            "Declara símbolos adicionales para que el AFD tenga filas completas para ellos."
+        }
+        Key::AlphabetUnused => "{0} ({1} sin usar en la expresión)",
+        Key::TokensShown => "▾ Tokens ({0})",
+        Key::TokensHidden => "▸ Tokens ({0})",
+        Key::TokenColumn => "col. {0}",
+        Key::TokenColumns => "cols. {0}–{1}",
+        Key::TokenLiteral => "literal",
+        Key::TokenEpsilon => "cadena vacía",
+        Key::TokenOperator => "operador",
+        Key::TokenGroup => "grupo",
+        Key::TokenEnd => "fin de la entrada",
+
+        Key::NewTab => "+ Nueva pestaña",
+        Key::UntitledTab => "Sin título",
+        Key::PaneLayout => "Disposición",
+        Key::LayoutControlsLeft => "Controles a la izquierda",
+        Key::LayoutControlsRight => "Controles a la derecha",
+        Key::LayoutControlsBottom => "Controles abajo",
+        Key::LayoutVisualizationOnly => "Maximizada",
+        Key::BoundingBoxes => "Cajas delimitadoras",
+        Key::BoxLiteral => "Literal",
+        Key::BoxConcat => "Concat.",
+        Key::BoxAlternation => "Alt.",
+        Key::BoxStar => "Estrella",
+        Key::BoxPlus => "Más",
+        Key::BoxOptional => "Opcional",
+        Key::DimNestedBoxes => "Atenuar cajas anidadas",
+        Key::NestingLevels => "Niveles: {0} de {1}",
+        Key::ExpandedLevels => "Expandidos: {0} de {1}",
+        Key::BoxStyle => "Estilo de las cajas",
+        Key::GraphSearchPlaceholder => "Buscar un estado o símbolo",
+        Key::OpenInWindow => "Abrir en otra ventana",
+        Key::BoxStyleLabel => "Etiqueta",
+        Key::BoxStyleDashed => "Discontinuo",
+        Key::BoxStyleFill => "Relleno",
+        Key::BoxStyleCorners => "Radio de las esquinas: {0} px",
+        Key::LayoutTuningShown => "▾ Ajuste de la disposición",
+        Key::LayoutTuningHidden => "▸ Ajuste de la disposición",
+        Key::NodeSpacing => "Separación entre estados: {0}",
+        Key::LevelSpacing => "Separación entre niveles: {0}",
+        Key::BranchGap => "Separación entre ramas: {0}",
+        Key::BoxPadding => "Margen de las cajas: {0}",
+        Key::ResetLayoutTuning => "Restablecer separaciones",
+        Key::NfaOrientation => "Dirección del AFN",
+        Key::OrientationLeftToRight => "De izquierda a derecha",
+        Key::OrientationRightToLeft => "De derecha a izquierda",
+        Key::OrientationTopToBottom => "De arriba abajo",
+        Key::Overlays => "Capas",
+        Key::SccColors => "Colores por CFC",
+        Key::ShowInfiniteWitness => "Mostrar por qué el lenguaje es infinito",
+        Key::SccCaption => "Los estados del mismo color están en un ciclo común.",
+        Key::Heatmap => "Mapa de calor",
+        Key::HeatmapCaption => {
+            "Los estados más rojos tienen más transiciones; las transiciones hacia estados concurridos son ámbar o rojas."
+        }
+        Key::SymbolCounts => "Transiciones por símbolo: {0}",
+        Key::EmptyString => "Cadena vacía",
+        Key::StateNumbers => "Numeración de estados del AFD",
+        Key::NumberingOriginal => "Original",
+        Key::NumberingBfs => "BFS",
+        Key::NumberingNerodeClass => "Clase de Nerode",
+        Key::TransitionGroupingLabel => "Transiciones del AFD",
+        Key::GroupingMerged => "Unidas",
+        Key::GroupingPartitioned => "Partición de Σ",
+        Key::GroupingSeparate => "Separadas",
+        Key::ImageExport => "Exportar imagen",
+        Key::ExportNodeRadius => "Radio de estado: {0}px",
+        Key::ExportStrokeWidth => "Grosor de línea: {0}",
+        Key::ExportTextSize => "Tamaño de etiqueta: {0}",
+        Key::ExportSupersampling => "Densidad de píxeles: {0}x",
+        Key::CopySvg => "Copiar vista como SVG",
+        Key::SvgCopied => "Vista copiada al portapapeles como SVG.",
+        Key::DeltaTable => "Tabla",
+        Key::DeltaListing => "Lista de la función",
+        Key::TransitionFunction => "Función de transición δ",
+        Key::CopyLatex => "Copiar como LaTeX",
+        Key::LatexCopied => "Definición copiada al portapapeles como LaTeX.",
+        Key::DfaUnavailable => "El AFD no está disponible",
+        Key::LanguageFinite => {
+            "El lenguaje es finito: ningún ciclo alcanza un estado de aceptación."
+        }
+        Key::LassoCaption => {
+            "Se acepta todo u·vⁿ·w, con u = \"{0}\", v = \"{1}\", w = \"{2}\" (p. ej. \"{3}\")."
+        }
+        Key::Zoom => "Zoom: {0}",
+        Key::ResetView => "Restablecer vista",
+
+        Key::OperationsHeading => "Operaciones",
+        Key::OpComplement => "Complemento",
+        Key::OpReverse => "Reverso",
+        Key::OpStar => "Estrella",
+        Key::OpTrim => "Recortar",
+        Key::OperationsHint => {
+            "Se aplica al AFD en pantalla (o al AFD determinizado) y después a cada resultado."
+        }
+        Key::Undo => "Deshacer",
+        Key::Clear => "Limpiar",
+
+        Key::InspectorHeading => "Inspector",
+        Key::Accepting => "de aceptación",
+        Key::NotAccepting => "de no aceptación",
+        Key::StateSummary => "Estado {0} • {1}",
+        Key::ShowReachable => "Mostrar alcanzables",
+        Key::ShowAcceptingPaths => "Mostrar estados que llegan a aceptación pasando por aquí",
+        Key::UnreachableState => "Este estado no es alcanzable desde el estado inicial.",
+        Key::Name => "Nombre",
+        Key::RenamePlaceholder => "Renombrar este estado",
+        Key::ResidualLanguage => "Lenguaje desde este estado: u⁻¹L con u = \"{0}\"",
+        Key::EmptyResidual => "∅ (no se acepta ninguna cadena desde aquí)",
+        Key::LeftLanguage => "Cadenas que llegan a este estado: {0}",
+        Key::RightLanguage => "Cadenas aceptadas desde este estado: {0}",
+
+        Key::PinState => "Fijar aquí",
+        Key::UnpinState => "Soltar",
+        Key::RenameState => "Renombrar…",
+        Key::SetBreakpoint => "Poner punto de interrupción",
+        Key::RemoveBreakpoint => "Quitar punto de interrupción",
+        Key::CopyRightLanguage => "Copiar regex residual",
+        Key::SelectTransition => "Seleccionar transición",
+        Key::CollapseBox => "Contraer caja",
+        Key::ExpandBox => "Expandir caja",
+
+        Key::CursorPosition => "x {0}, y {1}",
+        Key::StateCount => "{0} estados, {1} aristas",
+        Key::TreeNodeCount => "{0} nodos, {1} aristas",
+        Key::SelectedElement => "Seleccionado: {0}",
+        Key::NothingSelected => "Nada seleccionado",
+        Key::EditSummary => "Última edición: {0} añadidos, {1} eliminados, {2} cambiados",
+
+        Key::TitleAst => "Árbol sintáctico",
+        Key::TitleNfa => "Simulación del AFN",
+        Key::TitleDfa => "Simulación del AFD",
+        Key::TitleMinDfa => "Simulación del AFD mínimo",
+        Key::TitleAnalysis => "Análisis del lenguaje",
+        Key::TitleOperations => "Resultado de la operación",
+        Key::TitleWorkbench => "Banco booleano",
+        Key::TitleExercises => "Ejercicios",
+        Key::TitleDefinition => "Definición formal",
+        Key::NoParseTree => {
+            "Este autómata no se construyó a partir de una expresión regular, así que no tiene árbol sintáctico"
+        }
+        Key::TabNfa => "AFN",
+        Key::TabDfa => "AFD",
+        Key::TabMinDfa => "AFD mín.",
+        Key::TabAst => "AST",
+        Key::TabAnalysis => "Análisis",
+        Key::TabOperations => "Operaciones",
+        Key::TabWorkbench => "Banco",
+        Key::TabExercises => "Ejercicios",
+        Key::TabDefinition => "Definición",
+        Key::DeterminizedUnavailable => "El AFD determinizado no está disponible",
+        Key::MinimizedUnavailable => "El AFD mínimo no está disponible",
+        Key::OperationsEmpty => {
+            "Aplica una operación desde el panel Operaciones para ver aquí su resultado"
+        }
+        Key::Statistics => "Tamaños de los autómatas",
+        Key::StatStates => "Estados",
+        Key::StatTransitions => "Transiciones",
+        Key::StatEpsilonTransitions => "Transiciones {0}",
+        Key::StatDeadStates => "Estados muertos",
+        Key::StatUnreachableStates => "Estados inalcanzables",
+        Key::MinimizationReduction => "La minimización eliminó {0} de {1} estados del AFD ({2}).",
+        Key::Complexity => "Complejidad",
+        Key::StarHeight => "Altura de estrella de la expresión",
+        Key::MinimalDfaStates => "Estados del AFD mínimo",
+        Key::FoolingSetCaption => {
+            "Ningún par de estas {0} palabras puede compartir estado, pues algún \
+             sufijo se acepta tras una pero no tras la otra, así que todo AFD para \
+             este lenguaje necesita al menos {0} estados:"
+        }
+        Key::TransitionMonoid => "Monoide de transiciones",
+        Key::MonoidSize => "{0} elementos, {1} de ellos idempotentes.",
+        Key::MonoidSizeAtLeast => "Al menos {0} elementos; solo se enumeran los primeros.",
+        Key::MonoidCaption => {
+            "Cada fila es la función que una palabra induce sobre los estados: la \
+             columna de un estado indica adónde lleva la palabra desde él. Los \
+             idempotentes cumplen f∘f = f. Para el AFD mínimo es el monoide \
+             sintáctico del lenguaje."
+        }
+        Key::MonoidWord => "Palabra",
+        Key::MonoidIdempotent => "Idempotente",
+        Key::MonoidMoreElements => "… y {0} elementos más",
+        Key::WordsPerLength => "Palabras aceptadas por longitud",
+        Key::WordsPerLengthCaption => {
+            "Las barras dejan de crecer en los lenguajes finitos, crecen de forma \
+             constante en los polinómicos y se multiplican en los exponenciales."
+        }
+
+        Key::SimulationHeading => "Simulación",
+        Key::WhatIf => "¿Y si, tras \"{0}\", la entrada continuara con:",
+        Key::RemainingInput => "Entrada restante",
+        Key::NothingMore => "nada más",
+        Key::FoundSymbol => "se encontró '{0}'",
+        Key::InputEnded => "la entrada terminó",
+        Key::LongestPrefix => "Prefijo reconocido más largo: {0}",
+        Key::ExpectedBut => "Se esperaba {0}, pero {1}.",
+        Key::PathChoice => "Camino {0} ({1} aristas)",
+        Key::AcceptingPaths => "Caminos de aceptación",
+        Key::EdgePosition => "Arista {0} / {1} • {2} —{3}→ {4}",
+        Key::EdgeAtStart => "Arista 0 / {0} • en el estado inicial",
+        Key::Back => "Atrás",
+        Key::TakeEdge => "Tomar arista",
+        Key::CopyTraceJson => "Copiar traza como JSON",
+        Key::CopyTraceCsv => "Copiar traza como CSV",
+        Key::TraceCopied => "Traza copiada al portapapeles como {0}.",
+        Key::SaveAnimation => "Guardar animación (GIF)",
+        Key::AnimationSaved => "Animación guardada en {0}.",
+        Key::AnimationFailed => "No se pudo guardar la animación: {0}.",
+        Key::HideComparison => "Ocultar comparación de motores",
+        Key::CompareEngines => "Comparar motores",
+        Key::EnginesDisagree => {
+            "Los motores discrepan desde el paso {0}. Reconocen el mismo lenguaje, así que es un error de RegViz."
+        }
+        Key::EnginesAgree => "El AFN, el AFD y el AFD mínimo coinciden en cada prefijo.",
+        Key::StepColumn => "Paso",
+        Key::HideSuffixes => "Ocultar sufijos reconocidos",
+        Key::ShowSuffixes => "Mostrar sufijos reconocidos",
+        Key::SuffixCaption => "Leyendo la entrada hacia atrás con el autómata invertido:",
+        Key::SuffixMatches => "reconocido",
+        Key::SuffixNoMatch => "no reconocido",
+        Key::RegexRequired => "Falta la expresión",
+        Key::InputError => "Error en la entrada",
+        Key::Ready => "Listo",
+        Key::TestStringHeading => "Cadena de prueba",
+        Key::TestStringPlaceholder => "Escribe una cadena para validarla",
+        Key::TestStringDisabled => "Escribe una expresión para habilitar la simulación",
+        Key::SimulationLocked => {
+            "Construye una expresión regular válida para habilitar la simulación."
+        }
+        Key::SimulationHelp => {
+            "Simula con el autómata seleccionado. Haz clic derecho en un estado o una transición para poner un punto de interrupción."
+        }
+        Key::Previous => "Anterior",
+        Key::Reset => "Reiniciar",
+        Key::Next => "Siguiente",
+        Key::Pause => "Pausa",
+        Key::Play => "Reproducir",
+        Key::Consumed => "Consumido: '{0}'",
+        Key::ConsumedNothing => "Consumido: –",
+        Key::AcceptingYes => "Aceptación: sí",
+        Key::AcceptingNo => "Aceptación: no",
+        Key::StepSummary => "Paso {0} / {1} • {2} • {3}",
+        Key::ActiveStates => "Estados activos del {0}: {1}",
+        Key::PausedAtBreakpoint => "En pausa en un punto de interrupción: {0}",
+        Key::Breakpoints => {
+            "Puntos de interrupción: {0} (clic derecho en un estado o una transición para alternar)"
+        }
+        Key::InputRejected => "La cadena no es aceptada.",
+        Key::InputAccepted => "La cadena es aceptada.",
+        Key::SimulationUnknownSymbol => {
+            "No se puede simular: el símbolo '{0}' en la posición {1} no está en el alfabeto"
+        }
+        Key::SimulationEmptyAutomaton => "No se puede simular: el autómata no tiene estados",
+        Key::ReplayConstruction => "Repetir la construcción",
+        Key::ShowFullNfa => "Ver el AFN completo",
+        Key::ConstructionStep => "Fragmento {0} / {1} • {2} • de {3}",
+        Key::ReplayParse => "Repetir el análisis",
+        Key::ShowFullTree => "Ver el árbol completo",
+        Key::ParseTraceStep => "Paso {0} / {1} • {2}",
+        Key::ParseConsumed => "Se lee {0} en la columna {1}",
+        Key::ParseBound => "{0} enlaza con fuerza {1} ≥ {2} y toma {3} como operando izquierdo",
+        Key::ParseYielded => {
+            "{0} enlaza con fuerza {1} < {2}, así que {3} queda completo en este nivel"
+        }
+        Key::ParseBuilt => "Se construye {0}",
+        Key::ImplicitConcat => "La concatenación implícita",
+
+        Key::LeftPattern => "Patrón izquierdo",
+        Key::RightPattern => "Patrón derecho",
+        Key::PatternPlaceholder => "p. ej., (a+b)*c",
+        Key::OpUnion => "Unión",
+        Key::OpIntersection => "Intersección",
+        Key::OpDifference => "Diferencia",
+        Key::OpSymmetricDifference => "Diferencia simétrica",
+        Key::CombinationEmpty => "L(izq.) {0} L(der.) es vacío.",
+        Key::CombinationNonEmpty => "L(izq.) {0} L(der.) no es vacío, p. ej. \"{1}\".",
+        Key::EnterBothPatterns => "Escribe ambos patrones para combinar sus lenguajes",
+        Key::PatternsEquivalent => "L(izq.) = L(der.): los patrones son equivalentes",
+        Key::PatternsDistinguished => {
+            "L(izq.) ≠ L(der.): solo uno de los patrones reconoce \"{0}\""
+        }
+        Key::LeftInRight => "L(izq.) ⊆ L(der.)",
+        Key::RightInLeft => "L(der.) ⊆ L(izq.)",
+        Key::ContainmentHolds => "{0}: se cumple",
+        Key::ContainmentFails => "{0}: no se cumple, p. ej. \"{1}\"",
+        Key::VerdictBothEmpty => "Ambos lenguajes son vacíos.",
+        Key::VerdictSomeNonEmpty => "Al menos un lenguaje no es vacío.",
+        Key::VerdictDisjoint => "Los lenguajes son disjuntos.",
+        Key::VerdictOverlap => "Los lenguajes se solapan.",
+        Key::VerdictContained => "L(izq.) ⊆ L(der.).",
+        Key::VerdictNotContained => "L(izq.) ⊄ L(der.).",
+        Key::VerdictEquivalent => "Los patrones son equivalentes.",
+        Key::VerdictNotEquivalent => "Los patrones no son equivalentes.",
+
+        Key::ExerciseProgress => "Ejercicio {0} de {1} · Σ = {a, b}",
+        Key::ExercisesSolved => "{0} resueltos",
+        Key::AnswerPlaceholder => "Tu expresión regular",
+        Key::RevealSolution => "Mostrar solución",
+        Key::Solution => "Solución: {0}",
+        Key::AnswerDfaHint => "Escribe un patrón para ver su AFD mínimo",
+        Key::AnswerEmpty => "Describe el lenguaje anterior con una expresión regular",
+        Key::AnswerOverAccepts => "Aún no: tu patrón reconoce \"{0}\" pero el lenguaje objetivo no",
+        Key::AnswerUnderAccepts => {
+            "Aún no: el lenguaje objetivo contiene \"{0}\" pero tu patrón la rechaza"
+        }
+        Key::AnswerCorrect => "Correcto: tu patrón describe exactamente el lenguaje objetivo",
+        Key::AnswerDiffSummary => {
+            "Frente al AFD mínimo del objetivo: {0} estados de más, {1} estados que faltan, {2} transiciones distintas"
+        }
+        Key::ExerciseEndsInAb => "Cadenas que terminan en ab.",
+        Key::ExerciseContainsAba => "Cadenas que contienen aba como subcadena.",
+        Key::ExerciseAFollowedByB => {
+            "Cadenas en las que cada a va seguida inmediatamente de una b."
+        }
+        Key::ExerciseLengthMultipleOfThree => "Cadenas cuya longitud es múltiplo de 3.",
+        Key::ExerciseEvenAs => "Cadenas con un número par de aes.",
+        Key::ExerciseNoDoubleA => "Cadenas sin dos aes consecutivas.",
+        Key::ExerciseSameEnds => "Cadenas no vacías que empiezan y terminan con el mismo símbolo.",
+
+        Key::TutorialProgress => "Paso {0} de {1} · {2}",
+        Key::Finish => "Terminar",
+        Key::SkipTutorial => "Saltar tutorial",
+        Key::TutorialTypeRegexTitle => "Escribe una expresión regular",
+        Key::TutorialTypeRegexCaption => {
+            "Empieza escribiendo un patrón como (a+b)*c. El árbol sintáctico y los autómatas se reconstruyen mientras escribes."
+        }
+        Key::TutorialStepSimulationTitle => "Avanza por una simulación",
+        Key::TutorialStepSimulationCaption => {
+            "Escribe una cadena de prueba y pulsa Siguiente para seguir al autómata símbolo a símbolo."
+        }
+        Key::TutorialToggleBoxesTitle => "Activa las cajas delimitadoras",
+        Key::TutorialToggleBoxesCaption => {
+            "En la vista del AFN, las cajas delimitadoras muestran qué parte del patrón construyó cada grupo de estados. Activa o desactiva una."
+        }
+        Key::TutorialSwitchToDfaTitle => "Cambia al AFD",
+        Key::TutorialSwitchToDfaCaption => {
+            "Por último, elige AFD bajo el lienzo para ver el autómata determinista obtenido por construcción de subconjuntos."
+        }
+
+        Key::SyntaxReferenceShown => "▾ Referencia de sintaxis",
+        Key::SyntaxReferenceHidden => "▸ Referencia de sintaxis",
+        Key::SyntaxSearchPlaceholder => "Buscar en la sintaxis, p. ej. vacía",
+        Key::SyntaxNoMatch => "Nada coincide con \"{0}\"",
+        Key::SyntaxPrecedenceNote => {
+            "La precedencia 1 es la más fuerte. Los operadores binarios agrupan de izquierda a derecha."
+        }
+        Key::SyntaxPrecedenceLevel => "{0} (precedencia {1})",
+        Key::SyntaxLiterals => "Literales",
+        Key::SyntaxEpsilon => "Épsilon",
+        Key::SyntaxEscapes => "Escapes",
+        Key::SyntaxGrouping => "Agrupación",
+        Key::SyntaxOperators => "Operadores",
+        Key::SyntaxLiteralMeaning => "Reconoce ese carácter; los espacios se ignoran",
+        Key::SyntaxEpsilonMeaning => "Reconoce la cadena vacía",
+        Key::SyntaxEscapeMeaning => "Reconoce el carácter c literalmente",
+        Key::SyntaxGroupingMeaning => "Agrupa una subexpresión",
+        Key::SyntaxStarMeaning => "Cero o más repeticiones del operando",
+        Key::SyntaxOptMeaning => "Cero o una aparición del operando",
+        Key::SyntaxConcatMeaning => "Concatenación; yuxtaponer (ab) significa lo mismo",
+        Key::SyntaxAltMeaning => "Alternancia: cualquiera de los operandos",
+
+        Key::Theme => "Tema",
+        Key::ThemeSystem => "Sistema",
+        Key::ThemeLight => "Claro",
+        Key::ThemeDark => "Oscuro",
+        Key::HighContrast => "Alto contraste",
+        Key::ZoomStep => "Paso de zoom: {0} %",
+        Key::ResetSettings => "Restablecer ajustes",
+        Key::TextView => "Vista de texto",
+        Key::TextViewHeading => "Estados y transiciones",
+        Key::TextViewHint => {
+            "↑/↓ recorren los estados, Inicio salta al estado inicial, escribir un símbolo sigue su transición y Esc borra la selección."
+        }
+        Key::StateEntry => "Estado {0}",
+        Key::StateStart => "inicial",
+        Key::StateAccepting => "de aceptación",
+        Key::StateNoTransitions => "sin transiciones",
+        Key::StateSelected => "Seleccionado: {0}",
+    }
+}
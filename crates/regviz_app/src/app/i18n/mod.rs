@@ -0,0 +1,530 @@
+//! Message catalog for the UI.
+//!
+//! Every user-facing string is a [`Key`]; each [`Locale`] maps every key to its
+//! text in an exhaustive `match`, so a missing translation fails to compile.
+//! Texts with values use `{0}`, `{1}`, ... placeholders filled by
+//! [`Locale::format`].
+
+mod en;
+mod es;
+
+use std::fmt::{self, Display};
+
+use regviz_core::core::lexer::OpToken;
+use regviz_core::core::product::BoolOp;
+use regviz_core::core::syntax::{SyntaxCategory, SyntaxEntry};
+
+/// Languages the UI can be shown in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    /// English.
+    #[default]
+    English,
+    /// Spanish.
+    Spanish,
+}
+
+impl Locale {
+    /// Every locale, in the order offered by the language picker.
+    pub const ALL: [Locale; 2] = [Locale::English, Locale::Spanish];
+
+    /// Returns the text for `key` in this locale.
+    #[must_use]
+    pub fn text(self, key: Key) -> &'static str {
+        match self {
+            Locale::English => en::text(key),
+            Locale::Spanish => es::text(key),
+        }
+    }
+
+    /// Returns the text for `key` with `{0}`, `{1}`, ... replaced by `args`.
+    ///
+    /// # Arguments
+    ///
+    /// - `key` (`Key`) - Catalog entry to look up.
+    /// - `args` (`&[&dyn Display]`) - Values for the placeholders, in order.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The localized text with every placeholder filled.
+    #[must_use]
+    pub fn format(self, key: Key, args: &[&dyn Display]) -> String {
+        args.iter()
+            .enumerate()
+            .fold(self.text(key).to_string(), |text, (idx, arg)| {
+                text.replace(&format!("{{{idx}}}"), &arg.to_string())
+            })
+    }
+
+    /// Formats a count with this locale's digit grouping (`12,345` or `12.345`).
+    #[must_use]
+    pub fn number(self, value: u128) -> String {
+        let digits = value.to_string();
+        let separator = match self {
+            Locale::English => ',',
+            Locale::Spanish => '.',
+        };
+        let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+        for (idx, digit) in digits.chars().enumerate() {
+            if idx > 0 && (digits.len() - idx).is_multiple_of(3) {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+
+    /// Formats a large count in scientific notation with one decimal (`1.2e6` or `1,2e6`).
+    #[must_use]
+    pub fn scientific(self, value: f64) -> String {
+        let formatted = format!("{value:.1e}");
+        match self {
+            Locale::English => formatted,
+            Locale::Spanish => formatted.replace('.', ","),
+        }
+    }
+
+    /// Formats a ratio as a whole percentage (`150%` or `150 %`).
+    #[must_use]
+    pub fn percent(self, ratio: f32) -> String {
+        let value = (ratio * 100.0).round() as i32;
+        match self {
+            Locale::English => format!("{value}%"),
+            Locale::Spanish => format!("{value} %"),
+        }
+    }
+
+    /// Returns the name of a Boolean operation.
+    #[must_use]
+    pub fn bool_op(self, op: BoolOp) -> &'static str {
+        self.text(match op {
+            BoolOp::Union => Key::OpUnion,
+            BoolOp::Intersection => Key::OpIntersection,
+            BoolOp::Difference => Key::OpDifference,
+            BoolOp::SymmetricDifference => Key::OpSymmetricDifference,
+        })
+    }
+
+    /// Returns the heading of a syntax reference section.
+    #[must_use]
+    pub fn syntax_category(self, category: SyntaxCategory) -> &'static str {
+        self.text(match category {
+            SyntaxCategory::Literal => Key::SyntaxLiterals,
+            SyntaxCategory::Epsilon => Key::SyntaxEpsilon,
+            SyntaxCategory::Escape => Key::SyntaxEscapes,
+            SyntaxCategory::Grouping => Key::SyntaxGrouping,
+            SyntaxCategory::Operator => Key::SyntaxOperators,
+        })
+    }
+
+    /// Returns what a syntax reference entry means.
+    #[must_use]
+    pub fn syntax_meaning(self, entry: &SyntaxEntry) -> &'static str {
+        self.text(match (entry.category, entry.operator) {
+            (_, Some(OpToken::Star)) => Key::SyntaxStarMeaning,
+            (_, Some(OpToken::Opt)) => Key::SyntaxOptMeaning,
+            (_, Some(OpToken::Dot)) => Key::SyntaxConcatMeaning,
+            (_, Some(OpToken::Plus)) => Key::SyntaxAltMeaning,
+            (SyntaxCategory::Literal, None) => Key::SyntaxLiteralMeaning,
+            (SyntaxCategory::Epsilon, None) => Key::SyntaxEpsilonMeaning,
+            (SyntaxCategory::Escape, None) => Key::SyntaxEscapeMeaning,
+            (SyntaxCategory::Grouping | SyntaxCategory::Operator, None) => {
+                Key::SyntaxGroupingMeaning
+            }
+        })
+    }
+}
+
+impl Display for Locale {
+    /// Shows the locale's name in its own language, as language pickers do.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Locale::English => "English",
+            Locale::Spanish => "Español",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Catalog entries, grouped by the part of the UI that shows them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    // Header
+    AppTitle,
+    AppSubtitle,
+    TutorialButton,
+    Language,
+    ShowControls,
+    HideControls,
+
+    // Regex and alphabet inputs
+    RegexHeading,
+    RegexPlaceholder,
+    ParsedSummary,
+    EnterRegexHint,
+    ErrorMessage,
+    HintDanglingEscape,
+    HintInvalidCharacter,
+    HintUnexpectedEof,
+    HintMissingOperand,
+    HintExpectedRightParen,
+    HintUnmatchedRightParen,
+    HintEmptyGroup,
+    DidYouMean,
+    ApplySuggestion,
+    FixUseAlternation,
+    FixEscapeCharacter,
+    FixRemoveCharacter,
+    FixRemoveEscape,
+    FixRemoveOperator,
+    FixCloseGroup,
+    FixRemoveLeftParen,
+    FixRemoveRightParen,
+    FixOpenGroup,
+    FixEmptyGroupAsEpsilon,
+    WarningRedundantRepetition,
+    WarningUnreachableAlternative,
+    WarningEpsilonLoop,
+    WarningLargeDfa,
+    AlphabetHeading,
+    AlphabetPlaceholder,
+    AlphabetRejected,
+    AlphabetHint,
+    AlphabetUnused,
+    TokensShown,
+    TokensHidden,
+    TokenColumn,
+    TokenColumns,
+    TokenLiteral,
+    TokenEpsilon,
+    TokenOperator,
+    TokenGroup,
+    TokenEnd,
+
+    // Workspace tabs
+    NewTab,
+    UntitledTab,
+
+    // Pane layout
+    PaneLayout,
+    LayoutControlsLeft,
+    LayoutControlsRight,
+    LayoutControlsBottom,
+    LayoutVisualizationOnly,
+
+    // Canvas controls
+    BoundingBoxes,
+    BoxLiteral,
+    BoxConcat,
+    BoxAlternation,
+    BoxStar,
+    BoxPlus,
+    BoxOptional,
+    DimNestedBoxes,
+    NestingLevels,
+    ExpandedLevels,
+    BoxStyle,
+    GraphSearchPlaceholder,
+    OpenInWindow,
+    BoxStyleLabel,
+    BoxStyleDashed,
+    BoxStyleFill,
+    BoxStyleCorners,
+    LayoutTuningShown,
+    LayoutTuningHidden,
+    NodeSpacing,
+    LevelSpacing,
+    BranchGap,
+    BoxPadding,
+    ResetLayoutTuning,
+    NfaOrientation,
+    OrientationLeftToRight,
+    OrientationRightToLeft,
+    OrientationTopToBottom,
+    Overlays,
+    SccColors,
+    ShowInfiniteWitness,
+    SccCaption,
+    Heatmap,
+    HeatmapCaption,
+    SymbolCounts,
+    EmptyString,
+    StateNumbers,
+    NumberingOriginal,
+    NumberingBfs,
+    NumberingNerodeClass,
+    TransitionGroupingLabel,
+    GroupingMerged,
+    GroupingPartitioned,
+    GroupingSeparate,
+    ImageExport,
+    ExportNodeRadius,
+    ExportStrokeWidth,
+    ExportTextSize,
+    ExportSupersampling,
+    CopySvg,
+    SvgCopied,
+    DeltaTable,
+    DeltaListing,
+    TransitionFunction,
+    CopyLatex,
+    LatexCopied,
+    DfaUnavailable,
+    LanguageFinite,
+    LassoCaption,
+    Zoom,
+    ResetView,
+
+    // Operations panel
+    OperationsHeading,
+    OpComplement,
+    OpReverse,
+    OpStar,
+    OpTrim,
+    OperationsHint,
+    Undo,
+    Clear,
+
+    // Inspector
+    InspectorHeading,
+    Accepting,
+    NotAccepting,
+    StateSummary,
+    ShowReachable,
+    ShowAcceptingPaths,
+    UnreachableState,
+    Name,
+    RenamePlaceholder,
+    ResidualLanguage,
+    EmptyResidual,
+    LeftLanguage,
+    RightLanguage,
+
+    // Canvas context menu
+    PinState,
+    UnpinState,
+    RenameState,
+    SetBreakpoint,
+    RemoveBreakpoint,
+    CopyRightLanguage,
+    SelectTransition,
+    CollapseBox,
+    ExpandBox,
+
+    // Canvas status bar
+    CursorPosition,
+    StateCount,
+    TreeNodeCount,
+    SelectedElement,
+    NothingSelected,
+    EditSummary,
+
+    // Right pane
+    TitleAst,
+    TitleNfa,
+    TitleDfa,
+    TitleMinDfa,
+    TitleAnalysis,
+    TitleOperations,
+    TitleWorkbench,
+    TitleExercises,
+    TitleDefinition,
+    NoParseTree,
+    TabNfa,
+    TabDfa,
+    TabMinDfa,
+    TabAst,
+    TabAnalysis,
+    TabOperations,
+    TabWorkbench,
+    TabExercises,
+    TabDefinition,
+    DeterminizedUnavailable,
+    MinimizedUnavailable,
+    OperationsEmpty,
+    Statistics,
+    StatStates,
+    StatTransitions,
+    StatEpsilonTransitions,
+    StatDeadStates,
+    StatUnreachableStates,
+    MinimizationReduction,
+    Complexity,
+    StarHeight,
+    MinimalDfaStates,
+    FoolingSetCaption,
+    TransitionMonoid,
+    MonoidSize,
+    MonoidSizeAtLeast,
+    MonoidCaption,
+    MonoidWord,
+    MonoidIdempotent,
+    MonoidMoreElements,
+    WordsPerLength,
+    WordsPerLengthCaption,
+
+    // Simulation
+    SimulationHeading,
+    WhatIf,
+    RemainingInput,
+    NothingMore,
+    FoundSymbol,
+    InputEnded,
+    LongestPrefix,
+    ExpectedBut,
+    PathChoice,
+    AcceptingPaths,
+    EdgePosition,
+    EdgeAtStart,
+    Back,
+    TakeEdge,
+    CopyTraceJson,
+    CopyTraceCsv,
+    TraceCopied,
+    SaveAnimation,
+    AnimationSaved,
+    AnimationFailed,
+    HideComparison,
+    CompareEngines,
+    EnginesDisagree,
+    EnginesAgree,
+    StepColumn,
+    HideSuffixes,
+    ShowSuffixes,
+    SuffixCaption,
+    SuffixMatches,
+    SuffixNoMatch,
+    RegexRequired,
+    InputError,
+    Ready,
+    TestStringHeading,
+    TestStringPlaceholder,
+    TestStringDisabled,
+    SimulationLocked,
+    SimulationHelp,
+    Previous,
+    Reset,
+    Next,
+    Pause,
+    Play,
+    Consumed,
+    ConsumedNothing,
+    AcceptingYes,
+    AcceptingNo,
+    StepSummary,
+    ActiveStates,
+    PausedAtBreakpoint,
+    Breakpoints,
+    InputRejected,
+    InputAccepted,
+    SimulationUnknownSymbol,
+    SimulationEmptyAutomaton,
+    ReplayConstruction,
+    ShowFullNfa,
+    ConstructionStep,
+    ReplayParse,
+    ShowFullTree,
+    ParseTraceStep,
+    ParseConsumed,
+    ParseBound,
+    ParseYielded,
+    ParseBuilt,
+    ImplicitConcat,
+
+    // Workbench
+    LeftPattern,
+    RightPattern,
+    PatternPlaceholder,
+    OpUnion,
+    OpIntersection,
+    OpDifference,
+    OpSymmetricDifference,
+    CombinationEmpty,
+    CombinationNonEmpty,
+    EnterBothPatterns,
+    PatternsEquivalent,
+    PatternsDistinguished,
+    LeftInRight,
+    RightInLeft,
+    ContainmentHolds,
+    ContainmentFails,
+    VerdictBothEmpty,
+    VerdictSomeNonEmpty,
+    VerdictDisjoint,
+    VerdictOverlap,
+    VerdictContained,
+    VerdictNotContained,
+    VerdictEquivalent,
+    VerdictNotEquivalent,
+
+    // Exercises
+    ExerciseProgress,
+    ExercisesSolved,
+    AnswerPlaceholder,
+    RevealSolution,
+    Solution,
+    AnswerDfaHint,
+    AnswerEmpty,
+    AnswerOverAccepts,
+    AnswerUnderAccepts,
+    AnswerCorrect,
+    AnswerDiffSummary,
+    ExerciseEndsInAb,
+    ExerciseContainsAba,
+    ExerciseAFollowedByB,
+    ExerciseLengthMultipleOfThree,
+    ExerciseEvenAs,
+    ExerciseNoDoubleA,
+    ExerciseSameEnds,
+
+    // Tutorial
+    TutorialProgress,
+    Finish,
+    SkipTutorial,
+    TutorialTypeRegexTitle,
+    TutorialTypeRegexCaption,
+    TutorialStepSimulationTitle,
+    TutorialStepSimulationCaption,
+    TutorialToggleBoxesTitle,
+    TutorialToggleBoxesCaption,
+    TutorialSwitchToDfaTitle,
+    TutorialSwitchToDfaCaption,
+
+    // Syntax reference
+    SyntaxReferenceShown,
+    SyntaxReferenceHidden,
+    SyntaxSearchPlaceholder,
+    SyntaxNoMatch,
+    SyntaxPrecedenceNote,
+    SyntaxPrecedenceLevel,
+    SyntaxLiterals,
+    SyntaxEpsilon,
+    SyntaxEscapes,
+    SyntaxGrouping,
+    SyntaxOperators,
+    SyntaxLiteralMeaning,
+    SyntaxEpsilonMeaning,
+    SyntaxEscapeMeaning,
+    SyntaxGroupingMeaning,
+    SyntaxStarMeaning,
+    SyntaxOptMeaning,
+    SyntaxConcatMeaning,
+    SyntaxAltMeaning,
+
+    // Accessibility
+    Theme,
+    ThemeSystem,
+    ThemeLight,
+    ThemeDark,
+    HighContrast,
+    ZoomStep,
+    ResetSettings,
+    TextView,
+    TextViewHeading,
+    TextViewHint,
+    StateEntry,
+    StateStart,
+    StateAccepting,
+    StateNoTransitions,
+    StateSelected,
+}
@@ -0,0 +1,174 @@
+use iced::widget::pane_grid::{self, Axis};
+
+use super::i18n::Key;
+use super::state::PaneContent;
+
+/// Share of the window given to the controls when they sit beside the visualization.
+const SIDE_CONTROLS_RATIO: f32 = 0.35;
+
+/// Share of the window given to the visualization when the controls sit below it.
+const BOTTOM_VISUALIZATION_RATIO: f32 = 0.65;
+
+/// Narrowest share either pane can be left with by a remembered ratio.
+const MIN_PANE_RATIO: f32 = 0.1;
+
+/// Arrangements of the controls and visualization panes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayoutPreset {
+    /// Controls on the left, visualization on the right.
+    #[default]
+    ControlsLeft,
+    /// Visualization on the left, controls on the right.
+    ControlsRight,
+    /// Visualization on top, controls below it.
+    ControlsBottom,
+    /// Visualization alone, filling the window.
+    VisualizationOnly,
+}
+
+impl LayoutPreset {
+    /// Every preset, in the order they are offered.
+    pub const ALL: [LayoutPreset; 4] = [
+        LayoutPreset::ControlsLeft,
+        LayoutPreset::ControlsRight,
+        LayoutPreset::ControlsBottom,
+        LayoutPreset::VisualizationOnly,
+    ];
+
+    /// Catalog entry naming the preset.
+    #[must_use]
+    pub fn key(self) -> Key {
+        match self {
+            LayoutPreset::ControlsLeft => Key::LayoutControlsLeft,
+            LayoutPreset::ControlsRight => Key::LayoutControlsRight,
+            LayoutPreset::ControlsBottom => Key::LayoutControlsBottom,
+            LayoutPreset::VisualizationOnly => Key::LayoutVisualizationOnly,
+        }
+    }
+
+    /// Name of the preset in the saved settings.
+    fn name(self) -> &'static str {
+        match self {
+            LayoutPreset::ControlsLeft => "controls-left",
+            LayoutPreset::ControlsRight => "controls-right",
+            LayoutPreset::ControlsBottom => "controls-bottom",
+            LayoutPreset::VisualizationOnly => "visualization-only",
+        }
+    }
+
+    /// Index of the preset's remembered split ratio, if it splits the window.
+    fn ratio_slot(self) -> Option<usize> {
+        match self {
+            LayoutPreset::ControlsLeft => Some(0),
+            LayoutPreset::ControlsRight => Some(1),
+            LayoutPreset::ControlsBottom => Some(2),
+            LayoutPreset::VisualizationOnly => None,
+        }
+    }
+}
+
+/// The chosen preset and the split ratio last dragged to in each preset.
+///
+/// Ratios are the share of the window given to the first pane: the left one
+/// of a side-by-side split, the top one of a stacked split.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaneLayout {
+    /// Active arrangement.
+    pub preset: LayoutPreset,
+    ratios: [f32; 3],
+}
+
+impl Default for PaneLayout {
+    fn default() -> Self {
+        Self {
+            preset: LayoutPreset::default(),
+            ratios: [
+                SIDE_CONTROLS_RATIO,
+                1.0 - SIDE_CONTROLS_RATIO,
+                BOTTOM_VISUALIZATION_RATIO,
+            ],
+        }
+    }
+}
+
+impl PaneLayout {
+    /// Builds the pane grid for the active preset at its remembered ratio.
+    #[must_use]
+    pub fn panes(&self) -> pane_grid::State<PaneContent> {
+        let (first, axis, second) = match self.preset {
+            LayoutPreset::ControlsLeft => (
+                PaneContent::Controls,
+                Axis::Vertical,
+                PaneContent::Visualization,
+            ),
+            LayoutPreset::ControlsRight => (
+                PaneContent::Visualization,
+                Axis::Vertical,
+                PaneContent::Controls,
+            ),
+            LayoutPreset::ControlsBottom => (
+                PaneContent::Visualization,
+                Axis::Horizontal,
+                PaneContent::Controls,
+            ),
+            LayoutPreset::VisualizationOnly => {
+                return pane_grid::State::new(PaneContent::Visualization).0;
+            }
+        };
+
+        let (mut panes, pane) = pane_grid::State::new(first);
+        let (_pane, split) = panes
+            .split(axis, pane, second)
+            .expect("split pane should succeed");
+        if let Some(slot) = self.preset.ratio_slot() {
+            panes.resize(split, self.ratios[slot]);
+        }
+        panes
+    }
+
+    /// Remembers the ratio the active preset's split was dragged to.
+    pub fn set_ratio(&mut self, ratio: f32) {
+        if let Some(slot) = self.preset.ratio_slot() {
+            self.ratios[slot] = ratio.clamp(MIN_PANE_RATIO, 1.0 - MIN_PANE_RATIO);
+        }
+    }
+
+    /// Writes the layout as `key=value` lines.
+    pub(super) fn to_text(&self) -> String {
+        let mut text = format!("preset={}\n", self.preset.name());
+        for preset in LayoutPreset::ALL {
+            if let Some(slot) = preset.ratio_slot() {
+                text.push_str(&format!("ratio.{}={}\n", preset.name(), self.ratios[slot]));
+            }
+        }
+        text
+    }
+
+    /// Reads a layout written by [`PaneLayout::to_text`], keeping defaults for
+    /// anything missing or malformed. Lines with other keys are ignored.
+    pub(super) fn from_text(text: &str) -> Self {
+        let mut layout = Self::default();
+        for (key, value) in text.lines().filter_map(|line| line.split_once('=')) {
+            let (key, value) = (key.trim(), value.trim());
+            if key == "preset" {
+                if let Some(preset) = LayoutPreset::ALL.into_iter().find(|p| p.name() == value) {
+                    layout.preset = preset;
+                }
+                continue;
+            }
+            let Some(name) = key.strip_prefix("ratio.") else {
+                continue;
+            };
+            let slot = LayoutPreset::ALL
+                .into_iter()
+                .find(|preset| preset.name() == name)
+                .and_then(LayoutPreset::ratio_slot);
+            if let (Some(slot), Ok(ratio)) = (slot, value.parse::<f32>())
+                && ratio.is_finite()
+            {
+                layout.ratios[slot] = ratio.clamp(MIN_PANE_RATIO, 1.0 - MIN_PANE_RATIO);
+            }
+        }
+        layout
+    }
+}
@@ -0,0 +1,16 @@
+/// Messages emitted by the controls replaying the NFA's construction.
+#[derive(Debug, Clone)]
+pub enum ConstructionMessage {
+    /// Start replaying the construction from its first fragment.
+    Start,
+    /// Stop the replay and show the whole NFA again.
+    Stop,
+    /// Build the next fragment.
+    StepForward,
+    /// Take back the last fragment built.
+    StepBackward,
+    /// Start or pause automatic playback of the construction.
+    TogglePlay,
+    /// Playback timer fired; build the next fragment.
+    PlayTick,
+}
@@ -0,0 +1,12 @@
+/// Messages emitted by exercise mode.
+#[derive(Debug, Clone)]
+pub enum ExerciseMessage {
+    /// User edited their answer.
+    AnswerChanged(String),
+    /// User moved to the previous exercise.
+    Previous,
+    /// User moved to the next exercise.
+    Next,
+    /// User asked to see the solution.
+    Reveal,
+}
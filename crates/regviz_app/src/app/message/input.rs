@@ -1,6 +1,16 @@
+use iced::time::Instant;
+
 /// Messages originating from the regex input controls.
 #[derive(Debug, Clone)]
 pub enum InputMessage {
     /// User changed the regex input text.
     Changed(String),
+    /// User changed the declared alphabet (empty to infer it from the regex).
+    AlphabetChanged(String),
+    /// User expanded or collapsed the token stream inspector.
+    ToggleTokens,
+    /// User picked a token in the inspector, by its position in the stream.
+    SelectToken(usize),
+    /// The window drew a frame while the changes from the last edit are flashed.
+    EditFlashFrame(Instant),
 }
@@ -1,12 +1,29 @@
+mod construction;
+mod exercise;
 mod input;
 mod pane_grid;
+mod parse_trace;
 mod simulation;
+mod tab;
+mod tutorial;
 mod view_controls;
+mod window;
+mod workbench;
 
+pub use construction::ConstructionMessage;
+pub use exercise::ExerciseMessage;
 pub use input::InputMessage;
 pub use pane_grid::PaneGridMessage;
+pub use parse_trace::ParseTraceMessage;
 pub use simulation::SimulationMessage;
-pub use view_controls::{RightPaneMode, ViewMessage, ViewMode};
+pub use tab::TabMessage;
+pub use tutorial::TutorialMessage;
+pub use view_controls::{
+    CanvasElement, DeltaFormat, RightPaneMode, StateFocus, StateLayer, StateNumbering,
+    ThemePreference, ViewMessage, ViewMode,
+};
+pub use window::WindowMessage;
+pub use workbench::WorkbenchMessage;
 
 /// Aggregated application messages routed through the update loop.
 #[derive(Debug, Clone)]
@@ -15,8 +32,22 @@ pub enum Message {
     Input(InputMessage),
     /// Simulation control events.
     Simulation(SimulationMessage),
+    /// NFA construction replay events.
+    Construction(ConstructionMessage),
+    /// Parser trace replay events.
+    ParseTrace(ParseTraceMessage),
     /// Canvas/view configuration events.
     View(ViewMessage),
     /// PaneGrid drag/resize events
     PaneGrid(PaneGridMessage),
+    /// Boolean workbench events.
+    Workbench(WorkbenchMessage),
+    /// Exercise mode events.
+    Exercise(ExerciseMessage),
+    /// Guided tutorial events.
+    Tutorial(TutorialMessage),
+    /// Workspace tab events.
+    Tab(TabMessage),
+    /// Detached window events.
+    Window(WindowMessage),
 }
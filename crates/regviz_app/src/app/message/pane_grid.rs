@@ -1,7 +1,13 @@
 use iced::widget::pane_grid;
 
+use crate::app::layout::LayoutPreset;
+
 /// Messages related to PaneGrid interactions.
 #[derive(Debug, Clone)]
 pub enum PaneGridMessage {
     Resized(pane_grid::ResizeEvent),
+    /// User opened or closed the controls drawer of the narrow layout.
+    ToggleDrawer,
+    /// User picked how the controls and visualization panes are arranged.
+    SelectPreset(LayoutPreset),
 }
@@ -0,0 +1,16 @@
+/// Messages emitted by the controls replaying how the parser built the AST.
+#[derive(Debug, Clone)]
+pub enum ParseTraceMessage {
+    /// Start replaying the parse from its first token.
+    Start,
+    /// Stop the replay and show the whole tree again.
+    Stop,
+    /// Take the parser's next step.
+    StepForward,
+    /// Take back the parser's last step.
+    StepBackward,
+    /// Start or pause automatic playback of the parse.
+    TogglePlay,
+    /// Playback timer fired; take the parser's next step.
+    PlayTick,
+}
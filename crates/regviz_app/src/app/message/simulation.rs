@@ -1,15 +1,43 @@
-// (no imports)
+use iced::time::Instant;
+use regviz_core::core::automaton::StateId;
+use regviz_core::core::trace::TraceFormat;
 
 /// Messages emitted by the simulation control panel.
 #[derive(Debug, Clone)]
 pub enum SimulationMessage {
     /// User modified the simulation input string.
     InputChanged(String),
+    /// User edited the unconsumed tail of the input while keeping the current step.
+    RemainingInputChanged(String),
     /// Advance to the next simulation step (if available).
     StepForward,
     /// Move back to the previous simulation step (if available).
     StepBackward,
     /// Reset the simulation to the initial step.
     Reset,
+    /// Start or pause automatic playback of the trace.
+    TogglePlay,
+    /// Playback timer fired; advance one step unless a breakpoint is hit.
+    PlayTick,
+    /// The window drew a frame while the dashes of the taken transitions march.
+    MarchFrame(Instant),
+    /// Add or remove a breakpoint on a state of the simulated automaton.
+    ToggleBreakpoint(StateId),
+    /// Add or remove a breakpoint on the transition between two states of the simulated automaton.
+    ToggleEdgeBreakpoint(StateId, StateId),
+    /// Show or hide the NFA/DFA/min-DFA comparison table.
+    ToggleComparison,
+    /// Copy the full trace to the clipboard in the given format.
+    CopyTrace(TraceFormat),
+    /// Save the trace replayed on the simulated automaton as an animated GIF.
+    SaveAnimation,
+    /// Show or hide the suffix matches found by the reverse automaton.
+    ToggleSuffixes,
+    /// Start or stop the edge-by-edge replay of one accepting path.
+    ReplayPath(usize),
+    /// Take the next transition of the replayed path.
+    ReplayForward,
+    /// Undo the last transition of the replayed path.
+    ReplayBackward,
     // Target switching moved to right-pane toggle; no longer emitted here.
 }
@@ -0,0 +1,10 @@
+/// Messages emitted by the workspace tab strip.
+#[derive(Debug, Clone)]
+pub enum TabMessage {
+    /// User opened a new, empty workspace.
+    New,
+    /// User switched to the tab at this index.
+    Select(usize),
+    /// User closed the tab at this index.
+    Close(usize),
+}
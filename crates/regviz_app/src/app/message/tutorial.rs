@@ -0,0 +1,12 @@
+/// Messages emitted by the guided tutorial overlay.
+#[derive(Debug, Clone)]
+pub enum TutorialMessage {
+    /// User (re)launched the tutorial from the help menu.
+    Start,
+    /// User moved to the next step.
+    Next,
+    /// User moved back one step.
+    Back,
+    /// User closed the tutorial.
+    Dismiss,
+}
@@ -1,11 +1,81 @@
-use iced::Point;
-use regviz_core::core::automaton::{BoxKind, StateId};
+use iced::{Point, Vector, theme};
+use regviz_core::core::automaton::{BoxId, BoxKind, EpsilonGlyph, StateId};
+
+use crate::app::i18n::{Key, Locale};
+use crate::app::message::Message;
+use crate::app::operations::DfaOperation;
+use crate::app::simulation::SimulationTarget;
+use crate::graph::layout::Orientation;
+use crate::graph::{BoxStyle, ExportQuality, LayoutConfig, TransitionGrouping};
 
 /// Messages emitted by view and canvas controls.
 #[derive(Debug, Clone)]
 pub enum ViewMessage {
     /// User toggled visibility of a specific bounding box type (NFA only).
     ToggleBox(BoxKind),
+    /// User picked which kind of bounding box the style controls edit.
+    SelectStyledBox(BoxKind),
+    /// User changed how bounding boxes of a kind are drawn.
+    SetBoxStyle(BoxKind, BoxStyle),
+    /// User toggled fading bounding boxes by how deeply they are nested.
+    ToggleDepthDimming,
+    /// User limited the nesting depth of shown bounding boxes (`None` shows every level).
+    SetMaxBoxDepth(Option<usize>),
+    /// User capped the nesting depth laid out in NFA view, folding deeper
+    /// structure into super-nodes (`None` lays out every level).
+    SetCollapseDepth(Option<usize>),
+    /// User expanded or collapsed the layout tuning sliders.
+    ToggleLayoutTuning,
+    /// User changed the spacing the graphs are laid out with.
+    SetLayoutConfig(LayoutConfig),
+    /// User changed the direction the NFA flows in.
+    SetNfaOrientation(Orientation),
+    /// User toggled the strongly-connected-component coloring overlay.
+    ToggleSccOverlay,
+    /// User toggled the transition heatmap overlay.
+    ToggleHeatmap,
+    /// User toggled the lasso overlay explaining why the language is infinite.
+    ToggleInfiniteWitness,
+    /// User picked the UI language.
+    SetLocale(Locale),
+    /// User picked whether the theme is light, dark or follows the system.
+    SetThemePreference(ThemePreference),
+    /// The system reported its light or dark preference, at launch or after a change.
+    SystemThemeChanged(theme::Mode),
+    /// User toggled the high-contrast theme.
+    ToggleHighContrast,
+    /// User changed how far each scroll tick zooms.
+    SetZoomStep(f32),
+    /// User restored every saved preference to its default.
+    ResetSettings,
+    /// User switched automaton views between the canvas and the text listing.
+    ToggleTextView,
+    /// User moved the selected state from the keyboard.
+    FocusState(StateFocus),
+    /// User expanded or collapsed the syntax reference.
+    ToggleSyntaxReference,
+    /// User typed into the syntax reference search box.
+    SyntaxQueryChanged(String),
+    /// User typed into the canvas search bar.
+    GraphQueryChanged(String),
+    /// User picked the glyph drawn for the empty string.
+    SetEpsilonGlyph(EpsilonGlyph),
+    /// User picked how DFA states are numbered on the canvas.
+    SetStateNumbering(StateNumbering),
+    /// User picked how DFA transitions between the same two states are drawn.
+    SetTransitionGrouping(TransitionGrouping),
+    /// User changed the sizes or pixel density of exported figures.
+    SetExportQuality(ExportQuality),
+    /// User copied the current view to the clipboard as SVG.
+    CopySvg,
+    /// User picked which automaton the formal definition describes.
+    SetDefinitionTarget(SimulationTarget),
+    /// User picked how the transition function of the formal definition is written.
+    SetDeltaFormat(DeltaFormat),
+    /// User copied the formal definition to the clipboard as LaTeX.
+    CopyDefinitionLatex,
+    /// Inspector renamed a state (an empty name restores its number).
+    RenameState(StateId, String),
     /// User adjusted the zoom slider.
     ZoomChanged(f32),
     /// User scrolled mouse wheel to zoom (positive = zoom in, negative = zoom out).
@@ -18,10 +88,173 @@ pub enum ViewMessage {
     Pan(Point),
     /// User stopped panning the canvas.
     EndPan,
+    /// Trackpad scroll or kinetic glide moved the view by a screen offset.
+    PanBy(Vector),
+    /// Two fingers moved on the canvas: the new zoom factor and how far their midpoint moved.
+    Pinch(f32, Vector),
+    /// User double-clicked the canvas: the ratio to zoom by and the clicked point's offset from the canvas center.
+    ZoomAround(f32, Vector),
     /// User clicked reset view button to center and restore default zoom.
     ResetView,
     /// User is dragging a node; update its manual position. Point is in layout coordinates.
     NodeDrag(StateId, Point),
+    /// User clicked a node without dragging it; toggles it as the inspected state.
+    SelectState(StateId),
+    /// User clicked the transition between two states; toggles it as the selected transition.
+    SelectEdge(StateId, StateId),
+    /// Cursor moved onto (or off) the transition between two states.
+    HoverEdge(Option<(StateId, StateId)>),
+    /// Cursor moved over (or off) the canvas. Point is in layout coordinates, rounded to whole units.
+    CursorMoved(Option<Point>),
+    /// User applied a language operation to the current DFA.
+    ApplyOperation(DfaOperation),
+    /// User undid the latest operation.
+    UndoOperation,
+    /// User discarded every applied operation.
+    ClearOperations,
+    /// Inspector picked a set of states to mark around the selected state (`None` clears it).
+    ShowStateLayer(Option<StateLayer>),
+    /// User right-clicked an element of the canvas at a position on the canvas.
+    OpenContextMenu(CanvasElement, Point),
+    /// User dismissed the canvas context menu.
+    CloseContextMenu,
+    /// User picked an action from the canvas context menu, which closes it.
+    ChooseContextAction(Box<Message>),
+    /// Context menu pinned a state where it is drawn. Point is in layout coordinates.
+    PinState(StateId, Point),
+    /// Context menu released a pinned state back to the layout.
+    UnpinState(StateId),
+    /// Context menu asked to rename a state from the inspector.
+    StartRenameState(StateId),
+    /// Context menu copied a regular expression for the words a state still accepts.
+    CopyRightLanguage(StateId),
+    /// Context menu folded a bounding box of the NFA into a super-node.
+    CollapseBox(BoxId),
+    /// Context menu unfolded a super-node back into its bounding box.
+    ExpandBox(BoxId),
+}
+
+/// Element of a graph canvas under the cursor when it was right-clicked.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CanvasElement {
+    /// A state, with its center in layout coordinates.
+    State(StateId, Point),
+    /// The transition between two states.
+    Transition(StateId, StateId),
+    /// A bounding box, and whether it is folded into a super-node.
+    Box(BoxId, bool),
+}
+
+/// Sets of states the inspector can mark on the canvas around the selected state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateLayer {
+    /// Every state reachable from the selected state.
+    Reachable,
+    /// Every state on an accepting path through the selected state.
+    AcceptingPaths,
+}
+
+/// Keyboard moves of the selected state on automaton views.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateFocus {
+    /// Select the state after the current one, wrapping around.
+    Next,
+    /// Select the state before the current one, wrapping around.
+    Previous,
+    /// Select the start state.
+    Start,
+    /// Follow the transition on a symbol from the selected (or start) state.
+    Follow(char),
+    /// Clear the selection.
+    Clear,
+}
+
+/// Ways of numbering DFA states on the canvas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StateNumbering {
+    /// Keep the ids assigned by subset construction or minimization.
+    #[default]
+    Original,
+    /// Number reachable states breadth-first from the start state.
+    Bfs,
+    /// Label each state with its Myhill–Nerode class, i.e. the minimized state it merges into.
+    NerodeClass,
+}
+
+impl StateNumbering {
+    /// Every numbering, in the order they are offered.
+    pub const ALL: [StateNumbering; 3] = [
+        StateNumbering::Original,
+        StateNumbering::Bfs,
+        StateNumbering::NerodeClass,
+    ];
+}
+
+impl StateNumbering {
+    /// Catalog entry naming the numbering.
+    #[must_use]
+    pub fn key(self) -> Key {
+        match self {
+            StateNumbering::Original => Key::NumberingOriginal,
+            StateNumbering::Bfs => Key::NumberingBfs,
+            StateNumbering::NerodeClass => Key::NumberingNerodeClass,
+        }
+    }
+}
+
+/// Whether the UI is light, dark, or follows the system preference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemePreference {
+    /// Match the light or dark preference reported by the system.
+    #[default]
+    System,
+    /// Always use the light theme.
+    Light,
+    /// Always use the dark theme.
+    Dark,
+}
+
+impl ThemePreference {
+    /// Every preference, in the order they are offered.
+    pub const ALL: [ThemePreference; 3] = [
+        ThemePreference::System,
+        ThemePreference::Light,
+        ThemePreference::Dark,
+    ];
+
+    /// Catalog entry naming the preference.
+    #[must_use]
+    pub fn key(self) -> Key {
+        match self {
+            ThemePreference::System => Key::ThemeSystem,
+            ThemePreference::Light => Key::ThemeLight,
+            ThemePreference::Dark => Key::ThemeDark,
+        }
+    }
+}
+
+/// Ways of writing the transition function δ of a formal definition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeltaFormat {
+    /// A table with one row per state and one column per symbol.
+    #[default]
+    Table,
+    /// One `δ(q, a) = …` line per transition.
+    Listing,
+}
+
+impl DeltaFormat {
+    /// Every format, in the order they are offered.
+    pub const ALL: [DeltaFormat; 2] = [DeltaFormat::Table, DeltaFormat::Listing];
+
+    /// Catalog entry naming the format.
+    #[must_use]
+    pub fn key(self) -> Key {
+        match self {
+            DeltaFormat::Table => Key::DeltaTable,
+            DeltaFormat::Listing => Key::DeltaListing,
+        }
+    }
 }
 
 /// Available visualization modes.
@@ -35,6 +268,16 @@ pub enum ViewMode {
     Dfa,
     /// Show the Minimized Deterministic Finite Automaton.
     MinDfa,
+    /// Show language analytics for the minimized DFA.
+    Analysis,
+    /// Show the DFA produced by the applied operations.
+    Operations,
+    /// Combine two patterns with Boolean operations.
+    Workbench,
+    /// Write a regex for a target language and check it.
+    Exercise,
+    /// Show the simulated automaton as its formal five-tuple definition.
+    Definition,
 }
 
 /// Bottom-right toggle options (unifies AST view and NFA/DFA targets).
@@ -44,4 +287,9 @@ pub enum RightPaneMode {
     Nfa,
     Dfa,
     MinDfa,
+    Analysis,
+    Operations,
+    Workbench,
+    Exercise,
+    Definition,
 }
@@ -0,0 +1,14 @@
+use iced::window;
+
+use super::{Message, ViewMode};
+
+/// Messages about the visualization windows detached from the main one.
+#[derive(Debug, Clone)]
+pub enum WindowMessage {
+    /// User opened a view in a window of its own.
+    Detach(ViewMode),
+    /// A window was closed.
+    Closed(window::Id),
+    /// A canvas in a detached window emitted a message about its view.
+    Relay(ViewMode, Box<Message>),
+}
@@ -0,0 +1,12 @@
+use regviz_core::core::product::BoolOp;
+
+/// Messages emitted by the Boolean workbench.
+#[derive(Debug, Clone)]
+pub enum WorkbenchMessage {
+    /// User edited the left pattern.
+    LeftChanged(String),
+    /// User edited the right pattern.
+    RightChanged(String),
+    /// User picked the operation combining the two languages.
+    SetOperation(BoolOp),
+}
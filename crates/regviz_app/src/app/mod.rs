@@ -1,11 +1,24 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod animation;
 mod constants;
+mod construction;
+mod edit_diff;
+mod exercise;
+mod i18n;
+mod layout;
 pub mod message;
+mod operations;
 mod parser;
+mod settings;
 mod simulation;
 mod state;
 pub mod theme;
+mod tutorial;
 mod update;
 mod view;
+mod windows;
+mod workbench;
+mod workspace;
 
 // Re-export main types for convenience
 pub use state::App;
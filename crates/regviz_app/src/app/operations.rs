@@ -0,0 +1,110 @@
+use regviz_core::core::dfa::{self, Dfa};
+
+use super::i18n::{Key, Locale};
+
+/// Language operations that turn the current DFA into a new one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DfaOperation {
+    /// Accept exactly the words over the alphabet that were rejected.
+    Complement,
+    /// Accept the reversal of every accepted word.
+    Reverse,
+    /// Accept any concatenation of accepted words (Kleene star).
+    Star,
+    /// Drop unreachable and dead states.
+    Trim,
+}
+
+impl DfaOperation {
+    /// Every operation, in the order they are offered.
+    pub const ALL: [DfaOperation; 4] = [
+        DfaOperation::Complement,
+        DfaOperation::Reverse,
+        DfaOperation::Star,
+        DfaOperation::Trim,
+    ];
+
+    /// Catalog entry naming the operation.
+    #[must_use]
+    pub fn key(self) -> Key {
+        match self {
+            DfaOperation::Complement => Key::OpComplement,
+            DfaOperation::Reverse => Key::OpReverse,
+            DfaOperation::Star => Key::OpStar,
+            DfaOperation::Trim => Key::OpTrim,
+        }
+    }
+
+    /// Applies the operation, keeping the alphabet of `dfa`.
+    #[must_use]
+    pub fn apply(self, dfa: &Dfa) -> Dfa {
+        match self {
+            DfaOperation::Complement => dfa.complement(),
+            DfaOperation::Reverse => dfa::determinize_over(&dfa.reverse(), &dfa.alphabet),
+            DfaOperation::Star => dfa.star(),
+            DfaOperation::Trim => dfa.trim(),
+        }
+    }
+}
+
+/// Operations applied one after another on top of a base DFA, with undo.
+#[derive(Debug, Default)]
+pub struct OperationStack {
+    /// Name of the view the base DFA was taken from, and the DFA itself.
+    base: Option<(Key, Dfa)>,
+    /// Each applied operation with the DFA it produced.
+    steps: Vec<(DfaOperation, Dfa)>,
+}
+
+impl OperationStack {
+    /// Applies `operation` to the latest result.
+    ///
+    /// The first operation takes its input from `base`, which names the DFA
+    /// and where it came from for the breadcrumb.
+    pub fn apply(&mut self, operation: DfaOperation, base: impl FnOnce() -> (Key, Dfa)) {
+        let (_, base) = self.base.get_or_insert_with(base);
+        let input = self.steps.last().map_or(&*base, |(_, dfa)| dfa);
+        let result = operation.apply(input);
+        self.steps.push((operation, result));
+    }
+
+    /// Removes the latest operation, forgetting the base once nothing is left.
+    pub fn undo(&mut self) {
+        self.steps.pop();
+        if self.steps.is_empty() {
+            self.base = None;
+        }
+    }
+
+    /// Removes every operation, e.g. after the automata are rebuilt.
+    pub fn clear(&mut self) {
+        self.steps.clear();
+        self.base = None;
+    }
+
+    /// Returns whether no operation has been applied.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Returns the DFA produced by the latest operation.
+    #[must_use]
+    pub fn current(&self) -> Option<&Dfa> {
+        self.steps.last().map(|(_, dfa)| dfa)
+    }
+
+    /// Returns the base name followed by each applied operation.
+    #[must_use]
+    pub fn breadcrumb(&self, locale: Locale) -> Vec<&'static str> {
+        self.base
+            .iter()
+            .map(|(name, _)| locale.text(*name))
+            .chain(
+                self.steps
+                    .iter()
+                    .map(|(operation, _)| locale.text(operation.key())),
+            )
+            .collect()
+    }
+}
@@ -1,5 +1,6 @@
-use regviz_core::core::{BuildArtifacts, nfa::Nfa, parser};
+use regviz_core::core::{BuildArtifacts, diagnostics, nfa::Nfa, parser};
 
+use super::message::ViewMode;
 use super::state::App;
 
 impl App {
@@ -9,31 +10,62 @@ impl App {
     /// 1. Lexical analysis (tokenization)
     /// 2. Syntax analysis (AST construction)
     /// 3. NFA construction from the AST
-    /// 4. Alphabet extraction
+    /// 4. Alphabet extraction, or validation of the declared alphabet
     ///
     /// On success, `build_artifacts` is populated and `error` is cleared.
     /// On failure, `error` is set and `build_artifacts` is cleared.
+    ///
+    /// The DFAs built from the replaced regex are compared with the new ones
+    /// so the changes can be flashed; after a failed build, the next success
+    /// is compared with the last regex that built.
     pub fn lex_and_parse(&mut self) {
+        if let Some(previous) = self.build_artifacts.as_mut() {
+            self.edit_diff
+                .remember(previous.dfa.take(), previous.min_dfa.take());
+        }
         // Try to lex the input into tokens
         match parser::Ast::build(self.input.trim()) {
             Ok(ast) => {
+                self.warnings = diagnostics::warnings(&ast);
                 let nfa = Nfa::build(&ast);
                 let alphabet = nfa.alphabet();
-                self.build_artifacts = Some(BuildArtifacts {
-                    ast,
-                    nfa,
-                    alphabet,
-                    dfa: None,
-                    min_dfa: None,
-                });
+                let mut artifacts = BuildArtifacts::new(ast, nfa, alphabet);
+                let declared = self.declared_symbols();
+                // Fall back to the inferred alphabet while the declared one is incomplete.
+                self.alphabet_error = if declared.is_empty() {
+                    None
+                } else {
+                    artifacts.declare_alphabet(&declared).err()
+                };
+                self.build_artifacts = Some(artifacts);
                 self.error = None;
+                self.view_state.clear_selection();
+                self.simulation.breakpoints.clear();
+                self.simulation.edge_breakpoints.clear();
+                self.operations.clear();
+                self.construction = None;
+                self.parse_trace = None;
                 self.simulation.reset_cursor();
                 self.refresh_simulation_trace();
+                if matches!(self.view_mode(), ViewMode::Analysis | ViewMode::Definition)
+                    || self.shows_detached_dfa()
+                {
+                    self.ensure_min_dfa();
+                }
+                if let Some(artifacts) = &self.build_artifacts {
+                    self.edit_diff
+                        .compare(artifacts.dfa.as_ref(), artifacts.min_dfa.as_ref());
+                }
             }
             Err(e) => {
                 // Build error (lex or parse)
                 self.error = Some(e);
                 self.build_artifacts = None;
+                self.alphabet_error = None;
+                self.warnings.clear();
+                self.operations.clear();
+                self.construction = None;
+                self.parse_trace = None;
                 self.simulation.clear_trace();
                 self.simulation_error = None;
             }
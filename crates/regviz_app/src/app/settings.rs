@@ -0,0 +1,301 @@
+use regviz_core::core::automaton::{BoxKind, EpsilonGlyph};
+
+use super::constants::{MAX_ZOOM_STEP, MIN_ZOOM_STEP, ZOOM_STEP};
+use super::i18n::Locale;
+use super::layout::PaneLayout;
+use super::message::ThemePreference;
+use super::state::App;
+use crate::graph::{BoxStyle, BoxStyles};
+
+/// Every kind of bounding box with its name in the saved settings.
+const BOX_KINDS: [(BoxKind, &str); 6] = [
+    (BoxKind::Literal, "literal"),
+    (BoxKind::Concat, "concat"),
+    (BoxKind::Alternation, "alternation"),
+    (BoxKind::KleeneStar, "star"),
+    (BoxKind::KleenePlus, "plus"),
+    (BoxKind::Optional, "optional"),
+];
+
+/// Preferences kept from one session to the next.
+///
+/// Saved as `key=value` lines in the platform's configuration directory on
+/// native builds and in the browser's local storage on the web.
+#[derive(Debug, Clone)]
+pub struct Settings {
+    /// Whether the UI is light, dark, or follows the system preference.
+    pub theme: ThemePreference,
+    /// Whether the high-contrast theme replaces the light or dark one.
+    pub high_contrast: bool,
+    /// Language the UI is shown in.
+    pub locale: Locale,
+    /// Glyph used to draw the empty string.
+    pub epsilon_glyph: EpsilonGlyph,
+    /// Pane arrangement and the split ratios remembered for it.
+    pub pane_layout: PaneLayout,
+    /// Zoom change per scroll tick.
+    pub zoom_step: f32,
+    /// How each kind of bounding box is drawn in NFA view.
+    pub box_styles: BoxStyles,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: ThemePreference::default(),
+            high_contrast: false,
+            locale: Locale::default(),
+            epsilon_glyph: EpsilonGlyph::default(),
+            pane_layout: PaneLayout::default(),
+            zoom_step: ZOOM_STEP,
+            box_styles: BoxStyles::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Loads the settings saved by a previous session, or the defaults.
+    #[must_use]
+    pub fn load() -> Self {
+        storage::read().map_or_else(Self::default, |text| Self::from_text(&text))
+    }
+
+    /// Saves the settings for the next session.
+    ///
+    /// # Returns
+    /// - `std::io::Result<()>` - Whether the settings could be written; a
+    ///   failure only means the next session starts with older settings.
+    pub fn save(&self) -> std::io::Result<()> {
+        storage::write(&self.to_text())
+    }
+
+    /// Writes the settings as `key=value` lines.
+    fn to_text(&self) -> String {
+        let mut text = format!(
+            "theme={}\nhigh-contrast={}\nlocale={}\nepsilon={}\nzoom-step={}\n",
+            theme_name(self.theme),
+            self.high_contrast,
+            locale_code(self.locale),
+            self.epsilon_glyph,
+            self.zoom_step,
+        );
+        for (kind, name) in BOX_KINDS {
+            let style = self.box_styles.get(kind);
+            text.push_str(&format!(
+                "box.{name}.label={}\nbox.{name}.dashed={}\nbox.{name}.filled={}\nbox.{name}.radius={}\n",
+                style.show_label, style.dashed, style.filled, style.corner_radius,
+            ));
+        }
+        text.push_str(&self.pane_layout.to_text());
+        text
+    }
+
+    /// Reads settings written by [`Settings::to_text`], keeping defaults for
+    /// anything missing or malformed.
+    fn from_text(text: &str) -> Self {
+        let mut settings = Self {
+            pane_layout: PaneLayout::from_text(text),
+            ..Self::default()
+        };
+        for (key, value) in text.lines().filter_map(|line| line.split_once('=')) {
+            let (key, value) = (key.trim(), value.trim());
+            match key {
+                "theme" => {
+                    if let Some(theme) = ThemePreference::ALL
+                        .into_iter()
+                        .find(|&theme| theme_name(theme) == value)
+                    {
+                        settings.theme = theme;
+                    }
+                }
+                "high-contrast" => {
+                    if let Ok(high_contrast) = value.parse() {
+                        settings.high_contrast = high_contrast;
+                    }
+                }
+                "locale" => {
+                    if let Some(locale) = Locale::ALL
+                        .into_iter()
+                        .find(|&locale| locale_code(locale) == value)
+                    {
+                        settings.locale = locale;
+                    }
+                }
+                "epsilon" => {
+                    if let Ok(glyph) = value.parse() {
+                        settings.epsilon_glyph = glyph;
+                    }
+                }
+                "zoom-step" => {
+                    if let Ok(step) = value.parse::<f32>()
+                        && step.is_finite()
+                    {
+                        settings.zoom_step = step.clamp(MIN_ZOOM_STEP, MAX_ZOOM_STEP);
+                    }
+                }
+                _ => {
+                    if let Some(rest) = key.strip_prefix("box.") {
+                        read_box_style(&mut settings.box_styles, rest, value);
+                    }
+                }
+            }
+        }
+        settings
+    }
+}
+
+/// Applies one `box.<kind>.<field>` entry, with `key` stripped of its `box.` prefix.
+fn read_box_style(styles: &mut BoxStyles, key: &str, value: &str) {
+    let Some((name, field)) = key.split_once('.') else {
+        return;
+    };
+    let Some((kind, _)) = BOX_KINDS.into_iter().find(|(_, n)| *n == name) else {
+        return;
+    };
+    let mut style: BoxStyle = styles.get(kind);
+    match field {
+        "label" => style.show_label = value.parse().unwrap_or(style.show_label),
+        "dashed" => style.dashed = value.parse().unwrap_or(style.dashed),
+        "filled" => style.filled = value.parse().unwrap_or(style.filled),
+        "radius" => {
+            if let Ok(radius) = value.parse::<f32>()
+                && radius.is_finite()
+            {
+                style.corner_radius = radius.max(0.0);
+            }
+        }
+        _ => return,
+    }
+    styles.set(kind, style);
+}
+
+/// Name of a theme preference in the saved settings.
+fn theme_name(theme: ThemePreference) -> &'static str {
+    match theme {
+        ThemePreference::System => "system",
+        ThemePreference::Light => "light",
+        ThemePreference::Dark => "dark",
+    }
+}
+
+/// Language code of a locale in the saved settings.
+fn locale_code(locale: Locale) -> &'static str {
+    match locale {
+        Locale::English => "en",
+        Locale::Spanish => "es",
+    }
+}
+
+impl App {
+    /// Creates the app with the settings saved by a previous session.
+    #[must_use]
+    pub fn restored() -> Self {
+        let mut app = Self::default();
+        app.apply_settings(Settings::load());
+        app
+    }
+
+    /// Collects the current preferences into [`Settings`].
+    #[must_use]
+    pub fn settings(&self) -> Settings {
+        Settings {
+            theme: self.theme_preference,
+            high_contrast: self.high_contrast,
+            locale: self.locale,
+            epsilon_glyph: self.epsilon_glyph,
+            pane_layout: self.pane_layout.clone(),
+            zoom_step: self.zoom_step,
+            box_styles: self.box_styles.clone(),
+        }
+    }
+
+    /// Replaces the current preferences with `settings`, rearranging the panes.
+    pub fn apply_settings(&mut self, settings: Settings) {
+        self.theme_preference = settings.theme;
+        self.high_contrast = settings.high_contrast;
+        self.locale = settings.locale;
+        self.epsilon_glyph = settings.epsilon_glyph;
+        self.panes = settings.pane_layout.panes();
+        self.pane_layout = settings.pane_layout;
+        self.zoom_step = settings.zoom_step;
+        self.box_styles = settings.box_styles;
+        self.apply_theme();
+    }
+
+    /// Saves the current preferences for the next session.
+    pub fn save_settings(&self) {
+        if let Err(error) = self.settings().save() {
+            #[cfg(not(target_arch = "wasm32"))]
+            tracing::warn!("could not save the settings: {error}");
+            #[cfg(target_arch = "wasm32")]
+            log::warn!("could not save the settings: {error}");
+        }
+    }
+}
+
+/// Settings kept in a file in the platform's configuration directory.
+#[cfg(not(target_arch = "wasm32"))]
+mod storage {
+    use std::env::var_os;
+    use std::path::PathBuf;
+
+    /// File the settings are saved to.
+    const FILE_NAME: &str = "settings.txt";
+
+    /// File older versions saved the pane layout to, read when no settings exist yet.
+    const LEGACY_FILE_NAME: &str = "layout.txt";
+
+    /// Returns the saved settings, if any.
+    pub fn read() -> Option<String> {
+        let dir = config_dir()?;
+        std::fs::read_to_string(dir.join(FILE_NAME))
+            .or_else(|_| std::fs::read_to_string(dir.join(LEGACY_FILE_NAME)))
+            .ok()
+    }
+
+    /// Replaces the saved settings with `text`.
+    pub fn write(text: &str) -> std::io::Result<()> {
+        let Some(dir) = config_dir() else {
+            return Ok(());
+        };
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(dir.join(FILE_NAME), text)
+    }
+
+    /// Returns the platform's configuration directory for the app, or `None`
+    /// when it cannot be determined.
+    fn config_dir() -> Option<PathBuf> {
+        let config = var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| var_os("APPDATA").map(PathBuf::from))
+            .or_else(|| var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(config.join("regviz"))
+    }
+}
+
+/// Settings kept in the browser's local storage.
+#[cfg(target_arch = "wasm32")]
+mod storage {
+    use std::io;
+
+    /// Local storage key the settings are saved under.
+    const KEY: &str = "regviz.settings";
+
+    /// Returns the saved settings, if any.
+    pub fn read() -> Option<String> {
+        local_storage()?.get_item(KEY).ok()?
+    }
+
+    /// Replaces the saved settings with `text`.
+    pub fn write(text: &str) -> io::Result<()> {
+        local_storage()
+            .ok_or_else(|| io::Error::other("local storage is unavailable"))?
+            .set_item(KEY, text)
+            .map_err(|_| io::Error::other("local storage rejected the settings"))
+    }
+
+    /// Returns the page's local storage, if the browser allows it.
+    fn local_storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok()?
+    }
+}
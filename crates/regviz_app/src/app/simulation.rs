@@ -1,10 +1,16 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::PathBuf;
 
-use regviz_core::core::automaton::{EdgeLabel, StateId};
-use regviz_core::core::dfa::Dfa;
+use iced::time::Instant;
+use regviz_core::core::automaton::{Edge, EdgeLabel, StateId};
+use regviz_core::core::dfa::{self, Dfa};
 use regviz_core::core::nfa::Nfa;
 use regviz_core::core::sim;
+use regviz_core::core::state_set::StateSet;
+use regviz_core::core::trace::{self, Trace, TraceFormat, TraceStep};
+use regviz_core::errors::SimError;
 
+use super::message::ViewMode;
 use crate::graph::{EdgeHighlight, Highlights, StateHighlight};
 
 /// Specifies which automaton should drive the simulation.
@@ -19,6 +25,27 @@ pub enum SimulationTarget {
     MinDfa,
 }
 
+impl SimulationTarget {
+    /// Returns the target simulated on the `mode` view, if that view shows one.
+    pub fn for_view(mode: ViewMode) -> Option<Self> {
+        match mode {
+            ViewMode::Nfa => Some(Self::Nfa),
+            ViewMode::Dfa => Some(Self::Dfa),
+            ViewMode::MinDfa => Some(Self::MinDfa),
+            _ => None,
+        }
+    }
+
+    /// Returns the view showing the target automaton.
+    pub fn view(self) -> ViewMode {
+        match self {
+            Self::Nfa => ViewMode::Nfa,
+            Self::Dfa => ViewMode::Dfa,
+            Self::MinDfa => ViewMode::MinDfa,
+        }
+    }
+}
+
 /// Snapshot describing the automaton after consuming a prefix of the input.
 #[derive(Debug, Clone)]
 pub struct SimulationStep {
@@ -27,7 +54,7 @@ pub struct SimulationStep {
     /// Character consumed to reach this step (None for the initial state).
     pub consumed: Option<char>,
     /// Set of states that are currently active.
-    pub active_states: HashSet<StateId>,
+    pub active_states: StateSet,
     /// Edges that were taken while advancing to this step.
     pub traversed_edges: HashSet<EdgeHighlight>,
     /// Whether this step represents an accepting frontier.
@@ -40,7 +67,7 @@ impl SimulationStep {
     pub fn new(
         index: usize,
         consumed: Option<char>,
-        active_states: HashSet<StateId>,
+        active_states: StateSet,
         traversed_edges: HashSet<EdgeHighlight>,
         accepted: bool,
     ) -> Self {
@@ -78,6 +105,104 @@ impl SimulationTrace {
     pub fn step(&self, index: usize) -> Option<&SimulationStep> {
         self.steps.get(index)
     }
+
+    /// Converts the trace into the core representation used for exporting.
+    #[must_use]
+    pub fn to_export(&self) -> Trace {
+        let steps = self
+            .steps
+            .iter()
+            .map(|step| {
+                TraceStep::new(
+                    step.index,
+                    step.consumed,
+                    step.active_states.iter(),
+                    step.traversed_edges.iter().map(|edge| Edge {
+                        from: edge.from,
+                        to: edge.to,
+                        label: edge.label,
+                    }),
+                    step.accepted,
+                )
+            })
+            .collect();
+        Trace {
+            steps,
+            ..Trace::default()
+        }
+    }
+
+    /// Keeps the steps up to and including `index` and appends `tail` after them.
+    ///
+    /// Used to re-simulate only the unconsumed part of an edited input.
+    pub fn splice(&mut self, index: usize, tail: Vec<SimulationStep>) {
+        self.steps.truncate(index + 1);
+        self.steps.extend(tail);
+    }
+}
+
+/// Whether one suffix of the simulation input belongs to the language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuffixMatch {
+    /// Character index where the suffix begins (equal to the input length for ε).
+    pub start: usize,
+    /// Whether the suffix is accepted.
+    pub accepted: bool,
+}
+
+/// One consumed prefix of the input as seen by each engine in an [`EngineComparison`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComparisonRow {
+    /// Index of the step (0 = before consuming any input).
+    pub index: usize,
+    /// Character consumed to reach this step (None for the initial state).
+    pub consumed: Option<char>,
+    /// Active states of the NFA, DFA and minimized DFA, in that order.
+    ///
+    /// `None` once that engine's run stopped before reaching this step.
+    pub states: [Option<Vec<StateId>>; 3],
+    /// Whether each engine accepts the prefix consumed so far.
+    pub accepted: [bool; 3],
+}
+
+impl ComparisonRow {
+    /// Returns whether all three engines agree on accepting the prefix.
+    #[must_use]
+    pub fn agrees(&self) -> bool {
+        self.accepted
+            .iter()
+            .all(|&accepted| accepted == self.accepted[0])
+    }
+}
+
+/// The same input run through the NFA, DFA and minimized DFA side by side.
+///
+/// The three engines recognize the same language, so they must agree on
+/// every prefix; a divergence indicates a bug in the core constructions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EngineComparison {
+    /// One row per step, from the initial state to the end of the input.
+    pub rows: Vec<ComparisonRow>,
+}
+
+impl EngineComparison {
+    /// Returns the index of the first step where the engines disagree, if any.
+    #[must_use]
+    pub fn first_divergence(&self) -> Option<usize> {
+        self.rows
+            .iter()
+            .position(|row| !row.agrees())
+            .map(|position| self.rows[position].index)
+    }
+}
+
+/// Position within the edge-by-edge replay of one accepting path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathReplay {
+    /// Index into [`SimulationState::accepting_paths`].
+    pub path: usize,
+    /// Number of transitions of the path taken so far.
+    pub cursor: usize,
 }
 
 /// Reactive state used by the UI to drive step-by-step simulation.
@@ -90,12 +215,45 @@ pub struct SimulationState {
     /// Selected automaton (NFA or DFA).
     pub target: SimulationTarget,
     trace: Option<SimulationTrace>,
+    /// Whether the reverse-automaton suffix demo is shown.
+    pub show_suffixes: bool,
+    /// Suffix matches computed by the reverse automaton, longest suffix first.
+    pub suffix_matches: Vec<SuffixMatch>,
+    /// Explicit NFA paths accepting the input, if it is accepted.
+    pub accepting_paths: Vec<Vec<Edge>>,
+    /// Accepting path currently being replayed instead of the frontier trace.
+    pub replay: Option<PathReplay>,
+    /// Why the input is rejected, if it is.
+    pub rejection: Option<sim::Rejection>,
+    /// Speculative step shown as a ghost while a transition is hovered.
+    pub preview: Option<SimulationStep>,
+    /// Whether the trace is being stepped through automatically.
+    pub playing: bool,
+    /// States that pause playback when they become active.
+    pub breakpoints: BTreeSet<StateId>,
+    /// Transitions, keyed by their endpoints, that pause playback when they are taken.
+    pub edge_breakpoints: BTreeSet<(StateId, StateId)>,
+    /// Format the current trace was last copied to the clipboard as.
+    pub exported: Option<TraceFormat>,
+    /// Where the trace was last saved as an animation, or why it could not be.
+    pub animation: Option<Result<PathBuf, String>>,
+    /// Whether the NFA/DFA/min-DFA comparison table is shown.
+    pub show_comparison: bool,
+    /// Step table comparing the three engines on the input.
+    pub comparison: Option<EngineComparison>,
+    /// Seconds the dashes of the taken transitions have marched for.
+    pub march_time: f32,
+    /// Frame the dashes started marching on.
+    march_start: Option<Instant>,
 }
 
 impl SimulationState {
     /// Replaces the current trace and clamps the cursor to the new bounds.
     pub fn set_trace(&mut self, trace: Option<SimulationTrace>) {
         self.trace = trace;
+        self.preview = None;
+        self.exported = None;
+        self.animation = None;
         let len = self.trace.as_ref().map(|t| t.len()).unwrap_or(0);
         if len == 0 {
             self.cursor = 0;
@@ -108,6 +266,54 @@ impl SimulationState {
     pub fn clear_trace(&mut self) {
         self.trace = None;
         self.cursor = 0;
+        self.suffix_matches.clear();
+        self.accepting_paths.clear();
+        self.replay = None;
+        self.rejection = None;
+        self.preview = None;
+        self.playing = false;
+        self.exported = None;
+        self.animation = None;
+        self.comparison = None;
+    }
+
+    /// Replaces every step after the cursor with `tail`, keeping the current step.
+    pub fn splice_trace(&mut self, tail: Vec<SimulationStep>) {
+        if let Some(trace) = self.trace.as_mut() {
+            trace.splice(self.cursor, tail);
+        }
+        self.preview = None;
+        self.exported = None;
+        self.animation = None;
+    }
+
+    /// Returns the part of the input consumed up to the current step.
+    #[must_use]
+    pub fn consumed_input(&self) -> &str {
+        let end = self
+            .input
+            .char_indices()
+            .nth(self.cursor)
+            .map_or(self.input.len(), |(offset, _)| offset);
+        &self.input[..end]
+    }
+
+    /// Returns the part of the input not yet consumed at the current step.
+    #[must_use]
+    pub fn remaining_input(&self) -> &str {
+        &self.input[self.consumed_input().len()..]
+    }
+
+    /// Renders the full trace in `format`, if there is one, naming states by `labels`.
+    #[must_use]
+    pub fn export_trace(
+        &self,
+        format: TraceFormat,
+        labels: HashMap<StateId, String>,
+    ) -> Option<String> {
+        self.trace
+            .as_ref()
+            .map(|trace| trace.to_export().with_labels(labels).export(format))
     }
 
     /// Returns the number of available steps.
@@ -124,9 +330,26 @@ impl SimulationState {
             .and_then(|trace| trace.step(self.cursor))
     }
 
+    /// Returns whether the displayed step took any transition, whose dashes then march.
+    #[must_use]
+    pub fn is_marching(&self) -> bool {
+        self.current_highlights()
+            .is_some_and(|highlights| !highlights.edges.is_empty())
+    }
+
+    /// Moves the marching dashes to where they are on the frame drawn at `now`.
+    pub fn march(&mut self, now: Instant) {
+        let start = *self.march_start.get_or_insert(now);
+        self.march_time = now.duration_since(start).as_secs_f32();
+    }
+
     /// Returns highlights describing the active states and edges.
     #[must_use]
     pub fn current_highlights(&self) -> Option<Highlights> {
+        if let Some(highlights) = self.replay_highlights() {
+            return Some(highlights);
+        }
+
         let step = self.current_step()?;
         let total_steps = self.step_count()?;
         let is_terminal_step = self.cursor + 1 == total_steps;
@@ -137,11 +360,66 @@ impl SimulationState {
         };
 
         let mut states = HashMap::new();
-        for state in &step.active_states {
-            states.insert(*state, highlight_style);
+        for state in step.active_states.iter() {
+            states.insert(state, highlight_style);
         }
 
-        Some(Highlights::new(states, step.traversed_edges.clone()))
+        let highlights = Highlights::new(states, step.traversed_edges.clone());
+        Some(match &self.preview {
+            Some(preview) => highlights.with_ghost(
+                preview.active_states.iter().collect(),
+                preview.traversed_edges.clone(),
+            ),
+            None => highlights,
+        })
+    }
+
+    /// Returns the path being replayed, if any.
+    #[must_use]
+    pub fn replayed_path(&self) -> Option<&[Edge]> {
+        let replay = self.replay?;
+        self.accepting_paths.get(replay.path).map(Vec::as_slice)
+    }
+
+    /// Highlights the edges taken so far along the replayed path and the state reached.
+    fn replay_highlights(&self) -> Option<Highlights> {
+        let replay = self.replay?;
+        let path = self.replayed_path()?;
+        let taken = &path[..replay.cursor.min(path.len())];
+        let current = taken.last().map_or(path.first()?.from, |edge| edge.to);
+
+        let edges = taken
+            .iter()
+            .map(|edge| EdgeHighlight::new(edge.from, edge.to, edge.label))
+            .collect();
+        let states = HashMap::from([(current, StateHighlight::Active)]);
+        Some(Highlights::new(states, edges))
+    }
+
+    /// Starts replaying the given accepting path, or stops if it is already shown.
+    pub fn toggle_replay(&mut self, path: usize) {
+        if self.replay.is_some_and(|replay| replay.path == path) {
+            self.replay = None;
+        } else if path < self.accepting_paths.len() {
+            self.replay = Some(PathReplay { path, cursor: 0 });
+        }
+    }
+
+    /// Takes the next transition of the replayed path.
+    pub fn replay_forward(&mut self) {
+        let len = self.replayed_path().map_or(0, <[Edge]>::len);
+        if let Some(replay) = self.replay.as_mut()
+            && replay.cursor < len
+        {
+            replay.cursor += 1;
+        }
+    }
+
+    /// Undoes the last transition of the replayed path.
+    pub fn replay_backward(&mut self) {
+        if let Some(replay) = self.replay.as_mut() {
+            replay.cursor = replay.cursor.saturating_sub(1);
+        }
     }
 
     /// Returns whether stepping backward is possible.
@@ -163,6 +441,7 @@ impl SimulationState {
     pub fn step_backward(&mut self) {
         if self.can_step_backward() {
             self.cursor -= 1;
+            self.preview = None;
         }
     }
 
@@ -170,12 +449,91 @@ impl SimulationState {
     pub fn step_forward(&mut self) {
         if self.can_step_forward() {
             self.cursor += 1;
+            self.preview = None;
         }
     }
 
     /// Resets the cursor to the initial step.
     pub fn reset_cursor(&mut self) {
         self.cursor = 0;
+        self.preview = None;
+    }
+
+    /// Adds a breakpoint on `state`, or removes it if one is already set.
+    pub fn toggle_breakpoint(&mut self, state: StateId) {
+        if !self.breakpoints.remove(&state) {
+            self.breakpoints.insert(state);
+        }
+    }
+
+    /// Adds a breakpoint on the transition from `from` to `to`, or removes it
+    /// if one is already set.
+    pub fn toggle_edge_breakpoint(&mut self, from: StateId, to: StateId) {
+        if !self.edge_breakpoints.remove(&(from, to)) {
+            self.edge_breakpoints.insert((from, to));
+        }
+    }
+
+    /// Returns the breakpoint transitions taken by the current step.
+    #[must_use]
+    pub fn hit_edge_breakpoints(&self) -> Vec<(StateId, StateId)> {
+        let Some(step) = self.current_step() else {
+            return Vec::new();
+        };
+        self.edge_breakpoints
+            .iter()
+            .copied()
+            .filter(|&(from, to)| {
+                step.traversed_edges
+                    .iter()
+                    .any(|edge| edge.from == from && edge.to == to)
+            })
+            .collect()
+    }
+
+    /// Returns the breakpoint states active at the current step.
+    #[must_use]
+    pub fn hit_breakpoints(&self) -> Vec<StateId> {
+        let Some(step) = self.current_step() else {
+            return Vec::new();
+        };
+        self.breakpoints
+            .iter()
+            .copied()
+            .filter(|&state| step.active_states.contains(state))
+            .collect()
+    }
+
+    /// Starts playback from the current step, or pauses it.
+    ///
+    /// Starting at the last step rewinds to the beginning first.
+    pub fn toggle_play(&mut self) {
+        if self.playing {
+            self.playing = false;
+            return;
+        }
+        if !self.can_step_forward() {
+            self.reset_cursor();
+        }
+        self.replay = None;
+        self.playing = self.can_step_forward();
+    }
+
+    /// Advances playback by one step.
+    ///
+    /// Playback pauses at the end of the trace, when a breakpoint state becomes
+    /// active, or when a breakpoint transition is taken.
+    pub fn play_tick(&mut self) {
+        if !self.playing {
+            return;
+        }
+        self.step_forward();
+        if !self.can_step_forward()
+            || !self.hit_breakpoints().is_empty()
+            || !self.hit_edge_breakpoints().is_empty()
+        {
+            self.playing = false;
+        }
     }
 
     /// Returns whether the cursor is positioned at the terminal step without acceptance.
@@ -192,12 +550,28 @@ impl SimulationState {
 }
 
 /// Builds a simulation trace for an NFA by computing epsilon closures between steps.
-#[must_use]
-pub fn build_nfa_trace(nfa: &Nfa, input: &str) -> SimulationTrace {
+///
+/// # Arguments
+///
+/// - `nfa` (`&Nfa`) - The automaton to simulate.
+/// - `alphabet` (`&[char]`) - The symbols the input may use.
+/// - `input` (`&str`) - The word to read.
+///
+/// # Returns
+///
+/// - `Result<SimulationTrace, SimError>` - The trace, or why `input` cannot be simulated.
+pub fn build_nfa_trace(
+    nfa: &Nfa,
+    alphabet: &[char],
+    input: &str,
+) -> Result<SimulationTrace, SimError> {
+    if nfa.states.is_empty() {
+        return Err(SimError::EmptyAutomaton);
+    }
+    sim::check_input(alphabet, input)?;
     let symbols: Vec<char> = input.chars().collect();
-    let mut steps = Vec::with_capacity(symbols.len() + 1);
 
-    let mut current: HashSet<StateId> = HashSet::new();
+    let mut current = StateSet::new(nfa.states.len());
     current.insert(nfa.start);
 
     // Track initial epsilon transitions
@@ -212,113 +586,252 @@ pub fn build_nfa_trace(nfa: &Nfa, input: &str) -> SimulationTrace {
         }
     }
 
-    let initial_accepting = current.iter().any(|state| nfa.accepts.contains(state));
-    steps.push(SimulationStep::new(
-        0,
-        None,
-        current.clone(),
-        initial_eps_edges,
-        initial_accepting,
-    ));
-
-    for (idx, symbol) in symbols.iter().enumerate() {
-        let mut traversed = HashSet::new();
-
-        // Track symbol transitions
-        for state in &current {
-            for transition in nfa.transitions(*state) {
-                if transition.label == EdgeLabel::Sym(*symbol) {
-                    traversed.insert(EdgeHighlight::new(
-                        *state,
-                        transition.to,
-                        EdgeLabel::Sym(*symbol),
-                    ));
-                }
-            }
-        }
+    let initial_accepting = nfa.accepts.iter().any(|&state| current.contains(state));
+    let initial = SimulationStep::new(0, None, current, initial_eps_edges, initial_accepting);
 
-        let moved = sim::move_on(&current, *symbol, nfa);
-
-        // Track epsilon transitions after move
-        let mut next = moved.clone();
-        let mut stack: Vec<StateId> = moved.into_iter().collect();
-        while let Some(state) = stack.pop() {
-            for transition in nfa.transitions(state) {
-                if transition.label == EdgeLabel::Eps && next.insert(transition.to) {
-                    traversed.insert(EdgeHighlight::new(state, transition.to, EdgeLabel::Eps));
-                    stack.push(transition.to);
-                }
-            }
-        }
+    let tail = nfa_trace_tail(nfa, &initial, &symbols);
+    let mut steps = Vec::with_capacity(tail.len() + 1);
+    steps.push(initial);
+    steps.extend(tail);
+    Ok(SimulationTrace::new(steps))
+}
 
-        let accepting = next.iter().any(|state| nfa.accepts.contains(state));
+/// Continues an NFA trace from `from` by consuming `symbols` one at a time.
+///
+/// Stops early once the frontier empties, since no later step can revive it.
+///
+/// # Arguments
+///
+/// - `nfa` (`&Nfa`) - The automaton being simulated.
+/// - `from` (`&SimulationStep`) - The step the new steps follow.
+/// - `symbols` (`&[char]`) - The symbols still to consume.
+///
+/// # Returns
+///
+/// - `Vec<SimulationStep>` - The steps after `from`, indexed from `from.index + 1`.
+#[must_use]
+pub fn nfa_trace_tail(nfa: &Nfa, from: &SimulationStep, symbols: &[char]) -> Vec<SimulationStep> {
+    let mut steps = Vec::with_capacity(symbols.len());
+    let mut current = from.active_states.clone();
 
-        steps.push(SimulationStep::new(
-            idx + 1,
-            Some(*symbol),
-            next.clone(),
-            traversed,
-            accepting,
-        ));
-        current = next;
+    for (offset, symbol) in symbols.iter().enumerate() {
+        let step = nfa_step(nfa, &current, from.index + offset + 1, *symbol);
+        current = step.active_states.clone();
+        steps.push(step);
 
-        if current.is_empty() && idx + 1 < symbols.len() {
+        if current.is_empty() && offset + 1 < symbols.len() {
             break;
         }
     }
 
-    SimulationTrace::new(steps)
+    steps
 }
 
-/// Builds a simulation trace for a DFA using the deterministic transition table.
+/// Computes the NFA step reached by consuming `symbol` from the `current` frontier.
+///
+/// The trace builder uses this for every symbol; the UI also uses it to preview
+/// a transition without advancing the trace.
+///
+/// # Arguments
+///
+/// - `nfa` (`&Nfa`) - The automaton being simulated.
+/// - `current` (`&StateSet`) - The ε-closed frontier before the step.
+/// - `index` (`usize`) - Index to give the resulting step.
+/// - `symbol` (`char`) - The symbol to consume.
+///
+/// # Returns
+///
+/// - `SimulationStep` - The ε-closed frontier after the step and the edges taken to reach it.
 #[must_use]
-pub fn build_dfa_trace(dfa: &Dfa, alphabet: &[char], input: &str) -> SimulationTrace {
-    let symbols: Vec<char> = input.chars().collect();
-    let mut steps = Vec::with_capacity(symbols.len() + 1);
-    let mut current = Some(dfa.start);
+pub fn nfa_step(nfa: &Nfa, current: &StateSet, index: usize, symbol: char) -> SimulationStep {
+    let mut traversed = HashSet::new();
 
-    let mut initial = HashSet::new();
-    initial.insert(dfa.start);
-    let initial_accepting = initial.iter().any(|state| dfa.accepts.contains(state));
-    steps.push(SimulationStep::new(
-        0,
-        None,
-        initial,
-        HashSet::new(),
-        initial_accepting,
-    ));
-
-    for (idx, symbol) in symbols.iter().enumerate() {
-        let mut traversed = HashSet::new();
-
-        if let Some(state) = current {
-            if let Some(symbol_idx) = alphabet.iter().position(|&candidate| candidate == *symbol) {
-                let next = dfa.trans[state as usize][symbol_idx];
-                traversed.insert(EdgeHighlight::new(state, next, EdgeLabel::Sym(*symbol)));
-                current = Some(next);
-            } else {
-                current = None;
+    // Track symbol transitions
+    for state in current.iter() {
+        for transition in nfa.transitions(state) {
+            if transition.label == EdgeLabel::Sym(symbol) {
+                traversed.insert(EdgeHighlight::new(
+                    state,
+                    transition.to,
+                    EdgeLabel::Sym(symbol),
+                ));
             }
         }
+    }
+
+    let moved = sim::move_on(current, symbol, nfa);
 
-        let mut active = HashSet::new();
-        if let Some(state) = current {
-            active.insert(state);
+    // Track epsilon transitions after move
+    let mut next = moved.clone();
+    let mut stack: Vec<StateId> = moved.iter().collect();
+    while let Some(state) = stack.pop() {
+        for transition in nfa.transitions(state) {
+            if transition.label == EdgeLabel::Eps && next.insert(transition.to) {
+                traversed.insert(EdgeHighlight::new(state, transition.to, EdgeLabel::Eps));
+                stack.push(transition.to);
+            }
         }
-        let accepting = active.iter().any(|state| dfa.accepts.contains(state));
+    }
+
+    let accepting = nfa.accepts.iter().any(|&state| next.contains(state));
+    SimulationStep::new(index, Some(symbol), next, traversed, accepting)
+}
+
+/// Builds a simulation trace for a DFA using the deterministic transition table.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton to simulate; the input may use its alphabet.
+/// - `input` (`&str`) - The word to read.
+///
+/// # Returns
+///
+/// - `Result<SimulationTrace, SimError>` - The trace, or why `input` cannot be simulated.
+pub fn build_dfa_trace(dfa: &Dfa, input: &str) -> Result<SimulationTrace, SimError> {
+    if dfa.states.is_empty() {
+        return Err(SimError::EmptyAutomaton);
+    }
+    sim::check_input(&dfa.alphabet, input)?;
+    let symbols: Vec<char> = input.chars().collect();
+
+    let mut initial = StateSet::new(dfa.states.len());
+    initial.insert(dfa.start);
+    let initial_accepting = dfa.accepts.contains(&dfa.start);
+    let initial = SimulationStep::new(0, None, initial, HashSet::new(), initial_accepting);
+
+    let tail = dfa_trace_tail(dfa, &initial, &symbols);
+    let mut steps = Vec::with_capacity(tail.len() + 1);
+    steps.push(initial);
+    steps.extend(tail);
+    Ok(SimulationTrace::new(steps))
+}
+
+/// Continues a DFA trace from `from` by consuming `symbols` one at a time.
+///
+/// Stops early once the run dies on a symbol outside the alphabet.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton being simulated.
+/// - `from` (`&SimulationStep`) - The step the new steps follow.
+/// - `symbols` (`&[char]`) - The symbols still to consume.
+///
+/// # Returns
+///
+/// - `Vec<SimulationStep>` - The steps after `from`, indexed from `from.index + 1`.
+#[must_use]
+pub fn dfa_trace_tail(dfa: &Dfa, from: &SimulationStep, symbols: &[char]) -> Vec<SimulationStep> {
+    let mut steps = Vec::with_capacity(symbols.len());
+    let mut current = from.active_states.iter().next();
 
-        steps.push(SimulationStep::new(
-            idx + 1,
-            Some(*symbol),
-            active,
-            traversed,
-            accepting,
-        ));
+    for (offset, symbol) in symbols.iter().enumerate() {
+        let step = dfa_step(dfa, current, from.index + offset + 1, *symbol);
+        current = step.active_states.iter().next();
+        steps.push(step);
 
-        if current.is_none() && idx + 1 < symbols.len() {
+        if current.is_none() && offset + 1 < symbols.len() {
             break;
         }
     }
 
-    SimulationTrace::new(steps)
+    steps
+}
+
+/// Computes the DFA step reached by consuming `symbol` from `current`.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton being simulated.
+/// - `current` (`Option<StateId>`) - The state before the step, or `None` once the run died.
+/// - `index` (`usize`) - Index to give the resulting step.
+/// - `symbol` (`char`) - The symbol to consume.
+///
+/// # Returns
+///
+/// - `SimulationStep` - The state after the step and the edge taken to reach it.
+#[must_use]
+pub fn dfa_step(dfa: &Dfa, current: Option<StateId>, index: usize, symbol: char) -> SimulationStep {
+    let mut traversed = HashSet::new();
+    let mut active = StateSet::new(dfa.states.len());
+
+    if let Some(state) = current
+        && let Some(next) = dfa.step(state, symbol)
+    {
+        traversed.insert(EdgeHighlight::new(state, next, EdgeLabel::Sym(symbol)));
+        active.insert(next);
+    }
+
+    let accepting = active.iter().any(|state| dfa.accepts.contains(&state));
+    SimulationStep::new(index, Some(symbol), active, traversed, accepting)
+}
+
+/// Finds which suffixes of `input` are accepted by reading it backward once.
+///
+/// The reverse of `dfa` is determinized and fed the input right to left.
+/// After consuming the last `k` symbols it is in an accepting state exactly
+/// when those `k` symbols, read forward, form a word of the language.
+#[must_use]
+pub fn build_suffix_matches(dfa: &Dfa, input: &str) -> Vec<SuffixMatch> {
+    let reverse = dfa::determinize(&dfa.reverse());
+    let symbols: Vec<char> = input.chars().collect();
+    let mut matches = Vec::with_capacity(symbols.len() + 1);
+
+    let mut current = Some(reverse.start);
+    matches.push(SuffixMatch {
+        start: symbols.len(),
+        accepted: reverse.accepts.contains(&reverse.start),
+    });
+    for (start, symbol) in symbols.iter().enumerate().rev() {
+        current = current.and_then(|state| reverse.step(state, *symbol));
+        matches.push(SuffixMatch {
+            start,
+            accepted: current.is_some_and(|state| reverse.accepts.contains(&state)),
+        });
+    }
+
+    matches.reverse();
+    matches
+}
+
+/// Runs `input` through the NFA, DFA and minimized DFA and lines up their steps.
+///
+/// # Arguments
+///
+/// - `nfa` (`&Nfa`) - The Thompson NFA.
+/// - `alphabet` (`&[char]`) - The symbols the input may use.
+/// - `dfa` (`&Dfa`) - The determinized NFA.
+/// - `min_dfa` (`&Dfa`) - The minimized DFA.
+/// - `input` (`&str`) - The word to read.
+///
+/// # Returns
+///
+/// - `Result<EngineComparison, SimError>` - One row per prefix of `input`,
+///   including the empty prefix, or why `input` cannot be simulated.
+pub fn build_engine_comparison(
+    nfa: &Nfa,
+    alphabet: &[char],
+    dfa: &Dfa,
+    min_dfa: &Dfa,
+    input: &str,
+) -> Result<EngineComparison, SimError> {
+    let runs = [
+        trace::nfa_trace(nfa, alphabet, input)?,
+        trace::dfa_trace(dfa, input)?,
+        trace::dfa_trace(min_dfa, input)?,
+    ];
+    let symbols: Vec<char> = input.chars().collect();
+
+    let rows = (0..=symbols.len())
+        .map(|index| {
+            let steps = runs.each_ref().map(|run| run.steps.get(index));
+            ComparisonRow {
+                index,
+                consumed: index.checked_sub(1).map(|i| symbols[i]),
+                states: steps.map(|step| step.map(|step| step.active_states.clone())),
+                accepted: steps.map(|step| step.is_some_and(|step| step.accepted)),
+            }
+        })
+        .collect();
+
+    Ok(EngineComparison { rows })
 }
@@ -1,16 +1,32 @@
-use iced::widget::pane_grid::{self, Axis};
-use iced::{Point, Vector};
-use regviz_core::core::automaton::StateId;
-use regviz_core::{core::BuildArtifacts, errors::BuildError};
-use std::collections::HashMap;
-
-use super::constants::DEFAULT_ZOOM_FACTOR;
-use super::message::ViewMode;
-use super::simulation::SimulationState;
-use crate::app::theme::AppTheme;
-use crate::graph::BoxVisibility;
+use iced::widget::pane_grid;
+use iced::{Point, Vector, theme, window};
+use regviz_core::core::BuildArtifacts;
+use regviz_core::core::automaton::{BoxId, BoxKind, EpsilonGlyph, StateId};
+use regviz_core::core::dfa::Dfa;
+use regviz_core::core::diagnostics::Warning;
+use regviz_core::core::min;
+use regviz_core::core::nfa::ConstructionStep;
+use regviz_core::core::parser::ParseEvent;
+use regviz_core::errors::{AlphabetError, BuildError, SimError};
+use std::collections::{HashMap, HashSet};
 
-const PANEL_SPLIT_RATIO: f32 = 0.35;
+use super::constants::{DEFAULT_ZOOM_FACTOR, ZOOM_STEP};
+use super::construction::Replay;
+use super::edit_diff::EditDiff;
+use super::exercise::ExerciseState;
+use super::i18n::{Key, Locale};
+use super::layout::PaneLayout;
+use super::message::{
+    CanvasElement, DeltaFormat, StateLayer, StateNumbering, ThemePreference, ViewMode,
+};
+use super::operations::OperationStack;
+use super::simulation::{SimulationState, SimulationTarget};
+use super::tutorial::Tutorial;
+use super::workbench::WorkbenchState;
+use super::workspace::Tabs;
+use crate::app::theme::AppTheme;
+use crate::graph::layout::NfaLayoutStrategy;
+use crate::graph::{BoxStyles, BoxVisibility, ExportQuality, LayoutConfig, TransitionGrouping};
 
 /// Identifiers for content in each pane of the `PaneGrid`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -26,7 +42,33 @@ pub struct ViewData {
     /// Current zoom level for visualizations (1.0 = fit to screen).
     pub zoom_factor: f32,
     /// Manual per-node positions for the view
-    pub pinned_node_positions: HashMap<StateId, iced::Point>,
+    pub pinned_node_positions: HashMap<StateId, regviz_layout::Point>,
+    /// State picked by clicking a node, shown in the inspector.
+    pub selected_state: Option<StateId>,
+    /// Transition picked by clicking an edge, keyed by its endpoints.
+    pub selected_edge: Option<(StateId, StateId)>,
+    /// States marked around the selected state, shown as a halo on the canvas.
+    pub state_layer: Option<StateLayer>,
+    /// States matching the state selected in the other of the DFA and
+    /// minimized DFA views, through the merges made by minimization.
+    pub linked_states: Vec<StateId>,
+    /// Custom state names keyed by access word, so they survive rebuilding the automaton.
+    pub state_names: HashMap<String, String>,
+    /// Context menu open on the canvas, if any.
+    pub context_menu: Option<ContextMenu>,
+    /// Bounding boxes the user folded into super-nodes.
+    pub collapsed_boxes: HashSet<BoxId>,
+    /// Cursor position over the canvas in layout coordinates, if it is over it.
+    pub cursor_position: Option<Point>,
+}
+
+/// Menu of actions on a canvas element, opened by right-clicking it.
+#[derive(Debug, Clone, Copy)]
+pub struct ContextMenu {
+    /// The element the actions apply to.
+    pub element: CanvasElement,
+    /// Where the menu opens, relative to the canvas' top-left corner.
+    pub position: Point,
 }
 
 impl Default for ViewData {
@@ -35,6 +77,14 @@ impl Default for ViewData {
             pan_offset: Vector::ZERO,
             zoom_factor: DEFAULT_ZOOM_FACTOR,
             pinned_node_positions: HashMap::new(),
+            selected_state: None,
+            selected_edge: None,
+            state_layer: None,
+            linked_states: Vec::new(),
+            state_names: HashMap::new(),
+            context_menu: None,
+            collapsed_boxes: HashSet::new(),
+            cursor_position: None,
         }
     }
 }
@@ -43,7 +93,7 @@ pub struct ViewState {
     /// Currently active visualization mode.
     pub mode: ViewMode,
     /// Per-viewmode data.
-    data: [ViewData; 4], // One for each ViewMode
+    data: [ViewData; 9], // One for each ViewMode
 }
 
 impl Default for ViewState {
@@ -55,6 +105,11 @@ impl Default for ViewState {
                 ViewData::default(),
                 ViewData::default(),
                 ViewData::default(),
+                ViewData::default(),
+                ViewData::default(),
+                ViewData::default(),
+                ViewData::default(),
+                ViewData::default(),
             ],
         }
     }
@@ -63,11 +118,21 @@ impl Default for ViewState {
 impl ViewState {
     /// Gets the index in the data array corresponding to the current view mode.
     fn index(&self) -> usize {
-        match self.mode {
+        Self::index_of(self.mode)
+    }
+
+    /// Gets the index in the data array corresponding to `mode`.
+    fn index_of(mode: ViewMode) -> usize {
+        match mode {
             ViewMode::Ast => 0,
             ViewMode::Nfa => 1,
             ViewMode::Dfa => 2,
             ViewMode::MinDfa => 3,
+            ViewMode::Analysis => 4,
+            ViewMode::Operations => 5,
+            ViewMode::Workbench => 6,
+            ViewMode::Exercise => 7,
+            ViewMode::Definition => 8,
         }
     }
 
@@ -80,6 +145,30 @@ impl ViewState {
     pub fn data(&self) -> &ViewData {
         &self.data[self.index()]
     }
+
+    /// Gets a mutable reference to the data of any view.
+    pub fn data_for_mut(&mut self, mode: ViewMode) -> &mut ViewData {
+        &mut self.data[Self::index_of(mode)]
+    }
+
+    /// Gets an immutable reference to the data of any view.
+    pub fn data_for(&self, mode: ViewMode) -> &ViewData {
+        &self.data[Self::index_of(mode)]
+    }
+
+    /// Clears the selected state and transition in every view, e.g. after the automata are rebuilt.
+    ///
+    /// Context menus and collapsed boxes refer to elements of the old automata, so they go too.
+    pub fn clear_selection(&mut self) {
+        for data in &mut self.data {
+            data.selected_state = None;
+            data.selected_edge = None;
+            data.state_layer = None;
+            data.linked_states.clear();
+            data.context_menu = None;
+            data.collapsed_boxes.clear();
+        }
+    }
 }
 
 /// Main application state.
@@ -93,18 +182,149 @@ pub struct App {
     /// Successfully built AST, NFA, and alphabet, if available.
     pub build_artifacts: Option<BuildArtifacts>,
 
+    /// Alphabet typed by the user; empty to infer it from the regex.
+    pub declared_alphabet: String,
+
+    /// Why the declared alphabet was ignored, if the regex uses other symbols.
+    pub alphabet_error: Option<AlphabetError>,
+
+    /// Suspicious constructs in the regex, which still built.
+    pub warnings: Vec<Warning>,
+
     /// Controls which bounding boxes are visible in NFA view.
     pub box_visibility: BoxVisibility,
 
+    /// How each kind of bounding box is drawn in NFA view.
+    pub box_styles: BoxStyles,
+
+    /// Kind of bounding box whose style the controls are editing.
+    pub styled_box_kind: BoxKind,
+
+    /// Whether nested bounding boxes fade with their depth.
+    pub dim_nested_boxes: bool,
+
+    /// Depth of the deepest NFA boxes laid out; deeper structure is folded
+    /// into super-nodes. `None` lays out every level.
+    pub collapse_depth: Option<usize>,
+
+    /// Spacing the automata and the parse tree are laid out with.
+    pub layout_config: LayoutConfig,
+
+    /// Layout of the NFA view, flowing in the direction chosen by the user.
+    pub nfa_layout: NfaLayoutStrategy,
+
+    /// Whether the layout tuning sliders are expanded.
+    pub show_layout_tuning: bool,
+
+    /// Whether automaton states are tinted by strongly connected component.
+    pub show_scc_overlay: bool,
+
+    /// Whether automaton states and transitions are colored by how many
+    /// transitions touch them.
+    pub show_heatmap: bool,
+
+    /// Whether the DFA canvas highlights a lasso proving the language infinite.
+    pub show_infinite_witness: bool,
+
+    /// Language the UI is shown in.
+    pub locale: Locale,
+
+    /// Whether the UI is light, dark, or follows the system preference.
+    pub theme_preference: ThemePreference,
+
+    /// Light or dark preference last reported by the system.
+    pub system_theme: theme::Mode,
+
+    /// Whether the high-contrast theme replaces the light or dark one.
+    pub high_contrast: bool,
+
+    /// Whether automaton views list states and transitions as text instead of drawing them.
+    pub show_text_view: bool,
+
+    /// Whether the syntax reference panel is expanded.
+    pub show_syntax_reference: bool,
+
+    /// Whether the token stream inspector is expanded.
+    pub show_tokens: bool,
+
+    /// Token picked in the inspector, by its position in the stream.
+    pub selected_token: Option<usize>,
+
+    /// Search text filtering the syntax reference.
+    pub syntax_query: String,
+
+    /// Search text highlighting matching states and transitions on the canvas.
+    pub graph_query: String,
+
+    /// Glyph used to draw the empty string throughout the UI.
+    pub epsilon_glyph: EpsilonGlyph,
+
+    /// Zoom change per scroll tick.
+    pub zoom_step: f32,
+
+    /// How DFA states are numbered on the canvas and in exports.
+    pub state_numbering: StateNumbering,
+
+    /// How DFA transitions between the same two states are drawn.
+    pub transition_grouping: TransitionGrouping,
+
+    /// Sizes and pixel density of figures exported from the canvas.
+    pub export_quality: ExportQuality,
+
+    /// View last copied to the clipboard as SVG, if any.
+    pub svg_exported: Option<ViewMode>,
+
+    /// How the formal definition writes its transition function.
+    pub delta_format: DeltaFormat,
+
+    /// Automaton whose formal definition was last copied as LaTeX, if any.
+    pub definition_copied: Option<SimulationTarget>,
+
+    /// Operations applied to the DFA from the operations panel.
+    pub operations: OperationStack,
+
+    /// Two-regex workspace for Boolean operations on languages.
+    pub workbench: WorkbenchState,
+
+    /// Exercise mode: a target language and the user's attempt at it.
+    pub exercise: ExerciseState,
+
+    /// Guided walkthrough, shown on launch until finished or dismissed.
+    pub tutorial: Tutorial,
+
     /// Interactive simulation state for stepping through input strings.
     pub simulation: SimulationState,
 
     /// Validation error for the simulation input, if any.
-    pub simulation_error: Option<String>,
+    pub simulation_error: Option<SimError>,
+
+    /// Fragment-by-fragment replay of the NFA's construction, while one is shown.
+    pub construction: Option<Replay<ConstructionStep>>,
+
+    /// Step-by-step replay of how the parser built the AST, while one is shown.
+    pub parse_trace: Option<Replay<ParseEvent>>,
+
+    /// How the last edit of the regex changed its DFAs, while it is flashed.
+    pub edit_diff: EditDiff,
+
+    /// Open workspace tabs; the active one's regex and views are the fields above.
+    pub tabs: Tabs,
+
+    /// Chosen pane arrangement and the split ratios remembered for it.
+    pub pane_layout: PaneLayout,
 
-    /// Pane grid state for left (controls) and right (visualization) panes.
+    /// Pane grid state for the controls and visualization panes, built from `pane_layout`.
     pub panes: pane_grid::State<PaneContent>,
 
+    /// Whether the controls drawer covers the visualization on narrow viewports.
+    pub controls_drawer_open: bool,
+
+    /// The window showing the controls, when running with several windows.
+    pub main_window: Option<window::Id>,
+
+    /// Views opened in windows of their own, keyed by window.
+    pub detached: HashMap<window::Id, ViewMode>,
+
     pub(crate) theme: AppTheme,
 
     pub view_state: ViewState,
@@ -115,22 +335,60 @@ pub struct App {
 
 impl Default for App {
     fn default() -> Self {
-        // Initialize two-pane layout: left controls | right visualization
-        let (mut panes, left) = pane_grid::State::new(PaneContent::Controls);
-        let (_pane, split) = panes
-            .split(Axis::Vertical, left, PaneContent::Visualization)
-            .expect("split pane should succeed");
-
-        panes.resize(split, PANEL_SPLIT_RATIO);
+        let pane_layout = PaneLayout::default();
+        let panes = pane_layout.panes();
 
         Self {
             input: String::new(),
             error: None,
             build_artifacts: None,
+            declared_alphabet: String::new(),
+            alphabet_error: None,
+            warnings: Vec::new(),
             box_visibility: BoxVisibility::minimized(),
+            box_styles: BoxStyles::default(),
+            styled_box_kind: BoxKind::Literal,
+            dim_nested_boxes: false,
+            collapse_depth: None,
+            layout_config: LayoutConfig::default(),
+            nfa_layout: NfaLayoutStrategy::default(),
+            show_layout_tuning: false,
+            show_scc_overlay: false,
+            show_heatmap: false,
+            show_infinite_witness: false,
+            locale: Locale::default(),
+            theme_preference: ThemePreference::default(),
+            system_theme: theme::Mode::None,
+            high_contrast: false,
+            show_text_view: false,
+            show_syntax_reference: false,
+            show_tokens: false,
+            selected_token: None,
+            syntax_query: String::new(),
+            graph_query: String::new(),
+            epsilon_glyph: EpsilonGlyph::default(),
+            zoom_step: ZOOM_STEP,
+            state_numbering: StateNumbering::default(),
+            transition_grouping: TransitionGrouping::default(),
+            export_quality: ExportQuality::default(),
+            svg_exported: None,
+            delta_format: DeltaFormat::default(),
+            definition_copied: None,
+            operations: OperationStack::default(),
+            workbench: WorkbenchState::default(),
+            exercise: ExerciseState::default(),
+            tutorial: Tutorial::started(),
             simulation: SimulationState::default(),
             simulation_error: None,
+            construction: None,
+            parse_trace: None,
+            edit_diff: EditDiff::default(),
+            tabs: Tabs::default(),
+            pane_layout,
             panes,
+            controls_drawer_open: false,
+            main_window: None,
+            detached: HashMap::new(),
             theme: AppTheme::Dark,
             view_state: ViewState::default(),
             last_cursor_position: None,
@@ -139,6 +397,21 @@ impl Default for App {
 }
 
 impl App {
+    /// Returns the text for `key` in the current locale.
+    pub fn tr(&self, key: Key) -> &'static str {
+        self.locale.text(key)
+    }
+
+    /// Returns the text for `key` in the current locale with its placeholders filled.
+    pub fn tr_fmt(&self, key: Key, args: &[&dyn std::fmt::Display]) -> String {
+        self.locale.format(key, args)
+    }
+
+    /// Returns the window title in the current locale.
+    pub fn window_title(&self) -> String {
+        format!("RegViz - {}", self.tr(Key::AppTitle))
+    }
+
     /// Gets an immutable reference to the current view's data.
     pub fn view_data(&self) -> &ViewData {
         self.view_state.data()
@@ -158,4 +431,74 @@ impl App {
     pub fn set_view_mode(&mut self, view_mode: ViewMode) {
         self.view_state.mode = view_mode;
     }
+
+    /// Returns the symbols of the declared alphabet, ignoring whitespace and commas.
+    pub fn declared_symbols(&self) -> Vec<char> {
+        self.declared_alphabet
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != ',')
+            .collect()
+    }
+
+    /// Returns the DFA currently shown on the canvas, mirroring the visualization fallbacks.
+    pub fn inspected_dfa(&self) -> Option<&Dfa> {
+        let artifacts = self.build_artifacts.as_ref()?;
+        match self.view_mode() {
+            ViewMode::Dfa => artifacts.dfa.as_ref().or(artifacts.min_dfa.as_ref()),
+            ViewMode::MinDfa => artifacts.min_dfa.as_ref().or(artifacts.dfa.as_ref()),
+            ViewMode::Operations => self.operations.current(),
+            ViewMode::Workbench => self.workbench.result.as_ref().map(|result| &result.dfa),
+            ViewMode::Exercise => self.exercise.answer_dfa.as_ref(),
+            ViewMode::Ast | ViewMode::Nfa | ViewMode::Analysis | ViewMode::Definition => None,
+        }
+    }
+
+    /// Returns the names shown for the states of `dfa` in the `mode` view.
+    ///
+    /// Custom names from the inspector win over the active numbering. States
+    /// drawn with their original id are left out.
+    ///
+    /// # Arguments
+    ///
+    /// - `mode` (`ViewMode`) - The view whose custom names to use.
+    /// - `dfa` (`&Dfa`) - The automaton shown in that view.
+    ///
+    /// # Returns
+    ///
+    /// - `HashMap<StateId, String>` - Display names keyed by state id.
+    pub fn state_labels(&self, mode: ViewMode, dfa: &Dfa) -> HashMap<StateId, String> {
+        let words = dfa.access_words();
+        let mut labels: HashMap<StateId, String> = match self.state_numbering {
+            StateNumbering::Original => HashMap::new(),
+            StateNumbering::Bfs => {
+                let mut order: Vec<StateId> = words.iter().map(|(state, _)| *state).collect();
+                // Unreachable states keep counting after the reachable ones.
+                let unreachable: Vec<StateId> = dfa
+                    .states
+                    .iter()
+                    .copied()
+                    .filter(|state| !order.contains(state))
+                    .collect();
+                order.extend(unreachable);
+                order
+                    .into_iter()
+                    .enumerate()
+                    .map(|(number, state)| (state, number.to_string()))
+                    .collect()
+            }
+            StateNumbering::NerodeClass => min::nerode_classes(dfa)
+                .into_iter()
+                .enumerate()
+                .map(|(state, class)| (state as StateId, format!("[{class}]")))
+                .collect(),
+        };
+
+        let names = &self.view_state.data_for(mode).state_names;
+        for (state, word) in &words {
+            if let Some(name) = names.get(word) {
+                labels.insert(*state, name.clone());
+            }
+        }
+        labels
+    }
 }
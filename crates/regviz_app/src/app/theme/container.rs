@@ -9,6 +9,8 @@ pub enum ContainerClass {
     Rounded,
     RoundedLarge,
     FilledWith(iced::Color),
+    /// Region highlighted by the tutorial.
+    Spotlight,
 }
 
 impl<'a> From<container::StyleFn<'a, AppTheme>> for ContainerClass {
@@ -43,6 +45,14 @@ impl container::Catalog for AppTheme {
                 },
             ),
             ContainerClass::FilledWith(color) => (Background::Color(*color), Border::default()),
+            ContainerClass::Spotlight => (
+                Background::Color(AppTheme::with_alpha(self.accent(), 0.08)),
+                Border {
+                    radius: iced::border::Radius::new(10.0),
+                    width: 2.0,
+                    color: self.accent(),
+                },
+            ),
         };
 
         container::Style {
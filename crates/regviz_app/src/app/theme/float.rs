@@ -0,0 +1,20 @@
+use super::AppTheme;
+use iced::widget::float;
+use iced::{Color, Shadow, Vector};
+
+impl float::Catalog for AppTheme {
+    type Class<'a> = ();
+
+    fn default<'a>() -> Self::Class<'a> {}
+
+    fn style(&self, _class: &Self::Class<'_>) -> float::Style {
+        float::Style {
+            shadow: Shadow {
+                color: Color::from_rgba(0.0, 0.0, 0.0, 0.35),
+                offset: Vector::new(0.0, 4.0),
+                blur_radius: 12.0,
+            },
+            shadow_border_radius: 10.0.into(),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 mod button;
 pub mod colors;
 mod container;
+mod float;
 mod pane_grid;
 mod scrollable;
 mod slider;
@@ -10,8 +11,11 @@ mod text_input;
 use crate::app::{
     message::Message,
     theme::colors::{
-        AMBER_500, GRAY_50, GRAY_100, GRAY_200, GRAY_300, GRAY_500, GRAY_950, GREEN_400, GREEN_500,
-        RED_400, RED_500, SKY_500, SKY_800, SLATE_900,
+        AMBER_500, AMBER_600, BLUE_400, BLUE_600, FUCHSIA_400, GRAY_50, GRAY_100, GRAY_200,
+        GRAY_300, GRAY_500, GRAY_600, GRAY_700, GRAY_800, GRAY_900, GRAY_950, GREEN_300, GREEN_400,
+        GREEN_500, GREEN_600, GREEN_700, RED_300, RED_400, RED_500, RED_600, RED_700, SKY_200,
+        SKY_500, SKY_800, SLATE_50, SLATE_100, SLATE_200, SLATE_900, VIOLET_400, VIOLET_600,
+        YELLOW_300,
     },
 };
 use iced::{Color, Element, theme};
@@ -30,7 +34,10 @@ pub type ElementType<'a> = Element<'a, Message, AppTheme>;
 pub enum AppTheme {
     #[default]
     Dark,
-    // TODO: Light theme as well
+    /// Dark text on pale backgrounds.
+    Light,
+    /// Black background, white text and saturated accents for low-vision use.
+    HighContrast,
 }
 
 impl AppTheme {
@@ -44,29 +51,62 @@ impl AppTheme {
         warning: AMBER_500,
         danger: RED_500,
     };
+
+    const LIGHT_COLOR_PALETTE: theme::Palette = theme::Palette {
+        background: SLATE_50,
+        text: GRAY_900,
+        primary: Self::PRIMARY,
+        success: GREEN_600,
+        warning: AMBER_600,
+        danger: RED_600,
+    };
+
+    const HIGH_CONTRAST_PRIMARY: Color = BLUE_600;
+
+    const HIGH_CONTRAST_COLOR_PALETTE: theme::Palette = theme::Palette {
+        background: Color::BLACK,
+        text: Color::WHITE,
+        primary: Self::HIGH_CONTRAST_PRIMARY,
+        success: GREEN_400,
+        warning: YELLOW_300,
+        danger: RED_400,
+    };
 }
 
 impl theme::Base for AppTheme {
-    fn default(_preference: theme::Mode) -> Self {
-        // you could return Light or Dark depending on preference.
-        // we only support Dark for now:
-        Self::Dark
+    fn default(preference: theme::Mode) -> Self {
+        Self::for_mode(preference)
     }
 
     fn mode(&self) -> theme::Mode {
-        theme::Mode::Dark
+        match self {
+            AppTheme::Light => theme::Mode::Light,
+            AppTheme::Dark | AppTheme::HighContrast => theme::Mode::Dark,
+        }
     }
 
     fn base(&self) -> iced::theme::Style {
-        iced::theme::Style {
-            background_color: SLATE_900,
-            text_color: GRAY_50,
+        match self {
+            AppTheme::Dark => iced::theme::Style {
+                background_color: SLATE_900,
+                text_color: GRAY_50,
+            },
+            AppTheme::Light => iced::theme::Style {
+                background_color: SLATE_50,
+                text_color: GRAY_900,
+            },
+            AppTheme::HighContrast => iced::theme::Style {
+                background_color: Color::BLACK,
+                text_color: Color::WHITE,
+            },
         }
     }
 
     fn palette(&self) -> Option<theme::Palette> {
         match self {
             AppTheme::Dark => Some(Self::DARK_COLOR_PALETTE),
+            AppTheme::Light => Some(Self::LIGHT_COLOR_PALETTE),
+            AppTheme::HighContrast => Some(Self::HIGH_CONTRAST_COLOR_PALETTE),
         }
     }
 }
@@ -77,27 +117,49 @@ impl From<AppTheme> for iced::Theme {
             AppTheme::Dark => {
                 iced::Theme::custom("REGVIZ_DARK".to_string(), AppTheme::DARK_COLOR_PALETTE)
             }
+            AppTheme::Light => {
+                iced::Theme::custom("REGVIZ_LIGHT".to_string(), AppTheme::LIGHT_COLOR_PALETTE)
+            }
+            AppTheme::HighContrast => iced::Theme::custom(
+                "REGVIZ_HIGH_CONTRAST".to_string(),
+                AppTheme::HIGH_CONTRAST_COLOR_PALETTE,
+            ),
         }
     }
 }
 
 impl AppTheme {
+    /// Returns the light or dark theme matching a system preference, falling
+    /// back to dark when the system has none.
+    pub fn for_mode(mode: theme::Mode) -> Self {
+        match mode {
+            theme::Mode::Light => AppTheme::Light,
+            theme::Mode::Dark | theme::Mode::None => AppTheme::Dark,
+        }
+    }
+
     // Background colors
     pub fn bg_low(&self) -> Color {
         match self {
             AppTheme::Dark => Color::from_rgb(0.075, 0.102, 0.125),
+            AppTheme::Light => Color::WHITE,
+            AppTheme::HighContrast => Color::BLACK,
         }
     }
 
     pub fn bg_mid(&self) -> Color {
         match self {
             AppTheme::Dark => Color::from_rgb(0.125, 0.152, 0.175),
+            AppTheme::Light => SLATE_100,
+            AppTheme::HighContrast => Color::from_rgb(0.05, 0.05, 0.05),
         }
     }
 
     pub fn bg_high(&self) -> Color {
         match self {
             AppTheme::Dark => Color::from_rgb(0.175, 0.202, 0.225),
+            AppTheme::Light => SLATE_200,
+            AppTheme::HighContrast => GRAY_800,
         }
     }
 
@@ -105,24 +167,32 @@ impl AppTheme {
     pub fn text_primary(&self) -> Color {
         match self {
             AppTheme::Dark => GRAY_50,
+            AppTheme::Light => GRAY_900,
+            AppTheme::HighContrast => Color::WHITE,
         }
     }
 
     pub fn text_primary_inverse(&self) -> Color {
         match self {
             AppTheme::Dark => GRAY_950,
+            AppTheme::Light => GRAY_950,
+            AppTheme::HighContrast => Color::BLACK,
         }
     }
 
     pub fn text_secondary(&self) -> Color {
         match self {
             AppTheme::Dark => GRAY_300,
+            AppTheme::Light => GRAY_600,
+            AppTheme::HighContrast => GRAY_100,
         }
     }
 
     pub fn text_dim(&self) -> Color {
         match self {
             AppTheme::Dark => GRAY_200,
+            AppTheme::Light => GRAY_700,
+            AppTheme::HighContrast => GRAY_300,
         }
     }
 
@@ -130,12 +200,16 @@ impl AppTheme {
     pub fn accent(&self) -> Color {
         match self {
             AppTheme::Dark => Self::PRIMARY,
+            AppTheme::Light => Self::PRIMARY,
+            AppTheme::HighContrast => Self::HIGH_CONTRAST_PRIMARY,
         }
     }
 
     pub fn accent_dim(&self) -> Color {
         match self {
             AppTheme::Dark => SKY_800,
+            AppTheme::Light => SKY_200,
+            AppTheme::HighContrast => BLUE_400,
         }
     }
 
@@ -143,48 +217,88 @@ impl AppTheme {
     pub fn graph_node_default(&self) -> Color {
         match self {
             AppTheme::Dark => GRAY_50,
+            AppTheme::Light => Color::WHITE,
+            AppTheme::HighContrast => Color::WHITE,
         }
     }
 
     pub fn graph_node_active(&self) -> Color {
         match self {
             AppTheme::Dark => GREEN_500,
+            AppTheme::Light => GREEN_500,
+            AppTheme::HighContrast => GREEN_400,
         }
     }
 
     pub fn graph_node_rejected(&self) -> Color {
         match self {
             AppTheme::Dark => RED_500,
+            AppTheme::Light => RED_500,
+            AppTheme::HighContrast => RED_400,
         }
     }
 
     pub fn graph_node_outline_default(&self) -> Color {
         match self {
             AppTheme::Dark => GRAY_500,
+            AppTheme::Light => GRAY_600,
+            AppTheme::HighContrast => Color::WHITE,
         }
     }
 
     pub fn graph_node_outline_active(&self) -> Color {
         match self {
             AppTheme::Dark => GREEN_400,
+            AppTheme::Light => GREEN_700,
+            AppTheme::HighContrast => GREEN_300,
         }
     }
 
     pub fn graph_node_outline_rejected(&self) -> Color {
         match self {
             AppTheme::Dark => RED_400,
+            AppTheme::Light => RED_700,
+            AppTheme::HighContrast => RED_300,
+        }
+    }
+
+    pub fn graph_node_layer(&self) -> Color {
+        match self {
+            AppTheme::Dark => VIOLET_400,
+            AppTheme::Light => VIOLET_600,
+            AppTheme::HighContrast => FUCHSIA_400,
+        }
+    }
+
+    pub fn graph_node_linked(&self) -> Color {
+        match self {
+            AppTheme::Dark => AMBER_500,
+            AppTheme::Light => AMBER_600,
+            AppTheme::HighContrast => YELLOW_300,
+        }
+    }
+
+    pub fn graph_node_breakpoint(&self) -> Color {
+        match self {
+            AppTheme::Dark => RED_500,
+            AppTheme::Light => RED_600,
+            AppTheme::HighContrast => RED_400,
         }
     }
 
     pub fn graph_edge_default(&self) -> Color {
         match self {
             AppTheme::Dark => GRAY_200,
+            AppTheme::Light => GRAY_600,
+            AppTheme::HighContrast => Color::WHITE,
         }
     }
 
     pub fn graph_edge_active(&self) -> Color {
         match self {
             AppTheme::Dark => GRAY_100,
+            AppTheme::Light => GRAY_900,
+            AppTheme::HighContrast => YELLOW_300,
         }
     }
 
@@ -192,18 +306,24 @@ impl AppTheme {
     pub fn success(&self) -> Color {
         match self {
             AppTheme::Dark => Self::DARK_COLOR_PALETTE.success,
+            AppTheme::Light => Self::LIGHT_COLOR_PALETTE.success,
+            AppTheme::HighContrast => Self::HIGH_CONTRAST_COLOR_PALETTE.success,
         }
     }
 
     pub fn warning(&self) -> Color {
         match self {
             AppTheme::Dark => Self::DARK_COLOR_PALETTE.warning,
+            AppTheme::Light => Self::LIGHT_COLOR_PALETTE.warning,
+            AppTheme::HighContrast => Self::HIGH_CONTRAST_COLOR_PALETTE.warning,
         }
     }
 
     pub fn error(&self) -> Color {
         match self {
             AppTheme::Dark => Self::DARK_COLOR_PALETTE.danger,
+            AppTheme::Light => Self::LIGHT_COLOR_PALETTE.danger,
+            AppTheme::HighContrast => Self::HIGH_CONTRAST_COLOR_PALETTE.danger,
         }
     }
 
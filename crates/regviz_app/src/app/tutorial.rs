@@ -0,0 +1,115 @@
+use super::i18n::Key;
+
+/// One stop of the guided tutorial, each pointing at a region of the UI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TutorialStep {
+    /// Type a regular expression into the input field.
+    TypeRegex,
+    /// Enter a test string and step through its simulation.
+    StepSimulation,
+    /// Toggle the NFA's bounding boxes.
+    ToggleBoxes,
+    /// Switch the right pane to the DFA view.
+    SwitchToDfa,
+}
+
+impl TutorialStep {
+    /// Every step, in the order the tutorial visits them.
+    pub const ALL: [TutorialStep; 4] = [
+        TutorialStep::TypeRegex,
+        TutorialStep::StepSimulation,
+        TutorialStep::ToggleBoxes,
+        TutorialStep::SwitchToDfa,
+    ];
+
+    /// Short heading shown above the caption.
+    #[must_use]
+    pub fn title(self) -> Key {
+        match self {
+            TutorialStep::TypeRegex => Key::TutorialTypeRegexTitle,
+            TutorialStep::StepSimulation => Key::TutorialStepSimulationTitle,
+            TutorialStep::ToggleBoxes => Key::TutorialToggleBoxesTitle,
+            TutorialStep::SwitchToDfa => Key::TutorialSwitchToDfaTitle,
+        }
+    }
+
+    /// Instructions for the highlighted region.
+    #[must_use]
+    pub fn caption(self) -> Key {
+        match self {
+            TutorialStep::TypeRegex => Key::TutorialTypeRegexCaption,
+            TutorialStep::StepSimulation => Key::TutorialStepSimulationCaption,
+            TutorialStep::ToggleBoxes => Key::TutorialToggleBoxesCaption,
+            TutorialStep::SwitchToDfa => Key::TutorialSwitchToDfaCaption,
+        }
+    }
+
+    /// Position of this step in [`TutorialStep::ALL`].
+    #[must_use]
+    pub fn index(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|&step| step == self)
+            .expect("every step is listed in ALL")
+    }
+}
+
+/// Tutorial state machine: which step is showing, or `None` once dismissed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Tutorial {
+    /// Step currently highlighted.
+    pub step: Option<TutorialStep>,
+}
+
+impl Tutorial {
+    /// A tutorial already showing its first step, as first-time users see it.
+    #[must_use]
+    pub fn started() -> Self {
+        Self {
+            step: Some(TutorialStep::ALL[0]),
+        }
+    }
+
+    /// Returns `true` while the tutorial overlay is visible.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.step.is_some()
+    }
+
+    /// Returns `true` if `step` is the one currently highlighted.
+    #[must_use]
+    pub fn highlights(&self, step: TutorialStep) -> bool {
+        self.step == Some(step)
+    }
+
+    /// Restarts the tutorial from the first step.
+    pub fn start(&mut self) {
+        *self = Self::started();
+    }
+
+    /// Hides the tutorial.
+    pub fn dismiss(&mut self) {
+        self.step = None;
+    }
+
+    /// Moves to the next step, finishing the tutorial after the last one.
+    pub fn advance(&mut self) {
+        self.step = self
+            .step
+            .and_then(|step| TutorialStep::ALL.get(step.index() + 1).copied());
+    }
+
+    /// Moves back one step, staying on the first.
+    pub fn back(&mut self) {
+        if let Some(step) = self.step {
+            self.step = Some(TutorialStep::ALL[step.index().saturating_sub(1)]);
+        }
+    }
+
+    /// Advances when the user has just done what `step` asks for.
+    pub fn complete(&mut self, step: TutorialStep) {
+        if self.highlights(step) {
+            self.advance();
+        }
+    }
+}
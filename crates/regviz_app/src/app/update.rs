@@ -1,15 +1,47 @@
-use std::collections::BTreeSet;
+use std::collections::HashMap;
 
-use crate::app::state::ViewData;
+use crate::app::state::{ContextMenu, ViewData};
 
-use super::constants::{MAX_ZOOM_FACTOR, MIN_ZOOM_FACTOR, ZOOM_STEP};
+use super::constants::{
+    MAX_ACCEPTING_PATHS, MAX_STATE_NAME_LEN, MAX_ZOOM_FACTOR, MAX_ZOOM_STEP, MIN_ZOOM_FACTOR,
+    MIN_ZOOM_STEP, PLAYBACK_INTERVAL, REGEX_INPUT_ID, RENAME_INPUT_ID,
+};
+use super::construction::Replay;
+use super::exercise::EXERCISES;
+use super::i18n::{Key, Locale};
+use super::layout::LayoutPreset;
 use super::message::{
-    InputMessage, Message, PaneGridMessage, RightPaneMode, SimulationMessage, ViewMessage, ViewMode,
+    CanvasElement, ConstructionMessage, DeltaFormat, ExerciseMessage, InputMessage, Message,
+    PaneGridMessage, ParseTraceMessage, RightPaneMode, SimulationMessage, StateFocus, StateLayer,
+    StateNumbering, TabMessage, ThemePreference, TutorialMessage, ViewMessage, ViewMode,
+    WindowMessage, WorkbenchMessage,
+};
+use super::operations::DfaOperation;
+use super::settings::Settings;
+use super::simulation::{
+    SimulationTarget, build_dfa_trace, build_engine_comparison, build_nfa_trace,
+    build_suffix_matches, dfa_step, dfa_trace_tail, nfa_step, nfa_trace_tail,
 };
-use super::simulation::{SimulationTarget, build_dfa_trace, build_nfa_trace};
 use super::state::App;
-use iced::{Point, Task, Vector};
-use regviz_core::core::{dfa, min};
+use super::theme::AppTheme;
+use super::tutorial::TutorialStep;
+use super::view::{export_svg, formal_definition};
+use crate::graph::layout::Orientation;
+use crate::graph::{BoxStyle, ExportQuality, LayoutConfig, ToLayout, TransitionGrouping};
+use iced::keyboard::{self, key::Named};
+use iced::time::Instant;
+use iced::widget::operation;
+use iced::{
+    Event, Point, Subscription, Task, Vector, clipboard, event, system, theme, time, window,
+};
+use regviz_core::core::automaton::{BoxId, BoxKind, EdgeLabel, EpsilonGlyph, StateId};
+use regviz_core::core::lexer::Lexer;
+use regviz_core::core::nfa::Nfa;
+use regviz_core::core::parser::Ast;
+use regviz_core::core::product::BoolOp;
+use regviz_core::core::trace::TraceFormat;
+use regviz_core::core::{dfa, min, sim, to_regex};
+use regviz_core::errors::SimError;
 
 impl App {
     /// Handles incoming messages and updates application state accordingly.
@@ -23,12 +55,29 @@ impl App {
                     self.handle_input_changed(value);
                     ().into()
                 }
+                InputMessage::AlphabetChanged(value) => {
+                    self.handle_alphabet_changed(value);
+                    ().into()
+                }
+                InputMessage::ToggleTokens => {
+                    self.handle_toggle_tokens();
+                    ().into()
+                }
+                InputMessage::SelectToken(index) => self.handle_select_token(index),
+                InputMessage::EditFlashFrame(now) => {
+                    self.handle_edit_flash_frame(now);
+                    ().into()
+                }
             },
             Message::Simulation(sim_msg) => match sim_msg {
                 SimulationMessage::InputChanged(value) => {
                     self.handle_simulation_input_changed(value);
                     ().into()
                 }
+                SimulationMessage::RemainingInputChanged(value) => {
+                    self.handle_remaining_input_changed(value);
+                    ().into()
+                }
                 SimulationMessage::StepForward => {
                     self.handle_simulation_step_forward();
                     ().into()
@@ -40,13 +89,240 @@ impl App {
                 SimulationMessage::Reset => {
                     self.handle_simulation_reset();
                     ().into()
+                }
+                SimulationMessage::TogglePlay => {
+                    self.handle_toggle_play();
+                    ().into()
+                }
+                SimulationMessage::PlayTick => {
+                    self.handle_play_tick();
+                    ().into()
+                }
+                SimulationMessage::MarchFrame(now) => {
+                    self.handle_march_frame(now);
+                    ().into()
+                }
+                SimulationMessage::ToggleBreakpoint(state) => {
+                    self.handle_toggle_breakpoint(state);
+                    ().into()
+                }
+                SimulationMessage::ToggleEdgeBreakpoint(from, to) => {
+                    self.handle_toggle_edge_breakpoint(from, to);
+                    ().into()
+                }
+                SimulationMessage::ToggleComparison => {
+                    self.handle_toggle_comparison();
+                    ().into()
+                }
+                SimulationMessage::CopyTrace(format) => self.handle_copy_trace(format),
+                SimulationMessage::SaveAnimation => {
+                    self.handle_save_animation();
+                    ().into()
+                }
+                SimulationMessage::ToggleSuffixes => {
+                    self.handle_toggle_suffixes();
+                    ().into()
+                }
+                SimulationMessage::ReplayPath(path) => {
+                    self.handle_replay_path(path);
+                    ().into()
+                }
+                SimulationMessage::ReplayForward => {
+                    self.handle_replay_forward();
+                    ().into()
+                }
+                SimulationMessage::ReplayBackward => {
+                    self.handle_replay_backward();
+                    ().into()
                 } // Target switching handled via ViewMessage::SelectRightPaneMode
             },
+            Message::Construction(construction_msg) => match construction_msg {
+                ConstructionMessage::Start => {
+                    self.handle_start_construction();
+                    ().into()
+                }
+                ConstructionMessage::Stop => {
+                    self.handle_stop_construction();
+                    ().into()
+                }
+                ConstructionMessage::StepForward => {
+                    self.handle_construction_step_forward();
+                    ().into()
+                }
+                ConstructionMessage::StepBackward => {
+                    self.handle_construction_step_backward();
+                    ().into()
+                }
+                ConstructionMessage::TogglePlay => {
+                    self.handle_construction_toggle_play();
+                    ().into()
+                }
+                ConstructionMessage::PlayTick => {
+                    self.handle_construction_play_tick();
+                    ().into()
+                }
+            },
+            Message::ParseTrace(trace_msg) => match trace_msg {
+                ParseTraceMessage::Start => {
+                    self.handle_start_parse_trace();
+                    ().into()
+                }
+                ParseTraceMessage::Stop => {
+                    self.handle_stop_parse_trace();
+                    ().into()
+                }
+                ParseTraceMessage::StepForward => {
+                    self.handle_parse_trace_step_forward();
+                    ().into()
+                }
+                ParseTraceMessage::StepBackward => {
+                    self.handle_parse_trace_step_backward();
+                    ().into()
+                }
+                ParseTraceMessage::TogglePlay => {
+                    self.handle_parse_trace_toggle_play();
+                    ().into()
+                }
+                ParseTraceMessage::PlayTick => {
+                    self.handle_parse_trace_play_tick();
+                    ().into()
+                }
+            },
             Message::View(view_msg) => match view_msg {
                 ViewMessage::ToggleBox(kind) => {
                     self.handle_toggle_box(kind);
                     ().into()
                 }
+                ViewMessage::SelectStyledBox(kind) => {
+                    self.handle_select_styled_box(kind);
+                    ().into()
+                }
+                ViewMessage::SetBoxStyle(kind, style) => {
+                    self.handle_set_box_style(kind, style);
+                    ().into()
+                }
+                ViewMessage::ToggleDepthDimming => {
+                    self.handle_toggle_depth_dimming();
+                    ().into()
+                }
+                ViewMessage::SetMaxBoxDepth(max_depth) => {
+                    self.handle_set_max_box_depth(max_depth);
+                    ().into()
+                }
+                ViewMessage::SetCollapseDepth(depth) => {
+                    self.handle_set_collapse_depth(depth);
+                    ().into()
+                }
+                ViewMessage::ToggleLayoutTuning => {
+                    self.handle_toggle_layout_tuning();
+                    ().into()
+                }
+                ViewMessage::SetLayoutConfig(config) => {
+                    self.handle_set_layout_config(config);
+                    ().into()
+                }
+                ViewMessage::SetNfaOrientation(orientation) => {
+                    self.handle_set_nfa_orientation(orientation);
+                    ().into()
+                }
+                ViewMessage::ToggleSccOverlay => {
+                    self.handle_toggle_scc_overlay();
+                    ().into()
+                }
+                ViewMessage::ToggleHeatmap => {
+                    self.handle_toggle_heatmap();
+                    ().into()
+                }
+                ViewMessage::ToggleInfiniteWitness => {
+                    self.handle_toggle_infinite_witness();
+                    ().into()
+                }
+                ViewMessage::SetLocale(locale) => {
+                    self.handle_set_locale(locale);
+                    ().into()
+                }
+                ViewMessage::SetThemePreference(preference) => {
+                    self.handle_set_theme_preference(preference);
+                    ().into()
+                }
+                ViewMessage::SystemThemeChanged(mode) => {
+                    self.handle_system_theme_changed(mode);
+                    ().into()
+                }
+                ViewMessage::ToggleHighContrast => {
+                    self.handle_toggle_high_contrast();
+                    ().into()
+                }
+                ViewMessage::SetZoomStep(step) => {
+                    self.handle_set_zoom_step(step);
+                    ().into()
+                }
+                ViewMessage::ResetSettings => {
+                    self.handle_reset_settings();
+                    ().into()
+                }
+                ViewMessage::ToggleTextView => {
+                    self.handle_toggle_text_view();
+                    ().into()
+                }
+                ViewMessage::FocusState(focus) => {
+                    self.handle_focus_state(focus);
+                    ().into()
+                }
+                ViewMessage::ToggleSyntaxReference => {
+                    self.handle_toggle_syntax_reference();
+                    ().into()
+                }
+                ViewMessage::SyntaxQueryChanged(query) => {
+                    self.handle_syntax_query_changed(query);
+                    ().into()
+                }
+                ViewMessage::GraphQueryChanged(query) => {
+                    self.handle_graph_query_changed(query);
+                    ().into()
+                }
+                ViewMessage::SetExportQuality(quality) => {
+                    self.handle_set_export_quality(quality);
+                    ().into()
+                }
+                ViewMessage::CopySvg => self.handle_copy_svg(),
+                ViewMessage::SetDefinitionTarget(target) => {
+                    self.handle_set_definition_target(target);
+                    ().into()
+                }
+                ViewMessage::SetDeltaFormat(format) => {
+                    self.handle_set_delta_format(format);
+                    ().into()
+                }
+                ViewMessage::CopyDefinitionLatex => self.handle_copy_definition_latex(),
+                ViewMessage::SetEpsilonGlyph(glyph) => {
+                    self.handle_set_epsilon_glyph(glyph);
+                    ().into()
+                }
+                ViewMessage::ApplyOperation(operation) => {
+                    self.handle_apply_operation(operation);
+                    ().into()
+                }
+                ViewMessage::UndoOperation => {
+                    self.handle_undo_operation();
+                    ().into()
+                }
+                ViewMessage::ClearOperations => {
+                    self.handle_clear_operations();
+                    ().into()
+                }
+                ViewMessage::SetStateNumbering(numbering) => {
+                    self.handle_set_state_numbering(numbering);
+                    ().into()
+                }
+                ViewMessage::SetTransitionGrouping(grouping) => {
+                    self.handle_set_transition_grouping(grouping);
+                    ().into()
+                }
+                ViewMessage::RenameState(state, name) => {
+                    self.handle_rename_state(state, name);
+                    ().into()
+                }
                 ViewMessage::ZoomChanged(value) => {
                     self.handle_zoom_changed(value);
                     ().into()
@@ -71,33 +347,663 @@ impl App {
                     self.handle_end_pan();
                     ().into()
                 }
+                ViewMessage::PanBy(delta) => {
+                    self.handle_pan_by(delta);
+                    ().into()
+                }
+                ViewMessage::Pinch(zoom, delta) => {
+                    self.handle_pinch(zoom, delta);
+                    ().into()
+                }
+                ViewMessage::ZoomAround(factor, anchor) => {
+                    self.handle_zoom_around(factor, anchor);
+                    ().into()
+                }
                 ViewMessage::NodeDrag(id, position) => {
                     self.handle_node_drag(id, position);
                     ().into()
                 }
+                ViewMessage::SelectState(id) => {
+                    self.handle_select_state(id);
+                    ().into()
+                }
+                ViewMessage::SelectEdge(from, to) => {
+                    self.handle_select_edge(from, to);
+                    ().into()
+                }
+                ViewMessage::HoverEdge(edge) => {
+                    self.handle_hover_edge(edge);
+                    ().into()
+                }
+                ViewMessage::CursorMoved(position) => {
+                    self.handle_cursor_moved(position);
+                    ().into()
+                }
+                ViewMessage::ShowStateLayer(layer) => {
+                    self.handle_show_state_layer(layer);
+                    ().into()
+                }
                 ViewMessage::ResetView => {
                     self.handle_reset_view();
                     ().into()
                 }
+                ViewMessage::OpenContextMenu(element, position) => {
+                    self.handle_open_context_menu(element, position);
+                    ().into()
+                }
+                ViewMessage::CloseContextMenu => {
+                    self.handle_close_context_menu();
+                    ().into()
+                }
+                ViewMessage::ChooseContextAction(message) => {
+                    self.handle_choose_context_action(*message)
+                }
+                ViewMessage::PinState(id, position) => {
+                    self.handle_pin_state(id, position);
+                    ().into()
+                }
+                ViewMessage::UnpinState(id) => {
+                    self.handle_unpin_state(id);
+                    ().into()
+                }
+                ViewMessage::StartRenameState(id) => self.handle_start_rename_state(id),
+                ViewMessage::CopyRightLanguage(id) => self.handle_copy_right_language(id),
+                ViewMessage::CollapseBox(id) => {
+                    self.handle_collapse_box(id);
+                    ().into()
+                }
+                ViewMessage::ExpandBox(id) => {
+                    self.handle_expand_box(id);
+                    ().into()
+                }
             },
             Message::PaneGrid(event) => match event {
                 PaneGridMessage::Resized(event) => {
                     self.panes.resize(event.split, event.ratio);
+                    self.pane_layout.set_ratio(event.ratio);
+                    ().into()
+                }
+                PaneGridMessage::ToggleDrawer => {
+                    self.controls_drawer_open = !self.controls_drawer_open;
+                    ().into()
+                }
+                PaneGridMessage::SelectPreset(preset) => self.handle_select_layout_preset(preset),
+            },
+            Message::Exercise(exercise_msg) => match exercise_msg {
+                ExerciseMessage::AnswerChanged(value) => {
+                    self.handle_exercise_answer_changed(value);
+                    ().into()
+                }
+                ExerciseMessage::Previous => {
+                    self.handle_exercise_previous();
+                    ().into()
+                }
+                ExerciseMessage::Next => {
+                    self.handle_exercise_next();
+                    ().into()
+                }
+                ExerciseMessage::Reveal => {
+                    self.exercise.reveal();
+                    ().into()
+                }
+            },
+            Message::Tab(tab_msg) => match tab_msg {
+                TabMessage::New => {
+                    self.open_tab();
+                    ().into()
+                }
+                TabMessage::Select(index) => {
+                    self.select_tab(index);
+                    ().into()
+                }
+                TabMessage::Close(index) => {
+                    self.close_tab(index);
                     ().into()
                 }
             },
+            Message::Window(window_msg) => match window_msg {
+                WindowMessage::Detach(mode) => self.handle_detach_view(mode),
+                WindowMessage::Closed(id) => self.handle_window_closed(id),
+                WindowMessage::Relay(mode, message) => self.handle_relayed_message(mode, *message),
+            },
+            Message::Tutorial(tutorial_msg) => match tutorial_msg {
+                TutorialMessage::Start => {
+                    self.tutorial.start();
+                    ().into()
+                }
+                TutorialMessage::Next => {
+                    self.tutorial.advance();
+                    ().into()
+                }
+                TutorialMessage::Back => {
+                    self.tutorial.back();
+                    ().into()
+                }
+                TutorialMessage::Dismiss => {
+                    self.tutorial.dismiss();
+                    ().into()
+                }
+            },
+            Message::Workbench(workbench_msg) => match workbench_msg {
+                WorkbenchMessage::LeftChanged(value) => {
+                    self.handle_workbench_left_changed(value);
+                    ().into()
+                }
+                WorkbenchMessage::RightChanged(value) => {
+                    self.handle_workbench_right_changed(value);
+                    ().into()
+                }
+                WorkbenchMessage::SetOperation(op) => {
+                    self.handle_workbench_operation(op);
+                    ().into()
+                }
+            },
+        }
+    }
+
+    /// Drives simulation playback with a timer while it is playing.
+    pub fn subscription(&self) -> Subscription<Message> {
+        let keyboard = event::listen_with(state_focus_shortcut);
+        let closed = window::close_events().map(|id| Message::Window(WindowMessage::Closed(id)));
+        let system_theme = system::theme_changes()
+            .map(|mode| Message::View(ViewMessage::SystemThemeChanged(mode)));
+        let mut subscriptions = vec![keyboard, closed, system_theme];
+        if self.simulation.is_marching()
+            && SimulationTarget::for_view(self.view_mode()) == Some(self.simulation.target)
+        {
+            subscriptions.push(
+                window::frames().map(|now| Message::Simulation(SimulationMessage::MarchFrame(now))),
+            );
+        }
+        if self.edit_diff.is_flashing() {
+            subscriptions.push(
+                window::frames().map(|now| Message::Input(InputMessage::EditFlashFrame(now))),
+            );
+        }
+        if self.simulation.playing {
+            subscriptions.push(
+                time::every(PLAYBACK_INTERVAL)
+                    .map(|_| Message::Simulation(SimulationMessage::PlayTick)),
+            );
+        }
+        if self
+            .construction
+            .as_ref()
+            .is_some_and(|replay| replay.playing)
+        {
+            subscriptions.push(
+                time::every(PLAYBACK_INTERVAL)
+                    .map(|_| Message::Construction(ConstructionMessage::PlayTick)),
+            );
+        }
+        if self
+            .parse_trace
+            .as_ref()
+            .is_some_and(|replay| replay.playing)
+        {
+            subscriptions.push(
+                time::every(PLAYBACK_INTERVAL)
+                    .map(|_| Message::ParseTrace(ParseTraceMessage::PlayTick)),
+            );
+        }
+        Subscription::batch(subscriptions)
+    }
+
+    /// Updates the input text and re-parses the regex.
+    fn handle_input_changed(&mut self, input: String) {
+        self.input = input;
+        self.selected_token = None;
+        self.lex_and_parse();
+        if self.build_artifacts.is_some() {
+            self.tutorial.complete(TutorialStep::TypeRegex);
+        }
+    }
+
+    /// Expands or collapses the token stream inspector.
+    fn handle_toggle_tokens(&mut self) {
+        self.show_tokens = !self.show_tokens;
+    }
+
+    /// Selects the characters of the token at `index` in the regex input.
+    fn handle_select_token(&mut self, index: usize) -> Task<Message> {
+        let Some(token) = Lexer::tokenize_all(&self.input)
+            .ok()
+            .and_then(|tokens| tokens.get(index).copied())
+        else {
+            return Task::none();
+        };
+        self.selected_token = Some(index);
+        Task::batch([
+            operation::focus(REGEX_INPUT_ID),
+            operation::select_range(REGEX_INPUT_ID, token.start, token.end),
+        ])
+    }
+
+    /// Fades the changes from the last edit to the frame drawn at `now`.
+    fn handle_edit_flash_frame(&mut self, now: Instant) {
+        self.edit_diff.advance(now);
+    }
+
+    /// Handles edits to the declared alphabet by rebuilding the automata over it.
+    fn handle_alphabet_changed(&mut self, alphabet: String) {
+        self.declared_alphabet = alphabet;
+        self.lex_and_parse();
+    }
+
+    /// Opens the `mode` view in a window of its own, building the DFAs it may show.
+    fn handle_detach_view(&mut self, mode: ViewMode) -> Task<Message> {
+        if matches!(mode, ViewMode::Dfa | ViewMode::MinDfa) {
+            self.ensure_min_dfa();
         }
+        self.open_detached(mode)
+    }
+
+    /// Forgets a closed detached window, or quits when the main window closes.
+    fn handle_window_closed(&mut self, id: window::Id) -> Task<Message> {
+        if self.main_window == Some(id) {
+            // Ratios change too often while dragging to save each time.
+            self.save_settings();
+            return iced::exit();
+        }
+        self.detached.remove(&id);
+        Task::none()
+    }
+
+    /// Rearranges the panes into `preset` at the ratio last used with it, and remembers the choice.
+    fn handle_select_layout_preset(&mut self, preset: LayoutPreset) -> Task<Message> {
+        self.pane_layout.preset = preset;
+        self.panes = self.pane_layout.panes();
+        self.save_settings();
+        Task::none()
+    }
+
+    /// Handles a message from a detached window as if its view were the current one.
+    ///
+    /// Simulation messages only apply when the window shows the simulated automaton.
+    fn handle_relayed_message(&mut self, mode: ViewMode, message: Message) -> Task<Message> {
+        if matches!(message, Message::Simulation(_))
+            && SimulationTarget::for_view(mode) != Some(self.simulation.target)
+        {
+            return Task::none();
+        }
+        let current = self.view_mode();
+        self.set_view_mode(mode);
+        let task = self.update(message);
+        self.set_view_mode(current);
+        task
+    }
+
+    /// Toggles visibility of a specific bounding box type in the NFA view.
+    fn handle_toggle_box(&mut self, kind: BoxKind) {
+        self.box_visibility.toggle(kind);
+        self.tutorial.complete(TutorialStep::ToggleBoxes);
+    }
+
+    /// Points the bounding box style controls at another kind of box.
+    fn handle_select_styled_box(&mut self, kind: BoxKind) {
+        self.styled_box_kind = kind;
+    }
+
+    /// Changes how bounding boxes of one kind are drawn.
+    fn handle_set_box_style(&mut self, kind: BoxKind, style: BoxStyle) {
+        self.box_styles.set(kind, style);
+        self.save_settings();
+    }
+
+    /// Toggles fading nested bounding boxes by their depth.
+    fn handle_toggle_depth_dimming(&mut self) {
+        self.dim_nested_boxes = !self.dim_nested_boxes;
+    }
+
+    /// Hides bounding boxes nested deeper than `max_depth`.
+    fn handle_set_max_box_depth(&mut self, max_depth: Option<usize>) {
+        self.box_visibility.set_max_depth(max_depth);
+    }
+
+    /// Folds NFA structure nested deeper than `depth` into super-nodes.
+    fn handle_set_collapse_depth(&mut self, depth: Option<usize>) {
+        self.collapse_depth = depth;
+    }
+
+    /// Expands or collapses the layout tuning sliders.
+    fn handle_toggle_layout_tuning(&mut self) {
+        self.show_layout_tuning = !self.show_layout_tuning;
+    }
+
+    /// Lays the graphs out with the spacing in `config`.
+    fn handle_set_layout_config(&mut self, config: LayoutConfig) {
+        self.layout_config = config;
+    }
+
+    /// Lays the NFA out flowing in `orientation`.
+    fn handle_set_nfa_orientation(&mut self, orientation: Orientation) {
+        self.nfa_layout = self.nfa_layout.with_orientation(orientation);
+    }
+
+    /// Toggles the strongly-connected-component coloring of automaton states.
+    /// Both overlays fill the states, so it replaces the heatmap.
+    fn handle_toggle_scc_overlay(&mut self) {
+        self.show_scc_overlay = !self.show_scc_overlay;
+        self.show_heatmap &= !self.show_scc_overlay;
+    }
+
+    /// Toggles the heatmap of automaton states and transitions, replacing the
+    /// SCC coloring.
+    fn handle_toggle_heatmap(&mut self) {
+        self.show_heatmap = !self.show_heatmap;
+        self.show_scc_overlay &= !self.show_heatmap;
+    }
+
+    /// Toggles the lasso overlay on DFA views.
+    fn handle_toggle_infinite_witness(&mut self) {
+        self.show_infinite_witness = !self.show_infinite_witness;
+    }
+
+    /// Switches the UI language, re-wording messages already on screen.
+    fn handle_set_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+        self.save_settings();
+    }
+
+    /// Changes how far each scroll tick zooms.
+    fn handle_set_zoom_step(&mut self, step: f32) {
+        self.zoom_step = step.clamp(MIN_ZOOM_STEP, MAX_ZOOM_STEP);
+        self.save_settings();
+    }
+
+    /// Restores every saved preference to its default and saves the result.
+    fn handle_reset_settings(&mut self) {
+        self.apply_settings(Settings::default());
+        self.save_settings();
+    }
+
+    /// Asks the system for its light or dark preference, which the default
+    /// theme follows.
+    pub fn query_system_theme() -> Task<Message> {
+        system::theme().map(|mode| Message::View(ViewMessage::SystemThemeChanged(mode)))
+    }
+
+    /// Switches between the light and dark themes, or back to following the system.
+    fn handle_set_theme_preference(&mut self, preference: ThemePreference) {
+        self.theme_preference = preference;
+        self.apply_theme();
+        self.save_settings();
+    }
+
+    /// Remembers the system's light or dark preference and follows it if asked to.
+    fn handle_system_theme_changed(&mut self, mode: theme::Mode) {
+        self.system_theme = mode;
+        self.apply_theme();
+    }
+
+    /// Switches between the chosen light or dark theme and the high-contrast theme.
+    fn handle_toggle_high_contrast(&mut self) {
+        self.high_contrast = !self.high_contrast;
+        self.apply_theme();
+        self.save_settings();
+    }
+
+    /// Picks the theme from the high-contrast toggle, the preference and the
+    /// system's light or dark mode.
+    pub(crate) fn apply_theme(&mut self) {
+        self.theme = if self.high_contrast {
+            AppTheme::HighContrast
+        } else {
+            match self.theme_preference {
+                ThemePreference::System => AppTheme::for_mode(self.system_theme),
+                ThemePreference::Light => AppTheme::Light,
+                ThemePreference::Dark => AppTheme::Dark,
+            }
+        };
+    }
+
+    /// Switches automaton views between the canvas and the text listing.
+    fn handle_toggle_text_view(&mut self) {
+        self.show_text_view = !self.show_text_view;
+    }
+
+    /// Moves the selected state of the automaton on screen from the keyboard.
+    fn handle_focus_state(&mut self, focus: StateFocus) {
+        let Some((states, start)) = self.focusable_states() else {
+            return;
+        };
+        if states.is_empty() {
+            return;
+        }
+
+        let current = self.view_data().selected_state;
+        let position = states.iter().position(|&state| Some(state) == current);
+        let selected = match focus {
+            StateFocus::Next => Some(states[position.map_or(0, |idx| (idx + 1) % states.len())]),
+            StateFocus::Previous => Some(
+                states[position.map_or(states.len() - 1, |idx| {
+                    (idx + states.len() - 1) % states.len()
+                })],
+            ),
+            StateFocus::Start => Some(start),
+            StateFocus::Follow(symbol) => {
+                let Some(next) = self.focus_successor(current.unwrap_or(start), symbol) else {
+                    return;
+                };
+                Some(next)
+            }
+            StateFocus::Clear => None,
+        };
+
+        let data = self.view_data_mut();
+        data.selected_state = selected;
+        data.state_layer = None;
+    }
+
+    /// Returns the states of the automaton on screen, in id order, and its start state.
+    fn focusable_states(&self) -> Option<(Vec<StateId>, StateId)> {
+        if self.view_mode() == ViewMode::Nfa {
+            let nfa = &self.build_artifacts.as_ref()?.nfa;
+            return Some((nfa.states.iter().map(|state| state.id).collect(), nfa.start));
+        }
+        let dfa = self.inspected_dfa()?;
+        Some((dfa.states.clone(), dfa.start))
+    }
+
+    /// Returns the state reached from `state` on `symbol`, taking the first
+    /// matching transition on the NFA.
+    fn focus_successor(&self, state: StateId, symbol: char) -> Option<StateId> {
+        if self.view_mode() == ViewMode::Nfa {
+            let nfa = &self.build_artifacts.as_ref()?.nfa;
+            return nfa
+                .transitions(state)
+                .iter()
+                .find(|transition| transition.label == EdgeLabel::Sym(symbol))
+                .map(|transition| transition.to);
+        }
+        let dfa = self.inspected_dfa()?;
+        dfa.step(state, symbol)
+    }
+
+    /// Expands or collapses the syntax reference.
+    fn handle_toggle_syntax_reference(&mut self) {
+        self.show_syntax_reference = !self.show_syntax_reference;
+    }
+
+    /// Filters the syntax reference by `query`.
+    fn handle_syntax_query_changed(&mut self, query: String) {
+        self.syntax_query = query;
+    }
+
+    /// Highlights the states and transitions matching `query` on the canvas.
+    fn handle_graph_query_changed(&mut self, query: String) {
+        self.graph_query = query;
+    }
+
+    /// Updates the sizes and pixel density of exported figures.
+    fn handle_set_export_quality(&mut self, quality: ExportQuality) {
+        self.export_quality = quality;
+        self.svg_exported = None;
+    }
+
+    /// Copies the current view to the clipboard as an SVG document.
+    fn handle_copy_svg(&mut self) -> Task<Message> {
+        let mode = self.view_mode();
+        let Some(svg) = export_svg(self, mode) else {
+            return Task::none();
+        };
+        self.svg_exported = Some(mode);
+        clipboard::write(svg)
+    }
+
+    /// Describes another automaton in the formal definition view. The
+    /// simulation follows, as it does when switching automaton tabs.
+    fn handle_set_definition_target(&mut self, target: SimulationTarget) {
+        self.handle_simulation_target_changed(target);
+        self.definition_copied = None;
+    }
+
+    /// Switches how the formal definition writes its transition function.
+    fn handle_set_delta_format(&mut self, format: DeltaFormat) {
+        self.delta_format = format;
+    }
+
+    /// Copies the formal definition of the simulated automaton as LaTeX source.
+    fn handle_copy_definition_latex(&mut self) -> Task<Message> {
+        let Some(definition) = formal_definition(self) else {
+            return Task::none();
+        };
+        self.definition_copied = Some(self.simulation.target);
+        clipboard::write(definition.to_latex())
+    }
+
+    /// Switches the glyph drawn for the empty string.
+    fn handle_set_epsilon_glyph(&mut self, glyph: EpsilonGlyph) {
+        self.epsilon_glyph = glyph;
+        self.save_settings();
+    }
+
+    /// Applies an operation to the latest result, or to the DFA on screen if
+    /// this is the first one, and shows the outcome.
+    fn handle_apply_operation(&mut self, operation: DfaOperation) {
+        let base_mode = self.view_mode();
+        self.ensure_min_dfa();
+        let Some(artifacts) = self.build_artifacts.as_ref() else {
+            return;
+        };
+        let (Some(dfa), Some(min_dfa)) = (&artifacts.dfa, &artifacts.min_dfa) else {
+            return;
+        };
+        self.operations.apply(operation, || match base_mode {
+            ViewMode::MinDfa => (Key::TabMinDfa, min_dfa.clone()),
+            _ => (Key::TabDfa, dfa.clone()),
+        });
+        self.show_operation_result();
+    }
+
+    /// Removes the latest operation.
+    fn handle_undo_operation(&mut self) {
+        self.operations.undo();
+        self.show_operation_result();
+    }
+
+    /// Removes every operation.
+    fn handle_clear_operations(&mut self) {
+        self.operations.clear();
+    }
+
+    /// Switches to the operations view with a fresh canvas for the new result.
+    fn show_operation_result(&mut self) {
+        let data = self.view_state.data_for_mut(ViewMode::Operations);
+        data.pinned_node_positions.clear();
+        data.selected_state = None;
+        data.state_layer = None;
+        self.set_view_mode(ViewMode::Operations);
+    }
+
+    /// Checks the edited exercise answer against the target language.
+    fn handle_exercise_answer_changed(&mut self, value: String) {
+        self.exercise.set_answer(value);
+        self.reset_exercise_canvas();
+    }
+
+    /// Moves to the previous exercise.
+    fn handle_exercise_previous(&mut self) {
+        let index = self.exercise.index + EXERCISES.len() - 1;
+        self.exercise.go_to(index);
+        self.reset_exercise_canvas();
+    }
+
+    /// Moves to the next exercise.
+    fn handle_exercise_next(&mut self) {
+        self.exercise.go_to(self.exercise.index + 1);
+        self.reset_exercise_canvas();
+    }
+
+    /// Clears positions and selection left over from the previous answer's DFA.
+    fn reset_exercise_canvas(&mut self) {
+        let data = self.view_state.data_for_mut(ViewMode::Exercise);
+        data.pinned_node_positions.clear();
+        data.selected_state = None;
+        data.state_layer = None;
+    }
+
+    /// Updates the workbench's left pattern.
+    fn handle_workbench_left_changed(&mut self, value: String) {
+        self.workbench.left = value;
+        self.rebuild_workbench();
+    }
+
+    /// Updates the workbench's right pattern.
+    fn handle_workbench_right_changed(&mut self, value: String) {
+        self.workbench.right = value;
+        self.rebuild_workbench();
+    }
+
+    /// Switches the operation combining the workbench patterns.
+    fn handle_workbench_operation(&mut self, op: BoolOp) {
+        self.workbench.op = op;
+        self.rebuild_workbench();
     }
 
-    /// Updates the input text and re-parses the regex.
-    fn handle_input_changed(&mut self, input: String) {
-        self.input = input;
-        self.lex_and_parse();
+    /// Recombines the workbench patterns and resets the result canvas.
+    fn rebuild_workbench(&mut self) {
+        self.workbench.rebuild();
+
+        let data = self.view_state.data_for_mut(ViewMode::Workbench);
+        data.pinned_node_positions.clear();
+        data.selected_state = None;
+        data.state_layer = None;
     }
 
-    /// Toggles visibility of a specific bounding box type in the NFA view.
-    fn handle_toggle_box(&mut self, kind: regviz_core::core::automaton::BoxKind) {
-        self.box_visibility.toggle(kind);
+    /// Switches how DFA states are numbered.
+    fn handle_set_state_numbering(&mut self, numbering: StateNumbering) {
+        self.state_numbering = numbering;
+    }
+
+    /// Switches how DFA transitions between the same two states are drawn.
+    fn handle_set_transition_grouping(&mut self, grouping: TransitionGrouping) {
+        self.transition_grouping = grouping;
+    }
+
+    /// Gives the inspected state a custom name, or clears it when `name` is blank.
+    ///
+    /// Names are keyed by the state's access word rather than its id, so they
+    /// stick to the "same" state when the pattern is edited and rebuilt.
+    fn handle_rename_state(&mut self, state: StateId, name: String) {
+        let Some(dfa) = self.inspected_dfa() else {
+            return;
+        };
+        let Some((_, word)) = dfa
+            .access_words()
+            .into_iter()
+            .find(|(reached, _)| *reached == state)
+        else {
+            return;
+        };
+
+        let name: String = name.chars().take(MAX_STATE_NAME_LEN).collect();
+        let names = &mut self.view_data_mut().state_names;
+        if name.trim().is_empty() {
+            names.remove(&word);
+        } else {
+            names.insert(word, name);
+        }
     }
 
     /// Updates the zoom factor, clamping it to valid range.
@@ -107,8 +1013,8 @@ impl App {
 
     /// Handles mouse wheel scroll zoom (delta > 0 = zoom in, delta < 0 = zoom out).
     fn handle_zoom(&mut self, delta: f32) {
-        // Each scroll "tick" adjusts zoom by 10%
-        let zoom_change = delta * ZOOM_STEP;
+        // Each scroll "tick" adjusts zoom by the configured step
+        let zoom_change = delta * self.zoom_step;
         let new_zoom = self.view_data().zoom_factor + zoom_change;
         self.view_data_mut().zoom_factor = new_zoom.clamp(MIN_ZOOM_FACTOR, MAX_ZOOM_FACTOR);
     }
@@ -126,11 +1032,29 @@ impl App {
             RightPaneMode::Dfa => {
                 self.set_view_mode(ViewMode::Dfa);
                 self.handle_simulation_target_changed(SimulationTarget::Dfa);
+                self.tutorial.complete(TutorialStep::SwitchToDfa);
             }
             RightPaneMode::MinDfa => {
                 self.set_view_mode(ViewMode::MinDfa);
                 self.handle_simulation_target_changed(SimulationTarget::MinDfa);
             }
+            RightPaneMode::Analysis => {
+                self.set_view_mode(ViewMode::Analysis);
+                self.ensure_min_dfa();
+            }
+            RightPaneMode::Operations => {
+                self.set_view_mode(ViewMode::Operations);
+            }
+            RightPaneMode::Workbench => {
+                self.set_view_mode(ViewMode::Workbench);
+            }
+            RightPaneMode::Exercise => {
+                self.set_view_mode(ViewMode::Exercise);
+            }
+            RightPaneMode::Definition => {
+                self.set_view_mode(ViewMode::Definition);
+                self.ensure_min_dfa();
+            }
         }
     }
 
@@ -145,9 +1069,31 @@ impl App {
         self.refresh_simulation_trace();
     }
 
+    /// Replaces the unconsumed part of the input and re-simulates only that tail.
+    ///
+    /// The consumed prefix and current step are kept, so the user can ask
+    /// "what if the rest of the input were different" without starting over.
+    fn handle_remaining_input_changed(&mut self, remaining: String) {
+        if self.build_artifacts.is_none() {
+            return;
+        }
+
+        let mut input = self.simulation.consumed_input().to_string();
+        input.push_str(&remaining);
+        self.simulation.input = input;
+        self.simulation_error = self.validate_simulation_input();
+
+        self.rebuild_trace_tail();
+        self.rebuild_suffix_matches();
+        self.rebuild_accepting_paths();
+        self.rebuild_rejection();
+        self.rebuild_engine_comparison();
+    }
+
     /// Steps the simulation forward when possible.
     fn handle_simulation_step_forward(&mut self) {
         self.simulation.step_forward();
+        self.tutorial.complete(TutorialStep::StepSimulation);
     }
 
     /// Steps the simulation backward when possible.
@@ -161,63 +1107,248 @@ impl App {
         self.refresh_simulation_trace();
     }
 
-    /// Switches between NFA and DFA simulation modes.
-    fn handle_simulation_target_changed(&mut self, target: SimulationTarget) {
-        if self.simulation.target == target {
+    /// Starts or pauses automatic playback.
+    fn handle_toggle_play(&mut self) {
+        if self.simulation_error.is_some() {
             return;
         }
+        self.simulation.toggle_play();
+    }
 
-        self.simulation.target = target;
-        self.simulation.reset_cursor();
-        self.refresh_simulation_trace();
+    /// Advances playback by one step, pausing on breakpoints.
+    fn handle_play_tick(&mut self) {
+        self.simulation.play_tick();
     }
 
-    /// Validates the simulation input and rebuilds the trace when valid.
-    pub(crate) fn refresh_simulation_trace(&mut self) {
-        if let Some(error) = self.validate_simulation_input() {
-            self.simulation_error = Some(error);
-            self.simulation.clear_trace();
-        } else {
-            self.simulation_error = None;
+    /// Moves the dashes marching along the taken transitions to the frame drawn at `now`.
+    fn handle_march_frame(&mut self, now: Instant) {
+        self.simulation.march(now);
+    }
+
+    /// Replays the construction of the NFA from its first fragment.
+    ///
+    /// The NFA is rebuilt from the parse tree to record the order of its
+    /// fragments; automata composed without a pattern have none to replay.
+    fn handle_start_construction(&mut self) {
+        let Some(ast) = self
+            .build_artifacts
+            .as_ref()
+            .and_then(|artifacts| artifacts.ast.as_ref())
+        else {
+            return;
+        };
+        let (_, steps) = Nfa::build_recorded(ast);
+        self.construction = Replay::new(steps);
+    }
+
+    /// Stops the construction replay, showing the whole NFA again.
+    fn handle_stop_construction(&mut self) {
+        self.construction = None;
+    }
+
+    /// Builds the next fragment of the replayed construction, pausing playback.
+    fn handle_construction_step_forward(&mut self) {
+        if let Some(replay) = &mut self.construction {
+            replay.playing = false;
+            replay.step_forward();
         }
+    }
 
-        self.rebuild_simulation_trace();
+    /// Takes back the last fragment of the replayed construction, pausing playback.
+    fn handle_construction_step_backward(&mut self) {
+        if let Some(replay) = &mut self.construction {
+            replay.playing = false;
+            replay.step_backward();
+        }
     }
 
-    /// Returns an error if the simulation input uses symbols outside the regex alphabet.
-    fn validate_simulation_input(&self) -> Option<String> {
-        let Some(artifacts) = &self.build_artifacts else {
-            return None;
+    /// Starts or pauses playback of the construction replay.
+    fn handle_construction_toggle_play(&mut self) {
+        if let Some(replay) = &mut self.construction {
+            replay.toggle_play();
+        }
+    }
+
+    /// Builds the next fragment while the construction replay is playing.
+    fn handle_construction_play_tick(&mut self) {
+        if let Some(replay) = &mut self.construction {
+            replay.play_tick();
+        }
+    }
+
+    /// Replays how the parser built the AST, from its first token.
+    ///
+    /// The pattern is parsed again to record the parser's decisions; automata
+    /// composed without a pattern have no parse to replay.
+    fn handle_start_parse_trace(&mut self) {
+        let parsed = self
+            .build_artifacts
+            .as_ref()
+            .is_some_and(|artifacts| artifacts.ast.is_some());
+        if !parsed {
+            return;
+        }
+        self.parse_trace = Ast::build_traced(self.input.trim())
+            .ok()
+            .and_then(|(_, events)| Replay::new(events));
+    }
+
+    /// Stops the parser trace replay, showing the whole tree again.
+    fn handle_stop_parse_trace(&mut self) {
+        self.parse_trace = None;
+    }
+
+    /// Takes the parser's next step in the replay, pausing playback.
+    fn handle_parse_trace_step_forward(&mut self) {
+        if let Some(replay) = &mut self.parse_trace {
+            replay.playing = false;
+            replay.step_forward();
+        }
+    }
+
+    /// Takes back the parser's last step in the replay, pausing playback.
+    fn handle_parse_trace_step_backward(&mut self) {
+        if let Some(replay) = &mut self.parse_trace {
+            replay.playing = false;
+            replay.step_backward();
+        }
+    }
+
+    /// Starts or pauses playback of the parser trace replay.
+    fn handle_parse_trace_toggle_play(&mut self) {
+        if let Some(replay) = &mut self.parse_trace {
+            replay.toggle_play();
+        }
+    }
+
+    /// Takes the parser's next step while the replay is playing.
+    fn handle_parse_trace_play_tick(&mut self) {
+        if let Some(replay) = &mut self.parse_trace {
+            replay.play_tick();
+        }
+    }
+
+    /// Toggles a breakpoint on a state of the simulated automaton.
+    fn handle_toggle_breakpoint(&mut self, state: StateId) {
+        if !self.shows_simulated_automaton() {
+            return;
+        }
+        self.simulation.toggle_breakpoint(state);
+    }
+
+    /// Toggles a breakpoint on a transition of the simulated automaton.
+    fn handle_toggle_edge_breakpoint(&mut self, from: StateId, to: StateId) {
+        if !self.shows_simulated_automaton() {
+            return;
+        }
+        self.simulation.toggle_edge_breakpoint(from, to);
+    }
+
+    /// Returns whether the current view draws one of the automata the simulation can run on.
+    fn shows_simulated_automaton(&self) -> bool {
+        matches!(
+            self.view_mode(),
+            ViewMode::Nfa | ViewMode::Dfa | ViewMode::MinDfa
+        )
+    }
+
+    /// Shows or hides the table comparing the NFA, DFA and minimized DFA runs.
+    fn handle_toggle_comparison(&mut self) {
+        self.simulation.show_comparison = !self.simulation.show_comparison;
+        self.rebuild_engine_comparison();
+    }
+
+    /// Copies the full simulation trace to the clipboard as JSON or CSV.
+    fn handle_copy_trace(&mut self, format: TraceFormat) -> Task<Message> {
+        let labels = self.simulation_state_labels();
+        let Some(contents) = self.simulation.export_trace(format, labels) else {
+            return Task::none();
         };
+        self.simulation.exported = Some(format);
+        clipboard::write(contents)
+    }
 
-        if self.simulation.input.is_empty() {
-            return None;
+    /// Saves the simulation as an animated GIF. Browsers cannot save files, so
+    /// the option is only offered natively.
+    fn handle_save_animation(&mut self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let saved = self.save_simulation_animation();
+            self.simulation.animation = Some(saved);
         }
+    }
+
+    /// Returns the names drawn for the simulated automaton's states, for exports.
+    fn simulation_state_labels(&self) -> HashMap<StateId, String> {
+        let Some(artifacts) = self.build_artifacts.as_ref() else {
+            return HashMap::new();
+        };
+        let (mode, dfa) = match self.simulation.target {
+            SimulationTarget::Nfa => return HashMap::new(),
+            SimulationTarget::Dfa => (
+                ViewMode::Dfa,
+                artifacts.dfa.as_ref().or(artifacts.min_dfa.as_ref()),
+            ),
+            SimulationTarget::MinDfa => (
+                ViewMode::MinDfa,
+                artifacts.min_dfa.as_ref().or(artifacts.dfa.as_ref()),
+            ),
+        };
+        dfa.map(|dfa| self.state_labels(mode, dfa))
+            .unwrap_or_default()
+    }
 
-        let alphabet: BTreeSet<char> = artifacts.alphabet.iter().copied().collect();
-        let invalid: BTreeSet<char> = self
-            .simulation
-            .input
-            .chars()
-            .filter(|symbol| !alphabet.contains(symbol))
-            .collect();
+    /// Shows or hides the reverse-automaton suffix demo.
+    fn handle_toggle_suffixes(&mut self) {
+        self.simulation.show_suffixes = !self.simulation.show_suffixes;
+        self.rebuild_suffix_matches();
+    }
 
-        if invalid.is_empty() {
-            None
-        } else {
-            let symbols = invalid
-                .iter()
-                .map(|symbol| format!("'{symbol}'"))
-                .collect::<Vec<_>>()
-                .join(", ");
+    /// Starts or stops replaying one accepting path.
+    fn handle_replay_path(&mut self, path: usize) {
+        self.simulation.toggle_replay(path);
+    }
 
-            Some(format!(
-                "Input contains symbol(s) outside the regex alphabet: {symbols}"
-            ))
+    /// Advances the replayed path by one transition.
+    fn handle_replay_forward(&mut self) {
+        self.simulation.replay_forward();
+    }
+
+    /// Moves the replayed path back by one transition.
+    fn handle_replay_backward(&mut self) {
+        self.simulation.replay_backward();
+    }
+
+    /// Switches between NFA and DFA simulation modes.
+    fn handle_simulation_target_changed(&mut self, target: SimulationTarget) {
+        if self.simulation.target == target {
+            return;
         }
+
+        self.simulation.target = target;
+        // State ids are not shared between automata, so breakpoints do not carry over.
+        self.simulation.breakpoints.clear();
+        self.simulation.reset_cursor();
+        self.refresh_simulation_trace();
+    }
+
+    /// Rebuilds the trace and everything derived from the simulation input.
+    pub(crate) fn refresh_simulation_trace(&mut self) {
+        self.rebuild_simulation_trace();
+        self.rebuild_suffix_matches();
+        self.rebuild_accepting_paths();
+        self.rebuild_rejection();
+        self.rebuild_engine_comparison();
     }
 
-    /// Recomputes the simulation trace for the current target automaton.
+    /// Returns an error if the simulation input uses symbols outside the alphabet.
+    fn validate_simulation_input(&self) -> Option<SimError> {
+        let artifacts = self.build_artifacts.as_ref()?;
+        sim::check_input(&artifacts.alphabet, &self.simulation.input).err()
+    }
+
+    /// Recomputes the simulation trace for the current target automaton, or
+    /// records why the input cannot be simulated.
     pub(crate) fn rebuild_simulation_trace(&mut self) {
         let Some(artifacts) = self.build_artifacts.as_mut() else {
             self.simulation.clear_trace();
@@ -226,22 +1357,19 @@ impl App {
 
         let input = self.simulation.input.as_str();
 
-        match self.simulation.target {
-            SimulationTarget::Nfa => {
-                let trace = build_nfa_trace(&artifacts.nfa, input);
-                self.simulation.set_trace(Some(trace));
-            }
+        let trace = match self.simulation.target {
+            SimulationTarget::Nfa => build_nfa_trace(&artifacts.nfa, &artifacts.alphabet, input),
             SimulationTarget::Dfa => {
                 // Ensure the determinized DFA exists
                 let taken_dfa = match artifacts.dfa.take() {
                     Some(dfa_ref) => dfa_ref,
-                    None => dfa::determinize(&artifacts.nfa),
+                    None => dfa::determinize_over(&artifacts.nfa, &artifacts.alphabet),
                 };
 
-                let trace = build_dfa_trace(&taken_dfa, &artifacts.alphabet, input);
+                let trace = build_dfa_trace(&taken_dfa, input);
                 // Store back the taken DFA
                 artifacts.dfa = Some(taken_dfa);
-                self.simulation.set_trace(Some(trace));
+                trace
             }
             SimulationTarget::MinDfa => {
                 // Ensure the minimized DFA exists. This may require determinization first.
@@ -251,7 +1379,7 @@ impl App {
                         (Some(min_dfa), Some(dfa)) => (min_dfa, dfa),
                         (Some(_), None) => {
                             // dfa is missing, compute from nfa
-                            let dfa = dfa::determinize(&artifacts.nfa);
+                            let dfa = dfa::determinize_over(&artifacts.nfa, &artifacts.alphabet);
                             // compute min_dfa from dfa to ensure consistency
                             let min_dfa = min::minimize(&dfa);
                             (min_dfa, dfa)
@@ -263,20 +1391,142 @@ impl App {
                         }
                         (None, None) => {
                             // both missing, compute dfa from nfa, then min_dfa
-                            let dfa = dfa::determinize(&artifacts.nfa);
+                            let dfa = dfa::determinize_over(&artifacts.nfa, &artifacts.alphabet);
                             let min_dfa = min::minimize(&dfa);
                             (min_dfa, dfa)
                         }
                     };
-                let trace = build_dfa_trace(&taken_min_dfa, &artifacts.alphabet, input);
+                let trace = build_dfa_trace(&taken_min_dfa, input);
                 // Store back the taken DFAs
                 artifacts.dfa = Some(taken_dfa);
                 artifacts.min_dfa = Some(taken_min_dfa);
+                trace
+            }
+        };
+        match trace {
+            Ok(trace) => {
+                self.simulation_error = None;
                 self.simulation.set_trace(Some(trace));
             }
+            Err(error) => {
+                self.simulation_error = Some(error);
+                self.simulation.clear_trace();
+            }
         }
     }
 
+    /// Re-simulates the input after the current step, keeping the steps before it.
+    fn rebuild_trace_tail(&mut self) {
+        let Some(from) = self.simulation.current_step().cloned() else {
+            self.rebuild_simulation_trace();
+            return;
+        };
+        if self.simulation.target == SimulationTarget::MinDfa {
+            self.ensure_min_dfa();
+        }
+        let Some(artifacts) = self.build_artifacts.as_mut() else {
+            return;
+        };
+
+        let symbols: Vec<char> = self.simulation.remaining_input().chars().collect();
+        let tail = match self.simulation.target {
+            SimulationTarget::Nfa => nfa_trace_tail(&artifacts.nfa, &from, &symbols),
+            SimulationTarget::Dfa => {
+                let dfa = artifacts.dfa.get_or_insert_with(|| {
+                    dfa::determinize_over(&artifacts.nfa, &artifacts.alphabet)
+                });
+                dfa_trace_tail(dfa, &from, &symbols)
+            }
+            SimulationTarget::MinDfa => match &artifacts.min_dfa {
+                Some(min_dfa) => dfa_trace_tail(min_dfa, &from, &symbols),
+                None => Vec::new(),
+            },
+        };
+        self.simulation.splice_trace(tail);
+    }
+
+    /// Computes the determinized and minimized DFAs if they are not cached yet.
+    pub(crate) fn ensure_min_dfa(&mut self) {
+        let Some(artifacts) = self.build_artifacts.as_mut() else {
+            return;
+        };
+
+        let dfa = artifacts
+            .dfa
+            .get_or_insert_with(|| dfa::determinize_over(&artifacts.nfa, &artifacts.alphabet));
+        if artifacts.min_dfa.is_none() {
+            artifacts.min_dfa = Some(min::minimize(dfa));
+        }
+    }
+
+    /// Recomputes which suffixes of the simulation input are accepted.
+    pub(crate) fn rebuild_suffix_matches(&mut self) {
+        self.simulation.suffix_matches.clear();
+        if !self.simulation.show_suffixes || self.simulation_error.is_some() {
+            return;
+        }
+        let Some(artifacts) = self.build_artifacts.as_mut() else {
+            return;
+        };
+
+        let dfa = artifacts
+            .dfa
+            .get_or_insert_with(|| dfa::determinize_over(&artifacts.nfa, &artifacts.alphabet));
+        self.simulation.suffix_matches = build_suffix_matches(dfa, &self.simulation.input);
+    }
+
+    /// Recomputes the NFA paths accepting the simulation input and stops any replay.
+    fn rebuild_accepting_paths(&mut self) {
+        self.simulation.replay = None;
+        self.simulation.accepting_paths.clear();
+        if self.simulation_error.is_some() || self.simulation.target != SimulationTarget::Nfa {
+            return;
+        }
+        let Some(artifacts) = &self.build_artifacts else {
+            return;
+        };
+
+        self.simulation.accepting_paths =
+            sim::accepting_paths(&artifacts.nfa, &self.simulation.input, MAX_ACCEPTING_PATHS);
+    }
+
+    /// Recomputes the explanation shown when the simulation input is rejected.
+    fn rebuild_rejection(&mut self) {
+        self.simulation.rejection = None;
+        if self.simulation_error.is_some() {
+            return;
+        }
+        let Some(artifacts) = &self.build_artifacts else {
+            return;
+        };
+
+        self.simulation.rejection = sim::diagnose(&artifacts.nfa, &self.simulation.input);
+    }
+
+    /// Recomputes the NFA/DFA/min-DFA comparison table while it is shown.
+    fn rebuild_engine_comparison(&mut self) {
+        self.simulation.comparison = None;
+        if !self.simulation.show_comparison || self.simulation_error.is_some() {
+            return;
+        }
+        self.ensure_min_dfa();
+        let Some(artifacts) = &self.build_artifacts else {
+            return;
+        };
+        let (Some(dfa), Some(min_dfa)) = (&artifacts.dfa, &artifacts.min_dfa) else {
+            return;
+        };
+
+        self.simulation.comparison = build_engine_comparison(
+            &artifacts.nfa,
+            &artifacts.alphabet,
+            dfa,
+            min_dfa,
+            &self.simulation.input,
+        )
+        .ok();
+    }
+
     /// Starts a pan operation at the given cursor position.
     fn handle_start_pan(&mut self, position: Point) {
         self.last_cursor_position = Some(position);
@@ -296,11 +1546,211 @@ impl App {
         self.last_cursor_position = None;
     }
 
+    /// Shifts the view by a screen offset, e.g. from a trackpad scroll or a glide.
+    fn handle_pan_by(&mut self, delta: Vector) {
+        self.view_data_mut().pan_offset += delta;
+    }
+
+    /// Applies a two-finger pinch: zooms to `zoom` and pans by the fingers' midpoint movement.
+    fn handle_pinch(&mut self, zoom: f32, delta: Vector) {
+        let data = self.view_data_mut();
+        data.zoom_factor = zoom.clamp(MIN_ZOOM_FACTOR, MAX_ZOOM_FACTOR);
+        data.pan_offset += delta;
+    }
+
+    /// Zooms by `factor` around the point `anchor` away from the canvas center,
+    /// keeping the graph under that point in place.
+    fn handle_zoom_around(&mut self, factor: f32, anchor: Vector) {
+        let data = self.view_data_mut();
+        let zoom = (data.zoom_factor * factor).clamp(MIN_ZOOM_FACTOR, MAX_ZOOM_FACTOR);
+        // The graph scales around the canvas center, so shift it to bring the anchor back.
+        let ratio = zoom / data.zoom_factor;
+        data.pan_offset = anchor * (1.0 - ratio) + data.pan_offset * ratio;
+        data.zoom_factor = zoom;
+    }
+
     /// Updates the pinned position of a node during a drag operation.
     fn handle_node_drag(&mut self, id: u32, position: Point) {
+        let data = self.view_data_mut();
+        data.pinned_node_positions.insert(id, position.to_layout());
+        // The status bar follows the dragged node's center, where it is placed.
+        data.cursor_position = Some(Point::new(position.x.round(), position.y.round()));
+    }
+
+    /// Selects a state for the inspector, or deselects it if it was already selected.
+    fn handle_select_state(&mut self, id: u32) {
+        let data = self.view_data_mut();
+        data.selected_state = if data.selected_state == Some(id) {
+            None
+        } else {
+            Some(id)
+        };
+        data.selected_edge = None;
+        data.state_layer = None;
+        if matches!(self.view_mode(), ViewMode::Dfa | ViewMode::MinDfa) {
+            self.link_dfa_selection();
+        }
+    }
+
+    /// Selects a transition, or deselects it if it was already selected.
+    fn handle_select_edge(&mut self, from: StateId, to: StateId) {
+        let data = self.view_data_mut();
+        data.selected_edge = if data.selected_edge == Some((from, to)) {
+            None
+        } else {
+            Some((from, to))
+        };
+    }
+
+    /// Marks the states matching the selection in the DFA or minimized DFA
+    /// view on the other one: the minimized state a DFA state was merged
+    /// into, or every DFA state merged into a minimized state.
+    fn link_dfa_selection(&mut self) {
+        let mode = self.view_mode();
+        let other = if mode == ViewMode::Dfa {
+            ViewMode::MinDfa
+        } else {
+            ViewMode::Dfa
+        };
+        self.view_state.data_for_mut(mode).linked_states.clear();
+        self.view_state.data_for_mut(other).linked_states.clear();
+        let Some(selected) = self.view_data().selected_state else {
+            return;
+        };
+
+        self.ensure_min_dfa();
+        let Some(dfa) = self.build_artifacts.as_ref().and_then(|a| a.dfa.as_ref()) else {
+            return;
+        };
+        let (_, mapping) = min::minimize_with_mapping(dfa);
+        let linked = if mode == ViewMode::Dfa {
+            mapping
+                .get(selected as usize)
+                .copied()
+                .into_iter()
+                .collect()
+        } else {
+            (0..mapping.len() as StateId)
+                .filter(|&state| mapping[state as usize] == selected)
+                .collect()
+        };
+        self.view_state.data_for_mut(other).linked_states = linked;
+    }
+
+    /// Previews the frontier reached by taking the hovered transition from the current step.
+    ///
+    /// The preview is speculative: the trace and cursor are left untouched.
+    /// Records where the cursor is over the canvas, for the status bar.
+    fn handle_cursor_moved(&mut self, position: Option<Point>) {
+        self.view_data_mut().cursor_position = position;
+    }
+
+    fn handle_hover_edge(&mut self, edge: Option<(StateId, StateId)>) {
+        self.simulation.preview = None;
+        let Some((from, to)) = edge else {
+            return;
+        };
+        if !self.shows_simulated_automaton() || self.simulation.replay.is_some() {
+            return;
+        }
+        let (Some(artifacts), Some(step)) = (&self.build_artifacts, self.simulation.current_step())
+        else {
+            return;
+        };
+        if !step.active_states.contains(from) {
+            return;
+        }
+
+        let index = step.index + 1;
+        let preview = match self.simulation.target {
+            SimulationTarget::Nfa => artifacts
+                .nfa
+                .transitions(from)
+                .iter()
+                .find_map(|transition| match transition.label {
+                    EdgeLabel::Sym(symbol) if transition.to == to => Some(symbol),
+                    _ => None,
+                })
+                .map(|symbol| nfa_step(&artifacts.nfa, &step.active_states, index, symbol)),
+            target => {
+                let dfa = match target {
+                    SimulationTarget::MinDfa => artifacts.min_dfa.as_ref(),
+                    _ => artifacts.dfa.as_ref(),
+                };
+                dfa.and_then(|dfa| {
+                    let column = dfa.trans[from as usize]
+                        .iter()
+                        .position(|&next| next == to)?;
+                    let symbol = *dfa.alphabet.get(column)?;
+                    Some(dfa_step(dfa, Some(from), index, symbol))
+                })
+            }
+        };
+        self.simulation.preview = preview;
+    }
+
+    /// Marks a set of states around the selected state, or clears the marking.
+    fn handle_show_state_layer(&mut self, layer: Option<StateLayer>) {
+        self.view_data_mut().state_layer = layer;
+    }
+
+    /// Opens the context menu of a canvas element at `position` on the canvas.
+    fn handle_open_context_menu(&mut self, element: CanvasElement, position: Point) {
+        self.view_data_mut().context_menu = Some(ContextMenu { element, position });
+    }
+
+    /// Closes the canvas context menu.
+    fn handle_close_context_menu(&mut self) {
+        self.view_data_mut().context_menu = None;
+    }
+
+    /// Closes the canvas context menu and carries out the action picked from it.
+    fn handle_choose_context_action(&mut self, message: Message) -> Task<Message> {
+        self.handle_close_context_menu();
+        self.update(message)
+    }
+
+    /// Pins a node at `position`, in layout coordinates, as if it was dragged there.
+    fn handle_pin_state(&mut self, id: StateId, position: Point) {
         self.view_data_mut()
             .pinned_node_positions
-            .insert(id, position);
+            .insert(id, position.to_layout());
+    }
+
+    /// Lets the layout place a pinned node again.
+    fn handle_unpin_state(&mut self, id: StateId) {
+        self.view_data_mut().pinned_node_positions.remove(&id);
+    }
+
+    /// Selects a state for the inspector and focuses the field renaming it.
+    fn handle_start_rename_state(&mut self, state: StateId) -> Task<Message> {
+        if self.view_data().selected_state != Some(state) {
+            self.handle_select_state(state);
+        }
+        operation::focus(RENAME_INPUT_ID)
+    }
+
+    /// Copies a regular expression for the words `state` still accepts.
+    fn handle_copy_right_language(&mut self, state: StateId) -> Task<Message> {
+        let Some(dfa) = self.inspected_dfa() else {
+            return Task::none();
+        };
+        if state as usize >= dfa.states.len() {
+            return Task::none();
+        }
+        let pattern = to_regex::right_language(dfa, state)
+            .map_or_else(|| "∅".to_string(), |ast| ast.to_pattern(self.epsilon_glyph));
+        clipboard::write(pattern)
+    }
+
+    /// Folds an NFA bounding box into a super-node.
+    fn handle_collapse_box(&mut self, id: BoxId) {
+        self.view_data_mut().collapsed_boxes.insert(id);
+    }
+
+    /// Unfolds a super-node back into its bounding box.
+    fn handle_expand_box(&mut self, id: BoxId) {
+        self.view_data_mut().collapsed_boxes.remove(&id);
     }
 
     /// Resets the current view to center with default zoom and no pinned nodes.
@@ -309,3 +1759,40 @@ impl App {
         *self.view_state.data_mut() = ViewData::default();
     }
 }
+
+/// Maps key presses not consumed by a widget to state navigation.
+///
+/// Text inputs capture the keys typed into them, so shortcuts never fire while
+/// the user is editing a pattern.
+fn state_focus_shortcut(
+    event: Event,
+    status: event::Status,
+    _window: window::Id,
+) -> Option<Message> {
+    let (
+        event::Status::Ignored,
+        Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }),
+    ) = (status, event)
+    else {
+        return None;
+    };
+    if modifiers.command() || modifiers.alt() {
+        return None;
+    }
+
+    let focus = match key.as_ref() {
+        keyboard::Key::Named(Named::ArrowDown | Named::ArrowRight) => StateFocus::Next,
+        keyboard::Key::Named(Named::ArrowUp | Named::ArrowLeft) => StateFocus::Previous,
+        keyboard::Key::Named(Named::Home) => StateFocus::Start,
+        keyboard::Key::Named(Named::Escape) => StateFocus::Clear,
+        keyboard::Key::Character(typed) => {
+            let mut chars = typed.chars();
+            let (Some(symbol), None) = (chars.next(), chars.next()) else {
+                return None;
+            };
+            StateFocus::Follow(symbol)
+        }
+        _ => return None,
+    };
+    Some(Message::View(ViewMessage::FocusState(focus)))
+}
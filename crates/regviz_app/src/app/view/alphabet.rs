@@ -0,0 +1,69 @@
+use iced::{
+    Length,
+    widget::{column, text, text_input},
+};
+
+use crate::app::{
+    i18n::Key,
+    message::{InputMessage, Message},
+    state::App,
+    theme::{ElementType, TextClass, TextInputClass, TextSize},
+};
+
+/// Renders the field for declaring the alphabet explicitly.
+pub fn render(app: &App) -> ElementType<'_> {
+    let label = text(app.tr(Key::AlphabetHeading))
+        .size(TextSize::H3)
+        .class(TextClass::Primary);
+
+    let input_field = text_input(app.tr(Key::AlphabetPlaceholder), &app.declared_alphabet)
+        .class(if app.alphabet_error.is_some() {
+            TextInputClass::Invalid
+        } else {
+            TextInputClass::Default
+        })
+        .on_input(|value| Message::Input(InputMessage::AlphabetChanged(value)))
+        .padding([12, 16])
+        .size(TextSize::Body)
+        .width(Length::Fill);
+
+    column![label, input_field, status_text(app)]
+        .spacing(6)
+        .into()
+}
+
+/// Describes the alphabet in use, or why the declared one was rejected.
+fn status_text(app: &App) -> ElementType<'_> {
+    if let Some(err) = &app.alphabet_error {
+        return text(app.tr_fmt(Key::AlphabetRejected, &[err]))
+            .size(TextSize::Small)
+            .class(TextClass::Error)
+            .into();
+    }
+
+    let Some(artifacts) = &app.build_artifacts else {
+        return text(app.tr(Key::AlphabetHint))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary)
+            .into();
+    };
+
+    let used = artifacts.nfa.alphabet();
+    let symbols = artifacts
+        .alphabet
+        .iter()
+        .map(char::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let extra = artifacts.alphabet.len() - used.len();
+    let sigma = format!("Σ = {{{symbols}}}");
+    let summary = if extra == 0 {
+        sigma
+    } else {
+        app.tr_fmt(Key::AlphabetUnused, &[&sigma, &extra])
+    };
+    text(summary)
+        .size(TextSize::Small)
+        .class(TextClass::Secondary)
+        .into()
+}
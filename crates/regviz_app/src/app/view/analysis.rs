@@ -0,0 +1,374 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::canvas::{self, Frame, Path, Program, Text};
+use iced::widget::{Canvas, column, container, row, scrollable, text};
+use iced::{Length, Pixels, Point, Rectangle, Size, mouse};
+use iced_graphics::geometry::Renderer;
+use regviz_core::core::BuildArtifacts;
+use regviz_core::core::analysis::{self, AutomatonStats};
+use regviz_core::core::dfa::Dfa;
+
+use crate::app::APP_FONT;
+use crate::app::i18n::{Key, Locale};
+use crate::app::message::Message;
+use crate::app::state::App;
+use crate::app::theme::{AppTheme, ElementType, TextClass, TextSize};
+
+/// Longest word length included in the word-count chart.
+const WORD_COUNT_MAX_LEN: usize = 12;
+/// Height of the word-count chart in pixels.
+const CHART_HEIGHT: f32 = 220.0;
+/// Vertical space reserved above and below the bars for labels.
+const CHART_LABEL_SPACE: f32 = 20.0;
+/// Fraction of each slot occupied by its bar.
+const BAR_WIDTH_RATIO: f32 = 0.7;
+/// Font size of the chart labels.
+const CHART_LABEL_SIZE: f32 = 12.0;
+/// Width of each automaton column in the statistics table.
+const STATS_COLUMN_WIDTH: f32 = 80.0;
+/// Most lower-bound witness words listed before the rest are elided.
+const FOOLING_SET_SHOWN: usize = 16;
+/// Most transition monoid elements enumerated, since a monoid can be huge.
+const MONOID_LIMIT: usize = 1_000;
+/// Most transition monoid elements listed in the table.
+const MONOID_ROWS_SHOWN: usize = 24;
+/// Width of the word column of the transition monoid table.
+const MONOID_WORD_WIDTH: f32 = 96.0;
+/// Width of each state column of the transition monoid table.
+const MONOID_STATE_WIDTH: f32 = 36.0;
+
+/// Renders the analysis tab for the current automata.
+pub fn render<'a>(app: &App, artifacts: &'a BuildArtifacts) -> ElementType<'a> {
+    let Some(dfa) = artifacts.min_dfa.as_ref().or(artifacts.dfa.as_ref()) else {
+        return text(app.tr(Key::MinimizedUnavailable))
+            .size(TextSize::Body)
+            .class(TextClass::Warning)
+            .into();
+    };
+
+    let counts = dfa.count_words(WORD_COUNT_MAX_LEN);
+    let content = column![
+        statistics_section(app, artifacts),
+        complexity_section(app, artifacts, dfa),
+        monoid_section(app, dfa),
+        word_count_section(app, counts)
+    ]
+    .spacing(24);
+
+    scrollable(content).height(Length::Fill).into()
+}
+
+/// Renders a table comparing the sizes of the NFA, DFA and minimized DFA,
+/// followed by how much minimization shrank the DFA.
+fn statistics_section<'a>(app: &App, artifacts: &BuildArtifacts) -> ElementType<'a> {
+    let columns = [
+        Some(analysis::nfa_stats(&artifacts.nfa)),
+        artifacts.dfa.as_ref().map(analysis::dfa_stats),
+        artifacts.min_dfa.as_ref().map(analysis::dfa_stats),
+    ];
+    let cell = |value: String, class: TextClass| {
+        text(value)
+            .size(TextSize::Small)
+            .class(class)
+            .width(Length::Fixed(STATS_COLUMN_WIDTH))
+    };
+    let table_row = |label: String, measure: fn(&AutomatonStats) -> usize| {
+        columns.iter().fold(
+            row![
+                text(label)
+                    .size(TextSize::Small)
+                    .class(TextClass::Secondary)
+                    .width(Length::Fill)
+            ],
+            |line, stats| {
+                let value = stats.as_ref().map_or_else(
+                    || "—".to_string(),
+                    |stats| app.locale.number(measure(stats) as u128),
+                );
+                line.push(cell(value, TextClass::Primary))
+            },
+        )
+    };
+
+    let header = row![
+        text("").width(Length::Fill),
+        cell(app.tr(Key::TabNfa).to_string(), TextClass::Secondary),
+        cell(app.tr(Key::TabDfa).to_string(), TextClass::Secondary),
+        cell(app.tr(Key::TabMinDfa).to_string(), TextClass::Secondary),
+    ];
+
+    let mut content = column![
+        text(app.tr(Key::Statistics))
+            .size(TextSize::H3)
+            .class(TextClass::Primary),
+        header,
+        table_row(app.tr(Key::StatStates).to_string(), |stats| stats.states),
+        table_row(app.tr(Key::StatTransitions).to_string(), |stats| stats
+            .edges),
+        table_row(
+            app.tr_fmt(Key::StatEpsilonTransitions, &[&app.epsilon_glyph]),
+            |stats| stats.epsilon_edges
+        ),
+        table_row(app.tr(Key::StatDeadStates).to_string(), |stats| stats.dead),
+        table_row(app.tr(Key::StatUnreachableStates).to_string(), |stats| {
+            stats.unreachable
+        }),
+    ]
+    .spacing(6);
+
+    if let [_, Some(dfa), Some(min_dfa)] = columns
+        && dfa.states > 0
+    {
+        let removed = dfa.states.saturating_sub(min_dfa.states);
+        content = content.push(
+            text(app.tr_fmt(
+                Key::MinimizationReduction,
+                &[
+                    &app.locale.number(removed as u128),
+                    &app.locale.number(dfa.states as u128),
+                    &app.locale.percent(removed as f32 / dfa.states as f32),
+                ],
+            ))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+        );
+    }
+
+    content.into()
+}
+
+/// Renders the star height of the expression and the minimal DFA size, with
+/// the words proving that no smaller DFA exists.
+fn complexity_section<'a>(app: &App, artifacts: &BuildArtifacts, dfa: &Dfa) -> ElementType<'a> {
+    let words = analysis::fooling_set(dfa);
+    let star_height = artifacts.ast.as_ref().map_or_else(
+        || "—".to_string(),
+        |ast| app.locale.number(ast.star_height() as u128),
+    );
+    let minimal_states = app.locale.number(words.len() as u128);
+    let measure = |label: Key, value: String| {
+        row![
+            text(app.tr(label))
+                .size(TextSize::Small)
+                .class(TextClass::Secondary)
+                .width(Length::Fill),
+            text(value)
+                .size(TextSize::Small)
+                .class(TextClass::Primary)
+                .width(Length::Fixed(STATS_COLUMN_WIDTH)),
+        ]
+    };
+
+    let mut shown: Vec<String> = words
+        .iter()
+        .take(FOOLING_SET_SHOWN)
+        .map(|word| app.epsilon_glyph.or_word(word))
+        .collect();
+    if words.len() > FOOLING_SET_SHOWN {
+        shown.push("…".to_string());
+    }
+
+    column![
+        text(app.tr(Key::Complexity))
+            .size(TextSize::H3)
+            .class(TextClass::Primary),
+        measure(Key::StarHeight, star_height),
+        measure(Key::MinimalDfaStates, minimal_states.clone()),
+        text(app.tr_fmt(Key::FoolingSetCaption, &[&minimal_states]))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+        text(shown.join(", "))
+            .size(TextSize::Small)
+            .class(TextClass::Primary),
+    ]
+    .spacing(6)
+    .into()
+}
+
+/// Renders the transition monoid of `dfa` as a table with one row per
+/// element, mapping every state to the state its word leads to.
+fn monoid_section<'a>(app: &App, dfa: &Dfa) -> ElementType<'a> {
+    let Ok(monoid) = analysis::transition_monoid(dfa, MONOID_LIMIT) else {
+        return column![].into();
+    };
+    let size = app.locale.number(monoid.size() as u128);
+    let summary = if monoid.truncated {
+        app.tr_fmt(Key::MonoidSizeAtLeast, &[&size])
+    } else {
+        let idempotents = app.locale.number(monoid.idempotents().count() as u128);
+        app.tr_fmt(Key::MonoidSize, &[&size, &idempotents])
+    };
+    let cell = |value: String, width: f32, class: TextClass| {
+        text(value)
+            .size(TextSize::Small)
+            .class(class)
+            .width(Length::Fixed(width))
+    };
+
+    let mut header = row![cell(
+        app.tr(Key::MonoidWord).to_string(),
+        MONOID_WORD_WIDTH,
+        TextClass::Secondary
+    )];
+    for state in 0..dfa.trans.len() {
+        header = header.push(cell(
+            state.to_string(),
+            MONOID_STATE_WIDTH,
+            TextClass::Secondary,
+        ));
+    }
+    header = header.push(cell(
+        app.tr(Key::MonoidIdempotent).to_string(),
+        MONOID_WORD_WIDTH,
+        TextClass::Secondary,
+    ));
+
+    let mut table = column![header].spacing(4);
+    for element in monoid.elements.iter().take(MONOID_ROWS_SHOWN) {
+        let mut line = row![cell(
+            app.epsilon_glyph.or_word(&element.word),
+            MONOID_WORD_WIDTH,
+            TextClass::Primary
+        )];
+        for next in &element.map {
+            line = line.push(cell(
+                next.to_string(),
+                MONOID_STATE_WIDTH,
+                TextClass::Primary,
+            ));
+        }
+        let idempotent = if element.idempotent { "✓" } else { "" };
+        line = line.push(cell(
+            idempotent.to_string(),
+            MONOID_WORD_WIDTH,
+            TextClass::Primary,
+        ));
+        table = table.push(line);
+    }
+
+    let mut content = column![
+        text(app.tr(Key::TransitionMonoid))
+            .size(TextSize::H3)
+            .class(TextClass::Primary),
+        text(summary)
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+        text(app.tr(Key::MonoidCaption))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+        scrollable(table).direction(scrollable::Direction::Horizontal(Default::default())),
+    ]
+    .spacing(6);
+    if monoid.size() > MONOID_ROWS_SHOWN {
+        let hidden = app
+            .locale
+            .number((monoid.size() - MONOID_ROWS_SHOWN) as u128);
+        content = content.push(
+            text(app.tr_fmt(Key::MonoidMoreElements, &[&hidden]))
+                .size(TextSize::Small)
+                .class(TextClass::Secondary),
+        );
+    }
+    content.into()
+}
+
+/// Renders the accepted-words-per-length bar chart with a short caption.
+fn word_count_section<'a>(app: &App, counts: Vec<u128>) -> ElementType<'a> {
+    let chart = Canvas::new(WordCountChart {
+        counts,
+        locale: app.locale,
+    })
+    .width(Length::Fill)
+    .height(Length::Fixed(CHART_HEIGHT));
+
+    column![
+        text(app.tr(Key::WordsPerLength))
+            .size(TextSize::H3)
+            .class(TextClass::Primary),
+        text(app.tr(Key::WordsPerLengthCaption))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+        container(chart).width(Length::Fill),
+    ]
+    .spacing(8)
+    .into()
+}
+
+/// Formats a word count compactly so it fits above a bar.
+fn format_count(locale: Locale, count: u128) -> String {
+    if count < 100_000 {
+        locale.number(count)
+    } else {
+        locale.scientific(count as f64)
+    }
+}
+
+/// Canvas program drawing one bar per word length.
+struct WordCountChart {
+    counts: Vec<u128>,
+    /// Locale the counts are formatted for.
+    locale: Locale,
+}
+
+impl<R> Program<Message, AppTheme, R> for WordCountChart
+where
+    R: Renderer,
+{
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &R,
+        theme: &AppTheme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry<R>> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        if self.counts.is_empty() {
+            return vec![frame.into_geometry()];
+        }
+
+        let max = self.counts.iter().copied().max().unwrap_or(0).max(1) as f64;
+        let slot = bounds.width / self.counts.len() as f32;
+        let bar_width = slot * BAR_WIDTH_RATIO;
+        let baseline = bounds.height - CHART_LABEL_SPACE;
+        let usable = baseline - CHART_LABEL_SPACE;
+
+        for (len, count) in self.counts.iter().enumerate() {
+            let center_x = slot * (len as f32 + 0.5);
+            let height = (*count as f64 / max) as f32 * usable;
+            let top = baseline - height;
+
+            if height > 0.0 {
+                let bar = Path::rectangle(
+                    Point::new(center_x - bar_width / 2.0, top),
+                    Size::new(bar_width, height),
+                );
+                frame.fill(&bar, theme.accent());
+            }
+
+            frame.fill_text(Text {
+                content: format_count(self.locale, *count),
+                position: Point::new(center_x, top - 2.0),
+                color: theme.text_primary(),
+                font: APP_FONT,
+                size: Pixels(CHART_LABEL_SIZE),
+                align_x: Horizontal::Center.into(),
+                align_y: Vertical::Bottom,
+                ..Text::default()
+            });
+            frame.fill_text(Text {
+                content: len.to_string(),
+                position: Point::new(center_x, baseline + 4.0),
+                color: theme.text_secondary(),
+                font: APP_FONT,
+                size: Pixels(CHART_LABEL_SIZE),
+                align_x: Horizontal::Center.into(),
+                align_y: Vertical::Top,
+                ..Text::default()
+            });
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
@@ -0,0 +1,159 @@
+use iced::{
+    Alignment,
+    widget::{button, row, text},
+};
+use regviz_core::core::parser::ParseEvent;
+
+use crate::app::{
+    construction::Replay,
+    i18n::Key,
+    message::{ConstructionMessage, Message, ParseTraceMessage},
+    state::App,
+    theme::{ButtonClass, ElementType, TextClass, TextSize},
+};
+
+use super::controls::box_kind_label;
+
+/// Messages sent by the playback controls of one replay.
+struct PlaybackMessages {
+    back: Message,
+    toggle: Message,
+    forward: Message,
+    stop: Message,
+}
+
+/// Renders the controls replaying Thompson's construction on the NFA canvas,
+/// with the fragment just built and the part of the pattern it came from.
+pub fn nfa_construction(app: &App) -> ElementType<'_> {
+    let Some(replay) = &app.construction else {
+        return control(
+            app.tr(Key::ReplayConstruction),
+            Some(Message::Construction(ConstructionMessage::Start)),
+        );
+    };
+
+    let step = replay.current();
+    let caption = app.tr_fmt(
+        Key::ConstructionStep,
+        &[
+            &(replay.cursor + 1),
+            &replay.steps.len(),
+            &app.tr(box_kind_label(step.kind)),
+            &step.node.display_with(app.epsilon_glyph),
+        ],
+    );
+    let messages = PlaybackMessages {
+        back: Message::Construction(ConstructionMessage::StepBackward),
+        toggle: Message::Construction(ConstructionMessage::TogglePlay),
+        forward: Message::Construction(ConstructionMessage::StepForward),
+        stop: Message::Construction(ConstructionMessage::Stop),
+    };
+    playback(app, replay, messages, Key::ShowFullNfa, caption)
+}
+
+/// Renders the controls replaying how the parser built the AST, explaining
+/// each token it read and each precedence decision it made.
+pub fn parse_trace(app: &App) -> ElementType<'_> {
+    let Some(replay) = &app.parse_trace else {
+        return control(
+            app.tr(Key::ReplayParse),
+            Some(Message::ParseTrace(ParseTraceMessage::Start)),
+        );
+    };
+
+    let operand = replay
+        .last_built()
+        .map(|(_, node)| node.display_with(app.epsilon_glyph).to_string())
+        .unwrap_or_default();
+    let operator = |op, implicit| {
+        if implicit {
+            app.tr(Key::ImplicitConcat).to_string()
+        } else {
+            format!("'{op}'")
+        }
+    };
+    let event = match replay.current() {
+        ParseEvent::Consume { token, at } => {
+            app.tr_fmt(Key::ParseConsumed, &[&format!("'{token}'"), &(at + 1)])
+        }
+        &ParseEvent::Bind {
+            op,
+            power,
+            min_bp,
+            implicit,
+            ..
+        } => app.tr_fmt(
+            Key::ParseBound,
+            &[&operator(op, implicit), &power, &min_bp, &operand],
+        ),
+        &ParseEvent::Yield {
+            op,
+            power,
+            min_bp,
+            implicit,
+            ..
+        } => app.tr_fmt(
+            Key::ParseYielded,
+            &[&operator(op, implicit), &power, &min_bp, &operand],
+        ),
+        ParseEvent::Build { node, .. } => {
+            app.tr_fmt(Key::ParseBuilt, &[&node.display_with(app.epsilon_glyph)])
+        }
+    };
+    let caption = app.tr_fmt(
+        Key::ParseTraceStep,
+        &[&(replay.cursor + 1), &replay.steps.len(), &event],
+    );
+    let messages = PlaybackMessages {
+        back: Message::ParseTrace(ParseTraceMessage::StepBackward),
+        toggle: Message::ParseTrace(ParseTraceMessage::TogglePlay),
+        forward: Message::ParseTrace(ParseTraceMessage::StepForward),
+        stop: Message::ParseTrace(ParseTraceMessage::Stop),
+    };
+    playback(app, replay, messages, Key::ShowFullTree, caption)
+}
+
+/// Renders the back, play, forward and stop buttons of `replay` next to `caption`.
+fn playback<T>(
+    app: &App,
+    replay: &Replay<T>,
+    messages: PlaybackMessages,
+    stop: Key,
+    caption: String,
+) -> ElementType<'static> {
+    row![
+        control(
+            app.tr(Key::Previous),
+            replay.can_step_backward().then_some(messages.back),
+        ),
+        control(
+            app.tr(if replay.playing {
+                Key::Pause
+            } else {
+                Key::Play
+            }),
+            Some(messages.toggle),
+        ),
+        control(
+            app.tr(Key::Next),
+            replay.can_step_forward().then_some(messages.forward),
+        ),
+        control(app.tr(stop), Some(messages.stop)),
+        text(caption)
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center)
+    .wrap()
+    .into()
+}
+
+/// Renders a replay button, disabled when it has no `message` to send.
+fn control(label: &'static str, message: Option<Message>) -> ElementType<'static> {
+    button(text(label).size(TextSize::Small))
+        .class(ButtonClass::Secondary)
+        .padding([4, 12])
+        .on_press_maybe(message)
+        .into()
+}
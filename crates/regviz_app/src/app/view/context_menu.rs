@@ -0,0 +1,140 @@
+use iced::widget::{Space, button, column, container, float, mouse_area, pin, stack, text};
+use iced::{Length, Vector};
+
+use crate::app::i18n::Key;
+use crate::app::message::{CanvasElement, Message, SimulationMessage, ViewMessage, ViewMode};
+use crate::app::simulation::SimulationTarget;
+use crate::app::state::App;
+use crate::app::theme::{ButtonClass, ContainerClass, ElementType, TextSize};
+
+/// Width of the context menu, in pixels.
+const MENU_WIDTH: f32 = 220.0;
+
+/// Lays the context menu open in the `mode` view, if any, over its `canvas`.
+///
+/// The menu floats above the rest of the window at the right-clicked point,
+/// shifted back inside the window when opened near its edge. Clicking
+/// anywhere else on the canvas dismisses it.
+pub fn overlay<'a>(app: &'a App, mode: ViewMode, canvas: ElementType<'a>) -> ElementType<'a> {
+    let Some(menu) = app.view_state.data_for(mode).context_menu else {
+        return canvas;
+    };
+    let actions = actions(app, mode, menu.element);
+    if actions.is_empty() {
+        return canvas;
+    }
+
+    let items = column(actions.into_iter().map(|(key, message)| {
+        button(text(app.tr(key)).size(TextSize::Small))
+            .class(ButtonClass::Secondary)
+            .padding([6, 12])
+            .width(Length::Fill)
+            .on_press(Message::View(ViewMessage::ChooseContextAction(Box::new(
+                message,
+            ))))
+            .into()
+    }))
+    .spacing(4);
+    let card = container(items)
+        .class(ContainerClass::Rounded)
+        .padding(6)
+        .width(MENU_WIDTH);
+
+    // The menu is laid out at the canvas' corner and moved to the cursor as it floats.
+    let anchor = Vector::new(menu.position.x, menu.position.y);
+    let floating = float(card).translate(move |bounds, viewport| {
+        let x = (bounds.x + anchor.x)
+            .min(viewport.x + viewport.width - bounds.width)
+            .max(viewport.x);
+        let y = (bounds.y + anchor.y)
+            .min(viewport.y + viewport.height - bounds.height)
+            .max(viewport.y);
+        Vector::new(x - bounds.x, y - bounds.y)
+    });
+
+    let close = Message::View(ViewMessage::CloseContextMenu);
+    let dismiss = mouse_area(Space::new().width(Length::Fill).height(Length::Fill))
+        .on_press(close.clone())
+        .on_right_press(close);
+
+    stack![canvas, dismiss, pin(floating)]
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}
+
+/// Lists the actions offered on `element` in the `mode` view, with the
+/// message carrying out each one.
+fn actions(app: &App, mode: ViewMode, element: CanvasElement) -> Vec<(Key, Message)> {
+    let data = app.view_state.data_for(mode);
+    let simulated = SimulationTarget::for_view(mode) == Some(app.simulation.target);
+    let dfa_view = matches!(mode, ViewMode::Dfa | ViewMode::MinDfa);
+    let mut actions = Vec::new();
+
+    match element {
+        CanvasElement::State(id, position) => {
+            actions.push(if data.pinned_node_positions.contains_key(&id) {
+                (Key::UnpinState, Message::View(ViewMessage::UnpinState(id)))
+            } else {
+                (
+                    Key::PinState,
+                    Message::View(ViewMessage::PinState(id, position)),
+                )
+            });
+            if dfa_view {
+                actions.push((
+                    Key::RenameState,
+                    Message::View(ViewMessage::StartRenameState(id)),
+                ));
+            }
+            if simulated {
+                let key = if app.simulation.breakpoints.contains(&id) {
+                    Key::RemoveBreakpoint
+                } else {
+                    Key::SetBreakpoint
+                };
+                actions.push((
+                    key,
+                    Message::Simulation(SimulationMessage::ToggleBreakpoint(id)),
+                ));
+            }
+            if dfa_view {
+                actions.push((
+                    Key::CopyRightLanguage,
+                    Message::View(ViewMessage::CopyRightLanguage(id)),
+                ));
+            }
+        }
+        CanvasElement::Transition(from, to) => {
+            if data.selected_edge != Some((from, to)) {
+                actions.push((
+                    Key::SelectTransition,
+                    Message::View(ViewMessage::SelectEdge(from, to)),
+                ));
+            }
+            if simulated {
+                let key = if app.simulation.edge_breakpoints.contains(&(from, to)) {
+                    Key::RemoveBreakpoint
+                } else {
+                    Key::SetBreakpoint
+                };
+                actions.push((
+                    key,
+                    Message::Simulation(SimulationMessage::ToggleEdgeBreakpoint(from, to)),
+                ));
+            }
+        }
+        // Boxes folded by the depth cap unfold with it, not one by one.
+        CanvasElement::Box(id, collapsed) => {
+            if !collapsed {
+                actions.push((
+                    Key::CollapseBox,
+                    Message::View(ViewMessage::CollapseBox(id)),
+                ));
+            } else if data.collapsed_boxes.contains(&id) {
+                actions.push((Key::ExpandBox, Message::View(ViewMessage::ExpandBox(id))));
+            }
+        }
+    }
+    actions
+}
@@ -2,43 +2,845 @@ use iced::{
     Alignment, Length,
     widget::{button, column, row, slider, text},
 };
-use regviz_core::core::automaton::BoxKind;
+use regviz_core::core::{
+    analysis,
+    automaton::{BoxKind, EpsilonGlyph},
+};
 
-use crate::app::message::{Message, ViewMessage, ViewMode};
+use crate::app::i18n::{Key, Locale};
+use crate::app::layout::LayoutPreset;
+use crate::app::message::{
+    Message, PaneGridMessage, StateNumbering, ThemePreference, ViewMessage, ViewMode,
+};
 use crate::app::state::App;
 use crate::app::{
-    constants::{MAX_ZOOM_FACTOR, MIN_ZOOM_FACTOR},
+    constants::{MAX_ZOOM_FACTOR, MAX_ZOOM_STEP, MIN_ZOOM_FACTOR, MIN_ZOOM_STEP},
     theme::{ButtonClass, ElementType, TextClass, TextSize},
 };
+use crate::graph::layout::Orientation;
+use crate::graph::layout::nfa::nesting_levels;
+use crate::graph::{BoxStyle, ExportQuality, LayoutConfig, TransitionGrouping};
+
+/// Every kind of bounding box with the name shown on its controls.
+const BOX_KINDS: [(BoxKind, Key); 6] = [
+    (BoxKind::Literal, Key::BoxLiteral),
+    (BoxKind::Concat, Key::BoxConcat),
+    (BoxKind::Alternation, Key::BoxAlternation),
+    (BoxKind::KleeneStar, Key::BoxStar),
+    (BoxKind::KleenePlus, Key::BoxPlus),
+    (BoxKind::Optional, Key::BoxOptional),
+];
+
+/// Largest corner radius offered for bounding boxes, in pixels.
+const MAX_BOX_CORNER_RADIUS: f32 = 24.0;
+
+/// Distances between states offered for layouts, in layout units.
+const NODE_SPACINGS: std::ops::RangeInclusive<f32> = 100.0..=320.0;
+
+/// Distances between levels offered for layouts, in layout units.
+const LEVEL_SPACINGS: std::ops::RangeInclusive<f32> = 100.0..=320.0;
+
+/// Gaps between alternation branches offered for layouts, in layout units.
+const BRANCH_GAPS: std::ops::RangeInclusive<f32> = 40.0..=300.0;
+
+/// Bounding box paddings offered for layouts, in layout units.
+const BOX_PADDINGS: std::ops::RangeInclusive<f32> = 20.0..=80.0;
+
+/// State radii offered for exported figures, in layout units.
+const EXPORT_NODE_RADII: std::ops::RangeInclusive<f32> = 16.0..=48.0;
+
+/// Stroke and text scales offered for exported figures.
+const EXPORT_SCALES: std::ops::RangeInclusive<f32> = 0.5..=4.0;
+
+/// Highest pixel density offered for exported figures.
+const MAX_EXPORT_SUPERSAMPLING: u32 = 4;
+
+/// Returns the name shown for bounding boxes of `kind`.
+pub fn box_kind_label(kind: BoxKind) -> Key {
+    BOX_KINDS
+        .iter()
+        .find(|&&(candidate, _)| candidate == kind)
+        .map_or(Key::BoxLiteral, |&(_, label)| label)
+}
 
 /// Renders buttons for toggling bounding box visibility (NFA only).
 pub fn bounding_boxes(app: &App) -> ElementType<'_> {
     let enabled = matches!(app.view_mode(), ViewMode::Nfa);
-    let toggles = row![
-        box_toggle_button(app, BoxKind::Literal, "Literal", enabled),
-        box_toggle_button(app, BoxKind::Concat, "Concat", enabled),
-        box_toggle_button(app, BoxKind::Alternation, "Alt", enabled),
-        box_toggle_button(app, BoxKind::KleeneStar, "Star", enabled),
-        box_toggle_button(app, BoxKind::KleenePlus, "Plus", enabled),
-        box_toggle_button(app, BoxKind::Optional, "Optional", enabled),
+    let toggles = row(BOX_KINDS
+        .iter()
+        .map(|&(kind, label)| box_toggle_button(app, kind, label, enabled)))
+    .spacing(8)
+    .wrap();
+
+    column![
+        text(app.tr(Key::BoundingBoxes))
+            .size(TextSize::Body)
+            .class(TextClass::Secondary),
+        toggles,
+        box_depth(app, enabled),
+    ]
+    .spacing(4)
+    .into()
+}
+
+/// Renders the depth dimming toggle, the slider limiting how many nesting
+/// levels of boxes are shown, and the slider folding deeper levels into
+/// super-nodes.
+fn box_depth(app: &App, enabled: bool) -> ElementType<'_> {
+    let mut content = row![overlay_toggle_button(
+        app.tr(Key::DimNestedBoxes),
+        app.dim_nested_boxes,
+        enabled,
+        ViewMessage::ToggleDepthDimming,
+    )]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let levels = app
+        .build_artifacts
+        .as_ref()
+        .map_or(0, |artifacts| nesting_levels(&artifacts.nfa.boxes));
+    if !enabled || levels < 2 {
+        return content.wrap().into();
+    }
+
+    let shown = app
+        .box_visibility
+        .max_depth()
+        .map_or(levels, |depth| depth + 1)
+        .min(levels);
+    content = content
+        .push(
+            text(app.tr_fmt(
+                Key::NestingLevels,
+                &[
+                    &app.locale.number(shown as u128),
+                    &app.locale.number(levels as u128),
+                ],
+            ))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+        )
+        .push(
+            slider(1.0..=levels as f32, shown as f32, move |value| {
+                let shown = value as usize;
+                Message::View(ViewMessage::SetMaxBoxDepth(
+                    (shown < levels).then(|| shown - 1),
+                ))
+            })
+            .step(1.0)
+            .width(Length::Fixed(120.0)),
+        );
+
+    let expanded = app
+        .collapse_depth
+        .map_or(levels, |depth| depth + 1)
+        .min(levels);
+    content = content
+        .push(
+            text(app.tr_fmt(
+                Key::ExpandedLevels,
+                &[
+                    &app.locale.number(expanded as u128),
+                    &app.locale.number(levels as u128),
+                ],
+            ))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+        )
+        .push(
+            slider(1.0..=levels as f32, expanded as f32, move |value| {
+                let expanded = value as usize;
+                Message::View(ViewMessage::SetCollapseDepth(
+                    (expanded < levels).then(|| expanded - 1),
+                ))
+            })
+            .step(1.0)
+            .width(Length::Fixed(120.0)),
+        );
+    content.wrap().into()
+}
+
+/// Renders the style options of one kind of bounding box (NFA only).
+pub fn box_style(app: &App) -> ElementType<'_> {
+    let enabled = matches!(app.view_mode(), ViewMode::Nfa);
+    let kind = app.styled_box_kind;
+    let style = app.box_styles.get(kind);
+
+    let kinds = row(BOX_KINDS.iter().map(|&(option, label)| {
+        let mut choice = button(text(app.tr(label)).size(TextSize::Small))
+            .class(if option == kind {
+                ButtonClass::Primary
+            } else {
+                ButtonClass::Secondary
+            })
+            .padding([4, 12]);
+        if enabled {
+            choice = choice.on_press(Message::View(ViewMessage::SelectStyledBox(option)));
+        }
+        choice.into()
+    }))
+    .spacing(4)
+    .wrap();
+
+    let options = row![
+        overlay_toggle_button(
+            app.tr(Key::BoxStyleLabel),
+            style.show_label,
+            enabled,
+            ViewMessage::SetBoxStyle(
+                kind,
+                BoxStyle {
+                    show_label: !style.show_label,
+                    ..style
+                }
+            ),
+        ),
+        overlay_toggle_button(
+            app.tr(Key::BoxStyleDashed),
+            style.dashed,
+            enabled,
+            ViewMessage::SetBoxStyle(
+                kind,
+                BoxStyle {
+                    dashed: !style.dashed,
+                    ..style
+                }
+            ),
+        ),
+        overlay_toggle_button(
+            app.tr(Key::BoxStyleFill),
+            style.filled,
+            enabled,
+            ViewMessage::SetBoxStyle(
+                kind,
+                BoxStyle {
+                    filled: !style.filled,
+                    ..style
+                }
+            ),
+        ),
     ]
     .spacing(8)
     .wrap();
 
+    let radius = app.locale.number(style.corner_radius.round() as u128);
+    let mut corners = row![
+        text(app.tr_fmt(Key::BoxStyleCorners, &[&radius]))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary)
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+    // Sliders cannot be disabled, so the radius is only editable in NFA view.
+    if enabled {
+        corners = corners.push(
+            slider(
+                0.0..=MAX_BOX_CORNER_RADIUS,
+                style.corner_radius,
+                move |value| {
+                    Message::View(ViewMessage::SetBoxStyle(
+                        kind,
+                        BoxStyle {
+                            corner_radius: value,
+                            ..style
+                        },
+                    ))
+                },
+            )
+            .step(1.0)
+            .width(Length::Fixed(160.0)),
+        );
+    }
+
     column![
-        text("Bounding Boxes")
+        text(app.tr(Key::BoxStyle))
             .size(TextSize::Body)
             .class(TextClass::Secondary),
-        toggles
+        kinds,
+        options,
+        corners,
     ]
     .spacing(4)
     .into()
 }
 
+/// Renders the collapsible sliders spacing out the laid out graphs.
+pub fn layout_tuning(app: &App) -> ElementType<'_> {
+    let toggle = button(
+        text(app.tr(if app.show_layout_tuning {
+            Key::LayoutTuningShown
+        } else {
+            Key::LayoutTuningHidden
+        }))
+        .size(TextSize::Body),
+    )
+    .class(ButtonClass::Secondary)
+    .padding([4, 12])
+    .on_press(Message::View(ViewMessage::ToggleLayoutTuning));
+
+    let mut content = column![toggle].spacing(6);
+    if !app.show_layout_tuning {
+        return content.into();
+    }
+
+    let config = app.layout_config;
+    let set = |config| Message::View(ViewMessage::SetLayoutConfig(config));
+    let setting = |label: Key,
+                   value: f32,
+                   range: std::ops::RangeInclusive<f32>,
+                   update: fn(LayoutConfig, f32) -> LayoutConfig|
+     -> ElementType<'_> {
+        let amount = app.locale.number(value.round() as u128);
+        row![
+            text(app.tr_fmt(label, &[&amount]))
+                .size(TextSize::Small)
+                .class(TextClass::Secondary)
+                .width(Length::Fixed(200.0)),
+            slider(range, value, move |value| set(update(config, value)))
+                .step(5.0)
+                .width(Length::Fixed(160.0)),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center)
+        .into()
+    };
+
+    content = content
+        .push(setting(
+            Key::NodeSpacing,
+            config.node_spacing,
+            NODE_SPACINGS,
+            |config, node_spacing| LayoutConfig {
+                node_spacing,
+                ..config
+            },
+        ))
+        .push(setting(
+            Key::LevelSpacing,
+            config.level_spacing,
+            LEVEL_SPACINGS,
+            |config, level_spacing| LayoutConfig {
+                level_spacing,
+                ..config
+            },
+        ))
+        .push(setting(
+            Key::BranchGap,
+            config.branch_gap,
+            BRANCH_GAPS,
+            |config, branch_gap| LayoutConfig {
+                branch_gap,
+                ..config
+            },
+        ))
+        .push(setting(
+            Key::BoxPadding,
+            config.box_padding,
+            BOX_PADDINGS,
+            |config, box_padding| LayoutConfig {
+                box_padding,
+                ..config
+            },
+        ));
+
+    let mut reset = button(text(app.tr(Key::ResetLayoutTuning)).size(TextSize::Small))
+        .class(ButtonClass::Secondary)
+        .padding([4, 12]);
+    if config != LayoutConfig::default() {
+        reset = reset.on_press(set(LayoutConfig::default()));
+    }
+    content.push(reset).into()
+}
+
+/// Returns the name shown for NFA layouts flowing in `orientation`.
+pub fn orientation_label(orientation: Orientation) -> Key {
+    match orientation {
+        Orientation::LeftToRight => Key::OrientationLeftToRight,
+        Orientation::RightToLeft => Key::OrientationRightToLeft,
+        Orientation::TopToBottom => Key::OrientationTopToBottom,
+    }
+}
+
+/// Renders the picker for the direction the NFA flows in (NFA only).
+pub fn nfa_orientation(app: &App) -> ElementType<'_> {
+    let enabled = matches!(app.view_mode(), ViewMode::Nfa);
+    let current = app.nfa_layout.orientation();
+    let options = Orientation::ALL.iter().map(|&orientation| {
+        let mut choice = button(text(app.tr(orientation_label(orientation))).size(TextSize::Small))
+            .class(if orientation == current {
+                ButtonClass::Primary
+            } else {
+                ButtonClass::Secondary
+            })
+            .padding([4, 12]);
+        if enabled {
+            choice = choice.on_press(Message::View(ViewMessage::SetNfaOrientation(orientation)));
+        }
+        choice.into()
+    });
+
+    row![
+        text(app.tr(Key::NfaOrientation))
+            .size(TextSize::Body)
+            .class(TextClass::Secondary),
+        row(options).spacing(4).wrap(),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center)
+    .wrap()
+    .into()
+}
+
+/// Renders overlay toggles for automaton views.
+pub fn overlays(app: &App) -> ElementType<'_> {
+    let automaton_view = matches!(
+        app.view_mode(),
+        ViewMode::Nfa | ViewMode::Dfa | ViewMode::MinDfa
+    );
+    let dfa_view = matches!(app.view_mode(), ViewMode::Dfa | ViewMode::MinDfa);
+
+    let toggles = row![
+        overlay_toggle_button(
+            app.tr(Key::SccColors),
+            app.show_scc_overlay,
+            automaton_view,
+            ViewMessage::ToggleSccOverlay,
+        ),
+        overlay_toggle_button(
+            app.tr(Key::Heatmap),
+            app.show_heatmap,
+            automaton_view,
+            ViewMessage::ToggleHeatmap,
+        ),
+        overlay_toggle_button(
+            app.tr(Key::ShowInfiniteWitness),
+            app.show_infinite_witness,
+            dfa_view,
+            ViewMessage::ToggleInfiniteWitness,
+        ),
+    ]
+    .spacing(8)
+    .wrap();
+
+    let mut content = column![
+        text(app.tr(Key::Overlays))
+            .size(TextSize::Body)
+            .class(TextClass::Secondary),
+        toggles,
+        text(app.tr(Key::SccCaption))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+    ]
+    .spacing(4);
+
+    if automaton_view && app.show_heatmap {
+        content = content.push(heatmap_caption(app));
+    }
+    if dfa_view && app.show_infinite_witness {
+        content = content.push(infinite_witness_caption(app));
+    }
+
+    content.into()
+}
+
+/// Renders the language picker.
+pub fn language(app: &App) -> ElementType<'_> {
+    let options = Locale::ALL.iter().map(|&locale| {
+        let selected = app.locale == locale;
+        button(text(locale.to_string()).size(TextSize::Small))
+            .class(if selected {
+                ButtonClass::Primary
+            } else {
+                ButtonClass::Secondary
+            })
+            .padding([4, 12])
+            .on_press(Message::View(ViewMessage::SetLocale(locale)))
+            .into()
+    });
+
+    row![
+        text(app.tr(Key::Language))
+            .size(TextSize::Body)
+            .class(TextClass::Secondary),
+        row(options).spacing(4),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center)
+    .into()
+}
+
+/// Renders the picker between the light and dark themes and following the system.
+pub fn theme_preference(app: &App) -> ElementType<'_> {
+    let options = ThemePreference::ALL.iter().map(|&preference| {
+        let selected = app.theme_preference == preference;
+        button(text(app.tr(preference.key())).size(TextSize::Small))
+            .class(if selected {
+                ButtonClass::Primary
+            } else {
+                ButtonClass::Secondary
+            })
+            .padding([4, 12])
+            .on_press(Message::View(ViewMessage::SetThemePreference(preference)))
+            .into()
+    });
+
+    row![
+        text(app.tr(Key::Theme))
+            .size(TextSize::Body)
+            .class(TextClass::Secondary),
+        row(options).spacing(4),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center)
+    .wrap()
+    .into()
+}
+
+/// Renders the high-contrast theme and text view toggles.
+pub fn accessibility(app: &App) -> ElementType<'_> {
+    row![
+        overlay_toggle_button(
+            app.tr(Key::HighContrast),
+            app.high_contrast,
+            true,
+            ViewMessage::ToggleHighContrast,
+        ),
+        overlay_toggle_button(
+            app.tr(Key::TextView),
+            app.show_text_view,
+            true,
+            ViewMessage::ToggleTextView,
+        ),
+    ]
+    .spacing(8)
+    .wrap()
+    .into()
+}
+
+/// Renders the zoom step slider and the button restoring every saved
+/// preference to its default.
+pub fn preferences(app: &App) -> ElementType<'_> {
+    let percent = app.locale.number((app.zoom_step * 100.0).round() as u128);
+    row![
+        text(app.tr_fmt(Key::ZoomStep, &[&percent]))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+        slider(MIN_ZOOM_STEP..=MAX_ZOOM_STEP, app.zoom_step, |step| {
+            Message::View(ViewMessage::SetZoomStep(step))
+        })
+        .step(0.01)
+        .width(Length::Fixed(120.0)),
+        button(text(app.tr(Key::ResetSettings)).size(TextSize::Small))
+            .class(ButtonClass::Secondary)
+            .padding([4, 12])
+            .on_press(Message::View(ViewMessage::ResetSettings)),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center)
+    .wrap()
+    .into()
+}
+
+/// Renders the choice of glyph drawn for the empty string.
+pub fn epsilon_glyph(app: &App) -> ElementType<'_> {
+    let options = EpsilonGlyph::ALL.iter().map(|&glyph| {
+        let selected = app.epsilon_glyph == glyph;
+        button(text(glyph.to_string()).size(TextSize::Small))
+            .class(if selected {
+                ButtonClass::Primary
+            } else {
+                ButtonClass::Secondary
+            })
+            .padding([4, 12])
+            .on_press(Message::View(ViewMessage::SetEpsilonGlyph(glyph)))
+            .into()
+    });
+
+    row![
+        text(app.tr(Key::EmptyString))
+            .size(TextSize::Body)
+            .class(TextClass::Secondary),
+        row(options).spacing(4),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center)
+    .into()
+}
+
+/// Renders the picker for how DFA states are numbered.
+pub fn state_numbering(app: &App) -> ElementType<'_> {
+    let options = StateNumbering::ALL.iter().map(|&numbering| {
+        let selected = app.state_numbering == numbering;
+        button(text(app.tr(numbering.key())).size(TextSize::Small))
+            .class(if selected {
+                ButtonClass::Primary
+            } else {
+                ButtonClass::Secondary
+            })
+            .padding([4, 12])
+            .on_press(Message::View(ViewMessage::SetStateNumbering(numbering)))
+            .into()
+    });
+
+    row![
+        text(app.tr(Key::StateNumbers))
+            .size(TextSize::Body)
+            .class(TextClass::Secondary),
+        row(options).spacing(4),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center)
+    .wrap()
+    .into()
+}
+
+/// Returns the name shown for drawing DFA transitions grouped by `grouping`.
+pub fn grouping_label(grouping: TransitionGrouping) -> Key {
+    match grouping {
+        TransitionGrouping::Merged => Key::GroupingMerged,
+        TransitionGrouping::Partitioned => Key::GroupingPartitioned,
+        TransitionGrouping::Separate => Key::GroupingSeparate,
+    }
+}
+
+/// Renders the picker for how DFA transitions between the same two states are drawn.
+pub fn transition_grouping(app: &App) -> ElementType<'_> {
+    let options = TransitionGrouping::ALL.iter().map(|&grouping| {
+        let selected = app.transition_grouping == grouping;
+        button(text(app.tr(grouping_label(grouping))).size(TextSize::Small))
+            .class(if selected {
+                ButtonClass::Primary
+            } else {
+                ButtonClass::Secondary
+            })
+            .padding([4, 12])
+            .on_press(Message::View(ViewMessage::SetTransitionGrouping(grouping)))
+            .into()
+    });
+
+    row![
+        text(app.tr(Key::TransitionGroupingLabel))
+            .size(TextSize::Body)
+            .class(TextClass::Secondary),
+        row(options).spacing(4),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center)
+    .wrap()
+    .into()
+}
+
+/// Renders the sizes and pixel density of exported figures, and the button copying
+/// the current graph view as SVG.
+pub fn image_export(app: &App) -> ElementType<'_> {
+    let quality = app.export_quality;
+    let setting = |label: String, value: ElementType<'static>| -> ElementType<'static> {
+        row![
+            text(label)
+                .size(TextSize::Small)
+                .class(TextClass::Secondary)
+                .width(Length::Fixed(160.0)),
+            value
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center)
+        .into()
+    };
+    let set = |quality| Message::View(ViewMessage::SetExportQuality(quality));
+
+    let radius = app.locale.number(quality.node_radius.round() as u128);
+    let node_radius = setting(
+        app.tr_fmt(Key::ExportNodeRadius, &[&radius]),
+        slider(EXPORT_NODE_RADII, quality.node_radius, move |node_radius| {
+            set(ExportQuality {
+                node_radius,
+                ..quality
+            })
+        })
+        .step(1.0)
+        .width(Length::Fixed(160.0))
+        .into(),
+    );
+    let stroke_scale = setting(
+        app.tr_fmt(
+            Key::ExportStrokeWidth,
+            &[&app.locale.percent(quality.stroke_scale)],
+        ),
+        slider(EXPORT_SCALES, quality.stroke_scale, move |stroke_scale| {
+            set(ExportQuality {
+                stroke_scale,
+                ..quality
+            })
+        })
+        .step(0.25)
+        .width(Length::Fixed(160.0))
+        .into(),
+    );
+    let text_scale = setting(
+        app.tr_fmt(
+            Key::ExportTextSize,
+            &[&app.locale.percent(quality.text_scale)],
+        ),
+        slider(EXPORT_SCALES, quality.text_scale, move |text_scale| {
+            set(ExportQuality {
+                text_scale,
+                ..quality
+            })
+        })
+        .step(0.25)
+        .width(Length::Fixed(160.0))
+        .into(),
+    );
+    let density = app.locale.number(u128::from(quality.supersampling));
+    let supersampling = setting(
+        app.tr_fmt(Key::ExportSupersampling, &[&density]),
+        slider(
+            1..=MAX_EXPORT_SUPERSAMPLING,
+            quality.supersampling,
+            move |supersampling| {
+                set(ExportQuality {
+                    supersampling,
+                    ..quality
+                })
+            },
+        )
+        .width(Length::Fixed(160.0))
+        .into(),
+    );
+
+    let mode = app.view_mode();
+    let exportable = app.build_artifacts.is_some()
+        && !app.show_text_view
+        && matches!(
+            mode,
+            ViewMode::Ast | ViewMode::Nfa | ViewMode::Dfa | ViewMode::MinDfa
+        );
+    let mut copy = button(text(app.tr(Key::CopySvg)).size(TextSize::Small))
+        .class(ButtonClass::Secondary)
+        .padding([6, 12]);
+    if exportable {
+        copy = copy.on_press(Message::View(ViewMessage::CopySvg));
+    }
+
+    let mut content = column![
+        text(app.tr(Key::ImageExport))
+            .size(TextSize::Body)
+            .class(TextClass::Secondary),
+        node_radius,
+        stroke_scale,
+        text_scale,
+        supersampling,
+        copy
+    ]
+    .spacing(6);
+    if app.svg_exported == Some(mode) {
+        content = content.push(
+            text(app.tr(Key::SvgCopied))
+                .size(TextSize::Small)
+                .class(TextClass::Secondary),
+        );
+    }
+
+    content.into()
+}
+
+/// Renders the picker for how the controls and visualization panes are arranged.
+pub fn layout_preset(app: &App) -> ElementType<'_> {
+    let options = LayoutPreset::ALL.iter().map(|&preset| {
+        let selected = app.pane_layout.preset == preset;
+        button(text(app.tr(preset.key())).size(TextSize::Small))
+            .class(if selected {
+                ButtonClass::Primary
+            } else {
+                ButtonClass::Secondary
+            })
+            .padding([4, 12])
+            .on_press(Message::PaneGrid(PaneGridMessage::SelectPreset(preset)))
+            .into()
+    });
+
+    row![
+        text(app.tr(Key::PaneLayout))
+            .size(TextSize::Body)
+            .class(TextClass::Secondary),
+        row(options).spacing(4),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center)
+    .wrap()
+    .into()
+}
+
+/// Explains the heatmap and lists how many transitions each symbol labels.
+fn heatmap_caption(app: &App) -> ElementType<'_> {
+    let heat = match app.view_mode() {
+        ViewMode::Nfa => app
+            .build_artifacts
+            .as_ref()
+            .map(|artifacts| analysis::nfa_heat(&artifacts.nfa)),
+        _ => app.inspected_dfa().map(analysis::dfa_heat),
+    };
+
+    let mut content = column![
+        text(app.tr(Key::HeatmapCaption))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary)
+    ]
+    .spacing(4);
+    if let Some(heat) = heat {
+        let epsilon = (heat.epsilon > 0).then(|| (app.epsilon_glyph.symbol(), heat.epsilon));
+        let counts: Vec<String> = heat
+            .symbols
+            .iter()
+            .copied()
+            .chain(epsilon)
+            .map(|(symbol, count)| format!("{symbol} ×{count}"))
+            .collect();
+        content = content.push(
+            text(app.tr_fmt(Key::SymbolCounts, &[&counts.join(", ")]))
+                .size(TextSize::Small)
+                .class(TextClass::Primary),
+        );
+    }
+    content.into()
+}
+
+/// Explains the highlighted lasso, or states that the language is finite.
+fn infinite_witness_caption(app: &App) -> ElementType<'_> {
+    let Some(dfa) = app.inspected_dfa() else {
+        return text(app.tr(Key::DfaUnavailable))
+            .size(TextSize::Small)
+            .class(TextClass::Warning)
+            .into();
+    };
+
+    let Some(lasso) = analysis::infinite_witness(dfa) else {
+        return text(app.tr(Key::LanguageFinite))
+            .size(TextSize::Small)
+            .class(TextClass::Primary)
+            .into();
+    };
+
+    let show = |word: String| app.epsilon_glyph.or_word(&word);
+    text(app.tr_fmt(
+        Key::LassoCaption,
+        &[
+            &show(analysis::spell(&lasso.stem)),
+            &show(analysis::spell(&lasso.cycle)),
+            &show(analysis::spell(&lasso.suffix)),
+            &lasso.pumped(2),
+        ],
+    ))
+    .size(TextSize::Small)
+    .class(TextClass::Primary)
+    .into()
+}
+
 /// Renders zoom controls with slider and percentage display.
 pub fn zoom(app: &App) -> ElementType<'_> {
-    let zoom_percentage = (app.view_data().zoom_factor * 100.0).round() as i32;
-    let zoom_display = text(format!("Zoom: {zoom_percentage}%"))
+    let zoom_percentage = app.locale.percent(app.view_data().zoom_factor);
+    let zoom_display = text(app.tr_fmt(Key::Zoom, &[&zoom_percentage]))
         .size(TextSize::Body)
         .class(TextClass::Secondary);
 
@@ -51,7 +853,7 @@ pub fn zoom(app: &App) -> ElementType<'_> {
     .width(Length::Fixed(200.0));
 
     let reset_button = button(
-        text("Reset View")
+        text(app.tr(Key::ResetView))
             .size(TextSize::Small)
             .class(TextClass::Primary),
     )
@@ -66,14 +868,35 @@ pub fn zoom(app: &App) -> ElementType<'_> {
         .into()
 }
 
-fn box_toggle_button<'a>(
-    app: &App,
-    kind: BoxKind,
+fn overlay_toggle_button<'a>(
     label: &'a str,
+    is_on: bool,
     enabled: bool,
+    message: ViewMessage,
 ) -> ElementType<'a> {
+    let active = enabled && is_on;
+    let mut toggle = button(text(label).size(TextSize::Small).class(if active {
+        TextClass::Primary
+    } else {
+        TextClass::Secondary
+    }))
+    .class(if active {
+        ButtonClass::Primary
+    } else {
+        ButtonClass::Secondary
+    })
+    .padding([6, 12]);
+
+    if enabled {
+        toggle = toggle.on_press(Message::View(message));
+    }
+
+    toggle.into()
+}
+
+fn box_toggle_button<'a>(app: &App, kind: BoxKind, label: Key, enabled: bool) -> ElementType<'a> {
     let is_visible = app.box_visibility.is_visible(kind);
-    let text_label = text(label)
+    let text_label = text(app.tr(label))
         .size(TextSize::Small)
         .class(if enabled && is_visible {
             TextClass::Primary
@@ -0,0 +1,191 @@
+use iced::{
+    Alignment, Length,
+    widget::{button, column, row, scrollable, text},
+};
+use regviz_core::core::formal::FormalDefinition;
+
+use crate::app::{
+    i18n::Key,
+    message::{DeltaFormat, Message, ViewMessage},
+    simulation::SimulationTarget,
+    state::App,
+    theme::{ButtonClass, ElementType, TextClass, TextSize},
+};
+
+/// Width of each column of the transition table.
+const DELTA_COLUMN_WIDTH: f32 = 96.0;
+
+/// Width of the column marking the start and accepting states.
+const MARKER_COLUMN_WIDTH: f32 = 32.0;
+
+/// Builds the formal definition of the simulated automaton, naming states the
+/// way its canvas does.
+///
+/// # Returns
+/// - `Option<FormalDefinition>` - The definition, or `None` when nothing was
+///   built or the simulated DFA is unavailable.
+pub fn formal_definition(app: &App) -> Option<FormalDefinition> {
+    let artifacts = app.build_artifacts.as_ref()?;
+    let definition = match app.simulation.target {
+        SimulationTarget::Nfa => FormalDefinition::from_nfa(&artifacts.nfa),
+        target => {
+            // Mirror the canvas fallbacks when only one of the DFAs was built.
+            let dfa = if target == SimulationTarget::Dfa {
+                artifacts.dfa.as_ref().or(artifacts.min_dfa.as_ref())?
+            } else {
+                artifacts.min_dfa.as_ref().or(artifacts.dfa.as_ref())?
+            };
+            FormalDefinition::from_dfa(dfa).with_labels(app.state_labels(target.view(), dfa))
+        }
+    };
+    Some(definition.with_epsilon_glyph(app.epsilon_glyph))
+}
+
+/// Renders the simulated automaton as its five-tuple `(Q, Σ, δ, q₀, F)`, with
+/// `δ` as a table or a function listing and a button copying it as LaTeX.
+pub fn render(app: &App) -> ElementType<'_> {
+    let target = app.simulation.target;
+    let targets = [
+        (SimulationTarget::Nfa, Key::TabNfa),
+        (SimulationTarget::Dfa, Key::TabDfa),
+        (SimulationTarget::MinDfa, Key::TabMinDfa),
+    ]
+    .into_iter()
+    .map(|(option, key)| {
+        option_button(
+            app.tr(key),
+            option == target,
+            ViewMessage::SetDefinitionTarget(option),
+        )
+    });
+    let formats = DeltaFormat::ALL.into_iter().map(|format| {
+        option_button(
+            app.tr(format.key()),
+            format == app.delta_format,
+            ViewMessage::SetDeltaFormat(format),
+        )
+    });
+
+    let Some(definition) = formal_definition(app) else {
+        return column![
+            row(targets).spacing(4),
+            text(app.tr(Key::MinimizedUnavailable))
+                .size(TextSize::Body)
+                .class(TextClass::Warning),
+        ]
+        .spacing(12)
+        .into();
+    };
+
+    let copy = button(text(app.tr(Key::CopyLatex)).size(TextSize::Small))
+        .class(ButtonClass::Secondary)
+        .padding([4, 12])
+        .on_press(Message::View(ViewMessage::CopyDefinitionLatex));
+    let mut controls = row![row(targets).spacing(4), row(formats).spacing(4), copy]
+        .spacing(16)
+        .align_y(Alignment::Center);
+    if app.definition_copied == Some(target) {
+        controls = controls.push(
+            text(app.tr(Key::LatexCopied))
+                .size(TextSize::Small)
+                .class(TextClass::Secondary),
+        );
+    }
+
+    let components = definition.component_lines().into_iter().map(|line| {
+        text(line)
+            .size(TextSize::Body)
+            .class(TextClass::Primary)
+            .into()
+    });
+    let delta = match app.delta_format {
+        DeltaFormat::Table => delta_table(&definition),
+        DeltaFormat::Listing => delta_listing(&definition),
+    };
+    let body = column![
+        column(components).spacing(4),
+        text(app.tr(Key::TransitionFunction))
+            .size(TextSize::H3)
+            .class(TextClass::Primary),
+        delta,
+    ]
+    .spacing(12);
+
+    column![controls.wrap(), scrollable(body).height(Length::Fill)]
+        .spacing(12)
+        .height(Length::Fill)
+        .into()
+}
+
+/// Writes `δ` as a table: one row per state, one column per symbol, with the
+/// start state marked `→` and accepting states marked `*`.
+fn delta_table<'a>(definition: &FormalDefinition) -> ElementType<'a> {
+    let cell = |value: String, class: TextClass| {
+        text(value)
+            .size(TextSize::Small)
+            .class(class)
+            .width(Length::Fixed(DELTA_COLUMN_WIDTH))
+    };
+
+    let header = definition.columns().into_iter().fold(
+        row![
+            text("").width(Length::Fixed(MARKER_COLUMN_WIDTH)),
+            cell("δ".to_string(), TextClass::Secondary),
+        ],
+        |line, column| line.push(cell(column, TextClass::Secondary)),
+    );
+    let rows = definition
+        .states
+        .iter()
+        .zip(&definition.delta)
+        .map(|(&state, targets)| {
+            let mut marker = String::new();
+            if state == definition.start {
+                marker.push('→');
+            }
+            if definition.accepting.contains(&state) {
+                marker.push('*');
+            }
+            targets
+                .iter()
+                .fold(
+                    row![
+                        text(marker)
+                            .size(TextSize::Small)
+                            .class(TextClass::Secondary)
+                            .width(Length::Fixed(MARKER_COLUMN_WIDTH)),
+                        cell(definition.state_name(state), TextClass::Primary),
+                    ],
+                    |line, targets| {
+                        line.push(cell(definition.target_text(targets), TextClass::Primary))
+                    },
+                )
+                .into()
+        });
+
+    column![header, column(rows).spacing(4)].spacing(6).into()
+}
+
+/// Writes `δ` as one `δ(q, a) = …` line per transition.
+fn delta_listing<'a>(definition: &FormalDefinition) -> ElementType<'a> {
+    let lines = definition.transition_lines().into_iter().map(|line| {
+        text(line)
+            .size(TextSize::Small)
+            .class(TextClass::Primary)
+            .into()
+    });
+    column(lines).spacing(4).into()
+}
+
+/// Renders one choice of a segmented control, highlighted when selected.
+fn option_button(label: &str, selected: bool, message: ViewMessage) -> ElementType<'_> {
+    button(text(label).size(TextSize::Small))
+        .class(if selected {
+            ButtonClass::Primary
+        } else {
+            ButtonClass::Secondary
+        })
+        .padding([4, 12])
+        .on_press(Message::View(message))
+        .into()
+}
@@ -0,0 +1,127 @@
+use iced::{
+    Alignment, Length,
+    alignment::Horizontal,
+    widget::{button, column, row, text, text_input},
+};
+
+use crate::app::{
+    exercise::{EXERCISES, ExerciseStatus},
+    i18n::Key,
+    message::{ExerciseMessage, Message, ViewMode},
+    state::App,
+    theme::{ButtonClass, ElementType, TextClass, TextInputClass, TextSize},
+};
+
+use super::visualization;
+
+/// Renders the current exercise, the answer input, its verdict and the answer's DFA.
+pub fn render(app: &App) -> ElementType<'_> {
+    let exercise = &app.exercise;
+    let solved = exercise.solved.iter().filter(|&&solved| solved).count();
+    let header = row![
+        text(app.tr_fmt(
+            Key::ExerciseProgress,
+            &[&(exercise.index + 1), &EXERCISES.len()],
+        ))
+        .size(TextSize::Small)
+        .class(TextClass::Secondary)
+        .width(Length::Fill),
+        text(app.tr_fmt(Key::ExercisesSolved, &[&solved]))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+    ]
+    .align_y(Alignment::Center);
+
+    let answer = text_input(app.tr(Key::AnswerPlaceholder), &exercise.answer)
+        .class(match exercise.status {
+            ExerciseStatus::Invalid(_) => TextInputClass::Invalid,
+            _ => TextInputClass::Default,
+        })
+        .on_input(|value| Message::Exercise(ExerciseMessage::AnswerChanged(value)))
+        .padding([8, 12])
+        .size(TextSize::Body)
+        .width(Length::Fill);
+
+    let navigation = row![
+        nav_button(app.tr(Key::Previous), ExerciseMessage::Previous),
+        nav_button(app.tr(Key::Next), ExerciseMessage::Next),
+        nav_button(app.tr(Key::RevealSolution), ExerciseMessage::Reveal),
+    ]
+    .spacing(8);
+
+    let mut content = column![
+        header,
+        text(app.tr(exercise.current().description))
+            .size(TextSize::Body)
+            .class(TextClass::Primary),
+        answer,
+        status_line(app),
+    ]
+    .spacing(8);
+    if let Some(diff) = &exercise.answer_diff {
+        content = content.push(
+            text(app.tr_fmt(
+                Key::AnswerDiffSummary,
+                &[
+                    &diff.added.len(),
+                    &diff.removed.len(),
+                    &diff.transitions_changed.len(),
+                ],
+            ))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+        );
+    }
+    content = content.push(navigation);
+    if exercise.revealed {
+        content = content.push(
+            text(app.tr_fmt(Key::Solution, &[&exercise.current().solution]))
+                .size(TextSize::Small)
+                .class(TextClass::Secondary),
+        );
+    }
+
+    content = match &exercise.answer_dfa {
+        Some(dfa) => content.push(visualization::dfa_canvas(app, dfa, ViewMode::Exercise)),
+        None => content.push(
+            text(app.tr(Key::AnswerDfaHint))
+                .height(Length::Fill)
+                .size(TextSize::Body)
+                .class(TextClass::Secondary)
+                .width(Length::Fill)
+                .align_x(Horizontal::Center),
+        ),
+    };
+
+    content.height(Length::Fill).into()
+}
+
+/// Describes how the answer compares to the target language.
+fn status_line(app: &App) -> ElementType<'_> {
+    let (summary, class) = match &app.exercise.status {
+        ExerciseStatus::Empty => (app.tr(Key::AnswerEmpty).to_string(), TextClass::Secondary),
+        ExerciseStatus::Invalid(err) => (app.tr_fmt(Key::ErrorMessage, &[err]), TextClass::Error),
+        ExerciseStatus::Wrong {
+            counterexample,
+            accepted_by_answer,
+        } => {
+            let word = app.epsilon_glyph.or_word(counterexample);
+            let summary = if *accepted_by_answer {
+                app.tr_fmt(Key::AnswerOverAccepts, &[&word])
+            } else {
+                app.tr_fmt(Key::AnswerUnderAccepts, &[&word])
+            };
+            (summary, TextClass::Primary)
+        }
+        ExerciseStatus::Solved => (app.tr(Key::AnswerCorrect).to_string(), TextClass::Success),
+    };
+    text(summary).size(TextSize::Small).class(class).into()
+}
+
+fn nav_button(label: &str, message: ExerciseMessage) -> ElementType<'_> {
+    button(text(label).size(TextSize::Small))
+        .class(ButtonClass::Secondary)
+        .padding([4, 12])
+        .on_press(Message::Exercise(message))
+        .into()
+}
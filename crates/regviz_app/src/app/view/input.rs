@@ -1,29 +1,33 @@
 use iced::{
-    Length,
-    widget::{column, container, scrollable, text, text_input},
+    Alignment, Length,
+    widget::{button, column, container, scrollable, text, text_input},
 };
 
 use iced::widget::row;
 
 use crate::app::{
+    constants::REGEX_INPUT_ID,
+    i18n::Key,
     message::{InputMessage, Message},
     state::App,
-    theme::{ContainerClass, ElementType, TextClass, TextInputClass, TextSize},
+    theme::{ButtonClass, ContainerClass, ElementType, TextClass, TextInputClass, TextSize},
 };
-use regviz_core::errors::BuildError;
+use regviz_core::core::diagnostics::{self, FixKind, Suggestion, Warning};
+use regviz_core::errors::{BuildError, LexErrorKind, ParseErrorKind};
 
 /// Renders the regex input field and status text.
 pub fn render(app: &App) -> ElementType<'_> {
-    let label = text("Regular Expression")
+    let label = text(app.tr(Key::RegexHeading))
         .size(TextSize::H3)
         .class(TextClass::Primary);
 
-    let input_field = text_input("e.g., (a+b)c", &app.input)
+    let input_field = text_input(app.tr(Key::RegexPlaceholder), &app.input)
         .class(if app.error.is_some() {
             TextInputClass::Invalid
         } else {
             TextInputClass::Default
         })
+        .id(REGEX_INPUT_ID)
         .on_input(|value| Message::Input(InputMessage::Changed(value)))
         .padding([12, 16])
         .size(TextSize::Body)
@@ -36,17 +40,31 @@ pub fn render(app: &App) -> ElementType<'_> {
 
 fn status_text(app: &App) -> ElementType<'_> {
     match &app.error {
-        Some(err) => error_box(&app.input, err),
+        Some(err) => error_box(app, err),
         None => match &app.build_artifacts {
-            Some(artifacts) => text(format!(
-                "Parsed successfully | {} states | Alphabet: {:?}",
-                artifacts.nfa.states.len(),
-                artifacts.alphabet
-            ))
-            .size(TextSize::Small)
-            .class(TextClass::Success)
-            .into(),
-            None => text("Enter a regular expression to visualize")
+            Some(artifacts) => {
+                let summary = text(app.tr_fmt(
+                    Key::ParsedSummary,
+                    &[
+                        &app.locale.number(artifacts.nfa.states.len() as u128),
+                        &format!("{:?}", artifacts.alphabet),
+                    ],
+                ))
+                .size(TextSize::Small)
+                .class(TextClass::Success);
+                // The DFA is built lazily, so its size is only checked once it exists.
+                let size = artifacts.dfa.as_ref().and_then(diagnostics::size_warning);
+                let warnings = app.warnings.iter().chain(&size).map(|warning| {
+                    text(warning_text(app, warning))
+                        .size(TextSize::Small)
+                        .class(TextClass::Warning)
+                        .into()
+                });
+                column![summary, column(warnings).spacing(2)]
+                    .spacing(2)
+                    .into()
+            }
+            None => text(app.tr(Key::EnterRegexHint))
                 .size(TextSize::Small)
                 .class(TextClass::Secondary)
                 .into(),
@@ -54,51 +72,51 @@ fn status_text(app: &App) -> ElementType<'_> {
     }
 }
 
-/// Displays an error with highlighted character at the error position.
-fn error_box<'a>(input: &'a str, err: &'a BuildError) -> ElementType<'a> {
-    let error_char_index = match err {
-        BuildError::Lex(lex_err) => lex_err.at,
-        BuildError::Parse(parse_err) => parse_err.at,
-    };
+/// Displays the input with a caret under the character where the error
+/// occurred, a message about what was wrong there, and the full error.
+fn error_box<'a>(app: &'a App, err: &'a BuildError) -> ElementType<'a> {
+    let input = &app.input;
+    let error_char_index = err.at();
 
-    // Iterate through characters with their index
+    // Stack each character over its marker so the caret lines up with it.
     let char_count = input.chars().count();
-    let spans = input
-        .chars()
-        .enumerate()
-        .map(|(idx, ch)| {
-            if idx == error_char_index {
-                // Error character (white text, red background)
-                container(
-                    text(ch.to_string())
-                        .size(TextSize::Small)
-                        .class(TextClass::Primary),
-                )
+    let cell = |glyph: String, at_error: bool| -> ElementType<'a> {
+        let glyph = text(glyph).size(TextSize::Small).class(TextClass::Primary);
+        let glyph: ElementType<'a> = if at_error {
+            // Error character (white text, red background)
+            container(glyph)
                 .class(ContainerClass::FilledWith(
                     iced::Color::from_rgba(1.0, 0.2, 0.2, 0.8), // Red background
                 ))
                 .into()
-            } else {
-                // Normal character
-                text(ch.to_string())
-                    .size(TextSize::Small)
-                    .class(TextClass::Primary)
-                    .into()
-            }
-        })
-        .chain((error_char_index == char_count).then(|| {
-            container(text("⏎").size(TextSize::Small).class(TextClass::Primary))
-                .class(ContainerClass::FilledWith(
-                    crate::app::theme::colors::RED_500,
-                ))
-                .into()
-        }));
+        } else {
+            glyph.into()
+        };
+        let caret = text(if at_error { "^" } else { " " })
+            .size(TextSize::Small)
+            .class(TextClass::Error);
+        column![glyph, caret].align_x(Alignment::Center).into()
+    };
+    let spans = input
+        .chars()
+        .enumerate()
+        .map(|(idx, ch)| cell(ch.to_string(), idx == error_char_index))
+        .chain((error_char_index == char_count).then(|| cell("⏎".to_string(), true)));
 
     let error_display = column![
         row(spans).spacing(0), // Display the highlighted input
-        text(format!("Error: {err}"))
+        text(error_hint(app, err))
             .size(TextSize::Small)
             .class(TextClass::Error),
+        text(app.tr_fmt(Key::ErrorMessage, &[err]))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+        column(
+            diagnostics::suggest(input, err)
+                .into_iter()
+                .map(|suggestion| suggestion_row(app, suggestion))
+        )
+        .spacing(4),
     ]
     .spacing(4);
 
@@ -114,3 +132,81 @@ fn error_box<'a>(input: &'a str, err: &'a BuildError) -> ElementType<'a> {
         .width(Length::Fill)
         .into()
 }
+
+/// Says what went wrong at the character the caret points to.
+fn error_hint(app: &App, err: &BuildError) -> String {
+    match err {
+        BuildError::Lex(error) => match error.kind {
+            LexErrorKind::DanglingEscape => app.tr(Key::HintDanglingEscape).to_string(),
+            LexErrorKind::InvalidCharacter(ch) => app.tr_fmt(Key::HintInvalidCharacter, &[&ch]),
+        },
+        BuildError::Parse(error) => match &error.kind {
+            ParseErrorKind::UnexpectedEof => app.tr(Key::HintUnexpectedEof).to_string(),
+            ParseErrorKind::UnexpectedPrefixOperator(op) => {
+                app.tr_fmt(Key::HintMissingOperand, &[op])
+            }
+            ParseErrorKind::MismatchedLeftParen { .. } => {
+                app.tr(Key::HintExpectedRightParen).to_string()
+            }
+            ParseErrorKind::RightParenWithoutLeft => {
+                app.tr(Key::HintUnmatchedRightParen).to_string()
+            }
+            ParseErrorKind::ParenthesesWithInvalidExp => app.tr(Key::HintEmptyGroup).to_string(),
+        },
+    }
+}
+
+/// Describes a suspicious construct of a regex that built.
+fn warning_text(app: &App, warning: &Warning) -> String {
+    match warning {
+        Warning::RedundantRepetition { pattern } => {
+            app.tr_fmt(Key::WarningRedundantRepetition, &[pattern])
+        }
+        Warning::UnreachableAlternative {
+            alternative,
+            covered_by,
+        } => app.tr_fmt(
+            Key::WarningUnreachableAlternative,
+            &[alternative, covered_by],
+        ),
+        Warning::EpsilonLoop { pattern } => app.tr_fmt(Key::WarningEpsilonLoop, &[pattern]),
+        Warning::LargeDfa { states } => {
+            app.tr_fmt(Key::WarningLargeDfa, &[&app.locale.number(*states as u128)])
+        }
+    }
+}
+
+/// Offers a corrected pattern with what was changed and a button applying it.
+fn suggestion_row(app: &App, suggestion: Suggestion) -> ElementType<'_> {
+    let description = match suggestion.kind {
+        FixKind::UseAlternation => app.tr(Key::FixUseAlternation).to_string(),
+        FixKind::EscapeCharacter(ch) => app.tr_fmt(Key::FixEscapeCharacter, &[&ch]),
+        FixKind::RemoveCharacter(ch) => app.tr_fmt(Key::FixRemoveCharacter, &[&ch]),
+        FixKind::RemoveEscape => app.tr(Key::FixRemoveEscape).to_string(),
+        FixKind::RemoveOperator(op) => app.tr_fmt(Key::FixRemoveOperator, &[&op]),
+        FixKind::CloseGroup => app.tr(Key::FixCloseGroup).to_string(),
+        FixKind::RemoveLeftParen => app.tr(Key::FixRemoveLeftParen).to_string(),
+        FixKind::RemoveRightParen => app.tr(Key::FixRemoveRightParen).to_string(),
+        FixKind::OpenGroup => app.tr(Key::FixOpenGroup).to_string(),
+        FixKind::EmptyGroupAsEpsilon => app.tr(Key::FixEmptyGroupAsEpsilon).to_string(),
+    };
+    let apply = button(text(app.tr(Key::ApplySuggestion)).size(TextSize::Small))
+        .class(ButtonClass::Secondary)
+        .padding([2, 10])
+        .on_press(Message::Input(InputMessage::Changed(
+            suggestion.pattern.clone(),
+        )));
+
+    row![
+        text(app.tr_fmt(Key::DidYouMean, &[&suggestion.pattern]))
+            .size(TextSize::Small)
+            .class(TextClass::Primary),
+        text(description)
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+        apply,
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center)
+    .into()
+}
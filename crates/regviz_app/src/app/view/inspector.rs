@@ -0,0 +1,218 @@
+use std::collections::{HashSet, VecDeque};
+
+use iced::widget::{button, column, row, text, text_input};
+use regviz_core::core::automaton::StateId;
+use regviz_core::core::dfa::Dfa;
+use regviz_core::core::parser::Ast;
+use regviz_core::core::to_regex;
+
+use crate::app::constants::RENAME_INPUT_ID;
+use crate::app::i18n::Key;
+use crate::app::message::{Message, StateLayer, ViewMessage};
+use crate::app::state::App;
+use crate::app::theme::{ButtonClass, ElementType, TextClass, TextInputClass, TextSize};
+
+/// Number of example words listed for a state's language.
+const SAMPLE_WORD_COUNT: usize = 6;
+/// Longest example word considered when sampling a state's language.
+const SAMPLE_MAX_LEN: usize = 8;
+/// Upper bound on search entries expanded while sampling, to keep rendering cheap.
+const SAMPLE_SEARCH_LIMIT: usize = 10_000;
+
+/// Renders details about the state selected on the DFA canvas, if any.
+pub fn render(app: &App) -> Option<ElementType<'_>> {
+    let state = app.view_data().selected_state?;
+    let dfa = app.inspected_dfa()?;
+    if state as usize >= dfa.states.len() {
+        return None;
+    }
+
+    let accepting = if dfa.accepts.contains(&state) {
+        app.tr(Key::Accepting)
+    } else {
+        app.tr(Key::NotAccepting)
+    };
+    let shown = app
+        .state_labels(app.view_mode(), dfa)
+        .remove(&state)
+        .map_or_else(|| state.to_string(), |label| format!("{label} (#{state})"));
+    let mut content = column![
+        text(app.tr(Key::InspectorHeading))
+            .size(TextSize::H3)
+            .class(TextClass::Primary),
+        text(app.tr_fmt(Key::StateSummary, &[&shown, &accepting]))
+            .size(TextSize::Body)
+            .class(TextClass::Secondary),
+    ]
+    .spacing(6);
+
+    let layer = app.view_data().state_layer;
+    content = content.push(
+        row![
+            layer_button(app.tr(Key::ShowReachable), StateLayer::Reachable, layer),
+            layer_button(
+                app.tr(Key::ShowAcceptingPaths),
+                StateLayer::AcceptingPaths,
+                layer,
+            ),
+        ]
+        .spacing(8)
+        .wrap(),
+    );
+
+    let Some((_, prefix)) = dfa
+        .access_words()
+        .into_iter()
+        .find(|(reached, _)| *reached == state)
+    else {
+        return Some(
+            content
+                .push(
+                    text(app.tr(Key::UnreachableState))
+                        .size(TextSize::Small)
+                        .class(TextClass::Warning),
+                )
+                .into(),
+        );
+    };
+
+    let name = app
+        .view_data()
+        .state_names
+        .get(&prefix)
+        .map_or("", String::as_str);
+    content = content.push(
+        row![
+            text(app.tr(Key::Name))
+                .size(TextSize::Small)
+                .class(TextClass::Secondary),
+            text_input(app.tr(Key::RenamePlaceholder), name)
+                .id(RENAME_INPUT_ID)
+                .class(TextInputClass::Default)
+                .on_input(move |value| Message::View(ViewMessage::RenameState(state, value)))
+                .padding([6, 10])
+                .size(TextSize::Small),
+        ]
+        .spacing(8)
+        .align_y(iced::Alignment::Center),
+    );
+
+    let pattern = |regex: Option<Ast>| {
+        regex.map_or_else(|| "∅".to_string(), |ast| ast.to_pattern(app.epsilon_glyph))
+    };
+    for (key, regex) in [
+        (Key::LeftLanguage, to_regex::left_language(dfa, state)),
+        (Key::RightLanguage, to_regex::right_language(dfa, state)),
+    ] {
+        content = content.push(
+            text(app.tr_fmt(key, &[&pattern(regex)]))
+                .size(TextSize::Small)
+                .class(TextClass::Primary),
+        );
+    }
+
+    // Every prefix reaching the state gives the same residual; show the shortest.
+    let Ok(residual) = dfa.left_quotient(&prefix) else {
+        return Some(content.into());
+    };
+    let shown_prefix = app.epsilon_glyph.or_word(&prefix);
+    content = content.push(
+        text(app.tr_fmt(Key::ResidualLanguage, &[&shown_prefix]))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+    );
+
+    let words = sample_words(&residual);
+    let summary = if words.is_empty() {
+        app.tr(Key::EmptyResidual).to_string()
+    } else {
+        words
+            .iter()
+            .map(|word| app.epsilon_glyph.or_word(word))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    content = content.push(
+        text(summary)
+            .size(TextSize::Small)
+            .class(TextClass::Primary),
+    );
+
+    Some(content.into())
+}
+
+/// Renders a toggle for one of the inspector's canvas layers.
+fn layer_button(label: &str, layer: StateLayer, current: Option<StateLayer>) -> ElementType<'_> {
+    let active = current == Some(layer);
+    button(text(label).size(TextSize::Small).class(if active {
+        TextClass::Primary
+    } else {
+        TextClass::Secondary
+    }))
+    .class(if active {
+        ButtonClass::Primary
+    } else {
+        ButtonClass::Secondary
+    })
+    .padding([6, 12])
+    .on_press(Message::View(ViewMessage::ShowStateLayer(
+        (!active).then_some(layer),
+    )))
+    .into()
+}
+
+/// Lists the first accepted words in length-then-alphabetical order.
+fn sample_words(dfa: &Dfa) -> Vec<String> {
+    let live = live_states(dfa);
+    let mut words = Vec::new();
+    if !live.contains(&dfa.start) {
+        return words;
+    }
+
+    let mut queue = VecDeque::from([(dfa.start, String::new())]);
+    let mut expanded = 0;
+    while let Some((state, word)) = queue.pop_front() {
+        expanded += 1;
+        if expanded > SAMPLE_SEARCH_LIMIT {
+            break;
+        }
+        if dfa.accepts.contains(&state) {
+            words.push(word.clone());
+            if words.len() == SAMPLE_WORD_COUNT {
+                break;
+            }
+        }
+        if word.chars().count() == SAMPLE_MAX_LEN {
+            continue;
+        }
+        for (idx, symbol) in dfa.alphabet.iter().enumerate() {
+            let next = dfa.trans[state as usize][idx];
+            if live.contains(&next) {
+                let mut longer = word.clone();
+                longer.push(*symbol);
+                queue.push_back((next, longer));
+            }
+        }
+    }
+    words
+}
+
+/// Returns the states from which some accepting state is reachable.
+fn live_states(dfa: &Dfa) -> HashSet<StateId> {
+    let mut live: HashSet<StateId> = dfa.accepts.iter().copied().collect();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for state in &dfa.states {
+            if !live.contains(state)
+                && dfa.trans[*state as usize]
+                    .iter()
+                    .any(|next| live.contains(next))
+            {
+                live.insert(*state);
+                changed = true;
+            }
+        }
+    }
+    live
+}
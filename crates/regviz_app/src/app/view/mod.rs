@@ -1,60 +1,139 @@
+mod alphabet;
+mod analysis;
+mod construction;
+mod context_menu;
 mod controls;
+mod definition;
+mod exercise;
 mod input;
+mod inspector;
+mod operations;
 mod simulation;
+mod status_bar;
+mod syntax;
+mod tabs;
+mod tokens;
+mod transcript;
+mod tutorial;
 mod visualization;
+mod workbench;
 
 use iced::{
     Alignment, Length,
-    widget::{Space, button, column, container, pane_grid, row, scrollable, text},
+    widget::{
+        Space, button, column, container, opaque, pane_grid, responsive, row, scrollable, stack,
+        text,
+    },
+    window,
 };
 
 use crate::app::{
-    message::InputMessage,
+    message::{InputMessage, TutorialMessage},
     theme::{ButtonClass, ContainerClass, ElementType, TextClass, TextSize},
 };
 
+use super::constants::NARROW_LAYOUT_WIDTH;
+use super::i18n::Key;
 use super::message::{Message, PaneGridMessage};
 use super::state::{App, PaneContent};
+use super::tutorial::TutorialStep;
+
+pub(crate) use definition::formal_definition;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use visualization::export_image;
+pub(crate) use visualization::export_svg;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use visualization::title_key;
 
 const INPUT_EXAMPLES: &[&str] = &["a+b", "\\e", "(a+b)*c", "ab+cd?", "a(bc)*d+e?"];
 
 impl App {
     /// Renders the entire application UI.
+    ///
+    /// Narrow viewports, such as phones running the web build, stack the
+    /// visualization under a bar that opens the controls as a drawer.
     pub fn view(&self) -> ElementType<'_> {
-        let grid = pane_grid::PaneGrid::new(&self.panes, |_, pane, _| match pane {
-            PaneContent::Controls => pane_grid::Content::new(left_controls(self)),
-            PaneContent::Visualization => pane_grid::Content::new(right_visual(self)),
-        })
-        .on_resize(8, |event| {
-            Message::PaneGrid(PaneGridMessage::Resized(event))
-        })
-        .width(Length::Fill)
-        .height(Length::Fill);
+        let body = responsive(|size| {
+            if size.width < NARROW_LAYOUT_WIDTH {
+                stacked(self)
+            } else {
+                side_by_side(self)
+            }
+        });
+
+        let mut layers = stack![column![tabs::render(self), body]]
+            .width(Length::Fill)
+            .height(Length::Fill);
+        if let Some(overlay) = tutorial::overlay(self) {
+            layers = layers.push(overlay);
+        }
 
-        container(grid)
+        container(layers)
             .class(ContainerClass::RoundedLarge)
             .width(Length::Fill)
             .height(Length::Fill)
             .into()
     }
+
+    /// Renders `window`: the whole app in the main window, or a single view
+    /// in a window it was detached into.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn view_window(&self, window: window::Id) -> ElementType<'_> {
+        match self.detached.get(&window) {
+            Some(&mode) => container(visualization::render_detached(self, mode))
+                .class(ContainerClass::FilledWith(self.theme.bg_mid()))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .into(),
+            None => self.view(),
+        }
+    }
 }
 
-fn information_block() -> ElementType<'static> {
+/// Places the controls and the visualization in resizable side-by-side panes.
+fn side_by_side(app: &App) -> ElementType<'_> {
+    pane_grid::PaneGrid::new(&app.panes, |_, pane, _| match pane {
+        PaneContent::Controls => pane_grid::Content::new(left_controls(app)),
+        PaneContent::Visualization => pane_grid::Content::new(right_visual(app)),
+    })
+    .on_resize(8, |event| {
+        Message::PaneGrid(PaneGridMessage::Resized(event))
+    })
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .into()
+}
+
+/// Shows the visualization full-width, with the controls in a drawer that covers it when open.
+fn stacked(app: &App) -> ElementType<'_> {
+    let toggle = button(
+        text(app.tr(if app.controls_drawer_open {
+            Key::HideControls
+        } else {
+            Key::ShowControls
+        }))
+        .size(TextSize::Body),
+    )
+    .class(ButtonClass::Secondary)
+    .padding([6, 12])
+    .on_press(Message::PaneGrid(PaneGridMessage::ToggleDrawer));
+
+    let mut content = stack![right_visual(app)]
+        .width(Length::Fill)
+        .height(Length::Fill);
+    if app.controls_drawer_open {
+        content = content.push(opaque(left_controls(app)));
+    }
+
     column![
-        text(
-            "
-Alphanumeric characters (a-z, A-Z, 0-9) and the following special characters are supported:
-1. '\\e': epsilon
-2. '(', ')': for grouping
-3. '+': alternation
-4. '*': kleene star
-5. '.': concatenation
-"
-        )
-        .size(TextSize::Small)
-        .class(TextClass::Secondary),
+        container(toggle)
+            .padding([8, 15])
+            .width(Length::Fill)
+            .class(ContainerClass::FilledWith(app.theme.bg_mid())),
+        content,
     ]
-    .spacing(8)
+    .width(Length::Fill)
+    .height(Length::Fill)
     .into()
 }
 
@@ -68,22 +147,44 @@ fn left_controls(app: &App) -> ElementType<'_> {
     .spacing(6)
     .wrap();
 
-    let content = column![
+    let mut content = column![
         column![
-            text!("Regular Expression Visualizer").size(TextSize::H1),
-            text!("Build and visualize finite automata from regular expressions.")
+            row![
+                text(app.tr(Key::AppTitle))
+                    .size(TextSize::H1)
+                    .width(Length::Fill),
+                button(text(app.tr(Key::TutorialButton)).size(TextSize::Small))
+                    .class(ButtonClass::Secondary)
+                    .padding([4, 12])
+                    .on_press_maybe(
+                        (!app.tutorial.is_active())
+                            .then_some(Message::Tutorial(TutorialMessage::Start))
+                    ),
+            ]
+            .align_y(Alignment::Center),
+            text(app.tr(Key::AppSubtitle))
                 .size(TextSize::Body)
                 .class(TextClass::Secondary),
+            controls::language(app),
+            controls::theme_preference(app),
+            controls::accessibility(app),
+            controls::preferences(app),
         ]
         .spacing(4),
-        information_block(),
-        input::render(app),
+        syntax::render(app),
+        tutorial::spotlight(app, TutorialStep::TypeRegex, input::render(app)),
+        tokens::render(app),
         examples_row,
+        alphabet::render(app),
+        operations::render(app),
         simulation::test_string_input(app),
         Space::new().height(Length::Fill),
         simulation::panel(app),
     ]
     .spacing(16);
+    if let Some(inspector) = inspector::render(app) {
+        content = content.push(inspector);
+    }
 
     let scrollable_content = scrollable(content).width(Length::Fill).height(Length::Fill);
 
@@ -0,0 +1,63 @@
+use iced::{
+    Alignment,
+    widget::{button, column, row, text},
+};
+
+use crate::app::{
+    i18n::Key,
+    message::{Message, ViewMessage},
+    operations::DfaOperation,
+    state::App,
+    theme::{ButtonClass, ElementType, TextClass, TextSize},
+};
+
+/// Renders one-click language operations on the current DFA, with a breadcrumb and undo.
+pub fn render(app: &App) -> ElementType<'_> {
+    let enabled = app.build_artifacts.is_some();
+    let operations = DfaOperation::ALL.iter().map(|&operation| {
+        button(text(app.tr(operation.key())).size(TextSize::Small))
+            .class(ButtonClass::Secondary)
+            .padding([4, 12])
+            .on_press_maybe(
+                enabled.then_some(Message::View(ViewMessage::ApplyOperation(operation))),
+            )
+            .into()
+    });
+
+    let history = app.operations.breadcrumb(app.locale);
+    let caption = if history.is_empty() {
+        text(app.tr(Key::OperationsHint))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary)
+    } else {
+        text(history.join(" › "))
+            .size(TextSize::Small)
+            .class(TextClass::Primary)
+    };
+
+    let has_history = !app.operations.is_empty();
+    let history_controls = row![
+        button(text(app.tr(Key::Undo)).size(TextSize::Small))
+            .class(ButtonClass::Secondary)
+            .padding([4, 12])
+            .on_press_maybe(has_history.then_some(Message::View(ViewMessage::UndoOperation))),
+        button(text(app.tr(Key::Clear)).size(TextSize::Small))
+            .class(ButtonClass::Danger)
+            .padding([4, 12])
+            .on_press_maybe(has_history.then_some(Message::View(ViewMessage::ClearOperations))),
+    ]
+    .spacing(4);
+
+    column![
+        text(app.tr(Key::OperationsHeading))
+            .size(TextSize::H3)
+            .class(TextClass::Primary),
+        row(operations).spacing(4).wrap(),
+        row![caption, history_controls]
+            .spacing(8)
+            .align_y(Alignment::Center)
+            .wrap(),
+    ]
+    .spacing(6)
+    .into()
+}
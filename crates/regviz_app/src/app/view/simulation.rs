@@ -3,14 +3,20 @@ use iced::{
     widget::{Space, button, column, row, text, text_input},
 };
 
+use regviz_core::core::automaton::StateId;
+use regviz_core::core::trace::TraceFormat;
+use regviz_core::errors::SimError;
+
+use crate::app::i18n::Key;
 use crate::app::simulation::SimulationTarget;
 use crate::app::state::App;
+use crate::app::tutorial::TutorialStep;
 use crate::app::{
     message::{Message, SimulationMessage},
     theme::{ButtonClass, ElementType, TextClass, TextInputClass, TextSize},
 };
 
-use super::controls;
+use super::{controls, tutorial};
 
 /// Renders controls for stepping through the simulation input.
 pub fn panel(app: &App) -> ElementType<'_> {
@@ -18,7 +24,7 @@ pub fn panel(app: &App) -> ElementType<'_> {
     let (status_label, status_class) = simulation_status(app);
 
     let header = row![
-        text("Simulation")
+        text(app.tr(Key::SimulationHeading))
             .size(TextSize::H3)
             .class(TextClass::Primary),
         Space::new().width(Length::Fill),
@@ -28,17 +34,381 @@ pub fn panel(app: &App) -> ElementType<'_> {
 
     let disabled = !ready || app.simulation_error.is_some();
     let controls_section = simulation_controls_section(app, disabled);
+    let what_if = what_if_section(app, ready);
+    let export = export_section(app, disabled);
+    let suffixes = suffix_section(app, disabled);
+    let comparison = comparison_section(app, disabled);
+    let paths = path_section(app);
+    let rejection = rejection_section(app);
+
+    column![
+        header,
+        controls_section,
+        what_if,
+        rejection,
+        suffixes,
+        comparison,
+        paths,
+        export
+    ]
+    .spacing(12)
+    .into()
+}
+
+/// Lets the user rewrite the unconsumed input while keeping the current step.
+///
+/// Only shown mid-simulation; before any symbol is consumed the test string
+/// field already edits the whole input.
+fn what_if_section(app: &App, ready: bool) -> ElementType<'_> {
+    if !ready || app.simulation.cursor == 0 || app.simulation.step_count().is_none() {
+        return column![].into();
+    }
+
+    let consumed = app.simulation.consumed_input();
+    let label = text(app.tr_fmt(Key::WhatIf, &[&consumed]))
+        .size(TextSize::Small)
+        .class(TextClass::Secondary);
+    let field = text_input(
+        app.tr(Key::RemainingInput),
+        app.simulation.remaining_input(),
+    )
+    .class(if app.simulation_error.is_some() {
+        TextInputClass::Invalid
+    } else {
+        TextInputClass::Default
+    })
+    .on_input(|value| Message::Simulation(SimulationMessage::RemainingInputChanged(value)))
+    .padding([8, 12])
+    .size(TextSize::Body)
+    .width(Length::Fill);
+
+    column![label, field].spacing(4).into()
+}
+
+/// Explains where a rejected input went wrong and what could have come next.
+fn rejection_section(app: &App) -> ElementType<'_> {
+    let Some(rejection) = &app.simulation.rejection else {
+        return column![].into();
+    };
+
+    let prefix = if rejection.matched_prefix.is_empty() {
+        app.epsilon_glyph.to_string()
+    } else {
+        format!("\"{}\"", rejection.matched_prefix)
+    };
+    let expected = if rejection.expected.is_empty() {
+        app.tr(Key::NothingMore).to_string()
+    } else {
+        rejection
+            .expected
+            .iter()
+            .map(|symbol| format!("'{symbol}'"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let found = match rejection.found {
+        Some(symbol) => app.tr_fmt(Key::FoundSymbol, &[&symbol]),
+        None => app.tr(Key::InputEnded).to_string(),
+    };
+
+    column![
+        text(app.tr_fmt(Key::LongestPrefix, &[&prefix]))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+        text(app.tr_fmt(Key::ExpectedBut, &[&expected, &found]))
+            .size(TextSize::Small)
+            .class(TextClass::Error),
+    ]
+    .spacing(2)
+    .into()
+}
+
+/// Renders the accepting NFA paths and the controls for replaying one edge by edge.
+fn path_section(app: &App) -> ElementType<'_> {
+    let paths = &app.simulation.accepting_paths;
+    if app.simulation.target != SimulationTarget::Nfa || paths.is_empty() {
+        return column![].into();
+    }
+
+    let replaying = app.simulation.replay.map(|replay| replay.path);
+    let choices = paths
+        .iter()
+        .enumerate()
+        .fold(row![].spacing(8), |choices, (idx, path)| {
+            let active = replaying == Some(idx);
+            choices.push(
+                button(
+                    text(app.tr_fmt(Key::PathChoice, &[&(idx + 1), &path.len()]))
+                        .size(TextSize::Small)
+                        .class(if active {
+                            TextClass::Primary
+                        } else {
+                            TextClass::Secondary
+                        }),
+                )
+                .class(if active {
+                    ButtonClass::Primary
+                } else {
+                    ButtonClass::Secondary
+                })
+                .padding([6, 12])
+                .on_press(Message::Simulation(SimulationMessage::ReplayPath(idx))),
+            )
+        })
+        .wrap();
+
+    let mut content = column![
+        text(app.tr(Key::AcceptingPaths))
+            .size(TextSize::Body)
+            .class(TextClass::Secondary),
+        choices,
+    ]
+    .spacing(4);
+
+    let (Some(replay), Some(path)) = (app.simulation.replay, app.simulation.replayed_path()) else {
+        return content.into();
+    };
+
+    let position = match replay.cursor.checked_sub(1).and_then(|idx| path.get(idx)) {
+        Some(edge) => app.tr_fmt(
+            Key::EdgePosition,
+            &[
+                &replay.cursor,
+                &path.len(),
+                &edge.from,
+                &edge.label,
+                &edge.to,
+            ],
+        ),
+        None => app.tr_fmt(Key::EdgeAtStart, &[&path.len()]),
+    };
+
+    let mut back = button(text(app.tr(Key::Back)).size(TextSize::Small))
+        .class(ButtonClass::Secondary)
+        .padding([6, 12]);
+    if replay.cursor > 0 {
+        back = back.on_press(Message::Simulation(SimulationMessage::ReplayBackward));
+    }
+    let mut forward = button(text(app.tr(Key::TakeEdge)).size(TextSize::Small))
+        .class(ButtonClass::Primary)
+        .padding([6, 12]);
+    if replay.cursor < path.len() {
+        forward = forward.on_press(Message::Simulation(SimulationMessage::ReplayForward));
+    }
+
+    content = content.push(
+        row![
+            back,
+            forward,
+            text(position)
+                .size(TextSize::Small)
+                .class(TextClass::Primary),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    );
+
+    content.into()
+}
+
+/// Renders buttons copying the full trace to the clipboard for lab reports.
+fn export_section(app: &App, disabled: bool) -> ElementType<'_> {
+    let enabled = !disabled && app.simulation.step_count().is_some();
+    let copy_button = |label, format| {
+        let mut copy = button(text(label).size(TextSize::Small))
+            .class(ButtonClass::Secondary)
+            .padding([6, 12]);
+        if enabled {
+            copy = copy.on_press(Message::Simulation(SimulationMessage::CopyTrace(format)));
+        }
+        copy
+    };
+
+    #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+    let mut buttons = row![
+        copy_button(app.tr(Key::CopyTraceJson), TraceFormat::Json),
+        copy_button(app.tr(Key::CopyTraceCsv), TraceFormat::Csv),
+    ]
+    .spacing(8);
+    // Browsers cannot save files, so only native builds record animations.
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let mut save = button(text(app.tr(Key::SaveAnimation)).size(TextSize::Small))
+            .class(ButtonClass::Secondary)
+            .padding([6, 12]);
+        if enabled {
+            save = save.on_press(Message::Simulation(SimulationMessage::SaveAnimation));
+        }
+        buttons = buttons.push(save);
+    }
+
+    let mut content = column![buttons].spacing(4);
+    if let Some(format) = app.simulation.exported {
+        content = content.push(
+            text(app.tr_fmt(Key::TraceCopied, &[&format.extension().to_uppercase()]))
+                .size(TextSize::Small)
+                .class(TextClass::Secondary),
+        );
+    }
+    match &app.simulation.animation {
+        Some(Ok(path)) => {
+            content = content.push(
+                text(app.tr_fmt(Key::AnimationSaved, &[&path.display()]))
+                    .size(TextSize::Small)
+                    .class(TextClass::Secondary),
+            );
+        }
+        Some(Err(error)) => {
+            content = content.push(
+                text(app.tr_fmt(Key::AnimationFailed, &[error]))
+                    .size(TextSize::Small)
+                    .class(TextClass::Error),
+            );
+        }
+        None => {}
+    }
 
-    column![header, controls_section].spacing(12).into()
+    content.into()
+}
+
+/// Renders the step table running the input through all three engines at once.
+fn comparison_section(app: &App, disabled: bool) -> ElementType<'_> {
+    let label = if app.simulation.show_comparison {
+        app.tr(Key::HideComparison)
+    } else {
+        app.tr(Key::CompareEngines)
+    };
+    let mut toggle = button(text(label).size(TextSize::Small))
+        .class(ButtonClass::Secondary)
+        .padding([6, 12]);
+    if !disabled {
+        toggle = toggle.on_press(Message::Simulation(SimulationMessage::ToggleComparison));
+    }
+
+    let mut content = column![toggle].spacing(4);
+    let Some(comparison) = app
+        .simulation
+        .comparison
+        .as_ref()
+        .filter(|_| app.simulation.show_comparison)
+    else {
+        return content.into();
+    };
+
+    content = content.push(match comparison.first_divergence() {
+        Some(index) => text(app.tr_fmt(Key::EnginesDisagree, &[&index]))
+            .size(TextSize::Small)
+            .class(TextClass::Error),
+        None => text(app.tr(Key::EnginesAgree))
+            .size(TextSize::Small)
+            .class(TextClass::Success),
+    });
+
+    let cell = |value: String, class: TextClass| {
+        text(value)
+            .size(TextSize::Small)
+            .class(class)
+            .width(Length::FillPortion(2))
+    };
+    content = content.push(row![
+        cell(app.tr(Key::StepColumn).to_string(), TextClass::Secondary)
+            .width(Length::FillPortion(1)),
+        cell(app.tr(Key::TabNfa).to_string(), TextClass::Secondary),
+        cell(app.tr(Key::TabDfa).to_string(), TextClass::Secondary),
+        cell(app.tr(Key::TabMinDfa).to_string(), TextClass::Secondary),
+    ]);
+
+    for row_data in &comparison.rows {
+        let class = || {
+            if !row_data.agrees() {
+                TextClass::Error
+            } else if row_data.index == app.simulation.cursor {
+                TextClass::Primary
+            } else {
+                TextClass::Secondary
+            }
+        };
+        let step = match row_data.consumed {
+            Some(symbol) => format!("{} '{symbol}'", row_data.index),
+            None => row_data.index.to_string(),
+        };
+        let engine = |engine: usize| {
+            let states = match &row_data.states[engine] {
+                Some(states) if !states.is_empty() => states
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                _ => "∅".to_string(),
+            };
+            let verdict = if row_data.accepted[engine] {
+                " ✓"
+            } else {
+                ""
+            };
+            cell(format!("{{{states}}}{verdict}"), class())
+        };
+        content = content.push(row![
+            cell(step, class()).width(Length::FillPortion(1)),
+            engine(0),
+            engine(1),
+            engine(2),
+        ]);
+    }
+
+    content.into()
+}
+
+/// Renders the reverse-automaton demo listing which suffixes of the input match.
+fn suffix_section(app: &App, disabled: bool) -> ElementType<'_> {
+    let label = if app.simulation.show_suffixes {
+        app.tr(Key::HideSuffixes)
+    } else {
+        app.tr(Key::ShowSuffixes)
+    };
+    let mut toggle = button(text(label).size(TextSize::Small))
+        .class(ButtonClass::Secondary)
+        .padding([6, 12]);
+    if !disabled {
+        toggle = toggle.on_press(Message::Simulation(SimulationMessage::ToggleSuffixes));
+    }
+
+    let mut content = column![toggle].spacing(4);
+    if !app.simulation.show_suffixes || app.simulation.suffix_matches.is_empty() {
+        return content.into();
+    }
+
+    content = content.push(
+        text(app.tr(Key::SuffixCaption))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+    );
+    let symbols: Vec<char> = app.simulation.input.chars().collect();
+    for suffix in &app.simulation.suffix_matches {
+        let word: String = symbols[suffix.start..].iter().collect();
+        let word = app.epsilon_glyph.or_word(&word);
+        let (verdict, class) = if suffix.accepted {
+            (app.tr(Key::SuffixMatches), TextClass::Success)
+        } else {
+            (app.tr(Key::SuffixNoMatch), TextClass::Secondary)
+        };
+        content = content.push(
+            text(format!("\"{word}\" • {verdict}"))
+                .size(TextSize::Small)
+                .class(class),
+        );
+    }
+
+    content.into()
 }
 
 fn simulation_status(app: &App) -> (String, TextClass) {
     if app.build_artifacts.is_none() {
-        ("Regex required".to_string(), TextClass::Secondary)
+        (app.tr(Key::RegexRequired).to_string(), TextClass::Secondary)
     } else if app.simulation_error.is_some() {
-        ("Input error".to_string(), TextClass::Error)
+        (app.tr(Key::InputError).to_string(), TextClass::Error)
     } else {
-        ("Ready".to_string(), TextClass::Success)
+        (app.tr(Key::Ready).to_string(), TextClass::Success)
     }
 }
 
@@ -46,27 +416,46 @@ fn simulation_status(app: &App) -> (String, TextClass) {
 pub fn test_string_input(app: &App) -> ElementType<'_> {
     let enabled = app.build_artifacts.is_some();
     let placeholder = if enabled {
-        "Enter a string to validate"
+        app.tr(Key::TestStringPlaceholder)
     } else {
-        "Provide a regex to enable simulation"
+        app.tr(Key::TestStringDisabled)
     };
 
     let helper = if let Some(error) = &app.simulation_error {
-        text(error).size(TextSize::Small).class(TextClass::Error)
+        let message = match error {
+            // Positions are shown counting from 1.
+            SimError::UnknownSymbol { symbol, at } => {
+                app.tr_fmt(Key::SimulationUnknownSymbol, &[symbol, &(at + 1)])
+            }
+            SimError::EmptyAutomaton => app.tr(Key::SimulationEmptyAutomaton).to_string(),
+        };
+        text(message).size(TextSize::Small).class(TextClass::Error)
     } else if !enabled {
-        text("Build a valid regular expression to unlock the simulation.")
+        text(app.tr(Key::SimulationLocked))
             .size(TextSize::Small)
             .class(TextClass::Secondary)
     } else {
-        text("Simulate against the currently selected automaton.")
+        text(app.tr(Key::SimulationHelp))
             .size(TextSize::Small)
             .class(TextClass::Secondary)
     };
 
-    let bounding_boxes = controls::bounding_boxes(app);
+    let bounding_boxes = tutorial::spotlight(
+        app,
+        TutorialStep::ToggleBoxes,
+        controls::bounding_boxes(app),
+    );
+    let box_style = controls::box_style(app);
+    let layout_tuning = controls::layout_tuning(app);
+    let nfa_orientation = controls::nfa_orientation(app);
+    let overlays = controls::overlays(app);
+    let epsilon_glyph = controls::epsilon_glyph(app);
+    let state_numbering = controls::state_numbering(app);
+    let transition_grouping = controls::transition_grouping(app);
+    let image_export = controls::image_export(app);
 
     column![
-        text("Test String")
+        text(app.tr(Key::TestStringHeading))
             .size(TextSize::H3)
             .class(TextClass::Primary),
         text_input(placeholder, &app.simulation.input)
@@ -84,14 +473,27 @@ pub fn test_string_input(app: &App) -> ElementType<'_> {
             .size(TextSize::Body)
             .width(Length::Fill),
         helper,
-        bounding_boxes
+        bounding_boxes,
+        box_style,
+        layout_tuning,
+        nfa_orientation,
+        overlays,
+        epsilon_glyph,
+        state_numbering,
+        transition_grouping,
+        image_export
     ]
     .spacing(6)
     .into()
 }
 
 fn simulation_controls_section(app: &App, disabled: bool) -> ElementType<'_> {
-    let mut content = column![step_controls(app, disabled)].spacing(6);
+    let mut content = column![tutorial::spotlight(
+        app,
+        TutorialStep::StepSimulation,
+        step_controls(app, disabled)
+    )]
+    .spacing(6);
 
     for message in summary_messages(app) {
         content = content.push(
@@ -106,7 +508,7 @@ fn simulation_controls_section(app: &App, disabled: bool) -> ElementType<'_> {
 
 fn step_controls(app: &App, disabled: bool) -> ElementType<'_> {
     let prev_active = !disabled && app.simulation.can_step_backward();
-    let mut prev_button = button(text("Previous").size(TextSize::Body))
+    let mut prev_button = button(text(app.tr(Key::Previous)).size(TextSize::Body))
         .class(if prev_active {
             ButtonClass::Primary
         } else {
@@ -120,7 +522,7 @@ fn step_controls(app: &App, disabled: bool) -> ElementType<'_> {
     }
 
     let reset_active = !disabled;
-    let mut reset_button = button(text("Reset").size(TextSize::Body))
+    let mut reset_button = button(text(app.tr(Key::Reset)).size(TextSize::Body))
         .class(if reset_active {
             ButtonClass::Primary
         } else {
@@ -134,7 +536,7 @@ fn step_controls(app: &App, disabled: bool) -> ElementType<'_> {
     }
 
     let next_active = !disabled && app.simulation.can_step_forward();
-    let mut next_button = button(text("Next").size(TextSize::Body))
+    let mut next_button = button(text(app.tr(Key::Next)).size(TextSize::Body))
         .class(if next_active {
             ButtonClass::Primary
         } else {
@@ -147,7 +549,26 @@ fn step_controls(app: &App, disabled: bool) -> ElementType<'_> {
         next_button = next_button.on_press(Message::Simulation(SimulationMessage::StepForward));
     }
 
-    row![prev_button, reset_button, next_button]
+    let play_active = !disabled && app.simulation.step_count().is_some_and(|len| len > 1);
+    let play_label = if app.simulation.playing {
+        app.tr(Key::Pause)
+    } else {
+        app.tr(Key::Play)
+    };
+    let mut play_button = button(text(play_label).size(TextSize::Body))
+        .class(if play_active {
+            ButtonClass::Primary
+        } else {
+            ButtonClass::Secondary
+        })
+        .padding([10, 16])
+        .width(Length::Fill);
+
+    if play_active {
+        play_button = play_button.on_press(Message::Simulation(SimulationMessage::TogglePlay));
+    }
+
+    row![prev_button, reset_button, play_button, next_button]
         .spacing(12)
         .align_y(Alignment::Center)
         .width(Length::Fill)
@@ -159,25 +580,24 @@ fn summary_line(app: &App) -> Option<String> {
     let total = app.simulation.step_count()?;
     let max_index = total.saturating_sub(1);
     let consumed = match step.consumed {
-        Some(ch) => format!("Consumed: '{ch}'"),
-        None => "Consumed: –".to_string(),
+        Some(ch) => app.tr_fmt(Key::Consumed, &[&ch]),
+        None => app.tr(Key::ConsumedNothing).to_string(),
     };
     let accepting = if step.accepted {
-        "Accepting: Yes"
+        app.tr(Key::AcceptingYes)
     } else {
-        "Accepting: No"
+        app.tr(Key::AcceptingNo)
     };
 
-    Some(format!(
-        "Step {} / {} • {} • {}",
-        step.index, max_index, consumed, accepting
+    Some(app.tr_fmt(
+        Key::StepSummary,
+        &[&step.index, &max_index, &consumed, &accepting],
     ))
 }
 
 fn active_states_line(app: &App) -> Option<String> {
     let step = app.simulation.current_step()?;
-    let mut states: Vec<_> = step.active_states.iter().copied().collect();
-    states.sort_unstable();
+    let states: Vec<_> = step.active_states.iter().collect();
 
     let states_text = if states.is_empty() {
         "∅".to_string()
@@ -192,13 +612,42 @@ fn active_states_line(app: &App) -> Option<String> {
         )
     };
 
-    let target_label = match app.simulation.target {
-        SimulationTarget::Nfa => "NFA",
-        SimulationTarget::Dfa => "DFA",
-        SimulationTarget::MinDfa => "Min DFA",
+    let target_label = app.tr(match app.simulation.target {
+        SimulationTarget::Nfa => Key::TabNfa,
+        SimulationTarget::Dfa => Key::TabDfa,
+        SimulationTarget::MinDfa => Key::TabMinDfa,
+    });
+
+    Some(app.tr_fmt(Key::ActiveStates, &[&target_label, &states_text]))
+}
+
+/// Describes where breakpoints are set, and which ones paused the current step.
+///
+/// Breakpoint states are listed by id and breakpoint transitions as `from→to`.
+fn breakpoint_line(app: &App) -> Option<String> {
+    let simulation = &app.simulation;
+    if simulation.breakpoints.is_empty() && simulation.edge_breakpoints.is_empty() {
+        return None;
+    }
+    let list = |states: &[StateId], edges: &[(StateId, StateId)]| {
+        let items = states
+            .iter()
+            .map(|id| id.to_string())
+            .chain(edges.iter().map(|(from, to)| format!("{from}→{to}")))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("{{{items}}}")
     };
 
-    Some(format!("Active {target_label} states: {states_text}"))
+    let hit = simulation.hit_breakpoints();
+    let hit_edges = simulation.hit_edge_breakpoints();
+    if (!hit.is_empty() || !hit_edges.is_empty()) && !simulation.playing {
+        return Some(app.tr_fmt(Key::PausedAtBreakpoint, &[&list(&hit, &hit_edges)]));
+    }
+
+    let states: Vec<StateId> = simulation.breakpoints.iter().copied().collect();
+    let edges: Vec<(StateId, StateId)> = simulation.edge_breakpoints.iter().copied().collect();
+    Some(app.tr_fmt(Key::Breakpoints, &[&list(&states, &edges)]))
 }
 
 fn acceptance_hint(app: &App) -> bool {
@@ -238,14 +687,21 @@ fn summary_messages(app: &App) -> Vec<SummaryMessage> {
         });
     }
 
+    if let Some(breakpoints) = breakpoint_line(app) {
+        messages.push(SummaryMessage {
+            text: breakpoints,
+            class: TextClass::Warning,
+        });
+    }
+
     if app.simulation.is_current_rejection() {
         messages.push(SummaryMessage {
-            text: "Input string is not accepted.".to_string(),
+            text: app.tr(Key::InputRejected).to_string(),
             class: TextClass::Error,
         });
     } else if acceptance_hint(app) {
         messages.push(SummaryMessage {
-            text: "Input string is accepted.".to_string(),
+            text: app.tr(Key::InputAccepted).to_string(),
             class: TextClass::Success,
         });
     }
@@ -0,0 +1,110 @@
+use std::collections::BTreeSet;
+
+use iced::widget::{row, text};
+use iced::{Alignment, Length};
+use regviz_core::core::automaton::StateId;
+
+use crate::app::i18n::Key;
+use crate::app::message::ViewMode;
+use crate::app::state::App;
+use crate::app::theme::{ElementType, TextClass, TextSize};
+use crate::graph::GraphLayout;
+
+/// Placeholder shown for a coordinate while the cursor is off the canvas.
+const NO_COORDINATE: &str = "–";
+
+/// Renders the slim bar under the `mode` canvas.
+///
+/// It reads, from left to right, the cursor's position in layout
+/// coordinates, the zoom, how many nodes and edges `layout` draws and the
+/// selected state or transition. While the changes from the last edit of
+/// the regex flash on a DFA, it also counts the states the edit added,
+/// removed and changed.
+pub fn render<'a>(app: &'a App, mode: ViewMode, layout: &GraphLayout) -> ElementType<'a> {
+    let data = app.view_state.data_for(mode);
+    let locale = app.locale;
+
+    let cursor = match data.cursor_position {
+        Some(position) => app.tr_fmt(
+            Key::CursorPosition,
+            &[&(position.x as i32), &(position.y as i32)],
+        ),
+        None => app.tr_fmt(Key::CursorPosition, &[&NO_COORDINATE, &NO_COORDINATE]),
+    };
+    let zoom = app.tr_fmt(Key::Zoom, &[&locale.percent(data.zoom_factor)]);
+    let counts = app.tr_fmt(
+        if mode == ViewMode::Ast {
+            Key::TreeNodeCount
+        } else {
+            Key::StateCount
+        },
+        &[
+            &locale.number(layout.nodes.len() as u128),
+            &locale.number(layout.edges.len() as u128),
+        ],
+    );
+
+    // Elements are named as they are drawn, falling back to their number.
+    let label = |id: StateId| {
+        layout
+            .nodes
+            .iter()
+            .find(|node| node.data.id == id)
+            .map_or_else(|| id.to_string(), |node| node.data.label.clone())
+    };
+    let selection = match (data.selected_state, data.selected_edge) {
+        (Some(state), _) => app.tr_fmt(Key::SelectedElement, &[&label(state)]),
+        (None, Some((from, to))) => app.tr_fmt(
+            Key::SelectedElement,
+            &[&format!("{} → {}", label(from), label(to))],
+        ),
+        (None, None) => app.tr(Key::NothingSelected).to_owned(),
+    };
+
+    let fields = [cursor, zoom, counts, selection]
+        .into_iter()
+        .chain(edit_summary(app, mode));
+    row(fields.map(|field| {
+        text(field)
+            .size(TextSize::Small)
+            .class(TextClass::Secondary)
+            .into()
+    }))
+    .spacing(24)
+    .align_y(Alignment::Center)
+    .width(Length::Fill)
+    .into()
+}
+
+/// Counts the states the last edit added to, removed from and changed in the
+/// DFA drawn in the `mode` view, while those changes are flashed.
+fn edit_summary(app: &App, mode: ViewMode) -> Option<String> {
+    let artifacts = app.build_artifacts.as_ref()?;
+    // Mirrors the fallback between the two DFAs when drawing the views.
+    let minimized = match mode {
+        ViewMode::Dfa => artifacts.dfa.is_none(),
+        ViewMode::MinDfa => artifacts.min_dfa.is_some(),
+        _ => return None,
+    };
+    let changes = app.edit_diff.changes(minimized)?;
+    let changed: BTreeSet<StateId> = changes
+        .acceptance_changed
+        .iter()
+        .map(|change| change.right)
+        .chain(
+            changes
+                .transitions_changed
+                .iter()
+                .map(|change| change.right),
+        )
+        .collect();
+    let locale = app.locale;
+    Some(app.tr_fmt(
+        Key::EditSummary,
+        &[
+            &locale.number(changes.added.len() as u128),
+            &locale.number(changes.removed.len() as u128),
+            &locale.number(changed.len() as u128),
+        ],
+    ))
+}
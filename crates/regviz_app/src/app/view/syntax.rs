@@ -0,0 +1,117 @@
+use iced::{
+    Alignment, Length,
+    widget::{button, column, row, text, text_input},
+};
+use regviz_core::core::syntax::{SYNTAX, SyntaxEntry};
+
+use crate::app::{
+    i18n::Key,
+    message::{Message, ViewMessage},
+    state::App,
+    theme::{ButtonClass, ElementType, TextClass, TextSize},
+};
+
+/// Renders the collapsible, searchable syntax reference.
+///
+/// Entries come from the parser's own [`SYNTAX`] description, so operators,
+/// precedence levels and escapes always match what is accepted.
+pub fn render(app: &App) -> ElementType<'_> {
+    let toggle = button(
+        text(app.tr(if app.show_syntax_reference {
+            Key::SyntaxReferenceShown
+        } else {
+            Key::SyntaxReferenceHidden
+        }))
+        .size(TextSize::Body),
+    )
+    .class(ButtonClass::Secondary)
+    .padding([4, 12])
+    .on_press(Message::View(ViewMessage::ToggleSyntaxReference));
+
+    let mut content = column![toggle].spacing(8);
+    if !app.show_syntax_reference {
+        return content.into();
+    }
+
+    content = content.push(
+        text_input(app.tr(Key::SyntaxSearchPlaceholder), &app.syntax_query)
+            .on_input(|value| Message::View(ViewMessage::SyntaxQueryChanged(value)))
+            .padding([6, 10])
+            .size(TextSize::Small)
+            .width(Length::Fill),
+    );
+
+    // Meanings and category names are searched in the selected language.
+    let query = app.syntax_query.trim().to_lowercase();
+    let entries: Vec<SyntaxEntry> = SYNTAX
+        .reference()
+        .into_iter()
+        .map(|entry| SyntaxEntry {
+            meaning: app.locale.syntax_meaning(&entry).to_string(),
+            ..entry
+        })
+        .filter(|entry| {
+            entry.matches(&query)
+                || app
+                    .locale
+                    .syntax_category(entry.category)
+                    .to_lowercase()
+                    .contains(&query)
+        })
+        .collect();
+    if entries.is_empty() {
+        return content
+            .push(
+                text(app.tr_fmt(Key::SyntaxNoMatch, &[&app.syntax_query.trim()]))
+                    .size(TextSize::Small)
+                    .class(TextClass::Secondary),
+            )
+            .into();
+    }
+
+    let mut category = None;
+    for entry in entries {
+        if category != Some(entry.category) {
+            category = Some(entry.category);
+            content = content.push(
+                text(app.locale.syntax_category(entry.category))
+                    .size(TextSize::Small)
+                    .class(TextClass::Primary),
+            );
+        }
+        content = content.push(entry_row(app, entry));
+    }
+
+    content
+        .push(
+            text(app.tr(Key::SyntaxPrecedenceNote))
+                .size(TextSize::Small)
+                .class(TextClass::Secondary),
+        )
+        .into()
+}
+
+/// Renders one reference line: syntax, meaning (with precedence for operators) and example.
+fn entry_row<'a>(app: &App, entry: SyntaxEntry) -> ElementType<'a> {
+    let meaning = match entry.precedence {
+        Some(level) => app.tr_fmt(Key::SyntaxPrecedenceLevel, &[&entry.meaning, &level]),
+        None => entry.meaning,
+    };
+    row![
+        text(entry.syntax)
+            .size(TextSize::Small)
+            .class(TextClass::Primary)
+            .width(Length::FillPortion(1)),
+        text(meaning)
+            .size(TextSize::Small)
+            .class(TextClass::Secondary)
+            .width(Length::FillPortion(3)),
+        text(entry.example)
+            .size(TextSize::Small)
+            .class(TextClass::Secondary)
+            .width(Length::FillPortion(1)),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Start)
+    .into()
+}
@@ -0,0 +1,82 @@
+use iced::{
+    Alignment, Length,
+    widget::{button, container, row, scrollable, text},
+};
+
+use crate::app::i18n::Key;
+use crate::app::message::{Message, TabMessage};
+use crate::app::state::App;
+use crate::app::theme::{ButtonClass, ContainerClass, ElementType, TextSize};
+
+/// Longest pattern prefix shown as a tab title, in characters.
+const TAB_TITLE_MAX_CHARS: usize = 18;
+
+/// Renders the strip of workspace tabs, each named after its pattern.
+pub fn render(app: &App) -> ElementType<'_> {
+    let active = app.tabs.active();
+    let closable = app.tabs.len() > 1;
+
+    let tabs = app
+        .tabs
+        .patterns(app)
+        .into_iter()
+        .enumerate()
+        .map(|(index, pattern)| {
+            let class = || {
+                if index == active {
+                    ButtonClass::Primary
+                } else {
+                    ButtonClass::Secondary
+                }
+            };
+            let mut tab = row![
+                button(text(title(app, pattern)).size(TextSize::Small))
+                    .class(class())
+                    .padding([4, 12])
+                    .on_press(Message::Tab(TabMessage::Select(index)))
+            ]
+            .spacing(2)
+            .align_y(Alignment::Center);
+            if closable {
+                tab = tab.push(
+                    button(text("×").size(TextSize::Small))
+                        .class(class())
+                        .padding([4, 8])
+                        .on_press(Message::Tab(TabMessage::Close(index))),
+                );
+            }
+            tab.into()
+        });
+
+    let new_tab = button(text(app.tr(Key::NewTab)).size(TextSize::Small))
+        .class(ButtonClass::Secondary)
+        .padding([4, 12])
+        .on_press(Message::Tab(TabMessage::New));
+
+    container(
+        scrollable(
+            row(tabs)
+                .push(new_tab)
+                .spacing(8)
+                .align_y(Alignment::Center),
+        )
+        .direction(scrollable::Direction::Horizontal(Default::default())),
+    )
+    .padding([6, 15])
+    .width(Length::Fill)
+    .class(ContainerClass::FilledWith(app.theme.bg_mid()))
+    .into()
+}
+
+/// Names a tab after its pattern, shortened to fit, or as untitled while empty.
+fn title(app: &App, pattern: &str) -> String {
+    let pattern = pattern.trim();
+    if pattern.is_empty() {
+        return app.tr(Key::UntitledTab).to_string();
+    }
+    if pattern.chars().count() <= TAB_TITLE_MAX_CHARS {
+        return pattern.to_string();
+    }
+    let prefix: String = pattern.chars().take(TAB_TITLE_MAX_CHARS - 1).collect();
+    format!("{prefix}…")
+}
@@ -0,0 +1,92 @@
+use iced::{
+    Alignment, Length,
+    widget::{button, column, row, text},
+};
+use regviz_core::core::lexer::{Lexer, TokenClass};
+
+use crate::app::{
+    i18n::Key,
+    message::{InputMessage, Message},
+    state::App,
+    theme::{ButtonClass, ElementType, TextClass, TextSize},
+};
+
+/// Width of the column showing each token.
+const TOKEN_COLUMN_WIDTH: f32 = 48.0;
+
+/// Width of the column showing where each token was read.
+const POSITION_COLUMN_WIDTH: f32 = 96.0;
+
+/// Renders the collapsible list of tokens the lexer read from the regex, with
+/// their positions and classes. Picking one selects its characters in the input.
+///
+/// Nothing is shown while the input is empty or cannot be tokenized.
+pub fn render(app: &App) -> ElementType<'_> {
+    let tokens = match Lexer::tokenize_all(&app.input) {
+        Ok(tokens) if !tokens.is_empty() => tokens,
+        _ => return column![].into(),
+    };
+
+    let toggle = button(
+        text(app.tr_fmt(
+            if app.show_tokens {
+                Key::TokensShown
+            } else {
+                Key::TokensHidden
+            },
+            &[&tokens.len()],
+        ))
+        .size(TextSize::Body),
+    )
+    .class(ButtonClass::Secondary)
+    .padding([4, 12])
+    .on_press(Message::Input(InputMessage::ToggleTokens));
+    if !app.show_tokens {
+        return toggle.into();
+    }
+
+    let rows = tokens.iter().enumerate().map(|(index, spanned)| {
+        let position = if spanned.end - spanned.start == 1 {
+            app.tr_fmt(Key::TokenColumn, &[&spanned.end])
+        } else {
+            app.tr_fmt(Key::TokenColumns, &[&(spanned.start + 1), &spanned.end])
+        };
+        let line = row![
+            text(spanned.token.to_string())
+                .size(TextSize::Small)
+                .class(TextClass::Primary)
+                .width(Length::Fixed(TOKEN_COLUMN_WIDTH)),
+            text(position)
+                .size(TextSize::Small)
+                .class(TextClass::Secondary)
+                .width(Length::Fixed(POSITION_COLUMN_WIDTH)),
+            text(app.tr(class_key(spanned.token.class())))
+                .size(TextSize::Small)
+                .class(TextClass::Secondary),
+        ]
+        .align_y(Alignment::Center);
+        button(line)
+            .class(if app.selected_token == Some(index) {
+                ButtonClass::Primary
+            } else {
+                ButtonClass::Secondary
+            })
+            .padding([2, 8])
+            .width(Length::Fill)
+            .on_press(Message::Input(InputMessage::SelectToken(index)))
+            .into()
+    });
+
+    column![toggle, column(rows).spacing(2)].spacing(8).into()
+}
+
+/// Returns the name shown for tokens of `class`.
+fn class_key(class: TokenClass) -> Key {
+    match class {
+        TokenClass::Literal => Key::TokenLiteral,
+        TokenClass::Epsilon => Key::TokenEpsilon,
+        TokenClass::Operator => Key::TokenOperator,
+        TokenClass::Group => Key::TokenGroup,
+        TokenClass::End => Key::TokenEnd,
+    }
+}
@@ -0,0 +1,162 @@
+use iced::{
+    Length,
+    widget::{button, column, scrollable, text},
+};
+use regviz_core::core::{BuildArtifacts, automaton::StateId, dfa::Dfa, nfa::Nfa};
+
+use crate::app::{
+    i18n::Key,
+    message::{Message, ViewMessage, ViewMode},
+    state::App,
+    theme::{ButtonClass, ElementType, TextClass, TextSize},
+};
+
+/// One entry of the listing: a state and a sentence describing it.
+struct StateLine {
+    id: StateId,
+    description: String,
+}
+
+/// Renders the automaton on screen as a list of states and their transitions.
+///
+/// Every state is a button selecting it, so the listing stands in for the
+/// canvas when navigating by keyboard or with a screen reader. Returns `None`
+/// on views without states, or when their automaton is unavailable.
+pub fn render<'a>(app: &'a App, artifacts: &'a BuildArtifacts) -> Option<ElementType<'a>> {
+    let lines = match app.view_mode() {
+        ViewMode::Nfa => nfa_lines(app, &artifacts.nfa),
+        ViewMode::Dfa | ViewMode::MinDfa | ViewMode::Operations => {
+            dfa_lines(app, app.inspected_dfa()?)
+        }
+        _ => return None,
+    };
+
+    let selected = app.view_data().selected_state;
+    let mut content = column![
+        text(app.tr(Key::TextViewHeading))
+            .size(TextSize::H3)
+            .class(TextClass::Primary),
+        text(app.tr(Key::TextViewHint))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+    ]
+    .spacing(8);
+    if let Some(line) = lines.iter().find(|line| Some(line.id) == selected) {
+        content = content.push(
+            text(app.tr_fmt(Key::StateSelected, &[&line.description]))
+                .size(TextSize::Body)
+                .class(TextClass::Primary),
+        );
+    }
+
+    let rows = lines.into_iter().map(|line| {
+        let active = selected == Some(line.id);
+        button(
+            text(line.description)
+                .size(TextSize::Body)
+                .class(if active {
+                    TextClass::Primary
+                } else {
+                    TextClass::Secondary
+                }),
+        )
+        .class(if active {
+            ButtonClass::Primary
+        } else {
+            ButtonClass::Secondary
+        })
+        .width(Length::Fill)
+        .padding([6, 12])
+        .on_press(Message::View(ViewMessage::SelectState(line.id)))
+        .into()
+    });
+
+    Some(
+        content
+            .push(scrollable(column(rows).spacing(4)).height(Length::Fill))
+            .height(Length::Fill)
+            .into(),
+    )
+}
+
+/// Describes every NFA state with its outgoing transitions, ε moves included.
+fn nfa_lines(app: &App, nfa: &Nfa) -> Vec<StateLine> {
+    nfa.states
+        .iter()
+        .map(|state| {
+            let moves = nfa
+                .transitions(state.id)
+                .iter()
+                .map(|transition| {
+                    format!(
+                        "{} → {}",
+                        transition.label.to_string_with(app.epsilon_glyph),
+                        transition.to
+                    )
+                })
+                .collect();
+            StateLine {
+                id: state.id,
+                description: describe(
+                    app,
+                    &state.id.to_string(),
+                    nfa.start == state.id,
+                    nfa.accepts.contains(&state.id),
+                    moves,
+                ),
+            }
+        })
+        .collect()
+}
+
+/// Describes every DFA state with one transition per alphabet symbol, using
+/// the names shown on the canvas.
+fn dfa_lines(app: &App, dfa: &Dfa) -> Vec<StateLine> {
+    let labels = app.state_labels(app.view_mode(), dfa);
+    let name = |state: StateId| {
+        labels
+            .get(&state)
+            .cloned()
+            .unwrap_or_else(|| state.to_string())
+    };
+
+    dfa.states
+        .iter()
+        .map(|&state| {
+            let moves = dfa
+                .alphabet
+                .iter()
+                .zip(&dfa.trans[state as usize])
+                .map(|(symbol, &next)| format!("{symbol} → {}", name(next)))
+                .collect();
+            StateLine {
+                id: state,
+                description: describe(
+                    app,
+                    &name(state),
+                    dfa.start == state,
+                    dfa.accepts.contains(&state),
+                    moves,
+                ),
+            }
+        })
+        .collect()
+}
+
+/// Phrases a state as e.g. "State 0, start, accepting: a → 1, b → 0".
+fn describe(app: &App, name: &str, start: bool, accepting: bool, moves: Vec<String>) -> String {
+    let mut traits = vec![app.tr_fmt(Key::StateEntry, &[&name])];
+    if start {
+        traits.push(app.tr(Key::StateStart).to_string());
+    }
+    if accepting {
+        traits.push(app.tr(Key::StateAccepting).to_string());
+    }
+
+    let moves = if moves.is_empty() {
+        app.tr(Key::StateNoTransitions).to_string()
+    } else {
+        moves.join(", ")
+    };
+    format!("{}: {moves}", traits.join(", "))
+}
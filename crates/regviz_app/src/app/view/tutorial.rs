@@ -0,0 +1,78 @@
+use iced::{
+    Alignment, Length,
+    widget::{button, column, container, opaque, row, text},
+};
+
+use crate::app::{
+    i18n::Key,
+    message::{Message, TutorialMessage},
+    state::App,
+    theme::{ButtonClass, ContainerClass, ElementType, TextClass, TextSize},
+    tutorial::TutorialStep,
+};
+
+/// Outlines `content` while the tutorial is pointing at `step`.
+pub fn spotlight<'a>(app: &App, step: TutorialStep, content: ElementType<'a>) -> ElementType<'a> {
+    if app.tutorial.highlights(step) {
+        container(content)
+            .class(ContainerClass::Spotlight)
+            .padding(8)
+            .into()
+    } else {
+        content
+    }
+}
+
+/// Renders the caption card for the current tutorial step, if the tutorial is running.
+pub fn overlay(app: &App) -> Option<ElementType<'_>> {
+    let step = app.tutorial.step?;
+    let index = step.index();
+    let is_last = index + 1 == TutorialStep::ALL.len();
+
+    let mut back = button(text(app.tr(Key::Back)).size(TextSize::Small))
+        .class(ButtonClass::Secondary)
+        .padding([4, 12]);
+    if index > 0 {
+        back = back.on_press(Message::Tutorial(TutorialMessage::Back));
+    }
+    let next =
+        button(text(app.tr(if is_last { Key::Finish } else { Key::Next })).size(TextSize::Small))
+            .class(ButtonClass::Primary)
+            .padding([4, 12])
+            .on_press(Message::Tutorial(TutorialMessage::Next));
+    let skip = button(text(app.tr(Key::SkipTutorial)).size(TextSize::Small))
+        .class(ButtonClass::Secondary)
+        .padding([4, 12])
+        .on_press(Message::Tutorial(TutorialMessage::Dismiss));
+
+    let card = container(
+        column![
+            text(app.tr_fmt(
+                Key::TutorialProgress,
+                &[
+                    &(index + 1),
+                    &TutorialStep::ALL.len(),
+                    &app.tr(step.title()),
+                ],
+            ))
+            .size(TextSize::H3)
+            .class(TextClass::Primary),
+            text(app.tr(step.caption()))
+                .size(TextSize::Body)
+                .class(TextClass::Secondary),
+            row![skip, back, next].spacing(8).align_y(Alignment::Center),
+        ]
+        .spacing(8),
+    )
+    .class(ContainerClass::RoundedLarge)
+    .padding(16)
+    .max_width(420);
+
+    Some(
+        container(opaque(card))
+            .align_right(Length::Fill)
+            .align_top(Length::Fill)
+            .padding(24)
+            .into(),
+    )
+}
@@ -1,61 +1,219 @@
+use std::collections::{HashMap, HashSet};
+
 use iced::{
     Alignment, Element, Length,
     alignment::{Horizontal, Vertical},
-    widget::{Canvas, button, column, container, row, text, themer},
+    widget::{Canvas, button, column, container, row, text, text_input, themer},
+};
+use regviz_core::core::{
+    analysis::{self as scc, TransitionHeat},
+    automaton::{EdgeLabel, StateId},
+    dfa::Dfa,
+    diff::DfaDiff,
+    nfa::Nfa,
 };
+use regviz_layout::Color;
 
 use crate::app::{
-    message::{Message, RightPaneMode, ViewMessage, ViewMode},
+    i18n::Key, simulation::SimulationTarget, theme::AppTheme, tutorial::TutorialStep, windows,
+};
+use crate::app::{
+    message::{Message, RightPaneMode, StateLayer, ViewMessage, ViewMode, WindowMessage},
     theme::{ButtonClass, ElementType, TextClass, TextSize},
 };
-use crate::app::{simulation::SimulationTarget, theme::AppTheme};
-use crate::graph::layout::{DfaLayoutStrategy, NfaLayoutStrategy, TreeLayoutStrategy};
-use crate::graph::{AstGraph, BoxVisibility, GraphCanvas, Highlights, VisualDfa, VisualNfa};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::graph::RgbaImage;
+use crate::graph::layout::{DfaLayoutStrategy, DynLayoutStrategy, TreeLayoutStrategy};
+use crate::graph::{
+    AstGraph, BoxVisibility, EdgeHighlight, GraphCanvas, HighlightLayer, Highlights, SearchQuery,
+    StateHighlight, ToLayout, TransitionNotation, VisualDfa, VisualNfa, component_tints,
+    heat_tints,
+};
 use crate::{
-    app::state::App,
+    app::state::{App, ViewData},
     graph::{Graph, layout::LayoutStrategy},
 };
 
-use super::controls;
+use super::{
+    analysis, construction, context_menu, controls, definition, exercise, status_bar, transcript,
+    tutorial, workbench,
+};
+
+/// Name of the highlight layer marking the inspector's state layer.
+const STATE_LAYER: &str = "inspector";
+/// Stacking order of the inspector's layer among the highlight layers.
+const STATE_LAYER_Z_INDEX: i32 = 0;
+/// Opacity of the inspector's layer.
+const STATE_LAYER_ALPHA: f32 = 0.6;
+/// Name of the highlight layer marking states linked to the other DFA view's selection.
+const LINKED_LAYER: &str = "linked";
+/// Stacking order of the linked states' layer, above the inspector's layer.
+const LINKED_LAYER_Z_INDEX: i32 = 1;
+/// Name of the heatmap layer marking transitions into fairly busy states.
+const HEAT_WARM_LAYER: &str = "heat-warm";
+/// Name of the heatmap layer marking transitions into the busiest states.
+const HEAT_HOT_LAYER: &str = "heat-hot";
+/// Stacking order of the heatmap layers, below the inspector's layer.
+const HEAT_Z_INDEX: i32 = -1;
+/// Name of the highlight layer marking transitions that pause playback.
+const EDGE_BREAKPOINT_LAYER: &str = "edge-breakpoints";
+/// Stacking order of the breakpoint transitions' layer, above the linked states' layer.
+const EDGE_BREAKPOINT_LAYER_Z_INDEX: i32 = 2;
+/// Name of the highlight layer marking the selected transition.
+const SELECTED_EDGE_LAYER: &str = "selected-edge";
+/// Stacking order of the selected transition's layer, above every other layer
+/// but the edit flash.
+const SELECTED_EDGE_LAYER_Z_INDEX: i32 = 3;
+/// Name of the highlight layer flashing the states the last edit added.
+const EDIT_ADDED_LAYER: &str = "edit-added";
+/// Name of the highlight layer flashing the states and transitions the last edit changed.
+const EDIT_CHANGED_LAYER: &str = "edit-changed";
+/// Stacking order of the edit flash layers, above every other layer.
+const EDIT_FLASH_Z_INDEX: i32 = 4;
 
 /// Renders the active visualization (AST or automaton).
 pub fn render<'a>(
     app: &'a App,
     artifacts: &'a regviz_core::core::BuildArtifacts,
 ) -> ElementType<'a> {
-    let canvas = match app.view_mode() {
-        ViewMode::Ast => render_ast_canvas(app, artifacts),
-        ViewMode::Nfa | ViewMode::Dfa | ViewMode::MinDfa => render_automaton_canvas(app, artifacts),
-    };
-
-    let title_text = match app.view_mode() {
-        ViewMode::Ast => "Parse Tree Visualization",
-        ViewMode::Nfa => "NFA Simulation",
-        ViewMode::Dfa => "DFA Simulation",
-        ViewMode::MinDfa => "Minimized DFA Simulation",
+    let listing = if app.show_text_view {
+        transcript::render(app, artifacts)
+    } else {
+        None
     };
+    let canvas = listing.unwrap_or_else(|| match app.view_mode() {
+        ViewMode::Ast => render_ast_canvas(app, artifacts),
+        mode @ (ViewMode::Nfa | ViewMode::Dfa | ViewMode::MinDfa) => {
+            render_automaton_canvas(app, artifacts, mode)
+        }
+        ViewMode::Analysis => analysis::render(app, artifacts),
+        ViewMode::Operations => render_operations_canvas(app),
+        ViewMode::Workbench => workbench::render(app),
+        ViewMode::Exercise => exercise::render(app),
+        ViewMode::Definition => definition::render(app),
+    });
 
-    let title = text(title_text)
-        .size(TextSize::H2)
-        .class(TextClass::Primary);
+    let mut title = row![
+        text(app.tr(title_key(app.view_mode())))
+            .size(TextSize::H2)
+            .class(TextClass::Primary)
+            .width(Length::Fill)
+    ]
+    .spacing(12)
+    .align_y(Alignment::Center);
+    let graph_view = matches!(
+        app.view_mode(),
+        ViewMode::Ast | ViewMode::Nfa | ViewMode::Dfa | ViewMode::MinDfa | ViewMode::Operations
+    );
+    if graph_view && !app.show_text_view {
+        title = title.push(search_bar(app));
+    }
+    let mode = app.view_mode();
+    let detachable = matches!(
+        mode,
+        ViewMode::Ast | ViewMode::Nfa | ViewMode::Dfa | ViewMode::MinDfa
+    );
+    if windows::can_detach() && detachable {
+        title = title.push(
+            button(text(app.tr(Key::OpenInWindow)).size(TextSize::Small))
+                .class(ButtonClass::Secondary)
+                .padding([4, 12])
+                .on_press(Message::Window(WindowMessage::Detach(mode))),
+        );
+    }
     let bottom = bottom_controls(app);
 
-    let content = column![title, canvas, bottom]
+    let mut content = column![title, canvas]
         .spacing(12)
         .height(Length::Fill)
         .align_x(Alignment::Start);
+    if !app.show_text_view && artifacts.ast.is_some() {
+        match mode {
+            ViewMode::Ast => content = content.push(construction::parse_trace(app)),
+            ViewMode::Nfa => content = content.push(construction::nfa_construction(app)),
+            _ => {}
+        }
+    }
 
-    container(content).padding(20).height(Length::Fill).into()
+    container(content.push(bottom))
+        .padding(20)
+        .height(Length::Fill)
+        .into()
+}
+
+/// Renders the `mode` view alone, for a window it was detached into.
+///
+/// Canvas messages are relayed with the view they came from, so they pan,
+/// zoom and select in that view whichever one the main window shows.
+pub fn render_detached(app: &App, mode: ViewMode) -> ElementType<'_> {
+    let canvas = match &app.build_artifacts {
+        Some(artifacts) if mode == ViewMode::Ast => render_ast_canvas(app, artifacts),
+        Some(artifacts) => render_automaton_canvas(app, artifacts, mode),
+        None => text(app.tr(Key::EnterRegexHint))
+            .height(Length::Fill)
+            .size(TextSize::Body)
+            .class(TextClass::Secondary)
+            .align_x(Horizontal::Center)
+            .into(),
+    }
+    .map(move |message| Message::Window(WindowMessage::Relay(mode, Box::new(message))));
+
+    let title = text(app.tr(title_key(mode)))
+        .size(TextSize::H2)
+        .class(TextClass::Primary);
+    container(column![title, canvas].spacing(12).height(Length::Fill))
+        .padding(20)
+        .height(Length::Fill)
+        .into()
+}
+
+/// Renders the box searching the canvas for states and transitions.
+fn search_bar(app: &App) -> ElementType<'_> {
+    let mut bar = row![
+        text_input(app.tr(Key::GraphSearchPlaceholder), &app.graph_query)
+            .on_input(|value| Message::View(ViewMessage::GraphQueryChanged(value)))
+            .padding([6, 10])
+            .size(TextSize::Small)
+            .width(Length::Fixed(240.0))
+    ]
+    .spacing(4)
+    .align_y(Alignment::Center);
+
+    if !app.graph_query.is_empty() {
+        bar = bar.push(
+            button(text(app.tr(Key::Clear)).size(TextSize::Small))
+                .class(ButtonClass::Secondary)
+                .padding([4, 12])
+                .on_press(Message::View(ViewMessage::GraphQueryChanged(String::new()))),
+        );
+    }
+
+    bar.into()
+}
+
+/// Returns the heading shown above the `mode` view.
+pub fn title_key(mode: ViewMode) -> Key {
+    match mode {
+        ViewMode::Ast => Key::TitleAst,
+        ViewMode::Nfa => Key::TitleNfa,
+        ViewMode::Dfa => Key::TitleDfa,
+        ViewMode::MinDfa => Key::TitleMinDfa,
+        ViewMode::Analysis => Key::TitleAnalysis,
+        ViewMode::Operations => Key::TitleOperations,
+        ViewMode::Workbench => Key::TitleWorkbench,
+        ViewMode::Exercise => Key::TitleExercises,
+        ViewMode::Definition => Key::TitleDefinition,
+    }
 }
 
 /// Applies pan and drag state from the app to the given canvas.
-fn apply_pan_state<'a, G, S>(app: &App, canvas: &mut GraphCanvas<G, S>)
+fn apply_pan_state<'a, G, S>(app: &App, data: &ViewData, canvas: &mut GraphCanvas<G, S>)
 where
     G: Graph + 'a,
     S: LayoutStrategy + 'a,
 {
     // Apply pan state from app
-    canvas.pan_offset = app.view_data().pan_offset;
+    canvas.pan_offset = data.pan_offset;
     canvas.panning = app.last_cursor_position.is_some();
 }
 
@@ -63,32 +221,83 @@ fn render_ast_canvas<'a>(
     app: &'a App,
     artifacts: &'a regviz_core::core::BuildArtifacts,
 ) -> ElementType<'a> {
-    let ast_graph = AstGraph::new(&artifacts.ast, &app.view_data().pinned_node_positions);
-    let mut canvas: GraphCanvas<AstGraph, TreeLayoutStrategy> = GraphCanvas::new(
-        ast_graph,
-        BoxVisibility::default(),
-        app.view_data().zoom_factor,
-        TreeLayoutStrategy,
-    );
-
-    apply_pan_state(app, &mut canvas);
+    let Some(mut canvas) = ast_canvas(app, artifacts) else {
+        // Automata composed directly (not parsed from a pattern) have no tree to draw.
+        return text(app.tr(Key::NoParseTree))
+            .height(Length::Fill)
+            .size(TextSize::Body)
+            .class(TextClass::Secondary)
+            .align_x(Horizontal::Center)
+            .into();
+    };
+    apply_pan_state(app, app.view_state.data_for(ViewMode::Ast), &mut canvas);
+    let status = status_bar::render(app, ViewMode::Ast, canvas.laid_out());
 
     let canvas_elem: Element<'_, Message, AppTheme> = Canvas::new(canvas)
         .width(Length::Fill)
         .height(Length::Fill)
         .into();
 
-    themer(app.theme.into(), canvas_elem).into()
+    frame_canvas(
+        app,
+        ViewMode::Ast,
+        themer(app.theme.into(), canvas_elem).into(),
+        status,
+    )
+}
+
+/// Puts the context menu over the `mode` canvas and its `status` bar under it.
+fn frame_canvas<'a>(
+    app: &'a App,
+    mode: ViewMode,
+    canvas: ElementType<'a>,
+    status: ElementType<'a>,
+) -> ElementType<'a> {
+    column![context_menu::overlay(app, mode, canvas), status]
+        .spacing(6)
+        .height(Length::Fill)
+        .into()
+}
+
+/// Builds the canvas of the parse tree, or `None` when the automata were not parsed from a pattern.
+fn ast_canvas<'a>(
+    app: &'a App,
+    artifacts: &'a regviz_core::core::BuildArtifacts,
+) -> Option<GraphCanvas<AstGraph<'a>, TreeLayoutStrategy>> {
+    let ast = artifacts.ast.as_ref()?;
+    let data = app.view_state.data_for(ViewMode::Ast);
+    let replay = app.parse_trace.as_ref();
+    let ast_graph = AstGraph::new(ast, &data.pinned_node_positions)
+        .with_epsilon_glyph(app.epsilon_glyph)
+        .with_highlights(replay.map(|replay| replay.highlights()).unwrap_or_default());
+    Some(
+        GraphCanvas::new(
+            ast_graph,
+            BoxVisibility::default(),
+            data.zoom_factor,
+            TreeLayoutStrategy,
+        )
+        .with_layout_config(app.layout_config)
+        .with_search(SearchQuery::parse(&app.graph_query))
+        .with_reveal(replay.map(|replay| replay.reveal(ast))),
+    )
 }
 
 /// Renders an empty right pane when no artifacts are available.
+///
+/// The workbench and exercises have their own patterns, so they stay usable without one.
 pub fn render_empty(app: &App) -> ElementType<'_> {
-    let hint = text("Enter a regular expression to visualize")
-        .height(Length::Fill)
-        .size(TextSize::Body)
-        .class(TextClass::Secondary)
-        .align_y(Vertical::Top)
-        .align_x(Horizontal::Center);
+    let hint: ElementType<'_> = match app.view_mode() {
+        ViewMode::Workbench => workbench::render(app),
+        ViewMode::Exercise => exercise::render(app),
+        _ => text(app.tr(Key::EnterRegexHint))
+            .height(Length::Fill)
+            .size(TextSize::Body)
+            .class(TextClass::Secondary)
+            .align_y(Vertical::Top)
+            .align_x(Horizontal::Center)
+            .into(),
+    };
 
     let bottom = bottom_controls(app);
 
@@ -101,14 +310,17 @@ pub fn render_empty(app: &App) -> ElementType<'_> {
 }
 
 fn bottom_controls(app: &App) -> ElementType<'_> {
+    // Both rows wrap so the controls stay reachable on narrow viewports.
     let selector = selector_buttons(app);
-    let selector_elem: Element<'_, Message, AppTheme> = selector.into();
+    let selector_elem = tutorial::spotlight(app, TutorialStep::SwitchToDfa, selector.wrap().into());
     let zoom_controls = controls::zoom(app);
+    let layout_preset = controls::layout_preset(app);
 
-    let row = row![selector_elem, zoom_controls]
+    let row = row![selector_elem, zoom_controls, layout_preset]
         .spacing(16)
         .align_y(Alignment::Center)
-        .width(Length::Fill);
+        .width(Length::Fill)
+        .wrap();
 
     container(row)
         .align_x(Horizontal::Center)
@@ -123,11 +335,41 @@ fn selector_buttons<'a>(app: &App) -> iced::widget::Row<'a, Message, AppTheme> {
     let is_dfa = curr_view_mode == ViewMode::Dfa;
     let is_min_dfa = curr_view_mode == ViewMode::MinDfa;
     let is_ast = curr_view_mode == ViewMode::Ast;
+    let is_analysis = curr_view_mode == ViewMode::Analysis;
+    let is_operations = curr_view_mode == ViewMode::Operations;
+    let is_workbench = curr_view_mode == ViewMode::Workbench;
+    let is_exercise = curr_view_mode == ViewMode::Exercise;
+    let is_definition = curr_view_mode == ViewMode::Definition;
     row![
-        tri_button("NFA", is_nfa, RightPaneMode::Nfa),
-        tri_button("DFA", is_dfa, RightPaneMode::Dfa),
-        tri_button("Min DFA", is_min_dfa, RightPaneMode::MinDfa),
-        tri_button("AST", is_ast, RightPaneMode::Ast),
+        tri_button(app.tr(Key::TabNfa), is_nfa, RightPaneMode::Nfa),
+        tri_button(app.tr(Key::TabDfa), is_dfa, RightPaneMode::Dfa),
+        tri_button(app.tr(Key::TabMinDfa), is_min_dfa, RightPaneMode::MinDfa),
+        tri_button(app.tr(Key::TabAst), is_ast, RightPaneMode::Ast),
+        tri_button(
+            app.tr(Key::TabAnalysis),
+            is_analysis,
+            RightPaneMode::Analysis
+        ),
+        tri_button(
+            app.tr(Key::TabOperations),
+            is_operations,
+            RightPaneMode::Operations
+        ),
+        tri_button(
+            app.tr(Key::TabWorkbench),
+            is_workbench,
+            RightPaneMode::Workbench
+        ),
+        tri_button(
+            app.tr(Key::TabExercises),
+            is_exercise,
+            RightPaneMode::Exercise
+        ),
+        tri_button(
+            app.tr(Key::TabDefinition),
+            is_definition,
+            RightPaneMode::Definition
+        ),
     ]
     .spacing(12)
     .align_y(Alignment::Center)
@@ -151,83 +393,485 @@ fn tri_button(label: &str, active: bool, mode: RightPaneMode) -> ElementType<'_>
         .into()
 }
 
+/// Canvas of an automaton view, built before it is shown or exported.
+///
+/// The NFA and DFA views share one canvas type, so they are drawn and exported
+/// by the same code.
+type AutomatonCanvas<'a> = GraphCanvas<Box<dyn Graph + 'a>, &'a dyn DynLayoutStrategy>;
+
+/// Renders the NFA, DFA or minimized DFA named by `mode`, with the simulation
+/// highlights when it is the simulated automaton.
 fn render_automaton_canvas<'a>(
     app: &'a App,
     artifacts: &'a regviz_core::core::BuildArtifacts,
+    mode: ViewMode,
 ) -> ElementType<'a> {
-    let pinned_node_positions = &app.view_data().pinned_node_positions;
-    let zoom_factor = app.view_data().zoom_factor;
-
-    match app.simulation.target {
-        SimulationTarget::Nfa => {
-            let highlights: Highlights = app.simulation.current_highlights().unwrap_or_default();
-            let graph = VisualNfa::new(&artifacts.nfa, highlights, pinned_node_positions);
-            let mut canvas: GraphCanvas<VisualNfa, NfaLayoutStrategy> = GraphCanvas::new(
-                graph,
-                app.box_visibility.clone(),
-                zoom_factor,
-                NfaLayoutStrategy,
-            );
-            apply_pan_state(app, &mut canvas);
-
-            Canvas::new(canvas)
+    let data = app.view_state.data_for(mode);
+    match automaton_canvas(app, artifacts, mode) {
+        Some(mut canvas) => {
+            apply_pan_state(app, data, &mut canvas);
+            let status = status_bar::render(app, mode, canvas.laid_out());
+            let canvas = Canvas::new(canvas)
                 .width(Length::Fill)
                 .height(Length::Fill)
-                .into()
+                .into();
+            frame_canvas(app, mode, canvas, status)
         }
-        SimulationTarget::Dfa => {
-            // Prefer the determinized DFA, fall back to minimized if only that exists.
-            let maybe_dfa = artifacts.dfa.as_ref().or(artifacts.min_dfa.as_ref());
-
-            let Some(dfa) = maybe_dfa else {
-                return text("Determinized DFA is not available")
-                    .size(TextSize::Body)
-                    .class(TextClass::Warning)
-                    .into();
+        None => text(app.tr(if mode == ViewMode::Dfa {
+            Key::DeterminizedUnavailable
+        } else {
+            Key::MinimizedUnavailable
+        }))
+        .size(TextSize::Body)
+        .class(TextClass::Warning)
+        .into(),
+    }
+}
+
+/// Builds the canvas of the NFA, DFA or minimized DFA named by `mode`, or
+/// `None` when that DFA was not built.
+fn automaton_canvas<'a>(
+    app: &'a App,
+    artifacts: &'a regviz_core::core::BuildArtifacts,
+    mode: ViewMode,
+) -> Option<AutomatonCanvas<'a>> {
+    let data = app.view_state.data_for(mode);
+    let pinned_node_positions = &data.pinned_node_positions;
+    let simulated = SimulationTarget::for_view(mode) == Some(app.simulation.target);
+
+    let dfa = match mode {
+        ViewMode::Nfa => {
+            let replay = app.construction.as_ref();
+            let highlights: Highlights = if let Some(replay) = replay {
+                replay.highlights()
+            } else if simulated {
+                app.simulation.current_highlights().unwrap_or_default()
+            } else {
+                Highlights::default()
             };
+            let highlights = with_nfa_heat(app, &artifacts.nfa, highlights);
+            let highlights =
+                with_edge_layers(app, data, nfa_transitions(&artifacts.nfa), highlights);
+            let graph = VisualNfa::new(&artifacts.nfa, highlights, pinned_node_positions)
+                .with_selection(data.selected_state)
+                .with_tints(nfa_tints(app, &artifacts.nfa))
+                .with_breakpoints(breakpoints(app))
+                .with_epsilon_glyph(app.epsilon_glyph)
+                .with_max_depth(app.collapse_depth)
+                .with_collapsed_boxes(data.collapsed_boxes.clone());
+            let canvas = GraphCanvas::new(
+                Box::new(graph) as Box<dyn Graph>,
+                app.box_visibility.clone(),
+                data.zoom_factor,
+                &app.nfa_layout as &dyn DynLayoutStrategy,
+            )
+            .with_layout_config(app.layout_config)
+            .with_box_styles(app.box_styles.clone())
+            .with_depth_dimming(app.dim_nested_boxes)
+            .with_search(SearchQuery::parse(&app.graph_query))
+            .with_reveal(replay.map(|replay| replay.reveal()))
+            .with_animation_time(
+                (simulated && replay.is_none()).then_some(app.simulation.march_time),
+            )
+            .with_transitions(TransitionNotation::Nondeterministic);
+            return Some(canvas);
+        }
+        // Prefer the determinized DFA, fall back to minimized if only that exists.
+        ViewMode::Dfa => artifacts.dfa.as_ref().or(artifacts.min_dfa.as_ref())?,
+        // Prefer the minimized DFA, fall back to determinized if only that exists.
+        _ => artifacts.min_dfa.as_ref().or(artifacts.dfa.as_ref())?,
+    };
 
-            let highlights: Highlights = app.simulation.current_highlights().unwrap_or_default();
-            let graph = VisualDfa::new(dfa, &artifacts.alphabet, highlights, pinned_node_positions);
-            let mut canvas: GraphCanvas<VisualDfa, DfaLayoutStrategy> = GraphCanvas::new(
-                graph,
-                BoxVisibility::default(),
-                zoom_factor,
-                DfaLayoutStrategy,
-            );
+    let highlights = with_state_layer(app, data, dfa, dfa_highlights(app, dfa, simulated));
+    let highlights = with_linked_states(app, data, highlights);
+    let highlights = with_dfa_heat(app, dfa, highlights);
+    let highlights = with_edge_layers(app, data, dfa_transitions(dfa), highlights);
+    let minimized = artifacts
+        .min_dfa
+        .as_ref()
+        .is_some_and(|min_dfa| std::ptr::eq(min_dfa, dfa));
+    let highlights = with_edit_flash(app, app.edit_diff.changes(minimized), highlights);
+    let graph = VisualDfa::new(dfa, &artifacts.alphabet, highlights, pinned_node_positions)
+        .with_selection(data.selected_state)
+        .with_tints(dfa_tints(app, dfa))
+        .with_breakpoints(breakpoints(app))
+        .with_labels(app.state_labels(mode, dfa))
+        .with_grouping(app.transition_grouping);
+    let canvas = GraphCanvas::new(
+        Box::new(graph) as Box<dyn Graph>,
+        BoxVisibility::default(),
+        data.zoom_factor,
+        &DfaLayoutStrategy as &dyn DynLayoutStrategy,
+    )
+    .with_layout_config(app.layout_config)
+    .with_search(SearchQuery::parse(&app.graph_query))
+    .with_animation_time(simulated.then_some(app.simulation.march_time))
+    .with_transitions(TransitionNotation::Deterministic);
+    Some(canvas)
+}
 
-            apply_pan_state(app, &mut canvas);
+/// Draws the `mode` view as an SVG document for exporting.
+///
+/// # Returns
+/// - `Option<String>` - The document, or `None` when `mode` does not draw a
+///   graph or nothing was built to draw.
+pub fn export_svg(app: &App, mode: ViewMode) -> Option<String> {
+    let artifacts = app.build_artifacts.as_ref()?;
+    let theme = &app.theme;
+    let quality = &app.export_quality;
+    match mode {
+        ViewMode::Ast => Some(ast_canvas(app, artifacts)?.to_svg(theme, quality)),
+        ViewMode::Nfa | ViewMode::Dfa | ViewMode::MinDfa => {
+            Some(automaton_canvas(app, artifacts, mode)?.to_svg(theme, quality))
+        }
+        _ => None,
+    }
+}
 
-            Canvas::new(canvas)
-                .width(Length::Fill)
-                .height(Length::Fill)
-                .into()
+/// Rasterizes the `mode` view offscreen, as drawn by [`export_svg`].
+///
+/// # Returns
+/// - `Option<RgbaImage>` - The picture, or `None` when `mode` does not draw a
+///   graph or nothing was built to draw.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn export_image(app: &App, mode: ViewMode, renderer: &mut iced::Renderer) -> Option<RgbaImage> {
+    let artifacts = app.build_artifacts.as_ref()?;
+    let theme = &app.theme;
+    let quality = &app.export_quality;
+    match mode {
+        ViewMode::Ast => Some(ast_canvas(app, artifacts)?.to_image(renderer, theme, quality)),
+        ViewMode::Nfa | ViewMode::Dfa | ViewMode::MinDfa => {
+            Some(automaton_canvas(app, artifacts, mode)?.to_image(renderer, theme, quality))
         }
-        SimulationTarget::MinDfa => {
-            // Prefer the minimized DFA, fall back to determinized if only that exists.
-            let maybe_dfa = artifacts.min_dfa.as_ref().or(artifacts.dfa.as_ref());
-
-            let Some(dfa) = maybe_dfa else {
-                return text("Minimized DFA is not available")
-                    .size(TextSize::Body)
-                    .class(TextClass::Warning)
-                    .into();
-            };
+        _ => None,
+    }
+}
 
-            let highlights: Highlights = app.simulation.current_highlights().unwrap_or_default();
-            let graph = VisualDfa::new(dfa, &artifacts.alphabet, highlights, pinned_node_positions);
-            let mut canvas: GraphCanvas<VisualDfa, DfaLayoutStrategy> = GraphCanvas::new(
-                graph,
-                BoxVisibility::default(),
-                zoom_factor,
-                DfaLayoutStrategy,
-            );
+/// Renders the DFA produced by the operations panel, if any operation was applied.
+fn render_operations_canvas(app: &App) -> ElementType<'_> {
+    let Some(dfa) = app.operations.current() else {
+        return text(app.tr(Key::OperationsEmpty))
+            .height(Length::Fill)
+            .size(TextSize::Body)
+            .class(TextClass::Secondary)
+            .align_x(Horizontal::Center)
+            .into();
+    };
+    dfa_canvas(app, dfa, ViewMode::Operations)
+}
 
-            apply_pan_state(app, &mut canvas);
+/// Renders a DFA that is not driven by the simulation, with the inspector overlays.
+pub(super) fn dfa_canvas<'a>(app: &'a App, dfa: &'a Dfa, mode: ViewMode) -> ElementType<'a> {
+    let data = app.view_state.data_for(mode);
+    let graph = VisualDfa::new(
+        dfa,
+        &dfa.alphabet,
+        with_dfa_heat(
+            app,
+            dfa,
+            with_selected_edge(
+                app,
+                data,
+                dfa_transitions(dfa),
+                with_state_layer(app, data, dfa, Highlights::default()),
+            ),
+        ),
+        &data.pinned_node_positions,
+    )
+    .with_selection(data.selected_state)
+    .with_tints(match (mode, &app.exercise.answer_diff) {
+        (ViewMode::Exercise, Some(diff)) if !app.show_scc_overlay && !app.show_heatmap => {
+            diff_tints(diff)
+        }
+        _ => dfa_tints(app, dfa),
+    })
+    .with_labels(app.state_labels(mode, dfa))
+    .with_grouping(app.transition_grouping);
+    let mut canvas: GraphCanvas<VisualDfa, DfaLayoutStrategy> = GraphCanvas::new(
+        graph,
+        BoxVisibility::default(),
+        data.zoom_factor,
+        DfaLayoutStrategy,
+    )
+    .with_layout_config(app.layout_config)
+    .with_search(SearchQuery::parse(&app.graph_query))
+    .with_transitions(TransitionNotation::Deterministic);
 
-            Canvas::new(canvas)
-                .width(Length::Fill)
-                .height(Length::Fill)
-                .into()
+    apply_pan_state(app, data, &mut canvas);
+    let status = status_bar::render(app, mode, canvas.laid_out());
+
+    let canvas_elem: Element<'_, Message, AppTheme> = Canvas::new(canvas)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into();
+
+    frame_canvas(
+        app,
+        mode,
+        themer(app.theme.into(), canvas_elem).into(),
+        status,
+    )
+}
+
+/// Returns the states marked as simulation breakpoints.
+fn breakpoints(app: &App) -> HashSet<StateId> {
+    app.simulation.breakpoints.iter().copied().collect()
+}
+
+/// Adds the inspector's layer around the selected state to `highlights`: the
+/// states it marks and the transitions between them.
+fn with_state_layer(app: &App, data: &ViewData, dfa: &Dfa, highlights: Highlights) -> Highlights {
+    let (Some(state), Some(layer)) = (data.selected_state, data.state_layer) else {
+        return highlights;
+    };
+    let states: HashSet<StateId> = match layer {
+        StateLayer::Reachable => scc::reachable_from(dfa, state),
+        StateLayer::AcceptingPaths => scc::accepting_paths_through(dfa, state),
+    }
+    .into_iter()
+    .collect();
+    let edges: Vec<EdgeHighlight> = states
+        .iter()
+        .flat_map(|&from| {
+            dfa.alphabet
+                .iter()
+                .zip(&dfa.trans[from as usize])
+                .filter(|(_, to)| states.contains(to))
+                .map(move |(&symbol, &to)| EdgeHighlight::new(from, to, EdgeLabel::Sym(symbol)))
+        })
+        .collect();
+
+    let color = AppTheme::with_alpha(app.theme.graph_node_layer(), STATE_LAYER_ALPHA);
+    highlights.with_layer(
+        HighlightLayer::new(STATE_LAYER, color.to_layout(), STATE_LAYER_Z_INDEX)
+            .with_states(states)
+            .with_edges(edges),
+    )
+}
+
+/// Adds a layer around the states linked to the selection in the other of
+/// the DFA and minimized DFA views, if any.
+fn with_linked_states(app: &App, data: &ViewData, highlights: Highlights) -> Highlights {
+    if data.linked_states.is_empty() {
+        return highlights;
+    }
+    let color = app.theme.graph_node_linked();
+    highlights.with_layer(
+        HighlightLayer::new(LINKED_LAYER, color.to_layout(), LINKED_LAYER_Z_INDEX)
+            .with_states(data.linked_states.iter().copied()),
+    )
+}
+
+/// Adds the layers marking the selected transition and the breakpoint
+/// transitions among `edges`, the transitions of the drawn automaton.
+fn with_edge_layers(
+    app: &App,
+    data: &ViewData,
+    edges: impl IntoIterator<Item = EdgeHighlight>,
+    highlights: Highlights,
+) -> Highlights {
+    let breakpoints = &app.simulation.edge_breakpoints;
+    if breakpoints.is_empty() {
+        return with_selected_edge(app, data, edges, highlights);
+    }
+    let edges: Vec<EdgeHighlight> = edges.into_iter().collect();
+    let color = app.theme.graph_node_breakpoint();
+    let highlights = highlights.with_layer(
+        HighlightLayer::new(
+            EDGE_BREAKPOINT_LAYER,
+            color.to_layout(),
+            EDGE_BREAKPOINT_LAYER_Z_INDEX,
+        )
+        .with_edges(
+            edges
+                .iter()
+                .copied()
+                .filter(|edge| breakpoints.contains(&(edge.from, edge.to))),
+        ),
+    );
+    with_selected_edge(app, data, edges, highlights)
+}
+
+/// Adds a layer marking the selected transition among `edges`, if one is selected.
+fn with_selected_edge(
+    app: &App,
+    data: &ViewData,
+    edges: impl IntoIterator<Item = EdgeHighlight>,
+    highlights: Highlights,
+) -> Highlights {
+    let Some((from, to)) = data.selected_edge else {
+        return highlights;
+    };
+    let color = app.theme.accent();
+    highlights.with_layer(
+        HighlightLayer::new(
+            SELECTED_EDGE_LAYER,
+            color.to_layout(),
+            SELECTED_EDGE_LAYER_Z_INDEX,
+        )
+        .with_edges(
+            edges
+                .into_iter()
+                .filter(|edge| edge.from == from && edge.to == to),
+        ),
+    )
+}
+
+/// Adds the layers flashing the states and transitions the last edit of the
+/// regex added or changed, fading as the flash runs out.
+fn with_edit_flash(app: &App, changes: Option<&DfaDiff>, highlights: Highlights) -> Highlights {
+    let Some(changes) = changes else {
+        return highlights;
+    };
+    let intensity = app.edit_diff.intensity();
+    let added = AppTheme::with_alpha(app.theme.success(), intensity);
+    let changed = AppTheme::with_alpha(app.theme.warning(), intensity);
+    let changed_states = changes
+        .acceptance_changed
+        .iter()
+        .map(|change| change.right)
+        .chain(
+            changes
+                .transitions_changed
+                .iter()
+                .map(|change| change.right),
+        );
+    let changed_edges = changes.transitions_changed.iter().filter_map(|change| {
+        let to = change.right_target?;
+        Some(EdgeHighlight::new(
+            change.right,
+            to,
+            EdgeLabel::Sym(change.symbol),
+        ))
+    });
+    highlights
+        .with_layer(
+            HighlightLayer::new(EDIT_ADDED_LAYER, added.to_layout(), EDIT_FLASH_Z_INDEX)
+                .with_states(changes.added.iter().copied()),
+        )
+        .with_layer(
+            HighlightLayer::new(EDIT_CHANGED_LAYER, changed.to_layout(), EDIT_FLASH_Z_INDEX)
+                .with_states(changed_states)
+                .with_edges(changed_edges),
+        )
+}
+
+/// Returns every transition of an NFA.
+fn nfa_transitions(nfa: &Nfa) -> impl Iterator<Item = EdgeHighlight> + '_ {
+    nfa.edges
+        .iter()
+        .map(|edge| EdgeHighlight::new(edge.from, edge.to, edge.label))
+}
+
+/// Returns every transition of a DFA.
+fn dfa_transitions(dfa: &Dfa) -> impl Iterator<Item = EdgeHighlight> + '_ {
+    dfa.transitions()
+        .map(|(from, symbol, to)| EdgeHighlight::new(from, to, EdgeLabel::Sym(symbol)))
+}
+
+/// Returns heatmap or SCC overlay colors for an NFA, or none when both are off.
+fn nfa_tints(app: &App, nfa: &Nfa) -> HashMap<StateId, Color> {
+    if app.show_heatmap {
+        heat_tints(&scc::nfa_heat(nfa))
+    } else if app.show_scc_overlay {
+        component_tints(&scc::nfa_sccs(nfa))
+    } else {
+        HashMap::new()
+    }
+}
+
+/// Returns heatmap or SCC overlay colors for a DFA, or none when both are off.
+fn dfa_tints(app: &App, dfa: &Dfa) -> HashMap<StateId, Color> {
+    if app.show_heatmap {
+        heat_tints(&scc::dfa_heat(dfa))
+    } else if app.show_scc_overlay {
+        component_tints(&scc::sccs(dfa))
+    } else {
+        HashMap::new()
+    }
+}
+
+/// Adds the heatmap's transition layers of an NFA when the heatmap is on.
+fn with_nfa_heat(app: &App, nfa: &Nfa, highlights: Highlights) -> Highlights {
+    if !app.show_heatmap {
+        return highlights;
+    }
+    with_heat_layers(highlights, &scc::nfa_heat(nfa), nfa_transitions(nfa))
+}
+
+/// Adds the heatmap's transition layers of a DFA when the heatmap is on.
+fn with_dfa_heat(app: &App, dfa: &Dfa, highlights: Highlights) -> Highlights {
+    if !app.show_heatmap {
+        return highlights;
+    }
+    with_heat_layers(highlights, &scc::dfa_heat(dfa), dfa_transitions(dfa))
+}
+
+/// Colors `edges` by how many transitions enter their target, relative to the
+/// most entered state: red from two thirds of it, amber from one third.
+fn with_heat_layers(
+    highlights: Highlights,
+    heat: &TransitionHeat,
+    edges: impl IntoIterator<Item = EdgeHighlight>,
+) -> Highlights {
+    const WARM: Color = Color::from_rgb(0.95, 0.6, 0.1);
+    const HOT: Color = Color::from_rgb(0.85, 0.2, 0.15);
+
+    let hottest = heat.in_degree.iter().copied().max().unwrap_or(0).max(1) as f32;
+    let (mut warm, mut hot) = (Vec::new(), Vec::new());
+    for edge in edges {
+        let ratio = heat.in_degree[edge.to as usize] as f32 / hottest;
+        if ratio >= 2.0 / 3.0 {
+            hot.push(edge);
+        } else if ratio >= 1.0 / 3.0 {
+            warm.push(edge);
         }
     }
+    highlights
+        .with_layer(HighlightLayer::new(HEAT_WARM_LAYER, WARM, HEAT_Z_INDEX).with_edges(warm))
+        .with_layer(HighlightLayer::new(HEAT_HOT_LAYER, HOT, HEAT_Z_INDEX).with_edges(hot))
+}
+
+/// Tints the states of an exercise answer that differ from the target: red for
+/// states the target has no counterpart for, amber for aligned states whose
+/// acceptance or transitions differ.
+fn diff_tints(diff: &DfaDiff) -> HashMap<StateId, Color> {
+    const EXTRA: Color = Color::from_rgb(1.0, 0.7, 0.7);
+    const CHANGED: Color = Color::from_rgb(1.0, 0.85, 0.55);
+
+    let changed = diff
+        .acceptance_changed
+        .iter()
+        .map(|change| change.right)
+        .chain(diff.transitions_changed.iter().map(|change| change.right));
+    changed
+        .map(|state| (state, CHANGED))
+        .chain(diff.added.iter().map(|&state| (state, EXTRA)))
+        .collect()
+}
+
+/// Returns the lasso highlights when the infinite-language overlay is on,
+/// otherwise the current simulation step's highlights if `dfa` is `simulated`.
+fn dfa_highlights(app: &App, dfa: &Dfa, simulated: bool) -> Highlights {
+    if !app.show_infinite_witness {
+        return if simulated {
+            app.simulation.current_highlights().unwrap_or_default()
+        } else {
+            Highlights::default()
+        };
+    }
+
+    let Some(lasso) = scc::infinite_witness(dfa) else {
+        return Highlights::default();
+    };
+    let path = lasso.stem.iter().chain(&lasso.cycle).chain(&lasso.suffix);
+    let edges = path
+        .clone()
+        .map(|edge| EdgeHighlight::new(edge.from, edge.to, edge.label))
+        .collect();
+    let states = path
+        .flat_map(|edge| [edge.from, edge.to])
+        .map(|state| (state, StateHighlight::Active))
+        .collect();
+    Highlights::new(states, edges)
 }
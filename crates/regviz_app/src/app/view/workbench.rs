@@ -0,0 +1,182 @@
+use iced::{
+    Alignment, Length,
+    alignment::Horizontal,
+    widget::{button, column, row, text, text_input},
+};
+use regviz_core::core::equiv::Equivalence;
+use regviz_core::core::product::{BoolOp, Containment};
+use regviz_core::errors::BuildError;
+
+use crate::app::{
+    i18n::Key,
+    message::{Message, ViewMode, WorkbenchMessage},
+    state::App,
+    theme::{ButtonClass, ElementType, TextClass, TextInputClass, TextSize},
+};
+
+use super::visualization;
+
+/// Renders the two pattern inputs, the operation picker, the verdict and the combined DFA.
+pub fn render(app: &App) -> ElementType<'_> {
+    let workbench = &app.workbench;
+    let inputs = row![
+        operand(
+            app,
+            Key::LeftPattern,
+            &workbench.left,
+            workbench.left_error.as_ref(),
+            WorkbenchMessage::LeftChanged,
+        ),
+        operand(
+            app,
+            Key::RightPattern,
+            &workbench.right,
+            workbench.right_error.as_ref(),
+            WorkbenchMessage::RightChanged,
+        ),
+    ]
+    .spacing(12);
+
+    let operations = row(BoolOp::ALL.iter().map(|&op| {
+        let selected = workbench.op == op;
+        button(text(format!("{} {}", op.symbol(), app.locale.bool_op(op))).size(TextSize::Small))
+            .class(if selected {
+                ButtonClass::Primary
+            } else {
+                ButtonClass::Secondary
+            })
+            .padding([4, 12])
+            .on_press(Message::Workbench(WorkbenchMessage::SetOperation(op)))
+            .into()
+    }))
+    .spacing(4)
+    .wrap();
+
+    let mut content = column![inputs, operations].spacing(8);
+    match &workbench.result {
+        Some(result) => {
+            let symbol = workbench.op.symbol();
+            let emptiness = match &result.witness {
+                None => app.tr_fmt(Key::CombinationEmpty, &[&symbol]),
+                Some(word) => app.tr_fmt(
+                    Key::CombinationNonEmpty,
+                    &[&symbol, &app.epsilon_glyph.or_word(word)],
+                ),
+            };
+            let verdict = workbench.verdict().map_or("", |key| app.tr(key));
+            content = content
+                .push(
+                    text(format!("{emptiness} {verdict}"))
+                        .size(TextSize::Body)
+                        .class(if result.witness.is_none() {
+                            TextClass::Success
+                        } else {
+                            TextClass::Primary
+                        }),
+                )
+                .push(equivalence_line(app, &result.equivalence))
+                .push(containment_line(
+                    app,
+                    Key::LeftInRight,
+                    &result.left_in_right,
+                ))
+                .push(containment_line(
+                    app,
+                    Key::RightInLeft,
+                    &result.right_in_left,
+                ))
+                .push(visualization::dfa_canvas(
+                    app,
+                    &result.dfa,
+                    ViewMode::Workbench,
+                ));
+        }
+        None => {
+            content = content.push(
+                text(app.tr(Key::EnterBothPatterns))
+                    .height(Length::Fill)
+                    .size(TextSize::Body)
+                    .class(TextClass::Secondary)
+                    .width(Length::Fill)
+                    .align_x(Horizontal::Center),
+            );
+        }
+    }
+
+    content.height(Length::Fill).into()
+}
+
+/// Reports whether the two patterns are equivalent, with a distinguishing string if not.
+fn equivalence_line<'a>(app: &App, equivalence: &Equivalence) -> ElementType<'a> {
+    let (summary, class) = match equivalence {
+        Equivalence::Equivalent => (
+            app.tr(Key::PatternsEquivalent).to_string(),
+            TextClass::Success,
+        ),
+        Equivalence::Distinguished(word) => (
+            app.tr_fmt(
+                Key::PatternsDistinguished,
+                &[&app.epsilon_glyph.or_word(word)],
+            ),
+            TextClass::Secondary,
+        ),
+    };
+    text(summary).size(TextSize::Small).class(class).into()
+}
+
+/// Reports one direction of the containment check, with a counterexample if it fails.
+fn containment_line<'a>(app: &App, claim: Key, containment: &Containment) -> ElementType<'a> {
+    let claim = app.tr(claim);
+    let (summary, class) = match containment {
+        Containment::Contained => (
+            app.tr_fmt(Key::ContainmentHolds, &[&claim]),
+            TextClass::Success,
+        ),
+        Containment::Witness(word) => (
+            app.tr_fmt(
+                Key::ContainmentFails,
+                &[&claim, &app.epsilon_glyph.or_word(word)],
+            ),
+            TextClass::Secondary,
+        ),
+    };
+    text(summary).size(TextSize::Small).class(class).into()
+}
+
+/// Renders one labelled pattern input with its build error, if any.
+fn operand<'a>(
+    app: &App,
+    label: Key,
+    value: &'a str,
+    error: Option<&'a BuildError>,
+    on_input: fn(String) -> WorkbenchMessage,
+) -> ElementType<'a> {
+    let input = text_input(app.tr(Key::PatternPlaceholder), value)
+        .class(if error.is_some() {
+            TextInputClass::Invalid
+        } else {
+            TextInputClass::Default
+        })
+        .on_input(move |value| Message::Workbench(on_input(value)))
+        .padding([8, 12])
+        .size(TextSize::Body)
+        .width(Length::Fill);
+
+    let mut content = column![
+        text(app.tr(label))
+            .size(TextSize::Small)
+            .class(TextClass::Secondary),
+        input
+    ]
+    .spacing(4)
+    .width(Length::FillPortion(1))
+    .align_x(Alignment::Start);
+    if let Some(err) = error {
+        content = content.push(
+            text(app.tr_fmt(Key::ErrorMessage, &[err]))
+                .size(TextSize::Small)
+                .class(TextClass::Error),
+        );
+    }
+    content.into()
+}
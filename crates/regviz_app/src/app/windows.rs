@@ -0,0 +1,57 @@
+use iced::{Task, window};
+
+use super::constants::DETACHED_WINDOW_SIZE;
+use super::message::{Message, ViewMode};
+use super::state::App;
+#[cfg(not(target_arch = "wasm32"))]
+use super::view::title_key;
+
+impl App {
+    /// Creates the app and opens its main window.
+    ///
+    /// Native builds run as a daemon so views can be detached into windows of
+    /// their own; the main window is opened here like any other.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn boot() -> (Self, Task<Message>) {
+        let (id, open) = window::open(window::Settings::default());
+        let app = Self {
+            main_window: Some(id),
+            ..Self::restored()
+        };
+        (
+            app,
+            Task::batch([open.discard(), Self::query_system_theme()]),
+        )
+    }
+
+    /// Opens the `mode` view in a new window.
+    pub fn open_detached(&mut self, mode: ViewMode) -> Task<Message> {
+        let (id, open) = window::open(window::Settings {
+            size: DETACHED_WINDOW_SIZE,
+            ..window::Settings::default()
+        });
+        self.detached.insert(id, mode);
+        open.discard()
+    }
+
+    /// Returns whether a detached window shows a DFA, which is then kept built.
+    pub fn shows_detached_dfa(&self) -> bool {
+        self.detached
+            .values()
+            .any(|mode| matches!(mode, ViewMode::Dfa | ViewMode::MinDfa))
+    }
+
+    /// Returns the title of `window`, naming the view shown by detached ones.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn window_title_for(&self, window: window::Id) -> String {
+        match self.detached.get(&window) {
+            Some(&mode) => format!("RegViz - {}", self.tr(title_key(mode))),
+            None => self.window_title(),
+        }
+    }
+}
+
+/// Returns whether views can be opened in windows of their own on this platform.
+pub const fn can_detach() -> bool {
+    cfg!(not(target_arch = "wasm32"))
+}
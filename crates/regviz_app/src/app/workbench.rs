@@ -0,0 +1,113 @@
+use regviz_core::core::dfa::{self, Dfa};
+use regviz_core::core::equiv::{self, Equivalence};
+use regviz_core::core::min;
+use regviz_core::core::nfa::Nfa;
+use regviz_core::core::parser::Ast;
+use regviz_core::core::product::{self, BoolOp, Containment};
+use regviz_core::errors::BuildError;
+
+use super::i18n::Key;
+
+/// Two-regex workspace for combining languages with Boolean operations.
+#[derive(Debug)]
+pub struct WorkbenchState {
+    /// Left operand pattern.
+    pub left: String,
+    /// Right operand pattern.
+    pub right: String,
+    /// Operation applied to the two languages.
+    pub op: BoolOp,
+    /// Error from building the left pattern, if any.
+    pub left_error: Option<BuildError>,
+    /// Error from building the right pattern, if any.
+    pub right_error: Option<BuildError>,
+    /// Combined automaton, available once both patterns build.
+    pub result: Option<WorkbenchResult>,
+}
+
+impl Default for WorkbenchState {
+    fn default() -> Self {
+        Self {
+            left: String::new(),
+            right: String::new(),
+            op: BoolOp::SymmetricDifference,
+            left_error: None,
+            right_error: None,
+            result: None,
+        }
+    }
+}
+
+/// Outcome of combining the two workbench patterns.
+#[derive(Debug)]
+pub struct WorkbenchResult {
+    /// Minimized DFA for the combined language.
+    pub dfa: Dfa,
+    /// Shortest word in the combined language, or `None` if it is empty.
+    pub witness: Option<String>,
+    /// Whether `L(left) ⊆ L(right)`.
+    pub left_in_right: Containment,
+    /// Whether `L(right) ⊆ L(left)`.
+    pub right_in_left: Containment,
+    /// Whether the two patterns match the same strings.
+    pub equivalence: Equivalence,
+}
+
+impl WorkbenchState {
+    /// Rebuilds both operands and their combination after an edit.
+    ///
+    /// Empty patterns count as not yet entered rather than as `ε`, so nothing
+    /// is combined until both sides have been typed.
+    pub fn rebuild(&mut self) {
+        let left = build_dfa(&self.left);
+        let right = build_dfa(&self.right);
+        self.left_error = left
+            .as_ref()
+            .and_then(|built| built.as_ref().err().cloned());
+        self.right_error = right
+            .as_ref()
+            .and_then(|built| built.as_ref().err().cloned());
+
+        self.result = match (left, right) {
+            (Some(Ok(left)), Some(Ok(right))) => {
+                let dfa = min::minimize(&product::product(&left, &right, self.op));
+                let witness = dfa.shortest_accepted();
+                Some(WorkbenchResult {
+                    dfa,
+                    witness,
+                    left_in_right: product::contains(&left, &right),
+                    right_in_left: product::contains(&right, &left),
+                    equivalence: equiv::hopcroft_karp(&left, &right),
+                })
+            }
+            _ => None,
+        };
+    }
+
+    /// Explains what an empty or non-empty result says about the two languages.
+    #[must_use]
+    pub fn verdict(&self) -> Option<Key> {
+        let result = self.result.as_ref()?;
+        let empty = result.witness.is_none();
+        let verdict = match (self.op, empty) {
+            (BoolOp::Union, true) => Key::VerdictBothEmpty,
+            (BoolOp::Union, false) => Key::VerdictSomeNonEmpty,
+            (BoolOp::Intersection, true) => Key::VerdictDisjoint,
+            (BoolOp::Intersection, false) => Key::VerdictOverlap,
+            (BoolOp::Difference, true) => Key::VerdictContained,
+            (BoolOp::Difference, false) => Key::VerdictNotContained,
+            (BoolOp::SymmetricDifference, true) => Key::VerdictEquivalent,
+            (BoolOp::SymmetricDifference, false) => Key::VerdictNotEquivalent,
+        };
+        Some(verdict)
+    }
+}
+
+/// Builds a DFA for `pattern`, or `None` if nothing has been typed yet.
+fn build_dfa(pattern: &str) -> Option<Result<Dfa, BuildError>> {
+    let pattern = pattern.trim();
+    if pattern.is_empty() {
+        return None;
+    }
+    Some(Ast::build(pattern).map(|ast| dfa::determinize(&Nfa::build(&ast))))
+}
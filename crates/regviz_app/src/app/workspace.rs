@@ -0,0 +1,155 @@
+use std::mem;
+
+use regviz_core::core::BuildArtifacts;
+use regviz_core::core::diagnostics::Warning;
+use regviz_core::core::nfa::ConstructionStep;
+use regviz_core::core::parser::ParseEvent;
+use regviz_core::errors::{AlphabetError, BuildError, SimError};
+
+use super::construction::Replay;
+use super::edit_diff::EditDiff;
+use super::operations::OperationStack;
+use super::simulation::SimulationState;
+use super::state::{App, ViewState};
+
+/// A regex explored in one tab, with the automata, simulation and views built from it.
+#[derive(Default)]
+pub struct Workspace {
+    input: String,
+    error: Option<BuildError>,
+    build_artifacts: Option<BuildArtifacts>,
+    declared_alphabet: String,
+    alphabet_error: Option<AlphabetError>,
+    warnings: Vec<Warning>,
+    graph_query: String,
+    operations: OperationStack,
+    simulation: SimulationState,
+    simulation_error: Option<SimError>,
+    construction: Option<Replay<ConstructionStep>>,
+    parse_trace: Option<Replay<ParseEvent>>,
+    edit_diff: EditDiff,
+    view_state: ViewState,
+}
+
+impl Workspace {
+    /// Moves the active workspace out of `app`, leaving an empty one behind.
+    fn take(app: &mut App) -> Self {
+        Self {
+            input: mem::take(&mut app.input),
+            error: app.error.take(),
+            build_artifacts: app.build_artifacts.take(),
+            declared_alphabet: mem::take(&mut app.declared_alphabet),
+            alphabet_error: app.alphabet_error.take(),
+            warnings: mem::take(&mut app.warnings),
+            graph_query: mem::take(&mut app.graph_query),
+            operations: mem::take(&mut app.operations),
+            simulation: mem::take(&mut app.simulation),
+            simulation_error: app.simulation_error.take(),
+            construction: app.construction.take(),
+            parse_trace: app.parse_trace.take(),
+            edit_diff: mem::take(&mut app.edit_diff),
+            view_state: mem::take(&mut app.view_state),
+        }
+    }
+
+    /// Makes this the active workspace of `app`.
+    fn restore(self, app: &mut App) {
+        app.input = self.input;
+        app.error = self.error;
+        app.build_artifacts = self.build_artifacts;
+        app.declared_alphabet = self.declared_alphabet;
+        app.alphabet_error = self.alphabet_error;
+        app.warnings = self.warnings;
+        app.graph_query = self.graph_query;
+        app.operations = self.operations;
+        app.simulation = self.simulation;
+        app.simulation_error = self.simulation_error;
+        app.construction = self.construction;
+        app.parse_trace = self.parse_trace;
+        app.edit_diff = self.edit_diff;
+        app.view_state = self.view_state;
+    }
+}
+
+/// Open workspace tabs.
+///
+/// The active workspace lives in the [`App`] fields so the rest of the app
+/// never has to look it up; the other tabs are parked here until selected.
+pub struct Tabs {
+    /// One slot per tab, in order, holding `None` for the active tab.
+    parked: Vec<Option<Workspace>>,
+    /// Index of the active tab.
+    active: usize,
+}
+
+impl Default for Tabs {
+    fn default() -> Self {
+        Self {
+            parked: vec![None],
+            active: 0,
+        }
+    }
+}
+
+impl Tabs {
+    /// Returns the index of the active tab.
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Returns the number of open tabs.
+    pub fn len(&self) -> usize {
+        self.parked.len()
+    }
+
+    /// Returns the pattern typed in every tab, reading the active one from `app`.
+    pub fn patterns<'a>(&'a self, app: &'a App) -> Vec<&'a str> {
+        self.parked
+            .iter()
+            .map(|slot| slot.as_ref().map_or(app.input.as_str(), |ws| &ws.input))
+            .collect()
+    }
+}
+
+impl App {
+    /// Opens an empty workspace in a new tab after the others and switches to it.
+    pub fn open_tab(&mut self) {
+        let current = Workspace::take(self);
+        self.tabs.parked[self.tabs.active] = Some(current);
+        self.tabs.parked.push(None);
+        self.tabs.active = self.tabs.parked.len() - 1;
+        self.last_cursor_position = None;
+        self.selected_token = None;
+    }
+
+    /// Switches to the tab at `index`, keeping the current one as it is.
+    pub fn select_tab(&mut self, index: usize) {
+        if index == self.tabs.active || index >= self.tabs.parked.len() {
+            return;
+        }
+        let Some(next) = self.tabs.parked[index].take() else {
+            return;
+        };
+        let current = Workspace::take(self);
+        self.tabs.parked[self.tabs.active] = Some(current);
+        self.tabs.active = index;
+        next.restore(self);
+        self.last_cursor_position = None;
+        self.selected_token = None;
+    }
+
+    /// Closes the tab at `index`, discarding its workspace. The last tab stays open.
+    pub fn close_tab(&mut self, index: usize) {
+        if self.tabs.parked.len() <= 1 || index >= self.tabs.parked.len() {
+            return;
+        }
+        if index == self.tabs.active {
+            // Activate a neighbour first so the closed workspace is the parked one.
+            self.select_tab(if index == 0 { 1 } else { index - 1 });
+        }
+        self.tabs.parked.remove(index);
+        if self.tabs.active > index {
+            self.tabs.active -= 1;
+        }
+    }
+}
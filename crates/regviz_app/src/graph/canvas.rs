@@ -1,13 +1,46 @@
-use iced::widget::canvas::{self, Frame, Program};
-use iced::{Point, Rectangle, Size, Vector, mouse};
+use std::cell::OnceCell;
+use std::collections::HashMap;
+
+use iced::advanced::mouse::click::{self, Click};
+use iced::alignment::Vertical;
+use iced::time::Instant;
+use iced::widget::canvas::{self, Frame, Path, Program, Stroke, Text};
+use iced::{Point, Rectangle, Size, Vector, keyboard, mouse, touch, window};
 use iced_graphics::geometry::Renderer;
 use regviz_core::core::automaton::StateId;
 
-use super::layout::LayoutStrategy;
-use super::{BoxVisibility, DrawContext, Drawable, Graph, GraphLayout};
-use crate::app::message::{Message, ViewMessage};
+#[cfg(not(target_arch = "wasm32"))]
+use super::export::RgbaImage;
+use super::export::{ExportQuality, SvgRenderer};
+use super::layout::{LayoutConfig, LayoutStrategy};
+use super::{
+    BoxStyles, BoxVisibility, DrawContext, Drawable, Graph, GraphLayout, Reveal, SearchQuery,
+    SpatialIndex, ToIced, ToLayout,
+};
+use crate::app::APP_FONT;
+use crate::app::message::{CanvasElement, Message, ViewMessage};
 use crate::app::theme::AppTheme;
 
+/// How the tooltip of a hovered transition relates its endpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionNotation {
+    /// The transition is the one move on its symbol: `δ(q1, a) = q3`.
+    Deterministic,
+    /// The target is one of several moves on its symbols: `δ(q1, a) ∋ q3`.
+    Nondeterministic,
+}
+
+impl TransitionNotation {
+    /// Describes the transition from `from` to `to` on `label`.
+    fn describe(self, from: &str, label: &str, to: &str) -> String {
+        let relation = match self {
+            Self::Deterministic => '=',
+            Self::Nondeterministic => '∋',
+        };
+        format!("δ({from}, {label}) {relation} {to}")
+    }
+}
+
 /// Interactive canvas responsible for rendering graphs with zoom support.
 ///
 /// The canvas is generic over both the graph type and the layout strategy,
@@ -16,19 +49,161 @@ use crate::app::theme::AppTheme;
 pub struct GraphCanvas<G: Graph, S: LayoutStrategy> {
     graph: G,
     visibility: BoxVisibility,
+    layout_config: LayoutConfig,
+    box_styles: BoxStyles,
+    dim_boxes_by_depth: bool,
+    search: Option<SearchQuery>,
+    reveal: Option<Reveal>,
+    /// How edges are described when hovered, or `None` when they are not
+    /// transitions and cannot be selected.
+    transitions: Option<TransitionNotation>,
     zoom_factor: f32,
     strategy: S,
+    /// Seconds on the animation clock moving the taken transitions, or `None`
+    /// to draw them still.
+    animation_time: Option<f32>,
+    /// Layout and its hit-testing index, computed on first use so pointer
+    /// events between two redraws of the view reuse them.
+    layout: OnceCell<(GraphLayout, SpatialIndex)>,
     /// Pan offset for dragging the canvas
     pub pan_offset: Vector,
     /// Track if currently panning
     pub panning: bool,
 }
 
+/// Maximum cursor travel, in screen pixels, for a node press to count as a click.
+const CLICK_TOLERANCE: f32 = 4.0;
+
+/// Maximum cursor distance, in screen pixels, for an edge to count as hovered.
+const EDGE_HOVER_TOLERANCE: f32 = 6.0;
+
+/// Space left around an exported graph, in layout units, on top of a state's radius.
+const EXPORT_MARGIN: f32 = 16.0;
+
+/// Font size of the hovered transition's tooltip, in screen pixels.
+const TOOLTIP_TEXT_SIZE: f32 = 13.0;
+
+/// Space between the tooltip's text and its border, in screen pixels.
+const TOOLTIP_PADDING: f32 = 6.0;
+
+/// Offset of the tooltip from the cursor, in screen pixels.
+const TOOLTIP_OFFSET: f32 = 14.0;
+
+/// Opacity of the veil dimming everything a search does not match.
+const SEARCH_VEIL_ALPHA: f32 = 0.75;
+
+/// Fraction of the glide velocity kept after one second of kinetic panning.
+const GLIDE_FRICTION: f32 = 0.04;
+
+/// Speed, in screen pixels per second, below which kinetic panning stops.
+const GLIDE_MIN_SPEED: f32 = 30.0;
+
+/// Longest pause, in seconds, between the last pan move and the release for the view to keep gliding.
+const GLIDE_RELEASE_WINDOW: f32 = 0.08;
+
+/// Factor a double-click zooms in by, or out by with shift held.
+const DOUBLE_CLICK_ZOOM: f32 = 2.0;
+
+/// Distance, in screen pixels, an arrow key pans the focused canvas by.
+const KEYBOARD_PAN_STEP: f32 = 40.0;
+
+/// Width of the ring drawn around the focused canvas, in screen pixels.
+const FOCUS_RING_WIDTH: f32 = 2.0;
+
 /// Mutable runtime state for the canvas program.
 #[derive(Debug, Clone, Default)]
 pub struct CanvasState {
     /// Currently dragged node id + position, if any.
     node_dragging: Option<(StateId, Point)>,
+    /// Layout position where the current node drag started.
+    press_origin: Option<Point>,
+    /// Offset from the pointer to the dragged node's center, so the node does not jump to it.
+    grab_offset: Vector,
+    /// Zoom and translation frozen while a node is dragged, so refitting the
+    /// layout around the moved node does not shift the view under the pointer.
+    drag_frame: Option<(f32, Vector)>,
+    /// Endpoints of the edge currently under the cursor, if any.
+    hovered_edge: Option<(StateId, StateId)>,
+    /// Screen positions of the fingers currently touching the canvas.
+    fingers: HashMap<touch::Finger, Point>,
+    /// Distance between and midpoint of the two fingers of an ongoing pinch, as of the last move.
+    pinch: Option<(f32, Point)>,
+    /// Last pan position and when it was seen, to estimate the release velocity.
+    pan_sample: Option<(Point, Instant)>,
+    /// Smoothed pan velocity in screen pixels per second.
+    pan_velocity: Vector,
+    /// Velocity and last frame time of an ongoing kinetic glide.
+    glide: Option<(Vector, Instant)>,
+    /// Keyboard modifiers held, to tell trackpad pinches (ctrl + scroll) from two-finger scrolls.
+    modifiers: keyboard::Modifiers,
+    /// Latest left click on the canvas, to tell double-clicks apart.
+    last_click: Option<Click>,
+    /// Whether the canvas was clicked last, so arrow keys pan it and +/- zoom it.
+    focused: bool,
+    /// Cursor position last reported to the app, in whole layout units.
+    cursor: Option<Point>,
+}
+
+impl CanvasState {
+    /// Starts dragging the node at `position`, grabbed by the pointer at `pointer`
+    /// (both in layout coordinates), under the view transform `frame`.
+    fn begin_drag(&mut self, node: StateId, position: Point, pointer: Point, frame: (f32, Vector)) {
+        self.node_dragging = Some((node, position));
+        self.press_origin = Some(position);
+        self.grab_offset = position - pointer;
+        self.drag_frame = Some(frame);
+    }
+
+    /// Moves the dragged node along with the pointer, returning its new position.
+    fn drag_to(&mut self, pointer: Point) -> Option<(StateId, Point)> {
+        let (node, _) = self.node_dragging?;
+        let position = pointer + self.grab_offset;
+        self.node_dragging = Some((node, position));
+        Some((node, position))
+    }
+
+    /// Starts tracking a pan at `position`, stopping any glide in progress.
+    fn begin_pan(&mut self, position: Point) {
+        self.pan_sample = Some((position, Instant::now()));
+        self.pan_velocity = Vector::ZERO;
+        self.glide = None;
+    }
+
+    /// Records a pan move to estimate the velocity at release.
+    fn track_pan(&mut self, position: Point) {
+        let now = Instant::now();
+        if let Some((last, seen)) = self.pan_sample {
+            let elapsed = now.duration_since(seen).as_secs_f32();
+            if elapsed > 0.0 {
+                // Smooth out jitter between samples.
+                let velocity = (position - last) * (1.0 / elapsed);
+                self.pan_velocity = self.pan_velocity * 0.2 + velocity * 0.8;
+            }
+        }
+        self.pan_sample = Some((position, now));
+    }
+
+    /// Ends a pan, gliding on if the pointer was still moving when released.
+    fn release_pan(&mut self) {
+        let Some((_, seen)) = self.pan_sample.take() else {
+            return;
+        };
+        let now = Instant::now();
+        let moving = now.duration_since(seen).as_secs_f32() <= GLIDE_RELEASE_WINDOW;
+        if moving && speed(self.pan_velocity) >= GLIDE_MIN_SPEED {
+            self.glide = Some((self.pan_velocity, now));
+        }
+        self.pan_velocity = Vector::ZERO;
+    }
+
+    /// Advances the glide to `now`, returning how far the view moves this frame.
+    fn advance_glide(&mut self, now: Instant) -> Option<Vector> {
+        let (velocity, last) = self.glide?;
+        let elapsed = now.duration_since(last).as_secs_f32();
+        let velocity = velocity * GLIDE_FRICTION.powf(elapsed);
+        self.glide = (speed(velocity) >= GLIDE_MIN_SPEED).then_some((velocity, now));
+        Some(velocity * elapsed)
+    }
 }
 
 impl<G: Graph, S: LayoutStrategy> GraphCanvas<G, S> {
@@ -45,12 +220,101 @@ impl<G: Graph, S: LayoutStrategy> GraphCanvas<G, S> {
         Self {
             graph,
             visibility,
+            layout_config: LayoutConfig::default(),
+            box_styles: BoxStyles::default(),
+            dim_boxes_by_depth: false,
+            search: None,
+            reveal: None,
+            transitions: None,
             zoom_factor,
             strategy,
+            animation_time: None,
+            layout: OnceCell::new(),
             pan_offset: Vector::ZERO,
             panning: false,
         }
     }
+
+    /// Sets the spacing the layout strategy places elements with.
+    #[must_use]
+    pub fn with_layout_config(mut self, layout_config: LayoutConfig) -> Self {
+        self.layout_config = layout_config;
+        self
+    }
+
+    /// Sets how each kind of bounding box is drawn.
+    #[must_use]
+    pub fn with_box_styles(mut self, box_styles: BoxStyles) -> Self {
+        self.box_styles = box_styles;
+        self
+    }
+
+    /// Sets whether nested bounding boxes fade with their depth.
+    #[must_use]
+    pub fn with_depth_dimming(mut self, dim_boxes_by_depth: bool) -> Self {
+        self.dim_boxes_by_depth = dim_boxes_by_depth;
+        self
+    }
+
+    /// Highlights the states and transitions matching `search`, dimming the rest.
+    #[must_use]
+    pub fn with_search(mut self, search: Option<SearchQuery>) -> Self {
+        self.search = search;
+        self
+    }
+
+    /// Draws only the revealed part of the graph, laid out as if it were whole.
+    #[must_use]
+    pub fn with_reveal(mut self, reveal: Option<Reveal>) -> Self {
+        self.reveal = reveal;
+        self
+    }
+
+    /// Makes the edges selectable transitions, described with `notation` when hovered.
+    #[must_use]
+    pub fn with_transitions(mut self, notation: TransitionNotation) -> Self {
+        self.transitions = Some(notation);
+        self
+    }
+
+    /// Animates the transitions taken by the simulation, as of `time` seconds
+    /// on the animation clock.
+    #[must_use]
+    pub fn with_animation_time(mut self, time: Option<f32>) -> Self {
+        self.animation_time = time;
+        self
+    }
+
+    /// Returns the graph as laid out and drawn on the canvas.
+    ///
+    /// The layout is computed once and kept, so calling this before the canvas
+    /// is drawn costs nothing extra.
+    #[must_use]
+    pub fn laid_out(&self) -> &GraphLayout {
+        self.layout().0
+    }
+
+    /// Returns the laid out graph, trimmed to the revealed part when there is
+    /// one and with its boxes styled, along with the index for hit-testing it.
+    fn layout(&self) -> (&GraphLayout, &SpatialIndex) {
+        let (layout, index) = self.layout.get_or_init(|| {
+            let mut layout =
+                self.strategy
+                    .compute(&self.graph, &self.visibility, &self.layout_config);
+            if let Some(reveal) = &self.reveal {
+                reveal.apply(&mut layout);
+            }
+            for bbox in &mut layout.boxes {
+                bbox.style = self.box_styles.get(bbox.data.kind);
+                if self.dim_boxes_by_depth {
+                    bbox.dim_by_depth();
+                }
+            }
+            let index = SpatialIndex::new(&layout);
+            (layout, index)
+        });
+        (layout, index)
+    }
 }
 
 impl<G, S, R> Program<Message, AppTheme, R> for GraphCanvas<G, S>
@@ -63,32 +327,42 @@ where
 
     fn draw(
         &self,
-        _state: &Self::State,
+        state: &Self::State,
         renderer: &R,
         theme: &AppTheme,
         bounds: Rectangle,
-        _cursor: mouse::Cursor,
+        cursor: mouse::Cursor,
     ) -> Vec<canvas::Geometry<R>> {
         // Use the configured layout strategy
-        let layout = self.strategy.compute(&self.graph, &self.visibility);
-        let fit_zoom = fit_zoom(bounds.size(), &layout);
-        let zoom = fit_zoom * self.zoom_factor;
-
-        let translation = center_translation(bounds.size(), &layout, zoom);
-        // Apply pan offset to translation
-        let translation = translation + self.pan_offset;
-        let ctx = DrawContext { zoom, translation };
+        let (layout, _) = self.layout();
+        let (zoom, translation) = state
+            .drag_frame
+            .unwrap_or_else(|| self.transform(bounds.size(), layout));
+        let ctx = DrawContext::new(zoom, translation).with_time(self.animation_time);
 
         let mut frame = Frame::new(renderer, bounds.size());
-
-        for bbox in &layout.boxes {
-            bbox.draw(&mut frame, &ctx, theme);
-        }
-        for edge in &layout.edges {
-            edge.draw(&mut frame, &ctx, theme);
+        self.draw_layout(&mut frame, layout, &ctx, theme);
+        if state.focused {
+            let inset = FOCUS_RING_WIDTH / 2.0;
+            frame.stroke(
+                &Path::rectangle(
+                    Point::new(inset, inset),
+                    Size::new(
+                        bounds.width - FOCUS_RING_WIDTH,
+                        bounds.height - FOCUS_RING_WIDTH,
+                    ),
+                ),
+                Stroke::default()
+                    .with_color(theme.accent())
+                    .with_width(FOCUS_RING_WIDTH),
+            );
         }
-        for node in &layout.nodes {
-            node.draw(&mut frame, &ctx, theme);
+        if let (Some(notation), Some(edge), Some(cursor)) = (
+            self.transitions,
+            state.hovered_edge,
+            cursor.position_in(bounds),
+        ) {
+            draw_tooltip(&mut frame, layout, notation, edge, cursor, theme);
         }
 
         vec![frame.into_geometry()]
@@ -103,10 +377,44 @@ where
     ) -> Option<canvas::Action<Message>> {
         // We'll need the computed layout and transform to translate cursor
         // screen coordinates into layout coordinates for hit testing.
-        let layout = self.strategy.compute(&self.graph, &self.visibility);
-        let fit = fit_zoom(bounds.size(), &layout);
-        let zoom = fit * self.zoom_factor;
-        let translation = center_translation(bounds.size(), &layout, zoom) + self.pan_offset;
+        let (layout, index) = self.layout();
+        let (zoom, translation) = state
+            .drag_frame
+            .unwrap_or_else(|| self.transform(bounds.size(), layout));
+
+        match event {
+            canvas::Event::Touch(touch_event) => {
+                return self.touch_update(state, touch_event, bounds, translation, zoom);
+            }
+            canvas::Event::Window(window::Event::RedrawRequested(now)) => {
+                // Publishing redraws, so the glide keeps advancing frame by frame until it stops.
+                let delta = state.advance_glide(*now)?;
+                return Some(canvas::Action::publish(Message::View(ViewMessage::PanBy(
+                    delta,
+                ))));
+            }
+            canvas::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+                return None;
+            }
+            canvas::Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+                if state.focused =>
+            {
+                let message = keyboard_navigation(key, *modifiers)?;
+                state.glide = None;
+                // Captured, so the arrow keys do not also move the focused state.
+                return Some(canvas::Action::publish(Message::View(message)).and_capture());
+            }
+            _ => {}
+        }
+
+        // Clicking the canvas focuses it, and clicking anywhere else hands the
+        // keys back to the rest of the app.
+        let focus_changed = matches!(event, canvas::Event::Mouse(mouse::Event::ButtonPressed(_)))
+            && state.focused != cursor.is_over(bounds);
+        if focus_changed {
+            state.focused = !state.focused;
+        }
 
         if let canvas::Event::Mouse(mouse_event) = event {
             match mouse_event {
@@ -114,6 +422,10 @@ where
                 // or start panning the canvas.
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
                     if let Some(screen_pos) = cursor.position_in(bounds) {
+                        state.glide = None;
+                        let click = Click::new(screen_pos, mouse::Button::Left, state.last_click);
+                        state.last_click = Some(click);
+
                         // Convert to layout coordinates (inverse transform)
                         let logical = Point::new(
                             (screen_pos.x - translation.x) / zoom,
@@ -121,34 +433,73 @@ where
                         );
 
                         // Hit-test nodes by radius in layout coordinates.
-                        if let Some(hit) = layout.nodes.iter().find(|n| {
-                            let dx = n.position.x - logical.x;
-                            let dy = n.position.y - logical.y;
-                            (dx * dx + dy * dy) <= (n.radius * n.radius)
-                        }) {
+                        if let Some(hit) = index.node_at(layout, logical.to_layout()) {
                             // Start node-drag locally so subsequent cursor
                             // moves will immediately emit NodeDrag messages
                             // without waiting for the app->view roundtrip.
-                            state.node_dragging = Some((hit.data.id, logical));
+                            state.begin_drag(
+                                hit.data.id,
+                                hit.position.to_iced(),
+                                logical,
+                                (zoom, translation),
+                            );
 
                             // Tell the app about the initial drag
                             return Some(canvas::Action::publish(Message::View(
-                                ViewMessage::NodeDrag(hit.data.id, logical),
+                                ViewMessage::NodeDrag(hit.data.id, hit.position.to_iced()),
                             )));
                         }
 
-                        // No node hit — start panning instead. This message will tell the app
+                        // A click on a transition selects it.
+                        if let Some((from, to)) = self.transition_at(layout, index, logical, zoom) {
+                            return Some(canvas::Action::publish(Message::View(
+                                ViewMessage::SelectEdge(from, to),
+                            )));
+                        }
+
+                        // A double-click on the background zooms in on the clicked point,
+                        // or out with shift held.
+                        if click.kind() != click::Kind::Single {
+                            let factor = if state.modifiers.shift() {
+                                DOUBLE_CLICK_ZOOM.recip()
+                            } else {
+                                DOUBLE_CLICK_ZOOM
+                            };
+                            let center = Point::new(bounds.width / 2.0, bounds.height / 2.0);
+                            return Some(canvas::Action::publish(Message::View(
+                                ViewMessage::ZoomAround(factor, screen_pos - center),
+                            )));
+                        }
+
+                        // Nothing hit — start panning instead. This message will tell the app
                         // to set canvas' panning state to true.
+                        state.begin_pan(screen_pos);
                         return Some(canvas::Action::publish(Message::View(
                             ViewMessage::StartPan(screen_pos),
                         )));
                     }
                 }
 
+                // Right mouse press on a node, transition or box opens its context menu.
+                mouse::Event::ButtonPressed(mouse::Button::Right) => {
+                    if let Some(screen_pos) = cursor.position_in(bounds) {
+                        let logical = Point::new(
+                            (screen_pos.x - translation.x) / zoom,
+                            (screen_pos.y - translation.y) / zoom,
+                        );
+
+                        if let Some(element) = self.element_at(layout, index, logical, zoom) {
+                            return Some(canvas::Action::publish(Message::View(
+                                ViewMessage::OpenContextMenu(element, screen_pos),
+                            )));
+                        }
+                    }
+                }
+
                 // Cursor movement: if a node drag is active, publish NodeDrag;
                 // otherwise publish Pan if we're currently panning.
                 mouse::Event::CursorMoved { .. } => {
-                    if let Some((node_id, _)) = state.node_dragging
+                    if state.node_dragging.is_some()
                         && let Some(screen_pos) = cursor.position_in(bounds)
                     {
                         let logical = Point::new(
@@ -157,20 +508,52 @@ where
                         );
 
                         // Update last known position
-                        state.node_dragging = Some((node_id, logical));
+                        let (node_id, position) = state.drag_to(logical)?;
 
                         return Some(canvas::Action::publish(Message::View(
-                            ViewMessage::NodeDrag(node_id, logical),
+                            ViewMessage::NodeDrag(node_id, position),
                         )));
                     }
 
                     if self.panning
                         && let Some(position) = cursor.position_in(bounds)
                     {
+                        state.track_pan(position);
                         return Some(canvas::Action::publish(Message::View(ViewMessage::Pan(
                             position,
                         ))));
                     }
+
+                    // Otherwise track which edge is hovered so the app can preview it.
+                    let logical = cursor.position_in(bounds).map(|screen_pos| {
+                        Point::new(
+                            (screen_pos.x - translation.x) / zoom,
+                            (screen_pos.y - translation.y) / zoom,
+                        )
+                    });
+                    let hovered =
+                        logical.and_then(|logical| hovered_edge(layout, index, logical, zoom));
+
+                    if hovered != state.hovered_edge {
+                        state.hovered_edge = hovered;
+                        return Some(canvas::Action::publish(Message::View(
+                            ViewMessage::HoverEdge(hovered),
+                        )));
+                    }
+                    // Report the cursor's position to the status bar whenever it
+                    // crosses into another whole layout unit.
+                    let position =
+                        logical.map(|logical| Point::new(logical.x.round(), logical.y.round()));
+                    if position != state.cursor {
+                        state.cursor = position;
+                        return Some(canvas::Action::publish(Message::View(
+                            ViewMessage::CursorMoved(position),
+                        )));
+                    }
+                    // Keep the transition's tooltip next to the cursor.
+                    if hovered.is_some() && self.transitions.is_some() {
+                        return Some(canvas::Action::request_redraw());
+                    }
                 }
 
                 // Mouse release: end node drag if active, otherwise end pan.
@@ -178,17 +561,28 @@ where
                     if let Some((node_id, position)) = state.node_dragging {
                         // Clear local drag state
                         state.node_dragging = None;
+                        state.drag_frame = None;
+                        let origin = state.press_origin.take();
 
                         let final_position = if let Some(screen_pos) = cursor.position_in(bounds) {
                             Point::new(
                                 (screen_pos.x - translation.x) / zoom,
                                 (screen_pos.y - translation.y) / zoom,
-                            )
+                            ) + state.grab_offset
                         } else {
                             // Use last known if cursor is outside bounds
                             position
                         };
 
+                        // A press that barely moved is a click: select the node instead.
+                        if let Some(origin) = origin
+                            && origin.distance(final_position) * zoom <= CLICK_TOLERANCE
+                        {
+                            return Some(canvas::Action::publish(Message::View(
+                                ViewMessage::SelectState(node_id),
+                            )));
+                        }
+
                         // Notify app about final position
                         return Some(canvas::Action::publish(Message::View(
                             ViewMessage::NodeDrag(node_id, final_position),
@@ -196,22 +590,28 @@ where
                     }
 
                     if self.panning {
+                        state.release_pan();
                         // Notify app that panning ended. The app will update its canvas' panning state to false.
                         return Some(canvas::Action::publish(Message::View(ViewMessage::EndPan)));
                     }
                 }
 
-                // Handle scroll wheel for zooming
+                // Scroll wheels zoom; trackpads scroll in pixels and pan, or zoom when
+                // pinching, which they report as ctrl + scroll.
                 mouse::Event::WheelScrolled { delta } => {
                     if cursor.is_over(bounds) {
-                        let zoom_delta = match delta {
+                        let message = match delta {
                             // Positive delta for scrolling up (zoom in)
-                            mouse::ScrollDelta::Lines { y, .. } => *y,
-                            mouse::ScrollDelta::Pixels { y, .. } => y / 50.0, // Scale pixel deltas
+                            mouse::ScrollDelta::Lines { y, .. } => ViewMessage::Zoom(*y),
+                            mouse::ScrollDelta::Pixels { y, .. } if state.modifiers.control() => {
+                                ViewMessage::Zoom(y / 50.0) // Scale pixel deltas
+                            }
+                            mouse::ScrollDelta::Pixels { x, y } => {
+                                state.glide = None;
+                                ViewMessage::PanBy(Vector::new(*x, *y))
+                            }
                         };
-                        return Some(canvas::Action::publish(Message::View(ViewMessage::Zoom(
-                            zoom_delta,
-                        ))));
+                        return Some(canvas::Action::publish(Message::View(message)));
                     }
                 }
 
@@ -219,7 +619,8 @@ where
             }
         }
 
-        None
+        // Redraw to show or hide the focus ring.
+        focus_changed.then(canvas::Action::request_redraw)
     }
 
     fn mouse_interaction(
@@ -238,6 +639,452 @@ where
     }
 }
 
+impl<G: Graph, S: LayoutStrategy> GraphCanvas<G, S> {
+    /// Draws the boxes, transitions and states of `layout`, then the search
+    /// highlights, into `frame`. Elements entirely outside the frame are skipped.
+    fn draw_layout<R: Renderer>(
+        &self,
+        frame: &mut Frame<R>,
+        layout: &GraphLayout,
+        ctx: &DrawContext,
+        theme: &AppTheme,
+    ) {
+        let visible = ctx.visible_rect(frame.size());
+        for bbox in layout
+            .boxes
+            .iter()
+            .filter(|bbox| bbox.bounds().intersects(&visible))
+        {
+            bbox.draw(frame, ctx, theme);
+        }
+        for edge in layout
+            .edges
+            .iter()
+            .filter(|edge| edge.bounds().intersects(&visible))
+        {
+            edge.draw(frame, ctx, theme);
+        }
+        for node in layout
+            .nodes
+            .iter()
+            .filter(|node| node.bounds().intersects(&visible))
+        {
+            node.draw(frame, ctx, theme);
+        }
+
+        if let Some(search) = &self.search {
+            // Veil the whole graph, then redraw the matches on top of it.
+            frame.fill(
+                &Path::rectangle(Point::ORIGIN, frame.size()),
+                AppTheme::with_alpha(theme.bg_mid(), SEARCH_VEIL_ALPHA),
+            );
+            for edge in layout.edges.iter().filter(|edge| {
+                search.matches_edge(&edge.data) && edge.bounds().intersects(&visible)
+            }) {
+                edge.draw(frame, ctx, theme);
+            }
+            for node in layout.nodes.iter().filter(|node| {
+                search.matches_node(&node.data) && node.bounds().intersects(&visible)
+            }) {
+                node.draw(frame, ctx, theme);
+            }
+        }
+    }
+
+    /// Draws the graph as a standalone SVG document, fitted to its layout
+    /// rather than to the on-screen zoom and pan.
+    ///
+    /// # Arguments
+    /// - `theme` (`&AppTheme`) - Colors to draw with.
+    /// - `quality` (`&ExportQuality`) - Node radius, stroke and text scales, and pixel density.
+    ///
+    /// # Returns
+    /// - `String` - The SVG document.
+    #[must_use]
+    pub fn to_svg(&self, theme: &AppTheme, quality: &ExportQuality) -> String {
+        let (layout, size, ctx) = self.export_layout(quality);
+        let mut frame = Frame::new(&SvgRenderer, size);
+        frame.fill(&Path::rectangle(Point::ORIGIN, size), theme.bg_mid());
+        self.draw_layout(&mut frame, &layout, &ctx, theme);
+        frame
+            .into_geometry()
+            .into_document(size, quality.supersampling)
+    }
+
+    /// Rasterizes the graph offscreen, the same way [`GraphCanvas::to_svg`] draws it.
+    ///
+    /// # Arguments
+    /// - `renderer` (`&mut iced::Renderer`) - Headless renderer drawing the picture.
+    /// - `theme` (`&AppTheme`) - Colors to draw with.
+    /// - `quality` (`&ExportQuality`) - Node radius, stroke and text scales, and pixel density.
+    ///
+    /// # Returns
+    /// - `RgbaImage` - The picture, with `quality.supersampling` pixels per layout unit.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn to_image(
+        &self,
+        renderer: &mut iced::Renderer,
+        theme: &AppTheme,
+        quality: &ExportQuality,
+    ) -> RgbaImage {
+        use iced::advanced::renderer::{Headless, Renderer as _};
+
+        let (layout, size, ctx) = self.export_layout(quality);
+        renderer.reset(Rectangle::with_size(size));
+        let mut frame = Frame::new(&*renderer, size);
+        frame.fill(&Path::rectangle(Point::ORIGIN, size), theme.bg_mid());
+        self.draw_layout(&mut frame, &layout, &ctx, theme);
+        renderer.draw_geometry(frame.into_geometry());
+
+        let scale = quality.supersampling as f32;
+        let width = (size.width * scale).ceil() as u32;
+        let height = (size.height * scale).ceil() as u32;
+        RgbaImage {
+            width,
+            height,
+            pixels: renderer.screenshot(Size::new(width, height), scale, theme.bg_mid()),
+        }
+    }
+
+    /// Lays the graph out for exporting with `quality`.
+    ///
+    /// # Returns
+    /// - `(GraphLayout, Size, DrawContext)` - The layout, the size of the
+    ///   picture and the context drawing the layout inside its margins.
+    fn export_layout(&self, quality: &ExportQuality) -> (GraphLayout, Size, DrawContext) {
+        let mut layout = self.layout().0.clone();
+        for node in &mut layout.nodes {
+            node.radius = quality.node_radius;
+        }
+        for edge in &mut layout.edges {
+            edge.from_radius = quality.node_radius;
+            edge.to_radius = quality.node_radius;
+        }
+
+        // Self-loops and labels reach past the states, so leave room around them.
+        let margin = EXPORT_MARGIN + quality.node_radius;
+        let size = Size::new(
+            layout.bounds.width + margin * 2.0,
+            layout.bounds.height + margin * 2.0,
+        );
+        let ctx = DrawContext {
+            stroke_scale: quality.stroke_scale,
+            text_scale: quality.text_scale,
+            ..DrawContext::new(
+                1.0,
+                Vector::new(margin - layout.bounds.x, margin - layout.bounds.y),
+            )
+        };
+        (layout, size, ctx)
+    }
+
+    /// Returns the endpoints of the transition under `point` (in layout
+    /// coordinates), if the edges are transitions.
+    fn transition_at(
+        &self,
+        layout: &GraphLayout,
+        index: &SpatialIndex,
+        point: Point,
+        zoom: f32,
+    ) -> Option<(StateId, StateId)> {
+        self.transitions?;
+        hovered_edge(layout, index, point, zoom)
+    }
+
+    /// Returns the element under `point` (in layout coordinates) that a context
+    /// menu can act on: a state, else a transition, else the innermost box.
+    ///
+    /// Super-nodes stand for their collapsed box, so they are reported as it.
+    fn element_at(
+        &self,
+        layout: &GraphLayout,
+        index: &SpatialIndex,
+        point: Point,
+        zoom: f32,
+    ) -> Option<CanvasElement> {
+        let innermost = |point: Point, collapsed_only: bool| {
+            index
+                .boxes_at(layout, point.to_layout())
+                .into_iter()
+                .filter(|bbox| !collapsed_only || bbox.data.collapsed)
+                .min_by(|a, b| {
+                    (a.rect.width * a.rect.height).total_cmp(&(b.rect.width * b.rect.height))
+                })
+                .map(|bbox| CanvasElement::Box(bbox.data.id, bbox.data.collapsed))
+        };
+
+        if let Some(hit) = index.node_at(layout, point.to_layout()) {
+            let position = hit.position.to_iced();
+            if hit.data.collapsed.is_some()
+                && let Some(folded) = innermost(position, true)
+            {
+                return Some(folded);
+            }
+            return Some(CanvasElement::State(hit.data.id, position));
+        }
+        if let Some((from, to)) = self.transition_at(layout, index, point, zoom) {
+            return Some(CanvasElement::Transition(from, to));
+        }
+        innermost(point, false)
+    }
+
+    /// Returns the zoom and translation for `layout` in a canvas of `size`: the
+    /// layout is fitted and centered, then the user's zoom and pan are applied.
+    fn transform(&self, size: Size, layout: &GraphLayout) -> (f32, Vector) {
+        let zoom = fit_zoom(size, layout) * self.zoom_factor;
+        (
+            zoom,
+            center_translation(size, layout, zoom) + self.pan_offset,
+        )
+    }
+
+    /// Handles touch input the way the mouse is handled: one finger drags a
+    /// node or pans, a tap selects a node, and two fingers pinch to zoom while
+    /// their midpoint pans.
+    fn touch_update(
+        &self,
+        state: &mut CanvasState,
+        event: &touch::Event,
+        bounds: Rectangle,
+        translation: Vector,
+        zoom: f32,
+    ) -> Option<canvas::Action<Message>> {
+        let (layout, index) = self.layout();
+        let publish = |message: ViewMessage| Some(canvas::Action::publish(Message::View(message)));
+        // Touch positions are in window coordinates; panning works in canvas coordinates.
+        let local = |position: Point| Point::new(position.x - bounds.x, position.y - bounds.y);
+        let to_layout = |position: Point| {
+            let local = local(position);
+            Point::new(
+                (local.x - translation.x) / zoom,
+                (local.y - translation.y) / zoom,
+            )
+        };
+
+        match *event {
+            touch::Event::FingerPressed { id, position } => {
+                if !bounds.contains(position) {
+                    return None;
+                }
+                state.fingers.insert(id, position);
+                state.glide = None;
+                match state.fingers.len() {
+                    1 => {
+                        let logical = to_layout(position);
+                        if let Some((hit, center)) = node_at(layout, index, logical) {
+                            state.begin_drag(hit, center, logical, (zoom, translation));
+                            return publish(ViewMessage::NodeDrag(hit, center));
+                        }
+                        state.begin_pan(local(position));
+                        return publish(ViewMessage::StartPan(local(position)));
+                    }
+                    2 => {
+                        // A second finger turns the gesture into a pinch.
+                        state.node_dragging = None;
+                        state.press_origin = None;
+                        state.drag_frame = None;
+                        state.pinch = pinch(&state.fingers);
+                        state.pan_sample = None;
+                        if self.panning {
+                            return publish(ViewMessage::EndPan);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            touch::Event::FingerMoved { id, position } => {
+                let finger = state.fingers.get_mut(&id)?;
+                *finger = position;
+
+                if let Some((previous, previous_mid)) = state.pinch {
+                    let (distance, mid) = pinch(&state.fingers)?;
+                    state.pinch = Some((distance, mid));
+                    let scale = if previous > 0.0 {
+                        distance / previous
+                    } else {
+                        1.0
+                    };
+                    return publish(ViewMessage::Pinch(
+                        self.zoom_factor * scale,
+                        mid - previous_mid,
+                    ));
+                }
+
+                if state.node_dragging.is_some() {
+                    let (node_id, center) = state.drag_to(to_layout(position))?;
+                    return publish(ViewMessage::NodeDrag(node_id, center));
+                }
+
+                if self.panning {
+                    state.track_pan(local(position));
+                    return publish(ViewMessage::Pan(local(position)));
+                }
+            }
+
+            touch::Event::FingerLifted { id, position }
+            | touch::Event::FingerLost { id, position } => {
+                state.fingers.remove(&id)?;
+
+                if state.pinch.is_some() {
+                    if state.fingers.len() < 2 {
+                        state.pinch = None;
+                    }
+                    return None;
+                }
+
+                if let Some((node_id, last)) = state.node_dragging.take() {
+                    let origin = state.press_origin.take();
+                    state.drag_frame = None;
+                    let final_position = if bounds.contains(position) {
+                        to_layout(position) + state.grab_offset
+                    } else {
+                        last
+                    };
+
+                    // A lift close to where the finger went down is a tap: select the node.
+                    let tapped = matches!(event, touch::Event::FingerLifted { .. })
+                        && origin.is_some_and(|origin| {
+                            origin.distance(final_position) * zoom <= CLICK_TOLERANCE
+                        });
+                    if tapped {
+                        return publish(ViewMessage::SelectState(node_id));
+                    }
+                    return publish(ViewMessage::NodeDrag(node_id, final_position));
+                }
+
+                if self.panning {
+                    if matches!(event, touch::Event::FingerLifted { .. }) {
+                        state.release_pan();
+                    } else {
+                        state.pan_sample = None;
+                    }
+                    return publish(ViewMessage::EndPan);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Maps a key pressed while the canvas is focused to the view change it
+/// stands for: arrow keys pan and `+`/`-` zoom.
+fn keyboard_navigation(key: &keyboard::Key, modifiers: keyboard::Modifiers) -> Option<ViewMessage> {
+    use keyboard::key::Named;
+
+    if modifiers.command() || modifiers.alt() {
+        return None;
+    }
+    let pan = |x: f32, y: f32| Some(ViewMessage::PanBy(Vector::new(x, y) * KEYBOARD_PAN_STEP));
+    match key.as_ref() {
+        // Moving the view one way slides the graph the other way.
+        keyboard::Key::Named(Named::ArrowLeft) => pan(1.0, 0.0),
+        keyboard::Key::Named(Named::ArrowRight) => pan(-1.0, 0.0),
+        keyboard::Key::Named(Named::ArrowUp) => pan(0.0, 1.0),
+        keyboard::Key::Named(Named::ArrowDown) => pan(0.0, -1.0),
+        // `=` shares its key with `+` on most layouts.
+        keyboard::Key::Character("+" | "=") => Some(ViewMessage::Zoom(1.0)),
+        keyboard::Key::Character("-") => Some(ViewMessage::Zoom(-1.0)),
+        _ => None,
+    }
+}
+
+/// Returns the id and center of the node under `point` (in layout coordinates), if any.
+fn node_at(layout: &GraphLayout, index: &SpatialIndex, point: Point) -> Option<(StateId, Point)> {
+    index
+        .node_at(layout, point.to_layout())
+        .map(|node| (node.data.id, node.position.to_iced()))
+}
+
+/// Draws the description of the transition from `edge.0` to `edge.1` next to
+/// `cursor`, kept inside the frame.
+fn draw_tooltip<R: Renderer>(
+    frame: &mut Frame<R>,
+    layout: &GraphLayout,
+    notation: TransitionNotation,
+    edge: (StateId, StateId),
+    cursor: Point,
+    theme: &AppTheme,
+) {
+    let Some(positioned) = layout
+        .edges
+        .iter()
+        .find(|positioned| (positioned.data.from, positioned.data.to) == edge)
+    else {
+        return;
+    };
+    let name = |id: StateId| {
+        layout
+            .nodes
+            .iter()
+            .find(|node| node.data.id == id)
+            .map_or_else(|| id.to_string(), |node| node.data.label.clone())
+    };
+    let content = notation.describe(&name(edge.0), &positioned.data.label, &name(edge.1));
+
+    // Canvas text cannot be measured, so estimate its width from the glyph count.
+    let width = content.chars().count() as f32 * TOOLTIP_TEXT_SIZE * 0.6 + TOOLTIP_PADDING * 2.0;
+    let height = TOOLTIP_TEXT_SIZE + TOOLTIP_PADDING * 2.0;
+    let size = frame.size();
+    let top_left = Point::new(
+        (cursor.x + TOOLTIP_OFFSET).min(size.width - width).max(0.0),
+        (cursor.y + TOOLTIP_OFFSET)
+            .min(size.height - height)
+            .max(0.0),
+    );
+
+    let background = Path::rounded_rectangle(top_left, Size::new(width, height), 4.0.into());
+    frame.fill(&background, theme.bg_high());
+    frame.stroke(
+        &background,
+        Stroke::default()
+            .with_width(1.0)
+            .with_color(theme.text_dim()),
+    );
+    frame.fill_text(Text {
+        content,
+        position: Point::new(top_left.x + TOOLTIP_PADDING, top_left.y + height / 2.0),
+        color: theme.text_primary(),
+        font: APP_FONT,
+        align_y: Vertical::Center,
+        size: TOOLTIP_TEXT_SIZE.into(),
+        ..Text::default()
+    });
+}
+
+/// Returns the distance between and the midpoint of the first two fingers on the canvas.
+fn pinch(fingers: &HashMap<touch::Finger, Point>) -> Option<(f32, Point)> {
+    let mut positions = fingers.values();
+    let (first, second) = (positions.next()?, positions.next()?);
+    let mid = Point::new((first.x + second.x) / 2.0, (first.y + second.y) / 2.0);
+    Some((first.distance(*second), mid))
+}
+
+/// Length of a velocity vector.
+fn speed(velocity: Vector) -> f32 {
+    velocity.x.hypot(velocity.y)
+}
+
+/// Finds the edge closest to `point` (in layout coordinates) within the hover tolerance.
+///
+/// Points over a node never hover an edge, since the node is drawn on top.
+fn hovered_edge(
+    layout: &GraphLayout,
+    index: &SpatialIndex,
+    point: Point,
+    zoom: f32,
+) -> Option<(StateId, StateId)> {
+    let point = point.to_layout();
+    if index.node_at(layout, point).is_some() {
+        return None;
+    }
+
+    index
+        .edge_near(layout, point, EDGE_HOVER_TOLERANCE / zoom)
+        .map(|edge| (edge.data.from, edge.data.to))
+}
+
 fn fit_zoom(size: Size, layout: &GraphLayout) -> f32 {
     if layout.bounds.width <= 0.0 || layout.bounds.height <= 0.0 {
         return 1.0;
@@ -1,8 +1,25 @@
-use iced::{Point, Vector};
+use iced::{Color, Pixels, Point, Size, Vector};
 use iced_graphics::geometry::{Frame, Renderer as GeometryRenderer};
 
 use crate::app::theme::AppTheme;
 
+/// Zoom below which labels are left out, since they would overlap.
+const LABEL_MIN_ZOOM: f32 = 0.5;
+/// Zoom below which nodes are drawn as plain dots and edges without arrow heads.
+const OVERVIEW_MAX_ZOOM: f32 = 0.3;
+
+/// How much of each element is drawn, picked from the zoom so large graphs
+/// stay fast and legible when zoomed far out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Detail {
+    /// Nodes as plain dots and edges as bare lines.
+    Overview,
+    /// Every shape, without labels.
+    Shapes,
+    /// Everything, labels included.
+    Full,
+}
+
 /// Shared drawing context containing the active transform parameters.
 #[derive(Debug, Clone, Copy)]
 pub struct DrawContext {
@@ -10,12 +27,73 @@ pub struct DrawContext {
     pub zoom: f32,
     /// Translation applied after zooming.
     pub translation: Vector,
+    /// Multiplier applied to stroke widths, which do not follow the zoom.
+    pub stroke_scale: f32,
+    /// Multiplier applied to text sizes after they are zoomed and clamped.
+    pub text_scale: f32,
+    /// Seconds elapsed on the animation clock, or `None` when nothing is animated.
+    pub time: Option<f32>,
 }
 
 impl DrawContext {
+    /// Creates a context drawing at `zoom` then `translation`, with on-screen
+    /// stroke widths and text sizes.
+    #[must_use]
+    pub fn new(zoom: f32, translation: Vector) -> Self {
+        Self {
+            zoom,
+            translation,
+            stroke_scale: 1.0,
+            text_scale: 1.0,
+            time: None,
+        }
+    }
+
+    /// Animates the elements that move, as of `time` seconds on the animation clock.
+    #[must_use]
+    pub fn with_time(mut self, time: Option<f32>) -> Self {
+        self.time = time;
+        self
+    }
+
+    /// Returns how much detail to draw at the active zoom.
+    #[must_use]
+    pub fn detail(&self) -> Detail {
+        if self.zoom < OVERVIEW_MAX_ZOOM {
+            Detail::Overview
+        } else if self.zoom < LABEL_MIN_ZOOM {
+            Detail::Shapes
+        } else {
+            Detail::Full
+        }
+    }
+
+    /// Returns the width of a stroke drawn `width` pixels wide on screen.
+    #[must_use]
+    pub fn stroke_width(&self, width: f32) -> f32 {
+        width * self.stroke_scale
+    }
+
+    /// Returns the size of text whose `base` size is zoomed and kept within `min..=max` on screen.
+    #[must_use]
+    pub fn text_size(&self, base: f32, min: f32, max: f32) -> Pixels {
+        Pixels((base * self.zoom).clamp(min, max) * self.text_scale)
+    }
+
+    /// Returns the part of the layout shown in a frame of `size`, in layout coordinates.
+    #[must_use]
+    pub fn visible_rect(&self, size: Size) -> regviz_layout::Rect {
+        regviz_layout::Rect {
+            x: -self.translation.x / self.zoom,
+            y: -self.translation.y / self.zoom,
+            width: size.width / self.zoom,
+            height: size.height / self.zoom,
+        }
+    }
+
     /// Transforms a logical point into screen space using the active zoom and translation.
     #[must_use]
-    pub fn transform_point(&self, point: Point) -> Point {
+    pub fn transform_point(&self, point: regviz_layout::Point) -> Point {
         Point::new(
             point.x * self.zoom + self.translation.x,
             point.y * self.zoom + self.translation.y,
@@ -30,3 +108,53 @@ pub trait Drawable {
     where
         R: GeometryRenderer;
 }
+
+/// Converts renderer-agnostic layout values into their `iced` counterparts.
+pub trait ToIced {
+    /// The `iced` type drawn in place of `Self`.
+    type Output;
+
+    /// Returns the `iced` equivalent of `self`.
+    fn to_iced(self) -> Self::Output;
+}
+
+impl ToIced for regviz_layout::Point {
+    type Output = Point;
+
+    fn to_iced(self) -> Point {
+        Point::new(self.x, self.y)
+    }
+}
+
+impl ToIced for regviz_layout::Color {
+    type Output = Color;
+
+    fn to_iced(self) -> Color {
+        Color::from_rgba(self.r, self.g, self.b, self.a)
+    }
+}
+
+/// Converts `iced` values into the renderer-agnostic layout types.
+pub trait ToLayout {
+    /// The layout type equivalent to `Self`.
+    type Output;
+
+    /// Returns the layout equivalent of `self`.
+    fn to_layout(self) -> Self::Output;
+}
+
+impl ToLayout for Point {
+    type Output = regviz_layout::Point;
+
+    fn to_layout(self) -> regviz_layout::Point {
+        regviz_layout::Point::new(self.x, self.y)
+    }
+}
+
+impl ToLayout for Color {
+    type Output = regviz_layout::Color;
+
+    fn to_layout(self) -> regviz_layout::Color {
+        regviz_layout::Color::from_rgba(self.r, self.g, self.b, self.a)
+    }
+}
@@ -1,19 +1,19 @@
 use iced::{
-    Color, Pixels, Point, Vector,
+    Color, Point, Vector,
     alignment::{Horizontal, Vertical},
-    widget::canvas::{Frame, Path, Stroke, Text},
+    widget::canvas::{Frame, LineDash, Path, Stroke, Text},
 };
 use iced_graphics::geometry::Renderer;
-use regviz_core::core::automaton::StateId;
+use regviz_layout::{
+    EdgeCurve, LABEL_DISTANCE, PositionedEdge, curve_control_point, quadratic_bezier_point,
+    self_loop,
+};
 
 use crate::app::theme::AppTheme;
 
-use super::{DrawContext, Drawable};
+use super::{Detail, DrawContext, Drawable, ToIced, ToLayout};
 use crate::app::APP_FONT;
 
-/// Distance between the edge segment and its label in logical units.
-/// With centered text alignment, this needs to be smaller than before.
-const LABEL_DISTANCE: f32 = 13.0;
 /// Length of each arrow head side.
 const ARROW_HEAD_BASE_LENGTH: f32 = 10.0;
 /// Half-width of the arrow head at its base.
@@ -32,131 +32,14 @@ const EDGE_LABEL_MAX_SIZE: f32 = 42.0;
 const INACTIVE_EDGE_STROKE_WIDTH: f32 = 1.3;
 const ACTIVE_EDGE_STROKE_WIDTH: f32 = 2.4;
 const ACTIVE_ARROW_ALPHA: f32 = 0.35;
-
-/// Edge curvature style for different types of transitions.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum EdgeCurve {
-    /// Straight line between nodes.
-    Straight,
-    /// Curved downward.
-    CurveDown,
-    /// Curved upward (for star closure loop-back: inner_accept → inner_start).
-    CurveUp,
-    /// Loop back to the same node
-    Loop,
-}
-
-/// Renderable description of a transition between two states.
-#[derive(Debug, Clone)]
-pub struct GraphEdge {
-    /// Origin node identifier.
-    pub from: StateId,
-    /// Destination node identifier.
-    pub to: StateId,
-    /// Label displayed next to the edge.
-    pub label: String,
-    /// Curvature style for this edge.
-    pub curve: EdgeCurve,
-    /// Whether this edge was traversed in the current simulation step.
-    pub is_active: bool,
-}
-
-impl GraphEdge {
-    /// Creates a new [`GraphEdge`] with a straight line.
-    #[must_use]
-    pub fn new(from: StateId, to: StateId, label: String) -> Self {
-        Self {
-            from,
-            to,
-            label,
-            curve: EdgeCurve::Straight,
-            is_active: false,
-        }
-    }
-
-    /// Creates a new [`GraphEdge`] with a specified curve style.
-    #[must_use]
-    pub fn with_curve(from: StateId, to: StateId, label: String, curve: EdgeCurve) -> Self {
-        Self {
-            from,
-            to,
-            label,
-            curve,
-            is_active: false,
-        }
-    }
-
-    /// Marks the edge as active (or inactive) for the current simulation step.
-    #[must_use]
-    pub fn with_active(mut self, is_active: bool) -> Self {
-        self.is_active = is_active;
-        self
-    }
-}
-
-/// [`GraphEdge`] enriched with layout information.
-#[derive(Debug, Clone)]
-pub struct PositionedEdge {
-    /// Edge metadata.
-    pub data: GraphEdge,
-    /// Start position.
-    pub from: Point,
-    /// End position.
-    pub to: Point,
-    /// Suggested position for the label.
-    pub label_position: Point,
-    /// Radius of the source node (used to adjust edge start point).
-    pub from_radius: f32,
-    /// Radius of the destination node (used to adjust edge end point).
-    pub to_radius: f32,
-}
-
-impl PositionedEdge {
-    /// Creates a new positioned edge from metadata and coordinates, keeping the label legible
-    /// by offsetting it away from the rendered segment.
-    ///
-    /// Note: The `from` and `to` points represent node centers. The actual edge will be
-    /// drawn from the edge of the source node to the edge of the destination node.
-    #[must_use]
-    pub fn new(data: GraphEdge, from: Point, to: Point) -> Self {
-        let label_position = compute_label_anchor(from, to);
-        Self {
-            data,
-            from,
-            to,
-            label_position,
-            from_radius: 32.0, // Default node radius
-            to_radius: 32.0,   // Default node radius
-        }
-    }
-
-    /// Creates a new positioned edge with explicit node radii.
-    ///
-    /// This is useful when nodes have different radii (e.g., in different visualization modes).
-    ///
-    /// # Note
-    ///
-    /// This function is currently unused but kept for potential future use.
-    #[must_use]
-    #[allow(dead_code)]
-    pub fn with_radii(
-        data: GraphEdge,
-        from: Point,
-        to: Point,
-        from_radius: f32,
-        to_radius: f32,
-    ) -> Self {
-        let label_position = compute_label_anchor(from, to);
-        Self {
-            data,
-            from,
-            to,
-            label_position,
-            from_radius,
-            to_radius,
-        }
-    }
-}
+/// Opacity of edges taken by the previewed next step.
+const GHOST_EDGE_ALPHA: f32 = 0.7;
+/// Length of the dashes marching along taken transitions, in screen pixels.
+const MARCHING_DASH: f32 = 10.0;
+/// Length of the gaps between marching dashes, in screen pixels.
+const MARCHING_GAP: f32 = 6.0;
+/// Speed of the marching dashes, in screen pixels per second.
+const MARCHING_SPEED: f32 = 32.0;
 
 impl Drawable for PositionedEdge {
     /// Draws a directed edge from one state to another with an arrow head and label.
@@ -164,6 +47,7 @@ impl Drawable for PositionedEdge {
     /// This function handles both straight and curved edges:
     /// - Straight edges: Direct line between two nodes
     /// - Curved edges: Quadratic Bezier curve for star closure epsilon transitions
+    /// - Routed edges: Polyline through the bend points chosen by the layout
     ///
     /// The function performs these tasks:
     /// 1. Transforms coordinates from logical to screen space
@@ -190,19 +74,38 @@ impl Drawable for PositionedEdge {
 
         let stroke_color = if self.data.is_active {
             theme.graph_edge_active()
+        } else if self.data.is_ghost {
+            AppTheme::with_alpha(theme.graph_node_active(), GHOST_EDGE_ALPHA)
+        } else if let Some(overlay) = self.data.overlay {
+            overlay.to_iced()
         } else {
             theme.graph_edge_default()
         };
 
-        let stroke_width = if self.data.is_active {
+        let emphasized = self.data.is_active || self.data.is_ghost || self.data.overlay.is_some();
+        let stroke_width = ctx.stroke_width(if emphasized {
             ACTIVE_EDGE_STROKE_WIDTH
         } else {
             INACTIVE_EDGE_STROKE_WIDTH
-        };
+        });
+
+        if !self.route.is_empty() {
+            draw_routed_edge(
+                self,
+                frame,
+                from_radius,
+                to_radius,
+                ctx,
+                stroke_color,
+                stroke_width,
+            );
+            return;
+        }
 
         match self.data.curve {
             EdgeCurve::Straight => {
-                self.draw_straight_edge(
+                draw_straight_edge(
+                    self,
                     frame,
                     from_center,
                     to_center,
@@ -215,7 +118,8 @@ impl Drawable for PositionedEdge {
                 );
             }
             EdgeCurve::CurveDown => {
-                self.draw_curved_edge(
+                draw_curved_edge(
+                    self,
                     frame,
                     from_center,
                     to_center,
@@ -228,7 +132,8 @@ impl Drawable for PositionedEdge {
                 );
             }
             EdgeCurve::CurveUp => {
-                self.draw_curved_edge(
+                draw_curved_edge(
+                    self,
                     frame,
                     from_center,
                     to_center,
@@ -241,7 +146,8 @@ impl Drawable for PositionedEdge {
                 );
             }
             EdgeCurve::Loop => {
-                self.draw_self_loop(
+                draw_self_loop(
+                    self,
                     frame,
                     from_center,
                     from_radius,
@@ -254,319 +160,215 @@ impl Drawable for PositionedEdge {
     }
 }
 
-impl PositionedEdge {
-    /// Draws a straight edge between two nodes.
-    ///
-    /// # Arguments
-    /// - `frame`: Canvas frame to draw on
-    /// - `from_center`: Center of source node (screen coordinates)
-    /// - `to_center`: Center of destination node (screen coordinates)
-    /// - `unit`: Unit direction vector from source to destination
-    /// - `from_radius`: Radius of source node (scaled)
-    /// - `to_radius`: Radius of destination node (scaled)
-    /// - `ctx`: Drawing context with zoom/pan information
-    #[allow(clippy::too_many_arguments)]
-    fn draw_straight_edge<R: Renderer>(
-        &self,
-        frame: &mut Frame<R>,
-        from_center: Point,
-        to_center: Point,
-        unit: Vector,
-        from_radius: f32,
-        to_radius: f32,
-        ctx: &DrawContext,
-        stroke_color: Color,
-        stroke_width: f32,
-    ) {
-        // Adjust the start point: move from node center outward by the node radius
-        let from = Point::new(
-            from_center.x + unit.x * from_radius,
-            from_center.y + unit.y * from_radius,
-        );
-
-        // Adjust the end point: move from node center inward by the node radius
-        let to = Point::new(
-            to_center.x - unit.x * to_radius,
-            to_center.y - unit.y * to_radius,
-        );
-
-        // Draw the main line connecting the two states
-        let line = Path::line(from, to);
-        frame.stroke(
-            &line,
-            Stroke::default()
-                .with_width(stroke_width)
-                .with_color(stroke_color),
-        );
-
-        // Draw arrow head at destination
-        self.draw_arrow_head(frame, to, unit, stroke_color, ctx);
-
-        // Draw label
-        self.draw_label(frame, ctx, stroke_color);
-    }
-
-    /// Draws a self-loop edge for transitions that start and end at the same node.
-    ///
-    /// The loop is drawn as a circular arc above the node with an arrow head.
-    /// The label is positioned above the loop.
-    ///
-    /// # Arguments
-    /// - `frame`: Canvas frame to draw on
-    /// - `center`: Center of the node (screen coordinates)
-    /// - `radius`: Radius of the node (scaled)
-    /// - `ctx`: Drawing context with zoom/pan information
-    fn draw_self_loop<R: Renderer>(
-        &self,
-        frame: &mut Frame<R>,
-        center: Point,
-        radius: f32,
-        ctx: &DrawContext,
-        color: Color,
-        stroke_width: f32,
-    ) {
-        // Draw a circular arc above the node
-        let loop_radius = radius * 0.7;
-        let offset = Vector::new(0.0, -radius * 1.1);
-        let loop_center = Point::new(center.x + offset.x, center.y + offset.y);
-
-        let circle = Path::circle(loop_center, loop_radius);
-
-        frame.stroke(
-            &circle,
-            Stroke::default().with_width(stroke_width).with_color(color),
-        );
-
-        // Draw arrow head at end of arc
-        let tip_angle = std::f32::consts::PI * 1.5; // Top of the circle
-        let tip = Point::new(
-            // Shift right slightly to position arrow head's center to the top of the loop
-            loop_center.x
-                + loop_radius * tip_angle.cos()
-                + ctx.zoom.clamp(ARROW_HEAD_MIN_SCALE, ARROW_HEAD_MAX_SCALE)
-                    * 0.5
-                    * ARROW_HEAD_BASE_LENGTH,
-            loop_center.y + loop_radius * tip_angle.sin(),
-        );
-        let direction = Vector::new(-tip_angle.sin(), tip_angle.cos());
-        self.draw_arrow_head(frame, tip, direction, color, ctx);
-
-        // Draw label above the loop
-        let label_pos = Point::new(
-            loop_center.x,
-            loop_center.y - loop_radius - LABEL_DISTANCE * ctx.zoom,
-        );
-        let font_size = Pixels::from(
-            (EDGE_LABEL_BASE_SIZE * ctx.zoom).clamp(EDGE_LABEL_MIN_SIZE, EDGE_LABEL_MAX_SIZE),
-        );
-        if !self.data.label.is_empty() {
-            frame.fill_text(Text {
-                content: self.data.label.clone(),
-                position: label_pos,
-                color,
-                font: APP_FONT,
-                align_x: Horizontal::Center.into(),
-                align_y: Vertical::Center,
-                size: font_size,
-                ..Text::default()
-            });
-        }
-    }
-
-    /// Draws a curved edge using a quadratic Bezier curve.
-    ///
-    /// For star closures, we need curved arrows:
-    /// - `curve_down = true`: Curve wraps below (start → inner_start bypass)
-    /// - `curve_down = false`: Curve wraps above (inner_accept → inner_start loop)
-    ///
-    /// # Arguments
-    /// - `frame`: Canvas frame to draw on
-    /// - `from_center`: Center of source node (screen coordinates)
-    /// - `to_center`: Center of destination node (screen coordinates)
-    /// - `from_radius`: Radius of source node (scaled)
-    /// - `to_radius`: Radius of destination node (scaled)
-    /// - `curve_down`: If true, curve bends downward; if false, upward
-    /// - `ctx`: Drawing context with zoom/pan information
-    #[allow(clippy::too_many_arguments)]
-    fn draw_curved_edge<R: Renderer>(
-        &self,
-        frame: &mut Frame<R>,
-        from_center: Point,
-        to_center: Point,
-        from_radius: f32,
-        to_radius: f32,
-        curve_down: bool,
-        ctx: &DrawContext,
-        stroke_color: Color,
-        stroke_width: f32,
-    ) {
-        // Calculate the perpendicular offset for the control point
-        let direction = Vector::new(to_center.x - from_center.x, to_center.y - from_center.y);
-        let length = (direction.x * direction.x + direction.y * direction.y).sqrt();
+/// Draws a straight edge between two nodes.
+///
+/// # Arguments
+/// - `frame`: Canvas frame to draw on
+/// - `from_center`: Center of source node (screen coordinates)
+/// - `to_center`: Center of destination node (screen coordinates)
+/// - `unit`: Unit direction vector from source to destination
+/// - `from_radius`: Radius of source node (scaled)
+/// - `to_radius`: Radius of destination node (scaled)
+/// - `ctx`: Drawing context with zoom/pan information
+#[allow(clippy::too_many_arguments)]
+fn draw_straight_edge<R: Renderer>(
+    edge: &PositionedEdge,
+    frame: &mut Frame<R>,
+    from_center: Point,
+    to_center: Point,
+    unit: Vector,
+    from_radius: f32,
+    to_radius: f32,
+    ctx: &DrawContext,
+    stroke_color: Color,
+    stroke_width: f32,
+) {
+    // Adjust the start point: move from node center outward by the node radius
+    let from = Point::new(
+        from_center.x + unit.x * from_radius,
+        from_center.y + unit.y * from_radius,
+    );
+
+    // Adjust the end point: move from node center inward by the node radius
+    let to = Point::new(
+        to_center.x - unit.x * to_radius,
+        to_center.y - unit.y * to_radius,
+    );
+
+    // Draw the main line connecting the two states
+    let line = Path::line(from, to);
+    stroke_edge(frame, &line, edge, ctx, stroke_color, stroke_width);
+
+    // Draw arrow head at destination
+    draw_arrow_head(frame, to, unit, stroke_color, ctx);
+
+    // Draw label
+    draw_label(edge, frame, ctx, stroke_color);
+}
 
-        if length <= f32::EPSILON {
-            return;
+/// Draws an edge routed around obstacles as a polyline through its bend points.
+///
+/// # Arguments
+/// - `frame`: Canvas frame to draw on
+/// - `from_radius`: Radius of source node (scaled)
+/// - `to_radius`: Radius of destination node (scaled)
+/// - `ctx`: Drawing context with zoom/pan information
+fn draw_routed_edge<R: Renderer>(
+    edge: &PositionedEdge,
+    frame: &mut Frame<R>,
+    from_radius: f32,
+    to_radius: f32,
+    ctx: &DrawContext,
+    stroke_color: Color,
+    stroke_width: f32,
+) {
+    let mut points: Vec<Point> = edge
+        .path()
+        .into_iter()
+        .map(|point| ctx.transform_point(point))
+        .collect();
+    let last = points.len() - 1;
+    let (Some(start_unit), Some(end_unit)) = (
+        unit_between(points[0], points[1]),
+        unit_between(points[last - 1], points[last]),
+    ) else {
+        return;
+    };
+
+    // Start and end on the node borders, along the first and last segments
+    points[0] = Point::new(
+        points[0].x + start_unit.x * from_radius,
+        points[0].y + start_unit.y * from_radius,
+    );
+    points[last] = Point::new(
+        points[last].x - end_unit.x * to_radius,
+        points[last].y - end_unit.y * to_radius,
+    );
+
+    let polyline = Path::new(|builder| {
+        builder.move_to(points[0]);
+        for point in &points[1..] {
+            builder.line_to(*point);
         }
+    });
+    stroke_edge(frame, &polyline, edge, ctx, stroke_color, stroke_width);
 
-        let unit = Vector::new(direction.x / length, direction.y / length);
-
-        // Get perpendicular vector (rotates 90° counterclockwise)
-        // For horizontal edges (left to right), this gives an upward normal
-        let normal = perpendicular(unit);
-
-        // Control point offset: position it perpendicular to the line between nodes.
-        // Reduce the control offset to prevent curves from extending too far outside bounding boxes.
-        // Use a smaller factor to keep curves more contained.
-        let max_curve_height = length * 0.25; // Limit curve height to 25% of distance between nodes
-        // Positive offset moves in the direction of `normal`. For a left-to-right edge, the normal
-        // points downward in screen coordinates, so a positive offset bends the curve downward.
-        let base_control_offset = if curve_down {
-            max_curve_height
-        } else {
-            -max_curve_height
-        };
-
-        // Midpoint between nodes
-        let mid = Point::new(
-            (from_center.x + to_center.x) * 0.5,
-            (from_center.y + to_center.y) * 0.5,
-        );
-
-        // Control point offset perpendicular to the edge
-        let control = Point::new(
-            mid.x + normal.x * base_control_offset,
-            mid.y + normal.y * base_control_offset,
-        );
-
-        // Find where the curve intersects the node boundaries
-        // Use the tangent at t=0 for the start point and t=1 for the end point
-        let start_tangent = quadratic_bezier_tangent(from_center, control, to_center, 0.0);
-        let start_tangent_len =
-            (start_tangent.x * start_tangent.x + start_tangent.y * start_tangent.y).sqrt();
-        let start_tangent_unit = if start_tangent_len > f32::EPSILON {
-            Vector::new(
-                start_tangent.x / start_tangent_len,
-                start_tangent.y / start_tangent_len,
-            )
-        } else {
-            unit
-        };
-
-        let end_tangent = quadratic_bezier_tangent(from_center, control, to_center, 1.0);
-        let end_tangent_len =
-            (end_tangent.x * end_tangent.x + end_tangent.y * end_tangent.y).sqrt();
-        let end_tangent_unit = if end_tangent_len > f32::EPSILON {
-            Vector::new(
-                end_tangent.x / end_tangent_len,
-                end_tangent.y / end_tangent_len,
-            )
-        } else {
-            Vector::new(-unit.x, -unit.y)
-        };
+    draw_arrow_head(frame, points[last], end_unit, stroke_color, ctx);
+    draw_label(edge, frame, ctx, stroke_color);
+}
 
-        // Start point on the edge of the source node
-        let start = Point::new(
-            from_center.x + start_tangent_unit.x * from_radius,
-            from_center.y + start_tangent_unit.y * from_radius,
-        );
-
-        // End point on the edge of the destination node (where arrow tip will be)
-        let end = Point::new(
-            to_center.x - end_tangent_unit.x * to_radius,
-            to_center.y - end_tangent_unit.y * to_radius,
-        );
-
-        // Draw the quadratic Bezier curve
-        let curve_path = Path::new(|builder| {
-            builder.move_to(start);
-            builder.quadratic_curve_to(control, end);
-        });
-        frame.stroke(
-            &curve_path,
-            Stroke::default()
-                .with_width(stroke_width)
-                .with_color(stroke_color),
-        );
-
-        // Draw arrow head at the end point with the correct tangent direction
-        self.draw_arrow_head(frame, end, end_tangent_unit, stroke_color, ctx);
-
-        // Draw label on the curve - calculate position based on curve midpoint
-        self.draw_curved_label(
-            frame,
-            from_center,
-            control,
-            to_center,
-            curve_down,
-            ctx,
-            stroke_color,
-        );
+/// Strokes the line of `edge`, with its dashes marching towards the target
+/// while the animation clock runs and the simulation took the edge.
+///
+/// # Arguments
+/// - `frame`: Canvas frame to draw on
+/// - `path`: The line, curve or loop of the edge
+/// - `ctx`: Drawing context with the animation clock
+fn stroke_edge<R: Renderer>(
+    frame: &mut Frame<R>,
+    path: &Path,
+    edge: &PositionedEdge,
+    ctx: &DrawContext,
+    color: Color,
+    width: f32,
+) {
+    let stroke = Stroke::default().with_width(width).with_color(color);
+    let marching = ctx
+        .time
+        .filter(|_| edge.data.is_active && ctx.detail() > Detail::Overview)
+        .map(marching_dashes);
+    match &marching {
+        Some(segments) => frame.stroke(
+            path,
+            Stroke {
+                line_dash: LineDash {
+                    segments,
+                    offset: 0,
+                },
+                ..stroke
+            },
+        ),
+        None => frame.stroke(path, stroke),
     }
+}
 
-    /// Draws an arrow head at the specified point, oriented in the given direction.
-    ///
-    /// # Arguments
-    /// - `frame`: Canvas frame to draw on
-    /// - `tip`: Point where the arrow tip should be
-    /// - `direction`: Unit vector indicating the direction the arrow points
-    fn draw_arrow_head<R: Renderer>(
-        &self,
-        frame: &mut Frame<R>,
-        tip: Point,
-        direction: Vector,
-        color: Color,
-        ctx: &DrawContext,
-    ) {
-        let normal = perpendicular(direction);
-        let scale = ctx.zoom.clamp(ARROW_HEAD_MIN_SCALE, ARROW_HEAD_MAX_SCALE);
-        let arrow_length = ARROW_HEAD_BASE_LENGTH * scale;
-        let arrow_half_width = ARROW_HEAD_BASE_HALF_WIDTH * scale;
-
-        // Left wing: move back along the direction, then offset perpendicular
-        let left = Point::new(
-            tip.x - direction.x * arrow_length + normal.x * arrow_half_width,
-            tip.y - direction.y * arrow_length + normal.y * arrow_half_width,
-        );
-
-        // Right wing: move back along the direction, then offset opposite perpendicular
-        let right = Point::new(
-            tip.x - direction.x * arrow_length - normal.x * arrow_half_width,
-            tip.y - direction.y * arrow_length - normal.y * arrow_half_width,
-        );
-
-        // Create a filled triangle for the arrow head
-        let arrow_head = Path::new(|builder| {
-            builder.move_to(tip);
-            builder.line_to(left);
-            builder.line_to(right);
-            builder.close();
-        });
-        frame.fill(
-            &arrow_head,
-            Color::from_rgba(color.r, color.g, color.b, ACTIVE_ARROW_ALPHA),
-        );
-        frame.stroke(
-            &arrow_head,
-            Stroke::default().with_width(1.0).with_color(color),
-        );
+/// Returns the dash pattern of a marching edge once its dashes moved for `time` seconds.
+///
+/// Renderers disagree on what a dash offset measures, so the motion is baked
+/// into the pattern instead: the dash or gap under the start of the line is
+/// split in two, one part at either end, which keeps the pattern periodic.
+fn marching_dashes(time: f32) -> [f32; 4] {
+    let period = MARCHING_DASH + MARCHING_GAP;
+    // How far into the pattern the start of the line falls
+    let phase = (-time * MARCHING_SPEED).rem_euclid(period);
+    if phase < MARCHING_DASH {
+        [MARCHING_DASH - phase, MARCHING_GAP, phase, 0.0]
+    } else {
+        [0.0, period - phase, MARCHING_DASH, phase - MARCHING_DASH]
     }
+}
 
-    /// Draws the edge label at the pre-calculated label position.
-    ///
-    /// # Arguments
-    /// - `frame`: Canvas frame to draw on
-    /// - `ctx`: Drawing context with zoom/pan information
-    fn draw_label<R: Renderer>(&self, frame: &mut Frame<R>, ctx: &DrawContext, color: Color) {
-        if self.data.label.is_empty() {
-            return;
-        }
+/// Returns the unit vector pointing from `from` to `to`, or `None` when they coincide.
+fn unit_between(from: Point, to: Point) -> Option<Vector> {
+    let (dx, dy) = (to.x - from.x, to.y - from.y);
+    let length = (dx * dx + dy * dy).sqrt();
+    (length > f32::EPSILON).then(|| Vector::new(dx / length, dy / length))
+}
 
-        let label_pos = ctx.transform_point(self.label_position);
-        let font_size = Pixels::from(
-            (EDGE_LABEL_BASE_SIZE * ctx.zoom).clamp(EDGE_LABEL_MIN_SIZE, EDGE_LABEL_MAX_SIZE),
-        );
+/// Draws a self-loop edge for transitions that start and end at the same node.
+///
+/// The loop is drawn as a circular arc above the node with an arrow head.
+/// The label is positioned above the loop.
+///
+/// # Arguments
+/// - `frame`: Canvas frame to draw on
+/// - `center`: Center of the node (screen coordinates)
+/// - `radius`: Radius of the node (scaled)
+/// - `ctx`: Drawing context with zoom/pan information
+fn draw_self_loop<R: Renderer>(
+    edge: &PositionedEdge,
+    frame: &mut Frame<R>,
+    center: Point,
+    radius: f32,
+    ctx: &DrawContext,
+    color: Color,
+    stroke_width: f32,
+) {
+    // Draw a circular arc above the node
+    let (loop_center, loop_radius) = self_loop(center.to_layout(), radius, edge.data.bend);
+    let loop_center = loop_center.to_iced();
+
+    let circle = Path::circle(loop_center, loop_radius);
+
+    stroke_edge(frame, &circle, edge, ctx, color, stroke_width);
+
+    // Draw arrow head at end of arc
+    let tip_angle = std::f32::consts::PI * 1.5; // Top of the circle
+    let tip = Point::new(
+        // Shift right slightly to position arrow head's center to the top of the loop
+        loop_center.x
+            + loop_radius * tip_angle.cos()
+            + ctx.zoom.clamp(ARROW_HEAD_MIN_SCALE, ARROW_HEAD_MAX_SCALE)
+                * 0.5
+                * ARROW_HEAD_BASE_LENGTH,
+        loop_center.y + loop_radius * tip_angle.sin(),
+    );
+    let direction = Vector::new(-tip_angle.sin(), tip_angle.cos());
+    draw_arrow_head(frame, tip, direction, color, ctx);
+
+    // Draw label above the loop
+    let label_pos = Point::new(
+        loop_center.x,
+        loop_center.y - loop_radius - LABEL_DISTANCE * ctx.zoom,
+    );
+    let font_size = ctx.text_size(
+        EDGE_LABEL_BASE_SIZE,
+        EDGE_LABEL_MIN_SIZE,
+        EDGE_LABEL_MAX_SIZE,
+    );
+    if ctx.detail() == Detail::Full && !edge.data.label.is_empty() {
         frame.fill_text(Text {
-            content: self.data.label.clone(),
+            content: edge.data.label.clone(),
             position: label_pos,
             color,
             font: APP_FONT,
@@ -576,116 +378,266 @@ impl PositionedEdge {
             ..Text::default()
         });
     }
+}
 
-    /// Draws the label for a curved edge at the curve's midpoint.
-    ///
-    /// The label is positioned above the curve for upward curves and below for downward curves.
-    ///
-    /// # Arguments
-    /// - `frame`: Canvas frame to draw on
-    /// - `p0`: Start point of the curve (screen coordinates)
-    /// - `p1`: Control point of the curve (screen coordinates)
-    /// - `p2`: End point of the curve (screen coordinates)
-    /// - `curve_down`: Whether the curve bends downward
-    /// - `ctx`: Drawing context with zoom/pan information (used for zoom scaling)
-    #[allow(clippy::too_many_arguments)]
-    fn draw_curved_label<R: Renderer>(
-        &self,
-        frame: &mut Frame<R>,
-        p0: Point,
-        p1: Point,
-        p2: Point,
-        curve_down: bool,
-        ctx: &DrawContext,
-        color: Color,
-    ) {
-        if self.data.label.is_empty() {
-            return;
-        }
-
-        // Calculate the point on the curve at t=0.5 (midpoint)
-        // All points are already in screen coordinates
-        let mid_point = quadratic_bezier_point(p0, p1, p2, 0.5);
-
-        // Get the tangent at the midpoint to determine the perpendicular direction
-        let tangent = quadratic_bezier_tangent(p0, p1, p2, 0.5);
-        let tangent_len = (tangent.x * tangent.x + tangent.y * tangent.y).sqrt();
-
-        if tangent_len <= f32::EPSILON {
-            // Fallback to regular label positioning if tangent is degenerate
-            self.draw_label(frame, ctx, color);
-            return;
-        }
-
-        let tangent_unit = Vector::new(tangent.x / tangent_len, tangent.y / tangent_len);
-        let normal = perpendicular(tangent_unit);
-
-        // Position label on the outer (convex) side of the curve
-        let scaled_label_distance = LABEL_DISTANCE * ctx.zoom;
-        let label_offset = if curve_down {
-            -scaled_label_distance // Control point is down, label goes up (negative y)
-        } else {
-            scaled_label_distance // Control point is up, label goes down (positive y)
-        };
+/// Draws a curved edge using a quadratic Bezier curve.
+///
+/// For star closures, we need curved arrows:
+/// - `curve_down = true`: Curve wraps below (start → inner_start bypass)
+/// - `curve_down = false`: Curve wraps above (inner_accept → inner_start loop)
+///
+/// # Arguments
+/// - `frame`: Canvas frame to draw on
+/// - `from_center`: Center of source node (screen coordinates)
+/// - `to_center`: Center of destination node (screen coordinates)
+/// - `from_radius`: Radius of source node (scaled)
+/// - `to_radius`: Radius of destination node (scaled)
+/// - `curve_down`: If true, curve bends downward; if false, upward
+/// - `ctx`: Drawing context with zoom/pan information
+#[allow(clippy::too_many_arguments)]
+fn draw_curved_edge<R: Renderer>(
+    edge: &PositionedEdge,
+    frame: &mut Frame<R>,
+    from_center: Point,
+    to_center: Point,
+    from_radius: f32,
+    to_radius: f32,
+    curve_down: bool,
+    ctx: &DrawContext,
+    stroke_color: Color,
+    stroke_width: f32,
+) {
+    let Some(control) = curve_control_point(
+        from_center.to_layout(),
+        to_center.to_layout(),
+        curve_down,
+        edge.data.bend,
+    )
+    .map(ToIced::to_iced) else {
+        return;
+    };
+    let direction = Vector::new(to_center.x - from_center.x, to_center.y - from_center.y);
+    let length = (direction.x * direction.x + direction.y * direction.y).sqrt();
+    let unit = Vector::new(direction.x / length, direction.y / length);
+
+    // Find where the curve intersects the node boundaries
+    // Use the tangent at t=0 for the start point and t=1 for the end point
+    let start_tangent = quadratic_bezier_tangent(from_center, control, to_center, 0.0);
+    let start_tangent_len =
+        (start_tangent.x * start_tangent.x + start_tangent.y * start_tangent.y).sqrt();
+    let start_tangent_unit = if start_tangent_len > f32::EPSILON {
+        Vector::new(
+            start_tangent.x / start_tangent_len,
+            start_tangent.y / start_tangent_len,
+        )
+    } else {
+        unit
+    };
+
+    let end_tangent = quadratic_bezier_tangent(from_center, control, to_center, 1.0);
+    let end_tangent_len = (end_tangent.x * end_tangent.x + end_tangent.y * end_tangent.y).sqrt();
+    let end_tangent_unit = if end_tangent_len > f32::EPSILON {
+        Vector::new(
+            end_tangent.x / end_tangent_len,
+            end_tangent.y / end_tangent_len,
+        )
+    } else {
+        Vector::new(-unit.x, -unit.y)
+    };
+
+    // Start point on the edge of the source node
+    let start = Point::new(
+        from_center.x + start_tangent_unit.x * from_radius,
+        from_center.y + start_tangent_unit.y * from_radius,
+    );
+
+    // End point on the edge of the destination node (where arrow tip will be)
+    let end = Point::new(
+        to_center.x - end_tangent_unit.x * to_radius,
+        to_center.y - end_tangent_unit.y * to_radius,
+    );
+
+    // Draw the quadratic Bezier curve
+    let curve_path = Path::new(|builder| {
+        builder.move_to(start);
+        builder.quadratic_curve_to(control, end);
+    });
+    stroke_edge(frame, &curve_path, edge, ctx, stroke_color, stroke_width);
+
+    // Draw arrow head at the end point with the correct tangent direction
+    draw_arrow_head(frame, end, end_tangent_unit, stroke_color, ctx);
+
+    // Draw label on the curve - calculate position based on curve midpoint
+    draw_curved_label(
+        edge,
+        frame,
+        from_center,
+        control,
+        to_center,
+        curve_down,
+        ctx,
+        stroke_color,
+    );
+}
 
-        // Apply the offset in the normal direction
-        // No need to transform - we're already in screen coordinates
-        let label_position = Point::new(
-            mid_point.x + normal.x * label_offset,
-            mid_point.y + normal.y * label_offset,
-        );
+/// Draws an arrow head at the specified point, oriented in the given direction.
+///
+/// # Arguments
+/// - `frame`: Canvas frame to draw on
+/// - `tip`: Point where the arrow tip should be
+/// - `direction`: Unit vector indicating the direction the arrow points
+fn draw_arrow_head<R: Renderer>(
+    frame: &mut Frame<R>,
+    tip: Point,
+    direction: Vector,
+    color: Color,
+    ctx: &DrawContext,
+) {
+    if ctx.detail() == Detail::Overview {
+        return;
+    }
+    let normal = perpendicular(direction);
+    let scale = ctx.zoom.clamp(ARROW_HEAD_MIN_SCALE, ARROW_HEAD_MAX_SCALE);
+    let arrow_length = ARROW_HEAD_BASE_LENGTH * scale;
+    let arrow_half_width = ARROW_HEAD_BASE_HALF_WIDTH * scale;
+
+    // Left wing: move back along the direction, then offset perpendicular
+    let left = Point::new(
+        tip.x - direction.x * arrow_length + normal.x * arrow_half_width,
+        tip.y - direction.y * arrow_length + normal.y * arrow_half_width,
+    );
+
+    // Right wing: move back along the direction, then offset opposite perpendicular
+    let right = Point::new(
+        tip.x - direction.x * arrow_length - normal.x * arrow_half_width,
+        tip.y - direction.y * arrow_length - normal.y * arrow_half_width,
+    );
+
+    // Create a filled triangle for the arrow head
+    let arrow_head = Path::new(|builder| {
+        builder.move_to(tip);
+        builder.line_to(left);
+        builder.line_to(right);
+        builder.close();
+    });
+    frame.fill(
+        &arrow_head,
+        Color::from_rgba(color.r, color.g, color.b, ACTIVE_ARROW_ALPHA),
+    );
+    frame.stroke(
+        &arrow_head,
+        Stroke::default()
+            .with_width(ctx.stroke_width(1.0))
+            .with_color(color),
+    );
+}
 
-        frame.fill_text(Text {
-            content: self.data.label.clone(),
-            position: label_position,
-            color,
-            font: APP_FONT,
-            align_x: Horizontal::Center.into(),
-            align_y: Vertical::Center,
-            size: Pixels::from(
-                (EDGE_LABEL_BASE_SIZE * ctx.zoom).clamp(EDGE_LABEL_MIN_SIZE, EDGE_LABEL_MAX_SIZE),
-            ),
-            ..Text::default()
-        });
+/// Draws the edge label at the pre-calculated label position.
+///
+/// # Arguments
+/// - `frame`: Canvas frame to draw on
+/// - `ctx`: Drawing context with zoom/pan information
+fn draw_label<R: Renderer>(
+    edge: &PositionedEdge,
+    frame: &mut Frame<R>,
+    ctx: &DrawContext,
+    color: Color,
+) {
+    if ctx.detail() < Detail::Full || edge.data.label.is_empty() {
+        return;
     }
+
+    let label_pos = ctx.transform_point(edge.label_position);
+    let font_size = ctx.text_size(
+        EDGE_LABEL_BASE_SIZE,
+        EDGE_LABEL_MIN_SIZE,
+        EDGE_LABEL_MAX_SIZE,
+    );
+    frame.fill_text(Text {
+        content: edge.data.label.clone(),
+        position: label_pos,
+        color,
+        font: APP_FONT,
+        align_x: Horizontal::Center.into(),
+        align_y: Vertical::Center,
+        size: font_size,
+        ..Text::default()
+    });
 }
 
-/// Calculates the anchor point for an edge label connecting two points.
+/// Draws the label for a curved edge at the curve's midpoint.
 ///
-/// The label is positioned at the midpoint of the edge, offset perpendicular to the
-/// edge direction. The offset is placed "above" the edge (negative y direction in screen space),
-/// unless the edge points downward, in which case the offset is flipped to keep the label above.
+/// The label is positioned above the curve for upward curves and below for downward curves.
 ///
-/// # Algorithm
-/// 1. Find the midpoint between the two endpoints
-/// 2. Calculate the perpendicular (normal) vector to the edge direction
-/// 3. Offset the midpoint by `LABEL_DISTANCE` in the normal direction
-fn compute_label_anchor(from: Point, to: Point) -> Point {
-    // Calculate the midpoint of the edge
-    let mid = Point::new((from.x + to.x) * 0.5, (from.y + to.y) * 0.5);
-
-    // Calculate the vector from 'from' to 'to'
-    let direction = Vector::new(to.x - from.x, to.y - from.y);
-    let length = (direction.x * direction.x + direction.y * direction.y).sqrt();
-
-    // If the two points are basically the same, just place label above
-    if length <= f32::EPSILON {
-        return Point::new(mid.x, mid.y + LABEL_DISTANCE);
+/// # Arguments
+/// - `frame`: Canvas frame to draw on
+/// - `p0`: Start point of the curve (screen coordinates)
+/// - `p1`: Control point of the curve (screen coordinates)
+/// - `p2`: End point of the curve (screen coordinates)
+/// - `curve_down`: Whether the curve bends downward
+/// - `ctx`: Drawing context with zoom/pan information (used for zoom scaling)
+#[allow(clippy::too_many_arguments)]
+fn draw_curved_label<R: Renderer>(
+    edge: &PositionedEdge,
+    frame: &mut Frame<R>,
+    p0: Point,
+    p1: Point,
+    p2: Point,
+    curve_down: bool,
+    ctx: &DrawContext,
+    color: Color,
+) {
+    if ctx.detail() < Detail::Full || edge.data.label.is_empty() {
+        return;
     }
 
-    // Get the perpendicular (normal) vector - rotated 90° from the edge direction
-    let mut normal = perpendicular(Vector::new(direction.x / length, direction.y / length));
+    // Calculate the point on the curve at t=0.5 (midpoint)
+    // All points are already in screen coordinates
+    let mid_point =
+        quadratic_bezier_point(p0.to_layout(), p1.to_layout(), p2.to_layout(), 0.5).to_iced();
 
-    // Flip the normal if it points downward (positive y) so labels always appear above
-    if normal.y > 0.0 {
-        normal = Vector::new(-normal.x, -normal.y);
+    // Get the tangent at the midpoint to determine the perpendicular direction
+    let tangent = quadratic_bezier_tangent(p0, p1, p2, 0.5);
+    let tangent_len = (tangent.x * tangent.x + tangent.y * tangent.y).sqrt();
+
+    if tangent_len <= f32::EPSILON {
+        // Fallback to regular label positioning if tangent is degenerate
+        draw_label(edge, frame, ctx, color);
+        return;
     }
 
-    // Offset the midpoint by the normal vector scaled by LABEL_DISTANCE
-    Point::new(
-        mid.x + normal.x * LABEL_DISTANCE,
-        mid.y + normal.y * LABEL_DISTANCE,
-    )
+    let tangent_unit = Vector::new(tangent.x / tangent_len, tangent.y / tangent_len);
+    let normal = perpendicular(tangent_unit);
+
+    // Position label on the outer (convex) side of the curve
+    let scaled_label_distance = LABEL_DISTANCE * ctx.zoom;
+    let label_offset = if curve_down {
+        -scaled_label_distance // Control point is down, label goes up (negative y)
+    } else {
+        scaled_label_distance // Control point is up, label goes down (positive y)
+    };
+
+    // Apply the offset in the normal direction
+    // No need to transform - we're already in screen coordinates
+    let label_position = Point::new(
+        mid_point.x + normal.x * label_offset,
+        mid_point.y + normal.y * label_offset,
+    );
+
+    frame.fill_text(Text {
+        content: edge.data.label.clone(),
+        position: label_position,
+        color,
+        font: APP_FONT,
+        align_x: Horizontal::Center.into(),
+        align_y: Vertical::Center,
+        size: ctx.text_size(
+            EDGE_LABEL_BASE_SIZE,
+            EDGE_LABEL_MIN_SIZE,
+            EDGE_LABEL_MAX_SIZE,
+        ),
+        ..Text::default()
+    });
 }
 
 /// Returns a vector perpendicular (at 90°) to the input vector.
@@ -723,26 +675,3 @@ fn quadratic_bezier_tangent(p0: Point, p1: Point, p2: Point, t: f32) -> Vector {
         2.0 * mt * (p1.y - p0.y) + 2.0 * t * (p2.y - p1.y),
     )
 }
-
-/// Computes a point on a quadratic Bezier curve at parameter t.
-///
-/// Uses the quadratic Bezier formula: B(t) = (1-t)²p0 + 2(1-t)t*p1 + t²p2
-///
-/// # Arguments
-/// - `p0`: Start point of the curve
-/// - `p1`: Control point
-/// - `p2`: End point of the curve
-/// - `t`: Parameter in range [0, 1]
-///
-/// # Returns
-/// The point on the curve at parameter t
-fn quadratic_bezier_point(p0: Point, p1: Point, p2: Point, t: f32) -> Point {
-    let mt = 1.0 - t;
-    let mt2 = mt * mt;
-    let t2 = t * t;
-
-    Point::new(
-        mt2 * p0.x + 2.0 * mt * t * p1.x + t2 * p2.x,
-        mt2 * p0.y + 2.0 * mt * t * p1.y + t2 * p2.y,
-    )
-}
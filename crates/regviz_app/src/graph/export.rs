@@ -0,0 +1,430 @@
+use std::fmt::Write;
+
+use iced::alignment::Vertical;
+use iced::font::Family;
+use iced::widget::text::Alignment;
+use iced::{Color, Point, Rectangle, Size, Vector};
+use iced_graphics::cache::{Cached, Group};
+use iced_graphics::core::{Background, Radians, Transformation, image, renderer};
+use iced_graphics::geometry::fill::Rule;
+use iced_graphics::geometry::path::lyon_path::Event;
+use iced_graphics::geometry::path::lyon_path::math::{Angle, Transform};
+use iced_graphics::geometry::{
+    self, Fill, Image, LineCap, LineJoin, Path, Stroke, Style, Svg, Text, frame,
+};
+
+/// Radius of every state in exported figures unless configured otherwise.
+const DEFAULT_NODE_RADIUS: f32 = 32.0;
+
+/// Output pixels per layout unit unless configured otherwise.
+const DEFAULT_SUPERSAMPLING: u32 = 2;
+
+/// Settings for figures exported from the canvas.
+///
+/// Exports are drawn at a zoom of 1 whatever the on-screen zoom, so these are
+/// the only knobs deciding how a figure looks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExportQuality {
+    /// Radius of every state, in layout units.
+    pub node_radius: f32,
+    /// Multiplier applied to outline and transition widths.
+    pub stroke_scale: f32,
+    /// Multiplier applied to state, transition and box labels.
+    pub text_scale: f32,
+    /// Output pixels per layout unit, so the figure stays crisp when rasterized.
+    pub supersampling: u32,
+}
+
+impl Default for ExportQuality {
+    fn default() -> Self {
+        Self {
+            node_radius: DEFAULT_NODE_RADIUS,
+            stroke_scale: 1.0,
+            text_scale: 1.0,
+            supersampling: DEFAULT_SUPERSAMPLING,
+        }
+    }
+}
+
+/// Pixels of a rasterized figure.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbaImage {
+    /// Width in pixels.
+    pub width: u32,
+    /// Height in pixels.
+    pub height: u32,
+    /// Pixels in RGBA order, row by row.
+    pub pixels: Vec<u8>,
+}
+
+/// Geometry renderer writing SVG instead of drawing to the screen.
+///
+/// It lets the canvas draw pipeline run offscreen: a [`geometry::Frame`] of
+/// this renderer records every shape as SVG markup.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SvgRenderer;
+
+/// SVG markup recorded by a frame of the [`SvgRenderer`].
+#[derive(Debug, Clone, Default)]
+pub struct SvgGeometry {
+    body: String,
+}
+
+impl SvgGeometry {
+    /// Wraps the recorded shapes into a standalone SVG document.
+    ///
+    /// # Arguments
+    /// - `size` (`Size`) - Size of the drawing, in layout units.
+    /// - `supersampling` (`u32`) - Output pixels per layout unit.
+    ///
+    /// # Returns
+    /// - `String` - The document, whose pixel size is `size` times `supersampling`.
+    #[must_use]
+    pub fn into_document(self, size: Size, supersampling: u32) -> String {
+        let scale = supersampling.max(1) as f32;
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n{}</svg>\n",
+            number(size.width * scale),
+            number(size.height * scale),
+            number(size.width),
+            number(size.height),
+            self.body
+        )
+    }
+}
+
+impl Cached for SvgGeometry {
+    type Cache = SvgGeometry;
+
+    fn load(cache: &Self::Cache) -> Self {
+        cache.clone()
+    }
+
+    fn cache(self, _group: Group, _previous: Option<Self::Cache>) -> Self::Cache {
+        self
+    }
+}
+
+impl renderer::Renderer for SvgRenderer {
+    fn start_layer(&mut self, _bounds: Rectangle) {}
+
+    fn end_layer(&mut self) {}
+
+    fn start_transformation(&mut self, _transformation: Transformation) {}
+
+    fn end_transformation(&mut self) {}
+
+    fn fill_quad(&mut self, _quad: renderer::Quad, _background: impl Into<Background>) {}
+
+    fn reset(&mut self, _new_bounds: Rectangle) {}
+
+    fn allocate_image(
+        &mut self,
+        _handle: &image::Handle,
+        _callback: impl FnOnce(Result<image::Allocation, image::Error>) + Send + 'static,
+    ) {
+        // Graphs never draw images, so nothing is ever waiting on an allocation.
+    }
+}
+
+impl geometry::Renderer for SvgRenderer {
+    type Geometry = SvgGeometry;
+    type Frame = SvgFrame;
+
+    fn new_frame(&self, bounds: Rectangle) -> Self::Frame {
+        SvgFrame::new(bounds.size())
+    }
+
+    fn draw_geometry(&mut self, _geometry: Self::Geometry) {}
+}
+
+/// Frame of the [`SvgRenderer`], appending an SVG element per drawn shape.
+#[derive(Debug)]
+pub struct SvgFrame {
+    size: Size,
+    transform: Transform,
+    saved: Vec<Transform>,
+    body: String,
+}
+
+impl SvgFrame {
+    fn new(size: Size) -> Self {
+        Self {
+            size,
+            transform: Transform::identity(),
+            saved: Vec::new(),
+            body: String::new(),
+        }
+    }
+
+    /// Returns how much the current transform scales lengths.
+    fn length_scale(&self) -> f32 {
+        self.transform.determinant().abs().sqrt()
+    }
+
+    /// Returns the `d` attribute of `path` under the current transform.
+    fn path_data(&self, path: &Path) -> String {
+        let mut data = String::new();
+        for event in path.transform(&self.transform).raw().iter() {
+            let _ = match event {
+                Event::Begin { at } => write!(data, "M{} {} ", number(at.x), number(at.y)),
+                Event::Line { to, .. } => write!(data, "L{} {} ", number(to.x), number(to.y)),
+                Event::Quadratic { ctrl, to, .. } => write!(
+                    data,
+                    "Q{} {} {} {} ",
+                    number(ctrl.x),
+                    number(ctrl.y),
+                    number(to.x),
+                    number(to.y)
+                ),
+                Event::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => write!(
+                    data,
+                    "C{} {} {} {} {} {} ",
+                    number(ctrl1.x),
+                    number(ctrl1.y),
+                    number(ctrl2.x),
+                    number(ctrl2.y),
+                    number(to.x),
+                    number(to.y)
+                ),
+                Event::End { close: true, .. } => write!(data, "Z "),
+                Event::End { close: false, .. } => Ok(()),
+            };
+        }
+        data.trim_end().to_string()
+    }
+
+    /// Returns the `stroke-*` attributes of `stroke` under the current transform.
+    fn stroke_attributes(&self, stroke: &Stroke<'_>) -> String {
+        let scale = self.length_scale();
+        let mut attributes = format!(
+            "{} stroke-width=\"{}\"",
+            paint("stroke", &stroke.style),
+            number(stroke.width * scale)
+        );
+        let cap = match stroke.line_cap {
+            LineCap::Butt => None,
+            LineCap::Square => Some("square"),
+            LineCap::Round => Some("round"),
+        };
+        let join = match stroke.line_join {
+            LineJoin::Miter => None,
+            LineJoin::Round => Some("round"),
+            LineJoin::Bevel => Some("bevel"),
+        };
+        if let Some(cap) = cap {
+            let _ = write!(attributes, " stroke-linecap=\"{cap}\"");
+        }
+        if let Some(join) = join {
+            let _ = write!(attributes, " stroke-linejoin=\"{join}\"");
+        }
+        let dash = stroke.line_dash;
+        if !dash.segments.is_empty() {
+            let segments: Vec<String> = dash
+                .segments
+                .iter()
+                .map(|segment| number(segment * scale))
+                .collect();
+            let offset: f32 = dash.segments.iter().take(dash.offset).sum();
+            let _ = write!(
+                attributes,
+                " stroke-dasharray=\"{}\" stroke-dashoffset=\"{}\"",
+                segments.join(" "),
+                number(offset * scale)
+            );
+        }
+        attributes
+    }
+
+    /// Appends a `<text>` element drawing `text` with the given paint attributes.
+    fn text_element(&mut self, text: &Text, paint_attributes: &str) {
+        let position = self.transform.transform_point(lyon_point(text.position));
+        let anchor = match text.align_x {
+            Alignment::Center => "middle",
+            Alignment::Right => "end",
+            Alignment::Default | Alignment::Left | Alignment::Justified => "start",
+        };
+        let baseline = match text.align_y {
+            Vertical::Top => "text-before-edge",
+            Vertical::Center => "central",
+            Vertical::Bottom => "text-after-edge",
+        };
+        let family = match text.font.family {
+            Family::Name(name) => name,
+            Family::Serif => "serif",
+            Family::Monospace => "monospace",
+            Family::Cursive => "cursive",
+            Family::Fantasy => "fantasy",
+            Family::SansSerif => "sans-serif",
+        };
+        let _ = writeln!(
+            self.body,
+            "<text x=\"{}\" y=\"{}\" font-family=\"{}\" font-size=\"{}\" text-anchor=\"{anchor}\" dominant-baseline=\"{baseline}\" {paint_attributes}>{}</text>",
+            number(position.x),
+            number(position.y),
+            escape(family),
+            number(text.size.0 * self.length_scale()),
+            escape(&text.content)
+        );
+    }
+}
+
+impl frame::Backend for SvgFrame {
+    type Geometry = SvgGeometry;
+
+    fn width(&self) -> f32 {
+        self.size.width
+    }
+
+    fn height(&self) -> f32 {
+        self.size.height
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn center(&self) -> Point {
+        Point::new(self.size.width / 2.0, self.size.height / 2.0)
+    }
+
+    fn push_transform(&mut self) {
+        self.saved.push(self.transform);
+    }
+
+    fn pop_transform(&mut self) {
+        self.transform = self.saved.pop().unwrap_or_else(Transform::identity);
+    }
+
+    fn translate(&mut self, translation: Vector) {
+        self.transform = Transform::translation(translation.x, translation.y).then(&self.transform);
+    }
+
+    fn rotate(&mut self, angle: impl Into<Radians>) {
+        let angle: Radians = angle.into();
+        self.transform = Transform::rotation(Angle::radians(angle.0)).then(&self.transform);
+    }
+
+    fn scale(&mut self, scale: impl Into<f32>) {
+        let scale = scale.into();
+        self.scale_nonuniform(Vector::new(scale, scale));
+    }
+
+    fn scale_nonuniform(&mut self, scale: impl Into<Vector>) {
+        let scale = scale.into();
+        self.transform = Transform::scale(scale.x, scale.y).then(&self.transform);
+    }
+
+    fn draft(&mut self, clip_bounds: Rectangle) -> Self {
+        // Clipping is not recorded: the graph never draws outside its frame.
+        Self {
+            transform: self.transform,
+            ..Self::new(clip_bounds.size())
+        }
+    }
+
+    fn paste(&mut self, frame: Self) {
+        self.body.push_str(&frame.body);
+    }
+
+    fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<Stroke<'a>>) {
+        let stroke = stroke.into();
+        let _ = writeln!(
+            self.body,
+            "<path d=\"{}\" fill=\"none\" {}/>",
+            self.path_data(path),
+            self.stroke_attributes(&stroke)
+        );
+    }
+
+    fn stroke_rectangle<'a>(&mut self, top_left: Point, size: Size, stroke: impl Into<Stroke<'a>>) {
+        self.stroke(&Path::rectangle(top_left, size), stroke);
+    }
+
+    fn stroke_text<'a>(&mut self, text: impl Into<Text>, stroke: impl Into<Stroke<'a>>) {
+        let attributes = format!("fill=\"none\" {}", self.stroke_attributes(&stroke.into()));
+        self.text_element(&text.into(), &attributes);
+    }
+
+    fn fill(&mut self, path: &Path, fill: impl Into<Fill>) {
+        let fill = fill.into();
+        let rule = match fill.rule {
+            Rule::NonZero => "",
+            Rule::EvenOdd => " fill-rule=\"evenodd\"",
+        };
+        let _ = writeln!(
+            self.body,
+            "<path d=\"{}\" {}{rule}/>",
+            self.path_data(path),
+            paint("fill", &fill.style)
+        );
+    }
+
+    fn fill_text(&mut self, text: impl Into<Text>) {
+        let text = text.into();
+        let attributes = paint("fill", &Style::Solid(text.color));
+        self.text_element(&text, &attributes);
+    }
+
+    fn fill_rectangle(&mut self, top_left: Point, size: Size, fill: impl Into<Fill>) {
+        self.fill(&Path::rectangle(top_left, size), fill);
+    }
+
+    fn draw_image(&mut self, _bounds: Rectangle, _image: impl Into<Image>) {
+        // Graphs are made of shapes and text only.
+    }
+
+    fn draw_svg(&mut self, _bounds: Rectangle, _svg: impl Into<Svg>) {
+        // Graphs are made of shapes and text only.
+    }
+
+    fn into_geometry(self) -> Self::Geometry {
+        SvgGeometry { body: self.body }
+    }
+}
+
+/// Returns the `fill` or `stroke` attributes painting with `style`.
+///
+/// Gradients are painted with their first color, as the graph never uses them.
+fn paint(attribute: &str, style: &Style) -> String {
+    let color = match style {
+        Style::Solid(color) => *color,
+        Style::Gradient(geometry::Gradient::Linear(linear)) => linear
+            .stops
+            .iter()
+            .flatten()
+            .next()
+            .map_or(Color::TRANSPARENT, |stop| stop.color),
+    };
+    let [r, g, b, a] = color.into_rgba8();
+    let mut attributes = format!("{attribute}=\"#{r:02x}{g:02x}{b:02x}\"");
+    if a < u8::MAX {
+        let _ = write!(attributes, " {attribute}-opacity=\"{}\"", number(color.a));
+    }
+    attributes
+}
+
+/// Formats a coordinate with at most two decimals, dropping trailing zeros.
+fn number(value: f32) -> String {
+    let formatted = format!("{value:.2}");
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "" | "-0" => "0".to_string(),
+        _ => trimmed.to_string(),
+    }
+}
+
+/// Escapes the characters XML reserves in text and attribute values.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn lyon_point(point: Point) -> iced_graphics::geometry::path::lyon_path::math::Point {
+    iced_graphics::geometry::path::lyon_path::math::point(point.x, point.y)
+}
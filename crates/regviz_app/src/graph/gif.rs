@@ -0,0 +1,353 @@
+/// Colors in the palette shared by every frame.
+const PALETTE_SIZE: usize = 256;
+
+/// Bits kept per color channel when counting the colors of the frames.
+const BIN_BITS: u32 = 5;
+
+/// Number of color bins counted while building the palette.
+const BIN_COUNT: usize = 1 << (BIN_BITS * 3);
+
+/// Bits of the smallest LZW code, enough for one palette index.
+const MIN_CODE_SIZE: u32 = 8;
+
+/// Widest LZW code GIF allows.
+const MAX_CODE_SIZE: u32 = 12;
+
+/// One image of an animation.
+#[derive(Debug, Clone)]
+pub struct GifFrame {
+    /// Pixels in RGBA order, row by row. Alpha is ignored.
+    pub pixels: Vec<u8>,
+    /// How long the frame is shown, in hundredths of a second.
+    pub delay: u16,
+}
+
+/// Encodes `frames` as an animated GIF that loops forever.
+///
+/// Every frame shares one palette built from the most common colors of all
+/// of them, which suits graphs drawn with a handful of theme colors.
+///
+/// # Arguments
+/// - `width` (`u16`) - Width of every frame, in pixels.
+/// - `height` (`u16`) - Height of every frame, in pixels.
+/// - `frames` (`&[GifFrame]`) - The frames, in the order they are shown.
+///
+/// # Returns
+/// - `Vec<u8>` - The GIF file.
+#[must_use]
+pub fn encode(width: u16, height: u16, frames: &[GifFrame]) -> Vec<u8> {
+    let palette = Palette::from_frames(frames);
+
+    let mut gif = Vec::new();
+    gif.extend_from_slice(b"GIF89a");
+    gif.extend_from_slice(&width.to_le_bytes());
+    gif.extend_from_slice(&height.to_le_bytes());
+    // Global color table of 2^(7 + 1) entries, 8 bits per channel.
+    gif.extend_from_slice(&[0xF7, 0, 0]);
+    for index in 0..PALETTE_SIZE {
+        let [r, g, b] = palette.colors.get(index).copied().unwrap_or_default();
+        gif.extend_from_slice(&[r, g, b]);
+    }
+    // Application extension asking viewers to loop forever.
+    gif.extend_from_slice(&[0x21, 0xFF, 0x0B]);
+    gif.extend_from_slice(b"NETSCAPE2.0");
+    gif.extend_from_slice(&[0x03, 0x01, 0x00, 0x00, 0x00]);
+
+    for frame in frames {
+        // Graphic control extension: the delay, with no transparent color.
+        let [delay_low, delay_high] = frame.delay.to_le_bytes();
+        gif.extend_from_slice(&[0x21, 0xF9, 0x04, 0x00, delay_low, delay_high, 0x00, 0x00]);
+        // Image descriptor covering the whole canvas, using the global palette.
+        gif.push(0x2C);
+        gif.extend_from_slice(&[0, 0, 0, 0]);
+        gif.extend_from_slice(&width.to_le_bytes());
+        gif.extend_from_slice(&height.to_le_bytes());
+        gif.push(0x00);
+
+        let indices: Vec<u8> = frame
+            .pixels
+            .chunks_exact(4)
+            .map(|pixel| palette.index(pixel))
+            .collect();
+        gif.push(MIN_CODE_SIZE as u8);
+        for block in lzw(&indices).chunks(255) {
+            gif.push(block.len() as u8);
+            gif.extend_from_slice(block);
+        }
+        gif.push(0x00);
+    }
+
+    gif.push(0x3B);
+    gif
+}
+
+/// Colors shared by the frames, and the entry standing in for each color bin.
+struct Palette {
+    colors: Vec<[u8; 3]>,
+    bins: Vec<u8>,
+}
+
+impl Palette {
+    /// Keeps the most common colors of `frames`, averaging the colors of each bin.
+    fn from_frames(frames: &[GifFrame]) -> Self {
+        let mut counts = vec![0u64; BIN_COUNT];
+        let mut sums = vec![[0u64; 3]; BIN_COUNT];
+        for pixel in frames.iter().flat_map(|frame| frame.pixels.chunks_exact(4)) {
+            let bin = bin(pixel);
+            counts[bin] += 1;
+            for (sum, &channel) in sums[bin].iter_mut().zip(pixel) {
+                *sum += u64::from(channel);
+            }
+        }
+
+        let mut used: Vec<usize> = (0..BIN_COUNT).filter(|&bin| counts[bin] > 0).collect();
+        used.sort_by_key(|&bin| std::cmp::Reverse(counts[bin]));
+        let colors: Vec<[u8; 3]> = used
+            .iter()
+            .take(PALETTE_SIZE)
+            .map(|&bin| sums[bin].map(|sum| (sum / counts[bin]) as u8))
+            .collect();
+
+        let mut bins = vec![0u8; BIN_COUNT];
+        for &bin in &used {
+            let color = sums[bin].map(|sum| (sum / counts[bin]) as u8);
+            bins[bin] = nearest(&colors, color);
+        }
+        Self { colors, bins }
+    }
+
+    /// Returns the palette entry drawn for `pixel`.
+    fn index(&self, pixel: &[u8]) -> u8 {
+        self.bins[bin(pixel)]
+    }
+}
+
+/// Returns the bin counting colors close to `pixel`.
+fn bin(pixel: &[u8]) -> usize {
+    let shift = 8 - BIN_BITS;
+    (usize::from(pixel[0] >> shift) << (BIN_BITS * 2))
+        | (usize::from(pixel[1] >> shift) << BIN_BITS)
+        | usize::from(pixel[2] >> shift)
+}
+
+/// Returns the index of the entry of `colors` closest to `color`.
+fn nearest(colors: &[[u8; 3]], color: [u8; 3]) -> u8 {
+    let distance = |candidate: &[u8; 3]| -> u32 {
+        candidate
+            .iter()
+            .zip(color)
+            .map(|(&a, b)| u32::from(a.abs_diff(b)).pow(2))
+            .sum()
+    };
+    colors
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, candidate)| distance(candidate))
+        .map_or(0, |(index, _)| index as u8)
+}
+
+/// Compresses palette indices with the variable-width LZW coding GIF uses.
+fn lzw(indices: &[u8]) -> Vec<u8> {
+    let clear = 1u16 << MIN_CODE_SIZE;
+    let end = clear + 1;
+    let max_code = 1u16 << MAX_CODE_SIZE;
+    // Code extending each prefix code by each index, or 0 when not yet assigned.
+    let mut table = vec![0u16; usize::from(max_code) << MIN_CODE_SIZE];
+    let mut next = end + 1;
+    let mut width = MIN_CODE_SIZE + 1;
+    let mut writer = BitWriter::default();
+    writer.write(clear, width);
+
+    let Some((&first, rest)) = indices.split_first() else {
+        writer.write(end, width);
+        return writer.finish();
+    };
+    let mut prefix = u16::from(first);
+    for &index in rest {
+        let slot = (usize::from(prefix) << MIN_CODE_SIZE) | usize::from(index);
+        if table[slot] != 0 {
+            prefix = table[slot];
+            continue;
+        }
+        writer.write(prefix, width);
+        if next < max_code {
+            table[slot] = next;
+            next += 1;
+            // Decoders learn each code one step later, so widen once they need to.
+            if u32::from(next) > 1 << width && width < MAX_CODE_SIZE {
+                width += 1;
+            }
+        } else {
+            writer.write(clear, width);
+            table.fill(0);
+            next = end + 1;
+            width = MIN_CODE_SIZE + 1;
+        }
+        prefix = u16::from(index);
+    }
+    writer.write(prefix, width);
+    if u32::from(next) == 1 << width && width < MAX_CODE_SIZE {
+        width += 1;
+    }
+    writer.write(end, width);
+    writer.finish()
+}
+
+/// Packs codes into bytes, least significant bit first.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, width: u32) {
+        self.buffer |= u32::from(code) << self.bits;
+        self.bits += width;
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decodes a GIF LZW stream back into palette indices.
+    fn unlzw(data: &[u8]) -> Vec<u8> {
+        let clear = 1usize << MIN_CODE_SIZE;
+        let end = clear + 1;
+        let mut dict: Vec<Vec<u8>> = (0..=u8::MAX).map(|index| vec![index]).collect();
+        dict.extend([Vec::new(), Vec::new()]);
+        let mut width = MIN_CODE_SIZE + 1;
+        let mut previous: Option<Vec<u8>> = None;
+        let mut position = 0;
+        let mut indices = Vec::new();
+        loop {
+            let mut code = 0usize;
+            for bit in 0..width as usize {
+                code |= usize::from(data[position / 8] >> (position % 8) & 1) << bit;
+                position += 1;
+            }
+            if code == clear {
+                dict.truncate(end + 1);
+                width = MIN_CODE_SIZE + 1;
+                previous = None;
+                continue;
+            }
+            if code == end {
+                return indices;
+            }
+            let entry = match (dict.get(code), &previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(previous)) => [previous.as_slice(), &previous[..1]].concat(),
+                (None, None) => panic!("code {code} used before it was defined"),
+            };
+            indices.extend_from_slice(&entry);
+            if let Some(previous) = previous
+                && dict.len() < 1 << MAX_CODE_SIZE
+            {
+                dict.push([previous.as_slice(), &entry[..1]].concat());
+                if dict.len() == 1 << width && width < MAX_CODE_SIZE {
+                    width += 1;
+                }
+            }
+            previous = Some(entry);
+        }
+    }
+
+    /// Returns `len` indices from a fixed pseudo-random sequence over `range` values.
+    fn noise(len: usize, range: u32) -> Vec<u8> {
+        let mut seed = 0x2545_F491u32;
+        (0..len)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                ((seed >> 16) % range) as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_encode_single_pixel() {
+        let frame = GifFrame {
+            pixels: vec![255, 0, 0, 255],
+            delay: 50,
+        };
+        let gif = encode(1, 1, &[frame]);
+
+        assert_eq!(&gif[..13], b"GIF89a\x01\x00\x01\x00\xF7\x00\x00");
+        assert_eq!(&gif[13..16], &[255, 0, 0]);
+        assert!(gif[16..13 + 3 * PALETTE_SIZE].iter().all(|&byte| byte == 0));
+        let frame_start = 13 + 3 * PALETTE_SIZE + 19;
+        assert_eq!(
+            &gif[frame_start..],
+            &[
+                0x21, 0xF9, 0x04, 0x00, 50, 0, 0x00, 0x00, // graphic control
+                0x2C, 0, 0, 0, 0, 1, 0, 1, 0, 0x00, // image descriptor
+                8, 4, 0x00, 0x01, 0x04, 0x04, 0x00, // clear, 0, end
+                0x3B,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lzw_round_trips() {
+        for indices in [vec![], vec![7], vec![0; 100], vec![1, 2, 1, 2, 1, 2, 1, 2]] {
+            assert_eq!(unlzw(&lzw(&indices)), indices);
+        }
+    }
+
+    #[test]
+    fn test_lzw_widens_codes() {
+        // Enough distinct pairs to define more than 256 codes, so later codes
+        // need 10 bits and the stream only decodes if both sides widen together.
+        let indices = noise(2_000, 64);
+        assert_eq!(unlzw(&lzw(&indices)), indices);
+    }
+
+    #[test]
+    fn test_lzw_resets_a_full_table() {
+        let indices = noise(20_000, 256);
+        assert_eq!(unlzw(&lzw(&indices)), indices);
+    }
+
+    #[test]
+    fn test_palette_keeps_the_most_common_colors() {
+        // 300 colors in distinct bins; the first 256 appear twice, the rest once.
+        let color = |index: usize| [(index % 20 * 12) as u8, (index / 20 * 12) as u8, 0, 255];
+        let pixels: Vec<u8> = (0..256).chain(0..300).flat_map(color).collect();
+        let palette = Palette::from_frames(&[GifFrame { pixels, delay: 0 }]);
+
+        assert_eq!(palette.colors.len(), PALETTE_SIZE);
+        let common = color(10);
+        assert_eq!(
+            palette.colors[usize::from(palette.index(&common))],
+            common[..3]
+        );
+        // Colors that did not fit are drawn with the closest kept color.
+        let distance = |a: &[u8], b: &[u8]| -> u32 {
+            a.iter()
+                .zip(b)
+                .map(|(&a, &b)| u32::from(a.abs_diff(b)).pow(2))
+                .sum()
+        };
+        for rare in 256..300 {
+            let pixel = color(rare);
+            let drawn = palette.colors[usize::from(palette.index(&pixel))];
+            assert!((0..256).any(|kept| color(kept)[..3] == drawn));
+            let closest = (0..256).map(|kept| distance(&color(kept), &pixel)).min();
+            assert_eq!(Some(distance(&drawn, &pixel)), closest);
+        }
+    }
+}
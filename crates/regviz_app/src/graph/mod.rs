@@ -1,34 +1,23 @@
-mod ast;
 mod bbox;
 mod canvas;
-mod dfa;
 mod draw;
 mod edge;
-mod highlight;
-pub mod layout;
-mod nfa;
+mod export;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod gif;
 mod node;
-mod style;
+mod reveal;
+mod search;
 
-pub use ast::AstGraph;
-pub use bbox::GraphBox;
-pub use canvas::GraphCanvas;
-pub use dfa::VisualDfa;
-pub use draw::{DrawContext, Drawable};
-pub use edge::GraphEdge;
-pub use highlight::{EdgeHighlight, Highlights, StateHighlight};
-pub use layout::{BoxVisibility, GraphLayout};
-pub use nfa::VisualNfa;
-pub use node::GraphNode;
-pub use style::color_for_box;
-
-pub trait Graph {
-    /// Returns all renderable nodes for the graph.
-    fn nodes(&self) -> Vec<GraphNode>;
-
-    /// Returns all edges between the nodes.
-    fn edges(&self) -> Vec<GraphEdge>;
-
-    /// Returns bounding boxes that should be rendered behind the nodes.
-    fn boxes(&self) -> Vec<GraphBox>;
-}
+pub use canvas::{GraphCanvas, TransitionNotation};
+pub use draw::{Detail, DrawContext, Drawable, ToIced, ToLayout};
+pub use export::ExportQuality;
+#[cfg(not(target_arch = "wasm32"))]
+pub use export::RgbaImage;
+pub use regviz_layout::{
+    AstGraph, BoxStyle, BoxStyles, BoxVisibility, EdgeHighlight, Graph, GraphEdge, GraphLayout,
+    GraphNode, HighlightLayer, Highlights, LayoutConfig, SpatialIndex, StateHighlight,
+    TransitionGrouping, VisualDfa, VisualNfa, component_tints, heat_tints, layout,
+};
+pub use reveal::Reveal;
+pub use search::SearchQuery;
@@ -1,12 +1,12 @@
 use iced::alignment::{Horizontal, Vertical};
-use iced::widget::canvas::{Frame, Path, Stroke, Text};
-use iced::{Color, Pixels, Point};
+use iced::widget::canvas::{Frame, LineDash, Path, Stroke, Text};
+use iced::{Color, Font, Point, Size};
 use iced_graphics::geometry::Renderer;
-use regviz_core::core::automaton::{BoxId, StateId};
+use regviz_layout::{NodeShape, PositionedNode, StateHighlight};
 
 use crate::app::theme::AppTheme;
 
-use super::{DrawContext, Drawable, StateHighlight};
+use super::{Detail, DrawContext, Drawable, ToIced, ToLayout};
 use crate::app::APP_FONT;
 
 /// Width of the gap between the outer and inner circle for accepting states.
@@ -35,118 +35,157 @@ const NODE_LABEL_BASE_SIZE: f32 = 18.0;
 const NODE_LABEL_MIN_SIZE: f32 = 10.0;
 /// Maximum font size for node labels.
 const NODE_LABEL_MAX_SIZE: f32 = 52.0;
-
-/// Visual representation of a state in the rendered graph.
-#[derive(Debug, Clone)]
-pub struct GraphNode {
-    /// Identifier of the state being rendered.
-    pub id: StateId,
-    /// Human readable label.
-    pub label: String,
-    /// Whether this node is the start state.
-    pub is_start: bool,
-    /// Whether this node is an accepting state.
-    pub is_accept: bool,
-    /// Bounding box identifier that owns this node, if any.
-    #[allow(dead_code)]
-    pub box_id: Option<BoxId>,
-    /// Optional highlight applied during simulation.
-    pub highlight: Option<StateHighlight>,
-    /// If true the node's position has been manually set by the user and
-    /// should be respected by layout algorithms.
-    pub is_pinned: bool,
-    /// Optional manual position in layout coordinates. When present and
-    /// `is_pinned` is true this overrides any computed layout coordinate.
-    pub manual_position: Option<Point>,
-}
-
-impl GraphNode {
-    /// Creates a new [`GraphNode`] with sensible defaults.
-    #[must_use]
-    pub fn new(
-        id: StateId,
-        label: String,
-        is_start: bool,
-        is_accept: bool,
-        box_id: Option<BoxId>,
-    ) -> Self {
-        Self {
-            id,
-            label,
-            is_start,
-            is_accept,
-            box_id,
-            highlight: None,
-            is_pinned: false,
-            manual_position: None,
-        }
-    }
-
-    /// Applies a highlight style for the current simulation step.
-    #[must_use]
-    pub fn with_highlight(mut self, highlight: Option<StateHighlight>) -> Self {
-        self.highlight = highlight;
-        self
-    }
-}
-
-/// [`GraphNode`] accompanied by layout information.
-#[derive(Debug, Clone)]
-pub struct PositionedNode {
-    /// Node metadata.
-    pub data: GraphNode,
-    /// Logical position before transforms.
-    pub position: Point,
-    /// Node radius.
-    pub radius: f32,
-}
-
-impl PositionedNode {
-    /// Builds a positioned node from metadata and coordinates.
-    #[must_use]
-    pub fn new(data: GraphNode, position: Point, radius: f32) -> Self {
-        Self {
-            data,
-            position,
-            radius,
-        }
-    }
-}
+/// Distance between a selected node's outline and its selection ring.
+const SELECTION_RING_GAP: f32 = 5.0;
+/// Stroke width of the selection ring.
+const SELECTION_RING_WIDTH: f32 = 2.5;
+/// Distance between a node's outline and the dashed ring marking the previewed frontier.
+const GHOST_RING_GAP: f32 = 3.0;
+/// Stroke width of the previewed-frontier ring.
+const GHOST_RING_WIDTH: f32 = 2.0;
+/// Dash pattern of the previewed-frontier ring.
+const GHOST_RING_DASH: [f32; 2] = [5.0, 4.0];
+/// Width of the band each highlight layer adds around a node.
+const LAYER_HALO_WIDTH: f32 = 6.0;
+/// Radius of the breakpoint marker before zoom is applied.
+const BREAKPOINT_MARKER_RADIUS: f32 = 5.0;
+/// Offset of the breakpoint marker from the node centre, as a multiple of the radius.
+const BREAKPOINT_MARKER_OFFSET_FACTOR: f32 = 0.75;
+/// Font of syntax tree nodes standing for a character of the pattern.
+const LITERAL_FONT: Font = Font::MONOSPACE;
+/// Number of outlines stacked behind a super-node.
+const COLLAPSED_STACK_DEPTH: u8 = 2;
+/// Offset between the outlines stacked behind a super-node, before zoom is applied.
+const COLLAPSED_STACK_OFFSET: f32 = 5.0;
 
 impl Drawable for PositionedNode {
     fn draw<R: Renderer>(&self, frame: &mut Frame<R>, ctx: &DrawContext, theme: &AppTheme) {
         let center = ctx.transform_point(self.position);
         let radius = self.radius * ctx.zoom;
-        let circle = Path::circle(center, radius);
+        let shape = self.data.kind.shape();
+        let circle = outline(shape, center, radius);
         let highlight = self.data.highlight;
-        let fill_color = highlight_fill_color(highlight, theme);
+        let fill_color = match (highlight, self.data.tint) {
+            (None, Some(tint)) => tint.to_iced(),
+            _ => highlight_fill_color(highlight, theme),
+        };
         let outline_color = highlight_outline_color(highlight, theme);
+        let detail = ctx.detail();
+
+        if detail == Detail::Overview {
+            // Plain nodes take their outline color so they stand out from the background.
+            let dot_color = if highlight.is_none() && self.data.tint.is_none() {
+                outline_color
+            } else {
+                fill_color
+            };
+            frame.fill(&circle, dot_color);
+            return;
+        }
+
+        // Drawn first so the node body covers the inner half of each halo. Lower
+        // layers get wider halos, so every layer shows as its own band.
+        let layers = self.data.overlays.len();
+        for (index, overlay) in self.data.overlays.iter().enumerate() {
+            frame.stroke(
+                &circle,
+                Stroke::default()
+                    .with_width(LAYER_HALO_WIDTH * 2.0 * (layers - index) as f32 * ctx.zoom)
+                    .with_color(overlay.to_iced()),
+            );
+        }
+
+        if self.data.collapsed.is_some() {
+            // A stack of outlines behind the node hints at the states folded into it.
+            for layer in (1..=COLLAPSED_STACK_DEPTH).rev() {
+                let offset = f32::from(layer) * COLLAPSED_STACK_OFFSET * ctx.zoom;
+                let behind = outline(
+                    shape,
+                    Point::new(center.x + offset, center.y - offset),
+                    radius,
+                );
+                frame.fill(&behind, fill_color);
+                frame.stroke(
+                    &behind,
+                    Stroke::default()
+                        .with_width(ctx.stroke_width(AUXILIARY_STROKE_WIDTH))
+                        .with_color(outline_color),
+                );
+            }
+        }
 
         frame.fill(&circle, fill_color);
         frame.stroke(
             &circle,
             Stroke::default()
-                .with_width(NODE_OUTLINE_WIDTH)
+                .with_width(ctx.stroke_width(NODE_OUTLINE_WIDTH))
                 .with_color(outline_color),
         );
 
         if self.data.is_accept {
-            draw_accepting_ring(frame, center, radius, ctx.zoom);
+            draw_accepting_ring(frame, center, radius, ctx);
+        }
+
+        if self.data.is_ghost {
+            let ring = outline(shape, center, radius + GHOST_RING_GAP * ctx.zoom);
+            frame.stroke(
+                &ring,
+                Stroke {
+                    line_dash: LineDash {
+                        segments: &GHOST_RING_DASH,
+                        offset: 0,
+                    },
+                    ..Stroke::default()
+                        .with_width(ctx.stroke_width(GHOST_RING_WIDTH))
+                        .with_color(theme.graph_node_active())
+                },
+            );
+        }
+
+        if self.data.is_selected {
+            let ring = outline(shape, center, radius + SELECTION_RING_GAP * ctx.zoom);
+            frame.stroke(
+                &ring,
+                Stroke::default()
+                    .with_width(ctx.stroke_width(SELECTION_RING_WIDTH))
+                    .with_color(theme.accent()),
+            );
         }
 
         if self.data.is_start {
-            draw_start_arrow(frame, center, radius, outline_color, ctx.zoom);
+            draw_start_arrow(frame, center, radius, outline_color, ctx);
+        }
+
+        if self.data.is_breakpoint {
+            let offset = radius * BREAKPOINT_MARKER_OFFSET_FACTOR;
+            let marker = Path::circle(
+                Point::new(center.x - offset, center.y - offset),
+                BREAKPOINT_MARKER_RADIUS * ctx.zoom,
+            );
+            frame.fill(&marker, theme.graph_node_breakpoint());
+            frame.stroke(
+                &marker,
+                Stroke::default()
+                    .with_width(ctx.stroke_width(AUXILIARY_STROKE_WIDTH))
+                    .with_color(theme.bg_low()),
+            );
         }
 
-        if !self.data.label.is_empty() {
-            let font_size = Pixels::from(
-                (NODE_LABEL_BASE_SIZE * ctx.zoom).clamp(NODE_LABEL_MIN_SIZE, NODE_LABEL_MAX_SIZE),
+        if detail == Detail::Full && !self.data.label.is_empty() {
+            let font_size = ctx.text_size(
+                NODE_LABEL_BASE_SIZE,
+                NODE_LABEL_MIN_SIZE,
+                NODE_LABEL_MAX_SIZE,
             );
             frame.fill_text(Text {
                 content: self.data.label.clone(),
                 position: center,
                 color: theme.text_primary_inverse(),
-                font: APP_FONT,
+                font: if self.data.kind.is_literal() {
+                    LITERAL_FONT
+                } else {
+                    APP_FONT
+                },
                 align_x: Horizontal::Center.into(),
                 align_y: Vertical::Center,
                 size: font_size,
@@ -156,14 +195,48 @@ impl Drawable for PositionedNode {
     }
 }
 
-fn draw_accepting_ring<R: Renderer>(frame: &mut Frame<R>, center: Point, radius: f32, zoom: f32) {
-    let gap = (ACCEPT_RING_GAP * zoom).clamp(1.0, radius.max(1.0));
+/// Builds the outline of a node of `shape` with the given on-screen center and radius.
+fn outline(shape: NodeShape, center: Point, radius: f32) -> Path {
+    match shape {
+        NodeShape::Circle => Path::circle(center, radius),
+        NodeShape::RoundedRect => {
+            let (rect, corner) = NodeShape::rounded_rect(center.to_layout(), radius);
+            Path::rounded_rectangle(
+                Point::new(rect.x, rect.y),
+                Size::new(rect.width, rect.height),
+                corner.into(),
+            )
+        }
+        NodeShape::Hexagon | NodeShape::Diamond => Path::new(|builder| {
+            let corners = shape.corners(center.to_layout(), radius);
+            for (index, corner) in corners.into_iter().map(ToIced::to_iced).enumerate() {
+                if index == 0 {
+                    builder.move_to(corner);
+                } else {
+                    builder.line_to(corner);
+                }
+            }
+            builder.close();
+        }),
+    }
+}
+
+fn draw_accepting_ring<R: Renderer>(
+    frame: &mut Frame<R>,
+    center: Point,
+    radius: f32,
+    ctx: &DrawContext,
+) {
+    let gap = (ACCEPT_RING_GAP * ctx.zoom).clamp(1.0, radius.max(1.0));
     let inner_radius = (radius - gap).max(0.0);
     if inner_radius <= 0.0 {
         return;
     }
     let inner = Path::circle(center, inner_radius);
-    frame.stroke(&inner, Stroke::default().with_width(AUXILIARY_STROKE_WIDTH));
+    frame.stroke(
+        &inner,
+        Stroke::default().with_width(ctx.stroke_width(AUXILIARY_STROKE_WIDTH)),
+    );
 }
 
 fn draw_start_arrow<R: Renderer>(
@@ -171,7 +244,7 @@ fn draw_start_arrow<R: Renderer>(
     center: Point,
     radius: f32,
     outline_color: Color,
-    zoom: f32,
+    ctx: &DrawContext,
 ) {
     let arrow_tail = Point::new(center.x - radius * START_ARROW_DISTANCE_FACTOR, center.y);
     let arrow_tip = Point::new(center.x - radius * START_ARROW_HEAD_OFFSET, center.y);
@@ -179,11 +252,11 @@ fn draw_start_arrow<R: Renderer>(
     frame.stroke(
         &arrow,
         Stroke::default()
-            .with_width(START_ARROW_STROKE_WIDTH)
+            .with_width(ctx.stroke_width(START_ARROW_STROKE_WIDTH))
             .with_color(outline_color),
     );
 
-    let arrow_scale = zoom.clamp(START_ARROW_MIN_SCALE, START_ARROW_MAX_SCALE);
+    let arrow_scale = ctx.zoom.clamp(START_ARROW_MIN_SCALE, START_ARROW_MAX_SCALE);
     let head_length = START_ARROW_HEAD_LENGTH * arrow_scale;
     let head_half_height = START_ARROW_HEAD_HALF_HEIGHT * arrow_scale;
     let head_base = Point::new(arrow_tip.x - head_length, arrow_tip.y);
@@ -197,7 +270,7 @@ fn draw_start_arrow<R: Renderer>(
     frame.stroke(
         &head,
         Stroke::default()
-            .with_width(AUXILIARY_STROKE_WIDTH)
+            .with_width(ctx.stroke_width(AUXILIARY_STROKE_WIDTH))
             .with_color(outline_color),
     );
 }
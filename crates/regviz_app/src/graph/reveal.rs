@@ -0,0 +1,34 @@
+use std::collections::HashSet;
+
+use regviz_core::core::automaton::{BoxId, StateId};
+
+use super::GraphLayout;
+
+/// The part of a graph built so far, while its construction is replayed.
+///
+/// The layout is computed for the whole graph and then trimmed to the revealed
+/// part, so states keep their final positions as the graph grows around them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Reveal {
+    /// States drawn.
+    pub states: HashSet<StateId>,
+    /// Transitions drawn, by their endpoints.
+    pub edges: HashSet<(StateId, StateId)>,
+    /// Bounding boxes drawn.
+    pub boxes: HashSet<BoxId>,
+}
+
+impl Reveal {
+    /// Removes everything not yet revealed from `layout`, keeping its bounds.
+    pub fn apply(&self, layout: &mut GraphLayout) {
+        layout
+            .nodes
+            .retain(|node| self.states.contains(&node.data.id));
+        layout
+            .edges
+            .retain(|edge| self.edges.contains(&(edge.data.from, edge.data.to)));
+        layout
+            .boxes
+            .retain(|bbox| self.boxes.contains(&bbox.data.id));
+    }
+}
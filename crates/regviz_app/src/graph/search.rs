@@ -0,0 +1,41 @@
+use super::{GraphEdge, GraphNode};
+
+/// Text typed into the canvas search bar, matched against states and transitions.
+///
+/// Matching ignores case. A state matches when its number equals the query or
+/// its label contains it; a transition matches when its label contains it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchQuery {
+    needle: String,
+}
+
+impl SearchQuery {
+    /// Builds a query from the search bar contents.
+    ///
+    /// # Arguments
+    /// - `text` (`&str`) - What the user typed.
+    ///
+    /// # Returns
+    /// - `Option<SearchQuery>` - The query, or `None` when `text` is blank.
+    #[must_use]
+    pub fn parse(text: &str) -> Option<Self> {
+        let needle = text.trim().to_lowercase();
+        (!needle.is_empty()).then_some(Self { needle })
+    }
+
+    /// Returns whether the state drawn as `node` matches the query.
+    #[must_use]
+    pub fn matches_node(&self, node: &GraphNode) -> bool {
+        node.id.to_string() == self.needle || self.contained_in(&node.label)
+    }
+
+    /// Returns whether the transition drawn as `edge` matches the query.
+    #[must_use]
+    pub fn matches_edge(&self, edge: &GraphEdge) -> bool {
+        self.contained_in(&edge.label)
+    }
+
+    fn contained_in(&self, label: &str) -> bool {
+        label.to_lowercase().contains(&self.needle)
+    }
+}
@@ -14,7 +14,6 @@ mod app;
 mod graph;
 
 use app::App;
-use iced::{Task, application};
 
 /// Initializes debug tracing for development builds.
 ///
@@ -37,7 +36,8 @@ fn init_tracing() {
 /// Application entry point.
 ///
 /// Initializes tracing (in debug mode) and starts the Iced event loop
-/// with the RegViz application.
+/// with the RegViz application. Native builds run as a daemon so views can
+/// be detached into windows of their own; the web build has a single page.
 fn main() -> iced::Result {
     // Debug assertions and not wasm32
     #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
@@ -49,14 +49,29 @@ fn main() -> iced::Result {
         console_log::init_with_level(log::Level::Debug).expect("could not initialize logger");
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
     #[cfg_attr(not(feature = "embed-fonts"), allow(unused_mut))]
-    let mut app = application(|| (App::default(), Task::none()), App::update, App::view)
-        .theme(|state: &App| Some(state.theme))
+    let mut app = iced::daemon(App::boot, App::update, App::view_window)
+        .subscription(App::subscription)
+        .theme(|state: &App, _| Some(state.theme))
         .antialiasing(true)
-        .decorations(true)
-        .title(|_: &App| String::from("RegViz - Regular Expression Visualizer"))
+        .title(App::window_title_for)
         .default_font(app::APP_FONT);
 
+    #[cfg(target_arch = "wasm32")]
+    #[cfg_attr(not(feature = "embed-fonts"), allow(unused_mut))]
+    let mut app = iced::application(
+        || (App::restored(), App::query_system_theme()),
+        App::update,
+        App::view,
+    )
+    .subscription(App::subscription)
+    .theme(|state: &App| Some(state.theme))
+    .antialiasing(true)
+    .decorations(true)
+    .title(App::window_title)
+    .default_font(app::APP_FONT);
+
     #[cfg(feature = "embed-fonts")]
     {
         app = app
@@ -0,0 +1,84 @@
+//! ANSI styling for terminal output.
+
+use std::env;
+use std::io::{self, IsTerminal};
+
+/// Role of a piece of text, mapped to an ANSI style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Column headings.
+    Header,
+    /// Accepting states and accepted verdicts.
+    Accept,
+    /// Active states in a simulation trace.
+    Active,
+    /// States that can never reach acceptance.
+    Dead,
+    /// Where a simulation failed, and rejected verdicts.
+    Failure,
+    /// States and symbols only in the right-hand automaton of a diff.
+    Added,
+    /// States and symbols only in the left-hand automaton of a diff.
+    Removed,
+}
+
+impl Style {
+    /// Returns the SGR parameters of the style.
+    fn code(self) -> &'static str {
+        match self {
+            Style::Header => "1",
+            Style::Accept => "1;32",
+            Style::Active => "32",
+            Style::Dead => "2",
+            Style::Failure => "1;31",
+            Style::Added => "32",
+            Style::Removed => "31",
+        }
+    }
+}
+
+/// Applies [`Style`]s when color is enabled, and passes text through otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Painter {
+    enabled: bool,
+}
+
+impl Painter {
+    /// Creates a painter that colors only when `enabled` is set.
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+
+    /// Decides whether stdout should be colored.
+    ///
+    /// # Arguments
+    /// - `preference` (`Option<bool>`) - `--color`/`--no-color` or the config
+    ///   file, if either was given.
+    ///
+    /// # Returns
+    /// - `Painter` - Colors when asked to, or otherwise when stdout is a
+    ///   terminal and `NO_COLOR` is unset.
+    pub fn detect(preference: Option<bool>) -> Self {
+        Self::new(preference.unwrap_or_else(|| {
+            env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+                && io::stdout().is_terminal()
+        }))
+    }
+
+    /// Wraps `text` in the escape codes of `style`.
+    pub fn paint(&self, text: &str, style: Style) -> String {
+        if self.enabled {
+            format!("\x1b[{}m{text}\x1b[0m", style.code())
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// Like [`Painter::paint`], but leaves the text unstyled when `style` is `None`.
+    pub fn paint_if(&self, text: &str, style: Option<Style>) -> String {
+        match style {
+            Some(style) => self.paint(text, style),
+            None => text.to_string(),
+        }
+    }
+}
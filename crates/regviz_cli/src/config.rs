@@ -0,0 +1,177 @@
+//! User defaults loaded from `config.toml`.
+//!
+//! The file lives at `$XDG_CONFIG_HOME/regviz/config.toml` (falling back to
+//! `~/.config/regviz/config.toml`), or wherever `REGVIZ_CONFIG` points. Every
+//! key is optional, and command-line flags always win over the file.
+
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{env, fs};
+
+use regviz_core::core::automaton::EpsilonGlyph;
+use serde::{Deserialize, Deserializer, de};
+
+use crate::{TraceOutput, View};
+
+/// Environment variable overriding the config file location.
+const CONFIG_ENV: &str = "REGVIZ_CONFIG";
+
+/// Defaults read from the config file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Notation for the empty string in printed expressions.
+    #[serde(deserialize_with = "parse")]
+    pub epsilon: Option<EpsilonGlyph>,
+    /// Directory that relative output paths are written to.
+    pub export_dir: Option<PathBuf>,
+    /// Whether to color terminal output; detected from the terminal when unset.
+    pub color: Option<bool>,
+    /// Defaults for `regviz simulate`.
+    pub simulate: SimulateDefaults,
+    /// Defaults for `regviz render`.
+    pub render: RenderDefaults,
+}
+
+/// `[simulate]` section of the config file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SimulateDefaults {
+    /// Trace printed when `--trace` is not given.
+    #[serde(deserialize_with = "parse")]
+    pub trace: Option<TraceOutput>,
+    /// Whether to simulate the DFA instead of the NFA.
+    pub dfa: bool,
+}
+
+/// `[render]` section of the config file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RenderDefaults {
+    /// View rendered when `--view` is not given.
+    #[serde(deserialize_with = "parse")]
+    pub view: Option<View>,
+}
+
+impl Config {
+    /// Loads the config file, or the defaults when there is none.
+    ///
+    /// # Returns
+    /// - `Result<Config, String>` - The parsed config, or a message naming the
+    ///   file and what is wrong with it.
+    pub fn load() -> Result<Self, String> {
+        let Some(path) = config_path() else {
+            return Ok(Self::default());
+        };
+        match fs::read_to_string(&path) {
+            Ok(contents) => Self::parse(&contents)
+                .map_err(|e| format!("Invalid config {}: {e}", path.display())),
+            // A missing default file is fine; an explicitly requested one is not.
+            Err(e)
+                if e.kind() == std::io::ErrorKind::NotFound
+                    && env::var_os(CONFIG_ENV).is_none() =>
+            {
+                Ok(Self::default())
+            }
+            Err(e) => Err(format!("Failed to read config {}: {e}", path.display())),
+        }
+    }
+
+    /// Parses the contents of a config file.
+    pub fn parse(contents: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(contents)
+    }
+
+    /// Resolves an output path against [`Config::export_dir`].
+    ///
+    /// # Arguments
+    /// - `path` (`&str`) - The path given on the command line.
+    ///
+    /// # Returns
+    /// - `PathBuf` - `path` itself when absolute or when no export directory is set.
+    pub fn output_path(&self, path: &str) -> PathBuf {
+        match &self.export_dir {
+            Some(dir) if Path::new(path).is_relative() => dir.join(path),
+            _ => PathBuf::from(path),
+        }
+    }
+}
+
+/// Returns where the config file is looked up.
+fn config_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os(CONFIG_ENV) {
+        return Some(PathBuf::from(path));
+    }
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from))?;
+    Some(base.join("regviz").join("config.toml"))
+}
+
+/// Deserializes an optional string through `T`'s [`FromStr`] implementation.
+fn parse<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|value| value.parse().map_err(de::Error::custom))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use regviz_core::core::trace::TraceFormat;
+
+    use super::*;
+
+    #[test]
+    fn test_parses_every_key() {
+        let config = Config::parse(
+            r#"
+            epsilon = "lambda"
+            export_dir = "/tmp/diagrams"
+            color = false
+
+            [simulate]
+            trace = "csv"
+            dfa = true
+
+            [render]
+            view = "min-dfa"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.epsilon, Some(EpsilonGlyph::Lambda));
+        assert_eq!(config.color, Some(false));
+        assert_eq!(
+            config.simulate.trace,
+            Some(TraceOutput::Export(TraceFormat::Csv))
+        );
+        assert!(config.simulate.dfa);
+        assert_eq!(config.render.view, Some(View::MinDfa));
+        assert_eq!(
+            config.output_path("a.svg"),
+            Path::new("/tmp/diagrams").join("a.svg")
+        );
+    }
+
+    #[test]
+    fn test_empty_file_uses_defaults() {
+        let config = Config::parse("").unwrap();
+        assert_eq!(config.epsilon, None);
+        assert!(!config.simulate.dfa);
+        assert_eq!(config.output_path("a.svg"), PathBuf::from("a.svg"));
+    }
+
+    #[test]
+    fn test_rejects_unknown_keys_and_values() {
+        assert!(Config::parse("colour = true").is_err());
+        let error = Config::parse("[render]\nview = \"graph\"").unwrap_err();
+        assert!(error.to_string().contains("unknown view"));
+    }
+}
@@ -0,0 +1,188 @@
+//! Lazy character input for streaming simulation.
+
+use std::io::{self, BufRead, ErrorKind};
+use std::iter::Peekable;
+
+/// Decodes UTF-8 characters from a reader one at a time, without ever holding
+/// more than the reader's internal buffer in memory.
+pub struct Utf8Chars<R> {
+    reader: R,
+}
+
+impl<R: BufRead> Utf8Chars<R> {
+    /// Wraps `reader`, which is read lazily as characters are requested.
+    pub fn new(reader: R) -> Self {
+        Self { reader }
+    }
+
+    /// Reads a single byte, or `None` at end of input.
+    fn next_byte(&mut self) -> io::Result<Option<u8>> {
+        loop {
+            match self.reader.fill_buf() {
+                Ok([]) => return Ok(None),
+                Ok(buf) => {
+                    let byte = buf[0];
+                    self.reader.consume(1);
+                    return Ok(Some(byte));
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Utf8Chars<R> {
+    type Item = io::Result<char>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.next_byte() {
+            Ok(Some(byte)) => byte,
+            Ok(None) => return None,
+            Err(e) => return Some(Err(e)),
+        };
+        let width = match first {
+            0x00..=0x7F => return Some(Ok(char::from(first))),
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => return Some(Err(invalid_utf8())),
+        };
+
+        let mut bytes = [first, 0, 0, 0];
+        for slot in &mut bytes[1..width] {
+            match self.next_byte() {
+                Ok(Some(byte)) => *slot = byte,
+                Ok(None) => return Some(Err(invalid_utf8())),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        Some(
+            std::str::from_utf8(&bytes[..width])
+                .ok()
+                .and_then(|s| s.chars().next())
+                .ok_or_else(invalid_utf8),
+        )
+    }
+}
+
+/// Drops one `"\n"` or `"\r\n"` at the very end of a character stream, as
+/// left by `echo` or an editor, so it is not read as part of the input.
+pub struct TrimFinalNewline<I: Iterator<Item = char>> {
+    chars: Peekable<I>,
+    /// A `'\n'` read ahead after a `'\r'` that turned out not to be final.
+    pending: Option<char>,
+}
+
+impl<I: Iterator<Item = char>> TrimFinalNewline<I> {
+    /// Wraps `chars`, reading at most two characters ahead.
+    pub fn new(chars: I) -> Self {
+        Self {
+            chars: chars.peekable(),
+            pending: None,
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for TrimFinalNewline<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if let Some(c) = self.pending.take() {
+            return Some(c);
+        }
+        match self.chars.next()? {
+            '\n' if self.chars.peek().is_none() => None,
+            '\r' if self.chars.peek() == Some(&'\n') => {
+                self.chars.next();
+                // A final "\r\n" ends the stream.
+                self.chars.peek()?;
+                self.pending = Some('\n');
+                Some('\r')
+            }
+            c => Some(c),
+        }
+    }
+}
+
+/// Reads `"\r\n"` line endings in a character stream as `"\n"`.
+pub struct UnixLineEndings<I: Iterator<Item = char>> {
+    chars: Peekable<I>,
+}
+
+impl<I: Iterator<Item = char>> UnixLineEndings<I> {
+    /// Wraps `chars`, reading at most one character ahead.
+    pub fn new(chars: I) -> Self {
+        Self {
+            chars: chars.peekable(),
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for UnixLineEndings<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match self.chars.next()? {
+            '\r' if self.chars.peek() == Some(&'\n') => self.chars.next(),
+            c => Some(c),
+        }
+    }
+}
+
+/// Removes one `"\n"` or `"\r\n"` from the end of `input`.
+pub fn trim_final_newline(input: &str) -> &str {
+    input
+        .strip_suffix("\r\n")
+        .or_else(|| input.strip_suffix('\n'))
+        .unwrap_or(input)
+}
+
+fn invalid_utf8() -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, "input is not valid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(bytes: &[u8]) -> io::Result<String> {
+        Utf8Chars::new(bytes).collect()
+    }
+
+    #[test]
+    fn test_decodes_multibyte_characters() {
+        assert_eq!(decode("aε€😀".as_bytes()).unwrap(), "aε€😀");
+    }
+
+    #[test]
+    fn test_trims_one_final_newline() {
+        for (input, trimmed) in [
+            ("ab\n", "ab"),
+            ("ab\r\n", "ab"),
+            ("ab\n\n", "ab\n"),
+            ("a\r\nb", "a\r\nb"),
+            ("a\rb\r", "a\rb\r"),
+            ("\n", ""),
+            ("", ""),
+        ] {
+            assert_eq!(
+                TrimFinalNewline::new(input.chars()).collect::<String>(),
+                trimmed
+            );
+            assert_eq!(trim_final_newline(input), trimmed);
+        }
+    }
+
+    #[test]
+    fn test_reads_crlf_as_newline() {
+        let lines: String = UnixLineEndings::new("a\r\nb\rc\n\r".chars()).collect();
+        assert_eq!(lines, "a\nb\rc\n\r");
+    }
+
+    #[test]
+    fn test_rejects_truncated_sequences() {
+        assert!(decode(&[b'a', 0xE2, 0x82]).is_err());
+        assert!(decode(&[0xFF]).is_err());
+    }
+}
@@ -0,0 +1,392 @@
+//! Aligned terminal tables for transition functions, simulation traces, audit
+//! reports and diffs.
+
+use regviz_core::core::analysis::DfaAudit;
+use regviz_core::core::automaton::StateId;
+use regviz_core::core::diff::DfaDiff;
+use regviz_core::core::formal::FormalDefinition;
+use regviz_core::core::trace::Trace;
+
+use crate::color::{Painter, Style};
+
+/// One table cell: its text and an optional style overriding the row's.
+type Cell = (String, Option<Style>);
+
+/// Renders `δ` of `definition` with one row per state.
+///
+/// The start state is marked `→` and accepting states `*`. Accepting rows are
+/// highlighted and dead rows, from which no accepting state can be reached,
+/// are dimmed.
+///
+/// # Arguments
+/// - `definition` (`&FormalDefinition`) - The automaton to tabulate.
+/// - `painter` (`Painter`) - Colors the output, if enabled.
+///
+/// # Returns
+/// - `String` - The table, one line per row.
+pub fn transition_table(definition: &FormalDefinition, painter: Painter) -> String {
+    let dead = dead_rows(definition);
+    let mut header: Vec<Cell> = vec![(String::new(), None), ("δ".to_string(), None)];
+    header.extend(
+        definition
+            .columns()
+            .into_iter()
+            .map(|column| (column, None)),
+    );
+
+    let mut rows = vec![(header, Some(Style::Header))];
+    for (index, (&state, cells)) in definition.states.iter().zip(&definition.delta).enumerate() {
+        let accepting = definition.accepting.contains(&state);
+        let marker = match (state == definition.start, accepting) {
+            (true, true) => "→*",
+            (true, false) => "→",
+            (false, true) => "*",
+            (false, false) => "",
+        };
+        let mut row: Vec<Cell> = vec![
+            (marker.to_string(), None),
+            (definition.state_name(state), None),
+        ];
+        row.extend(
+            cells
+                .iter()
+                .map(|targets| (definition.target_text(targets), None)),
+        );
+        let style = if accepting {
+            Some(Style::Accept)
+        } else if dead[index] {
+            Some(Style::Dead)
+        } else {
+            None
+        };
+        rows.push((row, style));
+    }
+    aligned(&rows, painter)
+}
+
+/// Renders a simulation run with one row per step, followed by the verdict.
+///
+/// Active states are highlighted, and the step where the run failed (its
+/// frontier emptied, or the input ended outside an accepting state) is marked.
+///
+/// # Arguments
+/// - `trace` (`&Trace`) - The recorded run.
+/// - `painter` (`Painter`) - Colors the output, if enabled.
+///
+/// # Returns
+/// - `String` - The table and a closing `accepted`/`rejected` line.
+pub fn trace_table(trace: &Trace, painter: Painter) -> String {
+    let failure = if trace.accepted() {
+        None
+    } else {
+        trace
+            .steps
+            .iter()
+            .position(|step| step.active_states.is_empty())
+            .or(trace.steps.len().checked_sub(1))
+    };
+
+    let header = ["step", "read", "active", "accepting"]
+        .map(|heading| (heading.to_string(), None))
+        .to_vec();
+    let mut rows = vec![(header, Some(Style::Header))];
+    for (index, step) in trace.steps.iter().enumerate() {
+        let states = if step.active_states.is_empty() {
+            "∅".to_string()
+        } else {
+            let names: Vec<String> = step
+                .active_states
+                .iter()
+                .map(|&state| trace.state_name(state))
+                .collect();
+            format!("{{{}}}", names.join(", "))
+        };
+        let row = vec![
+            (step.index.to_string(), None),
+            (step.consumed.map(String::from).unwrap_or_default(), None),
+            (states, Some(Style::Active)),
+            (
+                if step.accepted { "yes" } else { "no" }.to_string(),
+                step.accepted.then_some(Style::Accept),
+            ),
+        ];
+        rows.push((row, (failure == Some(index)).then_some(Style::Failure)));
+    }
+
+    let mut table = aligned(&rows, painter);
+    table.push_str(&if trace.accepted() {
+        painter.paint("accepted", Style::Accept)
+    } else {
+        painter.paint("rejected", Style::Failure)
+    });
+    table.push('\n');
+    table
+}
+
+/// Renders an audit as one labelled line per finding, highlighting problems.
+///
+/// # Arguments
+/// - `audit` (`&DfaAudit`) - The findings to print.
+/// - `painter` (`Painter`) - Colors the output, if enabled.
+///
+/// # Returns
+/// - `String` - The report, one line per finding.
+pub fn audit_report(audit: &DfaAudit, painter: Painter) -> String {
+    let set = |states: &[StateId]| {
+        let names: Vec<String> = states.iter().map(StateId::to_string).collect();
+        format!("{{{}}}", names.join(", "))
+    };
+    let issues = |states: &[StateId]| {
+        if states.is_empty() {
+            ("none".to_string(), Some(Style::Accept))
+        } else {
+            (set(states), Some(Style::Dead))
+        }
+    };
+    let symbols: Vec<String> = audit.alphabet.iter().map(char::to_string).collect();
+    let complete = if audit.complete {
+        ("yes".to_string(), Some(Style::Accept))
+    } else {
+        let missing: Vec<String> = audit
+            .missing
+            .iter()
+            .map(|(state, symbol)| format!("δ({state}, {symbol})"))
+            .collect();
+        (
+            format!("no, missing {}", missing.join(", ")),
+            Some(Style::Failure),
+        )
+    };
+    let minimal = if audit.minimal {
+        ("yes".to_string(), Some(Style::Accept))
+    } else if audit.complete {
+        (
+            format!("no, the minimal DFA has {} states", audit.minimal_states),
+            Some(Style::Failure),
+        )
+    } else {
+        (
+            format!(
+                "no, the minimal partial DFA has {} states",
+                audit.minimal_partial_states
+            ),
+            Some(Style::Failure),
+        )
+    };
+
+    let findings = [
+        ("alphabet", (format!("{{{}}}", symbols.join(", ")), None)),
+        ("states", (audit.states.to_string(), None)),
+        ("start", (audit.start.to_string(), None)),
+        ("accepting", (set(&audit.accepting), None)),
+        ("unreachable", issues(&audit.unreachable)),
+        ("dead", issues(&audit.dead)),
+        ("complete", complete),
+        ("minimal", minimal),
+    ];
+    let rows: Vec<(Vec<Cell>, Option<Style>)> = findings
+        .into_iter()
+        .map(|(label, value)| (vec![(label.to_string(), Some(Style::Header)), value], None))
+        .collect();
+    aligned(&rows, painter)
+}
+
+/// Renders a diff as one line per difference, `-` for the left automaton and
+/// `+` for the right one.
+///
+/// Aligned states are named `q0`, `q1`, … by canonical number; states without a
+/// counterpart keep their own number, prefixed `L` or `R`.
+///
+/// # Arguments
+/// - `diff` (`&DfaDiff`) - The differences to print.
+/// - `painter` (`Painter`) - Colors the output, if enabled.
+///
+/// # Returns
+/// - `String` - The report, closing with an `identical`/`different` line.
+pub fn diff_report(diff: &DfaDiff, painter: Painter) -> String {
+    let left = |state: StateId| match diff.left_index(state) {
+        Some(index) => format!("q{index}"),
+        None => format!("L{state}"),
+    };
+    let right = |state: StateId| match diff.right_index(state) {
+        Some(index) => format!("q{index}"),
+        None => format!("R{state}"),
+    };
+    let target = |name: Option<String>| name.unwrap_or_else(|| "∅".to_string());
+
+    let mut rows: Vec<(Vec<Cell>, Option<Style>)> = Vec::new();
+    let mut line = |sign: &str, text: String, style: Option<Style>| {
+        rows.push((vec![(sign.to_string(), None), (text, None)], style));
+    };
+    for symbol in &diff.symbols_removed {
+        line("-", format!("symbol {symbol}"), Some(Style::Removed));
+    }
+    for symbol in &diff.symbols_added {
+        line("+", format!("symbol {symbol}"), Some(Style::Added));
+    }
+    for &state in &diff.removed {
+        line("-", format!("state {}", left(state)), Some(Style::Removed));
+    }
+    for &state in &diff.added {
+        line("+", format!("state {}", right(state)), Some(Style::Added));
+    }
+    for change in &diff.acceptance_changed {
+        let side = if change.left_accepting {
+            "left"
+        } else {
+            "right"
+        };
+        line(
+            "~",
+            format!("{} accepting only on the {side}", left(change.left)),
+            None,
+        );
+    }
+    for change in &diff.transitions_changed {
+        line(
+            "~",
+            format!(
+                "δ({}, {}): {} → {}",
+                left(change.left),
+                change.symbol,
+                target(change.left_target.map(left)),
+                target(change.right_target.map(right)),
+            ),
+            None,
+        );
+    }
+    let mut out = aligned(&rows, painter);
+    out.push_str(&if diff.is_identical() {
+        painter.paint("identical", Style::Accept)
+    } else {
+        painter.paint("different", Style::Failure)
+    });
+    out.push('\n');
+    out
+}
+
+/// Pads every column to its widest cell and joins the rows into lines.
+///
+/// A row style applies to all of its cells, taking precedence over cell styles.
+fn aligned(rows: &[(Vec<Cell>, Option<Style>)], painter: Painter) -> String {
+    let columns = rows.iter().map(|(cells, _)| cells.len()).max().unwrap_or(0);
+    let widths: Vec<usize> = (0..columns)
+        .map(|column| {
+            rows.iter()
+                .filter_map(|(cells, _)| cells.get(column))
+                .map(|(text, _)| text.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let mut out = String::new();
+    for (cells, row_style) in rows {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|((text, cell_style), &width)| {
+                let padded = format!("{text:<width$}");
+                painter.paint_if(&padded, row_style.or(*cell_style))
+            })
+            .collect();
+        out.push_str(line.join("  ").trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+/// Flags, for each row of `δ`, whether no accepting state is reachable from it.
+fn dead_rows(definition: &FormalDefinition) -> Vec<bool> {
+    let index_of = |state: StateId| definition.states.binary_search(&state).ok();
+    let mut productive: Vec<bool> = definition
+        .states
+        .iter()
+        .map(|state| definition.accepting.contains(state))
+        .collect();
+
+    // Propagate backwards until no row gains a productive successor.
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (index, cells) in definition.delta.iter().enumerate() {
+            if !productive[index]
+                && cells
+                    .iter()
+                    .flatten()
+                    .filter_map(|&target| index_of(target))
+                    .any(|target| productive[target])
+            {
+                productive[index] = true;
+                changed = true;
+            }
+        }
+    }
+    productive
+        .into_iter()
+        .map(|productive| !productive)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use regviz_core::core::{dfa, diff, min, nfa, parser, trace};
+
+    use super::*;
+
+    fn dfa_for(pattern: &str) -> dfa::Dfa {
+        dfa::determinize(&nfa::Nfa::build(&parser::Ast::build(pattern).unwrap()))
+    }
+
+    #[test]
+    fn test_marks_start_accepting_and_dead_rows() {
+        let definition = FormalDefinition::from_dfa(&dfa_for("ab"));
+        let table = transition_table(&definition, Painter::new(false));
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), definition.states.len() + 1);
+        assert!(lines[0].contains('a') && lines[0].contains('b'));
+        assert!(lines.iter().any(|line| line.starts_with("→ ")));
+        assert!(lines.iter().any(|line| line.starts_with("* ")));
+        assert_eq!(
+            dead_rows(&definition).iter().filter(|&&dead| dead).count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_colors_only_when_enabled() {
+        let definition = FormalDefinition::from_dfa(&dfa_for("a"));
+        assert!(!transition_table(&definition, Painter::new(false)).contains('\x1b'));
+        assert!(transition_table(&definition, Painter::new(true)).contains("\x1b[1;32m"));
+    }
+
+    #[test]
+    fn test_highlights_the_failure_point() {
+        let nfa = nfa::Nfa::build(&parser::Ast::build("ab").unwrap());
+        let run = trace::nfa_trace(&nfa, &nfa.alphabet(), "bb").unwrap();
+        let table = trace_table(&run, Painter::new(true));
+        let failed: Vec<&str> = table
+            .lines()
+            .filter(|line| line.contains("\x1b[1;31m"))
+            .collect();
+        // The step where no state is left active, and the verdict.
+        assert_eq!(failed.len(), 2);
+        assert!(failed[0].starts_with("\x1b[1;31m1 "));
+        assert!(table.ends_with("\x1b[1;31mrejected\x1b[0m\n"));
+    }
+
+    #[test]
+    fn test_reports_diff_lines() {
+        let minimal = |pattern| min::minimize(&dfa_for(pattern));
+        let same = diff::diff(&minimal("a*b"), &minimal("a*b"));
+        assert_eq!(diff_report(&same, Painter::new(false)), "identical\n");
+
+        let report = diff_report(
+            &diff::diff(&minimal("(a+b)*abb"), &minimal("(a+b)*ab")),
+            Painter::new(false),
+        );
+        assert!(report.lines().any(|line| line.starts_with("-  state L")));
+        assert!(report.contains("δ(q"));
+        assert!(report.ends_with("different\n"));
+    }
+}
@@ -0,0 +1,69 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+/// Runs `regviz` with `args`, piping `stdin` in, and returns its exit code and stdout.
+fn regviz(args: &[&str], stdin: &str) -> (i32, String) {
+    // An empty config file keeps the user's defaults out of the test.
+    let config = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("empty_config.toml");
+    std::fs::write(&config, "").unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_regviz"))
+        .args(args)
+        .env("REGVIZ_CONFIG", &config)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    (
+        output.status.code().unwrap(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn test_piped_input_ignores_final_newline() {
+    for stdin in ["abb", "abb\n", "abb\r\n"] {
+        for extra in [&[][..], &["--dfa"], &["--trace", "json"]] {
+            let args = [&["simulate", "(a+b)*abb", "-"][..], extra].concat();
+            assert_eq!(regviz(&args, stdin).0, 0, "{stdin:?} with {extra:?}");
+        }
+    }
+    // Only one line break is dropped.
+    assert_eq!(regviz(&["simulate", "(a+b)*abb", "-"], "abb\n\n").0, 5);
+}
+
+#[test]
+fn test_lines_accept_crlf_endings() {
+    let (status, stdout) = regviz(
+        &["simulate", "(a+b)*abb", "--lines"],
+        "abb\r\nab\r\naabb\r\n",
+    );
+    assert_eq!(status, 1);
+    assert_eq!(stdout, "accepted\nrejected\naccepted\n");
+
+    let (status, stdout) = regviz(&["simulate", "(a+b)*abb", "--lines"], "abb\nbabb");
+    assert_eq!(status, 0);
+    assert_eq!(stdout, "accepted\naccepted\n");
+}
+
+#[test]
+fn test_unknown_symbols_exit_with_invalid_input() {
+    assert_eq!(regviz(&["simulate", "ab", "-"], "axb\n").0, 5);
+    assert_eq!(
+        regviz(&["simulate", "ab", "axb", "--trace", "csv"], "").0,
+        5
+    );
+
+    let (status, stdout) = regviz(&["simulate", "ab", "--lines"], "ab\nbx\nb\n");
+    assert_eq!(status, 5);
+    assert_eq!(stdout, "accepted\nrejected\n");
+}
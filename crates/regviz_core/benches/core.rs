@@ -0,0 +1,91 @@
+//! Times every stage of the pipeline on each case of [`bench_corpus`].
+//!
+//! Run with `cargo bench --package regviz_core`, optionally followed by a
+//! filter such as `determinize/nth_from_end`.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use regviz_core::bench::BenchCase;
+use regviz_core::bench_corpus;
+use regviz_core::core::dfa::{self, Dfa};
+use regviz_core::core::min;
+use regviz_core::core::nfa::Nfa;
+use regviz_core::core::parser::Ast;
+use regviz_core::core::sim;
+
+/// Times `routine` on every case of the corpus as the `name` group.
+fn bench_each<T>(
+    c: &mut Criterion,
+    name: &str,
+    setup: impl Fn(&BenchCase) -> T,
+    routine: impl Fn(&T, &BenchCase),
+) {
+    let mut group = c.benchmark_group(name);
+    for case in bench_corpus() {
+        let prepared = setup(&case);
+        group.bench_with_input(BenchmarkId::from_parameter(case.name), &case, |b, case| {
+            b.iter(|| routine(&prepared, case));
+        });
+    }
+    group.finish();
+}
+
+fn ast(case: &BenchCase) -> Ast {
+    Ast::build(&case.pattern).expect("corpus patterns parse")
+}
+
+fn determinized(case: &BenchCase) -> Dfa {
+    dfa::determinize(&Nfa::build(&ast(case)))
+}
+
+fn construction(c: &mut Criterion) {
+    bench_each(
+        c,
+        "parse",
+        |_| (),
+        |_, case| {
+            black_box(Ast::build(black_box(&case.pattern)).ok());
+        },
+    );
+    bench_each(c, "thompson", ast, |ast, _| {
+        black_box(Nfa::build(black_box(ast)));
+    });
+    bench_each(c, "glushkov", ast, |ast, _| {
+        black_box(Nfa::glushkov(black_box(ast)));
+    });
+    bench_each(
+        c,
+        "determinize",
+        |case| Nfa::build(&ast(case)),
+        |nfa, _| {
+            black_box(dfa::determinize(black_box(nfa)));
+        },
+    );
+}
+
+fn minimization(c: &mut Criterion) {
+    bench_each(c, "hopcroft", determinized, |dfa, _| {
+        black_box(min::minimize(black_box(dfa)));
+    });
+    bench_each(c, "brzozowski", determinized, |dfa, _| {
+        black_box(min::minimize_brzozowski(black_box(dfa)));
+    });
+}
+
+fn simulation(c: &mut Criterion) {
+    bench_each(
+        c,
+        "simulate_nfa",
+        |case| Nfa::build(&ast(case)),
+        |nfa, case| {
+            black_box(sim::nfa_accepts(black_box(nfa), black_box(&case.input)));
+        },
+    );
+    bench_each(c, "simulate_dfa", determinized, |dfa, case| {
+        black_box(sim::simulate_dfa(black_box(dfa), black_box(&case.input)));
+    });
+}
+
+criterion_group!(benches, construction, minimization, simulation);
+criterion_main!(benches);
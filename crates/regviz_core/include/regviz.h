@@ -0,0 +1,93 @@
+#ifndef REGVIZ_H
+#define REGVIZ_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Returned by [`regviz_dfa_run`] when the input is accepted.
+#define REGVIZ_ACCEPT 1
+
+// Returned by [`regviz_dfa_run`] when the input is rejected.
+#define REGVIZ_REJECT 0
+
+// Returned by [`regviz_dfa_run`] when called with invalid arguments.
+#define REGVIZ_INVALID -1
+
+// Opaque handle to a validated DFA with a byte-indexed symbol lookup table.
+typedef struct RegvizDfa RegvizDfa;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Compiles a regular expression into a minimized DFA.
+//
+// Returns null if `pattern` is null, not valid UTF-8, or fails to parse.
+//
+// # Safety
+//
+// `pattern` must be null or point to a valid NUL-terminated string.
+RegvizDfa *regviz_dfa_from_pattern(const char *pattern);
+
+// Loads a DFA from its JSON representation.
+//
+// The JSON object mirrors [`Dfa`]: `states`, `start`, `accepts`, `trans`
+// (one row per state, one column per symbol) and `alphabet` (single-character
+// strings). Returns null if the document is malformed.
+//
+// # Safety
+//
+// `json` must be null or point to a valid NUL-terminated string.
+RegvizDfa *regviz_dfa_from_json(const char *json);
+
+// Builds a DFA from a dense transition table.
+//
+// States are numbered `0..num_states`. `alphabet` holds `num_symbols` ASCII
+// bytes, and `table` holds `num_states * num_symbols` target states in
+// row-major order. Returns null if the table is inconsistent, a symbol is not
+// ASCII, or a pointer is null while its array is non-empty.
+//
+// # Safety
+//
+// `alphabet`, `table` and `accepts` must point to arrays of at least
+// `num_symbols`, `num_states * num_symbols` and `num_accepts` elements, and
+// may only be null when that count is zero.
+RegvizDfa *regviz_dfa_from_table(size_t num_states,
+                                 size_t num_symbols,
+                                 const uint8_t *alphabet,
+                                 const uint32_t *table,
+                                 uint32_t start,
+                                 const uint32_t *accepts,
+                                 size_t num_accepts);
+
+// Runs the DFA over `len` bytes of `input`.
+//
+// Each byte is one symbol, so only ASCII symbols can match; a byte of `0x80`
+// or above, including any part of a multi-byte UTF-8 sequence, rejects.
+// Returns [`REGVIZ_ACCEPT`], [`REGVIZ_REJECT`], or [`REGVIZ_INVALID`] if
+// `dfa` is null or `input` is null with a non-zero length.
+//
+// # Safety
+//
+// `dfa` must be null or a live handle, and `input` must cover `len` bytes.
+int regviz_dfa_run(const RegvizDfa *dfa, const uint8_t *input, size_t len);
+
+// Releases a handle created by one of the `regviz_dfa_from_*` functions.
+//
+// Passing null is a no-op.
+//
+// # Safety
+//
+// `dfa` must be null or a handle that has not already been freed.
+void regviz_dfa_free(RegvizDfa *dfa);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* REGVIZ_H */
@@ -0,0 +1,90 @@
+//! Patterns the benchmarks in `benches/` time the core algorithms on.
+
+/// A pattern to build automata from, and an input to simulate them on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchCase {
+    /// Short name identifying the case in benchmark reports.
+    pub name: &'static str,
+    /// The regular expression.
+    pub pattern: String,
+    /// Input the automata built from `pattern` are run on.
+    pub input: String,
+}
+
+impl BenchCase {
+    fn new(name: &'static str, pattern: impl Into<String>, input: impl Into<String>) -> Self {
+        Self {
+            name,
+            pattern: pattern.into(),
+            input: input.into(),
+        }
+    }
+}
+
+/// Returns the corpus the benchmarks run on.
+///
+/// Alongside everyday patterns it holds pathological ones: `(a+b)*a(a+b)ⁿ`,
+/// whose DFA doubles with every `n`, `a?ⁿaⁿ`, whose NFA keeps most states
+/// active at once, and deeply nested stars.
+///
+/// # Returns
+///
+/// - `Vec<BenchCase>` - The cases, each with an input long enough to time simulations on.
+#[must_use]
+pub fn bench_corpus() -> Vec<BenchCase> {
+    const NTH_FROM_END: usize = 10;
+    const OPTIONAL_RUN: usize = 16;
+
+    vec![
+        BenchCase::new("literal", "regviz", "regviz"),
+        BenchCase::new(
+            "ends_in_abb",
+            "(a+b)*abb",
+            format!("{}abb", "ab".repeat(512)),
+        ),
+        BenchCase::new(
+            "keywords",
+            "(if+then+else+while+for+return)*",
+            "ifthenelsewhileforreturn".repeat(64),
+        ),
+        BenchCase::new(
+            "identifiers",
+            "(a+b+c+d+e+f)(a+b+c+d+e+f+0+1+2+3)*",
+            format!("f{}", "abc123def".repeat(128)),
+        ),
+        BenchCase::new(
+            "nth_from_end",
+            format!("(a+b)*a{}", "(a+b)".repeat(NTH_FROM_END)),
+            format!("{}a", "ab".repeat(512)),
+        ),
+        BenchCase::new(
+            "optional_run",
+            format!("{}{}", "a?".repeat(OPTIONAL_RUN), "a".repeat(OPTIONAL_RUN)),
+            "a".repeat(OPTIONAL_RUN),
+        ),
+        BenchCase::new("nested_stars", "((((a*b*)*c)*d?)*e)*", "abcde".repeat(256)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::core::nfa::Nfa;
+    use crate::core::parser::Ast;
+    use crate::core::sim;
+
+    #[test]
+    fn test_bench_corpus_builds_and_accepts_its_inputs() {
+        let corpus = bench_corpus();
+        let names: HashSet<&str> = corpus.iter().map(|case| case.name).collect();
+        assert_eq!(names.len(), corpus.len());
+
+        for case in &corpus {
+            let ast = Ast::build(&case.pattern).unwrap();
+            let nfa = Nfa::build(&ast);
+            assert!(sim::nfa_accepts(&nfa, &case.input), "{}", case.name);
+        }
+    }
+}
@@ -0,0 +1,1303 @@
+//! Structural analyses of automata that do not change the recognized language.
+
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::fmt;
+
+use crate::core::automaton::{Edge, EdgeLabel, StateId};
+use crate::core::dfa::{Dfa, write_columns};
+use crate::core::min;
+use crate::core::nfa::Nfa;
+use crate::errors::DfaError;
+
+/// Strongly connected components of an automaton and their condensation DAG.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SccAnalysis {
+    /// The components, in topological order of the condensation: every edge
+    /// between two components goes from a lower index to a higher one.
+    pub components: Vec<Vec<StateId>>,
+    /// Index into `components` for every state.
+    pub component_of: Vec<usize>,
+    /// Successor components of each component in the condensation DAG,
+    /// sorted and without self-edges.
+    pub condensation: Vec<Vec<usize>>,
+    /// Whether each component contains a cycle: more than one state, or a
+    /// single state with a self-loop.
+    pub cyclic: Vec<bool>,
+    /// Whether an accepting state is reachable from each component.
+    pub accepting_reachable: Vec<bool>,
+}
+
+/// Sizes of an automaton, as compared between its NFA, DFA and minimized forms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutomatonStats {
+    /// Number of states.
+    pub states: usize,
+    /// Number of transitions, ε transitions included.
+    pub edges: usize,
+    /// Number of ε transitions.
+    pub epsilon_edges: usize,
+    /// Reachable states from which no accepting state can be reached.
+    pub dead: usize,
+    /// States that cannot be reached from the start state.
+    pub unreachable: usize,
+}
+
+impl AutomatonStats {
+    /// Counts dead and unreachable states over `successors` and records the
+    /// provided transition counts.
+    fn compute(
+        successors: &[Vec<StateId>],
+        start: StateId,
+        accepts: &[StateId],
+        edges: usize,
+        epsilon_edges: usize,
+    ) -> Self {
+        let mut predecessors = vec![Vec::new(); successors.len()];
+        for (from, row) in successors.iter().enumerate() {
+            for &to in row {
+                predecessors[to as usize].push(from as StateId);
+            }
+        }
+        let reachable = mark_reachable(successors, &[start]);
+        let productive = mark_reachable(&predecessors, accepts);
+
+        Self {
+            states: successors.len(),
+            edges,
+            epsilon_edges,
+            dead: reachable
+                .iter()
+                .zip(&productive)
+                .filter(|&(&reached, &productive)| reached && !productive)
+                .count(),
+            unreachable: reachable.iter().filter(|reached| !**reached).count(),
+        }
+    }
+}
+
+/// Health report of a DFA, as checked before grading or comparing machines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DfaAudit {
+    /// The input symbols, in the order of the transition table.
+    pub alphabet: Vec<char>,
+    /// Number of states.
+    pub states: usize,
+    /// The start state.
+    pub start: StateId,
+    /// The accepting states, in ascending order.
+    pub accepting: Vec<StateId>,
+    /// States that cannot be reached from the start state, in ascending order.
+    pub unreachable: Vec<StateId>,
+    /// Reachable states from which no accepting state can be reached, in
+    /// ascending order.
+    pub dead: Vec<StateId>,
+    /// Transitions the table leaves out, as `(state, symbol)` pairs.
+    pub missing: Vec<(StateId, char)>,
+    /// Whether every state has a transition on every symbol.
+    pub complete: bool,
+    /// Number of states of the minimal complete DFA for the same language.
+    pub minimal_states: usize,
+    /// Number of states of the minimal DFA for the same language when
+    /// transitions may be left out, i.e. without a dead state.
+    pub minimal_partial_states: usize,
+    /// Whether the DFA has no unreachable states and already has
+    /// `minimal_states` states if it is complete, or `minimal_partial_states`
+    /// if it is not.
+    pub minimal: bool,
+}
+
+/// Writes the report as one `label  value` line per finding.
+impl fmt::Display for DfaAudit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let set = |states: &[StateId]| {
+            let names: Vec<String> = states.iter().map(StateId::to_string).collect();
+            format!("{{{}}}", names.join(", "))
+        };
+        let issues = |states: &[StateId]| {
+            if states.is_empty() {
+                "none".to_string()
+            } else {
+                set(states)
+            }
+        };
+        let symbols: Vec<String> = self.alphabet.iter().map(char::to_string).collect();
+        let complete = if self.complete {
+            "yes".to_string()
+        } else {
+            let missing: Vec<String> = self
+                .missing
+                .iter()
+                .map(|(state, symbol)| format!("δ({state}, {symbol})"))
+                .collect();
+            format!("no, missing {}", missing.join(", "))
+        };
+        let minimal = if self.minimal {
+            "yes".to_string()
+        } else if self.complete {
+            format!("no, the minimal DFA has {} states", self.minimal_states)
+        } else {
+            format!(
+                "no, the minimal partial DFA has {} states",
+                self.minimal_partial_states
+            )
+        };
+
+        let rows = [
+            ("alphabet", format!("{{{}}}", symbols.join(", "))),
+            ("states", self.states.to_string()),
+            ("start", self.start.to_string()),
+            ("accepting", set(&self.accepting)),
+            ("unreachable", issues(&self.unreachable)),
+            ("dead", issues(&self.dead)),
+            ("complete", complete),
+            ("minimal", minimal),
+        ]
+        .map(|(label, value)| vec![label.to_string(), value]);
+        write_columns(f, &rows)
+    }
+}
+
+/// Audits a DFA for unreachable, dead and missing states and checks whether it
+/// is already minimal.
+///
+/// The table is checked with [`Dfa::validate_partial`], so rows shorter than
+/// the alphabet are accepted and the transitions they leave out are reported
+/// as missing.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton to audit, e.g. one imported from JSON.
+///
+/// # Returns
+///
+/// - `Result<DfaAudit, DfaError>` - The report, or why the table is malformed
+///   beyond missing transitions.
+pub fn audit(dfa: &Dfa) -> Result<DfaAudit, DfaError> {
+    dfa.validate_partial()?;
+    let rows = dfa.trans.len();
+
+    let missing: Vec<(StateId, char)> = dfa
+        .trans
+        .iter()
+        .enumerate()
+        .flat_map(|(state, row)| {
+            dfa.alphabet[row.len()..]
+                .iter()
+                .map(move |&symbol| (state as StateId, symbol))
+        })
+        .collect();
+
+    let mut predecessors = vec![Vec::new(); rows];
+    for (from, row) in dfa.trans.iter().enumerate() {
+        for &to in row {
+            predecessors[to as usize].push(from as StateId);
+        }
+    }
+    let reachable = mark_reachable(&dfa.trans, &[dfa.start]);
+    let productive = mark_reachable(&predecessors, &dfa.accepts);
+    let unreachable: Vec<bool> = reachable.iter().map(|reached| !reached).collect();
+    let dead: Vec<bool> = reachable
+        .iter()
+        .zip(&productive)
+        .map(|(&reached, &productive)| reached && !productive)
+        .collect();
+
+    // Count the Nerode classes of the reachable part of the completed DFA.
+    let completed = dfa.complete_with_sink();
+    let classes = min::nerode_classes(&completed);
+    let reachable_completed = mark_reachable(&completed.trans, &[completed.start]);
+    let mut minimal_classes: Vec<StateId> = classes
+        .iter()
+        .zip(&reachable_completed)
+        .filter(|(_, reached)| **reached)
+        .map(|(&class, _)| class)
+        .collect();
+    minimal_classes.sort_unstable();
+    minimal_classes.dedup();
+    let minimal_states = minimal_classes.len();
+
+    // All dead states fall in one class, which a partial DFA leaves out.
+    let mut completed_predecessors = vec![Vec::new(); completed.trans.len()];
+    for (from, row) in completed.trans.iter().enumerate() {
+        for &to in row {
+            completed_predecessors[to as usize].push(from as StateId);
+        }
+    }
+    let productive_completed = mark_reachable(&completed_predecessors, &completed.accepts);
+    let has_dead_class = reachable_completed
+        .iter()
+        .zip(&productive_completed)
+        .any(|(&reached, &productive)| reached && !productive);
+    let minimal_partial_states = minimal_states - usize::from(has_dead_class);
+
+    let mut accepting = dfa.accepts.clone();
+    accepting.sort_unstable();
+    accepting.dedup();
+    let complete = missing.is_empty();
+    let unreachable = marked_states(&unreachable);
+
+    Ok(DfaAudit {
+        alphabet: dfa.alphabet.clone(),
+        states: rows,
+        start: dfa.start,
+        accepting,
+        minimal: unreachable.is_empty()
+            && rows
+                == if complete {
+                    minimal_states
+                } else {
+                    minimal_partial_states
+                },
+        unreachable,
+        dead: marked_states(&dead),
+        missing,
+        complete,
+        minimal_states,
+        minimal_partial_states,
+    })
+}
+
+/// A path that reaches a cycle and can leave it towards acceptance, proving
+/// the language is infinite: every word `stem · cycle^n · suffix` is accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lasso {
+    /// Transitions from the start state to the first state of the cycle.
+    pub stem: Vec<Edge>,
+    /// Transitions around the cycle, returning to where they began.
+    pub cycle: Vec<Edge>,
+    /// Transitions from the cycle to an accepting state.
+    pub suffix: Vec<Edge>,
+}
+
+impl Lasso {
+    /// Spells the accepted word that goes around the cycle `times` times.
+    ///
+    /// # Arguments
+    ///
+    /// - `times` (`usize`) - How often to repeat the cycle.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The word `stem · cycle^times · suffix`.
+    #[must_use]
+    pub fn pumped(&self, times: usize) -> String {
+        let mut word = spell(&self.stem);
+        word.push_str(&spell(&self.cycle).repeat(times));
+        word.push_str(&spell(&self.suffix));
+        word
+    }
+}
+
+/// Concatenates the symbols along a path, skipping epsilon labels.
+///
+/// # Arguments
+///
+/// - `path` (`&[Edge]`) - The transitions to read.
+///
+/// # Returns
+///
+/// - `String` - The spelled word.
+#[must_use]
+pub fn spell(path: &[Edge]) -> String {
+    path.iter()
+        .filter_map(|edge| match edge.label {
+            EdgeLabel::Sym(symbol) => Some(symbol),
+            EdgeLabel::Eps => None,
+        })
+        .collect()
+}
+
+/// Computes the strongly connected components of a DFA.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton to analyse.
+///
+/// # Returns
+///
+/// - `SccAnalysis` - The components, condensation and per-component flags.
+pub fn sccs(dfa: &Dfa) -> SccAnalysis {
+    let successors: Vec<Vec<StateId>> = dfa.trans.clone();
+    SccAnalysis::compute(&successors, &dfa.accepts)
+}
+
+/// Computes the strongly connected components of an NFA, following epsilon
+/// and symbol transitions alike.
+///
+/// # Arguments
+///
+/// - `nfa` (`&Nfa`) - The automaton to analyse.
+///
+/// # Returns
+///
+/// - `SccAnalysis` - The components, condensation and per-component flags.
+pub fn nfa_sccs(nfa: &Nfa) -> SccAnalysis {
+    let successors: Vec<Vec<StateId>> = nfa
+        .adjacency
+        .iter()
+        .map(|row| row.iter().map(|tr| tr.to).collect())
+        .collect();
+    SccAnalysis::compute(&successors, &nfa.accepts)
+}
+
+/// Measures the size of an NFA.
+///
+/// # Arguments
+///
+/// - `nfa` (`&Nfa`) - The automaton to measure.
+///
+/// # Returns
+///
+/// - `AutomatonStats` - Its state, transition, dead and unreachable counts.
+#[must_use]
+pub fn nfa_stats(nfa: &Nfa) -> AutomatonStats {
+    let successors: Vec<Vec<StateId>> = nfa
+        .adjacency
+        .iter()
+        .map(|row| row.iter().map(|tr| tr.to).collect())
+        .collect();
+    let epsilon_edges = nfa
+        .edges
+        .iter()
+        .filter(|edge| edge.label == EdgeLabel::Eps)
+        .count();
+    AutomatonStats::compute(
+        &successors,
+        nfa.start,
+        &nfa.accepts,
+        nfa.edges.len(),
+        epsilon_edges,
+    )
+}
+
+/// Measures the size of a DFA, counting one transition per state and symbol.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton to measure.
+///
+/// # Returns
+///
+/// - `AutomatonStats` - Its state, transition, dead and unreachable counts.
+#[must_use]
+pub fn dfa_stats(dfa: &Dfa) -> AutomatonStats {
+    let edges = dfa.trans.iter().map(Vec::len).sum();
+    AutomatonStats::compute(&dfa.trans, dfa.start, &dfa.accepts, edges, 0)
+}
+
+/// How often each symbol labels a transition, and how busy each state is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionHeat {
+    /// Number of transitions on each symbol, in ascending symbol order.
+    pub symbols: Vec<(char, usize)>,
+    /// Number of ε transitions.
+    pub epsilon: usize,
+    /// Number of transitions entering each state, indexed by state id.
+    pub in_degree: Vec<usize>,
+    /// Number of transitions leaving each state, indexed by state id.
+    pub out_degree: Vec<usize>,
+}
+
+impl TransitionHeat {
+    /// Counts `edges` between `states` states.
+    fn compute(states: usize, edges: impl IntoIterator<Item = Edge>) -> Self {
+        let mut symbols = BTreeMap::new();
+        let mut epsilon = 0;
+        let mut in_degree = vec![0; states];
+        let mut out_degree = vec![0; states];
+        for edge in edges {
+            match edge.label {
+                EdgeLabel::Sym(symbol) => *symbols.entry(symbol).or_insert(0) += 1,
+                EdgeLabel::Eps => epsilon += 1,
+            }
+            out_degree[edge.from as usize] += 1;
+            in_degree[edge.to as usize] += 1;
+        }
+        Self {
+            symbols: symbols.into_iter().collect(),
+            epsilon,
+            in_degree,
+            out_degree,
+        }
+    }
+
+    /// Returns the number of transitions entering or leaving `state`; a
+    /// self-loop counts twice.
+    #[must_use]
+    pub fn degree(&self, state: StateId) -> usize {
+        self.in_degree[state as usize] + self.out_degree[state as usize]
+    }
+
+    /// Returns the highest [`TransitionHeat::degree`] of any state.
+    #[must_use]
+    pub fn busiest(&self) -> usize {
+        (0..self.in_degree.len() as StateId)
+            .map(|state| self.degree(state))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Counts the transitions of an NFA per symbol and per state.
+///
+/// # Arguments
+///
+/// - `nfa` (`&Nfa`) - The automaton to measure.
+///
+/// # Returns
+///
+/// - `TransitionHeat` - Symbol counts and the in and out degree of every state.
+#[must_use]
+pub fn nfa_heat(nfa: &Nfa) -> TransitionHeat {
+    TransitionHeat::compute(nfa.states.len(), nfa.edges.iter().cloned())
+}
+
+/// Counts the transitions of a DFA per symbol and per state.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton to measure.
+///
+/// # Returns
+///
+/// - `TransitionHeat` - Symbol counts and the in and out degree of every state.
+#[must_use]
+pub fn dfa_heat(dfa: &Dfa) -> TransitionHeat {
+    let edges = dfa.transitions().map(|(from, symbol, to)| Edge {
+        from,
+        to,
+        label: EdgeLabel::Sym(symbol),
+    });
+    TransitionHeat::compute(dfa.trans.len(), edges)
+}
+
+/// Finds words that no DFA for the language of `dfa` can send to the same
+/// state, proving a lower bound on the number of states.
+///
+/// Every pair of words is told apart by some suffix accepted after one word
+/// but not the other, so any DFA reading them must end in different states.
+/// One access word is taken per state of the minimal complete DFA, so the
+/// bound is tight: the set has exactly as many words as that DFA has states.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - A complete DFA for the language.
+///
+/// # Returns
+///
+/// - `Vec<String>` - The words, shortest first, starting with the empty word.
+#[must_use]
+pub fn fooling_set(dfa: &Dfa) -> Vec<String> {
+    min::minimize(dfa)
+        .access_words()
+        .into_iter()
+        .map(|(_, word)| word)
+        .collect()
+}
+
+/// Finds a lasso witnessing that the DFA accepts infinitely many words.
+///
+/// The cycle is entered as close to the start state as possible, and the
+/// stem, cycle and suffix are each the shortest available.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton to inspect.
+///
+/// # Returns
+///
+/// - `Option<Lasso>` - The witness, or `None` if the language is finite.
+pub fn infinite_witness(dfa: &Dfa) -> Option<Lasso> {
+    let analysis = sccs(dfa);
+    let distance = distances_from_start(dfa);
+
+    // A pumpable state is reachable, on a cycle, and can still reach acceptance.
+    let entry = (0..dfa.trans.len())
+        .filter(|&state| {
+            let component = analysis.component_of[state];
+            distance[state].is_some()
+                && analysis.cyclic[component]
+                && analysis.accepting_reachable[component]
+        })
+        .min_by_key(|&state| distance[state])? as StateId;
+    let component = analysis.component_of[entry as usize];
+
+    let stem = if entry == dfa.start {
+        Vec::new()
+    } else {
+        shortest_path(dfa, dfa.start, |_| true, |state| state == entry)?
+    };
+    let cycle = shortest_path(
+        dfa,
+        entry,
+        |state| analysis.component_of[state as usize] == component,
+        |state| state == entry,
+    )?;
+    let suffix = if dfa.accepts.contains(&entry) {
+        Vec::new()
+    } else {
+        shortest_path(dfa, entry, |_| true, |state| dfa.accepts.contains(&state))?
+    };
+
+    Some(Lasso {
+        stem,
+        cycle,
+        suffix,
+    })
+}
+
+/// Lists the states reachable from `state`, including `state` itself.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton to inspect.
+/// - `state` (`StateId`) - Where to start.
+///
+/// # Returns
+///
+/// - `Vec<StateId>` - The reachable states in ascending order.
+pub fn reachable_from(dfa: &Dfa, state: StateId) -> Vec<StateId> {
+    let mut seen = vec![false; dfa.trans.len()];
+    let Some(slot) = seen.get_mut(state as usize) else {
+        return Vec::new();
+    };
+    *slot = true;
+    let mut stack = vec![state];
+    while let Some(current) = stack.pop() {
+        for &next in &dfa.trans[current as usize] {
+            if !seen[next as usize] {
+                seen[next as usize] = true;
+                stack.push(next);
+            }
+        }
+    }
+    marked_states(&seen)
+}
+
+/// Lists the states on some path from the start state through `state` to an
+/// accepting state.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton to inspect.
+/// - `state` (`StateId`) - The state every path must pass through.
+///
+/// # Returns
+///
+/// - `Vec<StateId>` - The states in ascending order; empty if no accepting
+///   path passes through `state`.
+pub fn accepting_paths_through(dfa: &Dfa, state: StateId) -> Vec<StateId> {
+    let count = dfa.trans.len();
+    if state as usize >= count {
+        return Vec::new();
+    }
+
+    let mut predecessors = vec![Vec::new(); count];
+    for (from, row) in dfa.trans.iter().enumerate() {
+        for &to in row {
+            predecessors[to as usize].push(from as StateId);
+        }
+    }
+    let backward = |seeds: &[StateId]| {
+        let mut seen = vec![false; count];
+        let mut stack = seeds.to_vec();
+        for seed in seeds {
+            seen[*seed as usize] = true;
+        }
+        while let Some(current) = stack.pop() {
+            for &prev in &predecessors[current as usize] {
+                if !seen[prev as usize] {
+                    seen[prev as usize] = true;
+                    stack.push(prev);
+                }
+            }
+        }
+        seen
+    };
+
+    let productive = backward(&dfa.accepts);
+    let leads_here = backward(&[state]);
+    if !productive[state as usize] || !leads_here[dfa.start as usize] {
+        return Vec::new();
+    }
+
+    let forward = reachable_from(dfa, state);
+    let start_reaches = reachable_from(dfa, dfa.start);
+    let mut on_path = vec![false; count];
+    for &before in &start_reaches {
+        on_path[before as usize] = leads_here[before as usize];
+    }
+    for &after in &forward {
+        on_path[after as usize] |= productive[after as usize];
+    }
+    marked_states(&on_path)
+}
+
+/// Flags the states reachable from `seeds`, the seeds included.
+fn mark_reachable(successors: &[Vec<StateId>], seeds: &[StateId]) -> Vec<bool> {
+    let mut seen = vec![false; successors.len()];
+    let mut stack = Vec::new();
+    for &seed in seeds {
+        if let Some(slot) = seen.get_mut(seed as usize) {
+            *slot = true;
+            stack.push(seed);
+        }
+    }
+    while let Some(current) = stack.pop() {
+        for &next in &successors[current as usize] {
+            if !seen[next as usize] {
+                seen[next as usize] = true;
+                stack.push(next);
+            }
+        }
+    }
+    seen
+}
+
+/// Collects the indices of the set flags.
+fn marked_states(flags: &[bool]) -> Vec<StateId> {
+    flags
+        .iter()
+        .enumerate()
+        .filter(|(_, marked)| **marked)
+        .map(|(state, _)| state as StateId)
+        .collect()
+}
+
+/// One element of a transition monoid: the function a word induces on states.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonoidElement {
+    /// The shortest, then alphabetically first, word inducing the function.
+    pub word: String,
+    /// The state reached from each state after reading `word`, indexed by state id.
+    pub map: Vec<StateId>,
+    /// Whether applying the function twice is the same as applying it once.
+    pub idempotent: bool,
+}
+
+/// The transition monoid of a DFA: every distinct function on states induced
+/// by some input word, under composition.
+///
+/// For a minimal DFA this is the syntactic monoid of its language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionMonoid {
+    /// The elements in shortlex order of their words, starting with the
+    /// identity induced by the empty word.
+    pub elements: Vec<MonoidElement>,
+    /// Whether enumeration stopped at the limit before finding every element.
+    pub truncated: bool,
+}
+
+impl TransitionMonoid {
+    /// Returns the number of elements found.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns the idempotent elements.
+    pub fn idempotents(&self) -> impl Iterator<Item = &MonoidElement> {
+        self.elements.iter().filter(|element| element.idempotent)
+    }
+}
+
+/// Enumerates the transition monoid of a DFA.
+///
+/// Words are extended breadth-first, reading symbols in sorted order, and a
+/// word is kept only when it induces a function not seen before. Enumeration
+/// stops after `limit` elements, since a monoid can have up to `n^n` of them.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton, which must have a complete transition table.
+/// - `limit` (`usize`) - Maximum number of elements to return.
+///
+/// # Returns
+///
+/// - `Result<TransitionMonoid, DfaError>` - The monoid, or why the DFA is malformed.
+pub fn transition_monoid(dfa: &Dfa, limit: usize) -> Result<TransitionMonoid, DfaError> {
+    dfa.validate()?;
+    let mut columns: Vec<usize> = (0..dfa.alphabet.len()).collect();
+    columns.sort_by_key(|&col| dfa.alphabet[col]);
+
+    let element = |word: String, map: Vec<StateId>| MonoidElement {
+        idempotent: map.iter().all(|&next| map[next as usize] == next),
+        word,
+        map,
+    };
+    let identity: Vec<StateId> = (0..dfa.trans.len() as StateId).collect();
+    let mut seen = HashSet::from([identity.clone()]);
+    let mut elements = vec![element(String::new(), identity)];
+    let mut truncated = false;
+    let mut next_idx = 0;
+    'search: while next_idx < elements.len() {
+        for &col in &columns {
+            let map: Vec<StateId> = elements[next_idx]
+                .map
+                .iter()
+                .map(|&state| dfa.trans[state as usize][col])
+                .collect();
+            if seen.contains(&map) {
+                continue;
+            }
+            if elements.len() == limit {
+                truncated = true;
+                break 'search;
+            }
+            let mut word = elements[next_idx].word.clone();
+            word.push(dfa.alphabet[col]);
+            seen.insert(map.clone());
+            elements.push(element(word, map));
+        }
+        next_idx += 1;
+    }
+    Ok(TransitionMonoid {
+        elements,
+        truncated,
+    })
+}
+
+/// Enumerates simple cycles among states reachable from the start state.
+///
+/// Each cycle is reported once, beginning at its smallest state. Parallel
+/// transitions between the same pair of states are represented by the one
+/// with the first symbol in the alphabet. Enumeration stops after `limit`
+/// cycles, since their number can grow exponentially with the state count.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton to inspect.
+/// - `limit` (`usize`) - Maximum number of cycles to return.
+///
+/// # Returns
+///
+/// - `Vec<Vec<Edge>>` - The cycles as transition lists.
+pub fn simple_cycles(dfa: &Dfa, limit: usize) -> Vec<Vec<Edge>> {
+    let analysis = sccs(dfa);
+    let distance = distances_from_start(dfa);
+    let successors: Vec<Vec<Edge>> = (0..dfa.trans.len())
+        .map(|state| {
+            let mut edges: Vec<Edge> = Vec::new();
+            for (idx, symbol) in dfa.alphabet.iter().enumerate() {
+                let to = dfa.trans[state][idx];
+                if !edges.iter().any(|edge| edge.to == to) {
+                    edges.push(Edge {
+                        from: state as StateId,
+                        to,
+                        label: EdgeLabel::Sym(*symbol),
+                    });
+                }
+            }
+            edges
+        })
+        .collect();
+
+    let mut cycles = Vec::new();
+    for (root, reached) in distance.iter().enumerate() {
+        let component = analysis.component_of[root];
+        if reached.is_none() || !analysis.cyclic[component] {
+            continue;
+        }
+        let mut search = CycleSearch {
+            successors: &successors,
+            root: root as StateId,
+            allowed: |state: StateId| {
+                state as usize > root && analysis.component_of[state as usize] == component
+            },
+            on_path: vec![false; dfa.trans.len()],
+            path: Vec::new(),
+            cycles: &mut cycles,
+            limit,
+        };
+        search.visit(root as StateId);
+        if cycles.len() >= limit {
+            break;
+        }
+    }
+    cycles
+}
+
+/// Depth-first enumeration of the simple cycles through one root state.
+struct CycleSearch<'a, F> {
+    successors: &'a [Vec<Edge>],
+    root: StateId,
+    allowed: F,
+    on_path: Vec<bool>,
+    path: Vec<Edge>,
+    cycles: &'a mut Vec<Vec<Edge>>,
+    limit: usize,
+}
+
+impl<F: Fn(StateId) -> bool> CycleSearch<'_, F> {
+    fn visit(&mut self, state: StateId) {
+        self.on_path[state as usize] = true;
+        for edge in &self.successors[state as usize] {
+            if self.cycles.len() >= self.limit {
+                break;
+            }
+            self.path.push(*edge);
+            if edge.to == self.root {
+                self.cycles.push(self.path.clone());
+            } else if (self.allowed)(edge.to) && !self.on_path[edge.to as usize] {
+                self.visit(edge.to);
+            }
+            self.path.pop();
+        }
+        self.on_path[state as usize] = false;
+    }
+}
+
+/// Breadth-first distances from the start state; `None` marks unreachable states.
+fn distances_from_start(dfa: &Dfa) -> Vec<Option<usize>> {
+    let mut distance = vec![None; dfa.trans.len()];
+    let Some(slot) = distance.get_mut(dfa.start as usize) else {
+        return distance;
+    };
+    *slot = Some(0);
+    let mut queue = VecDeque::from([dfa.start]);
+    while let Some(state) = queue.pop_front() {
+        let next_distance = distance[state as usize].map(|d| d + 1);
+        for &next in &dfa.trans[state as usize] {
+            if distance[next as usize].is_none() {
+                distance[next as usize] = next_distance;
+                queue.push_back(next);
+            }
+        }
+    }
+    distance
+}
+
+/// Finds a shortest non-empty path from `from` to a state satisfying `goal`,
+/// passing only through intermediate states satisfying `allowed`.
+fn shortest_path(
+    dfa: &Dfa,
+    from: StateId,
+    allowed: impl Fn(StateId) -> bool,
+    goal: impl Fn(StateId) -> bool,
+) -> Option<Vec<Edge>> {
+    let mut parent: Vec<Option<Edge>> = vec![None; dfa.trans.len()];
+    let mut queue = VecDeque::from([from]);
+    while let Some(state) = queue.pop_front() {
+        for (idx, symbol) in dfa.alphabet.iter().enumerate() {
+            let to = dfa.trans[state as usize][idx];
+            let edge = Edge {
+                from: state,
+                to,
+                label: EdgeLabel::Sym(*symbol),
+            };
+            if goal(to) {
+                let mut path = vec![edge];
+                let mut cursor = state;
+                while cursor != from {
+                    let previous = parent[cursor as usize]?;
+                    path.push(previous);
+                    cursor = previous.from;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if to != from && allowed(to) && parent[to as usize].is_none() {
+                parent[to as usize] = Some(edge);
+                queue.push_back(to);
+            }
+        }
+    }
+    None
+}
+
+impl SccAnalysis {
+    /// Runs Tarjan's algorithm over a successor list and derives the condensation.
+    ///
+    /// # Arguments
+    ///
+    /// - `successors` (`&[Vec<StateId>]`) - Outgoing neighbours of every state.
+    /// - `accepts` (`&[StateId]`) - The accepting states.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The complete analysis.
+    fn compute(successors: &[Vec<StateId>], accepts: &[StateId]) -> Self {
+        // Tarjan emits sinks first; reverse to list sources first.
+        let mut components = tarjan(successors);
+        components.reverse();
+
+        let mut component_of = vec![0; successors.len()];
+        for (idx, component) in components.iter().enumerate() {
+            for &state in component {
+                component_of[state as usize] = idx;
+            }
+        }
+
+        let mut condensation = vec![Vec::new(); components.len()];
+        let mut cyclic = vec![false; components.len()];
+        for (idx, component) in components.iter().enumerate() {
+            cyclic[idx] = component.len() > 1;
+            for &state in component {
+                for &next in &successors[state as usize] {
+                    let target = component_of[next as usize];
+                    if target == idx {
+                        cyclic[idx] = true;
+                    } else {
+                        condensation[idx].push(target);
+                    }
+                }
+            }
+            condensation[idx].sort_unstable();
+            condensation[idx].dedup();
+        }
+
+        // Successors always have higher indices, so a backward sweep sees them first.
+        let mut accepting_reachable = vec![false; components.len()];
+        for idx in (0..components.len()).rev() {
+            accepting_reachable[idx] = components[idx].iter().any(|state| accepts.contains(state))
+                || condensation[idx]
+                    .iter()
+                    .any(|&next| accepting_reachable[next]);
+        }
+
+        Self {
+            components,
+            component_of,
+            condensation,
+            cyclic,
+            accepting_reachable,
+        }
+    }
+}
+
+/// Iterative Tarjan's algorithm, returning components in reverse topological order.
+fn tarjan(successors: &[Vec<StateId>]) -> Vec<Vec<StateId>> {
+    let count = successors.len();
+    let mut index: Vec<Option<usize>> = vec![None; count];
+    let mut low = vec![0; count];
+    let mut on_stack = vec![false; count];
+    let mut stack: Vec<usize> = Vec::new();
+    let mut components = Vec::new();
+    let mut next_index = 0;
+
+    for root in 0..count {
+        if index[root].is_some() {
+            continue;
+        }
+
+        // Each frame is a state and the position of the next successor to visit.
+        let mut call_stack = vec![(root, 0)];
+        index[root] = Some(next_index);
+        low[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some((state, child)) = call_stack.last_mut() {
+            let state = *state;
+            if let Some(&next) = successors[state].get(*child) {
+                *child += 1;
+                let next = next as usize;
+                match index[next] {
+                    None => {
+                        index[next] = Some(next_index);
+                        low[next] = next_index;
+                        next_index += 1;
+                        stack.push(next);
+                        on_stack[next] = true;
+                        call_stack.push((next, 0));
+                    }
+                    Some(next_idx) if on_stack[next] => {
+                        low[state] = low[state].min(next_idx);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            call_stack.pop();
+            if let Some(&(parent, _)) = call_stack.last() {
+                low[parent] = low[parent].min(low[state]);
+            }
+            if Some(low[state]) == index[state] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component.push(member as StateId);
+                    if member == state {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                components.push(component);
+            }
+        }
+    }
+
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{dfa::determinize, min::minimize, parser::Ast, sim};
+
+    fn analyse(pattern: &str) -> (Dfa, SccAnalysis) {
+        let dfa = minimize(&determinize(&Nfa::build(&Ast::build(pattern).unwrap())));
+        let analysis = sccs(&dfa);
+        (dfa, analysis)
+    }
+
+    #[test]
+    fn test_sccs_topological_order() {
+        let (dfa, analysis) = analyse("ab*c");
+        // Start is a source, so it sits in the first component.
+        assert_eq!(analysis.component_of[dfa.start as usize], 0);
+        for (idx, successors) in analysis.condensation.iter().enumerate() {
+            assert!(successors.iter().all(|&next| next > idx));
+        }
+        let covered: usize = analysis.components.iter().map(Vec::len).sum();
+        assert_eq!(covered, dfa.states.len());
+    }
+
+    #[test]
+    fn test_sccs_flags() {
+        let (dfa, analysis) = analyse("ab*c");
+        let after_a = dfa.trans[dfa.start as usize][0];
+        let loop_component = analysis.component_of[after_a as usize];
+        assert!(analysis.cyclic[loop_component]);
+        assert!(analysis.accepting_reachable[loop_component]);
+        assert!(!analysis.cyclic[analysis.component_of[dfa.start as usize]]);
+
+        // The dead state loops on itself but can never accept.
+        let dead = dfa.trans[dfa.start as usize][1];
+        let dead_component = analysis.component_of[dead as usize];
+        assert!(analysis.cyclic[dead_component]);
+        assert!(!analysis.accepting_reachable[dead_component]);
+    }
+
+    #[test]
+    fn test_infinite_witness_pumps() {
+        let (dfa, _) = analyse("ab*c");
+        let lasso = infinite_witness(&dfa).expect("language is infinite");
+        assert_eq!(spell(&lasso.stem), "a");
+        assert_eq!(spell(&lasso.cycle), "b");
+        assert_eq!(spell(&lasso.suffix), "c");
+        assert_eq!(
+            lasso.cycle.first().unwrap().from,
+            lasso.cycle.last().unwrap().to
+        );
+        assert_eq!(lasso.pumped(3), "abbbc");
+
+        let (finite, _) = analyse("ab+cd");
+        assert!(infinite_witness(&finite).is_none());
+    }
+
+    #[test]
+    fn test_simple_cycles() {
+        let (dfa, _) = analyse("(ab)*");
+        // The (ab) loop plus the dead state's self-loop.
+        let cycles = simple_cycles(&dfa, 10);
+        let mut words: Vec<String> = cycles.iter().map(|cycle| spell(cycle)).collect();
+        words.sort();
+        assert_eq!(words, ["a", "ab"]);
+        assert_eq!(simple_cycles(&dfa, 1).len(), 1);
+    }
+
+    #[test]
+    fn test_reachable_and_accepting_paths_through() {
+        let (dfa, _) = analyse("a(b+c)");
+        let after_a = dfa.trans[dfa.start as usize][0];
+        let dead = dfa.trans[dfa.start as usize][1];
+        let accept = dfa.accepts[0];
+
+        assert_eq!(reachable_from(&dfa, dead), [dead]);
+        let from_a = reachable_from(&dfa, after_a);
+        assert!(from_a.contains(&accept) && from_a.contains(&dead));
+
+        let mut expected = vec![dfa.start, after_a, accept];
+        expected.sort_unstable();
+        assert_eq!(accepting_paths_through(&dfa, after_a), expected);
+        assert!(accepting_paths_through(&dfa, dead).is_empty());
+    }
+
+    #[test]
+    fn test_nfa_sccs_star_loop() {
+        let nfa = Nfa::build(&Ast::build("a*").unwrap());
+        let analysis = nfa_sccs(&nfa);
+        // The literal's start and accept form a cycle through the star's loop-back edge.
+        assert_eq!(analysis.component_of[0], analysis.component_of[1]);
+        assert!(analysis.cyclic[analysis.component_of[0]]);
+    }
+
+    #[test]
+    fn test_nfa_stats() {
+        let nfa = Nfa::build(&Ast::build("a*").unwrap());
+        let stats = nfa_stats(&nfa);
+        assert_eq!(stats.states, nfa.states.len());
+        assert_eq!(stats.edges, nfa.edges.len());
+        assert_eq!(stats.epsilon_edges, stats.edges - 1);
+        assert_eq!((stats.dead, stats.unreachable), (0, 0));
+    }
+
+    #[test]
+    fn test_audit_minimal_dfa() {
+        let (dfa, _) = analyse("(a+b)*abb");
+        let report = audit(&dfa).unwrap();
+        assert!(report.complete && report.minimal);
+        assert_eq!(report.minimal_states, dfa.states.len());
+        assert!(report.unreachable.is_empty());
+        assert!(report.dead.is_empty());
+    }
+
+    #[test]
+    fn test_audit_reports_redundant_states() {
+        let mut dfa = determinize(&Nfa::build(&Ast::build("ab").unwrap()));
+        let before = dfa.states.len();
+        // An extra state nothing leads to.
+        let orphan = dfa.trans.len() as StateId;
+        dfa.states.push(orphan);
+        dfa.trans.push(vec![orphan; dfa.alphabet.len()]);
+
+        let report = audit(&dfa).unwrap();
+        assert_eq!(report.unreachable, vec![orphan]);
+        assert_eq!(report.dead.len(), 1);
+        assert!(!report.minimal);
+        assert_eq!(report.minimal_states, before);
+    }
+
+    #[test]
+    fn test_audit_partial_rows() {
+        // Accepts exactly "a" over {a, b}, with no transitions out of state 1.
+        let dfa = Dfa {
+            states: vec![0, 1],
+            start: 0,
+            accepts: vec![1],
+            trans: vec![vec![1], vec![]],
+            alphabet: vec!['a', 'b'],
+        };
+        let report = audit(&dfa).unwrap();
+        assert!(!report.complete && report.minimal);
+        assert_eq!(report.missing, vec![(0, 'b'), (1, 'a'), (1, 'b')]);
+        // Completing it adds the dead sink state.
+        assert_eq!(report.minimal_states, 3);
+        assert_eq!(report.minimal_partial_states, 2);
+        assert!(report.to_string().contains("minimal      yes\n"));
+        assert!(
+            report
+                .to_string()
+                .contains("complete     no, missing δ(0, b), δ(1, a), δ(1, b)\n")
+        );
+        assert_eq!(dfa.to_string(), "   δ  a  b\n→  0  1  -\n*  1  -  -\n");
+
+        let broken = Dfa {
+            trans: vec![vec![1], vec![7]],
+            ..dfa
+        };
+        assert_eq!(audit(&broken), Err(DfaError::UnknownState(7)));
+    }
+
+    #[test]
+    fn test_audit_partial_dfa_with_redundant_states() {
+        // Accepts a+ over {a, b}: states 1 and 2 are equivalent.
+        let dfa = Dfa {
+            states: vec![0, 1, 2],
+            start: 0,
+            accepts: vec![1, 2],
+            trans: vec![vec![1], vec![2], vec![1]],
+            alphabet: vec!['a', 'b'],
+        };
+        let report = audit(&dfa).unwrap();
+        assert!(!report.complete && !report.minimal);
+        assert_eq!(report.minimal_states, 3);
+        assert_eq!(report.minimal_partial_states, 2);
+        assert!(
+            report
+                .to_string()
+                .contains("minimal      no, the minimal partial DFA has 2 states\n")
+        );
+    }
+
+    #[test]
+    fn test_heat() {
+        let nfa = Nfa::build(&Ast::build("ab+a").unwrap());
+        let heat = nfa_heat(&nfa);
+        assert_eq!(heat.symbols, vec![('a', 2), ('b', 1)]);
+        assert_eq!(heat.epsilon, nfa_stats(&nfa).epsilon_edges);
+        assert_eq!(heat.in_degree.iter().sum::<usize>(), nfa.edges.len());
+        assert_eq!(heat.out_degree.iter().sum::<usize>(), nfa.edges.len());
+
+        let (dfa, _) = analyse("(a+b)*abb");
+        let heat = dfa_heat(&dfa);
+        // Complete DFAs use every symbol once per state.
+        assert_eq!(heat.symbols, vec![('a', 4), ('b', 4)]);
+        assert!(heat.out_degree.iter().all(|&out| out == 2));
+        // The state after reading `a` is entered from every state.
+        let after_a = dfa.trans[dfa.start as usize][0];
+        assert_eq!(heat.in_degree[after_a as usize], 4);
+        assert_eq!(heat.busiest(), heat.degree(after_a));
+    }
+
+    #[test]
+    fn test_fooling_set() {
+        let dfa = determinize(&Nfa::build(&Ast::build("(a+b)*abb").unwrap()));
+        let words = fooling_set(&dfa);
+        assert_eq!(words, vec!["", "a", "ab", "abb"]);
+
+        // Each pair is separated by a suffix accepted after exactly one of them.
+        let min = minimize(&dfa);
+        let suffixes = ["", "b", "bb", "abb"];
+        for (i, x) in words.iter().enumerate() {
+            for y in &words[i + 1..] {
+                assert!(suffixes.iter().any(|suffix| {
+                    sim::simulate_dfa(&min, &format!("{x}{suffix}"))
+                        != sim::simulate_dfa(&min, &format!("{y}{suffix}"))
+                }));
+            }
+        }
+    }
+
+    #[test]
+    fn test_transition_monoid() {
+        // Words over {a} counted modulo 2: the monoid is the group Z/2.
+        let (dfa, _) = analyse("(aa)*");
+        let monoid = transition_monoid(&dfa, 100).unwrap();
+        assert!(!monoid.truncated);
+        let words: Vec<&str> = monoid.elements.iter().map(|e| e.word.as_str()).collect();
+        assert_eq!(words, vec!["", "a"]);
+        assert_eq!(monoid.idempotents().count(), 1);
+
+        // Each element sends the start state where its word leads, and no two
+        // elements induce the same function.
+        let (dfa, _) = analyse("a*b");
+        let monoid = transition_monoid(&dfa, 100).unwrap();
+        for element in &monoid.elements {
+            let reached = dfa.trace(&element.word).unwrap();
+            assert_eq!(element.map[dfa.start as usize], *reached.last().unwrap());
+        }
+        let mut maps: Vec<_> = monoid.elements.iter().map(|e| e.map.clone()).collect();
+        maps.sort();
+        maps.dedup();
+        assert_eq!(maps.len(), monoid.size());
+
+        let capped = transition_monoid(&dfa, 2).unwrap();
+        assert!(capped.truncated);
+        assert_eq!(capped.size(), 2);
+    }
+
+    #[test]
+    fn test_dfa_stats() {
+        let (dfa, _) = analyse("a(b+c)");
+        let stats = dfa_stats(&dfa);
+        assert_eq!(stats.states, dfa.states.len());
+        assert_eq!(stats.edges, dfa.states.len() * dfa.alphabet.len());
+        assert_eq!(stats.epsilon_edges, 0);
+        assert_eq!((stats.dead, stats.unreachable), (1, 0));
+
+        let orphaned = Dfa {
+            states: vec![0, 1, 2],
+            start: 0,
+            accepts: vec![1],
+            trans: vec![vec![1], vec![1], vec![0]],
+            alphabet: vec!['a'],
+        };
+        let stats = dfa_stats(&orphaned);
+        assert_eq!((stats.dead, stats.unreachable), (0, 1));
+    }
+}
@@ -1,4 +1,8 @@
+use std::collections::BTreeSet;
 use std::fmt;
+use std::str::FromStr;
+
+use crate::errors::EpsilonGlyphError;
 
 /// Identifier type for automaton states.
 pub type StateId = u32;
@@ -6,6 +10,62 @@ pub type StateId = u32;
 /// Identifier type for bounding boxes surrounding states during visualization.
 pub type BoxId = u32;
 
+/// Notation used to display the empty string.
+///
+/// Textbooks differ between `ε` and `λ`; the choice only affects rendering.
+/// The lexer accepts every spelling regardless of the configured glyph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EpsilonGlyph {
+    /// Greek epsilon, `ε`.
+    #[default]
+    Epsilon,
+    /// Greek lambda, `λ`.
+    Lambda,
+}
+
+impl EpsilonGlyph {
+    /// Every supported glyph, in display order.
+    pub const ALL: [EpsilonGlyph; 2] = [EpsilonGlyph::Epsilon, EpsilonGlyph::Lambda];
+
+    /// Returns the character drawn for the empty string.
+    #[must_use]
+    pub const fn symbol(self) -> char {
+        match self {
+            EpsilonGlyph::Epsilon => 'ε',
+            EpsilonGlyph::Lambda => 'λ',
+        }
+    }
+
+    /// Returns `word`, or the glyph itself when `word` is empty.
+    #[must_use]
+    pub fn or_word(self, word: &str) -> String {
+        if word.is_empty() {
+            self.symbol().to_string()
+        } else {
+            word.to_string()
+        }
+    }
+}
+
+impl fmt::Display for EpsilonGlyph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+impl FromStr for EpsilonGlyph {
+    type Err = EpsilonGlyphError;
+
+    /// Accepts the glyph's name (`epsilon`, `lambda`) or the glyph itself.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "epsilon" | "ε" => Ok(EpsilonGlyph::Epsilon),
+            "lambda" | "λ" => Ok(EpsilonGlyph::Lambda),
+            _ => Err(EpsilonGlyphError(s.to_string())),
+        }
+    }
+}
+
 /// Labels describing the kind of transition between states.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EdgeLabel {
@@ -15,22 +75,152 @@ pub enum EdgeLabel {
     Sym(char),
 }
 
-impl From<EdgeLabel> for String {
-    fn from(label: EdgeLabel) -> Self {
-        match label {
-            EdgeLabel::Eps => "ε".to_string(),
+impl EdgeLabel {
+    /// Renders the label, drawing ε transitions with `glyph`.
+    #[must_use]
+    pub fn to_string_with(self, glyph: EpsilonGlyph) -> String {
+        match self {
+            EdgeLabel::Eps => glyph.to_string(),
             EdgeLabel::Sym(c) => c.to_string(),
         }
     }
 }
 
+impl From<EdgeLabel> for String {
+    fn from(label: EdgeLabel) -> Self {
+        label.to_string_with(EpsilonGlyph::default())
+    }
+}
+
 impl fmt::Display for EdgeLabel {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            EdgeLabel::Eps => write!(f, "ε"),
-            EdgeLabel::Sym(c) => write!(f, "{c}"),
+        write!(f, "{}", self.to_string_with(EpsilonGlyph::default()))
+    }
+}
+
+/// Labels of several transitions between the same two states, drawn as one edge.
+///
+/// Formatting is shared by every view: ε comes first, single symbols are quoted
+/// (`'a', 'b'`) and runs of three or more consecutive letters or digits are
+/// collapsed into ranges (`[a-c]`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EdgeLabelSet {
+    epsilon: bool,
+    symbols: BTreeSet<char>,
+}
+
+impl EdgeLabelSet {
+    /// Creates an empty set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a label to the set.
+    pub fn insert(&mut self, label: EdgeLabel) {
+        match label {
+            EdgeLabel::Eps => self.epsilon = true,
+            EdgeLabel::Sym(c) => {
+                self.symbols.insert(c);
+            }
+        }
+    }
+
+    /// Returns whether the set holds `label`.
+    #[must_use]
+    pub fn contains(&self, label: EdgeLabel) -> bool {
+        match label {
+            EdgeLabel::Eps => self.epsilon,
+            EdgeLabel::Sym(c) => self.symbols.contains(&c),
         }
     }
+
+    /// Returns whether the set holds no labels.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        !self.epsilon && self.symbols.is_empty()
+    }
+
+    /// Returns the labels in display order: ε first, then symbols ascending.
+    pub fn labels(&self) -> impl Iterator<Item = EdgeLabel> + '_ {
+        self.epsilon
+            .then_some(EdgeLabel::Eps)
+            .into_iter()
+            .chain(self.symbols.iter().map(|&c| EdgeLabel::Sym(c)))
+    }
+
+    /// Renders the set, drawing ε with `glyph`.
+    ///
+    /// # Arguments
+    ///
+    /// - `glyph` (`EpsilonGlyph`) - Notation for the empty string.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The labels separated by `, `, e.g. `ε, [a-c], 'x'`.
+    #[must_use]
+    pub fn to_string_with(&self, glyph: EpsilonGlyph) -> String {
+        let mut parts = Vec::new();
+        if self.epsilon {
+            parts.push(glyph.to_string());
+        }
+
+        let symbols: Vec<char> = self.symbols.iter().copied().collect();
+        let mut start = 0;
+        while start < symbols.len() {
+            // Extend the run while the next symbol follows on in the same class.
+            let mut end = start;
+            while end + 1 < symbols.len()
+                && u32::from(symbols[end]) + 1 == u32::from(symbols[end + 1])
+                && range_class(symbols[start]).is_some()
+                && range_class(symbols[start]) == range_class(symbols[end + 1])
+            {
+                end += 1;
+            }
+            if end - start >= 2 {
+                parts.push(format!("[{}-{}]", symbols[start], symbols[end]));
+            } else {
+                parts.extend(symbols[start..=end].iter().map(|c| format!("'{c}'")));
+            }
+            start = end + 1;
+        }
+        parts.join(", ")
+    }
+}
+
+/// Groups characters that may be abbreviated as a range together.
+fn range_class(c: char) -> Option<u8> {
+    if c.is_ascii_lowercase() {
+        Some(0)
+    } else if c.is_ascii_uppercase() {
+        Some(1)
+    } else if c.is_ascii_digit() {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+impl FromIterator<EdgeLabel> for EdgeLabelSet {
+    fn from_iter<I: IntoIterator<Item = EdgeLabel>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<EdgeLabel> for EdgeLabelSet {
+    fn extend<I: IntoIterator<Item = EdgeLabel>>(&mut self, iter: I) {
+        for label in iter {
+            self.insert(label);
+        }
+    }
+}
+
+impl fmt::Display for EdgeLabelSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_with(EpsilonGlyph::default()))
+    }
 }
 
 /// A flattened representation of a transition, useful for visualization.
@@ -55,6 +245,7 @@ pub struct Transition {
 
 /// Describes the kind of AST operation represented by a bounding box.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BoxKind {
     Literal,
     Concat,
@@ -85,3 +276,35 @@ pub struct State {
     /// Bounding box this state belongs to.
     pub box_id: Option<BoxId>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(labels: &str) -> EdgeLabelSet {
+        labels.chars().map(EdgeLabel::Sym).collect()
+    }
+
+    #[test]
+    fn test_label_set_formats_symbols_and_ranges() {
+        assert_eq!(set("ba").to_string(), "'a', 'b'");
+        assert_eq!(set("cab").to_string(), "[a-c]");
+        assert_eq!(set("abcdxz0123").to_string(), "[0-3], [a-d], 'x', 'z'");
+        // Consecutive code points of different classes are not merged.
+        assert_eq!(set("XYZ[").to_string(), "[X-Z], '['");
+        assert_eq!(set("+,-").to_string(), "'+', ',', '-'");
+    }
+
+    #[test]
+    fn test_label_set_puts_epsilon_first() {
+        let mut labels = set("a");
+        labels.insert(EdgeLabel::Eps);
+        assert_eq!(labels.to_string_with(EpsilonGlyph::Lambda), "λ, 'a'");
+        assert!(labels.contains(EdgeLabel::Eps));
+        assert_eq!(
+            labels.labels().collect::<Vec<_>>(),
+            vec![EdgeLabel::Eps, EdgeLabel::Sym('a')]
+        );
+        assert!(EdgeLabelSet::new().is_empty());
+    }
+}
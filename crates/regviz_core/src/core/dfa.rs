@@ -1,13 +1,19 @@
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
 
 use indexmap::IndexMap;
 
-use crate::core::automaton::StateId;
+use crate::core::automaton::{Edge, EdgeLabel, StateId};
+use crate::core::min;
 use crate::core::nfa::Nfa;
+use crate::core::parser::Ast;
 use crate::core::sim;
+use crate::core::state_set::StateSet;
+use crate::errors::{BuildError, DfaError};
 
 /// Deterministic finite automaton produced from subset construction.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Dfa {
     /// All DFA state identifiers.
     pub states: Vec<StateId>,
@@ -21,6 +27,987 @@ pub struct Dfa {
     pub alphabet: Vec<char>,
 }
 
+impl Dfa {
+    /// Compiles a regular expression into its minimal DFA, running the same
+    /// parse, Thompson construction, subset construction and minimization
+    /// pipeline as the app.
+    ///
+    /// # Arguments
+    ///
+    /// - `pattern` (`&str`) - The regular expression.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Dfa, BuildError>` - The minimal DFA, or why the pattern failed to parse.
+    pub fn from_pattern(pattern: &str) -> Result<Dfa, BuildError> {
+        let nfa = Nfa::build(&Ast::build(pattern)?);
+        Ok(min::minimize(&determinize(&nfa)))
+    }
+
+    /// Computes a stable fingerprint of the language recognized by this DFA.
+    ///
+    /// The automaton is minimized and renumbered breadth-first from the start
+    /// state (visiting symbols in sorted order) before hashing, so two DFAs
+    /// share a fingerprint whenever they accept the same language over the
+    /// same alphabet. The hash is FNV-1a and does not depend on the Rust
+    /// version or platform, making it safe to persist. Missing transitions of a
+    /// partial DFA lead to a dead sink, so it hashes like its completion.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The canonical fingerprint.
+    #[must_use]
+    pub fn canonical_hash(&self) -> u64 {
+        let canonical = min::minimize(&self.complete_with_sink()).canonicalize();
+        let mut hasher = Fnv1a::new();
+
+        hasher.write_u32(canonical.alphabet.len() as u32);
+        for symbol in &canonical.alphabet {
+            hasher.write_u32(u32::from(*symbol));
+        }
+        hasher.write_u32(canonical.states.len() as u32);
+        for state in &canonical.states {
+            hasher.write_u32(u32::from(canonical.accepts.contains(state)));
+            for next in &canonical.trans[*state as usize] {
+                hasher.write_u32(*next);
+            }
+        }
+        hasher.finish()
+    }
+
+    /// Renumbers the states reachable from the start state in breadth-first order.
+    ///
+    /// Symbols are visited in sorted order and the transition columns are
+    /// reordered to match, so the result depends only on the shape of the
+    /// automaton and not on how its states or alphabet were originally numbered.
+    /// Unreachable states are dropped. Missing transitions are sent to a dead
+    /// sink state, which is numbered like any other state, so the result is
+    /// always complete.
+    ///
+    /// # Returns
+    ///
+    /// - `Dfa` - The renumbered DFA, whose start state is always `0`.
+    #[must_use]
+    pub fn canonicalize(&self) -> Dfa {
+        let mut columns: Vec<usize> = (0..self.alphabet.len()).collect();
+        columns.sort_by_key(|&idx| self.alphabet[idx]);
+
+        // `None` stands for the sink that missing transitions lead to.
+        let target = |state: Option<StateId>, col: usize| {
+            state.and_then(|state| self.trans.get(state as usize)?.get(col).copied())
+        };
+        let mut order: IndexMap<Option<StateId>, StateId> = IndexMap::new();
+        let mut queue = VecDeque::new();
+        order.insert(Some(self.start), 0);
+        queue.push_back(Some(self.start));
+        while let Some(state) = queue.pop_front() {
+            for &col in &columns {
+                let next = target(state, col);
+                if !order.contains_key(&next) {
+                    order.insert(next, order.len() as StateId);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let trans = order
+            .keys()
+            .map(|&old| {
+                columns
+                    .iter()
+                    .map(|&col| order[&target(old, col)])
+                    .collect()
+            })
+            .collect();
+        let mut accepts: Vec<StateId> = self
+            .accepts
+            .iter()
+            .filter_map(|&old| order.get(&Some(old)).copied())
+            .collect();
+        accepts.sort_unstable();
+
+        Dfa {
+            states: (0..order.len() as StateId).collect(),
+            start: 0,
+            accepts,
+            trans,
+            alphabet: columns.iter().map(|&col| self.alphabet[col]).collect(),
+        }
+    }
+
+    /// Lists every transition as a `(from, symbol, to)` triple, by source
+    /// state, then in alphabet order.
+    ///
+    /// Transitions left out of a row shorter than the alphabet are skipped.
+    pub fn transitions(&self) -> impl Iterator<Item = (StateId, char, StateId)> + '_ {
+        self.trans.iter().enumerate().flat_map(|(from, row)| {
+            row.iter()
+                .zip(&self.alphabet)
+                .map(move |(&to, &symbol)| (from as StateId, symbol, to))
+        })
+    }
+
+    /// Lists the state identifiers in ascending order.
+    pub fn states(&self) -> impl ExactSizeIterator<Item = StateId> + '_ {
+        self.states.iter().copied()
+    }
+
+    /// Lists the accepting state identifiers, in the order they are stored.
+    pub fn accept_states(&self) -> impl ExactSizeIterator<Item = StateId> + '_ {
+        self.accepts.iter().copied()
+    }
+
+    /// Runs the DFA on `input` and lists the states it visits.
+    ///
+    /// Unlike [`sim::simulate_dfa`], which rejects on any problem, this says
+    /// why a run could not finish, so callers can explain the verdict.
+    ///
+    /// # Arguments
+    ///
+    /// - `input` (`&str`) - The word to read.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<StateId>, DfaError>` - The start state followed by the
+    ///   state reached after each symbol, or the first symbol outside the
+    ///   alphabet or transition missing from the table.
+    pub fn trace(&self, input: &str) -> Result<Vec<StateId>, DfaError> {
+        let mut path = vec![self.start];
+        let mut state = self.start;
+        for symbol in input.chars() {
+            let column = self
+                .symbol_index(symbol)
+                .ok_or(DfaError::UnknownSymbol(symbol))?;
+            let row = self
+                .trans
+                .get(state as usize)
+                .ok_or(DfaError::UnknownState(state))?;
+            state = *row
+                .get(column)
+                .ok_or(DfaError::MissingTransition { state, symbol })?;
+            path.push(state);
+        }
+        Ok(path)
+    }
+
+    /// Finds the length of the longest prefix of `input` this DFA accepts.
+    ///
+    /// The run stops at the first symbol outside the alphabet or transition
+    /// missing from the table, since no longer prefix can be accepted.
+    ///
+    /// # Arguments
+    ///
+    /// - `input` (`&str`) - The text to match at its start.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The prefix length in characters, or `None` if no
+    ///   prefix, not even the empty one, is accepted.
+    #[must_use]
+    pub fn longest_match_prefix(&self, input: &str) -> Option<usize> {
+        let mut state = self.start;
+        let mut longest = self.accepts.contains(&state).then_some(0);
+        for (idx, symbol) in input.chars().enumerate() {
+            let Some(next) = self.step(state, symbol) else {
+                break;
+            };
+            state = next;
+            if self.accepts.contains(&state) {
+                longest = Some(idx + 1);
+            }
+        }
+        longest
+    }
+
+    /// Checks whether some prefix of `input` is accepted, i.e. whether `input`
+    /// is in `L·Σ*`.
+    #[must_use]
+    pub fn accepts_prefix_of(&self, input: &str) -> bool {
+        self.longest_match_prefix(input).is_some()
+    }
+
+    /// Checks whether some substring of `input` is accepted, i.e. whether
+    /// `input` is in `Σ*·L·Σ*`.
+    ///
+    /// Runs the subset simulation of that construction on the fly: the `Σ*`
+    /// prefix loop restarts the DFA at every position, and the `Σ*` suffix
+    /// loop lets the run stop as soon as any active state accepts. Symbols
+    /// outside the alphabet may surround a match but never be part of one.
+    ///
+    /// # Arguments
+    ///
+    /// - `input` (`&str`) - The text to search.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if a match starts at some position of `input`.
+    #[must_use]
+    pub fn accepts_some_substring_of(&self, input: &str) -> bool {
+        let mut active = HashSet::from([self.start]);
+        if self.accepts.contains(&self.start) {
+            return true;
+        }
+        for symbol in input.chars() {
+            active = active
+                .into_iter()
+                .filter_map(|state| self.step(state, symbol))
+                .collect();
+            if active.iter().any(|state| self.accepts.contains(state)) {
+                return true;
+            }
+            active.insert(self.start);
+        }
+        false
+    }
+
+    /// Finds the column of the transition table that `symbol` indexes.
+    ///
+    /// # Arguments
+    ///
+    /// - `symbol` (`char`) - The symbol to look up.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The position of `symbol` in the alphabet, or `None`
+    ///   if it is not part of the alphabet.
+    #[must_use]
+    pub fn symbol_index(&self, symbol: char) -> Option<usize> {
+        self.alphabet.iter().position(|&c| c == symbol)
+    }
+
+    /// Follows the transition of `state` on `symbol`.
+    ///
+    /// # Arguments
+    ///
+    /// - `state` (`StateId`) - The state to leave.
+    /// - `symbol` (`char`) - The symbol to read.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<StateId>` - The state reached, or `None` if `symbol` is
+    ///   outside the alphabet or the table has no such transition.
+    #[must_use]
+    pub fn step(&self, state: StateId, symbol: char) -> Option<StateId> {
+        let column = self.symbol_index(symbol)?;
+        self.trans.get(state as usize)?.get(column).copied()
+    }
+
+    /// Finds the shortest, then alphabetically first, accepted word.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - An accepted word, or `None` if the language is empty.
+    #[must_use]
+    pub fn shortest_accepted(&self) -> Option<String> {
+        self.access_words()
+            .into_iter()
+            .find(|(state, _)| self.accepts.contains(state))
+            .map(|(_, word)| word)
+    }
+
+    /// Checks whether two DFAs are the same machine up to a renaming of states.
+    ///
+    /// Unlike language equivalence, this does not minimize first: `a*` built by
+    /// subset construction and its minimized form accept the same strings but
+    /// are not isomorphic. Only states reachable from the start are compared.
+    ///
+    /// # Arguments
+    ///
+    /// - `other` (`&Dfa`) - The DFA to compare against.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if the reachable parts of both DFAs are isomorphic.
+    #[must_use]
+    pub fn isomorphic(&self, other: &Dfa) -> bool {
+        self.canonicalize() == other.canonicalize()
+    }
+
+    /// Lists the states reachable from the start state in breadth-first order,
+    /// each with its access word.
+    ///
+    /// Symbols are visited in sorted order, so the access word of a state is
+    /// the shortest, then alphabetically first, input that reaches it. The
+    /// order matches the numbering used by [`Dfa::canonicalize`].
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<(StateId, String)>` - Reachable states paired with their access
+    ///   words, starting with `(start, "")`.
+    #[must_use]
+    pub fn access_words(&self) -> Vec<(StateId, String)> {
+        let mut columns: Vec<usize> = (0..self.alphabet.len()).collect();
+        columns.sort_by_key(|&idx| self.alphabet[idx]);
+
+        let mut visited = HashSet::from([self.start]);
+        let mut order = vec![(self.start, String::new())];
+        let mut next_idx = 0;
+        while let Some((state, word)) = order.get(next_idx).cloned() {
+            next_idx += 1;
+            for &col in &columns {
+                let Some(&next) = self.trans.get(state as usize).and_then(|row| row.get(col))
+                else {
+                    continue;
+                };
+                if visited.insert(next) {
+                    let mut longer = word.clone();
+                    longer.push(self.alphabet[col]);
+                    order.push((next, longer));
+                }
+            }
+        }
+        order
+    }
+
+    /// Partitions the alphabet into classes of symbols that lead every state
+    /// to the same state, i.e. whose columns of the transition table are equal.
+    ///
+    /// Symbols of one class are interchangeable in any input, so each edge of
+    /// the DFA carries whole classes. A transition left out of a short row only
+    /// matches another missing transition.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Vec<char>>` - The classes, ordered by their first symbol, each
+    ///   listing its symbols in alphabet order.
+    #[must_use]
+    pub fn symbol_classes(&self) -> Vec<Vec<char>> {
+        let mut classes: Vec<Vec<char>> = Vec::new();
+        let mut by_column: HashMap<Vec<Option<StateId>>, usize> = HashMap::new();
+        for (col, &symbol) in self.alphabet.iter().enumerate() {
+            let column = self.trans.iter().map(|row| row.get(col).copied()).collect();
+            let class = *by_column.entry(column).or_insert_with(|| {
+                classes.push(Vec::new());
+                classes.len() - 1
+            });
+            classes[class].push(symbol);
+        }
+        classes
+    }
+
+    /// Checks that the transition table is complete and only references existing states.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), DfaError>` - `Ok` if the DFA is well formed.
+    pub fn validate(&self) -> Result<(), DfaError> {
+        self.check_table(false)
+    }
+
+    /// Checks the transition table like [`Dfa::validate`], but accepts rows
+    /// shorter than the alphabet: a row of length `k` defines transitions on
+    /// the first `k` symbols only.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), DfaError>` - `Ok` if the DFA is well formed apart from
+    ///   missing transitions.
+    pub fn validate_partial(&self) -> Result<(), DfaError> {
+        self.check_table(true)
+    }
+
+    /// Fills in missing transitions with a fresh rejecting sink state, if any
+    /// are missing. A complete DFA is returned unchanged.
+    ///
+    /// # Returns
+    ///
+    /// - `Dfa` - An equivalent DFA with a complete transition table.
+    pub(crate) fn complete_with_sink(&self) -> Dfa {
+        let symbols = self.alphabet.len();
+        if self.trans.iter().all(|row| row.len() == symbols) {
+            return self.clone();
+        }
+        let sink = self.trans.len() as StateId;
+        let mut completed = self.clone();
+        for row in &mut completed.trans {
+            row.resize(symbols, sink);
+        }
+        completed.trans.push(vec![sink; symbols]);
+        completed.states.push(sink);
+        completed
+    }
+
+    /// Shared body of [`Dfa::validate`] and [`Dfa::validate_partial`].
+    fn check_table(&self, allow_partial: bool) -> Result<(), DfaError> {
+        let rows = self.trans.len();
+        let contiguous = self.states.len() == rows
+            && self
+                .states
+                .iter()
+                .enumerate()
+                .all(|(i, s)| *s as usize == i);
+        if !contiguous {
+            return Err(DfaError::MismatchedStates { rows });
+        }
+
+        let check = |state: StateId| {
+            if (state as usize) < rows {
+                Ok(())
+            } else {
+                Err(DfaError::UnknownState(state))
+            }
+        };
+        check(self.start)?;
+        self.accepts.iter().try_for_each(|&state| check(state))?;
+        for (state, row) in self.trans.iter().enumerate() {
+            let short = allow_partial && row.len() < self.alphabet.len();
+            if row.len() != self.alphabet.len() && !short {
+                return Err(DfaError::IncompleteRow {
+                    state: state as StateId,
+                    expected: self.alphabet.len(),
+                    found: row.len(),
+                });
+            }
+            row.iter().try_for_each(|&next| check(next))?;
+        }
+        Ok(())
+    }
+
+    /// Projects the language onto a sub-alphabet by erasing every other symbol.
+    ///
+    /// Transitions on symbols outside `keep` become epsilon moves and the
+    /// result is determinized again, so `project(&['a'])` of `(ab)*` is `a*`.
+    ///
+    /// # Arguments
+    ///
+    /// - `keep` (`&[char]`) - The symbols to keep. Each must be in the alphabet.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Dfa, DfaError>` - The projected DFA over the sorted `keep` alphabet.
+    pub fn project(&self, keep: &[char]) -> Result<Dfa, DfaError> {
+        self.validate()?;
+        if let Some(&missing) = keep.iter().find(|c| !self.alphabet.contains(c)) {
+            return Err(DfaError::UnknownSymbol(missing));
+        }
+
+        let nfa = self.to_nfa(|symbol| {
+            if keep.contains(&symbol) {
+                EdgeLabel::Sym(symbol)
+            } else {
+                EdgeLabel::Eps
+            }
+        });
+        Ok(determinize(&nfa))
+    }
+
+    /// Applies a letter-to-letter homomorphism to every transition.
+    ///
+    /// When `f` is injective the states are kept as-is and only the alphabet
+    /// is renamed. When it merges symbols the relabelled automaton is
+    /// nondeterministic, so it is determinized again.
+    ///
+    /// # Arguments
+    ///
+    /// - `f` (`impl Fn(char) -> char`) - The symbol mapping.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Dfa, DfaError>` - The DFA for the image language, with a sorted alphabet.
+    pub fn map_symbols(&self, f: impl Fn(char) -> char) -> Result<Dfa, DfaError> {
+        self.validate()?;
+
+        let mapped: Vec<char> = self.alphabet.iter().map(|&c| f(c)).collect();
+        let mut image = mapped.clone();
+        image.sort_unstable();
+        image.dedup();
+        if image.len() < mapped.len() {
+            return Ok(determinize(
+                &self.to_nfa(|symbol| EdgeLabel::Sym(f(symbol))),
+            ));
+        }
+
+        let mut columns: Vec<usize> = (0..mapped.len()).collect();
+        columns.sort_by_key(|&idx| mapped[idx]);
+        Ok(Dfa {
+            states: self.states.clone(),
+            start: self.start,
+            accepts: self.accepts.clone(),
+            trans: self
+                .trans
+                .iter()
+                .map(|row| columns.iter().map(|&col| row[col]).collect())
+                .collect(),
+            alphabet: image,
+        })
+    }
+
+    /// Computes the left quotient (residual) of the language by a prefix.
+    ///
+    /// The residual `u⁻¹L` is the set of words `w` such that `uw` is in the
+    /// language. For a DFA this is the same machine started from the state
+    /// reached after reading `u`.
+    ///
+    /// # Arguments
+    ///
+    /// - `prefix` (`&str`) - The prefix `u` to divide by.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Dfa, DfaError>` - The residual DFA, or an error if `prefix`
+    ///   uses a symbol outside the alphabet.
+    pub fn left_quotient(&self, prefix: &str) -> Result<Dfa, DfaError> {
+        self.validate()?;
+
+        let mut state = self.start;
+        for symbol in prefix.chars() {
+            state = self
+                .step(state, symbol)
+                .ok_or(DfaError::UnknownSymbol(symbol))?;
+        }
+        Ok(Dfa {
+            start: state,
+            ..self.clone()
+        })
+    }
+
+    /// Counts the accepted words of every length from `0` to `max_len`.
+    ///
+    /// Uses dynamic programming over the transition table: after `k` rounds,
+    /// `paths[q]` is the number of words of length `k` leading from the start
+    /// state to `q`. Counts saturate at `u128::MAX` rather than overflowing.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_len` (`usize`) - The longest word length to count.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u128>` - Entry `k` is the number of accepted words of length `k`.
+    #[must_use]
+    pub fn count_words(&self, max_len: usize) -> Vec<u128> {
+        let mut counts = Vec::with_capacity(max_len + 1);
+        let mut paths = vec![0u128; self.trans.len()];
+        paths[self.start as usize] = 1;
+
+        for len in 0..=max_len {
+            let accepted = self.accepts.iter().fold(0u128, |sum, &state| {
+                sum.saturating_add(paths[state as usize])
+            });
+            counts.push(accepted);
+            if len == max_len {
+                break;
+            }
+
+            let mut next = vec![0u128; self.trans.len()];
+            for (state, row) in self.trans.iter().enumerate() {
+                for &to in row {
+                    next[to as usize] = next[to as usize].saturating_add(paths[state]);
+                }
+            }
+            paths = next;
+        }
+        counts
+    }
+
+    /// Builds an NFA for the reversed language.
+    ///
+    /// Every transition is flipped and a fresh start state is connected to
+    /// each former accepting state with an epsilon edge; the former start
+    /// state becomes the only accepting state. The result can be passed to
+    /// [`determinize`] to obtain a DFA that reads input right to left.
+    ///
+    /// # Returns
+    ///
+    /// - `Nfa` - An automaton accepting `w` reversed for every `w` this DFA accepts.
+    #[must_use]
+    pub fn reverse(&self) -> Nfa {
+        let start = self.states.len() as StateId;
+        let flipped = self.transitions().map(|(from, symbol, to)| Edge {
+            from: to,
+            to: from,
+            label: EdgeLabel::Sym(symbol),
+        });
+        let entries = self.accepts.iter().map(|&accept| Edge {
+            from: start,
+            to: accept,
+            label: EdgeLabel::Eps,
+        });
+        Nfa::from_edges(
+            self.states.len() + 1,
+            start,
+            vec![self.start],
+            flipped.chain(entries),
+        )
+    }
+
+    /// Builds a DFA for the complement of the language over this alphabet.
+    ///
+    /// Missing transitions of a partial DFA are first sent to a rejecting
+    /// sink, so that flipping which states accept is enough. Symbols outside
+    /// the alphabet are not part of the complement; declare them first (see
+    /// [`determinize_over`]) to include them.
+    ///
+    /// # Returns
+    ///
+    /// - `Dfa` - A DFA accepting exactly the words over the alphabet this one rejects.
+    #[must_use]
+    pub fn complement(&self) -> Dfa {
+        let completed = self.complete_with_sink();
+        Dfa {
+            accepts: completed
+                .states
+                .iter()
+                .copied()
+                .filter(|state| !completed.accepts.contains(state))
+                .collect(),
+            ..completed
+        }
+    }
+
+    /// Builds a DFA for the Kleene star of the language.
+    ///
+    /// # Returns
+    ///
+    /// - `Dfa` - A DFA over the same alphabet accepting `L(self)*`.
+    #[must_use]
+    pub fn star(&self) -> Dfa {
+        determinize_over(&self.to_nfa(EdgeLabel::Sym).star(), &self.alphabet)
+    }
+
+    /// Removes states that are unreachable or cannot reach an accepting state.
+    ///
+    /// The result is always complete: transitions into removed states, and
+    /// those missing from a partial DFA, are redirected to a single dead
+    /// state, added only when needed. The kept states are renumbered in their
+    /// original order, start first.
+    ///
+    /// # Returns
+    ///
+    /// - `Dfa` - An equivalent DFA with at most one useless state.
+    #[must_use]
+    pub fn trim(&self) -> Dfa {
+        let dfa = self.complete_with_sink();
+        let reachable: HashSet<StateId> = dfa
+            .access_words()
+            .into_iter()
+            .map(|(state, _)| state)
+            .collect();
+        let mut live: HashSet<StateId> = dfa.accepts.iter().copied().collect();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for state in &dfa.states {
+                if !live.contains(state)
+                    && dfa.trans[*state as usize]
+                        .iter()
+                        .any(|next| live.contains(next))
+                {
+                    live.insert(*state);
+                    changed = true;
+                }
+            }
+        }
+
+        let mut kept: IndexMap<StateId, StateId> = IndexMap::new();
+        kept.insert(dfa.start, 0);
+        for state in &dfa.states {
+            if reachable.contains(state) && live.contains(state) && !kept.contains_key(state) {
+                kept.insert(*state, kept.len() as StateId);
+            }
+        }
+
+        // An empty language keeps only its start state, which then doubles as the sink.
+        let sink = if live.contains(&dfa.start) {
+            kept.len() as StateId
+        } else {
+            0
+        };
+        let mut trans: Vec<Vec<StateId>> = kept
+            .keys()
+            .map(|&old| {
+                dfa.trans[old as usize]
+                    .iter()
+                    .map(|next| kept.get(next).copied().unwrap_or(sink))
+                    .collect()
+            })
+            .collect();
+        if trans
+            .iter()
+            .flatten()
+            .any(|&next| next as usize == kept.len())
+        {
+            trans.push(vec![sink; dfa.alphabet.len()]);
+        }
+
+        Dfa {
+            states: (0..trans.len() as StateId).collect(),
+            start: 0,
+            accepts: dfa
+                .accepts
+                .iter()
+                .filter_map(|old| kept.get(old).copied())
+                .collect(),
+            trans,
+            alphabet: dfa.alphabet.clone(),
+        }
+    }
+
+    /// Converts this DFA into an NFA, relabelling each transition with `label`.
+    fn to_nfa(&self, label: impl Fn(char) -> EdgeLabel) -> Nfa {
+        let edges = self.transitions().map(|(from, symbol, to)| Edge {
+            from,
+            to,
+            label: label(symbol),
+        });
+        Nfa::from_edges(self.states.len(), self.start, self.accepts.clone(), edges)
+    }
+}
+
+/// Writes the transition table, one row per state.
+///
+/// The start state is marked `→` and accepting states `*`. Transitions left
+/// out of a short row are written as `-`.
+impl fmt::Display for Dfa {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut header = vec![String::new(), "δ".to_string()];
+        header.extend(self.alphabet.iter().map(char::to_string));
+        let mut rows = vec![header];
+        for (state, row) in self.trans.iter().enumerate() {
+            let state = state as StateId;
+            let start = if state == self.start { "→" } else { "" };
+            let accepting = if self.accepts.contains(&state) {
+                "*"
+            } else {
+                ""
+            };
+            let mut cells = vec![format!("{start}{accepting}"), state.to_string()];
+            cells.extend(
+                (0..self.alphabet.len())
+                    .map(|col| row.get(col).map_or("-".to_string(), StateId::to_string)),
+            );
+            rows.push(cells);
+        }
+        write_columns(f, &rows)
+    }
+}
+
+/// Writes `rows` as left-aligned columns separated by two spaces.
+pub(crate) fn write_columns(f: &mut fmt::Formatter<'_>, rows: &[Vec<String>]) -> fmt::Result {
+    let mut widths = Vec::new();
+    for row in rows {
+        widths.resize(widths.len().max(row.len()), 0);
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    for row in rows {
+        let mut line = String::new();
+        for (cell, width) in row.iter().zip(&widths) {
+            line.push_str(cell);
+            line.extend(std::iter::repeat_n(' ', width - cell.chars().count() + 2));
+        }
+        writeln!(f, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+/// Assembles a [`Dfa`] by hand, e.g. one drawn on paper or imported from
+/// another tool, instead of determinizing an NFA.
+///
+/// States are numbered `0..states` and every state needs exactly one
+/// transition per alphabet symbol. [`DfaBuilder::build`] checks this and
+/// reports the first problem found.
+///
+/// ```
+/// use regviz_core::core::dfa::DfaBuilder;
+///
+/// // Strings over {a, b} ending in `a`.
+/// let dfa = DfaBuilder::new()
+///     .states(2)
+///     .alphabet(['a', 'b'])
+///     .start(0)
+///     .accept(1)
+///     .transition(0, 'a', 1)
+///     .transition(0, 'b', 0)
+///     .transition(1, 'a', 1)
+///     .transition(1, 'b', 0)
+///     .build()
+///     .unwrap();
+/// assert_eq!(dfa.accepts, vec![1]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[must_use]
+pub struct DfaBuilder {
+    states: usize,
+    alphabet: Vec<char>,
+    start: StateId,
+    accepts: Vec<StateId>,
+    transitions: Vec<(StateId, char, StateId)>,
+}
+
+impl DfaBuilder {
+    /// Creates a builder for a DFA without states, symbols or transitions,
+    /// whose start state defaults to `0`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of states, which are numbered `0..count`.
+    pub fn states(mut self, count: usize) -> Self {
+        self.states = count;
+        self
+    }
+
+    /// Sets the alphabet. The order of the symbols is the column order of the
+    /// transition table.
+    ///
+    /// # Arguments
+    ///
+    /// - `symbols` (`impl IntoIterator<Item = char>`) - The input symbols.
+    pub fn alphabet(mut self, symbols: impl IntoIterator<Item = char>) -> Self {
+        self.alphabet = symbols.into_iter().collect();
+        self
+    }
+
+    /// Sets the start state.
+    pub fn start(mut self, state: StateId) -> Self {
+        self.start = state;
+        self
+    }
+
+    /// Marks `state` as accepting.
+    pub fn accept(mut self, state: StateId) -> Self {
+        self.accepts.push(state);
+        self
+    }
+
+    /// Marks every state in `states` as accepting.
+    pub fn accepts(mut self, states: impl IntoIterator<Item = StateId>) -> Self {
+        self.accepts.extend(states);
+        self
+    }
+
+    /// Adds the transition `from --symbol--> to`.
+    ///
+    /// # Arguments
+    ///
+    /// - `from` (`StateId`) - The source state.
+    /// - `symbol` (`char`) - The symbol read. Must be in the alphabet.
+    /// - `to` (`StateId`) - The target state.
+    pub fn transition(mut self, from: StateId, symbol: char, to: StateId) -> Self {
+        self.transitions.push((from, symbol, to));
+        self
+    }
+
+    /// Validates the machine and builds it.
+    ///
+    /// Repeating a transition with the same target is allowed; giving a state
+    /// two different targets for one symbol is not.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Dfa, DfaError>` - The DFA with sorted accepting states, or the
+    ///   first problem found.
+    pub fn build(self) -> Result<Dfa, DfaError> {
+        for (idx, symbol) in self.alphabet.iter().enumerate() {
+            if self.alphabet[..idx].contains(symbol) {
+                return Err(DfaError::DuplicateSymbol(*symbol));
+            }
+        }
+
+        let mut rows = vec![vec![None; self.alphabet.len()]; self.states];
+        for (from, symbol, to) in self.transitions {
+            if to as usize >= self.states {
+                return Err(DfaError::UnknownState(to));
+            }
+            let row = rows
+                .get_mut(from as usize)
+                .ok_or(DfaError::UnknownState(from))?;
+            let column = self
+                .alphabet
+                .iter()
+                .position(|&c| c == symbol)
+                .ok_or(DfaError::UnknownSymbol(symbol))?;
+            match row[column] {
+                Some(existing) if existing != to => {
+                    return Err(DfaError::ConflictingTransition {
+                        state: from,
+                        symbol,
+                    });
+                }
+                _ => row[column] = Some(to),
+            }
+        }
+
+        let trans = rows
+            .into_iter()
+            .enumerate()
+            .map(|(state, row)| {
+                row.into_iter()
+                    .zip(&self.alphabet)
+                    .map(|(next, &symbol)| {
+                        next.ok_or(DfaError::MissingTransition {
+                            state: state as StateId,
+                            symbol,
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        let mut accepts = self.accepts;
+        accepts.sort_unstable();
+        accepts.dedup();
+
+        let dfa = Dfa {
+            states: (0..self.states as StateId).collect(),
+            start: self.start,
+            accepts,
+            trans,
+            alphabet: self.alphabet,
+        };
+        dfa.validate()?;
+        Ok(dfa)
+    }
+}
+
+/// Lists every transition of an existing DFA, so it can be edited and rebuilt.
+///
+/// The conversion is lossless: building the result gives back `dfa` with its
+/// state numbering and alphabet order unchanged, and accepting states sorted.
+impl From<&Dfa> for DfaBuilder {
+    fn from(dfa: &Dfa) -> Self {
+        Self {
+            states: dfa.trans.len(),
+            alphabet: dfa.alphabet.clone(),
+            start: dfa.start,
+            accepts: dfa.accepts.clone(),
+            transitions: dfa.transitions().collect(),
+        }
+    }
+}
+
+impl TryFrom<DfaBuilder> for Dfa {
+    type Error = DfaError;
+
+    fn try_from(builder: DfaBuilder) -> Result<Self, Self::Error> {
+        builder.build()
+    }
+}
+
+/// Minimal 64-bit FNV-1a hasher with a fixed, documented output.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        for byte in value.to_le_bytes() {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
 /// A helper function to determinize an NFA into a DFA using subset construction.
 ///
 /// # Arguments
@@ -31,14 +1018,28 @@ pub struct Dfa {
 ///
 /// - `(Dfa, Vec<char>)` - A tuple containing the resulting DFA and its alphabet.
 pub fn determinize(nfa: &Nfa) -> Dfa {
-    Determinizer::new(nfa).run()
+    Determinizer::new(nfa, nfa.alphabet()).run()
 }
 
-/// Converts a set of state IDs into a sorted vector key.
-fn set_to_key(set: HashSet<StateId>) -> Vec<StateId> {
-    let mut vec: Vec<StateId> = set.into_iter().collect();
-    vec.sort_unstable();
-    vec
+/// Determinizes an NFA over an explicit alphabet instead of the one it uses.
+///
+/// Every state gets a transition for each symbol of `alphabet`, so symbols
+/// the NFA never reads lead to the dead state. Transitions on symbols outside
+/// `alphabet` are never taken.
+///
+/// # Arguments
+///
+/// - `nfa` (`&Nfa`) - The NFA to be determinized.
+/// - `alphabet` (`&[char]`) - The symbols of the resulting DFA, in any order.
+///
+/// # Returns
+///
+/// - `Dfa` - The resulting DFA over the sorted, deduplicated `alphabet`.
+pub fn determinize_over(nfa: &Nfa, alphabet: &[char]) -> Dfa {
+    let mut alphabet = alphabet.to_vec();
+    alphabet.sort_unstable();
+    alphabet.dedup();
+    Determinizer::new(nfa, alphabet).run()
 }
 
 /// Represents a Determinizer performing subset construction.
@@ -50,11 +1051,14 @@ struct Determinizer<'a> {
     /// The alphabet of symbols used in the NFA.
     alphabet: Vec<char>,
 
+    /// The ε-closure of every NFA state, computed once up front.
+    closures: Vec<StateSet>,
+
     /// Mapping from NFA state subsets to DFA state IDs.
-    map: IndexMap<Vec<StateId>, StateId>,
+    map: IndexMap<StateSet, StateId>,
 
     /// Queue of NFA state subsets to process.
-    queue: VecDeque<Vec<StateId>>,
+    queue: VecDeque<StateSet>,
 
     /// Array of DFA transitions being built.
     transitions: Vec<Vec<StateId>>,
@@ -66,20 +1070,17 @@ impl<'a> Determinizer<'a> {
     /// # Arguments
     ///
     /// - `nfa` (`&'a Nfa`) - The NFA to be determinized.
+    /// - `alphabet` (`Vec<char>`) - The symbols to build transitions for.
     ///
     /// # Returns
     ///
     /// - `Self` - A new instance of `Determinizer`.
-    fn new(nfa: &'a Nfa) -> Self {
-        let alphabet = nfa.alphabet();
+    fn new(nfa: &'a Nfa, alphabet: Vec<char>) -> Self {
+        let closures = nfa.epsilon_closures();
+        let start_key = closures[nfa.start as usize].clone();
+
         let mut map = IndexMap::new();
         let mut queue = VecDeque::new();
-
-        let mut seed = HashSet::new();
-        seed.insert(nfa.start);
-        let closure = sim::epsilon_closure(&seed, nfa);
-        let start_key = set_to_key(closure);
-
         let start_id: StateId = 0;
         map.insert(start_key.clone(), start_id);
         queue.push_back(start_key);
@@ -87,6 +1088,7 @@ impl<'a> Determinizer<'a> {
         Self {
             nfa,
             alphabet,
+            closures,
             map,
             queue,
             transitions: Vec::new(),
@@ -94,13 +1096,12 @@ impl<'a> Determinizer<'a> {
     }
 
     fn run(mut self) -> Dfa {
-        while let Some(key) = self.queue.pop_front() {
-            let state_id = self.map[&key];
+        while let Some(subset) = self.queue.pop_front() {
+            let state_id = self.map[&subset];
             // Ensure transitions vector is large enough
             while self.transitions.len() < state_id as usize + 1 {
                 self.transitions.push(vec![]);
             }
-            let subset: HashSet<StateId> = key.iter().copied().collect();
 
             for symbol_idx in 0..self.alphabet.len() {
                 let symbol = self.alphabet[symbol_idx];
@@ -124,18 +1125,16 @@ impl<'a> Determinizer<'a> {
     ///
     /// # Arguments
     ///
-    /// - `subset` (`&HashSet<StateId>`) - The current subset of NFA states.
+    /// - `subset` (`&StateSet`) - The current subset of NFA states.
     /// - `symbol` (`char`) - The input symbol to advance on.
     ///
     /// # Returns
     ///
-    /// - `Option<StateId>` - The next DFA state ID, or `None` if there is no transition.
-    fn advance_subset(&mut self, subset: &HashSet<StateId>, symbol: char) -> StateId {
-        let moved = sim::move_on(subset, symbol, self.nfa);
-
-        // NOTE: If moved is empty, the epsilon closure will also be empty,
+    /// - `StateId` - The next DFA state ID.
+    fn advance_subset(&mut self, subset: &StateSet, symbol: char) -> StateId {
+        // NOTE: If no state moves on `symbol`, the closure is empty,
         // resulting in a dead state being created. This is the desired behavior.
-        let closure = sim::epsilon_closure(&moved, self.nfa);
+        let closure = sim::step_closed(self.nfa, &self.closures, subset, symbol);
         self.lookup_or_insert(closure)
     }
 
@@ -143,19 +1142,18 @@ impl<'a> Determinizer<'a> {
     ///
     /// # Arguments
     ///
-    /// - `subset` (`HashSet<StateId>`) - The subset of NFA states to look up or insert.
+    /// - `subset` (`StateSet`) - The subset of NFA states to look up or insert.
     ///
     /// # Returns
     ///
     /// - `StateId` - The DFA state ID corresponding to the subset.
-    fn lookup_or_insert(&mut self, subset: HashSet<StateId>) -> StateId {
-        let key = set_to_key(subset);
-        if let Some(id) = self.map.get(&key) {
+    fn lookup_or_insert(&mut self, subset: StateSet) -> StateId {
+        if let Some(id) = self.map.get(&subset) {
             *id
         } else {
             let new_id = self.map.len() as StateId;
-            self.map.insert(key.clone(), new_id);
-            self.queue.push_back(key);
+            self.map.insert(subset.clone(), new_id);
+            self.queue.push_back(subset);
             new_id
         }
     }
@@ -166,12 +1164,13 @@ impl<'a> Determinizer<'a> {
     ///
     /// - `Vec<StateId>` - A vector of DFA state IDs that are accepting states.
     fn collect_accepting(&self) -> Vec<StateId> {
+        let mut accepting = StateSet::new(self.nfa.states.len());
+        for &state in &self.nfa.accepts {
+            accepting.insert(state);
+        }
         self.map
             .iter()
-            .filter_map(|(subset, id)| {
-                let accepting = subset.iter().any(|state| self.nfa.accepts.contains(state));
-                accepting.then_some(*id)
-            })
+            .filter_map(|(subset, id)| subset.intersects(&accepting).then_some(*id))
             .collect()
     }
 }
@@ -182,6 +1181,182 @@ mod tests {
 
     use super::*;
 
+    fn ends_in_a() -> DfaBuilder {
+        DfaBuilder::new()
+            .states(2)
+            .alphabet(['a', 'b'])
+            .accepts([1, 1])
+            .transition(0, 'a', 1)
+            .transition(0, 'b', 0)
+            .transition(1, 'a', 1)
+            .transition(1, 'b', 0)
+    }
+
+    #[test]
+    fn test_builder_matches_determinized() {
+        let built = ends_in_a().build().unwrap();
+        assert_eq!(built.accepts, vec![1]);
+        assert!(built.isomorphic(&min::minimize(&determinize(&Nfa::build(
+            &Ast::build("(a+b)*a").unwrap()
+        )))));
+    }
+
+    #[test]
+    fn test_builder_rejects_malformed_machines() {
+        assert_eq!(
+            ends_in_a().states(3).build(),
+            Err(DfaError::MissingTransition {
+                state: 2,
+                symbol: 'a'
+            })
+        );
+        assert_eq!(
+            ends_in_a().transition(0, 'a', 0).build(),
+            Err(DfaError::ConflictingTransition {
+                state: 0,
+                symbol: 'a'
+            })
+        );
+        assert_eq!(
+            ends_in_a().transition(0, 'c', 0).build(),
+            Err(DfaError::UnknownSymbol('c'))
+        );
+        assert_eq!(
+            ends_in_a().transition(0, 'a', 5).build(),
+            Err(DfaError::UnknownState(5))
+        );
+        assert_eq!(ends_in_a().start(7).build(), Err(DfaError::UnknownState(7)));
+        assert_eq!(
+            ends_in_a().alphabet(['a', 'a']).build(),
+            Err(DfaError::DuplicateSymbol('a'))
+        );
+        // Repeating a transition with the same target is harmless.
+        assert!(ends_in_a().transition(1, 'b', 0).build().is_ok());
+    }
+
+    #[test]
+    fn test_builder_round_trip() {
+        let dfa = determinize(&Nfa::build(&Ast::build("(a+b)*abb").unwrap()));
+        let rebuilt = Dfa::try_from(DfaBuilder::from(&dfa)).unwrap();
+        assert_eq!(rebuilt, dfa);
+
+        // Editing the listed transitions goes through the same validation.
+        let edited = DfaBuilder::from(&dfa).transition(dfa.start, 'a', dfa.start);
+        assert!(matches!(
+            Dfa::try_from(edited),
+            Err(DfaError::ConflictingTransition { symbol: 'a', .. })
+        ));
+    }
+
+    #[test]
+    fn test_symbol_classes() {
+        // 'b' and 'c' are interchangeable everywhere, unlike 'a' and 'd'.
+        let dfa = Dfa::from_pattern("(a+b+c)*a(b+c)d").unwrap();
+        assert_eq!(
+            dfa.symbol_classes(),
+            vec![vec!['a'], vec!['b', 'c'], vec!['d']]
+        );
+        assert_eq!(ends_in_a().build().unwrap().symbol_classes().len(), 2);
+    }
+
+    #[test]
+    fn test_from_pattern() {
+        let dfa = Dfa::from_pattern("(a+b)*abb").unwrap();
+        assert_eq!(dfa.states.len(), 4);
+        assert!(sim::simulate_dfa(&dfa, "babb"));
+        assert!(Dfa::from_pattern("(a").is_err());
+    }
+
+    #[test]
+    fn test_display_table() {
+        let dfa = DfaBuilder::new()
+            .states(2)
+            .alphabet(['a', 'b'])
+            .accepts([0, 1])
+            .transition(0, 'a', 1)
+            .transition(0, 'b', 0)
+            .transition(1, 'a', 1)
+            .transition(1, 'b', 0)
+            .build()
+            .unwrap();
+        assert_eq!(dfa.to_string(), "    δ  a  b\n→*  0  1  0\n*   1  1  0\n");
+    }
+
+    #[test]
+    fn test_accessors() {
+        let dfa = Dfa {
+            states: vec![0, 1],
+            start: 0,
+            accepts: vec![1],
+            trans: vec![vec![1, 0], vec![1]],
+            alphabet: vec!['a', 'b'],
+        };
+        assert_eq!(
+            dfa.transitions().collect::<Vec<_>>(),
+            vec![(0, 'a', 1), (0, 'b', 0), (1, 'a', 1)]
+        );
+        assert_eq!(dfa.states().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(dfa.accept_states().collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn test_trace() {
+        let dfa = Dfa {
+            states: vec![0, 1],
+            start: 0,
+            accepts: vec![1],
+            trans: vec![vec![1, 0], vec![1]],
+            alphabet: vec!['a', 'b'],
+        };
+        assert_eq!(dfa.trace(""), Ok(vec![0]));
+        assert_eq!(dfa.trace("baa"), Ok(vec![0, 0, 1, 1]));
+        assert_eq!(dfa.trace("ac"), Err(DfaError::UnknownSymbol('c')));
+        assert_eq!(
+            dfa.trace("ab"),
+            Err(DfaError::MissingTransition {
+                state: 1,
+                symbol: 'b'
+            })
+        );
+    }
+
+    #[test]
+    fn test_step_and_symbol_index() {
+        let dfa = Dfa {
+            states: vec![0, 1],
+            start: 0,
+            accepts: vec![1],
+            trans: vec![vec![1, 0], vec![1]],
+            alphabet: vec!['a', 'b'],
+        };
+        assert_eq!(dfa.symbol_index('b'), Some(1));
+        assert_eq!(dfa.symbol_index('c'), None);
+        assert_eq!(dfa.step(0, 'a'), Some(1));
+        assert_eq!(dfa.step(0, 'c'), None);
+        assert_eq!(dfa.step(1, 'b'), None);
+        assert_eq!(dfa.step(2, 'a'), None);
+    }
+
+    #[test]
+    fn test_partial_matches() {
+        let dfa = Dfa::from_pattern("ab+abb").unwrap();
+        assert_eq!(dfa.longest_match_prefix("abbab"), Some(3));
+        assert_eq!(dfa.longest_match_prefix("abc"), Some(2));
+        assert_eq!(dfa.longest_match_prefix("ba"), None);
+        assert!(dfa.accepts_prefix_of("abx"));
+        assert!(!dfa.accepts_prefix_of("xab"));
+
+        assert!(dfa.accepts_some_substring_of("xxaab"));
+        assert!(dfa.accepts_some_substring_of("bab"));
+        assert!(!dfa.accepts_some_substring_of("a-b"));
+        assert!(!dfa.accepts_some_substring_of(""));
+
+        // The empty word is a prefix and a substring of everything.
+        let star = Dfa::from_pattern("a*").unwrap();
+        assert_eq!(star.longest_match_prefix("bbb"), Some(0));
+        assert!(star.accepts_some_substring_of("bbb"));
+    }
+
     #[test]
     fn test_determinize_epsilon() {
         let nfa = Nfa::build(&Ast::build("").unwrap());
@@ -193,7 +1368,7 @@ mod tests {
         assert_eq!(
             dfa.trans,
             vec![
-                vec![] // state 0 has no transitions
+                Vec::<StateId>::new() // state 0 has no transitions
             ]
         );
     }
@@ -279,4 +1454,295 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_determinize_over_declared_alphabet() {
+        let nfa = Nfa::build(&Ast::build("a*").unwrap());
+        let dfa = determinize_over(&nfa, &['c', 'a', 'b', 'a']);
+
+        assert_eq!(dfa.alphabet, vec!['a', 'b', 'c']);
+        assert!(dfa.validate().is_ok());
+        assert!(sim::simulate_dfa(&dfa, "aa"));
+        assert!(!sim::simulate_dfa(&dfa, "ab"));
+        assert_eq!(determinize_over(&nfa, &nfa.alphabet()), determinize(&nfa));
+    }
+
+    #[test]
+    fn test_complement_flips_acceptance() {
+        let dfa = determinize(&Nfa::build(&Ast::build("ab*").unwrap()));
+        let complement = dfa.complement();
+        for word in ["", "a", "ab", "abb", "b", "ba", "aab"] {
+            assert_ne!(
+                sim::simulate_dfa(&dfa, word),
+                sim::simulate_dfa(&complement, word)
+            );
+        }
+        assert_eq!(complement.complement(), dfa);
+    }
+
+    #[test]
+    fn test_star_accepts_repetitions() {
+        let dfa = determinize(&Nfa::build(&Ast::build("ab").unwrap())).star();
+        assert_eq!(dfa.alphabet, vec!['a', 'b']);
+        for (word, expected) in [("", true), ("ab", true), ("abab", true), ("aba", false)] {
+            assert_eq!(sim::simulate_dfa(&dfa, word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_complement_and_trim_of_partial_dfa() {
+        // c*a over {c, a}; every word continuing past the 'a' is missing.
+        let partial = Dfa {
+            states: vec![0, 1],
+            start: 0,
+            accepts: vec![1],
+            trans: vec![vec![0, 1], vec![]],
+            alphabet: vec!['c', 'a'],
+        };
+        let complement = partial.complement();
+        complement.validate().unwrap();
+        for (word, accepted) in [
+            ("", true),
+            ("cc", true),
+            ("ca", false),
+            ("aa", true),
+            ("cac", true),
+        ] {
+            assert_eq!(sim::simulate_dfa(&complement, word), accepted, "{word:?}");
+        }
+
+        let trimmed = partial.trim();
+        trimmed.validate().unwrap();
+        assert_eq!(trimmed.states.len(), 3);
+        assert_eq!(trimmed.canonical_hash(), partial.canonical_hash());
+    }
+
+    #[test]
+    fn test_trim_keeps_one_dead_state() {
+        // 2 and 3 are dead, 4 is unreachable.
+        let dfa = Dfa {
+            states: vec![0, 1, 2, 3, 4],
+            start: 0,
+            accepts: vec![1, 4],
+            trans: vec![vec![1], vec![2], vec![3], vec![3], vec![0]],
+            alphabet: vec!['a'],
+        };
+        let trimmed = dfa.trim();
+        assert_eq!(trimmed.trans, vec![vec![1], vec![2], vec![2]]);
+        assert_eq!(trimmed.accepts, vec![1]);
+        assert_eq!(trimmed.canonical_hash(), dfa.canonical_hash());
+
+        let empty = determinize(&Nfa::build(&Ast::build("a*").unwrap()))
+            .complement()
+            .trim();
+        assert_eq!(empty.trans, vec![vec![0]]);
+        assert!(empty.accepts.is_empty());
+    }
+
+    #[test]
+    fn test_canonical_hash_matches_equivalent_patterns() {
+        let hash = |pattern: &str| {
+            let nfa = Nfa::build(&Ast::build(pattern).unwrap());
+            determinize(&nfa).canonical_hash()
+        };
+        assert_eq!(hash("(a+b)*"), hash("(a*b*)*"));
+        assert_eq!(hash("a(ba)*"), hash("(ab)*a"));
+        assert_ne!(hash("a*"), hash("aa*"));
+    }
+
+    #[test]
+    fn test_canonical_hash_of_partial_dfa() {
+        // c*a over {c, a}, with state 1 leaving every transition out.
+        let partial = Dfa {
+            states: vec![0, 1],
+            start: 0,
+            accepts: vec![1],
+            trans: vec![vec![0, 1], vec![]],
+            alphabet: vec!['c', 'a'],
+        };
+        partial.validate_partial().unwrap();
+        assert_eq!(
+            partial.canonical_hash(),
+            Dfa::from_pattern("c*a").unwrap().canonical_hash()
+        );
+    }
+
+    #[test]
+    fn test_canonical_hash_ignores_state_numbering() {
+        // Same machine as `a*` over {a}, with states listed in reverse order.
+        let dfa = Dfa {
+            states: vec![0, 1],
+            start: 1,
+            accepts: vec![1, 0],
+            trans: vec![vec![0], vec![0]],
+            alphabet: vec!['a'],
+        };
+        let nfa = Nfa::build(&Ast::build("a*").unwrap());
+        assert_eq!(dfa.canonical_hash(), determinize(&nfa).canonical_hash());
+    }
+
+    #[test]
+    fn test_isomorphic_distinguishes_machine_from_language() {
+        let nfa = Nfa::build(&Ast::build("a*").unwrap());
+        let dfa = determinize(&nfa);
+        let min_dfa = min::minimize(&dfa);
+        assert_eq!(dfa.canonical_hash(), min_dfa.canonical_hash());
+        assert!(!dfa.isomorphic(&min_dfa));
+
+        // Swap the two state labels: still the same machine.
+        let renamed = Dfa {
+            states: vec![0, 1],
+            start: 1,
+            accepts: vec![0, 1],
+            trans: vec![vec![0], vec![0]],
+            alphabet: vec!['a'],
+        };
+        assert!(dfa.isomorphic(&renamed));
+        assert_eq!(renamed.canonicalize(), dfa);
+    }
+
+    #[test]
+    fn test_partial_dfa_queries() {
+        // Accepts c*a over {c, a}; state 1 has no transitions at all.
+        let dfa = Dfa {
+            states: vec![0, 1],
+            start: 0,
+            accepts: vec![1],
+            trans: vec![vec![0, 1], vec![]],
+            alphabet: vec!['c', 'a'],
+        };
+        assert_eq!(
+            dfa.access_words(),
+            vec![(0, String::new()), (1, "a".to_string())]
+        );
+        assert_eq!(dfa.symbol_classes(), vec![vec!['c'], vec!['a']]);
+        // The missing transitions all lead to one dead sink.
+        let canonical = dfa.canonicalize();
+        assert_eq!(canonical.alphabet, vec!['a', 'c']);
+        assert_eq!(canonical.trans, vec![vec![1, 0], vec![2, 2], vec![2, 2]]);
+        assert_eq!(canonical.accepts, vec![1]);
+        assert!(dfa.isomorphic(&canonical));
+
+        // Only δ(0, c) is defined, so 'a' is missing before 'c' once sorted.
+        let gap = Dfa {
+            trans: vec![vec![0], vec![]],
+            ..dfa
+        };
+        assert_eq!(gap.access_words(), vec![(0, String::new())]);
+        assert_eq!(gap.canonicalize().trans, vec![vec![1, 0], vec![1, 1]]);
+        assert!(gap.canonicalize().accepts.is_empty());
+    }
+
+    #[test]
+    fn test_access_words_follow_canonical_order() {
+        let dfa = determinize(&Nfa::build(&Ast::build("(a+b)*abb").unwrap()));
+        let words = dfa.access_words();
+
+        assert_eq!(words[0], (dfa.start, String::new()));
+        assert_eq!(words.len(), dfa.canonicalize().states.len());
+        for (state, word) in &words {
+            assert_eq!(dfa.left_quotient(word).unwrap().start, *state);
+        }
+    }
+
+    #[test]
+    fn test_project_erases_symbols() {
+        let dfa = determinize(&Nfa::build(&Ast::build("(ab)*").unwrap()));
+        let projected = dfa.project(&['a']).unwrap();
+        assert_eq!(projected.alphabet, vec!['a']);
+        for input in ["", "a", "aaa"] {
+            assert!(sim::simulate_dfa(&projected, input));
+        }
+        assert_eq!(dfa.project(&['c']), Err(DfaError::UnknownSymbol('c')));
+    }
+
+    #[test]
+    fn test_map_symbols_merges_and_renames() {
+        let dfa = determinize(&Nfa::build(&Ast::build("ab+ba").unwrap()));
+
+        let merged = dfa.map_symbols(|_| 'x').unwrap();
+        assert_eq!(merged.alphabet, vec!['x']);
+        assert!(sim::simulate_dfa(&merged, "xx"));
+        assert!(!sim::simulate_dfa(&merged, "x"));
+
+        let swapped = dfa
+            .map_symbols(|c| if c == 'a' { 'b' } else { 'a' })
+            .unwrap();
+        assert_eq!(swapped.states.len(), dfa.states.len());
+        assert!(sim::simulate_dfa(&swapped, "ba"));
+        assert!(sim::simulate_dfa(&swapped, "ab"));
+        assert!(!sim::simulate_dfa(&swapped, "aa"));
+    }
+
+    #[test]
+    fn test_validate_rejects_malformed_tables() {
+        let dfa = Dfa {
+            states: vec![0],
+            start: 0,
+            accepts: vec![0],
+            trans: vec![vec![3]],
+            alphabet: vec!['a'],
+        };
+        assert_eq!(dfa.validate(), Err(DfaError::UnknownState(3)));
+    }
+
+    #[test]
+    fn test_validate_partial_allows_short_rows_only() {
+        let mut dfa = Dfa {
+            states: vec![0, 1],
+            start: 0,
+            accepts: vec![1],
+            trans: vec![vec![1, 0], vec![1]],
+            alphabet: vec!['a', 'b'],
+        };
+        assert_eq!(dfa.validate_partial(), Ok(()));
+        assert!(dfa.validate().is_err());
+
+        dfa.trans[0][1] = 5;
+        assert_eq!(dfa.validate_partial(), Err(DfaError::UnknownState(5)));
+        dfa.trans[0] = vec![1, 0, 1];
+        assert_eq!(
+            dfa.validate_partial(),
+            Err(DfaError::IncompleteRow {
+                state: 0,
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_reverse_accepts_reversed_words() {
+        let dfa = determinize(&Nfa::build(&Ast::build("ab*c").unwrap()));
+        let reversed = dfa.reverse();
+        for input in ["ca", "cba", "cbbba"] {
+            assert!(sim::nfa_accepts(&reversed, input));
+        }
+        assert!(!sim::nfa_accepts(&reversed, "abc"));
+
+        let back = determinize(&reversed).reverse();
+        assert!(sim::nfa_accepts(&back, "abbc"));
+    }
+
+    #[test]
+    fn test_left_quotient_moves_start() {
+        let dfa = determinize(&Nfa::build(&Ast::build("abc+abd").unwrap()));
+        let residual = dfa.left_quotient("ab").unwrap();
+        assert!(sim::simulate_dfa(&residual, "c"));
+        assert!(sim::simulate_dfa(&residual, "d"));
+        assert!(!sim::simulate_dfa(&residual, "abc"));
+        assert_eq!(dfa.left_quotient("").unwrap(), dfa);
+        assert_eq!(dfa.left_quotient("ax"), Err(DfaError::UnknownSymbol('x')));
+    }
+
+    #[test]
+    fn test_count_words_growth() {
+        let count = |pattern: &str| {
+            let nfa = Nfa::build(&Ast::build(pattern).unwrap());
+            determinize(&nfa).count_words(4)
+        };
+        assert_eq!(count("ab+c"), vec![0, 1, 1, 0, 0]);
+        assert_eq!(count("a*b*"), vec![1, 2, 3, 4, 5]);
+        assert_eq!(count("(a+b)*"), vec![1, 2, 4, 8, 16]);
+    }
 }
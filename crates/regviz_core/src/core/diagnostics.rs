@@ -0,0 +1,501 @@
+//! Help for patterns that fail to build, and warnings for ones that build
+//! but are probably not what the user meant.
+//!
+//! Each kind of [`BuildError`] has its own heuristics proposing edits that are
+//! likely what the user meant. Only edits that make the pattern build are kept.
+
+use std::fmt::Write;
+
+use crate::core::dfa::{self, Dfa};
+use crate::core::lexer::OpToken;
+use crate::core::nfa::Nfa;
+use crate::core::parser::Ast;
+use crate::core::product;
+use crate::core::syntax::SYNTAX;
+use crate::errors::{BuildError, LexErrorKind, ParseErrorKind};
+
+/// Characters other regex dialects use for alternation.
+const ALTERNATION_LOOKALIKES: [char; 1] = ['|'];
+
+/// DFAs with more states than this are reported by [`size_warning`].
+pub const LARGE_DFA_STATES: usize = 64;
+
+/// Alternations with more alternatives than this are not checked for
+/// unreachable ones, since every pair is compared.
+const MAX_CHECKED_ALTERNATIVES: usize = 12;
+
+/// What a [`Suggestion`] changes in the pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixKind {
+    /// Write every `|` as `+`, which is how this syntax spells alternation.
+    UseAlternation,
+    /// Escape every occurrence of the character so it is matched literally.
+    EscapeCharacter(char),
+    /// Remove every occurrence of the character.
+    RemoveCharacter(char),
+    /// Remove the escape character the pattern ends with.
+    RemoveEscape,
+    /// Remove an operator that has no operand.
+    RemoveOperator(OpToken),
+    /// Close the unclosed group with `)`.
+    CloseGroup,
+    /// Remove a `(` that starts a group with nothing in it.
+    RemoveLeftParen,
+    /// Remove a `)` that closes no group.
+    RemoveRightParen,
+    /// Open a group with `(` at the start of the pattern.
+    OpenGroup,
+    /// Write an empty group `()` as the empty string.
+    EmptyGroupAsEpsilon,
+}
+
+/// A corrected pattern proposed for one that failed to build.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    /// What was changed.
+    pub kind: FixKind,
+    /// The corrected pattern, which builds.
+    pub pattern: String,
+}
+
+/// Proposes corrected patterns for `input`, which failed to build with `error`.
+///
+/// # Arguments
+///
+/// - `input` (`&str`) - The pattern that failed to build.
+/// - `error` (`&BuildError`) - The error building it produced.
+///
+/// # Returns
+///
+/// - `Vec<Suggestion>` - Corrections that build, most likely first and
+///   without duplicate patterns. Empty when no heuristic applies.
+#[must_use]
+pub fn suggest(input: &str, error: &BuildError) -> Vec<Suggestion> {
+    let chars: Vec<char> = input.chars().collect();
+    let at = error.at();
+    let mut candidates: Vec<(FixKind, Vec<char>)> = Vec::new();
+
+    match error {
+        BuildError::Lex(error) => match error.kind {
+            LexErrorKind::InvalidCharacter(ch) => {
+                if ALTERNATION_LOOKALIKES.contains(&ch) {
+                    candidates.push((FixKind::UseAlternation, replace_all(&chars, ch, &['+'])));
+                }
+                candidates.push((
+                    FixKind::EscapeCharacter(ch),
+                    replace_all(&chars, ch, &[SYNTAX.escape, ch]),
+                ));
+                candidates.push((FixKind::RemoveCharacter(ch), replace_all(&chars, ch, &[])));
+            }
+            LexErrorKind::DanglingEscape => {
+                candidates.push((FixKind::RemoveEscape, remove(&chars, at)));
+            }
+        },
+        BuildError::Parse(error) => match &error.kind {
+            ParseErrorKind::UnexpectedPrefixOperator(op) => {
+                candidates.push((FixKind::RemoveOperator(*op), remove(&chars, at)));
+            }
+            ParseErrorKind::UnexpectedEof | ParseErrorKind::ParenthesesWithInvalidExp => {
+                // Whatever precedes the error left an expression unfinished.
+                if let Some(before) = previous_char(&chars, at) {
+                    match chars[before] {
+                        '(' if chars.get(at) == Some(&')') => {
+                            let mut fixed = chars[..before].to_vec();
+                            fixed.extend([SYNTAX.escape, SYNTAX.epsilon_escapes[0]]);
+                            fixed.extend(&chars[at + 1..]);
+                            candidates.push((FixKind::EmptyGroupAsEpsilon, fixed));
+                        }
+                        '(' => candidates.push((FixKind::RemoveLeftParen, remove(&chars, before))),
+                        '+' => candidates.push((
+                            FixKind::RemoveOperator(OpToken::Plus),
+                            remove(&chars, before),
+                        )),
+                        '.' => candidates.push((
+                            FixKind::RemoveOperator(OpToken::Dot),
+                            remove(&chars, before),
+                        )),
+                        _ => {}
+                    }
+                }
+            }
+            ParseErrorKind::MismatchedLeftParen { .. } => {
+                let mut fixed = chars.clone();
+                fixed.insert(at.min(chars.len()), ')');
+                candidates.push((FixKind::CloseGroup, fixed));
+            }
+            ParseErrorKind::RightParenWithoutLeft => {
+                candidates.push((FixKind::RemoveRightParen, remove(&chars, at)));
+                let mut fixed = vec!['('];
+                fixed.extend(&chars);
+                candidates.push((FixKind::OpenGroup, fixed));
+            }
+        },
+    }
+
+    let mut suggestions: Vec<Suggestion> = Vec::new();
+    for (kind, fixed) in candidates {
+        let pattern: String = fixed.into_iter().collect();
+        let duplicate = suggestions.iter().any(|s| s.pattern == pattern);
+        if !duplicate && Ast::build(pattern.trim()).is_ok() {
+            suggestions.push(Suggestion { kind, pattern });
+        }
+    }
+    suggestions
+}
+
+/// Something suspicious about a pattern that builds.
+///
+/// Sub-expressions are written back as patterns, with as few parentheses as
+/// the precedence of the operators allows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    /// A repetition of an expression that already matches the empty string
+    /// or is already repeated, as in `(a*)*` or `(a*)?`.
+    RedundantRepetition { pattern: String },
+    /// An alternative whose every string another alternative also matches,
+    /// as in `a+a` or `a+a*`.
+    UnreachableAlternative {
+        alternative: String,
+        covered_by: String,
+    },
+    /// A star over an expression that only matches the empty string, which
+    /// loops on epsilon transitions without reading anything.
+    EpsilonLoop { pattern: String },
+    /// The DFA has more than [`LARGE_DFA_STATES`] states.
+    LargeDfa { states: usize },
+}
+
+/// Looks for redundant or useless constructs in a pattern that builds.
+///
+/// # Arguments
+///
+/// - `ast` (`&Ast`) - The parsed pattern.
+///
+/// # Returns
+///
+/// - `Vec<Warning>` - The warnings, outermost constructs first.
+#[must_use]
+pub fn warnings(ast: &Ast) -> Vec<Warning> {
+    let mut warnings = Vec::new();
+    lint(ast, &mut warnings);
+    warnings
+}
+
+/// Reports `dfa` when it has more than [`LARGE_DFA_STATES`] states.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The DFA built for the pattern.
+///
+/// # Returns
+///
+/// - `Option<Warning>` - A [`Warning::LargeDfa`], or `None` when `dfa` is small.
+#[must_use]
+pub fn size_warning(dfa: &Dfa) -> Option<Warning> {
+    let states = dfa.trans.len();
+    (states > LARGE_DFA_STATES).then_some(Warning::LargeDfa { states })
+}
+
+/// Appends the warnings for `ast` and its sub-expressions to `warnings`.
+fn lint(ast: &Ast, warnings: &mut Vec<Warning>) {
+    match ast {
+        Ast::Epsilon | Ast::Atom(_) => {}
+        Ast::Concat(lhs, rhs) => {
+            lint(lhs, warnings);
+            lint(rhs, warnings);
+        }
+        Ast::Alt(..) => {
+            let mut alternatives = Vec::new();
+            flatten_alternatives(ast, &mut alternatives);
+            if alternatives.len() <= MAX_CHECKED_ALTERNATIVES {
+                unreachable_alternatives(&alternatives, warnings);
+            }
+            for alternative in alternatives {
+                lint(alternative, warnings);
+            }
+        }
+        Ast::Star(inner) => {
+            if only_epsilon(inner) {
+                warnings.push(Warning::EpsilonLoop {
+                    pattern: pattern(ast),
+                });
+            } else if matches!(**inner, Ast::Star(_) | Ast::Opt(_)) {
+                warnings.push(Warning::RedundantRepetition {
+                    pattern: pattern(ast),
+                });
+            }
+            lint(inner, warnings);
+        }
+        Ast::Opt(inner) => {
+            if nullable(inner) {
+                warnings.push(Warning::RedundantRepetition {
+                    pattern: pattern(ast),
+                });
+            }
+            lint(inner, warnings);
+        }
+    }
+}
+
+/// Warns about each alternative whose language another one contains. Of two
+/// alternatives with the same language, only the later one is reported.
+fn unreachable_alternatives(alternatives: &[&Ast], warnings: &mut Vec<Warning>) {
+    let dfas: Vec<Dfa> = alternatives
+        .iter()
+        .map(|alternative| dfa::determinize(&Nfa::build(alternative)))
+        .collect();
+    for (idx, alternative) in alternatives.iter().enumerate() {
+        let covering = (0..alternatives.len()).find(|&other| {
+            other != idx
+                && product::contains(&dfas[idx], &dfas[other]).holds()
+                && (other < idx || !product::contains(&dfas[other], &dfas[idx]).holds())
+        });
+        if let Some(other) = covering {
+            warnings.push(Warning::UnreachableAlternative {
+                alternative: pattern(alternative),
+                covered_by: pattern(alternatives[other]),
+            });
+        }
+    }
+}
+
+/// Collects the operands of a chain of alternations, left to right.
+fn flatten_alternatives<'a>(ast: &'a Ast, alternatives: &mut Vec<&'a Ast>) {
+    if let Ast::Alt(lhs, rhs) = ast {
+        flatten_alternatives(lhs, alternatives);
+        flatten_alternatives(rhs, alternatives);
+    } else {
+        alternatives.push(ast);
+    }
+}
+
+/// Returns whether `ast` matches the empty string.
+fn nullable(ast: &Ast) -> bool {
+    match ast {
+        Ast::Epsilon | Ast::Star(_) | Ast::Opt(_) => true,
+        Ast::Atom(_) => false,
+        Ast::Concat(lhs, rhs) => nullable(lhs) && nullable(rhs),
+        Ast::Alt(lhs, rhs) => nullable(lhs) || nullable(rhs),
+    }
+}
+
+/// Returns whether the empty string is the only string `ast` matches.
+fn only_epsilon(ast: &Ast) -> bool {
+    match ast {
+        Ast::Epsilon => true,
+        Ast::Atom(_) => false,
+        Ast::Concat(lhs, rhs) | Ast::Alt(lhs, rhs) => only_epsilon(lhs) && only_epsilon(rhs),
+        Ast::Star(inner) | Ast::Opt(inner) => only_epsilon(inner),
+    }
+}
+
+/// Writes `ast` back as a pattern the lexer accepts.
+fn pattern(ast: &Ast) -> String {
+    let mut out = String::new();
+    write_pattern(ast, &mut out);
+    out
+}
+
+/// Appends `ast` to `out`, parenthesizing operands that bind more loosely
+/// than their operator, and repeated operands of a repetition.
+fn write_pattern(ast: &Ast, out: &mut String) {
+    let grouped = |ast: &Ast, out: &mut String, group: bool| {
+        if group {
+            out.push('(');
+        }
+        write_pattern(ast, out);
+        if group {
+            out.push(')');
+        }
+    };
+    match ast {
+        Ast::Epsilon => out.push(SYNTAX.epsilon_glyphs[0]),
+        Ast::Atom(c) if c.is_ascii_alphanumeric() => out.push(*c),
+        Ast::Atom(c) => {
+            let _ = write!(out, "{}{c}", SYNTAX.escape);
+        }
+        Ast::Alt(lhs, rhs) => {
+            write_pattern(lhs, out);
+            out.push('+');
+            grouped(rhs, out, matches!(**rhs, Ast::Alt(..)));
+        }
+        Ast::Concat(lhs, rhs) => {
+            grouped(lhs, out, matches!(**lhs, Ast::Alt(..)));
+            grouped(rhs, out, matches!(**rhs, Ast::Alt(..) | Ast::Concat(..)));
+        }
+        Ast::Star(inner) | Ast::Opt(inner) => {
+            grouped(inner, out, !matches!(**inner, Ast::Epsilon | Ast::Atom(_)));
+            out.push(if matches!(ast, Ast::Star(_)) {
+                '*'
+            } else {
+                '?'
+            });
+        }
+    }
+}
+
+/// Returns `chars` with every `from` replaced by `to`.
+fn replace_all(chars: &[char], from: char, to: &[char]) -> Vec<char> {
+    chars
+        .iter()
+        .flat_map(|&ch| if ch == from { to.to_vec() } else { vec![ch] })
+        .collect()
+}
+
+/// Returns `chars` without the character at `at`, if there is one.
+fn remove(chars: &[char], at: usize) -> Vec<char> {
+    let mut fixed = chars.to_vec();
+    if at < fixed.len() {
+        fixed.remove(at);
+    }
+    fixed
+}
+
+/// Returns the index of the last non-whitespace character before `at`.
+fn previous_char(chars: &[char], at: usize) -> Option<usize> {
+    (0..at.min(chars.len()))
+        .rev()
+        .find(|&idx| !chars[idx].is_whitespace())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the suggestions for `input`, which must fail to build.
+    fn suggestions(input: &str) -> Vec<(FixKind, String)> {
+        let error = Ast::build(input).unwrap_err();
+        suggest(input, &error)
+            .into_iter()
+            .map(|s| (s.kind, s.pattern))
+            .collect()
+    }
+
+    #[test]
+    fn test_pipe_becomes_plus() {
+        assert_eq!(
+            suggestions("a|b|c")[0],
+            (FixKind::UseAlternation, "a+b+c".to_string())
+        );
+    }
+
+    #[test]
+    fn test_invalid_character_is_escaped_or_removed() {
+        assert_eq!(
+            suggestions("a$"),
+            vec![
+                (FixKind::EscapeCharacter('$'), "a\\$".to_string()),
+                (FixKind::RemoveCharacter('$'), "a".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_operator_without_operand() {
+        assert_eq!(
+            suggestions("+a"),
+            vec![(FixKind::RemoveOperator(OpToken::Plus), "a".to_string())]
+        );
+        assert_eq!(
+            suggestions("a+"),
+            vec![(FixKind::RemoveOperator(OpToken::Plus), "a".to_string())]
+        );
+        assert_eq!(
+            suggestions("(a+)b"),
+            vec![(FixKind::RemoveOperator(OpToken::Plus), "(a)b".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_unbalanced_parentheses() {
+        assert_eq!(
+            suggestions("(ab"),
+            vec![(FixKind::CloseGroup, "(ab)".to_string())]
+        );
+        assert_eq!(
+            suggestions("ab)c"),
+            vec![
+                (FixKind::RemoveRightParen, "abc".to_string()),
+                (FixKind::OpenGroup, "(ab)c".to_string()),
+            ]
+        );
+        assert_eq!(
+            suggestions("a()"),
+            vec![(FixKind::EmptyGroupAsEpsilon, "a\\e".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_dangling_escape() {
+        assert_eq!(
+            suggestions("ab\\"),
+            vec![(FixKind::RemoveEscape, "ab".to_string())]
+        );
+    }
+
+    /// Returns the warnings for `input`, which must build.
+    fn lint(input: &str) -> Vec<Warning> {
+        warnings(&Ast::build(input).unwrap())
+    }
+
+    #[test]
+    fn test_redundant_repetition() {
+        assert_eq!(
+            lint("(a*)*b"),
+            vec![Warning::RedundantRepetition {
+                pattern: "(a*)*".to_string()
+            }]
+        );
+        assert_eq!(
+            lint("(a+b*)?"),
+            vec![Warning::RedundantRepetition {
+                pattern: "(a+b*)?".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_unreachable_alternative() {
+        assert_eq!(
+            lint("a+a"),
+            vec![Warning::UnreachableAlternative {
+                alternative: "a".to_string(),
+                covered_by: "a".to_string(),
+            }]
+        );
+        assert_eq!(
+            lint("ab+(a+b)*+c"),
+            vec![Warning::UnreachableAlternative {
+                alternative: "ab".to_string(),
+                covered_by: "(a+b)*".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_epsilon_loop() {
+        assert_eq!(
+            lint("a(\\e\\0)*"),
+            vec![Warning::EpsilonLoop {
+                pattern: "(εε)*".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_clean_pattern_has_no_warnings() {
+        assert!(lint("(a+b)*abb").is_empty());
+        assert!(lint("a?b*+\\$").is_empty());
+    }
+
+    #[test]
+    fn test_size_warning() {
+        let dfa = |input: &str| dfa::determinize(&Nfa::build(&Ast::build(input).unwrap()));
+        assert_eq!(size_warning(&dfa("(a+b)*a")), None);
+        let blowup = format!("(a+b)*a{}", "(a+b)".repeat(6));
+        assert!(matches!(
+            size_warning(&dfa(&blowup)),
+            Some(Warning::LargeDfa { states }) if states > LARGE_DFA_STATES
+        ));
+    }
+}
@@ -0,0 +1,262 @@
+//! Structural comparison of two DFAs, e.g. a student's machine against a
+//! reference machine.
+
+use std::collections::VecDeque;
+
+use super::automaton::StateId;
+use super::dfa::Dfa;
+
+/// A transition whose target differs between two aligned states.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TransitionChange {
+    /// The source state in the left automaton.
+    pub left: StateId,
+    /// The aligned source state in the right automaton.
+    pub right: StateId,
+    /// The symbol read.
+    pub symbol: char,
+    /// Target in the left automaton, or `None` if it has no such transition.
+    pub left_target: Option<StateId>,
+    /// Target in the right automaton, or `None` if it has no such transition.
+    pub right_target: Option<StateId>,
+}
+
+/// An aligned pair of states where only one of them is accepting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AcceptanceChange {
+    /// The state in the left automaton.
+    pub left: StateId,
+    /// The aligned state in the right automaton.
+    pub right: StateId,
+    /// Whether the left state is the accepting one.
+    pub left_accepting: bool,
+}
+
+/// Differences between two DFAs whose states were aligned by [`diff`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DfaDiff {
+    /// Aligned `(left, right)` state pairs. Index `i` is the canonical number
+    /// of the pair: the order in which both machines reach it from their start
+    /// states, breadth-first, reading symbols in sorted order.
+    pub matched: Vec<(StateId, StateId)>,
+    /// Left states without a counterpart, in ascending order.
+    pub removed: Vec<StateId>,
+    /// Right states without a counterpart, in ascending order.
+    pub added: Vec<StateId>,
+    /// Aligned pairs where only one of the states is accepting.
+    pub acceptance_changed: Vec<AcceptanceChange>,
+    /// Transitions of aligned pairs that lead to states that are not aligned
+    /// with each other, ordered by canonical number, then symbol.
+    pub transitions_changed: Vec<TransitionChange>,
+    /// Symbols only in the left alphabet, in ascending order.
+    pub symbols_removed: Vec<char>,
+    /// Symbols only in the right alphabet, in ascending order.
+    pub symbols_added: Vec<char>,
+}
+
+impl DfaDiff {
+    /// Returns whether both automata are the same machine up to renaming states.
+    #[must_use]
+    pub fn is_identical(&self) -> bool {
+        self.removed.is_empty()
+            && self.added.is_empty()
+            && self.acceptance_changed.is_empty()
+            && self.transitions_changed.is_empty()
+            && self.symbols_removed.is_empty()
+            && self.symbols_added.is_empty()
+    }
+
+    /// Returns the canonical number of a left state, if it was aligned.
+    #[must_use]
+    pub fn left_index(&self, state: StateId) -> Option<usize> {
+        self.matched.iter().position(|&(left, _)| left == state)
+    }
+
+    /// Returns the canonical number of a right state, if it was aligned.
+    #[must_use]
+    pub fn right_index(&self, state: StateId) -> Option<usize> {
+        self.matched.iter().position(|&(_, right)| right == state)
+    }
+}
+
+/// Aligns two DFAs state by state and lists where they differ.
+///
+/// The start states are aligned first. Then, breadth-first and reading the
+/// symbols of both alphabets in sorted order, the targets of every aligned
+/// pair are aligned too, unless either target is already aligned elsewhere.
+/// Isomorphic machines are therefore aligned completely, and a machine with an
+/// extra or missing state still lines up everywhere else.
+///
+/// Both machines should pass [`Dfa::validate_partial`]; a transition to a
+/// state that does not exist is treated as missing.
+///
+/// # Arguments
+///
+/// - `left` (`&Dfa`) - The machine compared against, e.g. the reference.
+/// - `right` (`&Dfa`) - The machine being compared, e.g. a student's answer.
+///   Rows may be shorter than the alphabet, leaving transitions out.
+///
+/// # Returns
+///
+/// - `DfaDiff` - The alignment and every difference found.
+#[must_use]
+pub fn diff(left: &Dfa, right: &Dfa) -> DfaDiff {
+    let mut symbols: Vec<char> = left
+        .alphabet
+        .iter()
+        .chain(&right.alphabet)
+        .copied()
+        .collect();
+    symbols.sort_unstable();
+    symbols.dedup();
+
+    let mut left_match = vec![false; left.trans.len()];
+    let mut right_match = vec![false; right.trans.len()];
+    let mut matched = Vec::new();
+    let mut queue = VecDeque::new();
+    if (left.start as usize) < left.trans.len() && (right.start as usize) < right.trans.len() {
+        left_match[left.start as usize] = true;
+        right_match[right.start as usize] = true;
+        matched.push((left.start, right.start));
+        queue.push_back((left.start, right.start));
+    }
+    while let Some((l, r)) = queue.pop_front() {
+        for &symbol in &symbols {
+            let (Some(lt), Some(rt)) = (target(left, l, symbol), target(right, r, symbol)) else {
+                continue;
+            };
+            if !left_match[lt as usize] && !right_match[rt as usize] {
+                left_match[lt as usize] = true;
+                right_match[rt as usize] = true;
+                matched.push((lt, rt));
+                queue.push_back((lt, rt));
+            }
+        }
+    }
+
+    let acceptance_changed = matched
+        .iter()
+        .filter_map(|&(l, r)| {
+            let left_accepting = left.accepts.contains(&l);
+            (left_accepting != right.accepts.contains(&r)).then_some(AcceptanceChange {
+                left: l,
+                right: r,
+                left_accepting,
+            })
+        })
+        .collect();
+    let mut transitions_changed = Vec::new();
+    for &(l, r) in &matched {
+        for &symbol in &symbols {
+            let left_target = target(left, l, symbol);
+            let right_target = target(right, r, symbol);
+            let aligned = match (left_target, right_target) {
+                (Some(lt), Some(rt)) => matched.contains(&(lt, rt)),
+                (None, None) => true,
+                _ => false,
+            };
+            if !aligned {
+                transitions_changed.push(TransitionChange {
+                    left: l,
+                    right: r,
+                    symbol,
+                    left_target,
+                    right_target,
+                });
+            }
+        }
+    }
+
+    let unmatched = |flags: &[bool]| {
+        flags
+            .iter()
+            .enumerate()
+            .filter(|(_, matched)| !**matched)
+            .map(|(state, _)| state as StateId)
+            .collect()
+    };
+    let only_in = |dfa: &Dfa, other: &Dfa| {
+        symbols
+            .iter()
+            .filter(|symbol| dfa.alphabet.contains(symbol) && !other.alphabet.contains(symbol))
+            .copied()
+            .collect()
+    };
+
+    DfaDiff {
+        removed: unmatched(&left_match),
+        added: unmatched(&right_match),
+        acceptance_changed,
+        transitions_changed,
+        symbols_removed: only_in(left, right),
+        symbols_added: only_in(right, left),
+        matched,
+    }
+}
+
+/// Returns the target of `state` on `symbol`, if the transition exists.
+fn target(dfa: &Dfa, state: StateId, symbol: char) -> Option<StateId> {
+    dfa.step(state, symbol)
+        .filter(|&next| (next as usize) < dfa.trans.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{dfa::determinize, min::minimize, nfa::Nfa, parser::Ast};
+
+    fn min_dfa(pattern: &str) -> Dfa {
+        minimize(&determinize(&Nfa::build(&Ast::build(pattern).unwrap())))
+    }
+
+    #[test]
+    fn test_diff_isomorphic_machines() {
+        let dfa = min_dfa("(a+b)*abb");
+        // Renumber the states by reversing them.
+        let last = dfa.trans.len() as StateId - 1;
+        let renumbered = Dfa {
+            states: dfa.states.clone(),
+            start: last - dfa.start,
+            accepts: dfa.accepts.iter().map(|s| last - s).collect(),
+            trans: dfa
+                .trans
+                .iter()
+                .rev()
+                .map(|row| row.iter().map(|s| last - s).collect())
+                .collect(),
+            alphabet: dfa.alphabet.clone(),
+        };
+
+        let report = diff(&dfa, &renumbered);
+        assert!(report.is_identical());
+        assert_eq!(report.matched.len(), dfa.states.len());
+        assert_eq!(report.matched[0], (dfa.start, renumbered.start));
+    }
+
+    #[test]
+    fn test_diff_extra_state() {
+        // `(a+b)*ab` needs one state fewer than `(a+b)*abb`.
+        let reference = min_dfa("(a+b)*abb");
+        let answer = min_dfa("(a+b)*ab");
+        let report = diff(&reference, &answer);
+        assert!(!report.is_identical());
+        assert_eq!(report.removed.len(), 1);
+        assert!(report.added.is_empty());
+        assert!(!report.transitions_changed.is_empty());
+        assert_eq!(report.left_index(reference.start), Some(0));
+        assert_eq!(report.right_index(answer.start), Some(0));
+    }
+
+    #[test]
+    fn test_diff_acceptance_and_alphabet() {
+        let report = diff(&min_dfa("a*"), &min_dfa("a+b"));
+        assert_eq!(report.symbols_added, vec!['b']);
+        assert!(report.symbols_removed.is_empty());
+        // `a*` accepts the empty string, `a+b` does not.
+        assert_eq!(report.acceptance_changed[0].left, 0);
+        assert!(report.acceptance_changed[0].left_accepting);
+    }
+}
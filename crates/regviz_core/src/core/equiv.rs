@@ -0,0 +1,171 @@
+use std::collections::VecDeque;
+
+use super::automaton::StateId;
+use super::dfa::Dfa;
+
+/// Outcome of a language equivalence check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Equivalence {
+    /// Both automata accept the same language.
+    Equivalent,
+    /// A word accepted by exactly one of the automata.
+    Distinguished(String),
+}
+
+impl Equivalence {
+    /// Returns whether the languages are equal.
+    #[must_use]
+    pub fn is_equivalent(&self) -> bool {
+        matches!(self, Equivalence::Equivalent)
+    }
+}
+
+/// Checks two DFAs for language equivalence with the Hopcroft–Karp algorithm.
+///
+/// The start states are merged in a union-find structure, then every pair of
+/// states reachable by the same word is merged as well. The languages differ
+/// exactly when some merged pair disagrees on acceptance. Each state is merged
+/// at most once, so the check runs in near-linear time and never builds the
+/// product automaton. Pairs are explored breadth-first, so the witness is
+/// short, although not necessarily the shortest.
+///
+/// Symbols missing from one alphabet send that automaton to an implicit dead
+/// state, as in [`super::product::product`].
+///
+/// # Arguments
+///
+/// - `left` (`&Dfa`) - The first automaton.
+/// - `right` (`&Dfa`) - The second automaton.
+///
+/// # Returns
+///
+/// - `Equivalence` - Whether the languages match, with a distinguishing word if not.
+#[must_use]
+pub fn hopcroft_karp(left: &Dfa, right: &Dfa) -> Equivalence {
+    let mut alphabet: Vec<char> = left
+        .alphabet
+        .iter()
+        .chain(&right.alphabet)
+        .copied()
+        .collect();
+    alphabet.sort_unstable();
+    alphabet.dedup();
+
+    let sides = [left, right];
+    // Nodes are numbered left states, right states, then one dead state per side.
+    let offsets = [0, left.trans.len()];
+    let dead = [
+        left.trans.len() + right.trans.len(),
+        left.trans.len() + right.trans.len() + 1,
+    ];
+    let node = |side: usize, state: Option<StateId>| {
+        state.map_or(dead[side], |state| offsets[side] + state as usize)
+    };
+    let step = |side: usize, state: Option<StateId>, symbol: char| sides[side].step(state?, symbol);
+    let accepts = |side: usize, state: Option<StateId>| {
+        state.is_some_and(|state| sides[side].accepts.contains(&state))
+    };
+
+    let mut sets = DisjointSets::new(dead[1] + 1);
+    let (left_start, right_start) = (Some(left.start), Some(right.start));
+    sets.union(node(0, left_start), node(1, right_start));
+    let mut queue = VecDeque::from([(left_start, right_start, String::new())]);
+    while let Some((l, r, word)) = queue.pop_front() {
+        if accepts(0, l) != accepts(1, r) {
+            return Equivalence::Distinguished(word);
+        }
+        for &symbol in &alphabet {
+            let (next_l, next_r) = (step(0, l, symbol), step(1, r, symbol));
+            if sets.union(node(0, next_l), node(1, next_r)) {
+                let mut longer = word.clone();
+                longer.push(symbol);
+                queue.push_back((next_l, next_r, longer));
+            }
+        }
+    }
+    Equivalence::Equivalent
+}
+
+/// Union-find with path halving and union by size.
+struct DisjointSets {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl DisjointSets {
+    fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+        }
+    }
+
+    fn find(&mut self, mut item: usize) -> usize {
+        while self.parent[item] != item {
+            self.parent[item] = self.parent[self.parent[item]];
+            item = self.parent[item];
+        }
+        item
+    }
+
+    /// Merges the sets of `a` and `b`, returning `false` if they were already merged.
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::product::{self, BoolOp};
+    use crate::core::{dfa, min, nfa::Nfa, parser::Ast, sim};
+
+    fn dfa(pattern: &str) -> Dfa {
+        dfa::determinize(&Nfa::build(&Ast::build(pattern).unwrap()))
+    }
+
+    #[test]
+    fn test_hopcroft_karp_agrees_with_product() {
+        let patterns = [
+            "(a+b)*", "(a*b*)*", "a*", "aa*", "a?b", "ab+b", "(ab)*a", "a(ba)*",
+        ];
+        for left in patterns {
+            for right in patterns {
+                let (l, r) = (dfa(left), dfa(right));
+                let expected = product::product(&l, &r, BoolOp::SymmetricDifference)
+                    .shortest_accepted()
+                    .is_none();
+                match hopcroft_karp(&l, &r) {
+                    Equivalence::Equivalent => assert!(expected, "{left} vs {right}"),
+                    Equivalence::Distinguished(word) => {
+                        assert!(!expected, "{left} vs {right}");
+                        assert_ne!(
+                            sim::simulate_dfa(&l, &word),
+                            sim::simulate_dfa(&r, &word),
+                            "{word:?} should tell {left} and {right} apart"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_hopcroft_karp_handles_minimized_and_foreign_symbols() {
+        let full = dfa("(ab)*a");
+        assert!(hopcroft_karp(&full, &min::minimize(&full)).is_equivalent());
+        assert_eq!(
+            hopcroft_karp(&dfa("a+c"), &dfa("a")),
+            Equivalence::Distinguished("c".to_string())
+        );
+    }
+}
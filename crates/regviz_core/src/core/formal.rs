@@ -0,0 +1,421 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use super::automaton::{EdgeLabel, EpsilonGlyph, StateId};
+use super::dfa::Dfa;
+use super::nfa::Nfa;
+
+/// An automaton written out as its formal definition, the five-tuple
+/// `(Q, Σ, δ, q₀, F)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormalDefinition {
+    /// `Q`: every state, in ascending order.
+    pub states: Vec<StateId>,
+    /// `Σ`: the input symbols, in ascending order.
+    pub alphabet: Vec<char>,
+    /// Whether `δ` maps to single states rather than to sets of states.
+    pub deterministic: bool,
+    /// `δ`: one row per entry of `states`, holding the states reached on each
+    /// symbol of `alphabet`, followed by those reached on ε when the automaton
+    /// is nondeterministic.
+    pub delta: Vec<Vec<Vec<StateId>>>,
+    /// `q₀`: the start state.
+    pub start: StateId,
+    /// `F`: the accepting states, in ascending order.
+    pub accepting: Vec<StateId>,
+    /// Display names for renamed or renumbered states. Unlisted states use their id.
+    pub labels: BTreeMap<StateId, String>,
+    /// Notation for the empty string in the ε column.
+    pub epsilon: EpsilonGlyph,
+}
+
+impl FormalDefinition {
+    /// Writes out a Thompson NFA, whose transition function maps to sets of
+    /// states and includes ε moves.
+    #[must_use]
+    pub fn from_nfa(nfa: &Nfa) -> Self {
+        let alphabet = nfa.alphabet();
+        let states: Vec<StateId> = nfa
+            .states
+            .iter()
+            .map(|state| state.id)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let delta = states
+            .iter()
+            .map(|&state| {
+                let mut row = vec![BTreeSet::new(); alphabet.len() + 1];
+                for transition in nfa.transitions(state) {
+                    let column = match transition.label {
+                        EdgeLabel::Sym(symbol) => alphabet.binary_search(&symbol).ok(),
+                        EdgeLabel::Eps => Some(alphabet.len()),
+                    };
+                    if let Some(column) = column {
+                        row[column].insert(transition.to);
+                    }
+                }
+                row.into_iter()
+                    .map(|set| set.into_iter().collect())
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            states,
+            alphabet,
+            deterministic: false,
+            delta,
+            start: nfa.start,
+            accepting: sorted(&nfa.accepts),
+            labels: BTreeMap::new(),
+            epsilon: EpsilonGlyph::default(),
+        }
+    }
+
+    /// Writes out a complete DFA, whose transition function maps every state
+    /// and symbol to exactly one state.
+    #[must_use]
+    pub fn from_dfa(dfa: &Dfa) -> Self {
+        let states = sorted(&dfa.states);
+        let delta = states
+            .iter()
+            .map(|&state| {
+                dfa.trans[state as usize]
+                    .iter()
+                    .map(|&next| vec![next])
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            states,
+            alphabet: dfa.alphabet.clone(),
+            deterministic: true,
+            delta,
+            start: dfa.start,
+            accepting: sorted(&dfa.accepts),
+            labels: BTreeMap::new(),
+            epsilon: EpsilonGlyph::default(),
+        }
+    }
+
+    /// Attaches display names to states, used wherever the definition names a state.
+    ///
+    /// # Arguments
+    ///
+    /// - `labels` (`impl IntoIterator<Item = (StateId, String)>`) - Names keyed by state id.
+    ///
+    /// # Returns
+    ///
+    /// - `FormalDefinition` - The definition with the labels replaced.
+    #[must_use]
+    pub fn with_labels(mut self, labels: impl IntoIterator<Item = (StateId, String)>) -> Self {
+        self.labels = labels.into_iter().collect();
+        self
+    }
+
+    /// Draws the empty string with `glyph`.
+    #[must_use]
+    pub fn with_epsilon_glyph(mut self, glyph: EpsilonGlyph) -> Self {
+        self.epsilon = glyph;
+        self
+    }
+
+    /// Returns the display name of `state`: its label, or its id if it has none.
+    #[must_use]
+    pub fn state_name(&self, state: StateId) -> String {
+        self.labels
+            .get(&state)
+            .cloned()
+            .unwrap_or_else(|| state.to_string())
+    }
+
+    /// Returns the headings of the columns of `δ`: each symbol, then ε for
+    /// nondeterministic automata.
+    #[must_use]
+    pub fn columns(&self) -> Vec<String> {
+        let mut columns: Vec<String> = self.alphabet.iter().map(char::to_string).collect();
+        if !self.deterministic {
+            columns.push(self.epsilon.to_string());
+        }
+        columns
+    }
+
+    /// Writes a set of states, `∅` when it is empty.
+    #[must_use]
+    pub fn set_text(&self, states: &[StateId]) -> String {
+        if states.is_empty() {
+            return "∅".to_string();
+        }
+        format!("{{{}}}", self.names(states).join(", "))
+    }
+
+    /// Writes one cell of `δ`: the state reached by a DFA, or the set of
+    /// states reached by an NFA.
+    #[must_use]
+    pub fn target_text(&self, targets: &[StateId]) -> String {
+        match targets {
+            [state] if self.deterministic => self.state_name(*state),
+            targets => self.set_text(targets),
+        }
+    }
+
+    /// Writes `δ` as a function listing, one `δ(q, a) = …` line per
+    /// transition. NFA entries mapping to `∅` are left out.
+    #[must_use]
+    pub fn transition_lines(&self) -> Vec<String> {
+        let columns = self.columns();
+        self.states
+            .iter()
+            .zip(&self.delta)
+            .flat_map(|(&state, row)| {
+                row.iter()
+                    .zip(&columns)
+                    .filter(|(targets, _)| self.deterministic || !targets.is_empty())
+                    .map(move |(targets, column)| {
+                        format!(
+                            "δ({}, {column}) = {}",
+                            self.state_name(state),
+                            self.target_text(targets)
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    /// Writes the tuple and every component but `δ`, one line each.
+    #[must_use]
+    pub fn component_lines(&self) -> Vec<String> {
+        let symbols: Vec<String> = self.alphabet.iter().map(char::to_string).collect();
+        vec![
+            "M = (Q, Σ, δ, q₀, F)".to_string(),
+            format!("Q = {}", self.set_text(&self.states)),
+            format!("Σ = {{{}}}", symbols.join(", ")),
+            format!("q₀ = {}", self.state_name(self.start)),
+            format!("F = {}", self.set_text(&self.accepting)),
+        ]
+    }
+
+    /// Writes the definition as plain Unicode text: the tuple, then each of
+    /// its components, with `δ` as a function listing.
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for line in self
+            .component_lines()
+            .into_iter()
+            .chain(self.transition_lines())
+        {
+            let _ = writeln!(text, "{line}");
+        }
+        text
+    }
+
+    /// Writes the definition as LaTeX source: the components in an `align*`
+    /// environment and `δ` as an `array`, with the start state marked `→` and
+    /// accepting states marked `*`.
+    ///
+    /// Requires the `amsmath` package.
+    #[must_use]
+    pub fn to_latex(&self) -> String {
+        let set = |states: &[StateId]| -> String {
+            if states.is_empty() {
+                return r"\emptyset".to_string();
+            }
+            let names: Vec<String> = states.iter().map(|&s| self.latex_state(s)).collect();
+            format!(r"\{{{}\}}", names.join(", "))
+        };
+        let symbols: Vec<String> = self.alphabet.iter().map(|&c| latex_symbol(c)).collect();
+        let mut columns = symbols.clone();
+        if !self.deterministic {
+            columns.push(self.latex_epsilon().to_string());
+        }
+
+        let mut latex = String::from("\\[ M = (Q, \\Sigma, \\delta, q_0, F) \\]\n");
+        latex.push_str("\\begin{align*}\n");
+        let _ = writeln!(latex, "Q &= {} \\\\", set(&self.states));
+        let _ = writeln!(latex, "\\Sigma &= \\{{{}\\}} \\\\", symbols.join(", "));
+        let _ = writeln!(latex, "q_0 &= {} \\\\", self.latex_state(self.start));
+        let _ = writeln!(latex, "F &= {}", set(&self.accepting));
+        latex.push_str("\\end{align*}\n");
+
+        latex.push_str("\\[\n");
+        let _ = writeln!(latex, "\\begin{{array}}{{r|{}}}", "c".repeat(columns.len()));
+        let _ = writeln!(latex, "\\delta & {} \\\\ \\hline", columns.join(" & "));
+        for (&state, row) in self.states.iter().zip(&self.delta) {
+            let mut marker = String::new();
+            if state == self.start {
+                marker.push_str("\\rightarrow ");
+            }
+            if self.accepting.contains(&state) {
+                marker.push_str("{*} ");
+            }
+            let cells: Vec<String> = row
+                .iter()
+                .map(|targets| match targets.as_slice() {
+                    [next] if self.deterministic => self.latex_state(*next),
+                    targets => set(targets),
+                })
+                .collect();
+            let _ = writeln!(
+                latex,
+                "{marker}{} & {} \\\\",
+                self.latex_state(state),
+                cells.join(" & ")
+            );
+        }
+        latex.push_str("\\end{array}\n\\]\n");
+        latex
+    }
+
+    /// Returns the display names of `states`.
+    fn names(&self, states: &[StateId]) -> Vec<String> {
+        states.iter().map(|&state| self.state_name(state)).collect()
+    }
+
+    /// Names `state` in math mode, falling back to `\text` for names that are
+    /// not plain letters and digits.
+    fn latex_state(&self, state: StateId) -> String {
+        let name = self.state_name(state);
+        if name.chars().all(|c| c.is_ascii_alphanumeric()) {
+            name
+        } else {
+            format!(r"\text{{{}}}", latex_escape(&name))
+        }
+    }
+
+    /// Returns the math-mode command drawing the empty string.
+    fn latex_epsilon(&self) -> &'static str {
+        match self.epsilon {
+            EpsilonGlyph::Epsilon => r"\varepsilon",
+            EpsilonGlyph::Lambda => r"\lambda",
+        }
+    }
+}
+
+/// Returns `states` in ascending order.
+fn sorted(states: &[StateId]) -> Vec<StateId> {
+    let mut states = states.to_vec();
+    states.sort_unstable();
+    states
+}
+
+/// Writes an input symbol in typewriter type.
+fn latex_symbol(symbol: char) -> String {
+    format!(r"\mathtt{{{}}}", latex_escape(&symbol.to_string()))
+}
+
+/// Escapes the characters LaTeX treats specially.
+fn latex_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str(r"\textbackslash{}"),
+            '~' => escaped.push_str(r"\textasciitilde{}"),
+            '^' => escaped.push_str(r"\textasciicircum{}"),
+            '#' | '$' | '%' | '&' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{dfa, parser};
+
+    fn nfa(pattern: &str) -> Nfa {
+        Nfa::build(&parser::Ast::build(pattern).unwrap())
+    }
+
+    #[test]
+    fn test_nfa_definition_lists_epsilon_column() {
+        let nfa = nfa("a*");
+        let definition = FormalDefinition::from_nfa(&nfa);
+        assert!(!definition.deterministic);
+        assert_eq!(definition.alphabet, vec!['a']);
+        assert_eq!(definition.columns(), vec!["a", "ε"]);
+        assert_eq!(definition.states.len(), nfa.states.len());
+        assert!(definition.delta.iter().all(|row| row.len() == 2));
+
+        let epsilon_moves: usize = definition.delta.iter().map(|row| row[1].len()).sum();
+        let expected = nfa
+            .edges
+            .iter()
+            .filter(|edge| edge.label == EdgeLabel::Eps)
+            .count();
+        assert_eq!(epsilon_moves, expected);
+        // Empty cells are left out of the listing.
+        let moves: BTreeSet<_> = nfa
+            .edges
+            .iter()
+            .map(|edge| (edge.from, edge.label.to_string()))
+            .collect();
+        assert_eq!(definition.transition_lines().len(), moves.len());
+    }
+
+    #[test]
+    fn test_dfa_definition_is_total() {
+        let dfa = dfa::determinize(&nfa("(a+b)*abb"));
+        let definition = FormalDefinition::from_dfa(&dfa);
+        assert!(definition.deterministic);
+        assert_eq!(definition.columns(), vec!["a", "b"]);
+        assert!(
+            definition
+                .delta
+                .iter()
+                .all(|row| row.len() == 2 && row.iter().all(|cell| cell.len() == 1))
+        );
+        assert_eq!(
+            definition.transition_lines().len(),
+            dfa.states.len() * dfa.alphabet.len()
+        );
+    }
+
+    #[test]
+    fn test_text_names_states_by_label() {
+        let dfa = dfa::determinize(&nfa("a"));
+        let labels = dfa
+            .states
+            .iter()
+            .map(|&state| (state, format!("q{state}")))
+            .collect::<Vec<_>>();
+        let text = FormalDefinition::from_dfa(&dfa)
+            .with_labels(labels)
+            .to_text();
+        assert!(text.starts_with("M = (Q, Σ, δ, q₀, F)\n"));
+        assert!(text.contains(&format!("q₀ = q{}\n", dfa.start)));
+        assert!(text.contains("Σ = {a}\n"));
+        assert!(text.contains(&format!("δ(q{}, a) = ", dfa.start)));
+    }
+
+    #[test]
+    fn test_latex_escapes_and_marks_states() {
+        let dfa = dfa::determinize(&nfa("a"));
+        let latex = FormalDefinition::from_dfa(&dfa)
+            .with_labels([(dfa.start, "s_0".to_string())])
+            .to_latex();
+        assert!(latex.contains(r"q_0 &= \text{s\_0}"));
+        assert!(latex.contains(r"\begin{array}{r|c}"));
+        assert!(latex.contains(r"\rightarrow \text{s\_0} & "));
+        assert!(latex.contains("{*} "));
+
+        let latex = FormalDefinition::from_nfa(&nfa("a"))
+            .with_epsilon_glyph(EpsilonGlyph::Lambda)
+            .to_latex();
+        assert!(latex.contains(r"\delta & \mathtt{a} & \lambda \\ \hline"));
+        assert!(latex.contains(r"\emptyset"));
+    }
+
+    #[test]
+    fn test_latex_escape_special_characters() {
+        assert_eq!(latex_escape("a_b{c}"), r"a\_b\{c\}");
+        assert_eq!(latex_escape(r"\"), r"\textbackslash{}");
+        assert_eq!(latex_symbol('#'), r"\mathtt{\#}");
+    }
+}
@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+use crate::core::syntax::SYNTAX;
 use crate::errors::{LexError, LexErrorKind};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -43,6 +44,51 @@ pub enum OpToken {
     Opt,
 }
 
+/// Broad category of a token, for presenting the token stream.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TokenClass {
+    /// A literal character, possibly escaped.
+    Literal,
+    /// The empty string.
+    Epsilon,
+    /// An operator.
+    Operator,
+    /// A parenthesis.
+    Group,
+    /// The end of the input.
+    End,
+}
+
+impl Token {
+    /// Returns the category this token belongs to.
+    #[must_use]
+    pub const fn class(&self) -> TokenClass {
+        match self {
+            Token::Epsilon => TokenClass::Epsilon,
+            Token::Literal(_) => TokenClass::Literal,
+            Token::Op(_) => TokenClass::Operator,
+            Token::LParen | Token::RParen => TokenClass::Group,
+            Token::Eof => TokenClass::End,
+        }
+    }
+}
+
+/// A token with the characters of the input it was read from.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SpannedToken {
+    /// The token.
+    pub token: Token,
+    /// Char index of the token's first character, including any escape.
+    pub start: usize,
+    /// Char index just past the token's last character.
+    pub end: usize,
+}
+
+impl OpToken {
+    /// Every operator token, in declaration order.
+    pub const ALL: [OpToken; 4] = [OpToken::Plus, OpToken::Star, OpToken::Dot, OpToken::Opt];
+}
+
 impl Display for OpToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let symbol = match self {
@@ -69,19 +115,43 @@ impl Lexer {
     /// Processes the input string into a sequence of tokens, stored in the [`Lexer`] instance.
     /// Returns a [`LexError`] if the input contains invalid characters.
     pub fn new(input: &str) -> Result<Self, LexError> {
+        // Tokens are reported at their last character: the escaped one for escapes.
+        let mut tokens: Vec<(Token, CharIndex)> = Self::tokenize_all(input)?
+            .into_iter()
+            .map(|spanned| (spanned.token, spanned.end - 1))
+            .collect();
+        tokens.reverse();
+        Ok(Self {
+            tokens,
+            num_chars: input.chars().count(),
+        })
+    }
+
+    /// Splits the input string into tokens, keeping the characters each was read from.
+    ///
+    /// # Arguments
+    ///
+    /// - `input` (`&str`) - The regular expression to tokenize.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<SpannedToken>, LexError>` - The tokens in input order, without
+    ///   a final [`Token::Eof`], or the first character that could not be read.
+    pub fn tokenize_all(input: &str) -> Result<Vec<SpannedToken>, LexError> {
         let mut tokens = Vec::new();
         let mut chars = input.chars().enumerate();
 
         while let Some((mut idx, ch)) = chars.next() {
+            let start = idx;
             let token = match ch {
                 // Escape character, treat next character as literal
-                '\\' => {
+                c if c == SYNTAX.escape => {
                     if let Some((next_idx, next_ch)) = chars.next() {
                         // Update idx to point to the escaped character
                         idx = next_idx;
 
-                        // Check for epsilon escape
-                        if next_ch == 'e' {
+                        // Check for epsilon escapes (`\e` or `\0`)
+                        if SYNTAX.epsilon_escapes.contains(&next_ch) {
                             Token::Epsilon
                         } else {
                             // Treat next character as literal
@@ -100,6 +170,8 @@ impl Lexer {
                 '?' => Token::Op(OpToken::Opt),
                 '(' => Token::LParen,
                 ')' => Token::RParen,
+                // Epsilon written directly with either textbook glyph
+                c if SYNTAX.epsilon_glyphs.contains(&c) => Token::Epsilon,
                 // Skip whitespace
                 c if c.is_ascii_whitespace() => continue,
                 // Only allow alphanumeric literals
@@ -112,15 +184,15 @@ impl Lexer {
                 }
             };
 
-            // Store token with its original index
-            tokens.push((token, idx));
+            // Store token with the characters it was read from
+            tokens.push(SpannedToken {
+                token,
+                start,
+                end: idx + 1,
+            });
         }
 
-        tokens.reverse();
-        Ok(Self {
-            tokens,
-            num_chars: input.chars().count(),
-        })
+        Ok(tokens)
     }
 
     /// Advance to the next token and return it.
@@ -142,7 +214,7 @@ impl Lexer {
 #[cfg(test)]
 mod tests {
     use crate::{
-        core::lexer::{Lexer, OpToken, Token},
+        core::lexer::{Lexer, OpToken, SpannedToken, Token, TokenClass},
         errors::{LexError, LexErrorKind},
     };
 
@@ -187,6 +259,17 @@ mod tests {
         assert_eq!(lexer.advance(), (Token::Eof, 7));
     }
 
+    #[test]
+    fn test_lexer_epsilon_spellings() {
+        let mut lexer = Lexer::new(r"\e\0ελ").unwrap();
+
+        assert_eq!(lexer.advance(), (Token::Epsilon, 1));
+        assert_eq!(lexer.advance(), (Token::Epsilon, 3));
+        assert_eq!(lexer.advance(), (Token::Epsilon, 4));
+        assert_eq!(lexer.advance(), (Token::Epsilon, 5));
+        assert_eq!(lexer.advance(), (Token::Eof, 6));
+    }
+
     #[test]
     fn test_lexer_invalid_character() {
         let input = "a+b$c";
@@ -216,4 +299,37 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_tokenize_all_spans() {
+        let tokens = Lexer::tokenize_all(r"a \+(b)*").unwrap();
+        let spans: Vec<(Token, usize, usize)> = tokens
+            .iter()
+            .map(|SpannedToken { token, start, end }| (*token, *start, *end))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                (Token::Literal('a'), 0, 1),
+                (Token::Literal('+'), 2, 4),
+                (Token::LParen, 4, 5),
+                (Token::Literal('b'), 5, 6),
+                (Token::RParen, 6, 7),
+                (Token::Op(OpToken::Star), 7, 8),
+            ]
+        );
+        assert_eq!(tokens[1].token.class(), TokenClass::Literal);
+        assert_eq!(tokens[5].token.class(), TokenClass::Operator);
+    }
+
+    #[test]
+    fn test_tokenize_all_reports_errors() {
+        assert_eq!(
+            Lexer::tokenize_all("a$"),
+            Err(LexError {
+                at: 1,
+                kind: LexErrorKind::InvalidCharacter('$'),
+            })
+        );
+    }
 }
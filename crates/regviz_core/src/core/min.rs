@@ -1,17 +1,130 @@
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use crate::core::automaton::StateId;
 use crate::core::dfa::Dfa;
 
 /// Minimizes a DFA using Hopcroft's partition refinement algorithm.
 pub fn minimize(dfa: &Dfa) -> Dfa {
+    minimize_with_mapping(dfa).0
+}
+
+/// Minimizes a DFA like [`minimize`], also reporting which states were merged.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The DFA to minimize.
+///
+/// # Returns
+///
+/// - `(Dfa, Vec<StateId>)` - The minimized DFA, and for every original state,
+///   indexed by state id, the minimized state it was merged into. States that
+///   share a target are equivalent.
+#[must_use]
+pub fn minimize_with_mapping(dfa: &Dfa) -> (Dfa, Vec<StateId>) {
     if dfa.trans.len() <= 1 {
-        return dfa.clone();
+        return (dfa.clone(), (0..dfa.trans.len() as StateId).collect());
     }
 
     PartitionRefinement::new(dfa).run()
 }
 
+/// Minimizes a DFA using Brzozowski's algorithm: determinizing its reverse,
+/// then the reverse of the result.
+///
+/// Accepts the same language as [`minimize`] with no more states: the counts
+/// match when every state of `dfa` is reachable, since only [`minimize`] keeps
+/// unreachable ones. Its states are numbered differently and it can take
+/// exponential time.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The DFA to minimize.
+///
+/// # Returns
+///
+/// - `Dfa` - The minimal DFA, over the alphabet of `dfa`.
+#[must_use]
+pub fn minimize_brzozowski(dfa: &Dfa) -> Dfa {
+    determinize_reverse(&determinize_reverse(dfa))
+}
+
+/// Determinizes the reverse of `dfa` by subset construction.
+///
+/// Unlike determinizing [`Dfa::reverse`], the subsets start from the set of
+/// accepting states itself rather than from a fresh state with ε moves into
+/// it, which Brzozowski's algorithm needs to end on a minimal DFA.
+fn determinize_reverse(dfa: &Dfa) -> Dfa {
+    // States reading each symbol into each state.
+    let mut predecessors = vec![vec![Vec::new(); dfa.alphabet.len()]; dfa.trans.len()];
+    for (from, row) in dfa.trans.iter().enumerate() {
+        for (symbol, &to) in row.iter().enumerate() {
+            predecessors[to as usize][symbol].push(from as StateId);
+        }
+    }
+
+    let mut start = dfa.accepts.clone();
+    start.sort_unstable();
+    start.dedup();
+    let mut ids = HashMap::from([(start.clone(), 0)]);
+    let mut subsets = vec![start];
+    let mut trans = Vec::new();
+    while trans.len() < subsets.len() {
+        let current = subsets[trans.len()].clone();
+        let row = (0..dfa.alphabet.len())
+            .map(|symbol| {
+                let mut next: Vec<StateId> = current
+                    .iter()
+                    .flat_map(|&state| predecessors[state as usize][symbol].iter().copied())
+                    .collect();
+                next.sort_unstable();
+                next.dedup();
+                *ids.entry(next).or_insert_with_key(|next| {
+                    subsets.push(next.clone());
+                    (subsets.len() - 1) as StateId
+                })
+            })
+            .collect();
+        trans.push(row);
+    }
+
+    Dfa {
+        states: (0..subsets.len() as StateId).collect(),
+        start: 0,
+        accepts: (0..subsets.len() as StateId)
+            .filter(|&id| subsets[id as usize].binary_search(&dfa.start).is_ok())
+            .collect(),
+        trans,
+        alphabet: dfa.alphabet.clone(),
+    }
+}
+
+/// Groups the states of a DFA into Myhill–Nerode equivalence classes.
+///
+/// Two states share a class when no suffix distinguishes them. Classes are
+/// numbered like the states of [`minimize`], so each state maps to the state
+/// of the minimized DFA it is merged into.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The DFA whose states to classify.
+///
+/// # Returns
+///
+/// - `Vec<StateId>` - The class of every state, indexed by state id.
+#[must_use]
+pub fn nerode_classes(dfa: &Dfa) -> Vec<StateId> {
+    if dfa.trans.len() <= 1 {
+        return vec![0; dfa.trans.len()];
+    }
+
+    PartitionRefinement::new(dfa)
+        .refine()
+        .state_class
+        .into_iter()
+        .map(|class| class as StateId)
+        .collect()
+}
+
 struct PartitionRefinement<'a> {
     /// The DFA being minimized.
     dfa: &'a Dfa,
@@ -71,7 +184,17 @@ impl<'a> PartitionRefinement<'a> {
         }
     }
 
-    fn run(mut self) -> Dfa {
+    fn run(self) -> (Dfa, Vec<StateId>) {
+        let refined = self.refine();
+        let mapping = refined
+            .state_class
+            .iter()
+            .map(|&class| class as StateId)
+            .collect();
+        (refined.build_minimized(), mapping)
+    }
+
+    fn refine(mut self) -> Self {
         while let Some((class_idx, symbol_idx)) = self.worklist.pop_front() {
             let involved = self.collect_involved(class_idx, symbol_idx);
             if involved.is_empty() {
@@ -80,7 +203,7 @@ impl<'a> PartitionRefinement<'a> {
             let splits = self.split_partitions(&involved);
             self.enqueue_splits(splits);
         }
-        self.build_minimized()
+        self
     }
 
     fn collect_involved(&self, class_idx: usize, symbol_idx: usize) -> HashSet<usize> {
@@ -194,6 +317,7 @@ impl<'a> PartitionRefinement<'a> {
 mod tests {
     use super::*;
     use crate::core::dfa;
+    use crate::core::equiv::hopcroft_karp;
     use crate::core::nfa::Nfa;
     use crate::core::parser::Ast;
     use crate::errors::BuildError;
@@ -209,10 +333,9 @@ mod tests {
         let mut current = dfa.start;
 
         for ch in input.chars() {
-            let symbol_idx = dfa.alphabet.iter().position(|&c| c == ch);
-            match symbol_idx {
-                Some(idx) => {
-                    current = dfa.trans[current as usize][idx];
+            match dfa.step(current, ch) {
+                Some(next) => {
+                    current = next;
                 }
                 None => return false, // Symbol not in alphabet, reject
             }
@@ -484,4 +607,60 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_nerode_classes_match_minimized_states() {
+        let dfa = dfa::determinize(&Nfa::build(&Ast::build("a+a*").unwrap()));
+        let min = minimize(&dfa);
+        let classes = nerode_classes(&dfa);
+
+        assert_eq!(classes.len(), dfa.states.len());
+        assert_eq!(classes[dfa.start as usize], min.start);
+        for state in &dfa.states {
+            for col in 0..dfa.alphabet.len() {
+                let next = dfa.trans[*state as usize][col];
+                assert_eq!(
+                    min.trans[classes[*state as usize] as usize][col],
+                    classes[next as usize]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_minimize_with_mapping() {
+        let dfa = dfa::determinize(&Nfa::build(&Ast::build("(a+b)*abb").unwrap()));
+        let (min, mapping) = minimize_with_mapping(&dfa);
+        assert_eq!(mapping.len(), dfa.states.len());
+        assert_eq!(min, minimize(&dfa));
+        assert_eq!(mapping[dfa.start as usize], min.start);
+
+        // Every transition of the original lands where the mapping says.
+        for (from, symbol, to) in dfa.transitions() {
+            let column = min.symbol_index(symbol).unwrap();
+            assert_eq!(
+                min.trans[mapping[from as usize] as usize][column],
+                mapping[to as usize]
+            );
+        }
+        // Subset construction builds 5 states; two of them are merged.
+        let mut merged = mapping.clone();
+        merged.sort_unstable();
+        merged.dedup();
+        assert_eq!((dfa.states.len(), merged.len()), (5, 4));
+    }
+
+    #[test]
+    fn test_brzozowski_agrees_with_hopcroft() {
+        for pattern in ["(a+b)*abb", "a+a*", "(a+b)*a(a+b)(a+b)", "ε", "(ab)*+(ba)*"] {
+            let dfa = dfa::determinize(&Nfa::build(&Ast::build(pattern).unwrap()));
+            let hopcroft = minimize(&dfa);
+            let brzozowski = minimize_brzozowski(&dfa);
+            assert_eq!(brzozowski.states.len(), hopcroft.states.len(), "{pattern}");
+            assert!(
+                hopcroft_karp(&brzozowski, &hopcroft).is_equivalent(),
+                "{pattern}"
+            );
+        }
+    }
 }
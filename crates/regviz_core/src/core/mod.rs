@@ -1,21 +1,33 @@
+pub mod analysis;
 pub mod automaton;
 pub mod dfa;
+pub mod diagnostics;
+pub mod diff;
+pub mod equiv;
+pub mod formal;
 pub mod lexer;
 pub mod min;
 pub mod nfa;
 pub mod parser;
+pub mod product;
 pub mod sim;
+pub mod state_set;
+pub mod syntax;
+pub mod to_regex;
+pub mod trace;
 
 use self::dfa::Dfa;
 use self::nfa::Nfa;
 use self::parser::Ast;
+use crate::errors::AlphabetError;
 
 /// Aggregates the intermediate products generated while building automata
 /// from a regular expression.
 #[derive(Debug, Clone)]
 pub struct BuildArtifacts {
-    /// The parsed regular-expression abstract syntax tree.
-    pub ast: Ast,
+    /// The parsed regular-expression abstract syntax tree, if the automaton
+    /// was built from a pattern rather than composed directly.
+    pub ast: Option<Ast>,
     /// The Thompson-constructed nondeterministic automaton.
     pub nfa: Nfa,
     /// The alphabet recognized by the NFA (and derived DFAs).
@@ -31,7 +43,56 @@ impl BuildArtifacts {
     #[must_use]
     pub fn new(ast: Ast, nfa: Nfa, alphabet: Vec<char>) -> Self {
         Self {
-            ast,
+            ast: Some(ast),
+            nfa,
+            alphabet,
+            dfa: None,
+            min_dfa: None,
+        }
+    }
+
+    /// Replaces the inferred alphabet with an explicitly declared one.
+    ///
+    /// Declared symbols the pattern never uses still get full rows in the DFA,
+    /// which makes complements and totals over that alphabet meaningful. Any
+    /// cached DFAs are dropped so they are rebuilt over the new alphabet with
+    /// [`dfa::determinize_over`].
+    ///
+    /// # Arguments
+    ///
+    /// - `declared` (`&[char]`) - The declared symbols, in any order.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), AlphabetError>` - An error listing the pattern's symbols
+    ///   missing from `declared`, in which case the artifacts are unchanged.
+    pub fn declare_alphabet(&mut self, declared: &[char]) -> Result<(), AlphabetError> {
+        let undeclared: Vec<char> = self
+            .nfa
+            .alphabet()
+            .into_iter()
+            .filter(|symbol| !declared.contains(symbol))
+            .collect();
+        if !undeclared.is_empty() {
+            return Err(AlphabetError { undeclared });
+        }
+
+        let mut alphabet = declared.to_vec();
+        alphabet.sort_unstable();
+        alphabet.dedup();
+        self.alphabet = alphabet;
+        self.dfa = None;
+        self.min_dfa = None;
+        Ok(())
+    }
+
+    /// Creates build artifacts for an automaton that has no syntax tree, such
+    /// as one produced by [`Nfa::concat`], [`Nfa::union`] or [`Nfa::star`].
+    #[must_use]
+    pub fn from_nfa(nfa: Nfa) -> Self {
+        let alphabet = nfa.alphabet();
+        Self {
+            ast: None,
             nfa,
             alphabet,
             dfa: None,
@@ -2,7 +2,9 @@ use crate::core::Ast;
 use crate::core::automaton::{
     BoundingBox, BoxId, BoxKind, Edge, EdgeLabel, State, StateId, Transition,
 };
-use std::collections::HashSet;
+use crate::core::state_set::StateSet;
+use crate::errors::NfaError;
+use std::collections::{BTreeSet, HashSet};
 
 /// Represents a Thompson-constructed nondeterministic finite automaton.
 #[derive(Debug, Clone)]
@@ -37,6 +39,162 @@ impl Nfa {
         builder.finalize(fragment)
     }
 
+    /// Builds an [`Nfa`] like [`Nfa::build`], also recording the order in which
+    /// the construction created its fragments.
+    ///
+    /// # Arguments
+    ///
+    /// - `ast` (`&Ast`) - The abstract syntax tree representing the regular expression.
+    ///
+    /// # Returns
+    ///
+    /// - `(Nfa, Vec<ConstructionStep>)` - The automaton, and one step per AST node
+    ///   in the order the builder finished them, children before their parent.
+    ///   Every state and transition of the automaton belongs to exactly one step.
+    pub fn build_recorded(ast: &Ast) -> (Nfa, Vec<ConstructionStep>) {
+        let mut builder = Builder {
+            recording: Some(Recording::default()),
+            ..Builder::default()
+        };
+        let fragment = builder.build(ast);
+        let steps = builder
+            .recording
+            .take()
+            .map(|recording| recording.steps)
+            .unwrap_or_default();
+        (builder.finalize(fragment), steps)
+    }
+
+    /// Builds an [`Nfa`] using Glushkov's position construction.
+    ///
+    /// Every literal of the pattern becomes a state entered only on its own
+    /// symbol, after a shared start state, so the result has no ε transitions
+    /// and no bounding boxes.
+    ///
+    /// # Arguments
+    ///
+    /// - `ast` (`&Ast`) - The abstract syntax tree representing the regular expression.
+    ///
+    /// # Returns
+    ///
+    /// - `Nfa` - The position automaton, with one state per literal after the start state `0`.
+    pub fn glushkov(ast: &Ast) -> Nfa {
+        let mut positions = Positions::default();
+        let root = positions.visit(ast);
+
+        let state = |position: usize| (position + 1) as StateId;
+        let edge = |from: StateId, to: usize| Edge {
+            from,
+            to: state(to),
+            label: EdgeLabel::Sym(positions.symbols[to]),
+        };
+        let edges: Vec<Edge> = root
+            .first
+            .iter()
+            .map(|&to| edge(0, to))
+            .chain(
+                positions
+                    .follow
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(from, next)| next.iter().map(move |&to| edge(state(from), to))),
+            )
+            .collect();
+        let accepts = root
+            .nullable
+            .then_some(0)
+            .into_iter()
+            .chain(root.last.iter().map(|&position| state(position)))
+            .collect();
+        Nfa::from_edges(positions.symbols.len() + 1, 0, accepts, edges)
+    }
+
+    /// Assembles an [`Nfa`] directly from a state count and an edge list.
+    ///
+    /// The result carries no bounding boxes, since it was not built from an AST.
+    ///
+    /// # Arguments
+    ///
+    /// - `state_count` (`usize`) - The number of states, identified `0..state_count`.
+    /// - `start` (`StateId`) - The start state.
+    /// - `accepts` (`Vec<StateId>`) - The accepting states.
+    /// - `edges` (`impl IntoIterator<Item = Edge>`) - The transitions between states.
+    ///
+    /// # Returns
+    ///
+    /// - `Nfa` - The assembled automaton.
+    pub fn from_edges(
+        state_count: usize,
+        start: StateId,
+        accepts: Vec<StateId>,
+        edges: impl IntoIterator<Item = Edge>,
+    ) -> Nfa {
+        let mut builder = Builder::default();
+        for _ in 0..state_count {
+            builder.new_state();
+        }
+        for edge in edges {
+            builder.add_edge(edge.from, edge.to, edge.label);
+        }
+        builder.finish(start, accepts)
+    }
+
+    /// Builds an NFA for the concatenation of two languages.
+    ///
+    /// Works directly on the automata, so it also composes NFAs that were not
+    /// built from a regular expression. The operands become nested boxes
+    /// inside a new [`BoxKind::Concat`] box.
+    ///
+    /// # Arguments
+    ///
+    /// - `other` (`&Nfa`) - The automaton matched after `self`.
+    ///
+    /// # Returns
+    ///
+    /// - `Nfa` - An automaton accepting `L(self)·L(other)`.
+    pub fn concat(&self, other: &Nfa) -> Nfa {
+        let mut builder = Builder::default();
+        let fragment = builder.with_box(BoxKind::Concat, |builder| {
+            let left = builder.embed(self);
+            let right = builder.embed(other);
+            builder.join_concat(left, right)
+        });
+        builder.finalize(fragment)
+    }
+
+    /// Builds an NFA for the union of two languages.
+    ///
+    /// # Arguments
+    ///
+    /// - `other` (`&Nfa`) - The alternative automaton.
+    ///
+    /// # Returns
+    ///
+    /// - `Nfa` - An automaton accepting `L(self) ∪ L(other)`.
+    pub fn union(&self, other: &Nfa) -> Nfa {
+        let mut builder = Builder::default();
+        let fragment = builder.with_box(BoxKind::Alternation, |builder| {
+            let left = builder.embed(self);
+            let right = builder.embed(other);
+            builder.join_alternation(left, right)
+        });
+        builder.finalize(fragment)
+    }
+
+    /// Builds an NFA for the Kleene star of this language.
+    ///
+    /// # Returns
+    ///
+    /// - `Nfa` - An automaton accepting `L(self)*`.
+    pub fn star(&self) -> Nfa {
+        let mut builder = Builder::default();
+        let fragment = builder.with_box(BoxKind::KleeneStar, |builder| {
+            let inner = builder.embed(self);
+            builder.join_star(inner)
+        });
+        builder.finalize(fragment)
+    }
+
     /// Retrieves the outgoing transitions from a given state.
     ///
     /// # Arguments
@@ -50,6 +208,37 @@ impl Nfa {
         &self.adjacency[state as usize]
     }
 
+    /// Computes the ε-closure of every state: the states it reaches through
+    /// ε transitions alone, itself included.
+    ///
+    /// Subset construction and powerset simulation compute this once per run
+    /// and union the closures of the states they move to, instead of walking
+    /// the ε transitions again for every subset.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<StateSet>` - The closure of every state, indexed by state id.
+    #[must_use]
+    pub fn epsilon_closures(&self) -> Vec<StateSet> {
+        let count = self.states.len();
+        let mut stack = Vec::new();
+        (0..count as StateId)
+            .map(|state| {
+                let mut closure = StateSet::new(count);
+                closure.insert(state);
+                stack.push(state);
+                while let Some(from) = stack.pop() {
+                    for tr in self.transitions(from) {
+                        if tr.label == EdgeLabel::Eps && closure.insert(tr.to) {
+                            stack.push(tr.to);
+                        }
+                    }
+                }
+                closure
+            })
+            .collect()
+    }
+
     /// Computes the alphabet used in this NFA, sorted by character.
     ///
     /// # Returns
@@ -70,6 +259,259 @@ impl Nfa {
     }
 }
 
+/// The literals of a pattern, numbered by position, and the positions that
+/// may be read after each one, gathered by [`Nfa::glushkov`].
+#[derive(Default)]
+struct Positions {
+    /// Symbol of every position.
+    symbols: Vec<char>,
+    /// Positions that may be read right after each position.
+    follow: Vec<BTreeSet<usize>>,
+}
+
+/// Positions a sub-pattern can start and end its words with.
+struct Linearized {
+    /// Whether the sub-pattern matches the empty string.
+    nullable: bool,
+    /// Positions its words can start with.
+    first: Vec<usize>,
+    /// Positions its words can end with.
+    last: Vec<usize>,
+}
+
+impl Positions {
+    /// Numbers the literals of `ast` and links the positions that follow one
+    /// another inside it.
+    fn visit(&mut self, ast: &Ast) -> Linearized {
+        match ast {
+            Ast::Epsilon => Linearized {
+                nullable: true,
+                first: Vec::new(),
+                last: Vec::new(),
+            },
+            Ast::Atom(symbol) => {
+                let position = self.symbols.len();
+                self.symbols.push(*symbol);
+                self.follow.push(BTreeSet::new());
+                Linearized {
+                    nullable: false,
+                    first: vec![position],
+                    last: vec![position],
+                }
+            }
+            Ast::Concat(left, right) => {
+                let left = self.visit(left);
+                let right = self.visit(right);
+                for &position in &left.last {
+                    self.follow[position].extend(&right.first);
+                }
+                let mut first = left.first;
+                if left.nullable {
+                    first.extend(&right.first);
+                }
+                let mut last = right.last;
+                if right.nullable {
+                    last.extend(&left.last);
+                }
+                Linearized {
+                    nullable: left.nullable && right.nullable,
+                    first,
+                    last,
+                }
+            }
+            Ast::Alt(left, right) => {
+                let mut left = self.visit(left);
+                let right = self.visit(right);
+                left.first.extend(right.first);
+                left.last.extend(right.last);
+                left.nullable |= right.nullable;
+                left
+            }
+            Ast::Star(inner) => {
+                let inner = self.visit(inner);
+                for &position in &inner.last {
+                    self.follow[position].extend(&inner.first);
+                }
+                Linearized {
+                    nullable: true,
+                    ..inner
+                }
+            }
+            Ast::Opt(inner) => Linearized {
+                nullable: true,
+                ..self.visit(inner)
+            },
+        }
+    }
+}
+
+/// Assembles an [`Nfa`] by hand, with ε transitions allowed, instead of
+/// running Thompson's construction on a pattern.
+///
+/// States are numbered `0..states`. The result can be determinized with
+/// [`determinize`](crate::core::dfa::determinize) and then audited or
+/// minimized like any other automaton.
+///
+/// ```
+/// use regviz_core::core::dfa::determinize;
+/// use regviz_core::core::nfa::NfaBuilder;
+///
+/// // `a` or the empty string, through an ε transition.
+/// let nfa = NfaBuilder::new()
+///     .states(2)
+///     .start(0)
+///     .accept(1)
+///     .transition(0, 'a', 1)
+///     .epsilon(0, 1)
+///     .build()
+///     .unwrap();
+/// assert_eq!(determinize(&nfa).accepts.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[must_use]
+pub struct NfaBuilder {
+    states: usize,
+    start: StateId,
+    accepts: Vec<StateId>,
+    edges: Vec<Edge>,
+}
+
+impl NfaBuilder {
+    /// Creates a builder for an NFA without states or transitions, whose start
+    /// state defaults to `0`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of states, which are numbered `0..count`.
+    pub fn states(mut self, count: usize) -> Self {
+        self.states = count;
+        self
+    }
+
+    /// Sets the start state.
+    pub fn start(mut self, state: StateId) -> Self {
+        self.start = state;
+        self
+    }
+
+    /// Marks `state` as accepting.
+    pub fn accept(mut self, state: StateId) -> Self {
+        self.accepts.push(state);
+        self
+    }
+
+    /// Marks every state in `states` as accepting.
+    pub fn accepts(mut self, states: impl IntoIterator<Item = StateId>) -> Self {
+        self.accepts.extend(states);
+        self
+    }
+
+    /// Adds the transition `from --symbol--> to`. A state may have any number
+    /// of transitions on the same symbol.
+    ///
+    /// # Arguments
+    ///
+    /// - `from` (`StateId`) - The source state.
+    /// - `symbol` (`char`) - The symbol read.
+    /// - `to` (`StateId`) - The target state.
+    pub fn transition(self, from: StateId, symbol: char, to: StateId) -> Self {
+        self.edge(from, EdgeLabel::Sym(symbol), to)
+    }
+
+    /// Adds an ε transition from `from` to `to`.
+    pub fn epsilon(self, from: StateId, to: StateId) -> Self {
+        self.edge(from, EdgeLabel::Eps, to)
+    }
+
+    fn edge(mut self, from: StateId, label: EdgeLabel, to: StateId) -> Self {
+        self.edges.push(Edge { from, to, label });
+        self
+    }
+
+    /// Checks that every referenced state exists and builds the automaton.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Nfa, NfaError>` - The NFA with sorted accepting states, or
+    ///   the first state out of range.
+    pub fn build(self) -> Result<Nfa, NfaError> {
+        let check = |state: StateId| {
+            if (state as usize) < self.states {
+                Ok(())
+            } else {
+                Err(NfaError::UnknownState(state))
+            }
+        };
+        check(self.start)?;
+        self.accepts.iter().try_for_each(|&state| check(state))?;
+        self.edges
+            .iter()
+            .try_for_each(|edge| check(edge.from).and(check(edge.to)))?;
+
+        let mut accepts = self.accepts;
+        accepts.sort_unstable();
+        accepts.dedup();
+        Ok(Nfa::from_edges(
+            self.states,
+            self.start,
+            accepts,
+            self.edges,
+        ))
+    }
+}
+
+/// One fragment of Thompson's construction: what the builder added for a single
+/// AST node on top of the fragments of its children.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstructionStep {
+    /// The kind of the fragment's bounding box.
+    pub kind: BoxKind,
+    /// The bounding box enclosing the fragment and its children.
+    pub box_id: BoxId,
+    /// The AST node the fragment was built from.
+    pub node: Ast,
+    /// States created for this node, in creation order.
+    pub states: Vec<StateId>,
+    /// Transitions created for this node, in creation order.
+    pub edges: Vec<Edge>,
+}
+
+/// Construction order collected by [`Nfa::build_recorded`].
+#[derive(Default)]
+struct Recording {
+    /// Finished steps, in the order their nodes were completed.
+    steps: Vec<ConstructionStep>,
+    /// Every transition added so far, in creation order.
+    edges: Vec<Edge>,
+    /// Number of states already claimed by a step.
+    states_seen: usize,
+    /// Number of transitions already claimed by a step.
+    edges_seen: usize,
+}
+
+impl Recording {
+    /// Claims everything created since the previous step for `node`.
+    ///
+    /// Construction is post-order, so whatever is unclaimed when a node finishes
+    /// was created for that node rather than for one of its children.
+    fn record(&mut self, node: &Ast, box_id: BoxId, kind: BoxKind, state_count: usize) {
+        let states = (self.states_seen..state_count)
+            .map(|state| state as StateId)
+            .collect();
+        let edges = self.edges[self.edges_seen..].to_vec();
+        self.states_seen = state_count;
+        self.edges_seen = self.edges.len();
+        self.steps.push(ConstructionStep {
+            kind,
+            box_id,
+            node: node.clone(),
+            states,
+            edges,
+        });
+    }
+}
+
 /// The internal builder struct for converting an AST to an NFA.
 /// Holds adjacency lists and provides methods for constructing NFA fragments.
 #[derive(Default)]
@@ -82,6 +524,8 @@ struct Builder {
     boxes: Vec<BoundingBox>,
     /// Stack tracking the current bounding box hierarchy.
     box_stack: Vec<BoxId>,
+    /// Construction order, collected only when requested.
+    recording: Option<Recording>,
 }
 
 /// Represents a fragment of an NFA with a start state and an accepting state.
@@ -160,6 +604,9 @@ impl Builder {
     /// None
     fn add_edge(&mut self, from: StateId, to: StateId, label: EdgeLabel) {
         self.adjacency[from as usize].push(Transition { to, label });
+        if let Some(recording) = &mut self.recording {
+            recording.edges.push(Edge { from, to, label });
+        }
     }
 
     /// A general blanket to build from a given AST input.
@@ -172,14 +619,21 @@ impl Builder {
     ///
     /// - `Fragment` - The NFA fragment constructed from the AST node.
     fn build(&mut self, ast: &Ast) -> Fragment {
-        match ast {
+        // Every node opens its bounding box before building its children.
+        let box_id = self.boxes.len() as BoxId;
+        let fragment = match ast {
             Ast::Epsilon => self.build_epsilon(),
             Ast::Atom(c) => self.build_char(*c),
             Ast::Concat(lhs, rhs) => self.build_concat(lhs, rhs),
             Ast::Alt(lhs, rhs) => self.build_alternation(lhs, rhs),
             Ast::Star(inner) => self.build_star(inner),
             Ast::Opt(inner) => self.build_optional(inner),
+        };
+        if let Some(recording) = &mut self.recording {
+            let kind = self.boxes[box_id as usize].kind;
+            recording.record(ast, box_id, kind, self.states.len());
         }
+        fragment
     }
 
     /// Builds an epsilon AST symbol within the NFA. Creates a single state that is both the start and accept state.
@@ -233,25 +687,28 @@ impl Builder {
         self.with_box(BoxKind::Concat, move |builder| {
             let left = builder.build(lhs);
             let right = builder.build(rhs);
-
-            let Fragment {
-                start: left_start,
-                accept: left_accept,
-            } = left;
-            let Fragment {
-                start: right_start,
-                accept: right_accept,
-            } = right;
-
-            builder.add_edge(left_accept, right_start, EdgeLabel::Eps);
-
-            Fragment {
-                start: left_start,
-                accept: right_accept,
-            }
+            builder.join_concat(left, right)
         })
     }
 
+    /// Connects the accept state of `left` to the start of `right`.
+    ///
+    /// # Arguments
+    ///
+    /// - `left` (`Fragment`) - The fragment matched first.
+    /// - `right` (`Fragment`) - The fragment matched second.
+    ///
+    /// # Returns
+    ///
+    /// - `Fragment` - The NFA fragment representing the concatenation.
+    fn join_concat(&mut self, left: Fragment, right: Fragment) -> Fragment {
+        self.add_edge(left.accept, right.start, EdgeLabel::Eps);
+        Fragment {
+            start: left.start,
+            accept: right.accept,
+        }
+    }
+
     /// Converts the alteration (or OR) '|' AST into its NFA fragment representation.
     ///
     /// # Arguments
@@ -266,28 +723,32 @@ impl Builder {
         self.with_box(BoxKind::Alternation, move |builder| {
             let left = builder.build(lhs);
             let right = builder.build(rhs);
+            builder.join_alternation(left, right)
+        })
+    }
 
-            let Fragment {
-                start: left_start,
-                accept: left_accept,
-            } = left;
-            let Fragment {
-                start: right_start,
-                accept: right_accept,
-            } = right;
-
-            let start = builder.new_state();
-            let accept = builder.new_state();
+    /// Wraps two fragments in a fresh start and accept state that branch into either.
+    ///
+    /// # Arguments
+    ///
+    /// - `left` (`Fragment`) - The first alternative.
+    /// - `right` (`Fragment`) - The second alternative.
+    ///
+    /// # Returns
+    ///
+    /// - `Fragment` - The NFA fragment representing the alternation.
+    fn join_alternation(&mut self, left: Fragment, right: Fragment) -> Fragment {
+        let start = self.new_state();
+        let accept = self.new_state();
 
-            builder.add_edge(start, left_start, EdgeLabel::Eps);
-            builder.add_edge(start, right_start, EdgeLabel::Eps);
+        self.add_edge(start, left.start, EdgeLabel::Eps);
+        self.add_edge(start, right.start, EdgeLabel::Eps);
 
-            for state in &[left_accept, right_accept] {
-                builder.add_edge(*state, accept, EdgeLabel::Eps);
-            }
+        for state in &[left.accept, right.accept] {
+            self.add_edge(*state, accept, EdgeLabel::Eps);
+        }
 
-            Fragment { start, accept }
-        })
+        Fragment { start, accept }
     }
 
     /// Builds the klnee-star operation from the given inner AST/
@@ -302,22 +763,84 @@ impl Builder {
     fn build_star(&mut self, inner: &Ast) -> Fragment {
         self.with_box(BoxKind::KleeneStar, move |builder| {
             let frag = builder.build(inner);
-            let Fragment {
-                start: inner_start,
-                accept: inner_accept,
-            } = frag;
+            builder.join_star(frag)
+        })
+    }
 
-            let start = builder.new_state();
-            let accept = builder.new_state();
+    /// Adds the loop-back and bypass edges that repeat a fragment zero or more times.
+    ///
+    /// # Arguments
+    ///
+    /// - `inner` (`Fragment`) - The fragment to repeat.
+    ///
+    /// # Returns
+    ///
+    /// - `Fragment` - The NFA fragment representing the kleene star operation.
+    fn join_star(&mut self, inner: Fragment) -> Fragment {
+        let start = self.new_state();
+        let accept = self.new_state();
 
-            builder.add_edge(start, inner_start, EdgeLabel::Eps);
-            builder.add_edge(start, accept, EdgeLabel::Eps);
+        self.add_edge(start, inner.start, EdgeLabel::Eps);
+        self.add_edge(start, accept, EdgeLabel::Eps);
 
-            builder.add_edge(inner_accept, inner_start, EdgeLabel::Eps);
-            builder.add_edge(inner_accept, accept, EdgeLabel::Eps);
+        self.add_edge(inner.accept, inner.start, EdgeLabel::Eps);
+        self.add_edge(inner.accept, accept, EdgeLabel::Eps);
 
-            Fragment { start, accept }
-        })
+        Fragment { start, accept }
+    }
+
+    /// Copies an existing NFA into this builder, renumbering its states and boxes.
+    ///
+    /// Top-level boxes of `nfa` are nested under the currently open box, and
+    /// states outside any box are attributed to it. Multiple accepting states
+    /// are funnelled into a single new accept state with epsilon edges.
+    ///
+    /// # Arguments
+    ///
+    /// - `nfa` (`&Nfa`) - The automaton to copy.
+    ///
+    /// # Returns
+    ///
+    /// - `Fragment` - The fragment covering the copied automaton.
+    fn embed(&mut self, nfa: &Nfa) -> Fragment {
+        let state_offset = self.adjacency.len() as StateId;
+        let box_offset = self.boxes.len() as BoxId;
+        let current = self.box_stack.last().copied();
+
+        for bbox in &nfa.boxes {
+            self.boxes.push(BoundingBox {
+                id: bbox.id + box_offset,
+                kind: bbox.kind,
+                parent: bbox.parent.map(|p| p + box_offset).or(current),
+                states: bbox.states.iter().map(|s| s + state_offset).collect(),
+            });
+        }
+        for state in &nfa.states {
+            let id = self.new_state();
+            if let Some(inner) = state.box_id {
+                // `new_state` attributed the state to the open box; move it to its own.
+                if let Some(bbox) = current.and_then(|c| self.boxes.get_mut(c as usize)) {
+                    bbox.states.pop();
+                }
+                self.states[id as usize].box_id = Some(inner + box_offset);
+            }
+        }
+        for edge in &nfa.edges {
+            self.add_edge(edge.from + state_offset, edge.to + state_offset, edge.label);
+        }
+
+        let start = nfa.start + state_offset;
+        let accept = match nfa.accepts.as_slice() {
+            [single] => single + state_offset,
+            accepts => {
+                let accept = self.new_state();
+                for state in accepts {
+                    self.add_edge(state + state_offset, accept, EdgeLabel::Eps);
+                }
+                accept
+            }
+        };
+        Fragment { start, accept }
     }
 
     /// Builds an optional (zero or one occurrence) fragment from the given inner AST.
@@ -358,7 +881,23 @@ impl Builder {
     /// # Returns
     ///
     /// - [`Nfa`] - The finalized NFA structure.
-    fn finalize(mut self, fragment: Fragment) -> Nfa {
+    fn finalize(self, fragment: Fragment) -> Nfa {
+        self.finish(fragment.start, vec![fragment.accept])
+    }
+
+    /// Flattens the adjacency lists into an [`Nfa`] with the given start and accepting states.
+    ///
+    /// # Arguments
+    ///
+    /// - `start` (`StateId`) - The start state.
+    /// - `accepts` (`Vec<StateId>`) - The accepting states. Sorted and deduplicated.
+    ///
+    /// # Returns
+    ///
+    /// - [`Nfa`] - The finalized NFA structure.
+    fn finish(mut self, start: StateId, mut accepts: Vec<StateId>) -> Nfa {
+        accepts.sort_unstable();
+        accepts.dedup();
         let mut edges = Vec::new();
 
         for (from, row) in self.adjacency.iter_mut().enumerate() {
@@ -377,8 +916,8 @@ impl Builder {
 
         Nfa {
             states: self.states,
-            start: fragment.start,
-            accepts: vec![fragment.accept],
+            start,
+            accepts,
             edges,
             adjacency: self.adjacency,
             boxes: self.boxes,
@@ -389,6 +928,37 @@ impl Builder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::sim;
+
+    #[test]
+    fn test_builder_with_epsilon() {
+        use crate::core::{analysis, dfa::determinize};
+
+        // `ab` or `b`, where the `a` can be skipped through an ε transition.
+        let nfa = NfaBuilder::new()
+            .states(3)
+            .accept(2)
+            .transition(0, 'a', 1)
+            .epsilon(0, 1)
+            .transition(1, 'b', 2)
+            .build()
+            .unwrap();
+        let dfa = determinize(&nfa);
+        assert!(sim::simulate_dfa(&dfa, "ab"));
+        assert!(sim::simulate_dfa(&dfa, "b"));
+        assert!(!sim::simulate_dfa(&dfa, "a"));
+        assert!(analysis::audit(&dfa).unwrap().complete);
+        assert_eq!(analysis::nfa_stats(&nfa).epsilon_edges, 1);
+
+        assert_eq!(
+            NfaBuilder::new()
+                .states(1)
+                .epsilon(0, 3)
+                .build()
+                .unwrap_err(),
+            NfaError::UnknownState(3)
+        );
+    }
 
     #[test]
     fn test_build_epsilon() {
@@ -751,4 +1321,148 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_compose_automata() {
+        let a = Nfa::build(&Ast::build("a").unwrap());
+        let b = Nfa::build(&Ast::build("b").unwrap());
+
+        let ab = a.concat(&b);
+        assert!(sim::nfa_accepts(&ab, "ab"));
+        assert!(!sim::nfa_accepts(&ab, "a"));
+
+        let a_or_b = a.union(&b);
+        assert!(sim::nfa_accepts(&a_or_b, "a"));
+        assert!(sim::nfa_accepts(&a_or_b, "b"));
+        assert!(!sim::nfa_accepts(&a_or_b, "ab"));
+
+        let star = ab.star();
+        for input in ["", "ab", "abab"] {
+            assert!(sim::nfa_accepts(&star, input));
+        }
+        assert!(!sim::nfa_accepts(&star, "aba"));
+    }
+
+    #[test]
+    fn test_compose_nests_boxes() {
+        let a = Nfa::build(&Ast::build("a").unwrap());
+        let b = Nfa::build(&Ast::build("b*").unwrap());
+        let nfa = a.concat(&b);
+
+        assert_eq!(nfa.boxes[0].kind, BoxKind::Concat);
+        assert_eq!(nfa.boxes[0].parent, None);
+        // Every embedded root box now hangs off the new concat box.
+        let roots: Vec<_> = nfa.boxes.iter().filter(|b| b.parent.is_none()).collect();
+        assert_eq!(roots.len(), 1);
+        for state in &nfa.states {
+            let bbox = &nfa.boxes[state.box_id.unwrap() as usize];
+            assert!(bbox.states.contains(&state.id));
+        }
+    }
+
+    #[test]
+    fn test_compose_boxless_automaton() {
+        // Two accepting states and no bounding boxes: `a` or `b`.
+        let edges = [
+            Edge {
+                from: 0,
+                to: 1,
+                label: EdgeLabel::Sym('a'),
+            },
+            Edge {
+                from: 0,
+                to: 2,
+                label: EdgeLabel::Sym('b'),
+            },
+        ];
+        let imported = Nfa::from_edges(3, 0, vec![1, 2], edges);
+        let star = imported.star();
+        assert!(sim::nfa_accepts(&star, "abba"));
+        assert!(!sim::nfa_accepts(&star, "c"));
+    }
+
+    #[test]
+    fn test_build_recorded_matches_build() {
+        let ast = Ast::build("(a+b)*abb?").unwrap();
+        let nfa = Nfa::build(&ast);
+        let (recorded, steps) = Nfa::build_recorded(&ast);
+        assert_eq!(recorded.edges, nfa.edges);
+        assert_eq!(recorded.accepts, nfa.accepts);
+
+        // Every state and transition is claimed by exactly one step.
+        let mut states: Vec<StateId> = steps.iter().flat_map(|s| s.states.clone()).collect();
+        states.sort_unstable();
+        assert_eq!(states, (0..nfa.states.len() as StateId).collect::<Vec<_>>());
+        let mut edges: Vec<Edge> = steps.iter().flat_map(|s| s.edges.clone()).collect();
+        let mut expected = nfa.edges.clone();
+        edges.sort_by_key(|e| (e.from, e.to));
+        expected.sort_by_key(|e| (e.from, e.to));
+        assert_eq!(edges, expected);
+    }
+
+    #[test]
+    fn test_build_recorded_is_post_order() {
+        let ast = Ast::build("a+b").unwrap();
+        let (nfa, steps) = Nfa::build_recorded(&ast);
+        let nodes: Vec<&Ast> = steps.iter().map(|s| &s.node).collect();
+        assert_eq!(nodes, vec![&Ast::Atom('a'), &Ast::Atom('b'), &ast]);
+        assert_eq!(steps[2].kind, BoxKind::Alternation);
+        assert_eq!(steps[2].box_id, 0);
+        // The union adds its own start and accept plus four epsilon moves.
+        assert_eq!(steps[2].states.len(), 2);
+        assert_eq!(steps[2].edges.len(), 4);
+        for step in &steps {
+            assert_eq!(nfa.boxes[step.box_id as usize].kind, step.kind);
+        }
+    }
+
+    #[test]
+    fn test_glushkov_matches_thompson() {
+        use crate::core::dfa::determinize;
+        use crate::core::equiv::hopcroft_karp;
+
+        for pattern in ["(a+b)*abb?", "a*(b+ε)c*", "((a*b?)*c)*", "ε", "(ab+ba)*a?"] {
+            let ast = Ast::build(pattern).unwrap();
+            let glushkov = Nfa::glushkov(&ast);
+            assert!(glushkov.edges.iter().all(|e| e.label != EdgeLabel::Eps));
+            let expected = determinize(&Nfa::build(&ast));
+            assert!(
+                hopcroft_karp(&determinize(&glushkov), &expected).is_equivalent(),
+                "{pattern}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_glushkov_has_one_state_per_literal() {
+        let ast = Ast::build("(a+b)*abb").unwrap();
+        let nfa = Nfa::glushkov(&ast);
+        assert_eq!(nfa.states.len(), 6);
+        assert_eq!(nfa.accepts, vec![5]);
+        assert!(sim::nfa_accepts(&nfa, "babb"));
+        assert!(!sim::nfa_accepts(&nfa, "abab"));
+    }
+
+    #[test]
+    fn test_epsilon_closures() {
+        // 0 -ε-> 1 -ε-> 2 -a-> 3, with an ε cycle back from 2 to 0.
+        let nfa = NfaBuilder::new()
+            .states(4)
+            .accept(3)
+            .epsilon(0, 1)
+            .epsilon(1, 2)
+            .epsilon(2, 0)
+            .transition(2, 'a', 3)
+            .build()
+            .unwrap();
+        let closures: Vec<Vec<StateId>> = nfa
+            .epsilon_closures()
+            .iter()
+            .map(|closure| closure.iter().collect())
+            .collect();
+        assert_eq!(
+            closures,
+            vec![vec![0, 1, 2], vec![0, 1, 2], vec![0, 1, 2], vec![3]]
+        );
+    }
 }
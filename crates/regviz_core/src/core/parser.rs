@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use crate::{
+    core::automaton::EpsilonGlyph,
     core::lexer::{Lexer, OpToken, Token},
     errors::{BuildError, ParseError, ParseErrorKind},
 };
@@ -22,6 +23,83 @@ pub enum Ast {
     Opt(Box<Ast>),
 }
 
+/// One decision of the Pratt parser, recorded by [`Ast::build_traced`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseEvent {
+    /// The parser consumed `token`, found at char index `at`.
+    Consume { token: Token, at: usize },
+    /// Operator `op` at char index `at` took the expression on its left as an
+    /// operand, since its binding power `power` reached the minimum `min_bp`
+    /// of the sub-expression being parsed. `implicit` marks a concatenation
+    /// the pattern does not spell out.
+    Bind {
+        op: OpToken,
+        at: usize,
+        power: u8,
+        min_bp: u8,
+        implicit: bool,
+    },
+    /// Operator `op` at char index `at` binds with `power`, less than the
+    /// minimum `min_bp` of the sub-expression being parsed, so that
+    /// sub-expression ended and the operator was left to an enclosing one.
+    Yield {
+        op: OpToken,
+        at: usize,
+        power: u8,
+        min_bp: u8,
+        implicit: bool,
+    },
+    /// The parser built `node`, numbered `id` in the pre-order of the final tree.
+    Build { id: usize, node: Ast },
+}
+
+impl ParseEvent {
+    /// Records whether operator `op`, binding with `power`, took the expression on its left.
+    fn decision(
+        bound: bool,
+        op: OpToken,
+        at: usize,
+        power: u8,
+        min_bp: u8,
+        explicit: bool,
+    ) -> Self {
+        let implicit = !explicit;
+        if bound {
+            Self::Bind {
+                op,
+                at,
+                power,
+                min_bp,
+                implicit,
+            }
+        } else {
+            Self::Yield {
+                op,
+                at,
+                power,
+                min_bp,
+                implicit,
+            }
+        }
+    }
+}
+
+/// Appends the event made by `event` to `trace` when tracing.
+fn record(trace: &mut Option<Vec<ParseEvent>>, event: impl FnOnce() -> ParseEvent) {
+    if let Some(events) = trace {
+        events.push(event());
+    }
+}
+
+/// Records that `node` was built, returning it. Its id is assigned once the tree is complete.
+fn built(trace: &mut Option<Vec<ParseEvent>>, node: Ast) -> Ast {
+    record(trace, || ParseEvent::Build {
+        id: 0,
+        node: node.clone(),
+    });
+    node
+}
+
 /// Infix operator definition for Pratt parsing.
 pub struct InfixOp {
     /// Left binding power.
@@ -83,6 +161,19 @@ impl OpToken {
         }
     }
 
+    /// Returns how tightly this operator binds its operand(s); higher binds tighter.
+    pub const fn binding_power(&self) -> u8 {
+        if let Some(infix) = self.infix() {
+            infix.left_bp
+        } else if let Some(postfix) = self.postfix() {
+            postfix.bp
+        } else if let Some(prefix) = self.prefix() {
+            prefix.bp
+        } else {
+            0
+        }
+    }
+
     /// Returns the prefix operator definition if this token represents a prefix operator.
     /// Returns `None` otherwise.
     pub const fn prefix(&self) -> Option<PrefixOp> {
@@ -95,15 +186,68 @@ impl Ast {
     /// Builds an AST from the input regular expression string.
     /// Returns a [`BuildError`] if lexing or parsing fails.
     pub fn build(input: &str) -> Result<Ast, BuildError> {
+        Self::build_with(input, &mut None)
+    }
+
+    /// Builds an AST like [`Ast::build`], also recording the parser's decisions.
+    ///
+    /// # Arguments
+    ///
+    /// - `input` (`&str`) - The regular expression to parse.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Ast, Vec<ParseEvent>), BuildError>` - The tree and, in order,
+    ///   every token consumed, every binding decision and every node built.
+    ///   Nodes are built children first, so [`ParseEvent::Build`] events visit
+    ///   the final tree in post-order.
+    pub fn build_traced(input: &str) -> Result<(Ast, Vec<ParseEvent>), BuildError> {
+        let mut trace = Some(Vec::new());
+        let ast = Self::build_with(input, &mut trace)?;
+        let mut events = trace.unwrap_or_default();
+
+        // Nodes were numbered as they were built; renumber them as the tree is read.
+        let mut ids = Vec::new();
+        ast.preorder_ids(&mut 0, &mut ids);
+        let mut built = ids.into_iter();
+        for event in &mut events {
+            if let ParseEvent::Build { id, .. } = event {
+                *id = built.next().unwrap_or_default();
+            }
+        }
+        Ok((ast, events))
+    }
+
+    /// Builds an AST from `input`, appending the parser's decisions to `trace` when tracing.
+    fn build_with(input: &str, trace: &mut Option<Vec<ParseEvent>>) -> Result<Ast, BuildError> {
         let mut lexer = Lexer::new(input)?;
         if let Token::Eof = lexer.peek().0 {
             // Empty input, interpreted as epsilon AST
+            record(trace, || ParseEvent::Build {
+                id: 0,
+                node: Ast::Epsilon,
+            });
             return Ok(Ast::Epsilon);
         }
-        let ast = Ast::parse(&mut lexer, 0, false)?;
+        let ast = Ast::parse(&mut lexer, 0, false, trace)?;
         Ok(ast)
     }
 
+    /// Collects the pre-order number of every node of this tree, in post-order.
+    fn preorder_ids(&self, next: &mut usize, ids: &mut Vec<usize>) {
+        let id = *next;
+        *next += 1;
+        match self {
+            Ast::Epsilon | Ast::Atom(_) => {}
+            Ast::Concat(lhs, rhs) | Ast::Alt(lhs, rhs) => {
+                lhs.preorder_ids(next, ids);
+                rhs.preorder_ids(next, ids);
+            }
+            Ast::Star(inner) | Ast::Opt(inner) => inner.preorder_ids(next, ids),
+        }
+        ids.push(id);
+    }
+
     /// Parses an expression from the lexer using Pratt parsing with the given minimum binding power.
     ///
     /// # Parameters
@@ -112,9 +256,15 @@ impl Ast {
     /// * `min_bp` - Minimum binding power. Operators with lower binding power than this will not be consumed,
     ///   causing the parser to return the current left-hand side. This is used to impose operator precedence.
     /// * `open_paren` - Whether we're currently inside a parenthesized sub-expression.
+    /// * `trace` - Where to record the parser's decisions, or `None` when not tracing.
     ///
     /// Returns a [`ParseError`] if parsing fails.
-    fn parse(lexer: &mut Lexer, min_bp: u8, open_paren: bool) -> Result<Ast, ParseError> {
+    fn parse(
+        lexer: &mut Lexer,
+        min_bp: u8,
+        open_paren: bool,
+        trace: &mut Option<Vec<ParseEvent>>,
+    ) -> Result<Ast, ParseError> {
         // Phase 1: parse primary
         //
         // Read the next token and convert it into the initial `lhs`.
@@ -125,14 +275,15 @@ impl Ast {
         //
         // We capture the token index `idx` (char index) for error reporting.
         let (token, idx) = lexer.advance();
+        record(trace, || ParseEvent::Consume { token, at: idx });
         let mut lhs = match token {
-            Token::Epsilon => Ast::Epsilon,
-            Token::Literal(c) => Ast::Atom(c),
+            Token::Epsilon => built(trace, Ast::Epsilon),
+            Token::Literal(c) => built(trace, Ast::Atom(c)),
             Token::Op(op_token) => {
                 if let Some(prefix_op) = op_token.prefix() {
                     // Found prefix operator, parse right-hand side
-                    let rhs = Ast::parse(lexer, prefix_op.bp, open_paren)?;
-                    (prefix_op.build)(rhs)
+                    let rhs = Ast::parse(lexer, prefix_op.bp, open_paren, trace)?;
+                    built(trace, (prefix_op.build)(rhs))
                 } else {
                     return Err(ParseError {
                         at: idx,
@@ -142,12 +293,16 @@ impl Ast {
             }
             Token::LParen => {
                 // Parse sub-expression
-                let sub_expr = Ast::parse(lexer, 0, true)?;
+                let sub_expr = Ast::parse(lexer, 0, true, trace)?;
 
                 // Expect closing parenthesis
                 let (token, idx) = lexer.advance();
                 match token {
-                    Token::RParen => sub_expr,
+                    Token::RParen => {
+                        record(trace, || ParseEvent::Consume { token, at: idx });
+                        // Grouping adds no node: the sub-expression was already built.
+                        sub_expr
+                    }
                     other => {
                         return Err(ParseError {
                             at: idx,
@@ -220,7 +375,11 @@ impl Ast {
             // NOTE: Check for postfix operator first, then infix operator (to handle cases like a*+b)
             // Postfix operators naturally bind tighter than infix operators
             if let Some(postfix_op) = op_token.postfix() {
-                if postfix_op.bp < min_bp {
+                let bound = postfix_op.bp >= min_bp;
+                record(trace, || {
+                    ParseEvent::decision(bound, op_token, idx, postfix_op.bp, min_bp, is_explicit)
+                });
+                if !bound {
                     // Postfix operator does not bind strong enough, lhs is complete
                     break;
                 }
@@ -228,12 +387,24 @@ impl Ast {
                 // Consume the postfix operator if it was explicit
                 if is_explicit {
                     lexer.advance();
+                    record(trace, || ParseEvent::Consume { token, at: idx });
                 }
 
-                lhs = (postfix_op.build)(lhs);
+                lhs = built(trace, (postfix_op.build)(lhs));
                 continue;
             } else if let Some(infix_op) = op_token.infix() {
-                if infix_op.left_bp < min_bp {
+                let bound = infix_op.left_bp >= min_bp;
+                record(trace, || {
+                    ParseEvent::decision(
+                        bound,
+                        op_token,
+                        idx,
+                        infix_op.left_bp,
+                        min_bp,
+                        is_explicit,
+                    )
+                });
+                if !bound {
                     // Infix operator does not bind strong enough, lhs is complete
                     break;
                 }
@@ -241,10 +412,11 @@ impl Ast {
                 // Consume the infix operator if it was explicit
                 if is_explicit {
                     lexer.advance();
+                    record(trace, || ParseEvent::Consume { token, at: idx });
                 }
 
-                let rhs = Ast::parse(lexer, infix_op.right_bp, open_paren)?;
-                lhs = (infix_op.build)(lhs, rhs);
+                let rhs = Ast::parse(lexer, infix_op.right_bp, open_paren, trace)?;
+                lhs = built(trace, (infix_op.build)(lhs, rhs));
                 continue;
             }
 
@@ -261,24 +433,134 @@ impl Ast {
     }
 }
 
-impl Display for Ast {
+impl Ast {
+    /// Computes the star height of this expression: the deepest nesting of
+    /// Kleene stars. `?` does not count, since `r?` is `r + ε`.
+    ///
+    /// This is the height of the expression as written; an equivalent
+    /// expression may need fewer nested stars.
+    #[must_use]
+    pub fn star_height(&self) -> usize {
+        match self {
+            Ast::Epsilon | Ast::Atom(_) => 0,
+            Ast::Concat(lhs, rhs) | Ast::Alt(lhs, rhs) => lhs.star_height().max(rhs.star_height()),
+            Ast::Star(inner) => inner.star_height() + 1,
+            Ast::Opt(inner) => inner.star_height(),
+        }
+    }
+
+    /// Writes the AST back as a pattern in the syntax [`Ast::build`] reads,
+    /// with as few parentheses as the operator precedences allow.
+    ///
+    /// # Arguments
+    ///
+    /// - `glyph` (`EpsilonGlyph`) - How to write the empty string.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - A pattern for the same language.
+    #[must_use]
+    pub fn to_pattern(&self, glyph: EpsilonGlyph) -> String {
+        self.pattern_at(glyph, 0)
+    }
+
+    /// Writes the pattern, parenthesized if it binds looser than `min_precedence`:
+    /// `0` for alternation, `1` for concatenation and `2` for postfix operators.
+    fn pattern_at(&self, glyph: EpsilonGlyph, min_precedence: u8) -> String {
+        let (precedence, pattern) = match self {
+            Ast::Epsilon => (2, glyph.symbol().to_string()),
+            Ast::Atom(c) => (2, c.to_string()),
+            Ast::Alt(lhs, rhs) => (
+                0,
+                format!("{}+{}", lhs.pattern_at(glyph, 0), rhs.pattern_at(glyph, 0)),
+            ),
+            Ast::Concat(lhs, rhs) => (
+                1,
+                format!("{}{}", lhs.pattern_at(glyph, 1), rhs.pattern_at(glyph, 1)),
+            ),
+            Ast::Star(inner) => (2, format!("{}*", inner.pattern_at(glyph, 2))),
+            Ast::Opt(inner) => (2, format!("{}?", inner.pattern_at(glyph, 2))),
+        };
+        if precedence < min_precedence {
+            format!("({pattern})")
+        } else {
+            pattern
+        }
+    }
+
+    /// Displays the AST with the empty string drawn as `glyph`.
+    #[must_use]
+    pub fn display_with(&self, glyph: EpsilonGlyph) -> AstDisplay<'_> {
+        AstDisplay { ast: self, glyph }
+    }
+}
+
+/// [`Display`] adapter for an [`Ast`] using a chosen [`EpsilonGlyph`].
+#[derive(Debug, Clone, Copy)]
+pub struct AstDisplay<'a> {
+    ast: &'a Ast,
+    glyph: EpsilonGlyph,
+}
+
+impl Display for AstDisplay<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let glyph = self.glyph;
         // Print the AST in unambiguous S-expression format
-        match self {
-            Ast::Epsilon => write!(f, "ε"),
+        match self.ast {
+            Ast::Epsilon => write!(f, "{glyph}"),
             Ast::Atom(c) => write!(f, "{c}"),
-            Ast::Concat(lhs, rhs) => write!(f, "(. {lhs} {rhs})"),
-            Ast::Alt(lhs, rhs) => write!(f, "(+ {lhs} {rhs})"),
-            Ast::Star(inner) => write!(f, "(* {inner})"),
-            Ast::Opt(inner) => write!(f, "(? {inner})"),
+            Ast::Concat(lhs, rhs) => write!(
+                f,
+                "(. {} {})",
+                lhs.display_with(glyph),
+                rhs.display_with(glyph)
+            ),
+            Ast::Alt(lhs, rhs) => write!(
+                f,
+                "(+ {} {})",
+                lhs.display_with(glyph),
+                rhs.display_with(glyph)
+            ),
+            Ast::Star(inner) => write!(f, "(* {})", inner.display_with(glyph)),
+            Ast::Opt(inner) => write!(f, "(? {})", inner.display_with(glyph)),
         }
     }
 }
 
+impl Display for Ast {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.display_with(EpsilonGlyph::default()).fmt(f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_to_pattern() {
+        for pattern in ["a+bc", "(a+b)*c?", "a(b+ε)*", "((ab)*c)*+d", "a**"] {
+            let ast = Ast::build(pattern).unwrap();
+            let written = ast.to_pattern(EpsilonGlyph::Epsilon);
+            assert_eq!(Ast::build(&written).unwrap(), ast, "{pattern} -> {written}");
+        }
+        assert_eq!(
+            Ast::build("(a+b)(c+d)")
+                .unwrap()
+                .to_pattern(EpsilonGlyph::Lambda),
+            "(a+b)(c+d)"
+        );
+    }
+
+    #[test]
+    fn test_star_height() {
+        let height = |pattern| Ast::build(pattern).unwrap().star_height();
+        assert_eq!(height("ab+c"), 0);
+        assert_eq!(height("a*b?"), 1);
+        assert_eq!(height("(a*b)*+c*"), 2);
+        assert_eq!(height("((a*)*)*"), 3);
+    }
+
     #[test]
     fn test_empty_input() {
         let ast = Ast::build("").unwrap();
@@ -561,6 +843,16 @@ mod tests {
         assert_eq!(ast.to_string(), "(. (* (. (+ a ε) ε)) b)");
     }
 
+    #[test]
+    fn test_display_with_lambda() {
+        let ast = Ast::build(r"(a+λ)*\0").unwrap();
+        assert_eq!(ast.to_string(), "(. (* (+ a ε)) ε)");
+        assert_eq!(
+            ast.display_with(EpsilonGlyph::Lambda).to_string(),
+            "(. (* (+ a λ)) λ)"
+        );
+    }
+
     #[test]
     fn test_explicit_dot_with_epsilon() {
         let ast = Ast::build("\\e.a").unwrap();
@@ -568,4 +860,44 @@ mod tests {
         let ast = Ast::build("a.\\e.b").unwrap();
         assert_eq!(ast.to_string(), "(. (. a ε) b)");
     }
+
+    #[test]
+    fn test_build_traced_matches_build() {
+        for input in ["", "a", "(a+b)*abb", "a+b*c", "(ab)?c", "\\e.a"] {
+            let (ast, events) = Ast::build_traced(input).unwrap();
+            assert_eq!(ast, Ast::build(input).unwrap());
+            // The last node built is the root, numbered 0.
+            assert_eq!(events.last(), Some(&ParseEvent::Build { id: 0, node: ast }));
+        }
+    }
+
+    #[test]
+    fn test_build_traced_records_precedence() {
+        // `ab+c` groups as `(ab)+c`: the `+` is too weak to extend `b`.
+        let (_, events) = Ast::build_traced("ab+c").unwrap();
+        assert!(events.contains(&ParseEvent::Bind {
+            op: OpToken::Dot,
+            at: 1,
+            power: 3,
+            min_bp: 0,
+            implicit: true,
+        }));
+        assert!(events.contains(&ParseEvent::Yield {
+            op: OpToken::Plus,
+            at: 2,
+            power: 1,
+            min_bp: 4,
+            implicit: false,
+        }));
+
+        // Build events visit the tree in post-order, numbered in pre-order.
+        let ids: Vec<usize> = events
+            .iter()
+            .filter_map(|event| match event {
+                ParseEvent::Build { id, .. } => Some(*id),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(ids, vec![2, 3, 1, 4, 0]);
+    }
 }
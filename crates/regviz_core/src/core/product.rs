@@ -0,0 +1,218 @@
+use std::fmt;
+
+use indexmap::IndexMap;
+
+use super::automaton::StateId;
+use super::dfa::Dfa;
+
+/// Boolean operations on languages, computed with the product construction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoolOp {
+    /// Words in either language.
+    Union,
+    /// Words in both languages.
+    Intersection,
+    /// Words in the left language but not the right one.
+    Difference,
+    /// Words in exactly one of the languages.
+    SymmetricDifference,
+}
+
+impl BoolOp {
+    /// Every operation, in the order they are usually listed.
+    pub const ALL: [BoolOp; 4] = [
+        BoolOp::Union,
+        BoolOp::Intersection,
+        BoolOp::Difference,
+        BoolOp::SymmetricDifference,
+    ];
+
+    /// Returns the set-theory symbol for the operation.
+    #[must_use]
+    pub fn symbol(self) -> &'static str {
+        match self {
+            BoolOp::Union => "∪",
+            BoolOp::Intersection => "∩",
+            BoolOp::Difference => "∖",
+            BoolOp::SymmetricDifference => "△",
+        }
+    }
+
+    /// Decides whether a product state accepts, given whether each side accepts.
+    #[must_use]
+    pub fn combine(self, left: bool, right: bool) -> bool {
+        match self {
+            BoolOp::Union => left || right,
+            BoolOp::Intersection => left && right,
+            BoolOp::Difference => left && !right,
+            BoolOp::SymmetricDifference => left != right,
+        }
+    }
+}
+
+impl fmt::Display for BoolOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            BoolOp::Union => "Union",
+            BoolOp::Intersection => "Intersection",
+            BoolOp::Difference => "Difference",
+            BoolOp::SymmetricDifference => "Symmetric Difference",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Combines two DFAs with the product construction.
+///
+/// The result runs both automata in lockstep over the union of their
+/// alphabets. A symbol missing from one alphabet sends that side to an
+/// implicit dead state, so each language is read as a set of words over the
+/// shared alphabet. Only reachable pairs are built.
+///
+/// # Arguments
+///
+/// - `left` (`&Dfa`) - The automaton for the left operand.
+/// - `right` (`&Dfa`) - The automaton for the right operand.
+/// - `op` (`BoolOp`) - How acceptance of the two sides is combined.
+///
+/// # Returns
+///
+/// - `Dfa` - A DFA over the sorted, merged alphabet accepting `L(left) op L(right)`.
+#[must_use]
+pub fn product(left: &Dfa, right: &Dfa, op: BoolOp) -> Dfa {
+    let mut alphabet: Vec<char> = left
+        .alphabet
+        .iter()
+        .chain(&right.alphabet)
+        .copied()
+        .collect();
+    alphabet.sort_unstable();
+    alphabet.dedup();
+
+    // `None` stands for the dead state of a side that cannot read a symbol.
+    let step = |dfa: &Dfa, state: Option<StateId>, symbol: char| dfa.step(state?, symbol);
+    let accepts =
+        |dfa: &Dfa, state: Option<StateId>| state.is_some_and(|s| dfa.accepts.contains(&s));
+
+    let mut pairs: IndexMap<(Option<StateId>, Option<StateId>), StateId> = IndexMap::new();
+    pairs.insert((Some(left.start), Some(right.start)), 0);
+    let mut trans = Vec::new();
+    let mut next_idx = 0;
+    while let Some((&(l, r), _)) = pairs.get_index(next_idx) {
+        next_idx += 1;
+        let row = alphabet
+            .iter()
+            .map(|&symbol| {
+                let pair = (step(left, l, symbol), step(right, r, symbol));
+                let fresh = pairs.len() as StateId;
+                *pairs.entry(pair).or_insert(fresh)
+            })
+            .collect();
+        trans.push(row);
+    }
+
+    let accepts = pairs
+        .iter()
+        .filter(|((l, r), _)| op.combine(accepts(left, *l), accepts(right, *r)))
+        .map(|(_, &id)| id)
+        .collect();
+    Dfa {
+        states: (0..pairs.len() as StateId).collect(),
+        start: 0,
+        accepts,
+        trans,
+        alphabet,
+    }
+}
+
+/// Outcome of a language containment check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Containment {
+    /// Every word of the left language is in the right one.
+    Contained,
+    /// A shortest word of the left language missing from the right one.
+    Witness(String),
+}
+
+impl Containment {
+    /// Returns whether the containment holds.
+    #[must_use]
+    pub fn holds(&self) -> bool {
+        matches!(self, Containment::Contained)
+    }
+}
+
+/// Checks whether `L(left) ⊆ L(right)`.
+///
+/// The containment holds exactly when `L(left) ∩ ¬L(right)` is empty, which
+/// is the [`BoolOp::Difference`] product. When it fails, the shortest (then
+/// alphabetically first) word of that difference is returned as a witness.
+///
+/// # Arguments
+///
+/// - `left` (`&Dfa`) - The automaton for the candidate subset.
+/// - `right` (`&Dfa`) - The automaton for the candidate superset.
+///
+/// # Returns
+///
+/// - `Containment` - Whether the containment holds, with a witness if not.
+#[must_use]
+pub fn contains(left: &Dfa, right: &Dfa) -> Containment {
+    match product(left, right, BoolOp::Difference).shortest_accepted() {
+        None => Containment::Contained,
+        Some(word) => Containment::Witness(word),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{dfa, nfa::Nfa, parser::Ast, sim};
+
+    fn dfa(pattern: &str) -> Dfa {
+        dfa::determinize(&Nfa::build(&Ast::build(pattern).unwrap()))
+    }
+
+    #[test]
+    fn test_product_matches_boolean_combination() {
+        let (left, right) = (dfa("a*b"), dfa("(a+c)*"));
+        for op in BoolOp::ALL {
+            let combined = product(&left, &right, op);
+            assert!(combined.validate().is_ok());
+            for word in ["", "b", "ab", "aab", "a", "ac", "cb", "abc"] {
+                assert_eq!(
+                    sim::simulate_dfa(&combined, word),
+                    op.combine(
+                        sim::simulate_dfa(&left, word),
+                        sim::simulate_dfa(&right, word)
+                    ),
+                    "{op} on {word:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_contains_reports_shortest_witness() {
+        assert_eq!(contains(&dfa("ab"), &dfa("a*b")), Containment::Contained);
+        assert_eq!(
+            contains(&dfa("a*b"), &dfa("ab")),
+            Containment::Witness("b".to_string())
+        );
+        // Symbols the right side never reads are not in its language.
+        assert_eq!(
+            contains(&dfa("c"), &dfa("a*")),
+            Containment::Witness("c".to_string())
+        );
+        assert!(contains(&dfa("\\e"), &dfa("a*")).holds());
+    }
+
+    #[test]
+    fn test_symmetric_difference_of_equivalent_patterns_is_empty() {
+        let diff = product(&dfa("(a+b)*"), &dfa("(a*b*)*"), BoolOp::SymmetricDifference);
+        assert_eq!(diff.shortest_accepted(), None);
+
+        let diff = product(&dfa("a*"), &dfa("aa*"), BoolOp::Difference);
+        assert_eq!(diff.shortest_accepted(), Some(String::new()));
+    }
+}
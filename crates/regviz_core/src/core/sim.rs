@@ -1,27 +1,69 @@
-use std::collections::HashSet;
-
-use super::automaton::{EdgeLabel, StateId};
+use super::automaton::{Edge, EdgeLabel, StateId};
 use super::dfa::Dfa;
 use super::nfa::Nfa;
+use super::state_set::StateSet;
+use crate::errors::SimError;
 
 /// Simulates a DFA and reports whether it accepts the provided input.
 pub fn simulate_dfa(dfa: &Dfa, input: &str) -> bool {
+    simulate_dfa_iter(dfa, input.chars())
+}
+
+/// Simulates a DFA over a lazily produced stream of symbols.
+///
+/// Symbols are consumed one at a time and never collected, so arbitrarily
+/// long inputs (e.g. read from stdin) run in constant memory. The stream is
+/// abandoned as soon as a symbol outside the alphabet is seen.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton to run.
+/// - `input` (`impl IntoIterator<Item = char>`) - The symbols to feed in order.
+///
+/// # Returns
+///
+/// - `bool` - Whether the DFA ends in an accepting state.
+pub fn simulate_dfa_iter(dfa: &Dfa, input: impl IntoIterator<Item = char>) -> bool {
     let mut state = dfa.start;
-    for ch in input.chars() {
-        let idx = match dfa.alphabet.iter().position(|&c| c == ch) {
-            Some(i) => i,
+    for ch in input {
+        state = match dfa.step(state, ch) {
+            Some(next) => next,
             None => return false,
         };
-        state = dfa.trans[state as usize][idx];
     }
     dfa.accepts.contains(&state)
 }
 
+/// Checks that `input` can be fed to an automaton reading `alphabet`.
+///
+/// The simulators treat a symbol outside the alphabet as a rejection; this
+/// lets frontends report it as an input error instead.
+///
+/// # Arguments
+///
+/// - `alphabet` (`&[char]`) - The symbols the automaton reads.
+/// - `input` (`&str`) - The word to simulate.
+///
+/// # Returns
+///
+/// - `Result<(), SimError>` - The first symbol outside the alphabet and its
+///   char index, if any.
+pub fn check_input(alphabet: &[char], input: &str) -> Result<(), SimError> {
+    match input
+        .chars()
+        .enumerate()
+        .find(|(_, symbol)| !alphabet.contains(symbol))
+    {
+        Some((at, symbol)) => Err(SimError::UnknownSymbol { symbol, at }),
+        None => Ok(()),
+    }
+}
+
 /// Computes the epsilon-closure of a state set in an NFA using DFS.
 /// That is, the set of states reachable from `seed` via only epsilon transitions.
-pub fn epsilon_closure(seed: &HashSet<StateId>, nfa: &Nfa) -> HashSet<StateId> {
+pub fn epsilon_closure(seed: &StateSet, nfa: &Nfa) -> StateSet {
     let mut closure = seed.clone();
-    let mut stack: Vec<StateId> = seed.iter().copied().collect();
+    let mut stack: Vec<StateId> = seed.iter().collect();
     while let Some(state) = stack.pop() {
         for tr in nfa.transitions(state) {
             if tr.label == EdgeLabel::Eps && closure.insert(tr.to) {
@@ -33,10 +75,10 @@ pub fn epsilon_closure(seed: &HashSet<StateId>, nfa: &Nfa) -> HashSet<StateId> {
 }
 
 /// Advances the frontier one step on a symbol, without taking epsilon-closures.
-pub fn move_on(states: &HashSet<StateId>, symbol: char, nfa: &Nfa) -> HashSet<StateId> {
-    let mut frontier = HashSet::new();
-    for state in states {
-        for tr in nfa.transitions(*state) {
+pub fn move_on(states: &StateSet, symbol: char, nfa: &Nfa) -> StateSet {
+    let mut frontier = StateSet::new(nfa.states.len());
+    for state in states.iter() {
+        for tr in nfa.transitions(state) {
             if tr.label == EdgeLabel::Sym(symbol) {
                 frontier.insert(tr.to);
             }
@@ -47,15 +89,283 @@ pub fn move_on(states: &HashSet<StateId>, symbol: char, nfa: &Nfa) -> HashSet<St
 
 /// Simulates an NFA using the standard powerset traversal.
 pub fn nfa_accepts(nfa: &Nfa, input: &str) -> bool {
-    let mut current = HashSet::new();
-    current.insert(nfa.start);
-    current = epsilon_closure(&current, nfa);
-    for ch in input.chars() {
-        let moved = move_on(&current, ch, nfa);
-        current = epsilon_closure(&moved, nfa);
+    nfa_accepts_iter(nfa, input.chars())
+}
+
+/// Simulates an NFA over a lazily produced stream of symbols.
+///
+/// Only the current frontier is kept, so memory does not grow with the input.
+/// The stream is abandoned as soon as the frontier becomes empty.
+///
+/// # Arguments
+///
+/// - `nfa` (`&Nfa`) - The automaton to run.
+/// - `input` (`impl IntoIterator<Item = char>`) - The symbols to feed in order.
+///
+/// # Returns
+///
+/// - `bool` - Whether any accepting state is active after the last symbol.
+pub fn nfa_accepts_iter(nfa: &Nfa, input: impl IntoIterator<Item = char>) -> bool {
+    nfa_accepts_with_closures(nfa, &nfa.epsilon_closures(), input)
+}
+
+/// Simulates an NFA like [`nfa_accepts_iter`], reusing ε-closures computed
+/// once by [`Nfa::epsilon_closures`], e.g. when running many inputs.
+///
+/// # Arguments
+///
+/// - `nfa` (`&Nfa`) - The automaton to run.
+/// - `closures` (`&[StateSet]`) - The ε-closure of every state of `nfa`.
+/// - `input` (`impl IntoIterator<Item = char>`) - The symbols to feed in order.
+///
+/// # Returns
+///
+/// - `bool` - Whether any accepting state is active after the last symbol.
+pub fn nfa_accepts_with_closures(
+    nfa: &Nfa,
+    closures: &[StateSet],
+    input: impl IntoIterator<Item = char>,
+) -> bool {
+    let mut current = closures[nfa.start as usize].clone();
+    for ch in input {
+        current = step_closed(nfa, closures, &current, ch);
         if current.is_empty() {
             return false;
         }
     }
-    current.iter().any(|state| nfa.accepts.contains(state))
+    nfa.accepts.iter().any(|&state| current.contains(state))
+}
+
+/// Moves `states` on `symbol` and closes the result under ε transitions, by
+/// uniting the precomputed `closures` of the states moved to.
+pub(crate) fn step_closed(
+    nfa: &Nfa,
+    closures: &[StateSet],
+    states: &StateSet,
+    symbol: char,
+) -> StateSet {
+    let mut next = StateSet::new(nfa.states.len());
+    for state in states.iter() {
+        for tr in nfa.transitions(state) {
+            if tr.label == EdgeLabel::Sym(symbol) {
+                next.union_with(&closures[tr.to as usize]);
+            }
+        }
+    }
+    next
+}
+
+/// Explanation of why an input was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rejection {
+    /// Longest prefix of the input after which acceptance was still possible.
+    pub matched_prefix: String,
+    /// Symbols that could have been read next to stay on course for acceptance.
+    pub expected: Vec<char>,
+    /// The symbol that broke the match, or `None` if the input ended too early.
+    pub found: Option<char>,
+}
+
+/// Explains why an NFA rejects an input.
+///
+/// A run stays alive while some active state can still reach an accepting
+/// state. The input is read until no run is alive; the prefix read up to that
+/// point and the symbols that would have continued a live run are reported.
+///
+/// # Arguments
+///
+/// - `nfa` (`&Nfa`) - The automaton to simulate.
+/// - `input` (`&str`) - The input to explain.
+///
+/// # Returns
+///
+/// - `Option<Rejection>` - The explanation, or `None` if the input is accepted.
+pub fn diagnose(nfa: &Nfa, input: &str) -> Option<Rejection> {
+    let productive = productive_states(nfa);
+    let closures = nfa.epsilon_closures();
+    let mut current = closures[nfa.start as usize].clone();
+    current.intersect_with(&productive);
+
+    let mut matched_prefix = String::new();
+    let mut found = None;
+    for ch in input.chars() {
+        let mut next = step_closed(nfa, &closures, &current, ch);
+        next.intersect_with(&productive);
+        if next.is_empty() {
+            found = Some(ch);
+            break;
+        }
+        matched_prefix.push(ch);
+        current = next;
+    }
+
+    if found.is_none() && nfa.accepts.iter().any(|&state| current.contains(state)) {
+        return None;
+    }
+
+    let mut expected: Vec<char> = current
+        .iter()
+        .flat_map(|state| nfa.transitions(state))
+        .filter_map(|tr| match tr.label {
+            EdgeLabel::Sym(symbol) if productive.contains(tr.to) => Some(symbol),
+            _ => None,
+        })
+        .collect();
+    expected.sort_unstable();
+    expected.dedup();
+
+    Some(Rejection {
+        matched_prefix,
+        expected,
+        found,
+    })
+}
+
+/// Returns the states from which some accepting state can be reached.
+fn productive_states(nfa: &Nfa) -> StateSet {
+    let mut productive = StateSet::new(nfa.states.len());
+    productive.extend(nfa.accepts.iter().copied());
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for state in &nfa.states {
+            if !productive.contains(state.id)
+                && nfa
+                    .transitions(state.id)
+                    .iter()
+                    .any(|tr| productive.contains(tr.to))
+            {
+                productive.insert(state.id);
+                changed = true;
+            }
+        }
+    }
+    productive
+}
+
+/// Extracts explicit accepting paths of an NFA for an input.
+///
+/// Each path lists every transition taken, epsilon moves included, from the
+/// start state to an accepting state while reading exactly `input`. Paths
+/// never repeat a state between two consumed symbols, so epsilon cycles are
+/// not unrolled. Enumeration stops after `limit` paths, since ambiguous
+/// automata can have exponentially many.
+///
+/// # Arguments
+///
+/// - `nfa` (`&Nfa`) - The automaton to trace.
+/// - `input` (`&str`) - The input to read.
+/// - `limit` (`usize`) - Maximum number of paths to return.
+///
+/// # Returns
+///
+/// - `Vec<Vec<Edge>>` - The accepting paths; empty if the input is rejected.
+pub fn accepting_paths(nfa: &Nfa, input: &str, limit: usize) -> Vec<Vec<Edge>> {
+    let symbols: Vec<char> = input.chars().collect();
+    let live = live_states(nfa, &symbols);
+    let mut search = PathSearch {
+        nfa,
+        symbols: &symbols,
+        live: &live,
+        on_path: StateSet::new(nfa.states.len()),
+        path: Vec::new(),
+        paths: Vec::new(),
+        limit,
+    };
+    if limit > 0 && live[0].contains(nfa.start) {
+        search.visit(nfa.start, 0);
+    }
+    search.paths
+}
+
+/// For every input position, the states from which the rest of the input is accepted.
+fn live_states(nfa: &Nfa, symbols: &[char]) -> Vec<StateSet> {
+    let mut live = vec![StateSet::new(nfa.states.len()); symbols.len() + 1];
+    live[symbols.len()].extend(nfa.accepts.iter().copied());
+    for pos in (0..=symbols.len()).rev() {
+        if let Some(symbol) = symbols.get(pos) {
+            for state in &nfa.states {
+                let consumes = nfa
+                    .transitions(state.id)
+                    .iter()
+                    .any(|tr| tr.label == EdgeLabel::Sym(*symbol) && live[pos + 1].contains(tr.to));
+                if consumes {
+                    live[pos].insert(state.id);
+                }
+            }
+        }
+
+        // Close backward over epsilon moves until nothing changes.
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for state in &nfa.states {
+                if !live[pos].contains(state.id)
+                    && nfa
+                        .transitions(state.id)
+                        .iter()
+                        .any(|tr| tr.label == EdgeLabel::Eps && live[pos].contains(tr.to))
+                {
+                    live[pos].insert(state.id);
+                    changed = true;
+                }
+            }
+        }
+    }
+    live
+}
+
+/// Depth-first enumeration of accepting paths, restricted to live states.
+struct PathSearch<'a> {
+    nfa: &'a Nfa,
+    symbols: &'a [char],
+    live: &'a [StateSet],
+    /// States visited since the last consumed symbol.
+    on_path: StateSet,
+    path: Vec<Edge>,
+    paths: Vec<Vec<Edge>>,
+    limit: usize,
+}
+
+impl PathSearch<'_> {
+    fn visit(&mut self, state: StateId, pos: usize) {
+        if pos == self.symbols.len() && self.nfa.accepts.contains(&state) {
+            self.paths.push(self.path.clone());
+            if self.paths.len() >= self.limit {
+                return;
+            }
+        }
+
+        self.on_path.insert(state);
+        for tr in self.nfa.transitions(state) {
+            if self.paths.len() >= self.limit {
+                break;
+            }
+            let next_pos = match tr.label {
+                EdgeLabel::Eps if !self.on_path.contains(tr.to) => pos,
+                EdgeLabel::Sym(symbol) if self.symbols.get(pos) == Some(&symbol) => pos + 1,
+                _ => continue,
+            };
+            if !self.live[next_pos].contains(tr.to) {
+                continue;
+            }
+
+            self.path.push(Edge {
+                from: state,
+                to: tr.to,
+                label: tr.label,
+            });
+            if next_pos == pos {
+                self.visit(tr.to, next_pos);
+            } else {
+                // Consuming a symbol starts a fresh epsilon segment.
+                let fresh = StateSet::new(self.nfa.states.len());
+                let segment = std::mem::replace(&mut self.on_path, fresh);
+                self.visit(tr.to, next_pos);
+                self.on_path = segment;
+            }
+            self.path.pop();
+        }
+        self.on_path.remove(state);
+    }
 }
@@ -0,0 +1,191 @@
+//! Fixed-size bitsets of automaton states, for subset construction and
+//! powerset simulation.
+
+use super::automaton::StateId;
+
+/// Number of states each block of a [`StateSet`] holds.
+const BLOCK_BITS: usize = u64::BITS as usize;
+
+/// A set of states of an automaton with a known number of states, stored as
+/// one bit per state.
+///
+/// Sets are sized for their automaton up front, so two sets over the same
+/// automaton compare and hash equal exactly when they hold the same states.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct StateSet {
+    blocks: Vec<u64>,
+}
+
+impl StateSet {
+    /// Creates an empty set able to hold states `0..states`.
+    ///
+    /// # Arguments
+    ///
+    /// - `states` (`usize`) - The number of states of the automaton.
+    ///
+    /// # Returns
+    ///
+    /// - `StateSet` - The empty set.
+    #[must_use]
+    pub fn new(states: usize) -> Self {
+        Self {
+            blocks: vec![0; states.div_ceil(BLOCK_BITS)],
+        }
+    }
+
+    /// Adds `state` to the set.
+    ///
+    /// # Arguments
+    ///
+    /// - `state` (`StateId`) - The state to add; must be below the size the set was created with.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the state was not in the set yet.
+    pub fn insert(&mut self, state: StateId) -> bool {
+        let (block, bit) = Self::locate(state);
+        let added = self.blocks[block] & bit == 0;
+        self.blocks[block] |= bit;
+        added
+    }
+
+    /// Removes `state` from the set.
+    ///
+    /// # Arguments
+    ///
+    /// - `state` (`StateId`) - The state to remove.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the state was in the set.
+    pub fn remove(&mut self, state: StateId) -> bool {
+        let (block, bit) = Self::locate(state);
+        let Some(bits) = self.blocks.get_mut(block) else {
+            return false;
+        };
+        let removed = *bits & bit != 0;
+        *bits &= !bit;
+        removed
+    }
+
+    /// Returns whether `state` is in the set.
+    #[must_use]
+    pub fn contains(&self, state: StateId) -> bool {
+        let (block, bit) = Self::locate(state);
+        self.blocks.get(block).is_some_and(|bits| bits & bit != 0)
+    }
+
+    /// Adds every state of `other` to the set.
+    ///
+    /// # Arguments
+    ///
+    /// - `other` (`&StateSet`) - A set over the same automaton.
+    pub fn union_with(&mut self, other: &StateSet) {
+        for (block, bits) in self.blocks.iter_mut().zip(&other.blocks) {
+            *block |= bits;
+        }
+    }
+
+    /// Keeps only the states that are also in `other`.
+    ///
+    /// # Arguments
+    ///
+    /// - `other` (`&StateSet`) - A set over the same automaton.
+    pub fn intersect_with(&mut self, other: &StateSet) {
+        for (block, bits) in self.blocks.iter_mut().zip(&other.blocks) {
+            *block &= bits;
+        }
+    }
+
+    /// Returns whether the set shares any state with `other`.
+    #[must_use]
+    pub fn intersects(&self, other: &StateSet) -> bool {
+        self.blocks
+            .iter()
+            .zip(&other.blocks)
+            .any(|(left, right)| left & right != 0)
+    }
+
+    /// Returns whether the set holds no state.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.blocks.iter().all(|&bits| bits == 0)
+    }
+
+    /// Returns the number of states in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.blocks
+            .iter()
+            .map(|bits| bits.count_ones() as usize)
+            .sum()
+    }
+
+    /// Iterates over the states of the set in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = StateId> + '_ {
+        self.blocks.iter().enumerate().flat_map(|(index, &bits)| {
+            let base = (index * BLOCK_BITS) as StateId;
+            std::iter::successors((bits != 0).then_some(bits), |&rest| {
+                let rest = rest & (rest - 1);
+                (rest != 0).then_some(rest)
+            })
+            .map(move |rest| base + rest.trailing_zeros())
+        })
+    }
+
+    /// Returns the block holding `state` and the bit marking it in that block.
+    fn locate(state: StateId) -> (usize, u64) {
+        let state = state as usize;
+        (state / BLOCK_BITS, 1 << (state % BLOCK_BITS))
+    }
+}
+
+impl Extend<StateId> for StateSet {
+    fn extend<I: IntoIterator<Item = StateId>>(&mut self, states: I) {
+        for state in states {
+            self.insert(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_contains_and_iterate_across_blocks() {
+        let mut set = StateSet::new(130);
+        assert!(set.is_empty());
+        for state in [129, 0, 64, 63, 64] {
+            set.insert(state);
+        }
+        assert!(!set.insert(0));
+        assert!(set.contains(63) && set.contains(129));
+        assert!(!set.contains(1) && !set.contains(500));
+        assert_eq!(set.len(), 4);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0, 63, 64, 129]);
+    }
+
+    #[test]
+    fn test_union_and_intersection() {
+        let mut left = StateSet::new(70);
+        left.insert(1);
+        let mut right = StateSet::new(70);
+        right.insert(68);
+        assert!(!left.intersects(&right));
+
+        left.union_with(&right);
+        assert!(left.intersects(&right));
+        assert_eq!(left.iter().collect::<Vec<_>>(), vec![1, 68]);
+
+        let mut same = StateSet::new(70);
+        same.extend([68, 1]);
+        assert_eq!(left, same);
+
+        left.intersect_with(&right);
+        assert_eq!(left.iter().collect::<Vec<_>>(), vec![68]);
+        assert!(left.remove(68));
+        assert!(!left.remove(68));
+        assert!(left.is_empty());
+    }
+}
@@ -0,0 +1,230 @@
+//! A description of the regular-expression syntax the lexer and parser accept.
+//!
+//! The lexer reads its escape and epsilon characters from [`SYNTAX`], and the
+//! operator precedences come straight from the Pratt parser's binding powers,
+//! so the reference generated here cannot drift from what is actually parsed.
+
+use crate::core::lexer::OpToken;
+
+/// Characters with special meaning to the lexer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyntaxConfig {
+    /// Character that makes the next character literal.
+    pub escape: char,
+    /// Characters that spell epsilon when escaped (e.g. `\e`).
+    pub epsilon_escapes: &'static [char],
+    /// Characters that spell epsilon on their own.
+    pub epsilon_glyphs: &'static [char],
+}
+
+/// The syntax used by [`crate::core::lexer::Lexer`].
+pub const SYNTAX: SyntaxConfig = SyntaxConfig {
+    escape: '\\',
+    epsilon_escapes: &['e', '0'],
+    epsilon_glyphs: &['ε', 'λ'],
+};
+
+impl Default for SyntaxConfig {
+    fn default() -> Self {
+        SYNTAX
+    }
+}
+
+/// Groups entries of the syntax reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxCategory {
+    /// Characters matched literally.
+    Literal,
+    /// Ways of writing the empty string.
+    Epsilon,
+    /// Escaping reserved characters.
+    Escape,
+    /// Parentheses.
+    Grouping,
+    /// Operators, listed from tightest to loosest binding.
+    Operator,
+}
+
+impl std::fmt::Display for SyntaxCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            SyntaxCategory::Literal => "Literals",
+            SyntaxCategory::Epsilon => "Epsilon",
+            SyntaxCategory::Escape => "Escapes",
+            SyntaxCategory::Grouping => "Grouping",
+            SyntaxCategory::Operator => "Operators",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// One line of the syntax reference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxEntry {
+    /// Section the entry belongs to.
+    pub category: SyntaxCategory,
+    /// How the construct is written.
+    pub syntax: String,
+    /// What it means.
+    pub meaning: String,
+    /// A pattern using it.
+    pub example: &'static str,
+    /// The operator described, for operator entries.
+    pub operator: Option<OpToken>,
+    /// Precedence level for operators, where 1 binds tightest.
+    pub precedence: Option<usize>,
+}
+
+impl SyntaxEntry {
+    /// Returns `true` if `query` appears (case-insensitively) in the syntax or meaning.
+    ///
+    /// # Arguments
+    ///
+    /// - `query` (`&str`) - Text to look for; blank matches every entry.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the entry should be listed for this search.
+    #[must_use]
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        query.is_empty()
+            || self.syntax.to_lowercase().contains(&query)
+            || self.meaning.to_lowercase().contains(&query)
+            || self.category.to_string().to_lowercase().contains(&query)
+    }
+}
+
+impl SyntaxConfig {
+    /// Builds the syntax reference: literals, epsilon, escapes, grouping and
+    /// every operator ordered by precedence.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<SyntaxEntry>` - Entries grouped by category, operators tightest first.
+    #[must_use]
+    pub fn reference(&self) -> Vec<SyntaxEntry> {
+        let mut entries = vec![
+            SyntaxEntry {
+                category: SyntaxCategory::Literal,
+                syntax: "a-z A-Z 0-9".to_string(),
+                meaning: "Matches that character; whitespace is ignored".to_string(),
+                example: "ab1",
+                operator: None,
+                precedence: None,
+            },
+            SyntaxEntry {
+                category: SyntaxCategory::Epsilon,
+                syntax: self.epsilon_spellings().join(" "),
+                meaning: "Matches the empty string".to_string(),
+                example: "a(b+\\e)",
+                operator: None,
+                precedence: None,
+            },
+            SyntaxEntry {
+                category: SyntaxCategory::Escape,
+                syntax: format!("{}c", self.escape),
+                meaning: "Matches the character c literally".to_string(),
+                example: "a\\+",
+                operator: None,
+                precedence: None,
+            },
+            SyntaxEntry {
+                category: SyntaxCategory::Grouping,
+                syntax: "( )".to_string(),
+                meaning: "Groups a sub-expression".to_string(),
+                example: "(ab)*",
+                operator: None,
+                precedence: None,
+            },
+        ];
+
+        let mut operators: Vec<OpToken> = OpToken::ALL.to_vec();
+        operators.sort_by_key(|op| std::cmp::Reverse(op.binding_power()));
+        let mut levels: Vec<u8> = operators.iter().map(OpToken::binding_power).collect();
+        levels.dedup();
+        for op in operators {
+            let level = levels
+                .iter()
+                .position(|&bp| bp == op.binding_power())
+                .expect("every operator's binding power is listed");
+            let (meaning, example) = operator_help(op);
+            entries.push(SyntaxEntry {
+                category: SyntaxCategory::Operator,
+                syntax: op.to_string(),
+                meaning: meaning.to_string(),
+                example,
+                operator: Some(op),
+                precedence: Some(level + 1),
+            });
+        }
+        entries
+    }
+
+    /// Every way of writing epsilon, escaped spellings first.
+    fn epsilon_spellings(&self) -> Vec<String> {
+        self.epsilon_escapes
+            .iter()
+            .map(|c| format!("{}{c}", self.escape))
+            .chain(self.epsilon_glyphs.iter().map(char::to_string))
+            .collect()
+    }
+}
+
+/// Meaning and example pattern for an operator.
+fn operator_help(op: OpToken) -> (&'static str, &'static str) {
+    match op {
+        OpToken::Star => ("Zero or more repetitions of the operand", "a*"),
+        OpToken::Opt => ("Zero or one occurrence of the operand", "ab?"),
+        OpToken::Dot => ("Concatenation; juxtaposition (ab) means the same", "a.b"),
+        OpToken::Plus => ("Alternation: either operand", "a+b"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::parser::Ast;
+
+    #[test]
+    fn every_example_parses() {
+        for entry in SYNTAX.reference() {
+            assert!(
+                Ast::build(entry.example).is_ok(),
+                "example {:?} for {:?} should parse",
+                entry.example,
+                entry.syntax
+            );
+        }
+    }
+
+    #[test]
+    fn operators_are_ordered_tightest_first() {
+        let levels: Vec<(String, usize)> = SYNTAX
+            .reference()
+            .into_iter()
+            .filter_map(|entry| entry.precedence.map(|level| (entry.syntax, level)))
+            .collect();
+        assert_eq!(
+            levels,
+            vec![
+                ("*".to_string(), 1),
+                ("?".to_string(), 1),
+                (".".to_string(), 2),
+                ("+".to_string(), 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn search_matches_syntax_and_meaning() {
+        let reference = SYNTAX.reference();
+        let hits: Vec<_> = reference
+            .iter()
+            .filter(|entry| entry.matches("empty"))
+            .collect();
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].syntax.contains("\\e"));
+        assert!(reference.iter().all(|entry| entry.matches("  ")));
+    }
+}
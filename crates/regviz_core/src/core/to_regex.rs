@@ -0,0 +1,291 @@
+//! Conversion of DFAs back into regular expressions by state elimination.
+//!
+//! The automaton is wrapped into a generalized NFA whose transitions are
+//! labelled with expressions, with a fresh start and a fresh final state. The
+//! original states are then removed one by one, rerouting every path through a
+//! removed state `k` as `R(i,k) R(k,k)* R(k,j)`. Expressions are simplified as
+//! they are built so the result stays readable.
+
+use super::automaton::StateId;
+use super::dfa::Dfa;
+use super::parser::Ast;
+
+/// Converts a DFA into a regular expression for its language.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton to convert.
+///
+/// # Returns
+///
+/// - `Option<Ast>` - An expression for the language, or `None` if it is empty.
+#[must_use]
+pub fn dfa_to_regex(dfa: &Dfa) -> Option<Ast> {
+    language_between(dfa, dfa.start, &dfa.accepts)
+}
+
+/// Describes the left language of `state`: the words leading to it from the
+/// start state.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton.
+/// - `state` (`StateId`) - The state the words end in.
+///
+/// # Returns
+///
+/// - `Option<Ast>` - An expression for the words, or `None` if the state is unreachable.
+#[must_use]
+pub fn left_language(dfa: &Dfa, state: StateId) -> Option<Ast> {
+    language_between(dfa, dfa.start, &[state])
+}
+
+/// Describes the right language of `state`: the words it still accepts.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton.
+/// - `state` (`StateId`) - The state the words are read from.
+///
+/// # Returns
+///
+/// - `Option<Ast>` - An expression for the words, or `None` if the state is dead.
+#[must_use]
+pub fn right_language(dfa: &Dfa, state: StateId) -> Option<Ast> {
+    language_between(dfa, state, &dfa.accepts)
+}
+
+/// Describes the words leading from `from` to any of the `targets` by state
+/// elimination.
+///
+/// States that are not on any such path are dropped before eliminating, and
+/// the remaining ones are eliminated fewest rerouted paths first, which keeps
+/// the expression small.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton. Rows shorter than the alphabet are allowed.
+/// - `from` (`StateId`) - The state the words are read from.
+/// - `targets` (`&[StateId]`) - The states the words may end in.
+///
+/// # Returns
+///
+/// - `Option<Ast>` - An expression for the words, or `None` if there are none.
+#[must_use]
+pub fn language_between(dfa: &Dfa, from: StateId, targets: &[StateId]) -> Option<Ast> {
+    let states = dfa.trans.len();
+    if from as usize >= states {
+        return None;
+    }
+    let forward = mark(states, &[from], |state| {
+        dfa.transitions()
+            .filter(move |&(source, _, _)| source == state)
+            .map(|(_, _, to)| to)
+    });
+    let targets: Vec<StateId> = targets
+        .iter()
+        .copied()
+        .filter(|&target| (target as usize) < states)
+        .collect();
+    let backward = mark(states, &targets, |state| {
+        dfa.transitions()
+            .filter(move |&(_, _, to)| to == state)
+            .map(|(source, _, _)| source)
+    });
+    let useful: Vec<bool> = forward
+        .iter()
+        .zip(&backward)
+        .map(|(f, b)| *f && *b)
+        .collect();
+    if !useful[from as usize] {
+        return None;
+    }
+
+    // Nodes are the original states, then the fresh start and final states.
+    let (start, end) = (states, states + 1);
+    let mut paths: Vec<Vec<Option<Ast>>> = vec![vec![None; states + 2]; states + 2];
+    paths[start][from as usize] = Some(Ast::Epsilon);
+    for &target in &targets {
+        if useful[target as usize] {
+            paths[target as usize][end] = Some(Ast::Epsilon);
+        }
+    }
+    for (source, symbol, to) in dfa.transitions() {
+        let (source, to) = (source as usize, to as usize);
+        if useful[source] && useful[to] {
+            paths[source][to] = union(paths[source][to].take(), Some(Ast::Atom(symbol)));
+        }
+    }
+
+    let mut remaining: Vec<usize> = (0..states).filter(|&state| useful[state]).collect();
+    while !remaining.is_empty() {
+        let rerouted = |k: usize| {
+            let incoming = (0..states + 2)
+                .filter(|&i| i != k && paths[i][k].is_some())
+                .count();
+            let outgoing = (0..states + 2)
+                .filter(|&j| j != k && paths[k][j].is_some())
+                .count();
+            incoming * outgoing
+        };
+        let position = (0..remaining.len())
+            .min_by_key(|&idx| rerouted(remaining[idx]))
+            .unwrap_or(0);
+        let k = remaining.remove(position);
+
+        let looped = star(paths[k][k].take());
+        let incoming: Vec<(usize, Ast)> = (0..states + 2)
+            .filter_map(|i| paths[i][k].take().map(|path| (i, path)))
+            .collect();
+        let outgoing: Vec<(usize, Ast)> = (0..states + 2)
+            .filter_map(|j| paths[k][j].take().map(|path| (j, path)))
+            .collect();
+        for (i, into) in &incoming {
+            for (j, out) in &outgoing {
+                let through = concat(concat(into.clone(), looped.clone()), out.clone());
+                paths[*i][*j] = union(paths[*i][*j].take(), Some(through));
+            }
+        }
+    }
+    paths[start][end].take()
+}
+
+/// Marks every state reachable from `seeds` by repeatedly following `next`.
+fn mark<I: Iterator<Item = StateId>>(
+    states: usize,
+    seeds: &[StateId],
+    next: impl Fn(StateId) -> I,
+) -> Vec<bool> {
+    let mut marked = vec![false; states];
+    let mut stack: Vec<StateId> = seeds.to_vec();
+    while let Some(state) = stack.pop() {
+        if std::mem::replace(&mut marked[state as usize], true) {
+            continue;
+        }
+        stack.extend(next(state).filter(|&to| !marked[to as usize]));
+    }
+    marked
+}
+
+/// Unites two languages, where `None` is the empty language.
+///
+/// Alternatives are flattened and deduplicated, and an `ε` alternative turns
+/// the rest optional.
+fn union(lhs: Option<Ast>, rhs: Option<Ast>) -> Option<Ast> {
+    let (lhs, rhs) = match (lhs, rhs) {
+        (None, other) | (other, None) => return other,
+        (Some(lhs), Some(rhs)) => (lhs, rhs),
+    };
+    let mut alternatives = Vec::new();
+    for ast in [lhs, rhs] {
+        flatten_alt(ast, &mut alternatives);
+    }
+    let nullable = alternatives.contains(&Ast::Epsilon);
+    alternatives.retain(|ast| *ast != Ast::Epsilon);
+
+    let combined = alternatives
+        .into_iter()
+        .reduce(|lhs, rhs| Ast::Alt(Box::new(lhs), Box::new(rhs)))
+        .map_or(
+            Ast::Epsilon,
+            |ast| if nullable { optional(ast) } else { ast },
+        );
+    Some(combined)
+}
+
+/// Appends the alternatives of `ast` to `out`, skipping ones already listed.
+fn flatten_alt(ast: Ast, out: &mut Vec<Ast>) {
+    match ast {
+        Ast::Alt(lhs, rhs) => {
+            flatten_alt(*lhs, out);
+            flatten_alt(*rhs, out);
+        }
+        Ast::Opt(inner) => {
+            flatten_alt(*inner, out);
+            flatten_alt(Ast::Epsilon, out);
+        }
+        ast if !out.contains(&ast) => out.push(ast),
+        _ => {}
+    }
+}
+
+/// Makes `ast` optional, unless it already accepts the empty string.
+fn optional(ast: Ast) -> Ast {
+    match ast {
+        Ast::Epsilon | Ast::Star(_) | Ast::Opt(_) => ast,
+        ast => Ast::Opt(Box::new(ast)),
+    }
+}
+
+/// Concatenates two expressions, dropping `ε` operands.
+fn concat(lhs: Ast, rhs: Ast) -> Ast {
+    match (lhs, rhs) {
+        (Ast::Epsilon, ast) | (ast, Ast::Epsilon) => ast,
+        (lhs, rhs) => Ast::Concat(Box::new(lhs), Box::new(rhs)),
+    }
+}
+
+/// Repeats a language any number of times, where `None` is the empty language.
+fn star(ast: Option<Ast>) -> Ast {
+    match ast {
+        None | Some(Ast::Epsilon) => Ast::Epsilon,
+        Some(Ast::Star(inner) | Ast::Opt(inner)) => star(Some(*inner)),
+        Some(ast) => Ast::Star(Box::new(ast)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::automaton::EpsilonGlyph;
+    use crate::core::equiv::hopcroft_karp;
+
+    /// Checks that `regex` describes the language of `expected`.
+    fn assert_describes(regex: Option<Ast>, expected: &Dfa) {
+        match regex {
+            Some(ast) => {
+                let pattern = ast.to_pattern(EpsilonGlyph::Epsilon);
+                let built = Dfa::from_pattern(&pattern).unwrap();
+                assert!(hopcroft_karp(&built, expected).is_equivalent(), "{pattern}");
+            }
+            None => assert_eq!(expected.shortest_accepted(), None),
+        }
+    }
+
+    #[test]
+    fn test_round_trips_patterns() {
+        for pattern in ["a", "ε", "(a+b)*abb", "a*b?c*", "(ab+ba)*", "a(b+c)*d+e"] {
+            let dfa = Dfa::from_pattern(pattern).unwrap();
+            assert_describes(dfa_to_regex(&dfa), &dfa);
+        }
+        assert_eq!(
+            dfa_to_regex(&Dfa::from_pattern("a*").unwrap()),
+            Some(Ast::build("a*").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_left_and_right_languages() {
+        let dfa = Dfa::from_pattern("(a+b)*abb").unwrap();
+        for state in dfa.states() {
+            let reaching = Dfa {
+                accepts: vec![state],
+                ..dfa.clone()
+            };
+            assert_describes(left_language(&dfa, state), &reaching);
+            let accepted = Dfa {
+                start: state,
+                ..dfa.clone()
+            };
+            assert_describes(right_language(&dfa, state), &accepted);
+        }
+    }
+
+    #[test]
+    fn test_dead_state_has_empty_right_language() {
+        let dfa = Dfa::from_pattern("ab").unwrap();
+        let dead = dfa.trans[dfa.start as usize][1];
+        assert_eq!(right_language(&dfa, dead), None);
+        assert!(left_language(&dfa, dead).is_some());
+    }
+}
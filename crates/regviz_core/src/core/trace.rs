@@ -0,0 +1,515 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::str::FromStr;
+
+use super::automaton::{Edge, EdgeLabel, StateId};
+use super::dfa::Dfa;
+use super::nfa::Nfa;
+use super::sim;
+use super::state_set::StateSet;
+use crate::errors::{SimError, TraceFormatError};
+
+/// One step of a simulation run: the frontier after consuming a prefix of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStep {
+    /// Index of the step (0 = before consuming any input).
+    pub index: usize,
+    /// Character consumed to reach this step (`None` for the initial step).
+    pub consumed: Option<char>,
+    /// Active states after the step, in ascending order.
+    pub active_states: Vec<StateId>,
+    /// Transitions taken to reach the step, ordered by origin, destination, then label.
+    pub traversed: Vec<Edge>,
+    /// Whether an active state is accepting.
+    pub accepted: bool,
+}
+
+impl TraceStep {
+    /// Creates a step, sorting its states and edges so exports are deterministic.
+    #[must_use]
+    pub fn new(
+        index: usize,
+        consumed: Option<char>,
+        active_states: impl IntoIterator<Item = StateId>,
+        traversed: impl IntoIterator<Item = Edge>,
+        accepted: bool,
+    ) -> Self {
+        let mut active_states: Vec<StateId> = active_states.into_iter().collect();
+        active_states.sort_unstable();
+        let mut traversed: Vec<Edge> = traversed.into_iter().collect();
+        traversed.sort_by_key(|edge| (edge.from, edge.to, label_key(edge.label)));
+        Self {
+            index,
+            consumed,
+            active_states,
+            traversed,
+            accepted,
+        }
+    }
+}
+
+/// Formats a [`Trace`] can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+    /// A JSON object with a `steps` array.
+    Json,
+    /// One CSV row per step, with a header row.
+    Csv,
+}
+
+impl TraceFormat {
+    /// File extension conventionally used for the format.
+    #[must_use]
+    pub fn extension(self) -> &'static str {
+        match self {
+            TraceFormat::Json => "json",
+            TraceFormat::Csv => "csv",
+        }
+    }
+}
+
+impl FromStr for TraceFormat {
+    type Err = TraceFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(TraceFormat::Json),
+            "csv" => Ok(TraceFormat::Csv),
+            _ => Err(TraceFormatError(s.to_string())),
+        }
+    }
+}
+
+/// Complete record of a simulation run, suitable for exporting.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Trace {
+    /// Steps from the initial frontier to the last symbol consumed.
+    pub steps: Vec<TraceStep>,
+    /// Display names for renamed or renumbered states. Unlisted states use their id.
+    pub labels: BTreeMap<StateId, String>,
+}
+
+impl Trace {
+    /// Attaches display names to states, used wherever the exports name a state.
+    ///
+    /// # Arguments
+    ///
+    /// - `labels` (`impl IntoIterator<Item = (StateId, String)>`) - Names keyed by state id.
+    ///
+    /// # Returns
+    ///
+    /// - `Trace` - The trace with the labels replaced.
+    #[must_use]
+    pub fn with_labels(mut self, labels: impl IntoIterator<Item = (StateId, String)>) -> Self {
+        self.labels = labels.into_iter().collect();
+        self
+    }
+
+    /// Returns the display name of `state`: its label, or its id if it has none.
+    #[must_use]
+    pub fn state_name(&self, state: StateId) -> String {
+        self.labels
+            .get(&state)
+            .cloned()
+            .unwrap_or_else(|| state.to_string())
+    }
+
+    /// Returns whether the run ended in an accepting frontier.
+    ///
+    /// Runs that stop early end on an empty frontier, so they are rejected.
+    #[must_use]
+    pub fn accepted(&self) -> bool {
+        self.steps.last().is_some_and(|step| step.accepted)
+    }
+
+    /// Renders the trace in the requested format.
+    #[must_use]
+    pub fn export(&self, format: TraceFormat) -> String {
+        match format {
+            TraceFormat::Json => self.to_json(),
+            TraceFormat::Csv => self.to_csv(),
+        }
+    }
+
+    /// Renders the trace as JSON.
+    ///
+    /// Each step is an object with `index`, `consumed` (a string or `null`),
+    /// `active_states`, `traversed_edges` (objects with `from`, `to` and
+    /// `label`, where ε transitions are labelled `"ε"`) and `accepted`.
+    /// States are written as ids; when the trace has labels, a `labels` object
+    /// maps each labelled id to its name.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{\"steps\":[");
+        for (i, step) in self.steps.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            let consumed = step
+                .consumed
+                .map_or_else(|| "null".to_string(), |c| json_string(&c.to_string()));
+            let states = step
+                .active_states
+                .iter()
+                .map(StateId::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+            let edges = step
+                .traversed
+                .iter()
+                .map(|edge| {
+                    format!(
+                        "{{\"from\":{},\"to\":{},\"label\":{}}}",
+                        edge.from,
+                        edge.to,
+                        json_string(&edge.label.to_string())
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            let _ = write!(
+                out,
+                "{{\"index\":{},\"consumed\":{consumed},\"active_states\":[{states}],\"traversed_edges\":[{edges}],\"accepted\":{}}}",
+                step.index, step.accepted
+            );
+        }
+        out.push(']');
+        if !self.labels.is_empty() {
+            let labels = self
+                .labels
+                .iter()
+                .map(|(state, name)| {
+                    format!("{}:{}", json_string(&state.to_string()), json_string(name))
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            let _ = write!(out, ",\"labels\":{{{labels}}}");
+        }
+        out.push('}');
+        out
+    }
+
+    /// Renders the trace as CSV with the header
+    /// `step,consumed,active_states,traversed_edges,accepted`.
+    ///
+    /// States are space-separated and edges are written as `from-label->to`,
+    /// naming states by their label when they have one.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("step,consumed,active_states,traversed_edges,accepted\n");
+        for step in &self.steps {
+            let consumed = step.consumed.map(String::from).unwrap_or_default();
+            let states = step
+                .active_states
+                .iter()
+                .map(|&state| self.state_name(state))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let edges = step
+                .traversed
+                .iter()
+                .map(|edge| {
+                    format!(
+                        "{}-{}->{}",
+                        self.state_name(edge.from),
+                        edge.label,
+                        self.state_name(edge.to)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            let _ = writeln!(
+                out,
+                "{},{},{},{},{}",
+                step.index,
+                csv_field(&consumed),
+                csv_field(&states),
+                csv_field(&edges),
+                step.accepted
+            );
+        }
+        out
+    }
+}
+
+/// Records the powerset simulation of `nfa` on `input`, including ε transitions.
+///
+/// # Arguments
+///
+/// - `nfa` (`&Nfa`) - The automaton to simulate.
+/// - `alphabet` (`&[char]`) - The symbols the input may use.
+/// - `input` (`&str`) - The word to read.
+///
+/// # Returns
+///
+/// - `Result<Trace, SimError>` - One step per consumed symbol after the
+///   initial ε-closure, stopping early once the frontier empties; or why
+///   `input` cannot be simulated.
+pub fn nfa_trace(nfa: &Nfa, alphabet: &[char], input: &str) -> Result<Trace, SimError> {
+    if nfa.states.is_empty() {
+        return Err(SimError::EmptyAutomaton);
+    }
+    sim::check_input(alphabet, input)?;
+    let symbols: Vec<char> = input.chars().collect();
+    let mut traversed = Vec::new();
+    let mut current = StateSet::new(nfa.states.len());
+    current.insert(nfa.start);
+    close_recording(nfa, &mut current, &mut traversed);
+
+    let accepted = nfa.accepts.iter().any(|&state| current.contains(state));
+    let mut steps = vec![TraceStep::new(0, None, current.iter(), traversed, accepted)];
+
+    for (idx, &symbol) in symbols.iter().enumerate() {
+        let mut traversed: Vec<Edge> = current
+            .iter()
+            .flat_map(|from| {
+                nfa.transitions(from)
+                    .iter()
+                    .filter(|tr| tr.label == EdgeLabel::Sym(symbol))
+                    .map(move |tr| Edge {
+                        from,
+                        to: tr.to,
+                        label: tr.label,
+                    })
+            })
+            .collect();
+        current = sim::move_on(&current, symbol, nfa);
+        close_recording(nfa, &mut current, &mut traversed);
+
+        let accepted = nfa.accepts.iter().any(|&state| current.contains(state));
+        steps.push(TraceStep::new(
+            idx + 1,
+            Some(symbol),
+            current.iter(),
+            traversed,
+            accepted,
+        ));
+
+        if current.is_empty() {
+            break;
+        }
+    }
+
+    Ok(Trace {
+        steps,
+        labels: BTreeMap::new(),
+    })
+}
+
+/// Records the run of `dfa` on `input`.
+///
+/// # Arguments
+///
+/// - `dfa` (`&Dfa`) - The automaton to simulate; the input may use its alphabet.
+/// - `input` (`&str`) - The word to read.
+///
+/// # Returns
+///
+/// - `Result<Trace, SimError>` - One step per consumed symbol, where a
+///   transition missing from a partial DFA ends the run with an empty
+///   frontier; or why `input` cannot be simulated.
+pub fn dfa_trace(dfa: &Dfa, input: &str) -> Result<Trace, SimError> {
+    if dfa.states.is_empty() {
+        return Err(SimError::EmptyAutomaton);
+    }
+    sim::check_input(&dfa.alphabet, input)?;
+    let mut state = dfa.start;
+    let mut steps = vec![TraceStep::new(
+        0,
+        None,
+        [state],
+        [],
+        dfa.accepts.contains(&state),
+    )];
+
+    for (idx, symbol) in input.chars().enumerate() {
+        let Some(next) = dfa.step(state, symbol) else {
+            steps.push(TraceStep::new(idx + 1, Some(symbol), [], [], false));
+            break;
+        };
+        let edge = Edge {
+            from: state,
+            to: next,
+            label: EdgeLabel::Sym(symbol),
+        };
+        state = next;
+        steps.push(TraceStep::new(
+            idx + 1,
+            Some(symbol),
+            [state],
+            [edge],
+            dfa.accepts.contains(&state),
+        ));
+    }
+
+    Ok(Trace {
+        steps,
+        labels: BTreeMap::new(),
+    })
+}
+
+/// Extends `states` to its ε-closure, recording every ε transition that adds a state.
+fn close_recording(nfa: &Nfa, states: &mut StateSet, traversed: &mut Vec<Edge>) {
+    let mut stack: Vec<StateId> = states.iter().collect();
+    while let Some(from) = stack.pop() {
+        for tr in nfa.transitions(from) {
+            if tr.label == EdgeLabel::Eps && states.insert(tr.to) {
+                traversed.push(Edge {
+                    from,
+                    to: tr.to,
+                    label: EdgeLabel::Eps,
+                });
+                stack.push(tr.to);
+            }
+        }
+    }
+}
+
+/// Orders ε before every symbol, then symbols by character.
+fn label_key(label: EdgeLabel) -> (u8, char) {
+    match label {
+        EdgeLabel::Eps => (0, '\0'),
+        EdgeLabel::Sym(c) => (1, c),
+    }
+}
+
+/// Quotes and escapes `s` as a JSON string literal.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Quotes a CSV field when it contains a delimiter, quote or line break.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{dfa, parser};
+
+    fn nfa(pattern: &str) -> Nfa {
+        Nfa::build(&parser::Ast::build(pattern).unwrap())
+    }
+
+    #[test]
+    fn test_nfa_trace_matches_simulation() {
+        let nfa = nfa("(a+b)*abb");
+        let alphabet = nfa.alphabet();
+        for input in ["abb", "babb", "ab", "", "ba"] {
+            let trace = nfa_trace(&nfa, &alphabet, input).unwrap();
+            assert_eq!(trace.accepted(), sim::nfa_accepts(&nfa, input));
+            assert_eq!(trace.steps[0].consumed, None);
+        }
+
+        let trace = nfa_trace(&nfa, &alphabet, "abb").unwrap();
+        assert_eq!(trace.steps.len(), 4);
+        assert!(
+            trace.steps[1]
+                .traversed
+                .iter()
+                .any(|edge| edge.label == EdgeLabel::Sym('a'))
+        );
+        assert!(
+            trace.steps[1]
+                .traversed
+                .iter()
+                .any(|edge| edge.label == EdgeLabel::Eps)
+        );
+    }
+
+    #[test]
+    fn test_traces_reject_unknown_symbols() {
+        let nfa = nfa("ab");
+        let dfa = dfa::determinize(&nfa);
+        let unknown = Err(SimError::UnknownSymbol { symbol: 'x', at: 1 });
+        assert_eq!(dfa_trace(&dfa, "axb"), unknown);
+        assert_eq!(nfa_trace(&nfa, &nfa.alphabet(), "axb"), unknown);
+        assert!(dfa_trace(&dfa, "ab").unwrap().accepted());
+    }
+
+    #[test]
+    fn test_dfa_trace_stops_on_missing_transition() {
+        // Accepts exactly "a"; state 1 has no transitions.
+        let dfa = Dfa {
+            states: vec![0, 1],
+            start: 0,
+            accepts: vec![1],
+            trans: vec![vec![1], vec![]],
+            alphabet: vec!['a'],
+        };
+        let trace = dfa_trace(&dfa, "aaa").unwrap();
+        assert_eq!(trace.steps.len(), 3);
+        assert!(trace.steps[2].active_states.is_empty());
+        assert!(!trace.accepted());
+    }
+
+    #[test]
+    fn test_exports() {
+        let dfa = dfa::determinize(&nfa("a"));
+        let trace = dfa_trace(&dfa, "a").unwrap();
+        let (start, next) = (dfa.start, trace.steps[1].active_states[0]);
+
+        assert_eq!(
+            trace.to_json(),
+            format!(
+                "{{\"steps\":[{{\"index\":0,\"consumed\":null,\"active_states\":[{start}],\"traversed_edges\":[],\"accepted\":false}},{{\"index\":1,\"consumed\":\"a\",\"active_states\":[{next}],\"traversed_edges\":[{{\"from\":{start},\"to\":{next},\"label\":\"a\"}}],\"accepted\":true}}]}}"
+            )
+        );
+        assert_eq!(
+            trace.to_csv(),
+            format!(
+                "step,consumed,active_states,traversed_edges,accepted\n0,,{start},,false\n1,a,{next},{start}-a->{next},true\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_exports_use_labels() {
+        let dfa = dfa::determinize(&nfa("a"));
+        let trace = dfa_trace(&dfa, "a").unwrap();
+        let (start, next) = (dfa.start, trace.steps[1].active_states[0]);
+        let trace = trace.with_labels([(start, "q0".to_string())]);
+
+        assert!(
+            trace
+                .to_json()
+                .ends_with(&format!("],\"labels\":{{\"{start}\":\"q0\"}}}}"))
+        );
+        assert_eq!(
+            trace.to_csv(),
+            format!(
+                "step,consumed,active_states,traversed_edges,accepted\n0,,q0,,false\n1,a,{next},q0-a->{next},true\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(json_string("a\"b\\"), "\"a\\\"b\\\\\"");
+        assert_eq!(csv_field("1 2"), "1 2");
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+        assert_eq!("CSV".parse::<TraceFormat>(), Ok(TraceFormat::Csv));
+        assert!("xml".parse::<TraceFormat>().is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use thiserror::Error;
 
+use crate::core::automaton::StateId;
 use crate::core::lexer::{OpToken, Token};
 
 /// Error emitted by the lexer with a message and position.
@@ -17,7 +18,7 @@ pub enum LexErrorKind {
     #[error("dangling escape character")]
     DanglingEscape,
     #[error(
-        "invalid character '{0}'. Only alphanumeric characters and reserved characters ('\\e', '(', ')', '+', '*', '.') are allowed"
+        "invalid character '{0}'. Only alphanumeric characters and reserved characters ('\\e', '\\0', 'ε', 'λ', '(', ')', '+', '*', '.') are allowed"
     )]
     InvalidCharacter(char),
 }
@@ -53,3 +54,81 @@ pub enum BuildError {
     #[error("[parse error] {0}")]
     Parse(#[from] ParseError),
 }
+
+impl BuildError {
+    /// Returns the char index in the input where the error occurred, which is
+    /// the input's length when it ended too early.
+    #[must_use]
+    pub fn at(&self) -> usize {
+        match self {
+            BuildError::Lex(error) => error.at,
+            BuildError::Parse(error) => error.at,
+        }
+    }
+}
+
+/// Errors raised by operations on an existing [`Dfa`](crate::core::dfa::Dfa).
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum DfaError {
+    #[error("symbol '{0}' is not in the alphabet")]
+    UnknownSymbol(char),
+    #[error("state {0} does not exist")]
+    UnknownState(StateId),
+    #[error("the state list must be 0..{rows} to match the transition table")]
+    MismatchedStates { rows: usize },
+    #[error("state {state} has {found} transitions but the alphabet has {expected} symbols")]
+    IncompleteRow {
+        state: StateId,
+        expected: usize,
+        found: usize,
+    },
+    #[error("state {state} has no transition on '{symbol}'")]
+    MissingTransition { state: StateId, symbol: char },
+    #[error("state {state} has more than one transition on '{symbol}'")]
+    ConflictingTransition { state: StateId, symbol: char },
+    #[error("symbol '{0}' appears more than once in the alphabet")]
+    DuplicateSymbol(char),
+}
+
+/// Errors raised while assembling an [`Nfa`](crate::core::nfa::Nfa) by hand.
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum NfaError {
+    #[error("state {0} does not exist")]
+    UnknownState(StateId),
+}
+
+/// Errors raised before simulating an automaton on an input.
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum SimError {
+    #[error("symbol '{symbol}' at index {at} is not in the alphabet")]
+    UnknownSymbol { symbol: char, at: usize },
+    #[error("the automaton has no states")]
+    EmptyAutomaton,
+}
+
+/// Error returned when a pattern uses symbols missing from a declared alphabet.
+#[derive(Debug, Error, Clone, PartialEq)]
+#[error("the pattern uses {} outside the declared alphabet", quote_symbols(.undeclared))]
+pub struct AlphabetError {
+    /// Symbols of the pattern that were not declared, in sorted order.
+    pub undeclared: Vec<char>,
+}
+
+/// Formats symbols as a comma-separated list of quoted characters.
+fn quote_symbols(symbols: &[char]) -> String {
+    symbols
+        .iter()
+        .map(|c| format!("'{c}'"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Error returned when parsing an unknown trace export format.
+#[derive(Debug, Error, Clone, PartialEq)]
+#[error("unknown trace format '{0}', expected 'json' or 'csv'")]
+pub struct TraceFormatError(pub String);
+
+/// Error returned when parsing an unknown epsilon notation.
+#[derive(Debug, Error, Clone, PartialEq)]
+#[error("unknown epsilon glyph '{0}', expected 'epsilon' or 'lambda'")]
+pub struct EpsilonGlyphError(pub String);
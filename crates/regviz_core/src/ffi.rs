@@ -0,0 +1,359 @@
+//! C-compatible bindings for running RegViz-built DFAs from other languages.
+//!
+//! Every automaton crosses the boundary as an opaque [`RegvizDfa`] handle.
+//! Handles are created by one of the `regviz_dfa_from_*` constructors, driven
+//! with [`regviz_dfa_run`], and must be released with [`regviz_dfa_free`].
+//!
+//! Input is matched one byte at a time, so only ASCII alphabet symbols are
+//! reachable through this API. [`regviz_dfa_from_table`] refuses any other
+//! symbol; in a DFA loaded from JSON, transitions on them are kept but can
+//! never fire, and a non-ASCII input byte always rejects.
+//!
+//! The matching C header lives in `include/regviz.h` and is regenerated with:
+//!
+//! ```text
+//! cbindgen --config cbindgen.toml --output include/regviz.h
+//! ```
+
+use std::ffi::{CStr, c_char, c_int};
+use std::ptr;
+use std::slice;
+
+use crate::core::automaton::StateId;
+use crate::core::dfa::Dfa;
+
+/// Returned by [`regviz_dfa_run`] when the input is accepted.
+pub const REGVIZ_ACCEPT: c_int = 1;
+/// Returned by [`regviz_dfa_run`] when the input is rejected.
+pub const REGVIZ_REJECT: c_int = 0;
+/// Returned by [`regviz_dfa_run`] when called with invalid arguments.
+pub const REGVIZ_INVALID: c_int = -1;
+
+/// Opaque handle to a validated DFA with a byte-indexed symbol lookup table.
+pub struct RegvizDfa {
+    /// The underlying automaton.
+    dfa: Dfa,
+    /// Maps each ASCII input byte to its alphabet column, if the byte is a symbol.
+    columns: [Option<usize>; 256],
+}
+
+impl RegvizDfa {
+    /// Validates `dfa` and prepares it for byte-oriented simulation.
+    ///
+    /// # Arguments
+    ///
+    /// - `dfa` (`Dfa`) - The automaton to wrap.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Self>` - The handle, or `None` if the transition table is malformed.
+    fn new(dfa: Dfa) -> Option<Self> {
+        dfa.validate().ok()?;
+
+        let mut columns = [None; 256];
+        for (idx, symbol) in dfa.alphabet.iter().enumerate() {
+            if symbol.is_ascii() {
+                columns[*symbol as usize] = Some(idx);
+            }
+        }
+
+        Some(Self { dfa, columns })
+    }
+
+    /// Runs the automaton over `input`, treating each byte as one symbol.
+    fn accepts(&self, input: &[u8]) -> bool {
+        let mut state = self.dfa.start;
+        for byte in input {
+            let Some(idx) = self.columns[*byte as usize] else {
+                return false;
+            };
+            state = self.dfa.trans[state as usize][idx];
+        }
+        self.dfa.accepts.contains(&state)
+    }
+
+    /// Moves the handle onto the heap and hands ownership to the caller.
+    fn into_raw(self) -> *mut RegvizDfa {
+        Box::into_raw(Box::new(self))
+    }
+}
+
+/// Reads a NUL-terminated UTF-8 string, returning `None` for null or invalid input.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a valid NUL-terminated string.
+unsafe fn read_str<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        return None;
+    }
+    // SAFETY: the caller guarantees `ptr` is a valid C string.
+    unsafe { CStr::from_ptr(ptr) }.to_str().ok()
+}
+
+/// Borrows `len` elements starting at `ptr`, returning `None` if `ptr` is null
+/// and `len` is not zero.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to at least `len` initialized elements.
+unsafe fn read_slice<'a, T>(ptr: *const T, len: usize) -> Option<&'a [T]> {
+    match (ptr.is_null(), len) {
+        (_, 0) => Some(&[]),
+        (true, _) => None,
+        // SAFETY: the caller guarantees `ptr` covers `len` elements.
+        (false, _) => Some(unsafe { slice::from_raw_parts(ptr, len) }),
+    }
+}
+
+/// Compiles a regular expression into a minimized DFA.
+///
+/// Returns null if `pattern` is null, not valid UTF-8, or fails to parse.
+///
+/// # Safety
+///
+/// `pattern` must be null or point to a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn regviz_dfa_from_pattern(pattern: *const c_char) -> *mut RegvizDfa {
+    // SAFETY: forwarded from the caller's contract.
+    let Some(pattern) = (unsafe { read_str(pattern) }) else {
+        return ptr::null_mut();
+    };
+    let Ok(dfa) = Dfa::from_pattern(pattern) else {
+        return ptr::null_mut();
+    };
+    RegvizDfa::new(dfa).map_or(ptr::null_mut(), RegvizDfa::into_raw)
+}
+
+/// Loads a DFA from its JSON representation.
+///
+/// The JSON object mirrors [`Dfa`]: `states`, `start`, `accepts`, `trans`
+/// (one row per state, one column per symbol) and `alphabet` (single-character
+/// strings). Returns null if the document is malformed.
+///
+/// # Safety
+///
+/// `json` must be null or point to a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn regviz_dfa_from_json(json: *const c_char) -> *mut RegvizDfa {
+    // SAFETY: forwarded from the caller's contract.
+    let Some(json) = (unsafe { read_str(json) }) else {
+        return ptr::null_mut();
+    };
+    let Ok(dfa) = serde_json::from_str::<Dfa>(json) else {
+        return ptr::null_mut();
+    };
+    RegvizDfa::new(dfa).map_or(ptr::null_mut(), RegvizDfa::into_raw)
+}
+
+/// Builds a DFA from a dense transition table.
+///
+/// States are numbered `0..num_states`. `alphabet` holds `num_symbols` ASCII
+/// bytes, and `table` holds `num_states * num_symbols` target states in
+/// row-major order. Returns null if the table is inconsistent, a symbol is not
+/// ASCII, or a pointer is null while its array is non-empty.
+///
+/// # Safety
+///
+/// `alphabet`, `table` and `accepts` must point to arrays of at least
+/// `num_symbols`, `num_states * num_symbols` and `num_accepts` elements, and
+/// may only be null when that count is zero.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn regviz_dfa_from_table(
+    num_states: usize,
+    num_symbols: usize,
+    alphabet: *const u8,
+    table: *const u32,
+    start: u32,
+    accepts: *const u32,
+    num_accepts: usize,
+) -> *mut RegvizDfa {
+    let Some(cells) = num_states.checked_mul(num_symbols) else {
+        return ptr::null_mut();
+    };
+    // SAFETY: the caller guarantees each pointer covers the advertised length.
+    let (Some(alphabet), Some(table), Some(accepts)) = (unsafe {
+        (
+            read_slice(alphabet, num_symbols),
+            read_slice(table, cells),
+            read_slice(accepts, num_accepts),
+        )
+    }) else {
+        return ptr::null_mut();
+    };
+    // Input is matched byte by byte, so other symbols could never be read.
+    if !alphabet.is_ascii() {
+        return ptr::null_mut();
+    }
+
+    let trans = if num_symbols == 0 {
+        vec![Vec::new(); num_states]
+    } else {
+        table.chunks(num_symbols).map(<[u32]>::to_vec).collect()
+    };
+    let dfa = Dfa {
+        states: (0..num_states as StateId).collect(),
+        start,
+        accepts: accepts.to_vec(),
+        trans,
+        alphabet: alphabet.iter().map(|&b| char::from(b)).collect(),
+    };
+    RegvizDfa::new(dfa).map_or(ptr::null_mut(), RegvizDfa::into_raw)
+}
+
+/// Runs the DFA over `len` bytes of `input`.
+///
+/// Each byte is one symbol, so only ASCII symbols can match; a byte of `0x80`
+/// or above, including any part of a multi-byte UTF-8 sequence, rejects.
+/// Returns [`REGVIZ_ACCEPT`], [`REGVIZ_REJECT`], or [`REGVIZ_INVALID`] if
+/// `dfa` is null or `input` is null with a non-zero length.
+///
+/// # Safety
+///
+/// `dfa` must be null or a live handle, and `input` must cover `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn regviz_dfa_run(
+    dfa: *const RegvizDfa,
+    input: *const u8,
+    len: usize,
+) -> c_int {
+    // SAFETY: the caller guarantees `dfa` is null or a live handle.
+    let Some(dfa) = (unsafe { dfa.as_ref() }) else {
+        return REGVIZ_INVALID;
+    };
+    // SAFETY: the caller guarantees `input` covers `len` bytes.
+    let Some(input) = (unsafe { read_slice(input, len) }) else {
+        return REGVIZ_INVALID;
+    };
+
+    if dfa.accepts(input) {
+        REGVIZ_ACCEPT
+    } else {
+        REGVIZ_REJECT
+    }
+}
+
+/// Releases a handle created by one of the `regviz_dfa_from_*` functions.
+///
+/// Passing null is a no-op.
+///
+/// # Safety
+///
+/// `dfa` must be null or a handle that has not already been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn regviz_dfa_free(dfa: *mut RegvizDfa) {
+    if !dfa.is_null() {
+        // SAFETY: the handle was produced by `Box::into_raw` in this module.
+        drop(unsafe { Box::from_raw(dfa) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(dfa: *const RegvizDfa, input: &str) -> c_int {
+        unsafe { regviz_dfa_run(dfa, input.as_ptr(), input.len()) }
+    }
+
+    #[test]
+    fn test_from_pattern_runs_bytes() {
+        let dfa = unsafe { regviz_dfa_from_pattern(c"(a+b)*abb".as_ptr()) };
+        assert!(!dfa.is_null());
+        assert_eq!(run(dfa, "aabb"), REGVIZ_ACCEPT);
+        assert_eq!(run(dfa, "abba"), REGVIZ_REJECT);
+        assert_eq!(run(dfa, "abc"), REGVIZ_REJECT);
+        unsafe { regviz_dfa_free(dfa) };
+    }
+
+    #[test]
+    fn test_from_table_and_json_agree() {
+        // Even number of 'a's over {a, b}.
+        let alphabet = *b"ab";
+        let table = [1, 0, 0, 1];
+        let accepts = [0];
+        let from_table = unsafe {
+            regviz_dfa_from_table(
+                2,
+                2,
+                alphabet.as_ptr(),
+                table.as_ptr(),
+                0,
+                accepts.as_ptr(),
+                1,
+            )
+        };
+        let json = cr#"{"states":[0,1],"start":0,"accepts":[0],"trans":[[1,0],[0,1]],"alphabet":["a","b"]}"#;
+        let from_json = unsafe { regviz_dfa_from_json(json.as_ptr()) };
+        assert!(!from_table.is_null() && !from_json.is_null());
+
+        for input in ["", "a", "aa", "abab", "bab"] {
+            assert_eq!(run(from_table, input), run(from_json, input));
+        }
+        assert_eq!(run(from_table, "abab"), REGVIZ_ACCEPT);
+        unsafe {
+            regviz_dfa_free(from_table);
+            regviz_dfa_free(from_json);
+        }
+    }
+
+    #[test]
+    fn test_non_ascii_symbols_never_match() {
+        // Accepts the single symbol 'é'; neither its UTF-8 nor its Latin-1
+        // encoding may reach it.
+        let json =
+            cr#"{"states":[0,1],"start":0,"accepts":[1],"trans":[[1],[1]],"alphabet":["é"]}"#;
+        let dfa = unsafe { regviz_dfa_from_json(json.as_ptr()) };
+        assert!(!dfa.is_null());
+        assert_eq!(run(dfa, "é"), REGVIZ_REJECT);
+        let latin1 = [0xE9];
+        assert_eq!(
+            unsafe { regviz_dfa_run(dfa, latin1.as_ptr(), latin1.len()) },
+            REGVIZ_REJECT
+        );
+        unsafe { regviz_dfa_free(dfa) };
+    }
+
+    #[test]
+    fn test_from_table_checks_pointers_and_symbols() {
+        // No accepting states: the empty language over {a}.
+        let table = [0];
+        let empty = unsafe {
+            regviz_dfa_from_table(1, 1, b"a".as_ptr(), table.as_ptr(), 0, ptr::null(), 0)
+        };
+        assert!(!empty.is_null());
+        assert_eq!(run(empty, "a"), REGVIZ_REJECT);
+        unsafe { regviz_dfa_free(empty) };
+
+        let accepts = [0];
+        let missing_accepts = unsafe {
+            regviz_dfa_from_table(1, 1, b"a".as_ptr(), table.as_ptr(), 0, ptr::null(), 1)
+        };
+        assert!(missing_accepts.is_null());
+        let latin1 = unsafe {
+            regviz_dfa_from_table(
+                1,
+                1,
+                [0xE9].as_ptr(),
+                table.as_ptr(),
+                0,
+                accepts.as_ptr(),
+                1,
+            )
+        };
+        assert!(latin1.is_null());
+    }
+
+    #[test]
+    fn test_invalid_inputs_are_rejected() {
+        let table = [5];
+        let accepts = [0];
+        let out_of_range = unsafe {
+            regviz_dfa_from_table(1, 1, b"a".as_ptr(), table.as_ptr(), 0, accepts.as_ptr(), 1)
+        };
+        assert!(out_of_range.is_null());
+        assert!(unsafe { regviz_dfa_from_pattern(c"a+(".as_ptr()) }.is_null());
+        assert!(unsafe { regviz_dfa_from_json(c"{}".as_ptr()) }.is_null());
+        assert_eq!(run(ptr::null(), "a"), REGVIZ_INVALID);
+    }
+}
@@ -1,2 +1,7 @@
+pub mod bench;
 pub mod core;
 pub mod errors;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+pub use bench::bench_corpus;
@@ -1,4 +1,4 @@
-use regviz_core::core::{nfa, parser};
+use regviz_core::core::{BuildArtifacts, nfa, parser};
 
 #[test]
 fn test_nfa_simple() {
@@ -18,3 +18,26 @@ fn test_nfa_complex() {
     assert!(!nfa.edges.is_empty());
     assert!(!nfa.accepts.is_empty());
 }
+
+#[test]
+fn test_nfa_composed_artifacts() {
+    let a = nfa::Nfa::build(&parser::Ast::build("a").unwrap());
+    let b = nfa::Nfa::build(&parser::Ast::build("b").unwrap());
+    let artifacts = BuildArtifacts::from_nfa(a.union(&b).star());
+    assert!(artifacts.ast.is_none());
+    assert_eq!(artifacts.alphabet, vec!['a', 'b']);
+}
+
+#[test]
+fn test_declared_alphabet_must_cover_pattern() {
+    let ast = parser::Ast::build("ab*").unwrap();
+    let nfa = nfa::Nfa::build(&ast);
+    let mut artifacts = BuildArtifacts::new(ast, nfa, vec!['a', 'b']);
+
+    let err = artifacts.declare_alphabet(&['a', 'c']).unwrap_err();
+    assert_eq!(err.undeclared, vec!['b']);
+    assert_eq!(artifacts.alphabet, vec!['a', 'b']);
+
+    artifacts.declare_alphabet(&['c', 'b', 'a']).unwrap();
+    assert_eq!(artifacts.alphabet, vec!['a', 'b', 'c']);
+}
@@ -33,3 +33,13 @@ fn test_parser_complex() {
         _ => panic!("Expected Concat or Star at root"),
     }
 }
+
+#[test]
+fn test_build_error_position() {
+    // Unclosed group: the parser wanted ')' where the input ended.
+    assert_eq!(Ast::build("(ab").unwrap_err().at(), 3);
+    // Invalid character: reported where it appears.
+    assert_eq!(Ast::build("a$b").unwrap_err().at(), 1);
+    // Stray ')': reported at the parenthesis.
+    assert_eq!(Ast::build("ab)").unwrap_err().at(), 2);
+}
@@ -1,4 +1,5 @@
-use regviz_core::core::{dfa, min, nfa, parser, sim};
+use regviz_core::core::{analysis, dfa, min, nfa, parser, sim, trace};
+use regviz_core::errors::SimError;
 
 #[test]
 fn test_simulate_nfa_accept() {
@@ -49,3 +50,125 @@ fn test_simulate_dfa_complex() {
     assert!(sim::simulate_dfa(&min_dfa, "aabb"));
     assert!(!sim::simulate_dfa(&min_dfa, "ab"));
 }
+
+#[test]
+fn test_accepting_paths() {
+    let ast = parser::Ast::build("(a+b)*abb").unwrap();
+    let nfa = nfa::Nfa::build(&ast);
+    let paths = sim::accepting_paths(&nfa, "babb", 10);
+    assert_eq!(paths.len(), 1);
+
+    let path = &paths[0];
+    assert_eq!(path.first().unwrap().from, nfa.start);
+    assert!(nfa.accepts.contains(&path.last().unwrap().to));
+    assert!(path.windows(2).all(|pair| pair[0].to == pair[1].from));
+    assert_eq!(analysis::spell(path), "babb");
+
+    assert!(sim::accepting_paths(&nfa, "bab", 10).is_empty());
+}
+
+#[test]
+fn test_accepting_paths_ambiguous_limit() {
+    // Each 'a' can be read by either branch of the alternation.
+    let ast = parser::Ast::build("(a+a)*").unwrap();
+    let nfa = nfa::Nfa::build(&ast);
+    assert_eq!(sim::accepting_paths(&nfa, "aaa", 100).len(), 8);
+    assert_eq!(sim::accepting_paths(&nfa, "aaa", 3).len(), 3);
+    assert_eq!(sim::accepting_paths(&nfa, "", 100).len(), 1);
+}
+
+#[test]
+fn test_diagnose_rejection() {
+    let ast = parser::Ast::build("ab(c+d)").unwrap();
+    let nfa = nfa::Nfa::build(&ast);
+    assert!(sim::diagnose(&nfa, "abc").is_none());
+
+    let wrong_symbol = sim::diagnose(&nfa, "abx").unwrap();
+    assert_eq!(wrong_symbol.matched_prefix, "ab");
+    assert_eq!(wrong_symbol.expected, ['c', 'd']);
+    assert_eq!(wrong_symbol.found, Some('x'));
+
+    let too_short = sim::diagnose(&nfa, "a").unwrap();
+    assert_eq!(too_short.matched_prefix, "a");
+    assert_eq!(too_short.expected, ['b']);
+    assert_eq!(too_short.found, None);
+
+    let too_long = sim::diagnose(&nfa, "abcd").unwrap();
+    assert_eq!(too_long.matched_prefix, "abc");
+    assert!(too_long.expected.is_empty());
+    assert_eq!(too_long.found, Some('d'));
+}
+
+#[test]
+fn test_engines_agree_on_every_prefix() {
+    for pattern in ["(a+b)*abb", "a*b*", "(ab+ba)*", "a(b+\\e)c*"] {
+        let ast = parser::Ast::build(pattern).unwrap();
+        let nfa = nfa::Nfa::build(&ast);
+        let dfa = dfa::determinize(&nfa);
+        let min_dfa = min::minimize(&dfa);
+
+        for input in ["", "a", "abb", "abab", "aabbc", "bca"] {
+            let runs = [
+                trace::nfa_trace(&nfa, &nfa.alphabet(), input),
+                trace::dfa_trace(&dfa, input),
+                trace::dfa_trace(&min_dfa, input),
+            ];
+            // Symbols outside the alphabet are rejected the same way by every engine.
+            if runs[0].is_err() {
+                assert!(
+                    runs.iter().all(|run| *run == runs[0]),
+                    "{pattern} on {input:?}"
+                );
+                continue;
+            }
+            let runs = runs.map(Result::unwrap);
+            for index in 0..=input.chars().count() {
+                let accepted = runs
+                    .each_ref()
+                    .map(|run| run.steps.get(index).is_some_and(|s| s.accepted));
+                assert!(
+                    accepted.iter().all(|&a| a == accepted[0]),
+                    "{pattern} disagrees on step {index} of {input:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_simulate_streamed_input_matches_str() {
+    let nfa = nfa::Nfa::build(&parser::Ast::build("(a+b)*abb").unwrap());
+    let dfa = dfa::determinize(&nfa);
+    let closures = nfa.epsilon_closures();
+    for input in ["", "abb", "aabb", "abab", "abbc"] {
+        let expected = sim::nfa_accepts(&nfa, input);
+        assert_eq!(sim::nfa_accepts_iter(&nfa, input.chars()), expected);
+        assert_eq!(
+            sim::nfa_accepts_with_closures(&nfa, &closures, input.chars()),
+            expected
+        );
+        assert_eq!(sim::simulate_dfa_iter(&dfa, input.chars()), expected);
+    }
+}
+
+#[test]
+fn test_simulate_streamed_input_is_lazy() {
+    let nfa = nfa::Nfa::build(&parser::Ast::build("a*").unwrap());
+    let dfa = dfa::determinize(&nfa);
+    // An endless stream is abandoned once no state can accept anymore.
+    let endless = || "aab".chars().chain(std::iter::repeat('a'));
+    assert!(!sim::nfa_accepts_iter(&nfa, endless()));
+    assert!(!sim::simulate_dfa_iter(&dfa, endless()));
+}
+
+#[test]
+fn test_check_input_reports_first_unknown_symbol() {
+    let nfa = nfa::Nfa::build(&parser::Ast::build("(a+b)*").unwrap());
+    let alphabet = nfa.alphabet();
+    assert_eq!(sim::check_input(&alphabet, ""), Ok(()));
+    assert_eq!(sim::check_input(&alphabet, "abba"), Ok(()));
+    assert_eq!(
+        sim::check_input(&alphabet, "abxcx"),
+        Err(SimError::UnknownSymbol { symbol: 'x', at: 2 })
+    );
+}
@@ -9,6 +9,7 @@
 /// Each AST node (Char, Concat, Alt, Star, Plus, Opt) becomes a `GraphNode` with:
 /// - A unique numeric ID
 /// - A label describing the operator or character
+/// - A [`NodeKind`](crate::NodeKind) giving each operator its own shape and fill
 /// - No special start/accept state markers (ASTs don't have those)
 ///
 /// # Edge Representation
@@ -29,9 +30,10 @@
 ///            Node 1: Char(a)
 ///            Node 2: Char(b)
 /// ```
+use regviz_core::core::automaton::EpsilonGlyph;
 use regviz_core::core::parser::Ast;
 
-use super::{Graph, GraphBox, GraphEdge, GraphNode};
+use crate::{Graph, GraphBox, GraphEdge, GraphNode, Highlights, NodeKind, Point, color_for_kind};
 
 /// Wrapper around an AST that implements the `Graph` trait.
 ///
@@ -43,28 +45,54 @@ pub struct AstGraph<'a> {
     /// The abstract syntax tree to visualize.
     ast: &'a Ast,
     /// Optional pinned positions for specific AST nodes (by generated numeric id).
-    pinned_positions: &'a std::collections::HashMap<u32, iced::Point>,
+    pinned_positions: &'a std::collections::HashMap<u32, Point>,
+    /// Glyph used to label epsilon leaves.
+    epsilon_glyph: EpsilonGlyph,
+    /// Emphasis for nodes, keyed by generated numeric id.
+    highlights: Highlights,
 }
 
 impl<'a> AstGraph<'a> {
     /// Creates a new AST graph wrapper.
     #[must_use]
-    pub fn new(
-        ast: &'a Ast,
-        pinned_positions: &'a std::collections::HashMap<u32, iced::Point>,
-    ) -> Self {
+    pub fn new(ast: &'a Ast, pinned_positions: &'a std::collections::HashMap<u32, Point>) -> Self {
         Self {
             ast,
             pinned_positions,
+            epsilon_glyph: EpsilonGlyph::default(),
+            highlights: Highlights::default(),
         }
     }
+
+    /// Labels epsilon leaves with `glyph` instead of the default `ε`.
+    #[must_use]
+    pub fn with_epsilon_glyph(mut self, glyph: EpsilonGlyph) -> Self {
+        self.epsilon_glyph = glyph;
+        self
+    }
+
+    /// Emphasizes the nodes in `highlights`, keyed by generated numeric id.
+    #[must_use]
+    pub fn with_highlights(mut self, highlights: Highlights) -> Self {
+        self.highlights = highlights;
+        self
+    }
 }
 
 impl<'a> Graph for AstGraph<'a> {
     fn nodes(&self) -> Vec<GraphNode> {
         let mut nodes = Vec::new();
         let mut next_id = 0;
-        collect_nodes(self.ast, &mut nodes, &mut next_id, self.pinned_positions);
+        collect_nodes(
+            self.ast,
+            &mut nodes,
+            &mut next_id,
+            self.pinned_positions,
+            self.epsilon_glyph,
+        );
+        for node in &mut nodes {
+            node.highlight = self.highlights.states.get(&node.id).copied();
+        }
         nodes
     }
 
@@ -92,31 +120,40 @@ impl<'a> Graph for AstGraph<'a> {
 /// - `ast`: The current AST node being processed
 /// - `nodes`: Accumulated list of graph nodes
 /// - `next_id`: Counter for generating unique node IDs
+/// - `glyph`: Glyph used to label epsilon leaves
 fn collect_nodes(
     ast: &Ast,
     nodes: &mut Vec<GraphNode>,
     next_id: &mut u32,
-    pinned: &std::collections::HashMap<u32, iced::Point>,
+    pinned: &std::collections::HashMap<u32, Point>,
+    glyph: EpsilonGlyph,
 ) {
     let id = *next_id;
     *next_id += 1;
 
-    let label = match ast {
-        Ast::Atom(c) => format!("'{c}'"),
-        Ast::Concat(_, _) => "·".to_string(), // Concatenation operator
-        Ast::Alt(_, _) => "+".to_string(),
-        Ast::Star(_) => "*".to_string(),
-        Ast::Epsilon => "ε".to_string(),
-        Ast::Opt(_) => "?".to_string(),
+    let (label, kind) = match ast {
+        Ast::Atom(c) => (format!("'{c}'"), NodeKind::Literal),
+        Ast::Concat(_, _) => ("·".to_string(), NodeKind::Concat), // Concatenation operator
+        Ast::Alt(_, _) => ("+".to_string(), NodeKind::Alternation),
+        Ast::Star(_) => ("*".to_string(), NodeKind::Star),
+        Ast::Epsilon => (glyph.to_string(), NodeKind::Epsilon),
+        Ast::Opt(_) => ("?".to_string(), NodeKind::Optional),
     };
 
     let mut node = GraphNode {
         id,
         label,
+        kind,
         is_start: false,
         is_accept: false,
         box_id: None,
         highlight: None,
+        is_selected: false,
+        tint: color_for_kind(kind),
+        overlays: Vec::new(),
+        is_ghost: false,
+        is_breakpoint: false,
+        collapsed: None,
         is_pinned: false,
         manual_position: None,
     };
@@ -133,11 +170,11 @@ fn collect_nodes(
         Ast::Atom(_) => {} // Leaf node, no children
         Ast::Epsilon => {} // Leaf node, no children
         Ast::Concat(left, right) | Ast::Alt(left, right) => {
-            collect_nodes(left, nodes, next_id, pinned);
-            collect_nodes(right, nodes, next_id, pinned);
+            collect_nodes(left, nodes, next_id, pinned, glyph);
+            collect_nodes(right, nodes, next_id, pinned, glyph);
         }
         Ast::Star(inner) | Ast::Opt(inner) => {
-            collect_nodes(inner, nodes, next_id, pinned);
+            collect_nodes(inner, nodes, next_id, pinned, glyph);
         }
     }
 }
@@ -0,0 +1,200 @@
+use regviz_core::core::automaton::{self, BoxId, BoxKind, StateId};
+use serde::Serialize;
+
+use crate::{Color, Point, Rect, color_for_box};
+
+/// Room left around a box's bounds for its border and label text.
+const BOX_LABEL_MARGIN: f32 = 24.0;
+
+/// Metadata describing a bounding box that groups multiple states together.
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphBox {
+    /// Unique identifier of the box.
+    pub id: BoxId,
+    /// The semantic kind of the box (concat, alternation, literal, ...).
+    pub kind: BoxKind,
+    /// Optional identifier of the parent box.
+    pub parent: Option<BoxId>,
+    /// The states that were created while this box was active.
+    pub states: Vec<StateId>,
+    /// Whether the box is folded into the single super-node in `states`.
+    pub collapsed: bool,
+}
+
+impl GraphBox {
+    /// Human readable label derived from the [`BoxKind`].
+    #[must_use]
+    pub fn label(&self) -> &'static str {
+        match self.kind {
+            BoxKind::Literal => "Literal",
+            BoxKind::Concat => "Concat",
+            BoxKind::Alternation => "Alternation",
+            BoxKind::KleeneStar => "Star",
+            BoxKind::KleenePlus => "Plus",
+            BoxKind::Optional => "Optional",
+        }
+    }
+}
+
+/// Opacity kept per nesting level when boxes are dimmed by depth.
+const DEPTH_FADE: f32 = 0.7;
+
+/// Lowest opacity a deeply nested box is dimmed to.
+const MIN_DEPTH_OPACITY: f32 = 0.2;
+
+/// How the bounding boxes of one [`BoxKind`] are drawn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoxStyle {
+    /// Whether the kind's name is written inside the box.
+    pub show_label: bool,
+    /// Whether the border is dashed rather than solid.
+    pub dashed: bool,
+    /// Whether the box is filled with its translucent color.
+    pub filled: bool,
+    /// Radius of the box's corners, in screen pixels.
+    pub corner_radius: f32,
+}
+
+impl Default for BoxStyle {
+    fn default() -> Self {
+        Self {
+            show_label: true,
+            dashed: false,
+            filled: true,
+            corner_radius: 12.0,
+        }
+    }
+}
+
+/// The [`BoxStyle`] chosen for every [`BoxKind`].
+#[derive(Debug, Clone, Default)]
+pub struct BoxStyles {
+    literal: BoxStyle,
+    concat: BoxStyle,
+    alternation: BoxStyle,
+    kleene_star: BoxStyle,
+    kleene_plus: BoxStyle,
+    optional: BoxStyle,
+}
+
+impl BoxStyles {
+    /// Returns the style boxes of the provided [`BoxKind`] are drawn with.
+    #[must_use]
+    pub fn get(&self, kind: BoxKind) -> BoxStyle {
+        match kind {
+            BoxKind::Literal => self.literal,
+            BoxKind::Concat => self.concat,
+            BoxKind::Alternation => self.alternation,
+            BoxKind::KleeneStar => self.kleene_star,
+            BoxKind::KleenePlus => self.kleene_plus,
+            BoxKind::Optional => self.optional,
+        }
+    }
+
+    /// Replaces the style of the provided [`BoxKind`].
+    pub fn set(&mut self, kind: BoxKind, style: BoxStyle) {
+        let slot = match kind {
+            BoxKind::Literal => &mut self.literal,
+            BoxKind::Concat => &mut self.concat,
+            BoxKind::Alternation => &mut self.alternation,
+            BoxKind::KleeneStar => &mut self.kleene_star,
+            BoxKind::KleenePlus => &mut self.kleene_plus,
+            BoxKind::Optional => &mut self.optional,
+        };
+        *slot = style;
+    }
+}
+
+/// Renderable bounding box with geometry information.
+#[derive(Debug, Clone, Serialize)]
+pub struct PositionedBox {
+    /// Logical box metadata.
+    pub data: GraphBox,
+    /// Screen-space rectangle enclosing the box.
+    pub rect: Rect,
+    /// Fill color used to render the box.
+    #[serde(skip)]
+    pub color: Color,
+    /// Anchor where the label should be drawn.
+    pub label_position: Point,
+    /// Border, fill and label options the box is drawn with.
+    #[serde(skip)]
+    pub style: BoxStyle,
+    /// Nesting level of the box, where root boxes are at depth 0.
+    pub depth: usize,
+    /// Opacity applied to the box's fill, border and label.
+    pub opacity: f32,
+}
+
+impl PositionedBox {
+    /// Returns a rectangle enclosing the box and its label.
+    #[must_use]
+    pub fn bounds(&self) -> Rect {
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = self.rect;
+        Rect::enclosing([
+            Point::new(x, y),
+            Point::new(x + width, y + height),
+            self.label_position,
+        ])
+        .expanded(BOX_LABEL_MARGIN)
+    }
+}
+
+impl From<automaton::BoundingBox> for GraphBox {
+    fn from(value: automaton::BoundingBox) -> Self {
+        Self {
+            id: value.id,
+            kind: value.kind,
+            parent: value.parent,
+            states: value.states,
+            collapsed: false,
+        }
+    }
+}
+
+impl PositionedBox {
+    /// Creates a [`PositionedBox`] from a [`GraphBox`] and the computed rectangle.
+    #[must_use]
+    pub fn new(data: GraphBox, rect: Rect) -> Self {
+        let color = color_for_box(data.id);
+        let label_position = label_anchor(rect);
+        Self {
+            data,
+            rect,
+            color,
+            label_position,
+            style: BoxStyle::default(),
+            depth: 0,
+            opacity: 1.0,
+        }
+    }
+
+    /// Moves the box to `rect`, keeping its label in the top-left corner.
+    pub fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+        self.label_position = label_anchor(rect);
+    }
+
+    /// Sets the nesting level of the box.
+    #[must_use]
+    pub fn with_depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Fades the box the more deeply it is nested, keeping root boxes opaque.
+    pub fn dim_by_depth(&mut self) {
+        let depth = i32::try_from(self.depth).unwrap_or(i32::MAX);
+        self.opacity = DEPTH_FADE.powi(depth).max(MIN_DEPTH_OPACITY);
+    }
+}
+
+/// Returns where the label of a box drawn at `rect` is anchored.
+fn label_anchor(rect: Rect) -> Point {
+    Point::new(rect.x + 8.0, rect.y + 18.0)
+}
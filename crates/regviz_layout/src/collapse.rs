@@ -0,0 +1,214 @@
+use std::collections::{HashMap, HashSet};
+
+use regviz_core::core::automaton::{BoundingBox, BoxId, BoxKind, StateId};
+use regviz_core::core::nfa::Nfa;
+
+use crate::GraphBox;
+
+/// Folds the structure of an NFA nested deeper than a cap into super-nodes.
+///
+/// Every bounding box at the cap depth that has nested boxes is drawn as a
+/// single node standing for all of its states, so gigantic regexes stay
+/// readable and the layout only sees the boxes above the cap. Boxes can also
+/// be folded one by one, whatever their depth. The node takes the identifier
+/// of the fragment's start state.
+#[derive(Debug, Clone, Default)]
+pub struct Collapse {
+    /// The super-node each folded state is drawn as.
+    folded: HashMap<StateId, StateId>,
+    /// The states each super-node stands for, itself included.
+    members: HashMap<StateId, Vec<StateId>>,
+    /// The super-node drawn for each collapsed box.
+    collapsed: HashMap<BoxId, StateId>,
+    /// Boxes nested inside a collapsed box, which are not drawn.
+    hidden: HashSet<BoxId>,
+    /// Transitions from the accept state of a folded fragment back to its
+    /// start, added by an enclosing star and kept as a self-loop.
+    loops: HashSet<(StateId, StateId)>,
+}
+
+impl Collapse {
+    /// Collapses the boxes of `nfa` at `max_depth`, where root boxes have
+    /// depth 0, along with each box of `folded`.
+    ///
+    /// # Arguments
+    /// - `nfa` (`&Nfa`) - The automaton to fold.
+    /// - `max_depth` (`Option<usize>`) - Depth of the deepest boxes drawn, or
+    ///   `None` to draw every level.
+    /// - `folded` (`&HashSet<BoxId>`) - Boxes to fold even above `max_depth`
+    ///   or with nothing nested in them.
+    ///
+    /// # Returns
+    /// - `Collapse` - The folding, which changes nothing when `folded` is
+    ///   empty and no box at `max_depth` has nested boxes.
+    #[must_use]
+    pub fn new(nfa: &Nfa, max_depth: Option<usize>, folded: &HashSet<BoxId>) -> Self {
+        let boxes = nfa.boxes.iter().map(|bbox| (bbox.id, bbox)).collect();
+        let mut children: HashMap<BoxId, Vec<BoxId>> = HashMap::new();
+        for bbox in &nfa.boxes {
+            if let Some(parent) = bbox.parent {
+                children.entry(parent).or_default().push(bbox.id);
+            }
+        }
+        for ids in children.values_mut() {
+            ids.sort_unstable();
+        }
+        let tree = BoxTree { boxes, children };
+
+        let mut collapse = Self::default();
+        let mut frontier: Vec<(BoxId, usize)> = nfa
+            .boxes
+            .iter()
+            .filter(|bbox| bbox.parent.is_none())
+            .map(|bbox| (bbox.id, 0))
+            .collect();
+        while let Some((id, depth)) = frontier.pop() {
+            let nested = tree.children(id);
+            if folded.contains(&id) {
+                collapse.fold(&tree, id);
+            } else if max_depth.is_none_or(|max_depth| depth < max_depth) {
+                frontier.extend(nested.iter().map(|&child| (child, depth + 1)));
+            } else if !nested.is_empty() {
+                collapse.fold(&tree, id);
+            }
+        }
+        collapse
+    }
+
+    /// Folds box `id` and everything nested in it into one super-node.
+    fn fold(&mut self, tree: &BoxTree, id: BoxId) {
+        let Some((start, accept)) = tree.endpoints(id) else {
+            return;
+        };
+        let mut members = Vec::new();
+        let mut stack = vec![id];
+        while let Some(current) = stack.pop() {
+            if let Some(bbox) = tree.boxes.get(&current) {
+                members.extend(&bbox.states);
+            }
+            let nested = tree.children(current);
+            if current != id {
+                self.hidden.insert(current);
+            }
+            stack.extend(nested);
+        }
+        members.sort_unstable();
+        for &state in &members {
+            self.folded.insert(state, start);
+        }
+        self.members.insert(start, members);
+        self.collapsed.insert(id, start);
+        if start != accept {
+            self.loops.insert((accept, start));
+        }
+    }
+
+    /// Returns the node `state` is drawn as.
+    #[must_use]
+    pub fn node(&self, state: StateId) -> StateId {
+        self.folded.get(&state).copied().unwrap_or(state)
+    }
+
+    /// Returns whether `state` is drawn as a node of its own or a super-node.
+    #[must_use]
+    pub fn is_drawn(&self, state: StateId) -> bool {
+        self.node(state) == state
+    }
+
+    /// Returns the states a super-node stands for, or `None` for plain nodes.
+    #[must_use]
+    pub fn members(&self, node: StateId) -> Option<&[StateId]> {
+        self.members.get(&node).map(Vec::as_slice)
+    }
+
+    /// Returns the endpoints a transition is drawn between.
+    ///
+    /// # Returns
+    /// - `Option<(StateId, StateId)>` - The nodes of both endpoints, or `None`
+    ///   when the transition is hidden inside a super-node.
+    #[must_use]
+    pub fn edge(&self, from: StateId, to: StateId) -> Option<(StateId, StateId)> {
+        let (from_node, to_node) = (self.node(from), self.node(to));
+        let inside = from_node == to_node && self.members.contains_key(&from_node);
+        (!inside || self.loops.contains(&(from, to))).then_some((from_node, to_node))
+    }
+
+    /// Drops boxes nested in collapsed ones and reduces each collapsed box to
+    /// its super-node.
+    #[must_use]
+    pub fn boxes(&self, boxes: Vec<GraphBox>) -> Vec<GraphBox> {
+        boxes
+            .into_iter()
+            .filter(|bbox| !self.hidden.contains(&bbox.id))
+            .map(|mut bbox| {
+                if let Some(&node) = self.collapsed.get(&bbox.id) {
+                    bbox.states = vec![node];
+                    bbox.collapsed = true;
+                }
+                bbox
+            })
+            .collect()
+    }
+}
+
+/// The bounding boxes of an NFA with their nested boxes, in construction order.
+struct BoxTree<'a> {
+    boxes: HashMap<BoxId, &'a BoundingBox>,
+    children: HashMap<BoxId, Vec<BoxId>>,
+}
+
+impl BoxTree<'_> {
+    /// Returns the boxes nested directly in box `id`.
+    fn children(&self, id: BoxId) -> &[BoxId] {
+        self.children.get(&id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the start and accept states of the fragment built in box `id`.
+    ///
+    /// Concatenations create no states of their own, so their fragment runs
+    /// from the start of their first operand to the accept of their last.
+    /// Every other construct creates its own start and accept first and last.
+    fn endpoints(&self, id: BoxId) -> Option<(StateId, StateId)> {
+        let bbox = self.boxes.get(&id)?;
+        if bbox.kind == BoxKind::Concat {
+            let nested = self.children(id);
+            let (start, _) = self.endpoints(*nested.first()?)?;
+            let (_, accept) = self.endpoints(*nested.last()?)?;
+            Some((start, accept))
+        } else {
+            Some((*bbox.states.first()?, *bbox.states.last()?))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regviz_core::core::parser::Ast;
+
+    use super::*;
+
+    #[test]
+    fn test_folds_a_single_box_whatever_its_depth() {
+        let nfa = Nfa::build(&Ast::build("(ab)*c").unwrap());
+        let literal = nfa
+            .boxes
+            .iter()
+            .find(|bbox| bbox.kind == BoxKind::Literal)
+            .unwrap();
+        let collapse = Collapse::new(&nfa, None, &HashSet::from([literal.id]));
+
+        let node = literal.states[0];
+        assert_eq!(collapse.members(node), Some(literal.states.as_slice()));
+        for &state in &literal.states {
+            assert_eq!(collapse.node(state), node);
+        }
+        let untouched = nfa
+            .states
+            .iter()
+            .filter(|state| collapse.is_drawn(state.id));
+        assert_eq!(
+            untouched.count(),
+            nfa.states.len() - literal.states.len() + 1
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use regviz_core::core::analysis::{SccAnalysis, TransitionHeat};
+use regviz_core::core::automaton::{BoxId, StateId};
+use serde::{Deserialize, Serialize};
+
+use crate::NodeKind;
+
+/// A color with linear `0.0..=1.0` channels, independent of any renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Color {
+    /// Red channel.
+    pub r: f32,
+    /// Green channel.
+    pub g: f32,
+    /// Blue channel.
+    pub b: f32,
+    /// Opacity, where `0.0` is fully transparent.
+    pub a: f32,
+}
+
+impl Color {
+    /// Creates an opaque color.
+    #[must_use]
+    pub const fn from_rgb(r: f32, g: f32, b: f32) -> Self {
+        Self::from_rgba(r, g, b, 1.0)
+    }
+
+    /// Creates a color with the given opacity.
+    #[must_use]
+    pub const fn from_rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+/// Deterministically generates a pseudo-random color for a bounding box.
+#[must_use]
+pub fn color_for_box(id: BoxId) -> Color {
+    let [r, g, b] = hashed_channels(id as u64);
+    Color::from_rgba(r, g, b, 0.25)
+}
+
+/// Deterministically generates an opaque pastel fill for a strongly connected component.
+///
+/// Channels are lifted towards white so node labels stay readable on top.
+#[must_use]
+pub fn color_for_component(index: usize) -> Color {
+    let [r, g, b] = hashed_channels(index as u64);
+    Color::from_rgb(0.45 + r * 0.55, 0.45 + g * 0.55, 0.45 + b * 0.55)
+}
+
+/// Returns the pastel fill of syntax tree operators of `kind`, so each operator
+/// stands out by color as well as by shape.
+///
+/// Characters, the empty string and automaton states keep their default fill.
+#[must_use]
+pub fn color_for_kind(kind: NodeKind) -> Option<Color> {
+    match kind {
+        NodeKind::Concat => Some(Color::from_rgb(0.68, 0.82, 0.98)),
+        NodeKind::Alternation => Some(Color::from_rgb(0.70, 0.90, 0.72)),
+        NodeKind::Star => Some(Color::from_rgb(0.99, 0.80, 0.60)),
+        NodeKind::Optional => Some(Color::from_rgb(0.84, 0.76, 0.98)),
+        NodeKind::State | NodeKind::Literal | NodeKind::Epsilon => None,
+    }
+}
+
+/// Assigns a fill color to every state that lies in a cyclic component.
+///
+/// States outside any cycle are left out so they keep their default fill.
+#[must_use]
+pub fn component_tints(analysis: &SccAnalysis) -> HashMap<StateId, Color> {
+    analysis
+        .components
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| analysis.cyclic[*idx])
+        .flat_map(|(idx, states)| {
+            let color = color_for_component(idx);
+            states.iter().map(move |state| (*state, color))
+        })
+        .collect()
+}
+
+/// Maps a heat ratio in `0.0..=1.0` onto a pale-yellow-to-red scale.
+///
+/// Like [`color_for_component`], the result stays light enough for node
+/// labels to remain readable on top.
+#[must_use]
+pub fn heat_color(ratio: f32) -> Color {
+    let ratio = ratio.clamp(0.0, 1.0);
+    Color::from_rgb(1.0, 0.95 - 0.5 * ratio, 0.75 - 0.35 * ratio)
+}
+
+/// Assigns a fill color to every state by how many transitions touch it,
+/// relative to the busiest state.
+#[must_use]
+pub fn heat_tints(heat: &TransitionHeat) -> HashMap<StateId, Color> {
+    let busiest = heat.busiest().max(1) as f32;
+    (0..heat.in_degree.len() as StateId)
+        .map(|state| (state, heat_color(heat.degree(state) as f32 / busiest)))
+        .collect()
+}
+
+/// Mixes `seed` into three color channels in `0.0..=1.0`.
+fn hashed_channels(seed: u64) -> [f32; 3] {
+    let mut value = (seed + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    value ^= value >> 33;
+    value = value.wrapping_mul(0xC2B2_AE35_0A97_0A4D);
+    value ^= value >> 29;
+    value = value.wrapping_mul(0x1656_67B1_9E37_9B97);
+
+    [
+        ((value >> 16) & 0xFF) as f32 / 255.0,
+        ((value >> 24) & 0xFF) as f32 / 255.0,
+        ((value >> 32) & 0xFF) as f32 / 255.0,
+    ]
+}
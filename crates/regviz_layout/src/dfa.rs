@@ -0,0 +1,324 @@
+use std::collections::{HashMap, HashSet};
+
+use regviz_core::core::automaton::{EdgeLabel, EdgeLabelSet, StateId};
+use regviz_core::core::dfa::Dfa;
+
+use crate::{
+    Color, EdgeCurve, Graph, GraphBox, GraphEdge, GraphNode, HighlightLayer, Highlights, Point,
+};
+
+/// How much wider each further self-loop on a state is than the previous one.
+const LOOP_BEND_STEP: f32 = 0.5;
+/// How much further each further edge between two states bows out than the previous one.
+const CURVE_BEND_STEP: f32 = 0.6;
+
+/// How the transitions of a DFA between the same two states are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransitionGrouping {
+    /// One edge labelled with its symbols, e.g. `[a-c]`.
+    #[default]
+    Merged,
+    /// One edge labelled with the classes of [`Dfa::symbol_classes`] it
+    /// carries, e.g. `a | b,c`.
+    Partitioned,
+    /// One edge per symbol.
+    Separate,
+}
+
+impl TransitionGrouping {
+    /// Every grouping, in the order they are offered to users.
+    pub const ALL: [Self; 3] = [Self::Merged, Self::Partitioned, Self::Separate];
+}
+
+/// Visual wrapper around a DFA with highlight metadata for simulation playback.
+#[derive(Debug, Clone)]
+pub struct VisualDfa<'a> {
+    dfa: &'a Dfa,
+    alphabet: &'a [char],
+    highlights: Highlights,
+    pinned_positions: &'a HashMap<StateId, Point>,
+    selected: Option<StateId>,
+    tints: HashMap<StateId, Color>,
+    breakpoints: HashSet<StateId>,
+    labels: HashMap<StateId, String>,
+    grouping: TransitionGrouping,
+}
+
+impl<'a> VisualDfa<'a> {
+    /// Creates a new highlighted DFA graph.
+    #[must_use]
+    pub fn new(
+        dfa: &'a Dfa,
+        alphabet: &'a [char],
+        highlights: Highlights,
+        pinned_positions: &'a HashMap<StateId, Point>,
+    ) -> Self {
+        Self {
+            dfa,
+            alphabet,
+            highlights,
+            pinned_positions,
+            selected: None,
+            tints: HashMap::new(),
+            breakpoints: HashSet::new(),
+            labels: HashMap::new(),
+            grouping: TransitionGrouping::default(),
+        }
+    }
+
+    /// Marks a state as selected so it is drawn with a selection ring.
+    #[must_use]
+    pub fn with_selection(mut self, selected: Option<StateId>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Marks states where simulation playback pauses.
+    #[must_use]
+    pub fn with_breakpoints(mut self, breakpoints: HashSet<StateId>) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+
+    /// Draws states with custom names instead of their ids.
+    #[must_use]
+    pub fn with_labels(mut self, labels: HashMap<StateId, String>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Fills states with overlay colors, e.g. from [`crate::component_tints`].
+    #[must_use]
+    pub fn with_tints(mut self, tints: HashMap<StateId, Color>) -> Self {
+        self.tints = tints;
+        self
+    }
+
+    /// Sets how transitions between the same two states are drawn.
+    #[must_use]
+    pub fn with_grouping(mut self, grouping: TransitionGrouping) -> Self {
+        self.grouping = grouping;
+        self
+    }
+}
+
+impl<'a> Graph for VisualDfa<'a> {
+    fn nodes(&self) -> Vec<GraphNode> {
+        build_nodes(self)
+    }
+
+    fn edges(&self) -> Vec<GraphEdge> {
+        build_edges(self.dfa, self.alphabet, &self.highlights, self.grouping)
+    }
+
+    fn boxes(&self) -> Vec<GraphBox> {
+        Vec::new()
+    }
+}
+
+/// Builds one node per DFA state, applying every overlay configured on `graph`.
+fn build_nodes(graph: &VisualDfa<'_>) -> Vec<GraphNode> {
+    let dfa = graph.dfa;
+    let highlights = &graph.highlights;
+    dfa.states
+        .iter()
+        .map(|state_id| {
+            let highlight = highlights.state_style(*state_id);
+            let label = graph
+                .labels
+                .get(state_id)
+                .cloned()
+                .unwrap_or_else(|| state_id.to_string());
+            let mut node = GraphNode::new(
+                *state_id,
+                label,
+                dfa.start == *state_id,
+                dfa.accepts.contains(state_id),
+                None,
+            )
+            .with_highlight(highlight)
+            .with_selected(graph.selected == Some(*state_id))
+            .with_tint(graph.tints.get(state_id).copied())
+            .with_ghost(highlights.is_state_ghost(*state_id))
+            .with_overlays(
+                highlights
+                    .state_layers(*state_id)
+                    .map(|layer| layer.color)
+                    .collect(),
+            )
+            .with_breakpoint(graph.breakpoints.contains(state_id));
+
+            if let Some(pos) = graph.pinned_positions.get(state_id) {
+                node.manual_position = Some(*pos);
+                node.is_pinned = true;
+            }
+
+            node
+        })
+        .collect()
+}
+
+fn build_edges(
+    dfa: &Dfa,
+    alphabet: &[char],
+    highlights: &Highlights,
+    grouping: TransitionGrouping,
+) -> Vec<GraphEdge> {
+    // Group transitions between the same pair of states so multiple labels are
+    // rendered as a single label, unless each symbol gets its own edge. Also
+    // collect activity, preview state and the topmost highlight layer of any symbol.
+    type Group<'h> = (EdgeLabelSet, bool, bool, Option<&'h HighlightLayer>);
+    let separate = grouping == TransitionGrouping::Separate;
+    let mut map: HashMap<(StateId, StateId, Option<char>), Group<'_>> = HashMap::new();
+    for (state_idx, state_id) in dfa.states.iter().enumerate() {
+        for (symbol_idx, symbol) in alphabet.iter().enumerate() {
+            let next = dfa.trans[state_idx][symbol_idx];
+            let edge_label = EdgeLabel::Sym(*symbol);
+            let is_active = highlights.is_edge_active(*state_id, next, edge_label);
+            let key = (*state_id, next, separate.then_some(*symbol));
+            let is_ghost = highlights.is_edge_ghost(*state_id, next, edge_label);
+            let layer = highlights.edge_layer(*state_id, next, edge_label);
+            let entry = map
+                .entry(key)
+                .or_insert_with(|| (EdgeLabelSet::new(), false, false, None));
+            entry.0.insert(edge_label);
+            entry.1 = entry.1 || is_active;
+            entry.2 = entry.2 || is_ghost;
+            entry.3 = entry.3.into_iter().chain(layer).max_by_key(|l| l.z_index);
+        }
+    }
+
+    // Symbols of the edges between each pair of states, in order, to fan
+    // separate edges out.
+    let mut parallel: HashMap<(StateId, StateId), Vec<Option<char>>> = HashMap::new();
+    for &(from, to, symbol) in map.keys() {
+        parallel.entry((from, to)).or_default().push(symbol);
+    }
+    for symbols in parallel.values_mut() {
+        symbols.sort_unstable();
+    }
+    let classes = (grouping == TransitionGrouping::Partitioned).then(|| dfa.symbol_classes());
+
+    // Build edges from grouped labels
+    let edges: Vec<GraphEdge> = map
+        .iter()
+        .map(
+            |((from, to, symbol), (labels, is_active, is_ghost, layer))| {
+                let label = match &classes {
+                    Some(classes) => partition_label(classes, labels),
+                    None => labels.to_string(),
+                };
+
+                // Consider edge curves based on from/to states
+                let lane = parallel[&(*from, *to)]
+                    .iter()
+                    .position(|other| other == symbol)
+                    .unwrap_or_default();
+                let reversed = parallel.contains_key(&(*to, *from));
+                let (curve, bend) = fan_out(*from, *to, reversed, lane);
+                GraphEdge::with_curve(*from, *to, label, curve)
+                    .with_bend(bend)
+                    .with_active(*is_active)
+                    .with_ghost(*is_ghost)
+                    .with_overlay(layer.map(|layer| layer.color))
+            },
+        )
+        .collect();
+
+    edges
+}
+
+/// Labels an edge with the classes of symbols among `classes` that it carries,
+/// e.g. `a | b,c`.
+fn partition_label(classes: &[Vec<char>], labels: &EdgeLabelSet) -> String {
+    // Symbols of a class lead to the same state, so an edge carries whole classes.
+    classes
+        .iter()
+        .filter(|class| labels.contains(EdgeLabel::Sym(class[0])))
+        .map(|class| {
+            class
+                .iter()
+                .map(char::to_string)
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+/// Picks the curve and bend of the `lane`-th edge from `from` to `to`, so
+/// several edges between the same states fan out instead of overlapping.
+///
+/// `reversed` tells whether edges also go from `to` to `from`; those take the
+/// other side, so these all bow out on this one.
+fn fan_out(from: StateId, to: StateId, reversed: bool, lane: usize) -> (EdgeCurve, f32) {
+    if from == to {
+        (EdgeCurve::Loop, 1.0 + LOOP_BEND_STEP * lane as f32)
+    } else if reversed {
+        (EdgeCurve::CurveDown, 1.0 + CURVE_BEND_STEP * lane as f32)
+    } else if lane == 0 {
+        (EdgeCurve::Straight, 1.0)
+    } else {
+        // Alternate sides, bowing further out every other edge.
+        let curve = if lane % 2 == 1 {
+            EdgeCurve::CurveDown
+        } else {
+            EdgeCurve::CurveUp
+        };
+        (curve, CURVE_BEND_STEP * lane.div_ceil(2) as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regviz_core::core::nfa::Nfa;
+    use regviz_core::core::parser::Ast;
+    use regviz_core::core::{dfa, min};
+
+    use super::*;
+
+    #[test]
+    fn test_merges_parallel_transitions_into_one_label() {
+        let nfa = Nfa::build(&Ast::build("(a+b+c)*d").unwrap());
+        let dfa = min::minimize(&dfa::determinize(&nfa));
+        let pinned = HashMap::new();
+        let graph = VisualDfa::new(&dfa, &dfa.alphabet, Highlights::default(), &pinned);
+        let labels: Vec<String> = graph.edges().into_iter().map(|edge| edge.label).collect();
+        assert!(labels.contains(&"[a-c]".to_string()));
+        assert!(labels.contains(&"'d'".to_string()));
+    }
+
+    #[test]
+    fn test_labels_merged_transitions_with_the_symbol_classes() {
+        let nfa = Nfa::build(&Ast::build("(a+b+c)*a(b+c)d").unwrap());
+        let dfa = min::minimize(&dfa::determinize(&nfa));
+        let pinned = HashMap::new();
+        let graph = VisualDfa::new(&dfa, &dfa.alphabet, Highlights::default(), &pinned)
+            .with_grouping(TransitionGrouping::Partitioned);
+        let labels: Vec<String> = graph.edges().into_iter().map(|edge| edge.label).collect();
+        // 'b' and 'c' always lead to the same state, so they share a class.
+        assert!(labels.contains(&"b,c".to_string()));
+        assert!(labels.contains(&"a | b,c | d".to_string()));
+        assert!(labels.iter().all(|label| !label.contains('\'')));
+    }
+
+    #[test]
+    fn test_separates_transitions_into_fanned_out_edges() {
+        let nfa = Nfa::build(&Ast::build("(a+b+c)*d").unwrap());
+        let dfa = min::minimize(&dfa::determinize(&nfa));
+        let pinned = HashMap::new();
+        let graph = VisualDfa::new(&dfa, &dfa.alphabet, Highlights::default(), &pinned)
+            .with_grouping(TransitionGrouping::Separate);
+        let edges = graph.edges();
+        assert_eq!(edges.len(), dfa.states.len() * dfa.alphabet.len());
+
+        // The three self-loops on the start state nest instead of overlapping.
+        let mut bends: Vec<f32> = edges
+            .iter()
+            .filter(|edge| edge.from == dfa.start && edge.to == dfa.start)
+            .map(|edge| edge.bend)
+            .collect();
+        bends.sort_by(f32::total_cmp);
+        assert_eq!(bends, vec![1.0, 1.5, 2.0]);
+    }
+}
@@ -0,0 +1,408 @@
+use regviz_core::core::automaton::StateId;
+use serde::Serialize;
+
+use crate::{Color, Point, Rect};
+
+/// Distance between the edge segment and its label in logical units.
+pub const LABEL_DISTANCE: f32 = 13.0;
+
+/// Number of straight pieces used to approximate a curved edge when hit-testing.
+const HIT_TEST_CURVE_SAMPLES: usize = 16;
+
+/// Edge curvature style for different types of transitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum EdgeCurve {
+    /// Straight line between nodes.
+    Straight,
+    /// Curved downward.
+    CurveDown,
+    /// Curved upward (for star closure loop-back: inner_accept → inner_start).
+    CurveUp,
+    /// Loop back to the same node
+    Loop,
+}
+
+/// Renderable description of a transition between two states.
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphEdge {
+    /// Origin node identifier.
+    pub from: StateId,
+    /// Destination node identifier.
+    pub to: StateId,
+    /// Label displayed next to the edge.
+    pub label: String,
+    /// Curvature style for this edge.
+    pub curve: EdgeCurve,
+    /// Whether this edge was traversed in the current simulation step.
+    pub is_active: bool,
+    /// Whether this edge would be traversed by the previewed step.
+    pub is_ghost: bool,
+    /// Color of the topmost highlight layer containing the edge.
+    #[serde(skip)]
+    pub overlay: Option<Color>,
+    /// How far a curve bows out or a self-loop reaches, relative to the usual
+    /// distance, so parallel edges between the same states stay apart.
+    #[serde(skip_serializing_if = "is_unit_bend")]
+    pub bend: f32,
+}
+
+/// Returns whether `bend` is the usual distance, which is left out of layouts.
+fn is_unit_bend(bend: &f32) -> bool {
+    *bend == 1.0
+}
+
+impl GraphEdge {
+    /// Creates a new [`GraphEdge`] with a straight line.
+    #[must_use]
+    pub fn new(from: StateId, to: StateId, label: String) -> Self {
+        Self {
+            from,
+            to,
+            label,
+            curve: EdgeCurve::Straight,
+            is_active: false,
+            is_ghost: false,
+            overlay: None,
+            bend: 1.0,
+        }
+    }
+
+    /// Creates a new [`GraphEdge`] with a specified curve style.
+    #[must_use]
+    pub fn with_curve(from: StateId, to: StateId, label: String, curve: EdgeCurve) -> Self {
+        Self {
+            from,
+            to,
+            label,
+            curve,
+            is_active: false,
+            is_ghost: false,
+            overlay: None,
+            bend: 1.0,
+        }
+    }
+
+    /// Marks the edge as active (or inactive) for the current simulation step.
+    #[must_use]
+    pub fn with_active(mut self, is_active: bool) -> Self {
+        self.is_active = is_active;
+        self
+    }
+
+    /// Marks the edge as part of the previewed next step.
+    #[must_use]
+    pub fn with_ghost(mut self, is_ghost: bool) -> Self {
+        self.is_ghost = is_ghost;
+        self
+    }
+
+    /// Draws the edge in the color of a highlight layer containing it.
+    #[must_use]
+    pub fn with_overlay(mut self, overlay: Option<Color>) -> Self {
+        self.overlay = overlay;
+        self
+    }
+
+    /// Bows the curve, or widens the self-loop, by `bend` times the usual distance.
+    #[must_use]
+    pub fn with_bend(mut self, bend: f32) -> Self {
+        self.bend = bend;
+        self
+    }
+}
+
+/// [`GraphEdge`] enriched with layout information.
+#[derive(Debug, Clone, Serialize)]
+pub struct PositionedEdge {
+    /// Edge metadata.
+    pub data: GraphEdge,
+    /// Start position.
+    pub from: Point,
+    /// End position.
+    pub to: Point,
+    /// Suggested position for the label.
+    pub label_position: Point,
+    /// Radius of the source node (used to adjust edge start point).
+    pub from_radius: f32,
+    /// Radius of the destination node (used to adjust edge end point).
+    pub to_radius: f32,
+    /// Bend points the edge passes through, in order, when it is routed
+    /// around obstacles instead of drawn with its [`EdgeCurve`].
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub route: Vec<Point>,
+}
+
+impl PositionedEdge {
+    /// Creates a new positioned edge from metadata and coordinates, keeping the label legible
+    /// by offsetting it away from the rendered segment.
+    ///
+    /// Note: The `from` and `to` points represent node centers. The actual edge will be
+    /// drawn from the edge of the source node to the edge of the destination node.
+    #[must_use]
+    pub fn new(data: GraphEdge, from: Point, to: Point) -> Self {
+        let label_position = compute_label_anchor(from, to);
+        Self {
+            data,
+            from,
+            to,
+            label_position,
+            from_radius: 32.0, // Default node radius
+            to_radius: 32.0,   // Default node radius
+            route: Vec::new(),
+        }
+    }
+
+    /// Creates a new positioned edge with explicit node radii.
+    ///
+    /// This is useful when nodes have different radii (e.g., in different visualization modes).
+    ///
+    /// # Note
+    ///
+    /// This function is currently unused but kept for potential future use.
+    #[must_use]
+    pub fn with_radii(
+        data: GraphEdge,
+        from: Point,
+        to: Point,
+        from_radius: f32,
+        to_radius: f32,
+    ) -> Self {
+        let label_position = compute_label_anchor(from, to);
+        Self {
+            data,
+            from,
+            to,
+            label_position,
+            from_radius,
+            to_radius,
+            route: Vec::new(),
+        }
+    }
+
+    /// Routes the edge through `route`, drawing it as a polyline instead of
+    /// its [`EdgeCurve`].
+    ///
+    /// The label moves next to the middle segment of the polyline.
+    ///
+    /// # Arguments
+    /// - `route` (`Vec<Point>`) - Bend points between the two endpoints, in order.
+    ///
+    /// # Returns
+    /// - `PositionedEdge` - The routed edge.
+    #[must_use]
+    pub fn with_route(mut self, route: Vec<Point>) -> Self {
+        self.route = route;
+        if !self.route.is_empty() {
+            let path = self.path();
+            let middle = (path.len() - 1) / 2;
+            self.label_position = compute_label_anchor(path[middle], path[middle + 1]);
+        }
+        self
+    }
+
+    /// Returns the polyline through the endpoints and the bend points of [`Self::route`].
+    #[must_use]
+    pub fn path(&self) -> Vec<Point> {
+        std::iter::once(self.from)
+            .chain(self.route.iter().copied())
+            .chain(std::iter::once(self.to))
+            .collect()
+    }
+}
+
+impl PositionedEdge {
+    /// Returns a rectangle enclosing everything drawn for the edge: its line,
+    /// curve or route, arrow head, self-loop and label.
+    #[must_use]
+    pub fn bounds(&self) -> Rect {
+        let control = match self.data.curve {
+            EdgeCurve::CurveDown | EdgeCurve::CurveUp => curve_control_point(
+                self.from,
+                self.to,
+                self.data.curve == EdgeCurve::CurveDown,
+                self.data.bend,
+            ),
+            EdgeCurve::Straight | EdgeCurve::Loop => None,
+        };
+        // Self-loops rise about twice the node radius above it, and labels
+        // spread on either side of their anchor.
+        let margin =
+            self.from_radius.max(self.to_radius) * 2.0 * self.data.bend.max(1.0) + LABEL_DISTANCE;
+        Rect::enclosing(
+            [self.from, self.to, self.label_position]
+                .into_iter()
+                .chain(control)
+                .chain(self.route.iter().copied()),
+        )
+        .expanded(margin)
+    }
+
+    /// Returns the distance from `point` to the drawn edge, in layout coordinates.
+    ///
+    /// Curved edges are approximated by sampling points along the curve.
+    #[must_use]
+    pub fn distance_to(&self, point: Point) -> f32 {
+        if !self.route.is_empty() {
+            return self
+                .path()
+                .windows(2)
+                .map(|pair| distance_to_segment(point, pair[0], pair[1]))
+                .fold(f32::INFINITY, f32::min);
+        }
+        let (from, to) = (self.from, self.to);
+        match self.data.curve {
+            EdgeCurve::Straight => distance_to_segment(point, from, to),
+            EdgeCurve::CurveDown | EdgeCurve::CurveUp => {
+                let curve_down = self.data.curve == EdgeCurve::CurveDown;
+                let Some(control) = curve_control_point(from, to, curve_down, self.data.bend)
+                else {
+                    return point.distance(from);
+                };
+                (0..=HIT_TEST_CURVE_SAMPLES)
+                    .map(|step| step as f32 / HIT_TEST_CURVE_SAMPLES as f32)
+                    .map(|t| quadratic_bezier_point(from, control, to, t))
+                    .collect::<Vec<_>>()
+                    .windows(2)
+                    .map(|pair| distance_to_segment(point, pair[0], pair[1]))
+                    .fold(f32::INFINITY, f32::min)
+            }
+            EdgeCurve::Loop => {
+                let (loop_center, loop_radius) = self_loop(from, self.from_radius, self.data.bend);
+                (point.distance(loop_center) - loop_radius).abs()
+            }
+        }
+    }
+}
+
+/// Distance from `point` to the segment between `start` and `end`.
+fn distance_to_segment(point: Point, start: Point, end: Point) -> f32 {
+    let (dx, dy) = (end.x - start.x, end.y - start.y);
+    let length_sq = dx * dx + dy * dy;
+    if length_sq <= f32::EPSILON {
+        return point.distance(start);
+    }
+    let t = (((point.x - start.x) * dx + (point.y - start.y) * dy) / length_sq).clamp(0.0, 1.0);
+    point.distance(Point::new(start.x + dx * t, start.y + dy * t))
+}
+
+/// Computes the control point of the quadratic Bezier used for curved edges.
+///
+/// The control point is offset perpendicular to the line between the nodes,
+/// `bend` times as far as usual. It is linear in its inputs, so it can be
+/// computed in layout or screen coordinates alike. Returns `None` when the
+/// endpoints coincide.
+#[must_use]
+pub fn curve_control_point(from: Point, to: Point, curve_down: bool, bend: f32) -> Option<Point> {
+    let (dx, dy) = (to.x - from.x, to.y - from.y);
+    let length = (dx * dx + dy * dy).sqrt();
+    if length <= f32::EPSILON {
+        return None;
+    }
+
+    // Get perpendicular vector (rotates 90° counterclockwise)
+    // For horizontal edges (left to right), this gives an upward normal
+    let (nx, ny) = perpendicular(dx / length, dy / length);
+
+    // Limit curve height to 25% of distance between nodes so curves stay
+    // inside their bounding boxes. Positive offsets move along the normal, which
+    // points downward in screen coordinates for a left-to-right edge.
+    let max_curve_height = length * 0.25 * bend;
+    let control_offset = if curve_down {
+        max_curve_height
+    } else {
+        -max_curve_height
+    };
+
+    let mid = Point::new((from.x + to.x) * 0.5, (from.y + to.y) * 0.5);
+    Some(Point::new(
+        mid.x + nx * control_offset,
+        mid.y + ny * control_offset,
+    ))
+}
+
+/// Computes the circle drawn for a self-loop on the node at `center`.
+///
+/// The loop sits above the node, overlapping its top. Wider loops, with a
+/// `bend` above one, rise higher from the same bottom point, so several loops
+/// on one node nest instead of crossing. Like [`curve_control_point`], it is
+/// linear in its inputs, so it works in layout or screen coordinates alike.
+///
+/// # Arguments
+/// - `center` (`Point`) - Center of the node.
+/// - `radius` (`f32`) - Radius of the node.
+/// - `bend` (`f32`) - Size of the loop relative to the usual one.
+///
+/// # Returns
+/// - `(Point, f32)` - The center and the radius of the loop.
+#[must_use]
+pub fn self_loop(center: Point, radius: f32, bend: f32) -> (Point, f32) {
+    let loop_radius = radius * 0.7 * bend;
+    (
+        Point::new(center.x, center.y - radius * 0.4 - loop_radius),
+        loop_radius,
+    )
+}
+
+/// Calculates the anchor point for an edge label connecting two points.
+///
+/// The label is positioned at the midpoint of the edge, offset perpendicular to the
+/// edge direction. The offset is placed "above" the edge (negative y direction in screen space),
+/// unless the edge points downward, in which case the offset is flipped to keep the label above.
+///
+/// # Algorithm
+/// 1. Find the midpoint between the two endpoints
+/// 2. Calculate the perpendicular (normal) vector to the edge direction
+/// 3. Offset the midpoint by `LABEL_DISTANCE` in the normal direction
+fn compute_label_anchor(from: Point, to: Point) -> Point {
+    // Calculate the midpoint of the edge
+    let mid = Point::new((from.x + to.x) * 0.5, (from.y + to.y) * 0.5);
+
+    // Calculate the vector from 'from' to 'to'
+    let (dx, dy) = (to.x - from.x, to.y - from.y);
+    let length = (dx * dx + dy * dy).sqrt();
+
+    // If the two points are basically the same, just place label above
+    if length <= f32::EPSILON {
+        return Point::new(mid.x, mid.y + LABEL_DISTANCE);
+    }
+
+    // Get the perpendicular (normal) vector - rotated 90° from the edge direction
+    let (mut nx, mut ny) = perpendicular(dx / length, dy / length);
+
+    // Flip the normal if it points downward (positive y) so labels always appear above
+    if ny > 0.0 {
+        (nx, ny) = (-nx, -ny);
+    }
+
+    // Offset the midpoint by the normal vector scaled by LABEL_DISTANCE
+    Point::new(mid.x + nx * LABEL_DISTANCE, mid.y + ny * LABEL_DISTANCE)
+}
+
+/// Rotates the vector `(x, y)` by 90° counterclockwise.
+fn perpendicular(x: f32, y: f32) -> (f32, f32) {
+    (-y, x)
+}
+
+/// Computes a point on a quadratic Bezier curve at parameter t.
+///
+/// Uses the quadratic Bezier formula: B(t) = (1-t)²p0 + 2(1-t)t*p1 + t²p2
+///
+/// # Arguments
+/// - `p0`: Start point of the curve
+/// - `p1`: Control point
+/// - `p2`: End point of the curve
+/// - `t`: Parameter in range [0, 1]
+///
+/// # Returns
+/// The point on the curve at parameter t
+#[must_use]
+pub fn quadratic_bezier_point(p0: Point, p1: Point, p2: Point, t: f32) -> Point {
+    let mt = 1.0 - t;
+    let mt2 = mt * mt;
+    let t2 = t * t;
+
+    Point::new(
+        mt2 * p0.x + 2.0 * mt * t * p1.x + t2 * p2.x,
+        mt2 * p0.y + 2.0 * mt * t * p1.y + t2 * p2.y,
+    )
+}
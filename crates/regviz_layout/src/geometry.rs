@@ -0,0 +1,124 @@
+//! Renderer-independent coordinates produced by the layout strategies.
+//!
+//! Layouts are computed in these plain `f32` types so they can be serialized
+//! and compared without a renderer; frontends convert them to their own
+//! geometry only when drawing.
+
+use serde::{Deserialize, Serialize};
+
+/// A position in layout coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Point {
+    /// Horizontal coordinate, growing to the right.
+    pub x: f32,
+    /// Vertical coordinate, growing downwards.
+    pub y: f32,
+}
+
+impl Point {
+    /// Creates a point at `(x, y)`.
+    #[must_use]
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the Euclidean distance to `other`.
+    #[must_use]
+    pub fn distance(self, other: Self) -> f32 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
+
+/// An axis-aligned rectangle in layout coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Rect {
+    /// Left edge.
+    pub x: f32,
+    /// Top edge.
+    pub y: f32,
+    /// Horizontal extent.
+    pub width: f32,
+    /// Vertical extent.
+    pub height: f32,
+}
+
+impl Rect {
+    /// Returns the smallest rectangle containing every point of `points`, or
+    /// an empty rectangle at the origin when there are none.
+    #[must_use]
+    pub fn enclosing(points: impl IntoIterator<Item = Point>) -> Self {
+        let mut points = points.into_iter();
+        let Some(first) = points.next() else {
+            return Self::default();
+        };
+        let (min, max) = points.fold((first, first), |(min, max), point| {
+            (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            )
+        });
+        Self {
+            x: min.x,
+            y: min.y,
+            width: max.x - min.x,
+            height: max.y - min.y,
+        }
+    }
+
+    /// Returns the rectangle grown by `margin` on every side.
+    #[must_use]
+    pub fn expanded(self, margin: f32) -> Self {
+        Self {
+            x: self.x - margin,
+            y: self.y - margin,
+            width: self.width + margin * 2.0,
+            height: self.height + margin * 2.0,
+        }
+    }
+
+    /// Returns whether the rectangle overlaps `other`, touching borders excluded.
+    #[must_use]
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.x < other.x + other.width
+            && other.x < self.x + self.width
+            && self.y < other.y + other.height
+            && other.y < self.y + self.height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enclosing_rect_spans_every_point() {
+        let rect = Rect::enclosing([
+            Point::new(4.0, -2.0),
+            Point::new(-1.0, 3.0),
+            Point::new(2.0, 1.0),
+        ]);
+        assert_eq!(
+            rect,
+            Rect {
+                x: -1.0,
+                y: -2.0,
+                width: 5.0,
+                height: 5.0,
+            }
+        );
+        assert_eq!(Rect::enclosing([]), Rect::default());
+    }
+
+    #[test]
+    fn test_expanded_rect_reaches_neighbours() {
+        let rect = Rect::enclosing([Point::new(0.0, 0.0)]);
+        let neighbour = Rect {
+            x: 3.0,
+            y: 0.0,
+            width: 1.0,
+            height: 1.0,
+        };
+        assert!(!rect.intersects(&neighbour));
+        assert!(rect.expanded(4.0).intersects(&neighbour));
+    }
+}
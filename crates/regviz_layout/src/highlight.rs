@@ -0,0 +1,232 @@
+use std::collections::{HashMap, HashSet};
+
+use regviz_core::core::automaton::{EdgeLabel, StateId};
+use serde::Serialize;
+
+use crate::Color;
+
+/// Key identifying a transition in an automaton.
+///
+/// This is used to track which edges should be highlighted when stepping through
+/// a simulation. Two edges are considered identical if they originate from the
+/// same state, terminate at the same state, and carry the same label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EdgeHighlight {
+    /// Origin state identifier.
+    pub from: StateId,
+    /// Destination state identifier.
+    pub to: StateId,
+    /// Transition label (ε or a concrete symbol).
+    pub label: EdgeLabel,
+}
+
+impl EdgeHighlight {
+    /// Creates a new keyed highlight reference for an edge.
+    #[must_use]
+    pub fn new(from: StateId, to: StateId, label: EdgeLabel) -> Self {
+        Self { from, to, label }
+    }
+}
+
+/// Visual emphasis applied to a state during simulation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum StateHighlight {
+    /// State is currently active, indicating a reachable frontier.
+    Active,
+    /// Simulation has terminated without acceptance; state is marked as rejecting.
+    Rejected,
+}
+
+/// A named set of states and transitions emphasised in one color.
+///
+/// Layers let independent features (simulation, inspector, search, analyses)
+/// mark the graph at the same time without overwriting each other. Layers are
+/// drawn in ascending [`HighlightLayer::z_index`], so higher layers end up on
+/// top where they overlap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HighlightLayer {
+    /// Name identifying the layer; adding a layer replaces one of the same name.
+    pub name: &'static str,
+    /// Color the layer's states and transitions are drawn with.
+    pub color: Color,
+    /// Stacking order; higher layers are drawn over lower ones.
+    pub z_index: i32,
+    /// States in the layer.
+    pub states: HashSet<StateId>,
+    /// Transitions in the layer.
+    pub edges: HashSet<EdgeHighlight>,
+}
+
+impl HighlightLayer {
+    /// Creates an empty layer.
+    #[must_use]
+    pub fn new(name: &'static str, color: Color, z_index: i32) -> Self {
+        Self {
+            name,
+            color,
+            z_index,
+            states: HashSet::new(),
+            edges: HashSet::new(),
+        }
+    }
+
+    /// Adds states to the layer.
+    #[must_use]
+    pub fn with_states(mut self, states: impl IntoIterator<Item = StateId>) -> Self {
+        self.states.extend(states);
+        self
+    }
+
+    /// Adds transitions to the layer.
+    #[must_use]
+    pub fn with_edges(mut self, edges: impl IntoIterator<Item = EdgeHighlight>) -> Self {
+        self.edges.extend(edges);
+        self
+    }
+}
+
+/// Collection of states and transitions that should be emphasised in the UI.
+#[derive(Debug, Clone, Default)]
+pub struct Highlights {
+    /// Highlight information for active states.
+    pub states: HashMap<StateId, StateHighlight>,
+    /// Set of edges that were traversed in the current simulation step.
+    pub edges: HashSet<EdgeHighlight>,
+    /// States that would be active after the previewed next step.
+    pub ghost_states: HashSet<StateId>,
+    /// Edges the previewed next step would traverse.
+    pub ghost_edges: HashSet<EdgeHighlight>,
+    /// Named overlay layers, in ascending z-order.
+    pub layers: Vec<HighlightLayer>,
+}
+
+impl Highlights {
+    /// Creates a highlights set from explicit state and edge collections.
+    #[must_use]
+    pub fn new(states: HashMap<StateId, StateHighlight>, edges: HashSet<EdgeHighlight>) -> Self {
+        Self {
+            states,
+            edges,
+            ..Self::default()
+        }
+    }
+
+    /// Adds a ghosted preview of the next frontier on top of the current highlights.
+    #[must_use]
+    pub fn with_ghost(
+        mut self,
+        ghost_states: HashSet<StateId>,
+        ghost_edges: HashSet<EdgeHighlight>,
+    ) -> Self {
+        self.ghost_states = ghost_states;
+        self.ghost_edges = ghost_edges;
+        self
+    }
+
+    /// Returns whether a state belongs to the previewed next frontier.
+    #[must_use]
+    pub fn is_state_ghost(&self, state: StateId) -> bool {
+        self.ghost_states.contains(&state)
+    }
+
+    /// Returns whether a transition would be taken by the previewed next step.
+    #[must_use]
+    pub fn is_edge_ghost(&self, from: StateId, to: StateId, label: EdgeLabel) -> bool {
+        self.ghost_edges
+            .contains(&EdgeHighlight::new(from, to, label))
+    }
+
+    /// Returns the highlight style for a state, if any.
+    #[must_use]
+    pub fn state_style(&self, state: StateId) -> Option<StateHighlight> {
+        self.states.get(&state).copied()
+    }
+
+    /// Returns whether a given transition should be emphasised.
+    #[must_use]
+    pub fn is_edge_active(&self, from: StateId, to: StateId, label: EdgeLabel) -> bool {
+        self.edges.contains(&EdgeHighlight::new(from, to, label))
+    }
+
+    /// Adds an overlay layer, replacing any layer with the same name.
+    #[must_use]
+    pub fn with_layer(mut self, layer: HighlightLayer) -> Self {
+        self.add_layer(layer);
+        self
+    }
+
+    /// Adds an overlay layer in z-order, replacing any layer with the same name.
+    ///
+    /// Layers with equal z-indices keep the order they were added in.
+    pub fn add_layer(&mut self, layer: HighlightLayer) {
+        self.layers.retain(|existing| existing.name != layer.name);
+        let index = self
+            .layers
+            .partition_point(|existing| existing.z_index <= layer.z_index);
+        self.layers.insert(index, layer);
+    }
+
+    /// Returns the layer named `name`, if one was added.
+    #[must_use]
+    pub fn layer(&self, name: &str) -> Option<&HighlightLayer> {
+        self.layers.iter().find(|layer| layer.name == name)
+    }
+
+    /// Returns the layers containing a state, bottom first.
+    pub fn state_layers(&self, state: StateId) -> impl Iterator<Item = &HighlightLayer> {
+        self.layers
+            .iter()
+            .filter(move |layer| layer.states.contains(&state))
+    }
+
+    /// Returns the topmost layer containing a transition, if any.
+    #[must_use]
+    pub fn edge_layer(
+        &self,
+        from: StateId,
+        to: StateId,
+        label: EdgeLabel,
+    ) -> Option<&HighlightLayer> {
+        let edge = EdgeHighlight::new(from, to, label);
+        self.layers
+            .iter()
+            .rev()
+            .find(|layer| layer.edges.contains(&edge))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layers_stack_by_z_index_and_replace_by_name() {
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+        let blue = Color::from_rgb(0.0, 0.0, 1.0);
+        let edge = EdgeHighlight::new(0, 1, EdgeLabel::Sym('a'));
+        let highlights = Highlights::default()
+            .with_layer(HighlightLayer::new("search", red, 2).with_states([0]))
+            .with_layer(
+                HighlightLayer::new("reachable", blue, 1)
+                    .with_states([0, 1])
+                    .with_edges([edge]),
+            )
+            .with_layer(
+                HighlightLayer::new("search", red, 2)
+                    .with_states([0, 1])
+                    .with_edges([edge]),
+            );
+
+        assert_eq!(highlights.layers.len(), 2);
+        let names: Vec<_> = highlights.state_layers(1).map(|layer| layer.name).collect();
+        assert_eq!(names, ["reachable", "search"]);
+        assert_eq!(
+            highlights
+                .edge_layer(0, 1, EdgeLabel::Sym('a'))
+                .map(|layer| layer.name),
+            Some("search")
+        );
+        assert!(highlights.edge_layer(1, 0, EdgeLabel::Sym('a')).is_none());
+        assert_eq!(highlights.state_layers(2).count(), 0);
+    }
+}
@@ -1,14 +1,13 @@
 use std::collections::{BTreeMap, HashMap, VecDeque};
 
-use iced::{Point, Rectangle};
 use regviz_core::core::automaton::StateId;
 
-use super::{BoxVisibility, GraphLayout, LayoutStrategy};
-use crate::graph::{Graph, GraphEdge, GraphNode, edge::PositionedEdge, node::PositionedNode};
+use super::{BoxVisibility, GraphLayout, LayoutConfig, LayoutStrategy};
+use crate::{Graph, GraphEdge, GraphNode, Point, PositionedEdge, PositionedNode, Rect};
 
-/// Horizontal distance between consecutive BFS layers.
+/// Horizontal distance between consecutive BFS layers, at the default [`LayoutConfig`].
 const LAYER_SPACING_X: f32 = 240.0;
-/// Vertical distance between nodes within a layer.
+/// Vertical distance between nodes within a layer, at the default [`LayoutConfig`].
 const NODE_SPACING_Y: f32 = 150.0;
 /// Radius of each rendered DFA state.
 const NODE_RADIUS: f32 = 32.0;
@@ -28,17 +27,22 @@ const LAYOUT_PADDING_Y: f32 = 90.0;
 pub struct DfaLayoutStrategy;
 
 impl LayoutStrategy for DfaLayoutStrategy {
-    fn compute<G: Graph>(&self, graph: &G, _visibility: &BoxVisibility) -> GraphLayout {
-        layout_graph(graph)
+    fn compute<G: Graph>(
+        &self,
+        graph: &G,
+        _visibility: &BoxVisibility,
+        config: &LayoutConfig,
+    ) -> GraphLayout {
+        layout_graph(graph, config)
     }
 }
 
-fn layout_graph<G: Graph>(graph: &G) -> GraphLayout {
+fn layout_graph<G: Graph>(graph: &G, config: &LayoutConfig) -> GraphLayout {
     let nodes = graph.nodes();
     let edges = graph.edges();
 
     let adjacency = build_adjacency(&edges);
-    let mut state_positions = compute_positions(&nodes, &adjacency);
+    let mut state_positions = compute_positions(&nodes, &adjacency, config);
 
     // If any nodes carry manual positions (pinned by the user), merge those
     // positions into the computed map so edges and positioned nodes use the
@@ -99,8 +103,11 @@ fn build_adjacency(edges: &[GraphEdge]) -> HashMap<StateId, Vec<StateId>> {
 fn compute_positions(
     nodes: &[GraphNode],
     adjacency: &HashMap<StateId, Vec<StateId>>,
+    config: &LayoutConfig,
 ) -> HashMap<StateId, Point> {
     let levels = assign_levels(nodes, adjacency);
+    let layer_spacing = LAYER_SPACING_X * config.horizontal_scale();
+    let node_spacing = NODE_SPACING_Y * config.vertical_scale();
 
     let mut layers: BTreeMap<usize, Vec<StateId>> = BTreeMap::new();
     for node in nodes {
@@ -124,16 +131,16 @@ fn compute_positions(
 
     for (level, ids) in layers {
         let column = level_index[&level] as f32;
-        let x = column * LAYER_SPACING_X;
+        let x = column * layer_spacing;
         let count = ids.len();
         let base_y = if count <= 1 {
             0.0
         } else {
-            -((count as f32 - 1.0) * NODE_SPACING_Y * 0.5)
+            -((count as f32 - 1.0) * node_spacing * 0.5)
         };
 
         for (index, state_id) in ids.into_iter().enumerate() {
-            let y = base_y + index as f32 * NODE_SPACING_Y;
+            let y = base_y + index as f32 * node_spacing;
             positions.insert(state_id, Point::new(x, y));
         }
     }
@@ -254,7 +261,7 @@ impl BoundsTracker {
     }
 
     fn include_circle(&mut self, center: Point, radius: f32) {
-        self.include_rect(Rectangle {
+        self.include_rect(Rect {
             x: center.x - radius,
             y: center.y - radius,
             width: radius * 2.0,
@@ -262,7 +269,7 @@ impl BoundsTracker {
         });
     }
 
-    fn include_rect(&mut self, rect: Rectangle) {
+    fn include_rect(&mut self, rect: Rect) {
         self.min_x = self.min_x.min(rect.x);
         self.min_y = self.min_y.min(rect.y);
         self.max_x = self.max_x.max(rect.x + rect.width);
@@ -279,9 +286,9 @@ impl BoundsTracker {
         }
     }
 
-    fn finish(self) -> Rectangle {
+    fn finish(self) -> Rect {
         if !self.has_content {
-            return Rectangle {
+            return Rect {
                 x: 0.0,
                 y: 0.0,
                 width: 1.0,
@@ -289,7 +296,7 @@ impl BoundsTracker {
             };
         }
 
-        Rectangle {
+        Rect {
             x: self.min_x,
             y: self.min_y,
             width: (self.max_x - self.min_x).max(1.0),
@@ -1,5 +1,8 @@
 pub mod dfa;
 pub mod nfa;
+pub mod registry;
+#[cfg(test)]
+mod snapshot;
 /// Layout algorithms for graph visualization.
 ///
 /// This module provides a pluggable strategy pattern for laying out different types
@@ -11,18 +14,21 @@ pub mod nfa;
 /// - **[`LayoutStrategy`]**: Trait defining the interface for layout algorithms
 /// - **[`NfaLayoutStrategy`]**: Hierarchical layout respecting regex bounding boxes
 /// - **[`TreeLayoutStrategy`]**: Binary tree layout for AST visualization
+/// - **[`LayoutRegistry`]**: Strategies by name, including custom ones
+///   registered at runtime through the object-safe [`DynLayoutStrategy`]
 ///
 /// The layout strategies.
 pub mod tree;
 
 pub use dfa::DfaLayoutStrategy;
-pub use nfa::NfaLayoutStrategy;
+pub use nfa::{NfaLayoutStrategy, Orientation};
+pub use registry::{DynLayoutStrategy, LayoutRegistry};
 pub use tree::TreeLayoutStrategy;
 
-use iced::Rectangle;
 use regviz_core::core::automaton::BoxKind;
+use serde::Serialize;
 
-use super::{Graph, bbox::PositionedBox, edge::PositionedEdge, node::PositionedNode};
+use crate::{Graph, PositionedBox, PositionedEdge, PositionedNode, Rect};
 
 /// Strategy pattern interface for graph layout algorithms.
 ///
@@ -32,7 +38,7 @@ use super::{Graph, bbox::PositionedBox, edge::PositionedEdge, node::PositionedNo
 /// - DFAs can use force-directed or circular layouts
 ///
 /// By abstracting the layout algorithm behind this trait, we can:
-/// - Keep [`GraphCanvas`](super::GraphCanvas) generic and reusable
+/// - Keep the renderers generic and reusable
 /// - Easily add new layout strategies without changing rendering code
 /// - Allow users to choose visualization style per graph type
 pub trait LayoutStrategy {
@@ -41,10 +47,69 @@ pub trait LayoutStrategy {
     /// # Arguments
     /// - `graph`: The graph to layout (implements [`Graph`] trait)
     /// - `visibility`: Controls which bounding boxes should be rendered (ignored by strategies that don't use boxes)
+    /// - `config`: Spacing between states, levels, branches and box borders
     ///
     /// # Returns
     /// A [`GraphLayout`] containing positioned nodes, edges, boxes, and overall bounds.
-    fn compute<G: Graph>(&self, graph: &G, visibility: &BoxVisibility) -> GraphLayout;
+    fn compute<G: Graph>(
+        &self,
+        graph: &G,
+        visibility: &BoxVisibility,
+        config: &LayoutConfig,
+    ) -> GraphLayout;
+}
+
+/// Spacing the layout strategies place elements with, so users can compact
+/// or spread out a drawing.
+///
+/// Values are in layout units. The NFA layout uses them as they are; the
+/// other strategies scale their own spacing by how far the values are from
+/// the [defaults](LayoutConfig::default).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayoutConfig {
+    /// Horizontal distance between consecutive states.
+    pub node_spacing: f32,
+    /// Vertical distance between levels of the graph.
+    pub level_spacing: f32,
+    /// Vertical gap between stacked alternation branches.
+    pub branch_gap: f32,
+    /// Space between the top or bottom of a bounding box and its contents;
+    /// the sides keep their default proportion to it.
+    pub box_padding: f32,
+}
+
+impl LayoutConfig {
+    /// Default horizontal distance between consecutive states.
+    pub const DEFAULT_NODE_SPACING: f32 = 200.0;
+    /// Default vertical distance between levels.
+    pub const DEFAULT_LEVEL_SPACING: f32 = 200.0;
+    /// Default gap between alternation branches.
+    pub const DEFAULT_BRANCH_GAP: f32 = Self::DEFAULT_LEVEL_SPACING * 0.9;
+    /// Default vertical padding of bounding boxes.
+    pub const DEFAULT_BOX_PADDING: f32 = 50.0;
+
+    /// Returns how much wider than by default states are spaced.
+    #[must_use]
+    pub fn horizontal_scale(&self) -> f32 {
+        self.node_spacing / Self::DEFAULT_NODE_SPACING
+    }
+
+    /// Returns how much taller than by default levels are spaced.
+    #[must_use]
+    pub fn vertical_scale(&self) -> f32 {
+        self.level_spacing / Self::DEFAULT_LEVEL_SPACING
+    }
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        Self {
+            node_spacing: Self::DEFAULT_NODE_SPACING,
+            level_spacing: Self::DEFAULT_LEVEL_SPACING,
+            branch_gap: Self::DEFAULT_BRANCH_GAP,
+            box_padding: Self::DEFAULT_BOX_PADDING,
+        }
+    }
 }
 
 /// Controls which bounding boxes are rendered on the canvas.
@@ -60,6 +125,8 @@ pub struct BoxVisibility {
     kleene_star: bool,
     kleene_plus: bool,
     optional: bool,
+    /// Deepest nesting level shown, where root boxes are at depth 0.
+    max_depth: Option<usize>,
 }
 
 impl Default for BoxVisibility {
@@ -71,6 +138,7 @@ impl Default for BoxVisibility {
             kleene_star: true,
             kleene_plus: true,
             optional: true,
+            max_depth: None,
         }
     }
 }
@@ -85,6 +153,7 @@ impl BoxVisibility {
             kleene_star: false,
             kleene_plus: false,
             optional: false,
+            max_depth: None,
         }
     }
 
@@ -101,6 +170,23 @@ impl BoxVisibility {
         }
     }
 
+    /// Returns the deepest nesting level shown, or `None` when every level is.
+    #[must_use]
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    /// Hides boxes nested deeper than `max_depth` (`None` shows every level).
+    pub fn set_max_depth(&mut self, max_depth: Option<usize>) {
+        self.max_depth = max_depth;
+    }
+
+    /// Returns whether boxes nested at `depth` should be shown.
+    #[must_use]
+    pub fn shows_depth(&self, depth: usize) -> bool {
+        self.max_depth.is_none_or(|max| depth <= max)
+    }
+
     /// Flips the visibility of the provided [`BoxKind`].
     pub fn toggle(&mut self, kind: BoxKind) {
         match kind {
@@ -118,7 +204,7 @@ impl BoxVisibility {
 ///
 /// Contains all visual elements (nodes, edges, boxes) with their final screen positions
 /// and the overall bounding rectangle needed to size the canvas appropriately.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GraphLayout {
     /// All positioned bounding boxes (drawn first, behind nodes/edges).
     pub boxes: Vec<PositionedBox>,
@@ -127,5 +213,5 @@ pub struct GraphLayout {
     /// All positioned edges (transitions between states).
     pub edges: Vec<PositionedEdge>,
     /// Logical bounds of the layout prior to zooming/panning.
-    pub bounds: Rectangle,
+    pub bounds: Rect,
 }
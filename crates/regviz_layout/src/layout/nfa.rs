@@ -15,7 +15,10 @@
 ///    - Unary operators (*, +, ?): Child centered with operator states on sides
 /// 3. **Normalization**: Convert each fragment to positive coordinates with padding
 /// 4. **Composition**: Stack root boxes vertically, merge all state positions
-/// 5. **Rendering**: Compute visual rectangles for boxes, create positioned nodes/edges
+/// 5. **Collision resolution**: Push apart sibling fragments whose padded boxes overlap
+/// 6. **Rendering**: Compute visual rectangles for boxes, create positioned nodes/edges
+/// 7. **Edge routing**: Detour edges that would cut through states around the fragments in their way
+/// 8. **Orientation**: Mirror or transpose the left-to-right layout so it flows in the requested direction
 ///
 /// # Example
 ///
@@ -29,14 +32,15 @@
 /// - `BoxHierarchy`: Parent-child relationships between bounding boxes
 /// - `BoxLayoutResult`: Intermediate layout with local coordinates and entry/exit points
 /// - [`GraphLayout`](super::GraphLayout): Final positioned elements ready for rendering
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
 
-use iced::{Point, Rectangle};
-use regviz_core::core::automaton::{BoxId, BoxKind, StateId};
+use regviz_core::core::automaton::{BoundingBox, BoxId, BoxKind, StateId};
 
-use super::{BoxVisibility, GraphLayout, LayoutStrategy};
-use crate::graph::{
-    Graph, GraphBox, GraphNode, bbox::PositionedBox, edge::PositionedEdge, node::PositionedNode,
+use super::{BoxVisibility, GraphLayout, LayoutConfig, LayoutStrategy};
+use crate::{
+    EdgeCurve, Graph, GraphBox, GraphNode, Point, PositionedBox, PositionedEdge, PositionedNode,
+    Rect,
 };
 
 /// NFA-specific hierarchical layout strategy.
@@ -59,47 +63,185 @@ use crate::graph::{
 /// 2. Apply operator-specific positioning rules
 /// 3. Normalize coordinates to positive quadrant with padding
 /// 4. Compose fragments into final layout
+/// 5. Turn the composed layout to the configured [`Orientation`]
 #[derive(Debug, Clone, Copy, Default)]
-pub struct NfaLayoutStrategy;
+pub struct NfaLayoutStrategy {
+    /// Direction the automaton flows in.
+    orientation: Orientation,
+}
+
+impl NfaLayoutStrategy {
+    /// Sets the direction the automaton flows in.
+    ///
+    /// # Arguments
+    /// - `orientation` (`Orientation`) - The direction from the start state to the accepting state.
+    ///
+    /// # Returns
+    /// - `NfaLayoutStrategy` - The strategy laying automata out in that direction.
+    #[must_use]
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Returns the direction the automaton flows in.
+    #[must_use]
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+}
 
 impl LayoutStrategy for NfaLayoutStrategy {
     fn compute<G: Graph>(
         &self,
         graph: &G,
         visibility: &super::BoxVisibility,
+        config: &LayoutConfig,
     ) -> super::GraphLayout {
-        layout_graph(graph, visibility)
+        layout_graph(graph, visibility, Spacing::new(config), self.orientation)
+    }
+}
+
+/// Direction an NFA flows in, from its start state towards its accepting state.
+///
+/// Fragments are always composed along a left-to-right flow axis, with
+/// alternation branches stacked across it; the orientation then decides how
+/// those two axes map onto the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    /// Flows to the right, with alternation branches stacked vertically.
+    #[default]
+    LeftToRight,
+    /// Flows to the left, mirroring the left-to-right layout.
+    RightToLeft,
+    /// Flows downward, with alternation branches side by side.
+    TopToBottom,
+}
+
+impl Orientation {
+    /// Every orientation, in the order they are offered to users.
+    pub const ALL: [Self; 3] = [Self::LeftToRight, Self::RightToLeft, Self::TopToBottom];
+
+    /// Maps a point between the left-to-right layout and this orientation.
+    ///
+    /// Every mapping is its own inverse, so the same call also maps a point of
+    /// this orientation back to the left-to-right layout.
+    ///
+    /// # Arguments
+    /// - `point` (`Point`) - The point to map.
+    ///
+    /// # Returns
+    /// - `Point` - The mapped point.
+    #[must_use]
+    pub fn map(self, point: Point) -> Point {
+        match self {
+            Self::LeftToRight => point,
+            Self::RightToLeft => Point::new(-point.x, point.y),
+            Self::TopToBottom => Point::new(point.y, point.x),
+        }
+    }
+
+    /// Maps a rectangle between the left-to-right layout and this orientation.
+    fn map_rect(self, rect: Rect) -> Rect {
+        Rect::enclosing([
+            self.map(Point::new(rect.x, rect.y)),
+            self.map(Point::new(rect.x + rect.width, rect.y + rect.height)),
+        ])
+    }
+
+    /// Turns a layout composed left to right into this orientation.
+    fn apply(self, layout: &mut GraphLayout) {
+        if self == Self::LeftToRight {
+            return;
+        }
+        for node in &mut layout.nodes {
+            node.position = self.map(node.position);
+        }
+        // Rebuild the edges so their labels are anchored to the mapped segments
+        layout.edges = std::mem::take(&mut layout.edges)
+            .into_iter()
+            .map(|edge| {
+                let mut data = edge.data;
+                // Both mappings are reflections, which move a curve to the other side of its edge
+                data.curve = match data.curve {
+                    EdgeCurve::CurveDown => EdgeCurve::CurveUp,
+                    EdgeCurve::CurveUp => EdgeCurve::CurveDown,
+                    curve => curve,
+                };
+                let route = edge
+                    .route
+                    .into_iter()
+                    .map(|point| self.map(point))
+                    .collect();
+                PositionedEdge::with_radii(
+                    data,
+                    self.map(edge.from),
+                    self.map(edge.to),
+                    edge.from_radius,
+                    edge.to_radius,
+                )
+                .with_route(route)
+            })
+            .collect();
+        for bbox in &mut layout.boxes {
+            bbox.set_rect(self.map_rect(bbox.rect));
+        }
+        layout.bounds = self.map_rect(layout.bounds);
     }
 }
 
-/// Horizontal distance between consecutive nodes on the same level.
-const NODE_SPACING_X: f32 = 200.0;
-/// Vertical distance between levels of the automaton.
-const LEVEL_SPACING_Y: f32 = 200.0;
 /// Radius used when drawing states.
 const NODE_RADIUS: f32 = 32.0;
-/// Horizontal padding applied to bounding boxes.
-const BOX_PADDING_X: f32 = 30.0;
-/// Vertical padding applied to bounding boxes.
-const BOX_PADDING_Y: f32 = 50.0;
-/// Gap inserted between fragments when laying out concatenations.
-const INLINE_GAP_X: f32 = NODE_SPACING_X * INLINE_GAP_RATIO;
-/// Distance inserted between stacked alternation branches.
-const BRANCH_GAP_Y: f32 = LEVEL_SPACING_Y * BRANCH_GAP_RATIO;
+/// Horizontal padding applied to bounding boxes at the default box padding.
+const DEFAULT_BOX_PADDING_X: f32 = 30.0;
 /// Multiplier converting node spacing into inline gaps.
 const INLINE_GAP_RATIO: f32 = 0.7;
-/// Multiplier converting level spacing into branch gaps.
-const BRANCH_GAP_RATIO: f32 = 0.9;
 /// Additional padding applied around the entire layout once nodes are placed.
 const LAYOUT_PADDING_RATIO: f32 = 0.25;
 /// Ratio used when normalising child layouts to keep content away from the border horizontally.
 const NORMALIZE_PADDING_X_RATIO: f32 = 0.2;
 /// Ratio used when normalising child layouts to keep content away from the border vertically.
 const NORMALIZE_PADDING_Y_RATIO: f32 = 0.3;
-/// Minimum content width retained during normalisation relative to [`NODE_SPACING_X`].
+/// Minimum content width retained during normalisation relative to the node spacing.
 const MIN_CONTENT_WIDTH_RATIO: f32 = 0.3;
-/// Minimum content height retained during normalisation relative to [`LEVEL_SPACING_Y`].
+/// Minimum content height retained during normalisation relative to the level spacing.
 const MIN_CONTENT_HEIGHT_RATIO: f32 = 0.3;
+/// Space kept between the boxes of sibling fragments, relative to the box padding.
+const SIBLING_GAP_RATIO: f32 = 0.5;
+/// Space kept between a routed edge and the fragments it goes around, relative to the box padding.
+const ROUTE_CLEARANCE_RATIO: f32 = 0.5;
+
+/// Distances the layout places states and boxes with, derived from a [`LayoutConfig`].
+#[derive(Debug, Clone, Copy)]
+struct Spacing {
+    /// Horizontal distance between consecutive nodes on the same level.
+    node_x: f32,
+    /// Vertical distance between levels of the automaton.
+    level_y: f32,
+    /// Horizontal padding applied to bounding boxes.
+    box_padding_x: f32,
+    /// Vertical padding applied to bounding boxes.
+    box_padding_y: f32,
+    /// Gap inserted between fragments when laying out concatenations.
+    inline_gap_x: f32,
+    /// Distance inserted between stacked alternation branches.
+    branch_gap_y: f32,
+}
+
+impl Spacing {
+    fn new(config: &LayoutConfig) -> Self {
+        Self {
+            node_x: config.node_spacing,
+            level_y: config.level_spacing,
+            // The sides keep their default proportion to the top and bottom.
+            box_padding_x: config.box_padding * DEFAULT_BOX_PADDING_X
+                / LayoutConfig::DEFAULT_BOX_PADDING,
+            box_padding_y: config.box_padding,
+            inline_gap_x: config.node_spacing * INLINE_GAP_RATIO,
+            branch_gap_y: config.branch_gap,
+        }
+    }
+}
 
 /// Represents the hierarchy of bounding boxes produced by the backend.
 ///
@@ -178,7 +320,7 @@ impl BoundsTracker {
     }
 
     fn include_circle(&mut self, center: Point, radius: f32) {
-        self.include_rect(Rectangle {
+        self.include_rect(Rect {
             x: center.x - radius,
             y: center.y - radius,
             width: radius * 2.0,
@@ -186,7 +328,7 @@ impl BoundsTracker {
         });
     }
 
-    fn include_rect(&mut self, rect: Rectangle) {
+    fn include_rect(&mut self, rect: Rect) {
         self.min_x = self.min_x.min(rect.x);
         self.min_y = self.min_y.min(rect.y);
         self.max_x = self.max_x.max(rect.x + rect.width);
@@ -203,9 +345,9 @@ impl BoundsTracker {
         }
     }
 
-    fn finish(self) -> Rectangle {
+    fn finish(self) -> Rect {
         if !self.has_content {
-            return Rectangle {
+            return Rect {
                 x: 0.0,
                 y: 0.0,
                 width: 1.0,
@@ -213,7 +355,7 @@ impl BoundsTracker {
             };
         }
 
-        Rectangle {
+        Rect {
             x: self.min_x,
             y: self.min_y,
             width: (self.max_x - self.min_x).max(1.0),
@@ -229,10 +371,13 @@ impl BoundsTracker {
 /// # Algorithm Overview
 /// 1. **Build hierarchy**: Organize bounding boxes into a parent-child tree structure
 /// 2. **Position states**: Recursively layout states within each bounding box based on regex operators
-/// 3. **Fallback positioning**: Place any orphaned states that aren't in bounding boxes
-/// 4. **Create positioned elements**: Convert logical positions to renderable nodes and edges
-/// 5. **Compute bounding boxes**: Calculate rectangles for visible bounding boxes
-/// 6. **Track bounds**: Determine the overall canvas size needed for the layout
+/// 3. **Resolve collisions**: Push apart sibling fragments whose boxes overlap
+/// 4. **Fallback positioning**: Place any orphaned states that aren't in bounding boxes
+/// 5. **Create positioned nodes**: Convert logical positions to renderable nodes
+/// 6. **Compute box rectangles**: Calculate the extent of every bounding box
+/// 7. **Create positioned edges**: Connect the states, routing edges around obstacles
+/// 8. **Collect visible boxes**: Keep the rectangles of the boxes that should be shown
+/// 9. **Track bounds**: Determine the overall canvas size needed for the layout
 ///
 /// # Example Flow
 /// For the regex `a+b`, this function will:
@@ -241,7 +386,12 @@ impl BoundsTracker {
 /// - Position entry/exit states for the alternation
 /// - Calculate the overall bounds for rendering
 #[must_use]
-fn layout_graph<G: Graph>(graph: &G, visibility: &super::BoxVisibility) -> super::GraphLayout {
+fn layout_graph<G: Graph>(
+    graph: &G,
+    visibility: &super::BoxVisibility,
+    spacing: Spacing,
+    orientation: Orientation,
+) -> super::GraphLayout {
     let nodes = graph.nodes();
     let edges = graph.edges();
     let boxes = graph.boxes();
@@ -252,22 +402,27 @@ fn layout_graph<G: Graph>(graph: &G, visibility: &super::BoxVisibility) -> super
 
     // Step 2: Recursively position all states within their bounding boxes
     // This handles literals, concatenations, alternations, and unary operators
-    let vertical_offset = assign_box_positions(&hierarchy, &mut state_positions);
+    let vertical_offset = assign_box_positions(&hierarchy, &mut state_positions, spacing);
 
-    // Step 3: Position any states that aren't part of a bounding box
+    // Step 3: Push apart sibling fragments whose padded boxes ended up overlapping
+    let inserted_y = separate_sibling_fragments(&hierarchy, &mut state_positions, spacing);
+
+    // Step 4: Position any states that aren't part of a bounding box
     // These get placed in a simple horizontal line below the main layout
     assign_fallback_positions(
         &nodes,
         &mut state_positions,
-        vertical_offset + LEVEL_SPACING_Y,
+        vertical_offset + inserted_y + spacing.level_y,
+        spacing,
     );
 
-    // Step 4: Convert state positions into renderable nodes with visual properties.
+    // Step 5: Convert state positions into renderable nodes with visual properties.
     // Merge any manual positions from the GraphNode objects so pinned nodes are
-    // respected by edges and rendering.
+    // respected by edges and rendering. They are given in the final orientation,
+    // so map them back to the left-to-right layout first.
     for node in &nodes {
         if let Some(manual) = node.manual_position {
-            state_positions.insert(node.id, manual);
+            state_positions.insert(node.id, orientation.map(manual));
         }
     }
 
@@ -282,37 +437,45 @@ fn layout_graph<G: Graph>(graph: &G, visibility: &super::BoxVisibility) -> super
         }
     }
 
-    // Step 5: Create positioned edges connecting the states
-    // Only create edges if both endpoints have valid positions
+    // Step 6: Compute the rectangle of every bounding box, visible or not
+    let extents = box_extents(&hierarchy, &state_positions, spacing);
+
+    // Step 7: Create positioned edges connecting the states
+    // Only create edges if both endpoints have valid positions, and detour
+    // those that would cut through other states
+    let router = EdgeRouter::new(&hierarchy, &extents, &state_positions, spacing);
     let positioned_edges = edges
         .into_iter()
         .filter_map(|edge| {
             let from = state_positions.get(&edge.from)?;
             let to = state_positions.get(&edge.to)?;
-            Some(PositionedEdge::new(edge, *from, *to))
+            Some(router.route(PositionedEdge::new(edge, *from, *to)))
         })
         .collect::<Vec<_>>();
 
-    // Step 6: Compute rectangles for every bounding box that should be visible
-    let positioned_boxes = layout_boxes(hierarchy, state_positions, visibility);
+    // Step 8: Keep the rectangles of every bounding box that should be visible
+    let positioned_boxes = layout_boxes(hierarchy, &extents, visibility);
     for bbox in &positioned_boxes {
         bounds.include_rect(bbox.rect);
     }
 
-    // Step 7: Add padding around the entire layout for aesthetics
+    // Step 9: Add padding around the entire layout for aesthetics
     if !positioned_nodes.is_empty() {
         bounds.pad(
-            BOX_PADDING_X * LAYOUT_PADDING_RATIO,
-            BOX_PADDING_Y * LAYOUT_PADDING_RATIO,
+            spacing.box_padding_x * LAYOUT_PADDING_RATIO,
+            spacing.box_padding_y * LAYOUT_PADDING_RATIO,
         );
     }
 
-    GraphLayout {
+    // Step 10: Turn the layout to the requested orientation
+    let mut layout = GraphLayout {
         boxes: positioned_boxes,
         nodes: positioned_nodes,
         edges: positioned_edges,
         bounds: bounds.finish(),
-    }
+    };
+    orientation.apply(&mut layout);
+    layout
 }
 
 /// Recursively computes positions for the states contained within the provided bounding box.
@@ -339,6 +502,7 @@ fn compute_box_layout(
     bbox: &GraphBox,
     boxes: &HashMap<BoxId, GraphBox>,
     children: &HashMap<BoxId, Vec<BoxId>>,
+    spacing: Spacing,
 ) -> BoxLayoutResult {
     // Recursively evaluate each child first so we can stitch them together below.
     // This bottom-up approach ensures we know the size of each child before positioning it.
@@ -348,24 +512,25 @@ fn compute_box_layout(
         .flat_map(|ids| {
             ids.iter()
                 .filter_map(|child_id| boxes.get(child_id))
-                .map(|child| compute_box_layout(child, boxes, children))
+                .map(|child| compute_box_layout(child, boxes, children, spacing))
         })
         .collect::<Vec<_>>();
 
     // Apply the layout strategy appropriate for this operator
     let mut layout = match bbox.kind {
-        BoxKind::Literal => layout_literal_box(bbox),
-        BoxKind::Concat => layout_concat_box(child_layouts),
-        BoxKind::Alternation => layout_alternation_box(bbox, child_layouts),
+        _ if bbox.collapsed => layout_collapsed_box(bbox, spacing),
+        BoxKind::Literal => layout_literal_box(bbox, spacing),
+        BoxKind::Concat => layout_concat_box(child_layouts, spacing),
+        BoxKind::Alternation => layout_alternation_box(bbox, child_layouts, spacing),
         BoxKind::KleeneStar | BoxKind::KleenePlus | BoxKind::Optional => {
-            layout_unary_box(bbox, child_layouts)
+            layout_unary_box(bbox, child_layouts, spacing)
         }
     };
 
     // Normalising ensures the fragment uses a consistent local coordinate system
     // so parents can place it using its reported width/height without extra math.
     // This converts all coordinates so min(x,y) = 0 with some padding.
-    normalize_layout(&mut layout);
+    normalize_layout(&mut layout, spacing);
     layout
 }
 
@@ -408,24 +573,46 @@ struct BoxLayoutResult {
 /// start accept
 /// ```
 ///
-/// Both states are placed on the same horizontal line, separated by NODE_SPACING_X.
-fn layout_literal_box(bbox: &GraphBox) -> BoxLayoutResult {
+/// Both states are placed on the same horizontal line, separated by the node spacing.
+fn layout_literal_box(bbox: &GraphBox, spacing: Spacing) -> BoxLayoutResult {
     let mut positions = HashMap::new();
     if let Some((&start, rest)) = bbox.states.split_first() {
         // The literal fragment has two states that sit on the same horizontal line.
-        let start_pos = Point::new(0.0, LEVEL_SPACING_Y * 0.5);
+        let start_pos = Point::new(0.0, spacing.level_y * 0.5);
         positions.insert(start, start_pos);
         if let Some(&accept) = rest.last() {
-            let accept_pos = Point::new(NODE_SPACING_X, LEVEL_SPACING_Y * 0.5);
+            let accept_pos = Point::new(spacing.node_x, spacing.level_y * 0.5);
             positions.insert(accept, accept_pos);
         }
     }
 
     BoxLayoutResult {
-        width: NODE_SPACING_X,
-        height: LEVEL_SPACING_Y,
-        entry: Point::new(0.0, LEVEL_SPACING_Y * 0.5),
-        exit: Point::new(NODE_SPACING_X, LEVEL_SPACING_Y * 0.5),
+        width: spacing.node_x,
+        height: spacing.level_y,
+        entry: Point::new(0.0, spacing.level_y * 0.5),
+        exit: Point::new(spacing.node_x, spacing.level_y * 0.5),
+        positions,
+    }
+}
+
+/// Places the super-node standing for a collapsed fragment.
+///
+/// Transitions enter and leave the fragment through the same node, so the
+/// entry and exit points coincide.
+fn layout_collapsed_box(bbox: &GraphBox, spacing: Spacing) -> BoxLayoutResult {
+    let center = Point::new(0.0, spacing.level_y * 0.5);
+    let positions = bbox
+        .states
+        .first()
+        .map(|&node| (node, center))
+        .into_iter()
+        .collect();
+
+    BoxLayoutResult {
+        width: 0.0,
+        height: spacing.level_y,
+        entry: center,
+        exit: center,
         positions,
     }
 }
@@ -445,16 +632,16 @@ fn layout_literal_box(bbox: &GraphBox) -> BoxLayoutResult {
 ///
 /// # Algorithm
 /// 1. **Find baseline**: Use the maximum entry.y of all children (keeps things aligned)
-/// 2. **Place children left-to-right**: Each child is offset horizontally with INLINE_GAP_X spacing
+/// 2. **Place children left-to-right**: Each child is offset horizontally by the inline gap
 /// 3. **Align to baseline**: Offset each child vertically so its entry point sits on the baseline
 /// 4. **Track dimensions**: Record the first child's entry and last child's exit as fragment endpoints
-fn layout_concat_box(child_layouts: Vec<BoxLayoutResult>) -> BoxLayoutResult {
+fn layout_concat_box(child_layouts: Vec<BoxLayoutResult>, spacing: Spacing) -> BoxLayoutResult {
     if child_layouts.is_empty() {
         return BoxLayoutResult {
-            width: NODE_SPACING_X,
-            height: LEVEL_SPACING_Y,
-            entry: Point::new(0.0, LEVEL_SPACING_Y * 0.5),
-            exit: Point::new(NODE_SPACING_X, LEVEL_SPACING_Y * 0.5),
+            width: spacing.node_x,
+            height: spacing.level_y,
+            entry: Point::new(0.0, spacing.level_y * 0.5),
+            exit: Point::new(spacing.node_x, spacing.level_y * 0.5),
             positions: HashMap::new(),
         };
     }
@@ -466,7 +653,7 @@ fn layout_concat_box(child_layouts: Vec<BoxLayoutResult>) -> BoxLayoutResult {
         .map(|child| child.entry.y)
         .fold(0.0, f32::max);
     if baseline.abs() < f32::EPSILON {
-        baseline = LEVEL_SPACING_Y * 0.5;
+        baseline = spacing.level_y * 0.5;
     }
 
     let mut positions = HashMap::new();
@@ -496,7 +683,7 @@ fn layout_concat_box(child_layouts: Vec<BoxLayoutResult>) -> BoxLayoutResult {
         // Move cursor to the right for the next child
         cursor_x += child.width;
         if index + 1 < count {
-            cursor_x += INLINE_GAP_X; // Add gap between children
+            cursor_x += spacing.inline_gap_x; // Add gap between children
         }
 
         // Track the maximum height needed
@@ -505,7 +692,7 @@ fn layout_concat_box(child_layouts: Vec<BoxLayoutResult>) -> BoxLayoutResult {
 
     BoxLayoutResult {
         width: cursor_x,
-        height: max_bottom.max(baseline + LEVEL_SPACING_Y * 0.25),
+        height: max_bottom.max(baseline + spacing.level_y * 0.25),
         entry,
         exit,
         positions,
@@ -527,11 +714,15 @@ fn layout_concat_box(child_layouts: Vec<BoxLayoutResult>) -> BoxLayoutResult {
 /// ```
 ///
 /// # Algorithm
-/// 1. **Stack branches vertically**: Each child fragment placed below the previous with BRANCH_GAP_Y spacing
+/// 1. **Stack branches vertically**: Each child fragment placed below the previous with the branch gap in between
 /// 2. **Track maximum width**: Need to know widest branch for positioning exit state
 /// 3. **Center entry/exit**: Place entry/exit states at vertical midpoint, horizontally on the sides
 /// 4. **Add operator states**: The first/last states in bbox.states become the entry/exit nodes
-fn layout_alternation_box(bbox: &GraphBox, child_layouts: Vec<BoxLayoutResult>) -> BoxLayoutResult {
+fn layout_alternation_box(
+    bbox: &GraphBox,
+    child_layouts: Vec<BoxLayoutResult>,
+    spacing: Spacing,
+) -> BoxLayoutResult {
     let mut positions = HashMap::new();
     let mut current_y = 0.0; // Current vertical position for placing next branch
     let mut max_width: f32 = 0.0; // Widest branch determines exit position
@@ -540,23 +731,23 @@ fn layout_alternation_box(bbox: &GraphBox, child_layouts: Vec<BoxLayoutResult>)
     // Each branch becomes a vertically stacked child, separated by a branch gap.
     for (index, child) in child_layouts.into_iter().enumerate() {
         // Offset horizontally to leave room for entry state, vertically to stack branches
-        let offset = Point::new(NODE_SPACING_X, current_y);
+        let offset = Point::new(spacing.node_x, current_y);
         max_width = max_width.max(child.width);
         merge_positions(&mut positions, child.positions, offset);
 
         // Move down for next branch
         current_y += child.height;
         if index + 1 < count {
-            current_y += BRANCH_GAP_Y; // Add vertical gap between branches
+            current_y += spacing.branch_gap_y; // Add vertical gap between branches
         }
     }
 
-    let total_height = current_y.max(LEVEL_SPACING_Y);
+    let total_height = current_y.max(spacing.level_y);
     let entry_y = total_height * 0.5; // Center entry/exit vertically
 
     // Entry state on the left, exit state on the right
     let entry = Point::new(0.0, entry_y);
-    let exit = Point::new(NODE_SPACING_X + max_width + NODE_SPACING_X, entry_y);
+    let exit = Point::new(spacing.node_x + max_width + spacing.node_x, entry_y);
 
     // Add the alternation's entry and exit states
     if let Some(start) = bbox.states.first() {
@@ -603,7 +794,11 @@ fn layout_alternation_box(bbox: &GraphBox, child_layouts: Vec<BoxLayoutResult>)
 ///
 /// # Returns
 /// A layout with the child centered and operator states positioned to show control flow
-fn layout_unary_box(bbox: &GraphBox, mut child_layouts: Vec<BoxLayoutResult>) -> BoxLayoutResult {
+fn layout_unary_box(
+    bbox: &GraphBox,
+    mut child_layouts: Vec<BoxLayoutResult>,
+    spacing: Spacing,
+) -> BoxLayoutResult {
     let mut positions = HashMap::new();
     let child = child_layouts.pop();
 
@@ -618,17 +813,17 @@ fn layout_unary_box(bbox: &GraphBox, mut child_layouts: Vec<BoxLayoutResult>) ->
             )
         } else {
             (
-                NODE_SPACING_X,
-                LEVEL_SPACING_Y,
-                Point::new(0.0, LEVEL_SPACING_Y * 0.5),
-                Point::new(NODE_SPACING_X, LEVEL_SPACING_Y * 0.5),
+                spacing.node_x,
+                spacing.level_y,
+                Point::new(0.0, spacing.level_y * 0.5),
+                Point::new(spacing.node_x, spacing.level_y * 0.5),
                 HashMap::new(),
             )
         };
 
     // Position child fragment in the center, with vertical padding for epsilon arcs
-    let vertical_padding = LEVEL_SPACING_Y * 0.4; // Space for curved epsilon transitions
-    let offset = Point::new(NODE_SPACING_X, vertical_padding);
+    let vertical_padding = spacing.level_y * 0.4; // Space for curved epsilon transitions
+    let offset = Point::new(spacing.node_x, vertical_padding);
     merge_positions(&mut positions, child_positions, offset);
 
     // Entry state on the left, centered vertically in the available space
@@ -637,7 +832,7 @@ fn layout_unary_box(bbox: &GraphBox, mut child_layouts: Vec<BoxLayoutResult>) ->
     let entry = Point::new(0.0, entry_y);
 
     // Exit state on the right, aligned with entry
-    let exit = Point::new(offset.x + child_width + NODE_SPACING_X, entry_y);
+    let exit = Point::new(offset.x + child_width + spacing.node_x, entry_y);
 
     // Add the operator's entry and exit states
     if let Some(start) = bbox.states.first() {
@@ -700,7 +895,7 @@ fn merge_positions(
 /// 2. **Calculate shift**: Determine how much to translate to move min to padding
 /// 3. **Apply shift**: Add shift to all positions, entry, and exit points
 /// 4. **Update dimensions**: Set width/height based on content size + padding
-fn normalize_layout(layout: &mut BoxLayoutResult) {
+fn normalize_layout(layout: &mut BoxLayoutResult, spacing: Spacing) {
     // Step 1: Find the current bounding box of all content
     let mut min_x = f32::INFINITY;
     let mut max_x = f32::NEG_INFINITY;
@@ -723,10 +918,10 @@ fn normalize_layout(layout: &mut BoxLayoutResult) {
 
     // Step 2: Handle empty fragments (no valid positions)
     if !min_x.is_finite() || !min_y.is_finite() {
-        layout.width = NODE_SPACING_X;
-        layout.height = LEVEL_SPACING_Y;
-        layout.entry = Point::new(0.0, LEVEL_SPACING_Y * 0.5);
-        layout.exit = Point::new(NODE_SPACING_X, LEVEL_SPACING_Y * 0.5);
+        layout.width = spacing.node_x;
+        layout.height = spacing.level_y;
+        layout.entry = Point::new(0.0, spacing.level_y * 0.5);
+        layout.exit = Point::new(spacing.node_x, spacing.level_y * 0.5);
         layout.positions.clear();
         return;
     }
@@ -735,10 +930,10 @@ fn normalize_layout(layout: &mut BoxLayoutResult) {
     // Normalisation converts the fragment so that its minimum x/y become `0` with
     // a little padding. This keeps every fragment in the positive quadrant and
     // prevents parents from having to apply ad-hoc offsets when composing.
-    let horizontal_padding = NODE_SPACING_X * NORMALIZE_PADDING_X_RATIO;
-    let vertical_padding = LEVEL_SPACING_Y * NORMALIZE_PADDING_Y_RATIO;
-    let content_width = (max_x - min_x).max(NODE_SPACING_X * MIN_CONTENT_WIDTH_RATIO);
-    let content_height = (max_y - min_y).max(LEVEL_SPACING_Y * MIN_CONTENT_HEIGHT_RATIO);
+    let horizontal_padding = spacing.node_x * NORMALIZE_PADDING_X_RATIO;
+    let vertical_padding = spacing.level_y * NORMALIZE_PADDING_Y_RATIO;
+    let content_width = (max_x - min_x).max(spacing.node_x * MIN_CONTENT_WIDTH_RATIO);
+    let content_height = (max_y - min_y).max(spacing.level_y * MIN_CONTENT_HEIGHT_RATIO);
 
     // Calculate how much to shift to move min to the padding offset
     let shift_x = -min_x + horizontal_padding;
@@ -764,9 +959,8 @@ fn normalize_layout(layout: &mut BoxLayoutResult) {
 /// Builds rectangles for every bounding box that should be displayed.
 ///
 /// This function determines which boxes to render and in what order:
-/// 1. Compute extents (rectangles) for all boxes
-/// 2. Filter to only visible boxes based on user preferences
-/// 3. Sort by depth (deeper boxes first) for proper layering
+/// 1. Filter to only visible boxes based on user preferences
+/// 2. Sort by depth (deeper boxes first) for proper layering
 ///
 /// # Rendering Order
 /// Boxes are drawn back-to-front (deepest first) so that:
@@ -775,16 +969,10 @@ fn normalize_layout(layout: &mut BoxLayoutResult) {
 /// - Overlapping boxes render correctly
 fn layout_boxes(
     hierarchy: BoxHierarchy,
-    state_positions: HashMap<StateId, Point>,
+    extents: &HashMap<BoxId, Rect>,
     visibility: &BoxVisibility,
 ) -> Vec<PositionedBox> {
-    // Step 1: Compute rectangles for all boxes (with caching)
-    let mut extents: HashMap<BoxId, Rectangle> = HashMap::new();
-    for id in hierarchy.map.keys().copied().collect::<Vec<_>>() {
-        compute_extent(id, &hierarchy, &state_positions, &mut extents);
-    }
-
-    // Step 2: Filter to visible boxes and annotate with depth
+    // Step 1: Filter to visible boxes and annotate with depth
     let mut depth_cache: HashMap<BoxId, usize> = HashMap::new();
     let mut positioned = Vec::new();
 
@@ -796,17 +984,36 @@ fn layout_boxes(
 
         if let Some(rect) = extents.get(&id) {
             let depth = compute_depth(id, &hierarchy.parents, &mut depth_cache);
-            positioned.push((depth, id, PositionedBox::new(data, *rect)));
+            // Skip boxes nested deeper than the user wants to see
+            if visibility.shows_depth(depth) {
+                positioned.push((depth, id, PositionedBox::new(data, *rect).with_depth(depth)));
+            }
         }
     }
 
-    // Step 3: Sort by depth (deeper first), then by ID for determinism
+    // Step 2: Sort by depth (deeper first), then by ID for determinism
     positioned.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
 
-    // Return just the positioned boxes (drop depth and id used for sorting)
+    // Return just the positioned boxes (drop the sort keys, boxes keep their depth)
     positioned.into_iter().map(|(_, _, pb)| pb).collect()
 }
 
+/// Computes the rectangles of all boxes in the hierarchy.
+///
+/// Boxes without any positioned state, such as the children of a collapsed
+/// box, have no rectangle.
+fn box_extents(
+    hierarchy: &BoxHierarchy,
+    state_positions: &HashMap<StateId, Point>,
+    spacing: Spacing,
+) -> HashMap<BoxId, Rect> {
+    let mut extents: HashMap<BoxId, Rect> = HashMap::new();
+    for id in hierarchy.map.keys() {
+        compute_extent(*id, hierarchy, state_positions, spacing, &mut extents);
+    }
+    extents
+}
+
 /// Computes the rectangle that encloses a bounding box and all of its visible children.
 ///
 /// This function recursively calculates the visual extent of a bounding box by:
@@ -818,14 +1025,15 @@ fn layout_boxes(
 /// The result is cached to avoid redundant computation when boxes are reused.
 ///
 /// # Returns
-/// A `Rectangle` that completely encloses the box and all its contents with padding,
+/// A `Rect` that completely encloses the box and all its contents with padding,
 /// or `None` if the box has no positioned states or children.
 fn compute_extent(
     id: BoxId,
     hierarchy: &BoxHierarchy,
     state_positions: &HashMap<StateId, Point>,
-    cache: &mut HashMap<BoxId, Rectangle>,
-) -> Option<Rectangle> {
+    spacing: Spacing,
+    cache: &mut HashMap<BoxId, Rect>,
+) -> Option<Rect> {
     // Check cache first - avoid recomputing the same box multiple times
     if let Some(rect) = cache.get(&id) {
         return Some(*rect);
@@ -853,7 +1061,9 @@ fn compute_extent(
     // Recursively expand bounds to include all child boxes
     if let Some(children_ids) = hierarchy.children.get(&id) {
         for child_id in children_ids {
-            if let Some(child_rect) = compute_extent(*child_id, hierarchy, state_positions, cache) {
+            if let Some(child_rect) =
+                compute_extent(*child_id, hierarchy, state_positions, spacing, cache)
+            {
                 // Include the entire child rectangle
                 min_x = min_x.min(child_rect.x);
                 min_y = min_y.min(child_rect.y);
@@ -869,11 +1079,11 @@ fn compute_extent(
     }
 
     // Create the final rectangle with padding around the content
-    let rect = Rectangle {
-        x: min_x - BOX_PADDING_X,
-        y: min_y - BOX_PADDING_Y,
-        width: (max_x - min_x) + BOX_PADDING_X * 2.0,
-        height: (max_y - min_y) + BOX_PADDING_Y * 2.0,
+    let rect = Rect {
+        x: min_x - spacing.box_padding_x,
+        y: min_y - spacing.box_padding_y,
+        width: (max_x - min_x) + spacing.box_padding_x * 2.0,
+        height: (max_y - min_y) + spacing.box_padding_y * 2.0,
     };
 
     // Cache the result for future lookups
@@ -916,6 +1126,25 @@ fn compute_depth(
     depth
 }
 
+/// Counts the nesting levels of the provided bounding boxes.
+///
+/// # Arguments
+/// - `boxes` (`&[BoundingBox]`) - The boxes recorded while building an NFA.
+///
+/// # Returns
+/// - `usize` - One for root boxes alone, plus one per level nested inside them;
+///   zero when there are no boxes.
+#[must_use]
+pub fn nesting_levels(boxes: &[BoundingBox]) -> usize {
+    let parents = boxes.iter().map(|bbox| (bbox.id, bbox.parent)).collect();
+    let mut cache = HashMap::new();
+    boxes
+        .iter()
+        .map(|bbox| compute_depth(bbox.id, &parents, &mut cache) + 1)
+        .max()
+        .unwrap_or(0)
+}
+
 /// Positions all states within their bounding boxes by processing the hierarchy top-down.
 ///
 /// This function iterates through all root-level bounding boxes and recursively
@@ -935,6 +1164,7 @@ fn compute_depth(
 fn assign_box_positions(
     hierarchy: &BoxHierarchy,
     state_positions: &mut HashMap<StateId, Point>,
+    spacing: Spacing,
 ) -> f32 {
     let mut vertical_offset = 0.0;
 
@@ -943,7 +1173,7 @@ fn assign_box_positions(
         if let Some(layout) = hierarchy
             .map
             .get(root_id)
-            .map(|bbox| compute_box_layout(bbox, &hierarchy.map, &hierarchy.children))
+            .map(|bbox| compute_box_layout(bbox, &hierarchy.map, &hierarchy.children, spacing))
         {
             // Add all state positions from this box's layout, offset vertically
             for (state, pos) in layout.positions {
@@ -952,13 +1182,290 @@ fn assign_box_positions(
             }
 
             // Move down for the next root box
-            vertical_offset += layout.height + LEVEL_SPACING_Y;
+            vertical_offset += layout.height + spacing.level_y;
         }
     }
 
     vertical_offset
 }
 
+/// Pushes apart sibling fragments whose boxes overlap once drawn.
+///
+/// Fragments are stacked using their reported size, but the box drawn around
+/// a fragment is padded once per nesting level, so the boxes of deeply nested
+/// branches (e.g. alternations inside stars inside an alternation) can reach
+/// into their neighbours.
+///
+/// # Algorithm
+/// Boxes are visited from the deepest up. For each alternation (branches
+/// stacked vertically) or concatenation (children placed horizontally), the
+/// children are taken in order along that axis, and wherever the box of one
+/// child reaches past the start of the next one's minus a small gap, space is
+/// inserted in front of the next child: every state from the next child's
+/// first state onwards moves by the overlap. Inserting space keeps the order
+/// of all states along the axis, so it never creates a new overlap, and a
+/// parent visited later sees its children already separated.
+///
+/// # Returns
+/// The total vertical space inserted, so states placed below the layout can
+/// be moved out of the way as well.
+fn separate_sibling_fragments(
+    hierarchy: &BoxHierarchy,
+    state_positions: &mut HashMap<StateId, Point>,
+    spacing: Spacing,
+) -> f32 {
+    let mut depths = HashMap::new();
+    let mut ids: Vec<BoxId> = hierarchy.map.keys().copied().collect();
+    ids.sort_by_key(|&id| {
+        (
+            Reverse(compute_depth(id, &hierarchy.parents, &mut depths)),
+            id,
+        )
+    });
+
+    let mut inserted_y = 0.0;
+    for id in ids {
+        let bbox = &hierarchy.map[&id];
+        let vertical = match bbox.kind {
+            _ if bbox.collapsed => continue,
+            BoxKind::Alternation => true,
+            BoxKind::Concat => false,
+            _ => continue,
+        };
+        let Some(children) = hierarchy.children.get(&id) else {
+            continue;
+        };
+        let gap = if vertical {
+            spacing.box_padding_y
+        } else {
+            spacing.box_padding_x
+        } * SIBLING_GAP_RATIO;
+        let along = |point: &Point| if vertical { point.y } else { point.x };
+
+        // Every insertion moves the children after it alike, so one pass in
+        // order separates them all.
+        let mut order: Vec<(BoxId, f32)> = children
+            .iter()
+            .filter_map(|&child| {
+                let first = fragment_states(child, hierarchy)
+                    .iter()
+                    .filter_map(|state| state_positions.get(state))
+                    .map(along)
+                    .reduce(f32::min)?;
+                Some((child, first))
+            })
+            .collect();
+        order.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        for pair in order.windows(2) {
+            let ((previous, _), (next, _)) = (pair[0], pair[1]);
+            let mut extents = HashMap::new();
+            let (Some(previous_rect), Some(next_rect)) = (
+                compute_extent(previous, hierarchy, state_positions, spacing, &mut extents),
+                compute_extent(next, hierarchy, state_positions, spacing, &mut extents),
+            ) else {
+                continue;
+            };
+            let (previous_end, next_start) = if vertical {
+                (previous_rect.y + previous_rect.height, next_rect.y)
+            } else {
+                (previous_rect.x + previous_rect.width, next_rect.x)
+            };
+            let overlap = previous_end + gap - next_start;
+            if overlap <= 0.0 {
+                continue;
+            }
+
+            let Some(threshold) = fragment_states(next, hierarchy)
+                .iter()
+                .filter_map(|state| state_positions.get(state))
+                .map(along)
+                .reduce(f32::min)
+            else {
+                continue;
+            };
+            for position in state_positions.values_mut() {
+                if vertical && position.y >= threshold {
+                    position.y += overlap;
+                } else if !vertical && position.x >= threshold {
+                    position.x += overlap;
+                }
+            }
+            if vertical {
+                inserted_y += overlap;
+            }
+        }
+    }
+    inserted_y
+}
+
+/// Returns the states of a box and of every box nested in it.
+fn fragment_states(id: BoxId, hierarchy: &BoxHierarchy) -> Vec<StateId> {
+    let mut states = Vec::new();
+    let mut pending = vec![id];
+    while let Some(id) = pending.pop() {
+        if let Some(bbox) = hierarchy.map.get(&id) {
+            states.extend_from_slice(&bbox.states);
+        }
+        if let Some(children) = hierarchy.children.get(&id) {
+            pending.extend_from_slice(children);
+        }
+    }
+    states
+}
+
+/// Detours edges that would cut through states around the fragments in their way.
+///
+/// An edge stays inside the smallest box holding both of its states, so the
+/// boxes nested in that one, and the states they hold, are its obstacles.
+/// When the straight line between the two states passes through another
+/// state, the edge leaves its source vertically, runs along a horizontal lane
+/// past every obstacle between the two states, and comes back to its target.
+/// The lane lies in the padding of the enclosing box, which is the corridor
+/// between its border and the fragments nested in it.
+struct EdgeRouter<'a> {
+    /// Rectangles of the boxes, from [`box_extents`].
+    extents: &'a HashMap<BoxId, Rect>,
+    /// Final positions of the states.
+    state_positions: &'a HashMap<StateId, Point>,
+    /// For each box, its states and those of every box nested in it.
+    contents: HashMap<BoxId, HashSet<StateId>>,
+    /// For each box, the boxes nested in it at any depth.
+    nested: HashMap<BoxId, Vec<BoxId>>,
+    /// Depth of each box in the hierarchy.
+    depths: HashMap<BoxId, usize>,
+    /// Spacing the layout was computed with.
+    spacing: Spacing,
+}
+
+impl<'a> EdgeRouter<'a> {
+    fn new(
+        hierarchy: &BoxHierarchy,
+        extents: &'a HashMap<BoxId, Rect>,
+        state_positions: &'a HashMap<StateId, Point>,
+        spacing: Spacing,
+    ) -> Self {
+        let mut depths = HashMap::new();
+        let mut contents = HashMap::new();
+        let mut nested = HashMap::new();
+        for &id in hierarchy.map.keys() {
+            compute_depth(id, &hierarchy.parents, &mut depths);
+            contents.insert(id, fragment_states(id, hierarchy).into_iter().collect());
+
+            let mut descendants = Vec::new();
+            let mut pending = hierarchy.children.get(&id).cloned().unwrap_or_default();
+            while let Some(child) = pending.pop() {
+                descendants.push(child);
+                pending.extend(hierarchy.children.get(&child).into_iter().flatten());
+            }
+            nested.insert(id, descendants);
+        }
+        Self {
+            extents,
+            state_positions,
+            contents,
+            nested,
+            depths,
+            spacing,
+        }
+    }
+
+    /// Gives `edge` a route around its obstacles if it would cut through a state.
+    ///
+    /// Bypass edges ([`EdgeCurve::CurveDown`]) go below the obstacles,
+    /// loop-backs ([`EdgeCurve::CurveUp`]) above them, and other edges take
+    /// the side with the shorter detour.
+    fn route(&self, edge: PositionedEdge) -> PositionedEdge {
+        let (from, to) = (edge.data.from, edge.data.to);
+        if from == to {
+            return edge;
+        }
+        let Some(enclosing) = self
+            .contents
+            .iter()
+            .filter(|(_, states)| states.contains(&from) && states.contains(&to))
+            .map(|(id, _)| *id)
+            .max_by_key(|id| self.depths[id])
+        else {
+            return edge;
+        };
+
+        let states: Vec<Rect> = self.contents[&enclosing]
+            .iter()
+            .filter(|&&state| state != from && state != to)
+            .filter_map(|state| self.state_positions.get(state))
+            .map(|position| Rect::enclosing([*position]).expanded(NODE_RADIUS))
+            .collect();
+        if !states
+            .iter()
+            .any(|rect| segment_crosses(rect, edge.from, edge.to))
+        {
+            return edge;
+        }
+
+        let (left, right) = (edge.from.x.min(edge.to.x), edge.from.x.max(edge.to.x));
+        let obstacles: Vec<Rect> = self.nested[&enclosing]
+            .iter()
+            .filter_map(|id| self.extents.get(id).copied())
+            .chain(states)
+            .filter(|rect| rect.x <= right && left <= rect.x + rect.width)
+            .collect();
+        let clearance = self.spacing.box_padding_y * ROUTE_CLEARANCE_RATIO;
+        let below = obstacles
+            .iter()
+            .map(|rect| rect.y + rect.height)
+            .fold(edge.from.y.max(edge.to.y), f32::max)
+            + clearance;
+        let above = obstacles
+            .iter()
+            .map(|rect| rect.y)
+            .fold(edge.from.y.min(edge.to.y), f32::min)
+            - clearance;
+        let lane = match edge.data.curve {
+            EdgeCurve::CurveDown => below,
+            EdgeCurve::CurveUp => above,
+            EdgeCurve::Straight | EdgeCurve::Loop => {
+                let detour = |lane: f32| (lane - edge.from.y).abs() + (lane - edge.to.y).abs();
+                if detour(below) < detour(above) {
+                    below
+                } else {
+                    above
+                }
+            }
+        };
+
+        let route = vec![Point::new(edge.from.x, lane), Point::new(edge.to.x, lane)];
+        edge.with_route(route)
+    }
+}
+
+/// Whether the segment from `start` to `end` passes through `rect`.
+///
+/// Clips the segment against each side of the rectangle in turn
+/// (Liang–Barsky); it crosses when a non-empty part of it is left.
+fn segment_crosses(rect: &Rect, start: Point, end: Point) -> bool {
+    let (dx, dy) = (end.x - start.x, end.y - start.y);
+    let (mut enter, mut exit) = (0.0_f32, 1.0_f32);
+    for (direction, distance) in [
+        (-dx, start.x - rect.x),
+        (dx, rect.x + rect.width - start.x),
+        (-dy, start.y - rect.y),
+        (dy, rect.y + rect.height - start.y),
+    ] {
+        if direction.abs() <= f32::EPSILON {
+            // Parallel to this side: outside it for good, or no constraint.
+            if distance < 0.0 {
+                return false;
+            }
+        } else if direction < 0.0 {
+            enter = enter.max(distance / direction);
+        } else {
+            exit = exit.min(distance / direction);
+        }
+    }
+    enter <= exit
+}
+
 /// Places any states not contained in bounding boxes in a simple horizontal line.
 ///
 /// Some states may not belong to any bounding box (orphaned states). This function
@@ -973,6 +1480,7 @@ fn assign_fallback_positions(
     nodes: &[GraphNode],
     state_positions: &mut HashMap<StateId, Point>,
     baseline_y: f32,
+    spacing: Spacing,
 ) {
     let mut index = 0usize;
 
@@ -983,7 +1491,7 @@ fn assign_fallback_positions(
         }
 
         // Place orphaned states in a horizontal line, evenly spaced
-        let position = Point::new(index as f32 * NODE_SPACING_X, baseline_y);
+        let position = Point::new(index as f32 * spacing.node_x, baseline_y);
         state_positions.insert(node.id, position);
         index += 1;
     }
@@ -0,0 +1,242 @@
+//! Runtime registry of layout strategies, looked up by name.
+//!
+//! [`LayoutStrategy`] is generic over the graph type, so it cannot be stored
+//! as a trait object. [`DynLayoutStrategy`] is its object-safe counterpart,
+//! implemented for every strategy, which lets applications embedding this crate
+//! register their own layouts next to the built-in ones and pick one by name.
+
+use std::collections::HashMap;
+
+use crate::Graph;
+
+use super::{
+    BoxVisibility, DfaLayoutStrategy, GraphLayout, LayoutConfig, LayoutStrategy, NfaLayoutStrategy,
+    TreeLayoutStrategy,
+};
+
+/// Name of the built-in [`NfaLayoutStrategy`].
+pub const NFA: &str = "nfa";
+/// Name of the built-in [`DfaLayoutStrategy`].
+pub const DFA: &str = "dfa";
+/// Name of the built-in [`TreeLayoutStrategy`].
+pub const TREE: &str = "tree";
+
+/// Object-safe form of [`LayoutStrategy`], laying out any `&dyn Graph`.
+///
+/// Every [`LayoutStrategy`] implements it, so custom strategies only need to
+/// implement [`LayoutStrategy`] to be registered.
+pub trait DynLayoutStrategy: Send + Sync {
+    /// Computes the layout of `graph`, like [`LayoutStrategy::compute`].
+    ///
+    /// # Arguments
+    /// - `graph` (`&dyn Graph`) - The graph to lay out.
+    /// - `visibility` (`&BoxVisibility`) - Which bounding boxes to render.
+    /// - `config` (`&LayoutConfig`) - Spacing to place elements with.
+    ///
+    /// # Returns
+    /// - `GraphLayout` - Positioned nodes, edges, boxes and overall bounds.
+    fn compute_dyn(
+        &self,
+        graph: &dyn Graph,
+        visibility: &BoxVisibility,
+        config: &LayoutConfig,
+    ) -> GraphLayout;
+}
+
+impl<S: LayoutStrategy + Send + Sync> DynLayoutStrategy for S {
+    fn compute_dyn(
+        &self,
+        graph: &dyn Graph,
+        visibility: &BoxVisibility,
+        config: &LayoutConfig,
+    ) -> GraphLayout {
+        self.compute(&graph, visibility, config)
+    }
+}
+
+/// Lets a strategy looked up at runtime stand in wherever a [`LayoutStrategy`]
+/// is expected, e.g. as the strategy of a generic canvas.
+impl LayoutStrategy for &dyn DynLayoutStrategy {
+    fn compute<G: Graph>(
+        &self,
+        graph: &G,
+        visibility: &BoxVisibility,
+        config: &LayoutConfig,
+    ) -> GraphLayout {
+        // Dispatch through the trait object; `self` would resolve to this impl.
+        (**self).compute_dyn(graph, visibility, config)
+    }
+}
+
+/// Layout strategies by name.
+///
+/// [`LayoutRegistry::default`] holds the built-in strategies under [`NFA`],
+/// [`DFA`] and [`TREE`]; [`LayoutRegistry::new`] starts empty.
+pub struct LayoutRegistry {
+    strategies: HashMap<&'static str, Box<dyn DynLayoutStrategy>>,
+}
+
+impl Default for LayoutRegistry {
+    fn default() -> Self {
+        Self::new()
+            .with(NFA, NfaLayoutStrategy::default())
+            .with(DFA, DfaLayoutStrategy)
+            .with(TREE, TreeLayoutStrategy)
+    }
+}
+
+impl std::fmt::Debug for LayoutRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LayoutRegistry")
+            .field("strategies", &self.names())
+            .finish()
+    }
+}
+
+impl LayoutRegistry {
+    /// Creates a registry without any strategies.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            strategies: HashMap::new(),
+        }
+    }
+
+    /// Registers `strategy` under `name`, replacing any strategy registered
+    /// under it before.
+    ///
+    /// # Arguments
+    /// - `name` (`&'static str`) - The name to look the strategy up by.
+    /// - `strategy` (`impl DynLayoutStrategy`) - The layout algorithm.
+    ///
+    /// # Returns
+    /// - `Option<Box<dyn DynLayoutStrategy>>` - The replaced strategy, if any.
+    pub fn register(
+        &mut self,
+        name: &'static str,
+        strategy: impl DynLayoutStrategy + 'static,
+    ) -> Option<Box<dyn DynLayoutStrategy>> {
+        self.strategies.insert(name, Box::new(strategy))
+    }
+
+    /// Builder form of [`LayoutRegistry::register`].
+    #[must_use]
+    pub fn with(mut self, name: &'static str, strategy: impl DynLayoutStrategy + 'static) -> Self {
+        self.register(name, strategy);
+        self
+    }
+
+    /// Returns the strategy registered under `name`.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&dyn DynLayoutStrategy> {
+        self.strategies.get(name).map(Box::as_ref)
+    }
+
+    /// Returns the registered names in alphabetical order.
+    #[must_use]
+    pub fn names(&self) -> Vec<&'static str> {
+        let mut names: Vec<_> = self.strategies.keys().copied().collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Lays out `graph` with the strategy registered under `name`.
+    ///
+    /// # Arguments
+    /// - `name` (`&str`) - The registered name of the strategy.
+    /// - `graph` (`&dyn Graph`) - The graph to lay out.
+    /// - `visibility` (`&BoxVisibility`) - Which bounding boxes to render.
+    /// - `config` (`&LayoutConfig`) - Spacing to place elements with.
+    ///
+    /// # Returns
+    /// - `Option<GraphLayout>` - The layout, or `None` when no strategy is
+    ///   registered under `name`.
+    #[must_use]
+    pub fn compute(
+        &self,
+        name: &str,
+        graph: &dyn Graph,
+        visibility: &BoxVisibility,
+        config: &LayoutConfig,
+    ) -> Option<GraphLayout> {
+        self.get(name)
+            .map(|strategy| strategy.compute_dyn(graph, visibility, config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regviz_core::core::nfa::Nfa;
+    use regviz_core::core::parser::Ast;
+
+    use super::*;
+    use crate::geometry::Rect;
+    use crate::layout::snapshot::render;
+
+    /// Places every node at the origin.
+    struct Stacked;
+
+    impl LayoutStrategy for Stacked {
+        fn compute<G: Graph>(
+            &self,
+            graph: &G,
+            _visibility: &BoxVisibility,
+            _config: &LayoutConfig,
+        ) -> GraphLayout {
+            GraphLayout {
+                boxes: Vec::new(),
+                nodes: Vec::new(),
+                edges: Vec::new(),
+                bounds: Rect {
+                    x: 0.0,
+                    y: 0.0,
+                    width: graph.nodes().len() as f32,
+                    height: 0.0,
+                },
+            }
+        }
+    }
+
+    fn nfa() -> Nfa {
+        Nfa::build(&Ast::build("(a+b)*c").unwrap())
+    }
+
+    #[test]
+    fn test_builtins_match_their_strategies() {
+        let registry = LayoutRegistry::default();
+        assert_eq!(registry.names(), vec![DFA, NFA, TREE]);
+
+        let visibility = BoxVisibility::default();
+        let config = LayoutConfig::default();
+        let nfa = nfa();
+        let looked_up = registry.compute(NFA, &nfa, &visibility, &config).unwrap();
+        let direct = NfaLayoutStrategy::default().compute(&nfa, &visibility, &config);
+        assert_eq!(render(&looked_up), render(&direct));
+        assert!(
+            registry
+                .compute("force", &nfa, &visibility, &config)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_registers_custom_strategies() {
+        let mut registry = LayoutRegistry::default();
+        assert!(registry.register("stacked", Stacked).is_none());
+        let nfa = nfa();
+        let layout = registry
+            .compute(
+                "stacked",
+                &nfa,
+                &BoxVisibility::default(),
+                &LayoutConfig::default(),
+            )
+            .unwrap();
+        assert_eq!(layout.bounds.width, nfa.states.len() as f32);
+
+        // A looked-up strategy still works where a `LayoutStrategy` is expected.
+        let strategy = registry.get("stacked").unwrap();
+        let layout = strategy.compute(&nfa, &BoxVisibility::default(), &LayoutConfig::default());
+        assert_eq!(layout.bounds.width, nfa.states.len() as f32);
+    }
+}
@@ -0,0 +1,304 @@
+//! Golden-file snapshots of computed layouts.
+//!
+//! A layout is serialized to JSON with its elements in a canonical order and
+//! its coordinates rounded, then compared against a file checked in under
+//! `src/layout/snapshots`. Set `REGVIZ_UPDATE_SNAPSHOTS=1` to write or
+//! rewrite the golden files after an intended layout change; without it a
+//! missing file is a test failure.
+
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use super::GraphLayout;
+
+/// Environment variable that rewrites golden files instead of comparing them.
+const UPDATE_VAR: &str = "REGVIZ_UPDATE_SNAPSHOTS";
+
+/// Number of decimals kept for coordinates, so float noise does not show up
+/// as a regression.
+const PRECISION: f64 = 100.0;
+
+/// Compares `layout` against the golden file `snapshots/<name>.json`.
+///
+/// # Arguments
+/// - `name` (`&str`) - File stem of the golden file.
+/// - `layout` (`&GraphLayout`) - The computed layout.
+///
+/// # Panics
+/// - When the serialized layout differs from the golden file.
+/// - When the golden file is missing and [`UPDATE_VAR`] is not set.
+pub fn assert_snapshot(name: &str, layout: &GraphLayout) {
+    let actual = render(layout);
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/layout/snapshots")
+        .join(format!("{name}.json"));
+
+    if std::env::var_os(UPDATE_VAR).is_some() {
+        std::fs::create_dir_all(path.parent().expect("snapshot directory"))
+            .expect("create snapshot directory");
+        std::fs::write(&path, actual).expect("write snapshot");
+        return;
+    }
+
+    let Ok(expected) = std::fs::read_to_string(&path) else {
+        panic!(
+            "layout snapshot `{name}` is missing at {}; rerun with {UPDATE_VAR}=1 to create it",
+            path.display()
+        );
+    };
+    assert!(
+        expected == actual,
+        "layout snapshot `{name}` changed; rerun with {UPDATE_VAR}=1 to accept it\n\
+         --- expected\n{expected}\n+++ actual\n{actual}"
+    );
+}
+
+/// Asserts that no two nodes of `layout` are drawn on top of each other.
+///
+/// # Panics
+/// - When the circles of two nodes intersect.
+pub fn assert_no_overlaps(layout: &GraphLayout) {
+    for (index, first) in layout.nodes.iter().enumerate() {
+        for second in &layout.nodes[index + 1..] {
+            let gap = first.position.distance(second.position) - first.radius - second.radius;
+            assert!(
+                gap >= 0.0,
+                "nodes {} and {} overlap by {}",
+                first.data.id,
+                second.data.id,
+                -gap
+            );
+        }
+    }
+}
+
+/// Serializes `layout` to pretty JSON in a canonical form.
+pub fn render(layout: &GraphLayout) -> String {
+    let mut value = serde_json::to_value(layout).expect("layout serializes");
+    sort_by(&mut value["boxes"], |item| item["data"]["id"].to_string());
+    sort_by(&mut value["nodes"], |item| item["data"]["id"].to_string());
+    sort_by(&mut value["edges"], |item| {
+        let data = &item["data"];
+        format!("{} {} {}", data["from"], data["to"], data["label"])
+    });
+    round(&mut value);
+    let mut json = serde_json::to_string_pretty(&value).expect("snapshot serializes");
+    json.push('\n');
+    json
+}
+
+/// Sorts the elements of a JSON array by a string key.
+fn sort_by(value: &mut Value, key: impl Fn(&Value) -> String) {
+    if let Value::Array(items) = value {
+        items.sort_by_cached_key(|item| {
+            // Pad numeric ids so they sort numerically.
+            format!("{:>12}", key(item))
+        });
+    }
+}
+
+/// Rounds every floating point number in `value` to [`PRECISION`].
+fn round(value: &mut Value) {
+    match value {
+        Value::Number(number) if number.is_f64() => {
+            let rounded = (number.as_f64().unwrap_or_default() * PRECISION).round() / PRECISION;
+            // `-0.0` and `0.0` must snapshot identically.
+            let rounded = if rounded == 0.0 { 0.0 } else { rounded };
+            if let Some(number) = serde_json::Number::from_f64(rounded) {
+                *value = Value::Number(number);
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(round),
+        Value::Object(fields) => fields.values_mut().for_each(round),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use regviz_core::core::automaton::BoxKind;
+    use regviz_core::core::dfa;
+    use regviz_core::core::nfa::Nfa;
+    use regviz_core::core::parser::Ast;
+
+    use super::*;
+    use crate::layout::{
+        BoxVisibility, DfaLayoutStrategy, LayoutConfig, LayoutStrategy, NfaLayoutStrategy,
+        Orientation, TreeLayoutStrategy,
+    };
+    use crate::{AstGraph, EdgeCurve, VisualDfa};
+
+    fn ast(pattern: &str) -> Ast {
+        Ast::build(pattern).expect("pattern parses")
+    }
+
+    fn nfa(pattern: &str) -> Nfa {
+        Nfa::build(&ast(pattern))
+    }
+
+    fn nfa_layout(pattern: &str) -> GraphLayout {
+        NfaLayoutStrategy::default().compute(
+            &nfa(pattern),
+            &BoxVisibility::default(),
+            &LayoutConfig::default(),
+        )
+    }
+
+    #[test]
+    fn test_nfa_layouts_match_snapshots() {
+        for (name, pattern) in [
+            ("nfa_literal", "a"),
+            ("nfa_concat", "ab"),
+            ("nfa_alternation", "a+b"),
+            ("nfa_star", "(a+b)*c"),
+            ("nfa_nested_alternation", "(ab+c)(d+ef)"),
+        ] {
+            let layout = nfa_layout(pattern);
+            assert_no_overlaps(&layout);
+            assert_snapshot(name, &layout);
+        }
+    }
+
+    #[test]
+    fn test_alternation_branches_do_not_overlap() {
+        let layout = nfa_layout("(ab+cd+ef)*");
+        let branches: Vec<_> = layout
+            .boxes
+            .iter()
+            .filter(|bbox| bbox.data.kind == BoxKind::Concat)
+            .collect();
+        assert_eq!(branches.len(), 3);
+        for (index, first) in branches.iter().enumerate() {
+            for second in &branches[index + 1..] {
+                assert!(!first.rect.intersects(&second.rect));
+            }
+        }
+    }
+
+    #[test]
+    fn test_nested_sibling_fragments_do_not_overlap() {
+        for pattern in ["((a+bc*)*+d)*", "((a+b)*+(c+d)*)*", "(a(b+c)*+d(e+f)*)*"] {
+            let layout = nfa_layout(pattern);
+            assert_no_overlaps(&layout);
+            for (index, first) in layout.boxes.iter().enumerate() {
+                for second in &layout.boxes[index + 1..] {
+                    if first.data.parent == second.data.parent {
+                        assert!(
+                            !first.rect.intersects(&second.rect),
+                            "boxes {} and {} of `{pattern}` overlap",
+                            first.data.id,
+                            second.data.id
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_long_edges_are_routed_around_states() {
+        for pattern in ["a*", "(ab)*", "(abc)?d", "((a+b)*c)*", "(a(b+c)*d)*"] {
+            let layout = nfa_layout(pattern);
+            assert!(
+                layout.edges.iter().any(|edge| !edge.route.is_empty()),
+                "no edge of `{pattern}` is routed"
+            );
+            let drawn_as_lines = layout
+                .edges
+                .iter()
+                .filter(|edge| !edge.route.is_empty() || edge.data.curve == EdgeCurve::Straight);
+            for edge in drawn_as_lines {
+                for node in &layout.nodes {
+                    if node.data.id == edge.data.from || node.data.id == edge.data.to {
+                        continue;
+                    }
+                    assert!(
+                        edge.distance_to(node.position) > node.radius,
+                        "edge {} -> {} of `{pattern}` crosses state {}",
+                        edge.data.from,
+                        edge.data.to,
+                        node.data.id
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dfa_layout_matches_snapshot() {
+        let nfa = nfa("(a+b)*abb");
+        let dfa = dfa::determinize(&nfa);
+        let pinned = HashMap::new();
+        let graph = VisualDfa::new(&dfa, &dfa.alphabet, Default::default(), &pinned);
+        let layout =
+            DfaLayoutStrategy.compute(&graph, &BoxVisibility::default(), &LayoutConfig::default());
+        assert_no_overlaps(&layout);
+        assert_snapshot("dfa_abb", &layout);
+    }
+
+    #[test]
+    fn test_tree_layout_matches_snapshot() {
+        let ast = ast("(a+b)*c");
+        let pinned = HashMap::new();
+        let layout = TreeLayoutStrategy.compute(
+            &AstGraph::new(&ast, &pinned),
+            &BoxVisibility::default(),
+            &LayoutConfig::default(),
+        );
+        assert_no_overlaps(&layout);
+        assert_snapshot("tree_star", &layout);
+    }
+
+    #[test]
+    fn test_spacing_follows_the_layout_config() {
+        let nfa = nfa("(a+b)*c");
+        let visibility = BoxVisibility::default();
+        let default =
+            NfaLayoutStrategy::default().compute(&nfa, &visibility, &LayoutConfig::default());
+        let compact = LayoutConfig {
+            node_spacing: 120.0,
+            level_spacing: 120.0,
+            branch_gap: 60.0,
+            box_padding: 20.0,
+        };
+        let layout = NfaLayoutStrategy::default().compute(&nfa, &visibility, &compact);
+        assert_no_overlaps(&layout);
+        assert!(layout.bounds.width < default.bounds.width);
+        assert!(layout.bounds.height < default.bounds.height);
+    }
+
+    #[test]
+    fn test_orientations_mirror_or_transpose_the_layout() {
+        let nfa = nfa("(a+b)*c");
+        let visibility = BoxVisibility::default();
+        let config = LayoutConfig::default();
+        let layout_in = |orientation| {
+            NfaLayoutStrategy::default()
+                .with_orientation(orientation)
+                .compute(&nfa, &visibility, &config)
+        };
+        let default = layout_in(Orientation::LeftToRight);
+        for orientation in [Orientation::RightToLeft, Orientation::TopToBottom] {
+            let layout = layout_in(orientation);
+            assert_no_overlaps(&layout);
+            for (node, original) in layout.nodes.iter().zip(&default.nodes) {
+                assert_eq!(node.position, orientation.map(original.position));
+            }
+        }
+
+        let flowing_down = layout_in(Orientation::TopToBottom);
+        let start = flowing_down.nodes.iter().find(|node| node.data.is_start);
+        let accept = flowing_down.nodes.iter().find(|node| node.data.is_accept);
+        assert!(start.unwrap().position.y < accept.unwrap().position.y);
+        assert!(flowing_down.bounds.height > flowing_down.bounds.width);
+    }
+
+    #[test]
+    fn test_layouts_are_deterministic() {
+        let pattern = "(a+b)*c(d+e)";
+        assert_eq!(render(&nfa_layout(pattern)), render(&nfa_layout(pattern)));
+    }
+}
@@ -0,0 +1,449 @@
+/// Tidy tree layout algorithm for visualizing Abstract Syntax Trees (ASTs).
+///
+/// This module implements the Reingold–Tilford algorithm, which places every
+/// subtree as close to its siblings as possible without overlapping them. Unlike
+/// spacing each level uniformly, unbalanced trees waste no space between their
+/// subtrees and every parent sits centered above its children.
+///
+/// # Algorithm Overview
+///
+/// The tree layout uses a two-pass algorithm:
+///
+/// 1. **Contours (bottom-up)**: For each node, compute the contour of its
+///    subtree: the leftmost and rightmost node offsets on every level below it.
+///    Children are placed left to right, each one shifted just far enough that
+///    its left contour stays [`NODE_WIDTH`] away from the right contour of the
+///    siblings placed before it. The parent is then centered over its first and
+///    last child.
+///
+/// 2. **Positions (top-down)**: Starting from the root, accumulate the offsets
+///    of each node relative to its parent into absolute coordinates.
+///
+/// # Layout Properties
+///
+/// - **Root Position**: Placed at the top, at `x = 0`
+/// - **Vertical Spacing**: Each level is separated by [`LEVEL_HEIGHT`]
+/// - **Horizontal Spacing**: Nodes on the same level are at least [`NODE_WIDTH`] apart
+/// - **Tree Direction**: Top-to-bottom (root at top, leaves at bottom)
+/// - **Child Ordering**: Children keep the order of their edges, left to right
+///
+/// # Example
+///
+/// For the regex AST of `(a+b)*`:
+/// ```text
+///        Star
+///         |
+///        Alt
+///       /   \
+///      a     b
+/// ```
+///
+/// The layout produces:
+/// - Star node at (0, 0)
+/// - Alt node at (0, LEVEL_HEIGHT)
+/// - 'a' node at (-NODE_WIDTH/2, 2*LEVEL_HEIGHT)
+/// - 'b' node at (+NODE_WIDTH/2, 2*LEVEL_HEIGHT)
+use std::collections::{HashMap, HashSet};
+
+use super::{LayoutConfig, LayoutStrategy};
+use crate::{Graph, GraphEdge, GraphNode, Point, PositionedEdge, PositionedNode, Rect};
+
+/// Tidy tree layout strategy for AST visualization.
+///
+/// This strategy arranges nodes in a hierarchical tree structure with uniform
+/// vertical spacing, packing sibling subtrees as tightly as their contours
+/// allow. It ignores bounding boxes (sets visibility to false for all box
+/// types) since ASTs don't have the regex operator boxes that NFAs use.
+///
+/// # Algorithm
+///
+/// 1. Find the children of every node from the edges
+/// 2. Compute the contour of every subtree, bottom-up, packing siblings tightly
+/// 3. Accumulate the relative offsets into positions, top-down
+/// 4. Connect nodes with edges based on parent-child relationships
+/// 5. Calculate overall bounds for the layout
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TreeLayoutStrategy;
+
+impl LayoutStrategy for TreeLayoutStrategy {
+    fn compute<G: Graph>(
+        &self,
+        graph: &G,
+        _visibility: &super::BoxVisibility,
+        config: &LayoutConfig,
+    ) -> super::GraphLayout {
+        layout_tree(graph, config)
+    }
+}
+
+/// Vertical distance between levels of the tree, at the default [`LayoutConfig`].
+const LEVEL_HEIGHT: f32 = 150.0;
+
+/// Horizontal distance between adjacent nodes on the same level, at the default [`LayoutConfig`].
+const NODE_WIDTH: f32 = 120.0;
+
+/// Radius of tree nodes (used for bounds calculation).
+const NODE_RADIUS: f32 = 40.0;
+
+/// Padding around the entire tree layout.
+const TREE_PADDING: f32 = 60.0;
+
+/// Main tree layout algorithm.
+///
+/// This function implements a tidy tree layout where:
+/// - Nodes at the same depth are placed on the same horizontal line
+/// - Sibling subtrees are packed as closely as their contours allow
+/// - Every parent is centered above its children
+/// - The tree grows top-to-bottom
+///
+/// # Algorithm Steps
+///
+/// 1. **Extract graph data**: Get nodes and edges from the graph
+/// 2. **Build the tree**: Find the roots and the ordered children of every node
+/// 3. **Compute positions**: Pack subtrees by their contours, then place every node
+/// 4. **Create positioned elements**: Build the final GraphLayout with coordinates
+/// 5. **Calculate bounds**: Determine the overall canvas size needed
+///
+/// # Returns
+///
+/// A [`GraphLayout`] containing:
+/// - No boxes (ASTs don't use bounding boxes)
+/// - Positioned nodes at calculated coordinates
+/// - Edges connecting parent nodes to children
+/// - Bounds rectangle encompassing all elements
+fn layout_tree<G: Graph>(graph: &G, config: &LayoutConfig) -> super::GraphLayout {
+    let nodes = graph.nodes();
+    let level_height = LEVEL_HEIGHT * config.vertical_scale();
+    let node_width = NODE_WIDTH * config.horizontal_scale();
+    let edges = graph.edges();
+
+    // ASTs don't use bounding boxes, so return empty
+    let boxes = Vec::new();
+
+    if nodes.is_empty() {
+        return super::GraphLayout {
+            boxes,
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            bounds: Rect::default(),
+        };
+    }
+
+    // Step 1: Find the roots and the ordered children of every node
+    let tree = Tree::new(&nodes, &edges);
+
+    // Step 2: Pack the subtrees and place every node relative to the roots
+    let layout = tree.tidy_layout(node_width);
+    let max_depth = layout.values().map(|&(_, depth)| depth).max().unwrap_or(0);
+    let node_positions: HashMap<u32, Point> = layout
+        .into_iter()
+        .map(|(id, (x, depth))| {
+            (
+                id,
+                Point::new(x, TREE_PADDING + depth as f32 * level_height),
+            )
+        })
+        .collect();
+
+    // Step 3: Create positioned nodes. Respect any manual positions supplied
+    // on `GraphNode` (these should override computed positions).
+    let positioned_nodes: Vec<PositionedNode> = nodes
+        .into_iter()
+        .filter_map(|node| {
+            let pos = node
+                .manual_position
+                .or_else(|| node_positions.get(&node.id).copied());
+            pos.map(|p| PositionedNode::new(node, p, NODE_RADIUS))
+        })
+        .collect();
+
+    // Step 4: Create positioned edges. Use the final positions of the
+    // positioned nodes (which already respect manual/pinned overrides) so
+    // edges attach to the visible node centers.
+    let final_positions: HashMap<u32, Point> = positioned_nodes
+        .iter()
+        .map(|pn| (pn.data.id, pn.position))
+        .collect();
+
+    let positioned_edges: Vec<PositionedEdge> = edges
+        .into_iter()
+        .filter_map(|edge| {
+            let from_pos = final_positions.get(&edge.from)?;
+            let to_pos = final_positions.get(&edge.to)?;
+            // Use explicit node radii so the edge drawing logic can trim
+            // the segment to the node boundaries and draw with stroke().
+            Some(PositionedEdge::with_radii(
+                edge,
+                *from_pos,
+                *to_pos,
+                NODE_RADIUS,
+                NODE_RADIUS,
+            ))
+        })
+        .collect();
+
+    // Step 5: Calculate bounds
+    let min_x = final_positions
+        .values()
+        .map(|p| p.x)
+        .fold(f32::INFINITY, f32::min);
+    let max_x = final_positions
+        .values()
+        .map(|p| p.x)
+        .fold(f32::NEG_INFINITY, f32::max);
+    let min_y = final_positions
+        .values()
+        .map(|p| p.y)
+        .fold(f32::INFINITY, f32::min)
+        .min(TREE_PADDING);
+    let max_y = final_positions
+        .values()
+        .map(|p| p.y)
+        .fold(f32::NEG_INFINITY, f32::max)
+        .max(TREE_PADDING + (max_depth as f32) * level_height);
+
+    let bounds = Rect {
+        x: min_x - NODE_RADIUS - TREE_PADDING,
+        y: min_y - NODE_RADIUS - TREE_PADDING,
+        width: (max_x - min_x) + 2.0 * (NODE_RADIUS + TREE_PADDING),
+        height: (max_y - min_y) + 2.0 * (NODE_RADIUS + TREE_PADDING),
+    };
+
+    super::GraphLayout {
+        boxes,
+        nodes: positioned_nodes,
+        edges: positioned_edges,
+        bounds,
+    }
+}
+
+/// Children of every node of the tree, in edge order.
+struct Tree {
+    /// Nodes without a parent, in node order.
+    roots: Vec<u32>,
+    /// Children of every node, left to right.
+    children: HashMap<u32, Vec<u32>>,
+}
+
+/// Leftmost and rightmost offsets of a subtree on each of its levels, relative
+/// to its root (level 0 is the root itself).
+type Contour = Vec<(f32, f32)>;
+
+impl Tree {
+    /// Builds the tree spanned by `edges` over `nodes`.
+    ///
+    /// Every node without incoming edges is a root. Should the edges not form
+    /// a forest, only the first edge reaching each node is kept and nodes left
+    /// unreachable become roots too, so every node is placed exactly once.
+    fn new(nodes: &[GraphNode], edges: &[GraphEdge]) -> Self {
+        let mut targets: HashMap<u32, Vec<u32>> = HashMap::new();
+        for edge in edges {
+            targets.entry(edge.from).or_default().push(edge.to);
+        }
+        let has_parent: HashSet<u32> = edges.iter().map(|edge| edge.to).collect();
+
+        let mut roots = Vec::new();
+        let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+        let mut reached = HashSet::new();
+        let candidates = nodes
+            .iter()
+            .filter(|node| !has_parent.contains(&node.id))
+            .chain(nodes);
+        for node in candidates {
+            if !reached.insert(node.id) {
+                continue;
+            }
+            roots.push(node.id);
+            let mut stack = vec![node.id];
+            while let Some(id) = stack.pop() {
+                for &child in targets.get(&id).into_iter().flatten() {
+                    if reached.insert(child) {
+                        children.entry(id).or_default().push(child);
+                        stack.push(child);
+                    }
+                }
+            }
+        }
+        Self { roots, children }
+    }
+
+    /// Returns the ordered children of `id`.
+    fn children_of(&self, id: u32) -> &[u32] {
+        self.children.get(&id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the nodes of the subtree rooted at `id`, parents before their children.
+    fn subtree(&self, id: u32) -> Vec<u32> {
+        let mut order = Vec::new();
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            order.push(id);
+            stack.extend(self.children_of(id));
+        }
+        order
+    }
+
+    /// Places every node with the Reingold–Tilford algorithm.
+    ///
+    /// Roots are packed side by side like the children of a virtual parent,
+    /// with the first root at `x = 0`.
+    ///
+    /// # Arguments
+    /// - `node_width` (`f32`) - Least horizontal distance between nodes on the same level.
+    ///
+    /// # Returns
+    /// - `HashMap<u32, (f32, usize)>` - The x coordinate and depth of every node.
+    fn tidy_layout(&self, node_width: f32) -> HashMap<u32, (f32, usize)> {
+        // Bottom-up: the offset of every child relative to its parent, and the
+        // contour of every subtree
+        let mut offsets: HashMap<u32, f32> = HashMap::new();
+        let mut contours: HashMap<u32, Contour> = HashMap::new();
+        let order: Vec<u32> = self
+            .roots
+            .iter()
+            .flat_map(|&root| self.subtree(root))
+            .collect();
+        for &id in order.iter().rev() {
+            let children = self.children_of(id);
+            let (placed, merged) = pack(children, &mut contours, node_width);
+            // Center the parent over its first and last child
+            let center = match (placed.first(), placed.last()) {
+                (Some(first), Some(last)) => (first + last) / 2.0,
+                _ => 0.0,
+            };
+            for (&child, offset) in children.iter().zip(placed) {
+                offsets.insert(child, offset - center);
+            }
+            let contour = std::iter::once((0.0, 0.0))
+                .chain(
+                    merged
+                        .into_iter()
+                        .map(|(left, right)| (left - center, right - center)),
+                )
+                .collect();
+            contours.insert(id, contour);
+        }
+
+        // Top-down: accumulate the offsets into absolute coordinates
+        let (roots, _) = pack(&self.roots, &mut contours, node_width);
+        let mut positions = HashMap::new();
+        let mut stack: Vec<(u32, f32, usize)> = self
+            .roots
+            .iter()
+            .zip(roots)
+            .map(|(&root, x)| (root, x, 0))
+            .collect();
+        while let Some((id, x, depth)) = stack.pop() {
+            positions.insert(id, (x, depth));
+            for &child in self.children_of(id) {
+                let offset = offsets.get(&child).copied().unwrap_or(0.0);
+                stack.push((child, x + offset, depth + 1));
+            }
+        }
+        positions
+    }
+}
+
+/// Packs the subtrees rooted at `ids` left to right, as close as their contours allow.
+///
+/// Each subtree is shifted just far enough right that, on every level both
+/// share, its left contour stays `node_width` away from the right contour of
+/// the subtrees packed before it.
+///
+/// # Arguments
+/// - `ids` (`&[u32]`) - Roots of the subtrees, in order. Their contours are taken from `contours`.
+/// - `contours` (`&mut HashMap<u32, Contour>`) - Contour of every subtree packed so far.
+/// - `node_width` (`f32`) - Least horizontal distance between nodes on the same level.
+///
+/// # Returns
+/// - `(Vec<f32>, Contour)` - The offset of every subtree root, the first at 0,
+///   and the contour of all of them together relative to that first root.
+fn pack(ids: &[u32], contours: &mut HashMap<u32, Contour>, node_width: f32) -> (Vec<f32>, Contour) {
+    let mut offsets = Vec::with_capacity(ids.len());
+    let mut merged: Contour = Vec::new();
+    for id in ids {
+        let contour = contours.remove(id).unwrap_or_else(|| vec![(0.0, 0.0)]);
+        let offset = if merged.is_empty() {
+            0.0
+        } else {
+            merged
+                .iter()
+                .zip(&contour)
+                .map(|(&(_, right), &(left, _))| right - left + node_width)
+                .fold(f32::NEG_INFINITY, f32::max)
+        };
+        for (level, &(left, right)) in contour.iter().enumerate() {
+            let (left, right) = (left + offset, right + offset);
+            match merged.get_mut(level) {
+                Some(extent) => *extent = (extent.0.min(left), extent.1.max(right)),
+                None => merged.push((left, right)),
+            }
+        }
+        offsets.push(offset);
+    }
+    (offsets, merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use regviz_core::core::parser::Ast;
+
+    use super::*;
+    use crate::{AstGraph, BoxVisibility, GraphLayout};
+
+    fn tree_layout(pattern: &str) -> GraphLayout {
+        let ast = Ast::build(pattern).unwrap();
+        TreeLayoutStrategy.compute(
+            &AstGraph::new(&ast, &HashMap::new()),
+            &BoxVisibility::default(),
+            &LayoutConfig::default(),
+        )
+    }
+
+    fn children(layout: &GraphLayout, parent: u32) -> Vec<Point> {
+        let position = |id| {
+            let node = layout.nodes.iter().find(|node| node.data.id == id);
+            node.unwrap().position
+        };
+        layout
+            .edges
+            .iter()
+            .filter(|edge| edge.data.from == parent)
+            .map(|edge| position(edge.data.to))
+            .collect()
+    }
+
+    #[test]
+    fn test_parents_are_centered_over_spaced_out_children() {
+        for pattern in ["(a+b)*c", "abcd(e+f)*", "((a+b)(c+d))*+e?"] {
+            let layout = tree_layout(pattern);
+            for node in &layout.nodes {
+                let children = children(&layout, node.data.id);
+                if let (Some(first), Some(last)) = (children.first(), children.last()) {
+                    assert_eq!(node.position.x, (first.x + last.x) / 2.0);
+                }
+            }
+            for (index, first) in layout.nodes.iter().enumerate() {
+                for second in &layout.nodes[index + 1..] {
+                    if first.position.y == second.position.y {
+                        let gap = (first.position.x - second.position.x).abs();
+                        assert!(gap >= NODE_WIDTH - 1e-3, "{pattern}: nodes {gap} apart");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_unbalanced_subtrees_pack_tightly() {
+        // Every concatenation has its previous prefix on the left and one
+        // letter on the right, which fits right next to the prefix's root.
+        let layout = tree_layout("abcde");
+        for node in &layout.nodes {
+            if let [left, right] = children(&layout, node.data.id)[..] {
+                assert_eq!(right.x - left.x, NODE_WIDTH);
+            }
+        }
+        assert!(layout.bounds.width < NODE_WIDTH * 4.0 + 2.0 * (NODE_RADIUS + TREE_PADDING));
+    }
+}
@@ -0,0 +1,83 @@
+//! Renderer-agnostic graph layouts for RegViz.
+//!
+//! Automata and syntax trees are turned into [`Graph`]s of nodes, edges and
+//! bounding boxes, which the strategies in [`layout`] position in plain `f32`
+//! coordinates. The resulting [`GraphLayout`] is serializable, so the desktop
+//! app, the CLI and the exporters all draw the exact same picture.
+
+mod ast;
+mod bbox;
+mod collapse;
+mod color;
+mod dfa;
+mod edge;
+pub mod geometry;
+mod highlight;
+pub mod layout;
+mod nfa;
+mod node;
+mod owned;
+pub mod spatial;
+pub mod svg;
+
+pub use ast::AstGraph;
+pub use bbox::{BoxStyle, BoxStyles, GraphBox, PositionedBox};
+pub use color::{Color, color_for_box, color_for_kind, component_tints, heat_color, heat_tints};
+pub use dfa::{TransitionGrouping, VisualDfa};
+pub use edge::{
+    EdgeCurve, GraphEdge, LABEL_DISTANCE, PositionedEdge, curve_control_point,
+    quadratic_bezier_point, self_loop,
+};
+pub use geometry::{Point, Rect};
+pub use highlight::{EdgeHighlight, HighlightLayer, Highlights, StateHighlight};
+pub use layout::{BoxVisibility, GraphLayout, LayoutConfig};
+pub use nfa::VisualNfa;
+pub use node::{GraphNode, NodeKind, NodeShape, PositionedNode};
+pub use owned::OwnedGraph;
+pub use spatial::SpatialIndex;
+
+/// A source of nodes, edges and bounding boxes to lay out.
+///
+/// The trait is object-safe, so graphs of different types, e.g. an NFA view, a
+/// DFA view and an [`OwnedGraph`] imported from elsewhere, can be handled
+/// through one `Box<dyn Graph>` or `&dyn Graph`.
+pub trait Graph {
+    /// Returns all renderable nodes for the graph.
+    fn nodes(&self) -> Vec<GraphNode>;
+
+    /// Returns all edges between the nodes.
+    fn edges(&self) -> Vec<GraphEdge>;
+
+    /// Returns bounding boxes that should be rendered behind the nodes.
+    fn boxes(&self) -> Vec<GraphBox>;
+}
+
+/// Lets borrowed graphs, including `&dyn Graph`, be passed to generic code.
+impl<G: Graph + ?Sized> Graph for &G {
+    fn nodes(&self) -> Vec<GraphNode> {
+        (**self).nodes()
+    }
+
+    fn edges(&self) -> Vec<GraphEdge> {
+        (**self).edges()
+    }
+
+    fn boxes(&self) -> Vec<GraphBox> {
+        (**self).boxes()
+    }
+}
+
+/// Lets boxed graphs, including `Box<dyn Graph>`, be passed to generic code.
+impl<G: Graph + ?Sized> Graph for Box<G> {
+    fn nodes(&self) -> Vec<GraphNode> {
+        (**self).nodes()
+    }
+
+    fn edges(&self) -> Vec<GraphEdge> {
+        (**self).edges()
+    }
+
+    fn boxes(&self) -> Vec<GraphBox> {
+        (**self).boxes()
+    }
+}
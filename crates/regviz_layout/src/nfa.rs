@@ -0,0 +1,390 @@
+use regviz_core::core::automaton::{
+    BoxId, BoxKind, EdgeLabel, EdgeLabelSet, EpsilonGlyph, StateId,
+};
+use regviz_core::core::nfa::Nfa;
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    Color, EdgeCurve, Graph, GraphBox, GraphEdge, GraphNode, HighlightLayer, Highlights, Point,
+    StateHighlight, collapse::Collapse,
+};
+
+impl Graph for Nfa {
+    fn nodes(&self) -> Vec<GraphNode> {
+        let empty = Highlights::default();
+        let pinned: HashMap<StateId, Point> = HashMap::new();
+        build_nodes(
+            self,
+            &empty,
+            &pinned,
+            &HashMap::new(),
+            &HashSet::new(),
+            None,
+            &Collapse::default(),
+        )
+    }
+
+    fn edges(&self) -> Vec<GraphEdge> {
+        let empty = Highlights::default();
+        build_edges(self, &empty, EpsilonGlyph::default(), &Collapse::default())
+    }
+
+    fn boxes(&self) -> Vec<GraphBox> {
+        self.boxes.iter().cloned().map(Into::into).collect()
+    }
+}
+
+/// Visual wrapper that augments an NFA with highlight metadata for rendering.
+#[derive(Debug, Clone)]
+pub struct VisualNfa<'a> {
+    nfa: &'a Nfa,
+    highlights: Highlights,
+    /// User-supplied manual positions for states (layout coordinates).
+    pinned_positions: &'a HashMap<StateId, Point>,
+    /// Overlay fill colors for states.
+    tints: HashMap<StateId, Color>,
+    /// States where simulation playback pauses.
+    breakpoints: HashSet<StateId>,
+    /// Glyph used to label ε transitions.
+    epsilon_glyph: EpsilonGlyph,
+    /// State drawn with a selection ring.
+    selected: Option<StateId>,
+    /// Depth of the deepest boxes laid out, or `None` for every level.
+    max_depth: Option<usize>,
+    /// Boxes folded into super-nodes whatever their depth.
+    collapsed_boxes: HashSet<BoxId>,
+    /// Structure folded into super-nodes below the depth cap or by the user.
+    collapse: Collapse,
+}
+
+impl<'a> VisualNfa<'a> {
+    /// Creates a new highlighted NFA ready for visualization. `pinned_positions`
+    /// allows the application to persist user-moved node coordinates.
+    #[must_use]
+    pub fn new(
+        nfa: &'a Nfa,
+        highlights: Highlights,
+        pinned_positions: &'a HashMap<StateId, Point>,
+    ) -> Self {
+        Self {
+            nfa,
+            highlights,
+            pinned_positions,
+            tints: HashMap::new(),
+            breakpoints: HashSet::new(),
+            epsilon_glyph: EpsilonGlyph::default(),
+            selected: None,
+            max_depth: None,
+            collapsed_boxes: HashSet::new(),
+            collapse: Collapse::default(),
+        }
+    }
+
+    /// Fills states with overlay colors, e.g. from [`crate::component_tints`].
+    #[must_use]
+    pub fn with_tints(mut self, tints: HashMap<StateId, Color>) -> Self {
+        self.tints = tints;
+        self
+    }
+
+    /// Marks states where simulation playback pauses.
+    #[must_use]
+    pub fn with_breakpoints(mut self, breakpoints: HashSet<StateId>) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+
+    /// Marks a state as selected so it is drawn with a selection ring.
+    #[must_use]
+    pub fn with_selection(mut self, selected: Option<StateId>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Folds boxes nested deeper than `max_depth` into super-nodes, where root
+    /// boxes have depth 0. `None` draws every level.
+    #[must_use]
+    pub fn with_max_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.max_depth = max_depth;
+        self.collapse = Collapse::new(self.nfa, self.max_depth, &self.collapsed_boxes);
+        self
+    }
+
+    /// Folds each of `boxes` into a super-node, whatever its depth.
+    #[must_use]
+    pub fn with_collapsed_boxes(mut self, boxes: HashSet<BoxId>) -> Self {
+        self.collapsed_boxes = boxes;
+        self.collapse = Collapse::new(self.nfa, self.max_depth, &self.collapsed_boxes);
+        self
+    }
+
+    /// Labels ε transitions with `glyph` instead of the default `ε`.
+    #[must_use]
+    pub fn with_epsilon_glyph(mut self, glyph: EpsilonGlyph) -> Self {
+        self.epsilon_glyph = glyph;
+        self
+    }
+}
+
+impl<'a> Graph for VisualNfa<'a> {
+    fn nodes(&self) -> Vec<GraphNode> {
+        build_nodes(
+            self.nfa,
+            &self.highlights,
+            self.pinned_positions,
+            &self.tints,
+            &self.breakpoints,
+            self.selected,
+            &self.collapse,
+        )
+    }
+
+    fn edges(&self) -> Vec<GraphEdge> {
+        build_edges(
+            self.nfa,
+            &self.highlights,
+            self.epsilon_glyph,
+            &self.collapse,
+        )
+    }
+
+    fn boxes(&self) -> Vec<GraphBox> {
+        self.collapse
+            .boxes(self.nfa.boxes.iter().cloned().map(Into::into).collect())
+    }
+}
+
+fn build_nodes(
+    nfa: &Nfa,
+    highlights: &Highlights,
+    pinned: &HashMap<StateId, Point>,
+    tints: &HashMap<StateId, Color>,
+    breakpoints: &HashSet<StateId>,
+    selected: Option<StateId>,
+    collapse: &Collapse,
+) -> Vec<GraphNode> {
+    nfa.states
+        .iter()
+        .filter(|state| collapse.is_drawn(state.id))
+        .map(|state| {
+            // A super-node takes on whatever applies to any state folded into it.
+            let folded = collapse.members(state.id);
+            let members = folded.unwrap_or(std::slice::from_ref(&state.id));
+            let any = |check: &dyn Fn(StateId) -> bool| members.iter().any(|&id| check(id));
+            let label = folded.map_or_else(
+                || state.id.to_string(),
+                |folded| format!("×{}", folded.len()),
+            );
+            let highlight = members
+                .iter()
+                .filter_map(|&id| highlights.state_style(id))
+                .min_by_key(|style| *style != StateHighlight::Active);
+            let mut node = GraphNode::new(
+                state.id,
+                label,
+                any(&|id| nfa.start == id),
+                any(&|id| nfa.accepts.contains(&id)),
+                state.box_id,
+            )
+            .with_highlight(highlight)
+            .with_tint(tints.get(&state.id).copied())
+            .with_ghost(any(&|id| highlights.is_state_ghost(id)))
+            .with_overlays(
+                highlights
+                    .layers
+                    .iter()
+                    .filter(|layer| members.iter().any(|id| layer.states.contains(id)))
+                    .map(|layer| layer.color)
+                    .collect(),
+            )
+            .with_breakpoint(any(&|id| breakpoints.contains(&id)))
+            .with_selected(selected.is_some_and(|id| members.contains(&id)))
+            .with_collapsed(folded.map(<[StateId]>::len));
+
+            if let Some(pos) = pinned.get(&state.id) {
+                node.manual_position = Some(*pos);
+                node.is_pinned = true;
+            }
+
+            node
+        })
+        .collect()
+}
+
+fn build_edges(
+    nfa: &Nfa,
+    highlights: &Highlights,
+    glyph: EpsilonGlyph,
+    collapse: &Collapse,
+) -> Vec<GraphEdge> {
+    // Build a map of box_id -> box for easy lookup
+    let box_map: HashMap<_, _> = nfa.boxes.iter().map(|b| (b.id, b)).collect();
+
+    // Parallel transitions, e.g. between collapsed super-nodes, share one edge
+    // whose label lists all of them. Edges keep the order of their first transition.
+    struct Group<'h> {
+        curve: EdgeCurve,
+        labels: EdgeLabelSet,
+        is_active: bool,
+        is_ghost: bool,
+        layer: Option<&'h HighlightLayer>,
+    }
+    let mut groups: Vec<((StateId, StateId), Group<'_>)> = Vec::new();
+    let mut index: HashMap<(StateId, StateId), usize> = HashMap::new();
+    for state in &nfa.states {
+        let transitions = nfa.transitions(state.id);
+        for transition in transitions {
+            // Transitions inside a super-node are not drawn.
+            let Some((from, to)) = collapse.edge(state.id, transition.to) else {
+                continue;
+            };
+            let label = transition.label;
+            let slot = *index.entry((from, to)).or_insert_with(|| {
+                // Determine if this edge should be curved based on star closure patterns
+                let curve = if from == to {
+                    EdgeCurve::Loop
+                } else {
+                    determine_edge_curve(
+                        state.id,
+                        transition.to,
+                        &transition.label,
+                        state.box_id,
+                        &box_map,
+                        nfa,
+                    )
+                };
+                groups.push((
+                    (from, to),
+                    Group {
+                        curve,
+                        labels: EdgeLabelSet::new(),
+                        is_active: false,
+                        is_ghost: false,
+                        layer: None,
+                    },
+                ));
+                groups.len() - 1
+            });
+
+            let group = &mut groups[slot].1;
+            group.labels.insert(label);
+            group.is_active |= highlights.is_edge_active(state.id, transition.to, label);
+            group.is_ghost |= highlights.is_edge_ghost(state.id, transition.to, label);
+            group.layer = group
+                .layer
+                .into_iter()
+                .chain(highlights.edge_layer(state.id, transition.to, label))
+                .max_by_key(|layer| layer.z_index);
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|((from, to), group)| {
+            GraphEdge::with_curve(from, to, group.labels.to_string_with(glyph), group.curve)
+                .with_active(group.is_active)
+                .with_ghost(group.is_ghost)
+                .with_overlay(group.layer.map(|layer| layer.color))
+        })
+        .collect()
+}
+
+/// Determines the curvature style for an edge based on its role in the NFA structure.
+///
+/// For star closures, Thompson's construction creates:
+/// ```text
+///        ┌────────ε (bypass, curve down)─────┐
+///        ↓                                    ↓
+/// START ──ε→ inner_start ──'a'→ inner_accept ──ε→ ACCEPT
+///   (straight)                   ↑
+///                 └───ε (loop up)┘
+/// ```
+///
+/// The star box contains [START, ACCEPT].
+/// The inner fragment is in a child box.
+///
+/// We curve two edges:
+/// 1. START → ACCEPT: Curve down (bypass, wraps below inner fragment)
+/// 2. inner_accept → inner_start: Curve up (loop-back, wraps above inner fragment)
+///
+/// All other edges (entry, exit, literal) are straight.
+///
+/// # Arguments
+/// - `from`: Source state ID
+/// - `to`: Destination state ID
+/// - `label`: Edge label (epsilon or symbol)
+/// - `_from_box_id`: The box ID containing the source state (unused)
+/// - `box_map`: Map of box IDs to box metadata
+/// - `nfa`: The NFA being processed
+///
+/// # Returns
+/// The appropriate `EdgeCurve` for this edge
+fn determine_edge_curve(
+    from: u32,
+    to: u32,
+    label: &EdgeLabel,
+    _from_box_id: Option<u32>,
+    box_map: &HashMap<u32, &regviz_core::core::automaton::BoundingBox>,
+    nfa: &Nfa,
+) -> EdgeCurve {
+    // Only epsilon transitions can be curved
+    if *label != EdgeLabel::Eps {
+        return EdgeCurve::Straight;
+    }
+
+    // Check all star/plus/optional boxes to see if this edge matches a curved pattern
+    for bbox in box_map.values() {
+        // Only apply curves to unary operators
+        if !matches!(
+            bbox.kind,
+            BoxKind::KleeneStar | BoxKind::KleenePlus | BoxKind::Optional
+        ) {
+            continue;
+        }
+
+        // Star box should have exactly 2 states: [start, accept]
+        if bbox.states.len() != 2 {
+            continue;
+        }
+
+        let star_start = bbox.states[0];
+        let star_accept = bbox.states[1];
+
+        // Pattern 1: star_start → star_accept (bypass, curve down)
+        if from == star_start && to == star_accept {
+            return EdgeCurve::CurveDown;
+        }
+
+        // Pattern 2: inner_accept → inner_start (loop-back, curve up)
+        // Find inner_start: it's the epsilon target of star_start that's NOT star_accept
+        let inner_start = nfa
+            .transitions(star_start)
+            .iter()
+            .find(|t| t.label == EdgeLabel::Eps && t.to != star_accept)
+            .map(|t| t.to);
+
+        if let Some(inner_start_id) = inner_start {
+            // Check if this edge goes TO inner_start
+            if to == inner_start_id {
+                // If FROM is star_start, this is the entry edge - keep it straight
+                if from == star_start {
+                    continue; // Don't curve the entry edge
+                }
+
+                // Check if FROM is an inner_accept (has epsilon edge to star_accept)
+                let from_is_inner_accept = nfa
+                    .transitions(from)
+                    .iter()
+                    .any(|t| t.to == star_accept && t.label == EdgeLabel::Eps);
+
+                if from_is_inner_accept {
+                    // This is the loop-back edge - curve it upward
+                    return EdgeCurve::CurveUp;
+                }
+            }
+        }
+    }
+
+    // All other edges (entry, exit, literal) are straight
+    EdgeCurve::Straight
+}
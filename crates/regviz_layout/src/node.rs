@@ -0,0 +1,288 @@
+use regviz_core::core::automaton::{BoxId, StateId};
+use serde::Serialize;
+
+use crate::{Color, Point, Rect, StateHighlight};
+
+/// Distance from a node's centre to the edge of its bounds, as a multiple of
+/// its radius; wide enough for the start arrow and highlight halos.
+const NODE_BOUNDS_FACTOR: f32 = 2.0;
+
+/// Height of a rounded rectangle node relative to its width.
+const ROUNDED_RECT_HEIGHT_RATIO: f32 = 0.75;
+
+/// Corner radius of a rounded rectangle node relative to the node radius.
+const ROUNDED_RECT_CORNER_RATIO: f32 = 0.3;
+
+/// What a node stands for, deciding the shape and font it is drawn with.
+///
+/// Automaton states are all alike; syntax tree nodes tell operators apart from
+/// the characters they combine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum NodeKind {
+    /// An automaton state, drawn as a circle.
+    #[default]
+    State,
+    /// A character of a syntax tree, drawn as a circle labelled in monospace.
+    Literal,
+    /// The empty string of a syntax tree, drawn as a circle.
+    Epsilon,
+    /// A concatenation of a syntax tree, drawn as a rounded rectangle.
+    Concat,
+    /// An alternation of a syntax tree, drawn as a hexagon.
+    Alternation,
+    /// A Kleene star of a syntax tree, drawn as a diamond.
+    Star,
+    /// An optional of a syntax tree, drawn as a diamond.
+    Optional,
+}
+
+impl NodeKind {
+    /// Returns whether the node is an automaton state.
+    #[must_use]
+    pub fn is_state(&self) -> bool {
+        *self == Self::State
+    }
+
+    /// Returns whether the node's label is a character of the pattern, shown in monospace.
+    #[must_use]
+    pub fn is_literal(&self) -> bool {
+        *self == Self::Literal
+    }
+
+    /// Returns the outline nodes of this kind are drawn with.
+    #[must_use]
+    pub fn shape(&self) -> NodeShape {
+        match self {
+            Self::State | Self::Literal | Self::Epsilon => NodeShape::Circle,
+            Self::Concat => NodeShape::RoundedRect,
+            Self::Alternation => NodeShape::Hexagon,
+            Self::Star | Self::Optional => NodeShape::Diamond,
+        }
+    }
+}
+
+/// Outline of a node, fitting within the circle of its radius.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeShape {
+    /// A circle.
+    Circle,
+    /// A rectangle as wide as the circle, flattened and with rounded corners.
+    RoundedRect,
+    /// A hexagon with a corner on either side.
+    Hexagon,
+    /// A square standing on one corner.
+    Diamond,
+}
+
+impl NodeShape {
+    /// Returns the corners of a polygonal outline, clockwise from the rightmost one.
+    ///
+    /// # Arguments
+    /// - `center` (`Point`) - Center of the node.
+    /// - `radius` (`f32`) - Radius of the node.
+    ///
+    /// # Returns
+    /// - `Vec<Point>` - The corners, or none for circles and rounded rectangles,
+    ///   which are not polygons.
+    #[must_use]
+    pub fn corners(&self, center: Point, radius: f32) -> Vec<Point> {
+        let at = |dx: f32, dy: f32| Point::new(center.x + dx, center.y + dy);
+        match self {
+            Self::Circle | Self::RoundedRect => Vec::new(),
+            Self::Hexagon => {
+                let (half, rise) = (radius / 2.0, radius * 3f32.sqrt() / 2.0);
+                vec![
+                    at(radius, 0.0),
+                    at(half, rise),
+                    at(-half, rise),
+                    at(-radius, 0.0),
+                    at(-half, -rise),
+                    at(half, -rise),
+                ]
+            }
+            Self::Diamond => vec![
+                at(radius, 0.0),
+                at(0.0, radius),
+                at(-radius, 0.0),
+                at(0.0, -radius),
+            ],
+        }
+    }
+
+    /// Returns the rectangle and corner radius of a rounded rectangle outline.
+    ///
+    /// # Arguments
+    /// - `center` (`Point`) - Center of the node.
+    /// - `radius` (`f32`) - Radius of the node.
+    ///
+    /// # Returns
+    /// - `(Rect, f32)` - The rectangle and the radius of its corners.
+    #[must_use]
+    pub fn rounded_rect(center: Point, radius: f32) -> (Rect, f32) {
+        let half_height = radius * ROUNDED_RECT_HEIGHT_RATIO;
+        let rect = Rect {
+            x: center.x - radius,
+            y: center.y - half_height,
+            width: radius * 2.0,
+            height: half_height * 2.0,
+        };
+        (rect, radius * ROUNDED_RECT_CORNER_RATIO)
+    }
+}
+
+/// Visual representation of a state in the rendered graph.
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphNode {
+    /// Identifier of the state being rendered.
+    pub id: StateId,
+    /// Human readable label.
+    pub label: String,
+    /// What the node stands for.
+    #[serde(skip_serializing_if = "NodeKind::is_state")]
+    pub kind: NodeKind,
+    /// Whether this node is the start state.
+    pub is_start: bool,
+    /// Whether this node is an accepting state.
+    pub is_accept: bool,
+    /// Bounding box identifier that owns this node, if any.
+    #[allow(dead_code)]
+    pub box_id: Option<BoxId>,
+    /// Optional highlight applied during simulation.
+    pub highlight: Option<StateHighlight>,
+    /// Whether the node is selected in the inspector.
+    pub is_selected: bool,
+    /// Overlay fill color used when no simulation highlight applies.
+    #[serde(skip)]
+    pub tint: Option<Color>,
+    /// Colors of the highlight layers containing the node, bottom first.
+    #[serde(skip)]
+    pub overlays: Vec<Color>,
+    /// Whether the node would be active after the previewed next step.
+    pub is_ghost: bool,
+    /// Whether playback pauses when this node becomes active.
+    pub is_breakpoint: bool,
+    /// Number of states folded into this node when it stands for a collapsed fragment.
+    pub collapsed: Option<usize>,
+    /// If true the node's position has been manually set by the user and
+    /// should be respected by layout algorithms.
+    pub is_pinned: bool,
+    /// Optional manual position in layout coordinates. When present and
+    /// `is_pinned` is true this overrides any computed layout coordinate.
+    pub manual_position: Option<Point>,
+}
+
+impl GraphNode {
+    /// Creates a new [`GraphNode`] with sensible defaults.
+    #[must_use]
+    pub fn new(
+        id: StateId,
+        label: String,
+        is_start: bool,
+        is_accept: bool,
+        box_id: Option<BoxId>,
+    ) -> Self {
+        Self {
+            id,
+            label,
+            kind: NodeKind::State,
+            is_start,
+            is_accept,
+            box_id,
+            highlight: None,
+            is_selected: false,
+            tint: None,
+            overlays: Vec::new(),
+            is_ghost: false,
+            is_breakpoint: false,
+            collapsed: None,
+            is_pinned: false,
+            manual_position: None,
+        }
+    }
+
+    /// Applies a highlight style for the current simulation step.
+    #[must_use]
+    pub fn with_highlight(mut self, highlight: Option<StateHighlight>) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Marks the node as selected in the inspector.
+    #[must_use]
+    pub fn with_selected(mut self, is_selected: bool) -> Self {
+        self.is_selected = is_selected;
+        self
+    }
+
+    /// Sets the colors of the highlight layers containing the node, bottom first.
+    #[must_use]
+    pub fn with_overlays(mut self, overlays: Vec<Color>) -> Self {
+        self.overlays = overlays;
+        self
+    }
+
+    /// Marks the node as part of the previewed next frontier.
+    #[must_use]
+    pub fn with_ghost(mut self, is_ghost: bool) -> Self {
+        self.is_ghost = is_ghost;
+        self
+    }
+
+    /// Marks the node as a simulation breakpoint.
+    #[must_use]
+    pub fn with_breakpoint(mut self, is_breakpoint: bool) -> Self {
+        self.is_breakpoint = is_breakpoint;
+        self
+    }
+
+    /// Marks the node as a super-node standing for `states` folded states.
+    #[must_use]
+    pub fn with_collapsed(mut self, states: Option<usize>) -> Self {
+        self.collapsed = states;
+        self
+    }
+
+    /// Sets what the node stands for.
+    #[must_use]
+    pub fn with_kind(mut self, kind: NodeKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Applies an overlay fill color, shown while the node is not highlighted.
+    #[must_use]
+    pub fn with_tint(mut self, tint: Option<Color>) -> Self {
+        self.tint = tint;
+        self
+    }
+}
+
+/// [`GraphNode`] accompanied by layout information.
+#[derive(Debug, Clone, Serialize)]
+pub struct PositionedNode {
+    /// Node metadata.
+    pub data: GraphNode,
+    /// Logical position before transforms.
+    pub position: Point,
+    /// Node radius.
+    pub radius: f32,
+}
+
+impl PositionedNode {
+    /// Builds a positioned node from metadata and coordinates.
+    #[must_use]
+    pub fn new(data: GraphNode, position: Point, radius: f32) -> Self {
+        Self {
+            data,
+            position,
+            radius,
+        }
+    }
+
+    /// Returns a rectangle enclosing everything drawn for the node: its
+    /// circle, start arrow, halos and markers.
+    #[must_use]
+    pub fn bounds(&self) -> Rect {
+        Rect::enclosing([self.position]).expanded(self.radius * NODE_BOUNDS_FACTOR)
+    }
+}
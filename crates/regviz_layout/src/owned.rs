@@ -0,0 +1,114 @@
+use serde::Serialize;
+
+use crate::{Graph, GraphBox, GraphEdge, GraphNode};
+
+/// A graph held as plain data rather than derived from an automaton.
+///
+/// Useful for graphs supplied from outside the crate, such as imported
+/// automata or machines built by another tool, and for freezing any other
+/// [`Graph`] so it can be stored and laid out again later. Its parts are
+/// public, so they can be borrowed without the copies [`Graph`] returns.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct OwnedGraph {
+    /// The renderable nodes.
+    pub nodes: Vec<GraphNode>,
+    /// The edges between the nodes.
+    pub edges: Vec<GraphEdge>,
+    /// Bounding boxes rendered behind the nodes.
+    pub boxes: Vec<GraphBox>,
+}
+
+impl OwnedGraph {
+    /// Copies the current nodes, edges and boxes of `graph`.
+    ///
+    /// # Arguments
+    /// - `graph` (`&dyn Graph`) - Any graph, e.g. a `VisualDfa`.
+    ///
+    /// # Returns
+    /// - `OwnedGraph` - A graph that lays out exactly like `graph`.
+    #[must_use]
+    pub fn from_graph(graph: &dyn Graph) -> Self {
+        Self {
+            nodes: graph.nodes(),
+            edges: graph.edges(),
+            boxes: graph.boxes(),
+        }
+    }
+}
+
+impl Graph for OwnedGraph {
+    fn nodes(&self) -> Vec<GraphNode> {
+        self.nodes.clone()
+    }
+
+    fn edges(&self) -> Vec<GraphEdge> {
+        self.edges.clone()
+    }
+
+    fn boxes(&self) -> Vec<GraphBox> {
+        self.boxes.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use regviz_core::core::nfa::Nfa;
+    use regviz_core::core::parser::Ast;
+    use regviz_core::core::{dfa, min};
+
+    use super::*;
+    use crate::layout::{
+        BoxVisibility, DfaLayoutStrategy, LayoutConfig, LayoutStrategy, NfaLayoutStrategy,
+    };
+    use crate::{Highlights, VisualDfa, svg};
+
+    #[test]
+    fn test_lays_out_like_its_source() {
+        let nfa = Nfa::build(&Ast::build("(a+b)*c").unwrap());
+        let visibility = BoxVisibility::default();
+        let owned = OwnedGraph::from_graph(&nfa);
+        assert_eq!(owned.nodes.len(), nfa.states.len());
+        assert_eq!(
+            svg::render(&NfaLayoutStrategy::default().compute(
+                &owned,
+                &visibility,
+                &LayoutConfig::default()
+            )),
+            svg::render(&NfaLayoutStrategy::default().compute(
+                &nfa,
+                &visibility,
+                &LayoutConfig::default()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_heterogeneous_graphs_share_a_type() {
+        let nfa = Nfa::build(&Ast::build("ab*").unwrap());
+        let dfa = min::minimize(&dfa::determinize(&nfa));
+        let pinned = HashMap::new();
+        let graphs: Vec<Box<dyn Graph + '_>> = vec![
+            Box::new(VisualDfa::new(
+                &dfa,
+                &dfa.alphabet,
+                Highlights::default(),
+                &pinned,
+            )),
+            Box::new(OwnedGraph::default()),
+        ];
+        let layouts: Vec<_> = graphs
+            .iter()
+            .map(|graph| {
+                DfaLayoutStrategy.compute(
+                    graph,
+                    &BoxVisibility::default(),
+                    &LayoutConfig::default(),
+                )
+            })
+            .collect();
+        assert_eq!(layouts[0].nodes.len(), dfa.states.len());
+        assert!(layouts[1].nodes.is_empty());
+    }
+}
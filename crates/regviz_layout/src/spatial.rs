@@ -0,0 +1,275 @@
+//! Uniform grid over a [`GraphLayout`] for picking the elements under a point.
+//!
+//! Frontends hit-test on every pointer event, so scanning every node and edge
+//! gets slow on large graphs. A [`SpatialIndex`] buckets each element by its
+//! [bounds](PositionedNode::bounds) into grid cells once per layout; a query
+//! then only looks at the few elements registered in the cells it touches.
+
+use crate::{GraphLayout, Point, PositionedBox, PositionedEdge, PositionedNode, Rect};
+
+/// Most cells along either side of the grid, bounding its memory on huge layouts.
+const MAX_CELLS_PER_SIDE: usize = 256;
+
+/// Grid of layout elements by the cells their bounds cover.
+///
+/// The index only stores element positions in the layout it was built from,
+/// so every query must be given that same, unmodified layout.
+#[derive(Debug, Clone, Default)]
+pub struct SpatialIndex {
+    /// Top-left corner of the grid.
+    origin: Point,
+    /// Width and height of one cell.
+    cell_size: f32,
+    /// Number of cells along the x axis.
+    columns: usize,
+    /// Number of cells along the y axis.
+    rows: usize,
+    /// Indices into [`GraphLayout::nodes`], per cell in row-major order.
+    nodes: Vec<Vec<usize>>,
+    /// Indices into [`GraphLayout::edges`], per cell in row-major order.
+    edges: Vec<Vec<usize>>,
+    /// Indices into [`GraphLayout::boxes`], per cell in row-major order.
+    boxes: Vec<Vec<usize>>,
+}
+
+impl SpatialIndex {
+    /// Builds the index for `layout`.
+    ///
+    /// The grid spans the bounds of every element and has about as many cells
+    /// as there are elements, so each cell holds only a handful of them.
+    ///
+    /// # Arguments
+    /// - `layout` (`&GraphLayout`) - The layout to index.
+    ///
+    /// # Returns
+    /// - `SpatialIndex` - The index, valid for as long as `layout` is unchanged.
+    #[must_use]
+    pub fn new(layout: &GraphLayout) -> Self {
+        let node_bounds: Vec<Rect> = layout.nodes.iter().map(PositionedNode::bounds).collect();
+        let edge_bounds: Vec<Rect> = layout.edges.iter().map(PositionedEdge::bounds).collect();
+        let box_bounds: Vec<Rect> = layout.boxes.iter().map(PositionedBox::bounds).collect();
+
+        let all = node_bounds.iter().chain(&edge_bounds).chain(&box_bounds);
+        let extent = Rect::enclosing(all.flat_map(|rect| {
+            [
+                Point::new(rect.x, rect.y),
+                Point::new(rect.x + rect.width, rect.y + rect.height),
+            ]
+        }));
+        let count = node_bounds.len() + edge_bounds.len() + box_bounds.len();
+        let per_side = ((count as f32).sqrt().ceil() as usize).clamp(1, MAX_CELLS_PER_SIDE);
+        let cell_size = (extent.width.max(extent.height) / per_side as f32).max(1.0);
+
+        let columns = ((extent.width / cell_size).ceil() as usize).max(1);
+        let rows = ((extent.height / cell_size).ceil() as usize).max(1);
+        let mut index = Self {
+            origin: Point::new(extent.x, extent.y),
+            cell_size,
+            columns,
+            rows,
+            nodes: vec![Vec::new(); columns * rows],
+            edges: vec![Vec::new(); columns * rows],
+            boxes: vec![Vec::new(); columns * rows],
+        };
+        for (i, rect) in node_bounds.iter().enumerate() {
+            for cell in index.cells(rect) {
+                index.nodes[cell].push(i);
+            }
+        }
+        for (i, rect) in edge_bounds.iter().enumerate() {
+            for cell in index.cells(rect) {
+                index.edges[cell].push(i);
+            }
+        }
+        for (i, rect) in box_bounds.iter().enumerate() {
+            for cell in index.cells(rect) {
+                index.boxes[cell].push(i);
+            }
+        }
+        index
+    }
+
+    /// Finds the node whose circle contains `point`.
+    ///
+    /// # Arguments
+    /// - `layout` (`&GraphLayout`) - The layout the index was built from.
+    /// - `point` (`Point`) - Position in layout coordinates.
+    ///
+    /// # Returns
+    /// - `Option<&PositionedNode>` - The first such node in layout order, if any.
+    #[must_use]
+    pub fn node_at<'a>(&self, layout: &'a GraphLayout, point: Point) -> Option<&'a PositionedNode> {
+        self.candidates(&self.nodes, point)
+            .iter()
+            .map(|&i| &layout.nodes[i])
+            .find(|node| node.position.distance(point) <= node.radius)
+    }
+
+    /// Finds the edge drawn closest to `point`, no farther than `tolerance`.
+    ///
+    /// # Arguments
+    /// - `layout` (`&GraphLayout`) - The layout the index was built from.
+    /// - `point` (`Point`) - Position in layout coordinates.
+    /// - `tolerance` (`f32`) - Farthest distance, in layout units, an edge may be from `point`.
+    ///
+    /// # Returns
+    /// - `Option<&PositionedEdge>` - The closest edge within `tolerance`, if any.
+    #[must_use]
+    pub fn edge_near<'a>(
+        &self,
+        layout: &'a GraphLayout,
+        point: Point,
+        tolerance: f32,
+    ) -> Option<&'a PositionedEdge> {
+        let area = Rect::enclosing([point]).expanded(tolerance);
+        let mut candidates: Vec<usize> = self
+            .cells(&area)
+            .flat_map(|cell| self.edges[cell].iter().copied())
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        candidates
+            .into_iter()
+            .map(|i| {
+                let edge = &layout.edges[i];
+                (edge, edge.distance_to(point))
+            })
+            .filter(|(_, distance)| *distance <= tolerance)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(edge, _)| edge)
+    }
+
+    /// Finds the bounding boxes whose rectangle contains `point`.
+    ///
+    /// # Arguments
+    /// - `layout` (`&GraphLayout`) - The layout the index was built from.
+    /// - `point` (`Point`) - Position in layout coordinates.
+    ///
+    /// # Returns
+    /// - `Vec<&PositionedBox>` - The boxes, in layout order.
+    #[must_use]
+    pub fn boxes_at<'a>(&self, layout: &'a GraphLayout, point: Point) -> Vec<&'a PositionedBox> {
+        self.candidates(&self.boxes, point)
+            .iter()
+            .map(|&i| &layout.boxes[i])
+            .filter(|bbox| {
+                let rect = bbox.rect;
+                (rect.x..=rect.x + rect.width).contains(&point.x)
+                    && (rect.y..=rect.y + rect.height).contains(&point.y)
+            })
+            .collect()
+    }
+
+    /// Returns the elements of `grid` registered in the cell containing `point`.
+    fn candidates<'s>(&self, grid: &'s [Vec<usize>], point: Point) -> &'s [usize] {
+        self.cells(&Rect::enclosing([point]))
+            .next()
+            .map_or(&[], |cell| grid[cell].as_slice())
+    }
+
+    /// Returns the cells, in row-major order, that `rect` overlaps.
+    fn cells(&self, rect: &Rect) -> impl Iterator<Item = usize> + use<> {
+        let cell_size = self.cell_size;
+        let span = |start: f32, length: f32, origin: f32, count: usize| {
+            let first = ((start - origin) / cell_size).floor();
+            let last = ((start + length - origin) / cell_size).floor();
+            // The far border of the grid belongs to its last cell.
+            if last < 0.0 || first > count as f32 {
+                return 0..0;
+            }
+            let first = (first.max(0.0) as usize).min(count - 1);
+            let last = (last as usize).min(count - 1);
+            first..last + 1
+        };
+        let columns = span(rect.x, rect.width, self.origin.x, self.columns);
+        let rows = span(rect.y, rect.height, self.origin.y, self.rows);
+        let width = self.columns;
+        rows.flat_map(move |row| columns.clone().map(move |column| row * width + column))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regviz_core::core::nfa::Nfa;
+    use regviz_core::core::parser::Ast;
+
+    use super::*;
+    use crate::layout::{LayoutStrategy, NfaLayoutStrategy};
+    use crate::{BoxVisibility, LayoutConfig};
+
+    /// Hover tolerance used by the tests, in layout units.
+    const TOLERANCE: f32 = 6.0;
+
+    fn nfa_layout(pattern: &str) -> GraphLayout {
+        let nfa = Nfa::build(&Ast::build(pattern).unwrap());
+        NfaLayoutStrategy::default().compute(
+            &nfa,
+            &BoxVisibility::default(),
+            &LayoutConfig::default(),
+        )
+    }
+
+    #[test]
+    fn test_finds_every_node_at_its_center() {
+        let layout = nfa_layout("(a+b)*abb");
+        let index = SpatialIndex::new(&layout);
+        for node in &layout.nodes {
+            let hit = index.node_at(&layout, node.position).unwrap();
+            assert_eq!(hit.data.id, node.data.id);
+        }
+        let far = Point::new(layout.bounds.x - 1000.0, layout.bounds.y - 1000.0);
+        assert!(index.node_at(&layout, far).is_none());
+    }
+
+    #[test]
+    fn test_picks_the_same_edge_as_a_linear_scan() {
+        let layout = nfa_layout("(a+b)*abb");
+        let index = SpatialIndex::new(&layout);
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = layout.bounds;
+        for step_x in 0..=40 {
+            for step_y in 0..=40 {
+                let point = Point::new(
+                    x + width * step_x as f32 / 40.0,
+                    y + height * step_y as f32 / 40.0,
+                );
+                let expected = layout
+                    .edges
+                    .iter()
+                    .map(|edge| (edge, edge.distance_to(point)))
+                    .filter(|(_, distance)| *distance <= TOLERANCE)
+                    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .map(|(edge, _)| (edge.data.from, edge.data.to));
+                let found = index
+                    .edge_near(&layout, point, TOLERANCE)
+                    .map(|edge| (edge.data.from, edge.data.to));
+                assert_eq!(found, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_finds_every_box_around_a_point() {
+        let layout = nfa_layout("(ab)*");
+        let index = SpatialIndex::new(&layout);
+        let inner = layout
+            .boxes
+            .iter()
+            .min_by(|a, b| {
+                (a.rect.width * a.rect.height).total_cmp(&(b.rect.width * b.rect.height))
+            })
+            .unwrap();
+        let center = Point::new(
+            inner.rect.x + inner.rect.width / 2.0,
+            inner.rect.y + inner.rect.height / 2.0,
+        );
+        let hits = index.boxes_at(&layout, center);
+        assert!(hits.len() > 1);
+        assert!(hits.iter().any(|bbox| bbox.data.id == inner.data.id));
+    }
+}
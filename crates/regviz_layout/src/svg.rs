@@ -0,0 +1,516 @@
+//! Standalone SVG rendering of computed layouts.
+//!
+//! Produces the same picture as the desktop canvas on a light background,
+//! without a GPU or a window, for documentation and CI artifacts.
+
+use std::fmt::Write;
+
+use crate::{
+    Color, EdgeCurve, GraphLayout, LABEL_DISTANCE, NodeShape, Point, PositionedBox, PositionedEdge,
+    PositionedNode, StateHighlight, curve_control_point, quadratic_bezier_point, self_loop,
+};
+
+/// Empty space kept around the layout bounds.
+const MARGIN: f32 = 24.0;
+/// Font stack used for every label.
+const FONT_FAMILY: &str = "Fira Sans, Helvetica, Arial, sans-serif";
+/// Font stack used for the characters of syntax trees.
+const MONOSPACE_FONT_FAMILY: &str = "Fira Mono, Menlo, Consolas, monospace";
+/// Page background.
+const BACKGROUND: &str = "#ffffff";
+/// Node labels and box captions.
+const TEXT_COLOR: &str = "#111827";
+/// Node outlines, edges and their labels.
+const STROKE_COLOR: &str = "#374151";
+/// Box borders.
+const BOX_BORDER_COLOR: &str = "#6b7280";
+/// Default node fill.
+const NODE_FILL: &str = "#f9fafb";
+/// Fill of states active in the simulation.
+const ACTIVE_FILL: &str = "#22c55e";
+/// Fill of states where the simulation rejected.
+const REJECTED_FILL: &str = "#ef4444";
+/// Gap between the outer and inner circle of accepting states.
+const ACCEPT_RING_GAP: f32 = 4.0;
+/// Width of the band each highlight layer adds around a state.
+const OVERLAY_HALO_WIDTH: f32 = 6.0;
+/// Length of arrow heads.
+const ARROW_LENGTH: f32 = 10.0;
+/// Half-width of arrow heads at their base.
+const ARROW_HALF_WIDTH: f32 = 5.0;
+/// Length of the start arrow drawn left of the start state.
+const START_ARROW_LENGTH: f32 = 24.0;
+/// Font size of node labels.
+const NODE_FONT_SIZE: f32 = 18.0;
+/// Font size of edge labels.
+const EDGE_FONT_SIZE: f32 = 14.0;
+/// Font size of box captions.
+const BOX_FONT_SIZE: f32 = 12.0;
+
+/// Renders `layout` as a self-contained SVG document.
+///
+/// # Arguments
+/// - `layout` (`&GraphLayout`) - The positioned graph to draw.
+///
+/// # Returns
+/// - `String` - The SVG document, sized to the layout bounds plus a margin.
+#[must_use]
+pub fn render(layout: &GraphLayout) -> String {
+    let bounds = layout.bounds;
+    let (x, y) = (bounds.x - MARGIN, bounds.y - MARGIN);
+    let (width, height) = (bounds.width + MARGIN * 2.0, bounds.height + MARGIN * 2.0);
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}" font-family="{FONT_FAMILY}">"#,
+        number(width),
+        number(height),
+        number(x),
+        number(y),
+        number(width),
+        number(height),
+    );
+    let _ = writeln!(
+        svg,
+        r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{BACKGROUND}"/>"#,
+        number(x),
+        number(y),
+        number(width),
+        number(height),
+    );
+    for bbox in &layout.boxes {
+        write_box(&mut svg, bbox);
+    }
+    for edge in &layout.edges {
+        write_edge(&mut svg, edge);
+    }
+    for node in &layout.nodes {
+        write_node(&mut svg, node);
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Writes a bounding box with its optional caption.
+fn write_box(svg: &mut String, bbox: &PositionedBox) {
+    let rect = bbox.rect;
+    let fill = if bbox.style.filled {
+        format!(
+            r#"fill="{}" fill-opacity="{}""#,
+            hex(bbox.color),
+            number(bbox.color.a * bbox.opacity)
+        )
+    } else {
+        r#"fill="none""#.to_string()
+    };
+    let dash = if bbox.style.dashed {
+        r#" stroke-dasharray="6 4""#
+    } else {
+        ""
+    };
+    let _ = writeln!(
+        svg,
+        r#"<rect x="{}" y="{}" width="{}" height="{}" rx="{}" {fill} stroke="{BOX_BORDER_COLOR}" stroke-opacity="{}"{dash}/>"#,
+        number(rect.x),
+        number(rect.y),
+        number(rect.width),
+        number(rect.height),
+        number(bbox.style.corner_radius),
+        number(bbox.opacity),
+    );
+    if bbox.style.show_label {
+        write_text(
+            svg,
+            bbox.label_position,
+            bbox.data.label(),
+            BOX_FONT_SIZE,
+            TEXT_COLOR,
+            "start",
+        );
+    }
+}
+
+/// Writes an edge with its arrow head and label.
+fn write_edge(svg: &mut String, edge: &PositionedEdge) {
+    let (from, to) = (edge.from, edge.to);
+    let stroke = edge
+        .data
+        .overlay
+        .map_or_else(|| STROKE_COLOR.to_string(), hex);
+    if !edge.route.is_empty() {
+        write_routed_edge(svg, edge, &stroke);
+        return;
+    }
+    match edge.data.curve {
+        EdgeCurve::Straight => {
+            let Some((dx, dy)) = unit(from, to) else {
+                return;
+            };
+            let start = Point::new(
+                from.x + dx * edge.from_radius,
+                from.y + dy * edge.from_radius,
+            );
+            let end = Point::new(to.x - dx * edge.to_radius, to.y - dy * edge.to_radius);
+            let _ = writeln!(
+                svg,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{stroke}"/>"#,
+                number(start.x),
+                number(start.y),
+                number(end.x),
+                number(end.y),
+            );
+            write_arrow_head(svg, end, (dx, dy), &stroke);
+            write_edge_label(svg, edge.label_position, &edge.data.label);
+        }
+        EdgeCurve::CurveDown | EdgeCurve::CurveUp => {
+            let curve_down = edge.data.curve == EdgeCurve::CurveDown;
+            let Some(control) = curve_control_point(from, to, curve_down, edge.data.bend) else {
+                return;
+            };
+            // The tangents at both ends point towards the control point.
+            let Some((sx, sy)) = unit(from, control) else {
+                return;
+            };
+            let Some((ex, ey)) = unit(control, to) else {
+                return;
+            };
+            let start = Point::new(
+                from.x + sx * edge.from_radius,
+                from.y + sy * edge.from_radius,
+            );
+            let end = Point::new(to.x - ex * edge.to_radius, to.y - ey * edge.to_radius);
+            let _ = writeln!(
+                svg,
+                r#"<path d="M {} {} Q {} {} {} {}" fill="none" stroke="{stroke}"/>"#,
+                number(start.x),
+                number(start.y),
+                number(control.x),
+                number(control.y),
+                number(end.x),
+                number(end.y),
+            );
+            write_arrow_head(svg, end, (ex, ey), &stroke);
+
+            // Labels sit on the outer side of the curve, like on the canvas.
+            let mid = quadratic_bezier_point(from, control, to, 0.5);
+            let (mx, my) = unit(mid, control).unwrap_or((0.0, -1.0));
+            let anchor = Point::new(mid.x - mx * LABEL_DISTANCE, mid.y - my * LABEL_DISTANCE);
+            write_edge_label(svg, anchor, &edge.data.label);
+        }
+        EdgeCurve::Loop => {
+            let (center, loop_radius) = self_loop(from, edge.from_radius, edge.data.bend);
+            let _ = writeln!(
+                svg,
+                r#"<circle cx="{}" cy="{}" r="{}" fill="none" stroke="{stroke}"/>"#,
+                number(center.x),
+                number(center.y),
+                number(loop_radius),
+            );
+            let tip = Point::new(center.x + ARROW_LENGTH * 0.5, center.y - loop_radius);
+            write_arrow_head(svg, tip, (1.0, 0.0), &stroke);
+            let anchor = Point::new(center.x, center.y - loop_radius - LABEL_DISTANCE);
+            write_edge_label(svg, anchor, &edge.data.label);
+        }
+    }
+}
+
+/// Writes an edge routed through bend points as a polyline.
+fn write_routed_edge(svg: &mut String, edge: &PositionedEdge, stroke: &str) {
+    let mut path = edge.path();
+    let last = path.len() - 1;
+    let (Some((sx, sy)), Some((ex, ey))) =
+        (unit(path[0], path[1]), unit(path[last - 1], path[last]))
+    else {
+        return;
+    };
+    path[0] = Point::new(
+        path[0].x + sx * edge.from_radius,
+        path[0].y + sy * edge.from_radius,
+    );
+    path[last] = Point::new(
+        path[last].x - ex * edge.to_radius,
+        path[last].y - ey * edge.to_radius,
+    );
+    let points = path
+        .iter()
+        .map(|point| format!("{},{}", number(point.x), number(point.y)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let _ = writeln!(
+        svg,
+        r#"<polyline points="{points}" fill="none" stroke="{stroke}"/>"#,
+    );
+    write_arrow_head(svg, path[last], (ex, ey), stroke);
+    write_edge_label(svg, edge.label_position, &edge.data.label);
+}
+
+/// Writes a state with its accepting ring, start arrow and label.
+fn write_node(svg: &mut String, node: &PositionedNode) {
+    let center = node.position;
+    let fill = match (node.data.highlight, node.data.tint) {
+        (Some(StateHighlight::Active), _) => ACTIVE_FILL.to_string(),
+        (Some(StateHighlight::Rejected), _) => REJECTED_FILL.to_string(),
+        (None, Some(tint)) => hex(tint),
+        (None, None) => NODE_FILL.to_string(),
+    };
+    // Lower layers form the outer bands; the body covers the inner half of each.
+    let shape = node.data.kind.shape();
+    let layers = node.data.overlays.len();
+    for (index, &color) in node.data.overlays.iter().enumerate() {
+        let width = OVERLAY_HALO_WIDTH * 2.0 * (layers - index) as f32;
+        write_outline(
+            svg,
+            shape,
+            center,
+            node.radius,
+            &format!(
+                r#"fill="none" stroke="{}" stroke-opacity="{}" stroke-width="{}""#,
+                hex(color),
+                number(color.a),
+                number(width),
+            ),
+        );
+    }
+    write_outline(
+        svg,
+        shape,
+        center,
+        node.radius,
+        &format!(r#"fill="{fill}" stroke="{STROKE_COLOR}" stroke-width="1.5""#),
+    );
+    if node.data.is_accept {
+        let _ = writeln!(
+            svg,
+            r#"<circle cx="{}" cy="{}" r="{}" fill="none" stroke="{STROKE_COLOR}" stroke-width="1.2"/>"#,
+            number(center.x),
+            number(center.y),
+            number(node.radius - ACCEPT_RING_GAP),
+        );
+    }
+    if node.data.is_start {
+        let tip = Point::new(center.x - node.radius, center.y);
+        let _ = writeln!(
+            svg,
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{STROKE_COLOR}"/>"#,
+            number(tip.x - START_ARROW_LENGTH),
+            number(tip.y),
+            number(tip.x),
+            number(tip.y),
+        );
+        write_arrow_head(svg, tip, (1.0, 0.0), STROKE_COLOR);
+    }
+    if node.data.kind.is_literal() {
+        let _ = writeln!(svg, r#"<g font-family="{MONOSPACE_FONT_FAMILY}">"#);
+    }
+    write_text(
+        svg,
+        center,
+        &node.data.label,
+        NODE_FONT_SIZE,
+        TEXT_COLOR,
+        "middle",
+    );
+    if node.data.kind.is_literal() {
+        svg.push_str("</g>\n");
+    }
+}
+
+/// Writes the outline of a node of `shape` with the presentation `attributes`.
+fn write_outline(svg: &mut String, shape: NodeShape, center: Point, radius: f32, attributes: &str) {
+    match shape {
+        NodeShape::Circle => {
+            let _ = writeln!(
+                svg,
+                r#"<circle cx="{}" cy="{}" r="{}" {attributes}/>"#,
+                number(center.x),
+                number(center.y),
+                number(radius),
+            );
+        }
+        NodeShape::RoundedRect => {
+            let (rect, corner) = NodeShape::rounded_rect(center, radius);
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" rx="{}" {attributes}/>"#,
+                number(rect.x),
+                number(rect.y),
+                number(rect.width),
+                number(rect.height),
+                number(corner),
+            );
+        }
+        NodeShape::Hexagon | NodeShape::Diamond => {
+            let points = shape
+                .corners(center, radius)
+                .iter()
+                .map(|corner| format!("{},{}", number(corner.x), number(corner.y)))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let _ = writeln!(svg, r#"<polygon points="{points}" {attributes}/>"#);
+        }
+    }
+}
+
+/// Writes a filled arrow head whose tip is at `tip`, pointing along `direction`, in `color`.
+fn write_arrow_head(svg: &mut String, tip: Point, (dx, dy): (f32, f32), color: &str) {
+    let base = Point::new(tip.x - dx * ARROW_LENGTH, tip.y - dy * ARROW_LENGTH);
+    let (nx, ny) = (-dy * ARROW_HALF_WIDTH, dx * ARROW_HALF_WIDTH);
+    let _ = writeln!(
+        svg,
+        r#"<polygon points="{},{} {},{} {},{}" fill="{color}"/>"#,
+        number(tip.x),
+        number(tip.y),
+        number(base.x + nx),
+        number(base.y + ny),
+        number(base.x - nx),
+        number(base.y - ny),
+    );
+}
+
+/// Writes a transition label centered on `anchor`, skipping empty ones.
+fn write_edge_label(svg: &mut String, anchor: Point, label: &str) {
+    if !label.is_empty() {
+        write_text(svg, anchor, label, EDGE_FONT_SIZE, STROKE_COLOR, "middle");
+    }
+}
+
+/// Writes `content` vertically centered on `position`.
+fn write_text(
+    svg: &mut String,
+    position: Point,
+    content: &str,
+    size: f32,
+    color: &str,
+    anchor: &str,
+) {
+    let _ = writeln!(
+        svg,
+        r#"<text x="{}" y="{}" font-size="{}" fill="{color}" text-anchor="{anchor}" dominant-baseline="central">{}</text>"#,
+        number(position.x),
+        number(position.y),
+        number(size),
+        escape(content),
+    );
+}
+
+/// Returns the unit vector from `from` to `to`, or `None` when they coincide.
+fn unit(from: Point, to: Point) -> Option<(f32, f32)> {
+    let length = from.distance(to);
+    (length > f32::EPSILON).then(|| ((to.x - from.x) / length, (to.y - from.y) / length))
+}
+
+/// Formats a color as `#rrggbb`, ignoring its opacity.
+fn hex(color: Color) -> String {
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        channel(color.r),
+        channel(color.g),
+        channel(color.b)
+    )
+}
+
+/// Formats a coordinate with at most two decimals.
+fn number(value: f32) -> String {
+    let rounded = (value * 100.0).round() / 100.0;
+    // Avoid writing `-0`.
+    format!("{}", if rounded == 0.0 { 0.0 } else { rounded })
+}
+
+/// Escapes the characters XML reserves in text content.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use regviz_core::core::nfa::Nfa;
+    use regviz_core::core::parser::Ast;
+
+    use super::*;
+    use crate::layout::{
+        BoxVisibility, LayoutConfig, LayoutStrategy, NfaLayoutStrategy, TreeLayoutStrategy,
+    };
+    use crate::{AstGraph, EdgeHighlight, HighlightLayer, Highlights, VisualNfa};
+
+    fn nfa_svg(pattern: &str) -> String {
+        let nfa = Nfa::build(&Ast::build(pattern).expect("pattern parses"));
+        render(&NfaLayoutStrategy::default().compute(
+            &nfa,
+            &BoxVisibility::default(),
+            &LayoutConfig::default(),
+        ))
+    }
+
+    #[test]
+    fn test_renders_every_state_and_transition() {
+        let svg = nfa_svg("ab");
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        // Four states, plus the inner ring of the accepting one.
+        assert_eq!(svg.matches("<circle").count(), 5);
+        assert!(svg.contains(">'a'</text>"));
+        assert!(svg.contains(">'b'</text>"));
+    }
+
+    #[test]
+    fn test_draws_star_loop_back_as_curve_and_routes_bypass() {
+        let svg = nfa_svg("a*");
+        assert_eq!(svg.matches("<path d=\"M").count(), 1);
+        assert_eq!(svg.matches("<polyline").count(), 1);
+    }
+
+    #[test]
+    fn test_draws_highlight_layers() {
+        let nfa = Nfa::build(&Ast::build("ab").expect("pattern parses"));
+        let pinned = std::collections::HashMap::new();
+        let highlights = Highlights::default().with_layer(
+            HighlightLayer::new("reachable", Color::from_rgba(0.0, 0.0, 1.0, 0.5), 0)
+                .with_states([nfa.start])
+                .with_edges(nfa.transitions(nfa.start).iter().map(|transition| {
+                    EdgeHighlight::new(nfa.start, transition.to, transition.label)
+                })),
+        );
+        let graph = VisualNfa::new(&nfa, highlights, &pinned);
+        let svg = render(&NfaLayoutStrategy::default().compute(
+            &graph,
+            &BoxVisibility::default(),
+            &LayoutConfig::default(),
+        ));
+        assert_eq!(
+            svg.matches("stroke=\"#0000ff\" stroke-opacity=\"0.5\"")
+                .count(),
+            1
+        );
+        assert!(svg.contains("<line x1") && svg.contains("fill=\"#0000ff\"/>"));
+    }
+
+    #[test]
+    fn test_draws_ast_operators_with_their_shapes() {
+        let ast = Ast::build("(a+b)*c").expect("pattern parses");
+        let pinned = std::collections::HashMap::new();
+        let svg = render(&TreeLayoutStrategy.compute(
+            &AstGraph::new(&ast, &pinned),
+            &BoxVisibility::default(),
+            &LayoutConfig::default(),
+        ));
+        // One concatenation, one alternation and one star around three characters,
+        // on top of the background.
+        assert_eq!(svg.matches("<rect x=").count(), 2);
+        let outlined = |line: &&str| line.contains("stroke-width=\"1.5\"");
+        let polygons = svg.lines().filter(|line| line.starts_with("<polygon"));
+        assert_eq!(polygons.filter(outlined).count(), 2);
+        assert_eq!(svg.matches("<circle").count(), 3);
+        assert_eq!(svg.matches("<g font-family=\"Fira Mono").count(), 3);
+    }
+
+    #[test]
+    fn test_escapes_reserved_characters() {
+        assert_eq!(escape("<a & b>"), "&lt;a &amp; b&gt;");
+        assert_eq!(number(-0.001), "0");
+        assert_eq!(number(1.234), "1.23");
+    }
+}